@@ -0,0 +1,220 @@
+//! Server clock estimation for signed requests
+//!
+//! Venues reject signed requests whose timestamp is too far from their own clock
+//! (Binance `-1021`, OKX "timestamp expired"). A [`ServerClock`] samples a venue's
+//! server-time endpoint through a [`TimeSource`], estimates the offset between the
+//! local and the server clock, and hands out corrected timestamps.
+//!
+//! Each sample measures the round trip of the time request and assumes the server
+//! read its clock halfway through. The sample with the shortest round trip of the
+//! most recent ones is used, as it has the least room for asymmetric delays.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+
+use crate::error::RestError;
+
+/// Number of recent samples the offset is chosen from
+const SAMPLES: usize = 8;
+
+/// Reads a venue's clock
+#[async_trait]
+pub trait TimeSource: Send + Sync {
+    /// The venue's current time in milliseconds since the Unix epoch
+    async fn server_time_ms(&self) -> Result<i64, RestError>;
+}
+
+/// One measurement of the server clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSample {
+    /// Server time minus local time, in milliseconds
+    pub offset_ms: i64,
+
+    /// Round trip time of the time request
+    pub rtt: Duration,
+}
+
+/// Estimated offset between the local clock and a venue's clock. Clones share the
+/// same estimate, so one clock can be synced in the background and given to every
+/// signed client of the venue.
+#[derive(Debug, Clone, Default)]
+pub struct ServerClock {
+    samples: Arc<RwLock<VecDeque<ClockSample>>>,
+}
+
+/// Local wall clock in milliseconds since the Unix epoch
+fn local_time_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| i64::try_from(since_epoch.as_millis()).unwrap_or(i64::MAX))
+        .unwrap_or_default()
+}
+
+fn duration_ms(duration: Duration) -> i64 {
+    i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
+}
+
+impl ServerClock {
+    /// A clock with no samples, which reports local time until it is synced
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a sample: the request left at local time `sent_at_ms`, the server
+    /// answered `server_time_ms` and the answer arrived after `rtt`
+    pub fn record(&self, sent_at_ms: i64, server_time_ms: i64, rtt: Duration) {
+        let midpoint_ms = sent_at_ms.saturating_add(duration_ms(rtt).saturating_div(2));
+        let sample = ClockSample {
+            offset_ms: server_time_ms.saturating_sub(midpoint_ms),
+            rtt,
+        };
+        let mut samples = self.samples.write().unwrap_or_else(PoisonError::into_inner);
+        if samples.len() >= SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(sample);
+    }
+
+    /// The sample the estimate is based on, if the clock has been synced
+    pub fn best_sample(&self) -> Option<ClockSample> {
+        self.samples
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .min_by_key(|sample| sample.rtt)
+            .copied()
+    }
+
+    /// Server time minus local time in milliseconds, 0 if not synced
+    pub fn offset_ms(&self) -> i64 {
+        self.best_sample().map_or(0, |sample| sample.offset_ms)
+    }
+
+    /// Round trip time of the best sample, if synced
+    pub fn rtt(&self) -> Option<Duration> {
+        self.best_sample().map(|sample| sample.rtt)
+    }
+
+    /// Estimated server time in milliseconds since the Unix epoch
+    pub fn now_ms(&self) -> u64 {
+        u64::try_from(local_time_ms().saturating_add(self.offset_ms())).unwrap_or_default()
+    }
+
+    /// A receive window that covers `base` plus the uncertainty of the estimate
+    /// and the time the request spends in flight, capped at `max`. `None` until
+    /// the clock has been synced, so the venue's default applies.
+    pub fn recv_window(&self, base: Duration, max: Duration) -> Option<Duration> {
+        let rtt = self.rtt()?;
+        Some(base.saturating_add(rtt.saturating_mul(2)).min(max))
+    }
+
+    /// Take one sample from `source`
+    pub async fn sync(&self, source: &dyn TimeSource) -> Result<ClockSample, RestError> {
+        let sent_at_ms = local_time_ms();
+        let start = Instant::now();
+        let server_time_ms = source.server_time_ms().await?;
+        let rtt = start.elapsed();
+        self.record(sent_at_ms, server_time_ms, rtt);
+        Ok(self
+            .best_sample()
+            .unwrap_or(ClockSample { offset_ms: 0, rtt }))
+    }
+
+    /// Sync from `source` every `interval` until the returned task is aborted.
+    /// Failed samples are logged and skipped.
+    pub fn spawn_sync(&self, source: Arc<dyn TimeSource>, interval: Duration) -> JoinHandle<()> {
+        let clock = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match clock.sync(source.as_ref()).await {
+                    Ok(sample) => tracing::debug!(
+                        offset_ms = sample.offset_ms,
+                        rtt = ?sample.rtt,
+                        "server clock synced"
+                    ),
+                    Err(err) => tracing::warn!(error = %err, "server clock sync failed"),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    use super::*;
+
+    /// A server whose clock runs a fixed amount ahead of the local one
+    struct SkewedServer {
+        skew_ms: AtomicI64,
+    }
+
+    #[async_trait]
+    impl TimeSource for SkewedServer {
+        async fn server_time_ms(&self) -> Result<i64, RestError> {
+            Ok(local_time_ms().saturating_add(self.skew_ms.load(Ordering::Relaxed)))
+        }
+    }
+
+    #[test]
+    fn test_unsynced_clock_uses_local_time() {
+        let clock = ServerClock::new();
+        assert_eq!(clock.offset_ms(), 0);
+        assert_eq!(clock.rtt(), None);
+        assert_eq!(
+            clock.recv_window(Duration::from_secs(5), Duration::from_secs(60)),
+            None
+        );
+        let local = u64::try_from(local_time_ms()).unwrap();
+        assert!(clock.now_ms().abs_diff(local) < 1000);
+    }
+
+    #[test]
+    fn test_sample_with_shortest_round_trip_wins() {
+        let clock = ServerClock::new();
+        // Sent at 1000, server read 2100, took 200ms: offset 1000
+        clock.record(1000, 2100, Duration::from_millis(200));
+        // A slow sample skewed by an asymmetric delay
+        clock.record(5000, 7000, Duration::from_millis(900));
+        assert_eq!(clock.offset_ms(), 1000);
+        assert_eq!(clock.rtt(), Some(Duration::from_millis(200)));
+        assert_eq!(
+            clock.recv_window(Duration::from_secs(5), Duration::from_secs(60)),
+            Some(Duration::from_millis(5400))
+        );
+        assert_eq!(
+            clock.recv_window(Duration::from_secs(5), Duration::from_secs(5)),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_old_samples_are_forgotten() {
+        let clock = ServerClock::new();
+        clock.record(0, 500, Duration::ZERO);
+        for _ in 0..SAMPLES {
+            clock.record(0, -300, Duration::from_millis(10));
+        }
+        assert_eq!(clock.offset_ms(), -305);
+    }
+
+    #[tokio::test]
+    async fn test_sync_estimates_skew() {
+        let server = SkewedServer {
+            skew_ms: AtomicI64::new(-90_000),
+        };
+        let clock = ServerClock::new();
+        let clone = clock.clone();
+
+        clock.sync(&server).await.unwrap();
+        assert!(clone.offset_ms().saturating_add(90_000).abs() < 1000);
+        let local = u64::try_from(local_time_ms()).unwrap();
+        assert!(local.saturating_sub(clone.now_ms()) > 89_000);
+    }
+}
@@ -1,5 +1,10 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
 use thiserror::Error;
 
+use crate::transport::TransportError;
+
 /// Common error type for REST clients
 #[derive(Error, Debug)]
 pub enum RestError {
@@ -17,4 +22,279 @@ pub enum RestError {
 
     #[error("Unknown error: {0}")]
     Unknown(String),
+
+    /// An error reported by a venue, already classified
+    #[error(transparent)]
+    Venue(#[from] VenueError),
+}
+
+impl RestError {
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            RestError::RequestError(err) => ErrorKind::from(err),
+            RestError::RateLimitExceeded => ErrorKind::RateLimited { retry_after: None },
+            RestError::AuthenticationError(_) => ErrorKind::AuthFailed,
+            RestError::ValidationError(_) => ErrorKind::InvalidRequest,
+            RestError::Unknown(_) => ErrorKind::Other,
+            RestError::Venue(err) => err.kind.clone(),
+        }
+    }
+}
+
+/// What a failed request means, independent of which venue reported it.
+///
+/// Retry, alerting and order-management code should branch on this rather than
+/// on venue error codes or message strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The request was rejected by a rate limit. `retry_after` is the delay the
+    /// venue asked for, if it gave one.
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The caller's IP has been banned, typically for ignoring earlier rate limit
+    /// rejections. `until` is when the ban lifts, if the venue said.
+    IpBanned { until: Option<SystemTime> },
+
+    /// Missing, invalid, expired or insufficiently permissioned credentials, or a
+    /// bad signature or timestamp
+    AuthFailed,
+
+    /// The venue understood the request and rejected it as invalid
+    InvalidRequest,
+
+    /// Not enough balance or margin to perform the request
+    InsufficientFunds,
+
+    /// The referenced order does not exist (or is no longer open)
+    OrderNotFound,
+
+    /// The venue could not be reached or is down for maintenance. The request was
+    /// not processed.
+    ///
+    /// A bare 5xx status does not guarantee that, so `from_http_status` maps it to
+    /// `UnknownExecutionStatus`; venues use this kind only for errors their exchange
+    /// documents as rejected before processing.
+    ExchangeUnavailable,
+
+    /// The request may or may not have been executed (e.g. a timeout or an
+    /// internal error after the request was accepted). For order placement the
+    /// outcome must be resolved by querying the order.
+    UnknownExecutionStatus,
+
+    /// Anything that does not fit the categories above
+    Other,
+}
+
+impl ErrorKind {
+    /// Classify an HTTP status code that arrived without a recognised venue error
+    pub fn from_http_status(status: u16, retry_after: Option<Duration>) -> Self {
+        match status {
+            429 => ErrorKind::RateLimited { retry_after },
+            418 => ErrorKind::IpBanned {
+                until: retry_after.and_then(|delay| SystemTime::now().checked_add(delay)),
+            },
+            401 | 403 => ErrorKind::AuthFailed,
+            400..=499 => ErrorKind::InvalidRequest,
+            // Gateways answer 502/503 after forwarding the request, so it may have been executed
+            500..=599 => ErrorKind::UnknownExecutionStatus,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Whether repeating the same request later can succeed without changing it.
+    ///
+    /// `UnknownExecutionStatus` is deliberately excluded: whether it is safe to
+    /// repeat depends on whether the request is idempotent.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorKind::RateLimited { .. } | ErrorKind::IpBanned { .. } | ErrorKind::ExchangeUnavailable
+        )
+    }
+
+    /// How long to wait before retrying, if the venue said
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ErrorKind::RateLimited { retry_after } => *retry_after,
+            ErrorKind::IpBanned { until } => until.and_then(|until| until.duration_since(SystemTime::now()).ok()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::RateLimited {
+                retry_after: Some(delay),
+            } => write!(f, "rate limited (retry after {:?})", delay),
+            ErrorKind::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            ErrorKind::IpBanned { .. } => write!(f, "IP banned"),
+            ErrorKind::AuthFailed => write!(f, "authentication failed"),
+            ErrorKind::InvalidRequest => write!(f, "invalid request"),
+            ErrorKind::InsufficientFunds => write!(f, "insufficient funds"),
+            ErrorKind::OrderNotFound => write!(f, "order not found"),
+            ErrorKind::ExchangeUnavailable => write!(f, "exchange unavailable"),
+            ErrorKind::UnknownExecutionStatus => write!(f, "unknown execution status"),
+            ErrorKind::Other => write!(f, "error"),
+        }
+    }
+}
+
+impl From<&reqwest::Error> for ErrorKind {
+    fn from(err: &reqwest::Error) -> Self {
+        if let Some(status) = err.status() {
+            ErrorKind::from_http_status(status.as_u16(), None)
+        } else if err.is_connect() {
+            // Nothing was sent
+            ErrorKind::ExchangeUnavailable
+        } else if err.is_timeout() || err.is_request() || err.is_body() {
+            ErrorKind::UnknownExecutionStatus
+        } else {
+            ErrorKind::Other
+        }
+    }
+}
+
+/// A venue error converted into the common taxonomy.
+///
+/// The venue's own code and message are kept so nothing is lost in the
+/// conversion; `kind` is what generic code should look at.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub struct VenueError {
+    /// Name of the venue that produced the error, e.g. `"binance"`
+    pub venue: &'static str,
+
+    /// The venue-agnostic classification
+    pub kind: ErrorKind,
+
+    /// The venue's error code, if it had one. Stored as a string because some
+    /// venues use string codes.
+    pub code: Option<String>,
+
+    /// The venue's error message
+    pub message: String,
+
+    /// Client order id of the order the error is about, set when its outcome
+    /// could not be determined so the caller can look it up later
+    pub client_order_id: Option<String>,
+}
+
+impl VenueError {
+    pub fn new(venue: &'static str, kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            venue,
+            kind,
+            code: None,
+            message: message.into(),
+            client_order_id: None,
+        }
+    }
+
+    /// Attach the venue's error code
+    pub fn with_code(mut self, code: impl ToString) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    /// Attach the client order id of the order the error is about
+    pub fn with_client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Classify a transport error
+    pub fn from_http_error(venue: &'static str, err: &TransportError) -> Self {
+        let error = Self::new(venue, ErrorKind::from(err), err.to_string());
+        match err.status() {
+            Some(status) => error.with_code(status.as_u16()),
+            None => error,
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.kind.is_retryable()
+    }
+}
+
+impl fmt::Display for VenueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.code {
+            Some(code) => write!(
+                f,
+                "{} {} ({}): {}",
+                self.venue, self.kind, code, self.message
+            ),
+            None => write!(f, "{} {}: {}", self.venue, self.kind, self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_http_status() {
+        assert_eq!(
+            ErrorKind::from_http_status(429, Some(Duration::from_secs(3))),
+            ErrorKind::RateLimited {
+                retry_after: Some(Duration::from_secs(3))
+            }
+        );
+        assert!(matches!(
+            ErrorKind::from_http_status(418, None),
+            ErrorKind::IpBanned { until: None }
+        ));
+        assert_eq!(
+            ErrorKind::from_http_status(401, None),
+            ErrorKind::AuthFailed
+        );
+        assert_eq!(
+            ErrorKind::from_http_status(400, None),
+            ErrorKind::InvalidRequest
+        );
+        assert_eq!(
+            ErrorKind::from_http_status(502, None),
+            ErrorKind::UnknownExecutionStatus
+        );
+        assert_eq!(
+            ErrorKind::from_http_status(503, None),
+            ErrorKind::UnknownExecutionStatus
+        );
+        assert_eq!(
+            ErrorKind::from_http_status(500, None),
+            ErrorKind::UnknownExecutionStatus
+        );
+        assert_eq!(ErrorKind::from_http_status(302, None), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_retryable() {
+        assert!(ErrorKind::RateLimited { retry_after: None }.is_retryable());
+        assert!(ErrorKind::ExchangeUnavailable.is_retryable());
+        assert!(!ErrorKind::UnknownExecutionStatus.is_retryable());
+        assert!(!ErrorKind::InsufficientFunds.is_retryable());
+    }
+
+    #[test]
+    fn test_venue_error_display_keeps_code_and_message() {
+        let err = VenueError::new("binance", ErrorKind::OrderNotFound, "Order does not exist.").with_code(-2013);
+        assert_eq!(err.code.as_deref(), Some("-2013"));
+        assert_eq!(
+            err.to_string(),
+            "binance order not found (-2013): Order does not exist."
+        );
+    }
+
+    #[test]
+    fn test_rest_error_kind() {
+        let err: RestError = VenueError::new("okx", ErrorKind::AuthFailed, "Invalid sign").into();
+        assert_eq!(err.kind(), ErrorKind::AuthFailed);
+        assert_eq!(
+            RestError::RateLimitExceeded.kind(),
+            ErrorKind::RateLimited { retry_after: None }
+        );
+    }
 }
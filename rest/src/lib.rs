@@ -1,6 +1,10 @@
 // Minimal library file to satisfy Cargo
 
+pub mod clock;
 pub mod error;
+pub mod middleware;
 pub mod rate_limiter;
 pub mod request;
+pub mod retry;
 pub mod secrets;
+pub mod transport;
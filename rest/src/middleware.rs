@@ -0,0 +1,729 @@
+//! Request/response middleware for venue clients
+//!
+//! A [`Pipeline`] wraps an [`HttpTransport`] in a chain of [`Middleware`] and is
+//! itself a transport, so any venue client can be given one in place of a plain
+//! `reqwest::Client`. Middleware runs in the order it was added: the first one
+//! sees the request first and the response last.
+//!
+//! Built-in middleware covers tracing spans, latency metrics, request ids, static
+//! headers, body logging with secrets redacted, and retrying requests that never
+//! reached the venue.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tracing::Instrument;
+
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, TransportError};
+
+/// One step of a [`Pipeline`].
+///
+/// Implementations can change the request, call `next.run(request)` any number of
+/// times (or not at all), and inspect or change the response.
+#[async_trait]
+pub trait Middleware: fmt::Debug + Send + Sync {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError>;
+}
+
+/// The rest of the chain after the current middleware
+#[derive(Debug, Clone, Copy)]
+pub struct Next<'a> {
+    middleware: &'a [Arc<dyn Middleware>],
+    transport: &'a dyn HttpTransport,
+}
+
+impl Next<'_> {
+    /// Pass the request to the next middleware, or to the transport at the end of the chain
+    pub async fn run(self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        match self.middleware.split_first() {
+            Some((current, rest)) => {
+                current
+                    .handle(
+                        request,
+                        Next {
+                            middleware: rest,
+                            transport: self.transport,
+                        },
+                    )
+                    .await
+            }
+            None => self.transport.execute(request).await,
+        }
+    }
+}
+
+/// A transport with middleware in front of it
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    transport: Arc<dyn HttpTransport>,
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Pipeline {
+    pub fn new(transport: impl HttpTransport + 'static) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            middleware: Vec::new(),
+        }
+    }
+
+    /// Add a middleware after the ones already in the pipeline
+    pub fn with(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Add a middleware that is shared with other pipelines, e.g. to collect metrics
+    /// of several clients in one place
+    pub fn with_shared(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+}
+
+#[async_trait]
+impl HttpTransport for Pipeline {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        Next {
+            middleware: &self.middleware,
+            transport: self.transport.as_ref(),
+        }
+        .run(request)
+        .await
+    }
+}
+
+/// Replaces secrets in URLs, headers and bodies before they are logged.
+///
+/// A header or parameter is treated as secret when its name, ignoring case,
+/// contains one of the configured fragments. The defaults cover the API keys,
+/// signatures, passphrases and tokens used by the supported venues.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    fragments: Vec<String>,
+}
+
+const REDACTED: &str = "***";
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self {
+            fragments: [
+                "key",
+                "sig",
+                "secret",
+                "passphrase",
+                "password",
+                "token",
+                "authorization",
+            ]
+            .iter()
+            .map(|fragment| fragment.to_string())
+            .collect(),
+        }
+    }
+}
+
+impl Redactor {
+    /// Also treat names containing `fragment` as secret
+    pub fn with_fragment(mut self, fragment: impl Into<String>) -> Self {
+        self.fragments.push(fragment.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Whether a header or parameter called `name` holds a secret
+    pub fn is_secret(&self, name: &str) -> bool {
+        let name = name.to_ascii_lowercase();
+        self.fragments
+            .iter()
+            .any(|fragment| name.contains(fragment.as_str()))
+    }
+
+    /// The URL with secret query parameters replaced
+    pub fn url(&self, url: &str) -> String {
+        match url.split_once('?') {
+            Some((path, query)) => format!("{}?{}", path, self.form(query)),
+            None => url.to_string(),
+        }
+    }
+
+    /// Headers with secret values replaced
+    pub fn headers(&self, headers: &[(String, String)]) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.is_secret(name) {
+                    REDACTED.to_string()
+                } else {
+                    value.clone()
+                };
+                (name.clone(), value)
+            })
+            .collect()
+    }
+
+    /// A JSON or form-encoded body with secret fields replaced. Other bodies are
+    /// returned unchanged.
+    pub fn body(&self, body: &str) -> String {
+        if let Ok(mut value) = serde_json::from_str::<Value>(body) {
+            self.json(&mut value);
+            return value.to_string();
+        }
+        if body.contains('=') && !body.contains(char::is_whitespace) {
+            return self.form(body);
+        }
+        body.to_string()
+    }
+
+    fn json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (name, field) in map.iter_mut() {
+                    if self.is_secret(name) && !field.is_object() && !field.is_array() {
+                        *field = Value::String(REDACTED.to_string());
+                    } else {
+                        self.json(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.json(item)),
+            _ => {}
+        }
+    }
+
+    fn form(&self, form: &str) -> String {
+        form.split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((name, _)) if self.is_secret(name) => format!("{}={}", name, REDACTED),
+                _ => pair.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+/// Opens a tracing span per request and records the outcome.
+///
+/// The span is named `http_request` and carries the method, the URL without its
+/// query string and the `X-Request-Id` header if one was set earlier in the chain.
+#[derive(Debug, Clone, Default)]
+pub struct TracingMiddleware;
+
+#[async_trait]
+impl Middleware for TracingMiddleware {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError> {
+        let path = request
+            .url
+            .split_once('?')
+            .map_or(request.url.as_str(), |(path, _)| path);
+        let span = tracing::info_span!(
+            "http_request",
+            method = %request.method,
+            url = %path,
+            request_id = request.header_value(REQUEST_ID_HEADER).unwrap_or_default(),
+        );
+
+        async move {
+            let start = Instant::now();
+            let result = next.run(request).await;
+            let elapsed_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+            match &result {
+                Ok(response) => tracing::info!(status = response.status.as_u16(), elapsed_ms, "response"),
+                Err(err) => tracing::warn!(error = %err, elapsed_ms, "request failed"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+/// Logs every request and response at debug level with secrets redacted
+#[derive(Debug, Clone)]
+pub struct LoggingMiddleware {
+    redactor: Redactor,
+
+    /// Bodies longer than this many characters are truncated in the log
+    max_body_len: usize,
+}
+
+impl Default for LoggingMiddleware {
+    fn default() -> Self {
+        Self {
+            redactor: Redactor::default(),
+            max_body_len: 2048,
+        }
+    }
+}
+
+impl LoggingMiddleware {
+    pub fn new(redactor: Redactor) -> Self {
+        Self {
+            redactor,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_body_len(mut self, max_body_len: usize) -> Self {
+        self.max_body_len = max_body_len;
+        self
+    }
+
+    fn body(&self, body: &str) -> String {
+        let redacted = self.redactor.body(body);
+        match redacted.char_indices().nth(self.max_body_len) {
+            Some((end, _)) => format!("{}...", redacted.get(..end).unwrap_or_default()),
+            None => redacted,
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for LoggingMiddleware {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError> {
+        tracing::debug!(
+            method = %request.method,
+            url = %self.redactor.url(&request.url),
+            headers = ?self.redactor.headers(&request.headers),
+            body = %request.body.as_deref().map(|body| self.body(body)).unwrap_or_default(),
+            "sending request"
+        );
+        let result = next.run(request).await;
+        if let Ok(response) = &result {
+            tracing::debug!(
+                status = response.status.as_u16(),
+                body = %self.body(&response.body),
+                "received response"
+            );
+        }
+        result
+    }
+}
+
+/// Upper bounds of the latency histogram buckets, in milliseconds
+pub const LATENCY_BUCKETS_MS: [u64; 11] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// Request counts and latencies collected by a [`MetricsMiddleware`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RequestMetrics {
+    /// Requests that got a 2xx response
+    pub success: u64,
+    /// Requests that got a 4xx response
+    pub client_errors: u64,
+    /// Requests that got a 5xx response
+    pub server_errors: u64,
+    /// Requests that got no response
+    pub transport_errors: u64,
+    /// Requests per latency bucket. Entry `i` counts requests that took at most
+    /// `LATENCY_BUCKETS_MS[i]` milliseconds and more than the bucket before it;
+    /// the last entry counts the slower ones.
+    pub latency_buckets: Vec<u64>,
+    /// Sum of all latencies
+    pub total_latency: Duration,
+}
+
+impl RequestMetrics {
+    /// Number of requests recorded
+    pub fn requests(&self) -> u64 {
+        self.latency_buckets.iter().sum()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    success: AtomicU64,
+    client_errors: AtomicU64,
+    server_errors: AtomicU64,
+    transport_errors: AtomicU64,
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+    total_latency_us: AtomicU64,
+}
+
+/// Counts outcomes and builds a latency histogram. Clones share the same counters.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsMiddleware {
+    counters: Arc<Counters>,
+}
+
+impl MetricsMiddleware {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of the counters
+    pub fn metrics(&self) -> RequestMetrics {
+        let counters = &self.counters;
+        RequestMetrics {
+            success: counters.success.load(Ordering::Relaxed),
+            client_errors: counters.client_errors.load(Ordering::Relaxed),
+            server_errors: counters.server_errors.load(Ordering::Relaxed),
+            transport_errors: counters.transport_errors.load(Ordering::Relaxed),
+            latency_buckets: counters
+                .latency_buckets
+                .iter()
+                .map(|bucket| bucket.load(Ordering::Relaxed))
+                .collect(),
+            total_latency: Duration::from_micros(counters.total_latency_us.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn record(&self, result: &Result<HttpResponse, TransportError>, elapsed: Duration) {
+        let counters = &self.counters;
+        let outcome = match result {
+            Ok(response) if response.status.is_success() => &counters.success,
+            Ok(response) if response.status.is_server_error() => &counters.server_errors,
+            Ok(_) => &counters.client_errors,
+            Err(_) => &counters.transport_errors,
+        };
+        outcome.fetch_add(1, Ordering::Relaxed);
+
+        let elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|&bound| elapsed_ms <= bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        if let Some(bucket) = counters.latency_buckets.get(bucket) {
+            bucket.fetch_add(1, Ordering::Relaxed);
+        }
+        let elapsed_us = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        counters
+            .total_latency_us
+            .fetch_add(elapsed_us, Ordering::Relaxed);
+    }
+}
+
+#[async_trait]
+impl Middleware for MetricsMiddleware {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError> {
+        let start = Instant::now();
+        let result = next.run(request).await;
+        self.record(&result, start.elapsed());
+        result
+    }
+}
+
+/// Header set by [`RequestIdMiddleware`] and read by [`TracingMiddleware`]
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Gives every request a unique `X-Request-Id` header, unless it already has one.
+///
+/// Ids are a per-middleware prefix followed by a counter, e.g. `18c3f2a9b01-42`.
+#[derive(Debug)]
+pub struct RequestIdMiddleware {
+    prefix: String,
+    counter: AtomicU64,
+}
+
+impl Default for RequestIdMiddleware {
+    fn default() -> Self {
+        let started = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since_epoch| since_epoch.as_nanos())
+            .unwrap_or_default();
+        Self::with_prefix(format!("{:x}", started))
+    }
+}
+
+impl RequestIdMiddleware {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            counter: AtomicU64::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for RequestIdMiddleware {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError> {
+        let request = if request.header_value(REQUEST_ID_HEADER).is_some() {
+            request
+        } else {
+            let id = self.counter.fetch_add(1, Ordering::Relaxed);
+            request.header(REQUEST_ID_HEADER, format!("{}-{}", self.prefix, id))
+        };
+        next.run(request).await
+    }
+}
+
+/// Adds fixed headers to every request, e.g. a `User-Agent`. Headers the request
+/// already has are left alone.
+#[derive(Debug, Clone, Default)]
+pub struct HeadersMiddleware {
+    headers: Vec<(String, String)>,
+}
+
+impl HeadersMiddleware {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+#[async_trait]
+impl Middleware for HeadersMiddleware {
+    async fn handle(&self, mut request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError> {
+        for (name, value) in &self.headers {
+            if request.header_value(name).is_none() {
+                request = request.header(name.as_str(), value.as_str());
+            }
+        }
+        next.run(request).await
+    }
+}
+
+/// Retries requests that failed to connect.
+///
+/// A connection failure means the venue never saw the request, so retrying is
+/// safe for every method, including order placement. Anything else, including
+/// timeouts, is passed straight back to the caller.
+#[derive(Debug, Clone)]
+pub struct RetryMiddleware {
+    max_retries: u32,
+
+    /// Delay before the first retry, doubled for each one after it
+    base_delay: Duration,
+}
+
+impl RetryMiddleware {
+    pub fn new(max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError> {
+        let mut delay = self.base_delay;
+        for _ in 0..self.max_retries {
+            match next.run(request.clone()).await {
+                Err(TransportError::Connect(reason)) => {
+                    tracing::debug!(%reason, ?delay, "connection failed, retrying");
+                    tokio::time::sleep(delay).await;
+                    delay = delay.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+        next.run(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    use super::*;
+    use crate::transport::MockTransport;
+
+    /// Records the order in which middleware saw the request and the response
+    #[derive(Debug)]
+    struct Probe {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Middleware for Probe {
+        async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, TransportError> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} request", self.name));
+            let result = next.run(request).await;
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} response", self.name));
+            result
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_runs_middleware_in_order() {
+        let mock = MockTransport::new();
+        mock.push_json(StatusCode::OK, json!({}));
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let pipeline = Pipeline::new(mock.clone())
+            .with(Probe {
+                name: "outer",
+                log: log.clone(),
+            })
+            .with(Probe {
+                name: "inner",
+                log: log.clone(),
+            });
+
+        pipeline
+            .execute(HttpRequest::new(Method::GET, "https://example.com/time"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "outer request",
+                "inner request",
+                "inner response",
+                "outer response"
+            ]
+        );
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_id_and_headers() {
+        let mock = MockTransport::new();
+        mock.push_json(StatusCode::OK, json!({}))
+            .push_json(StatusCode::OK, json!({}));
+        let pipeline = Pipeline::new(mock.clone())
+            .with(RequestIdMiddleware::with_prefix("test"))
+            .with(
+                HeadersMiddleware::new()
+                    .header("User-Agent", "ccrxt")
+                    .header("X-Extra", "1"),
+            )
+            .with(TracingMiddleware);
+
+        pipeline
+            .execute(HttpRequest::new(Method::GET, "https://example.com/a"))
+            .await
+            .unwrap();
+        pipeline
+            .execute(HttpRequest::new(Method::GET, "https://example.com/b").header("User-Agent", "custom"))
+            .await
+            .unwrap();
+
+        let requests = mock.requests();
+        let first = requests.first().unwrap();
+        let second = requests.get(1).unwrap();
+        assert_eq!(first.header_value("x-request-id"), Some("test-0"));
+        assert_eq!(second.header_value("x-request-id"), Some("test-1"));
+        assert_eq!(first.header_value("user-agent"), Some("ccrxt"));
+        assert_eq!(second.header_value("user-agent"), Some("custom"));
+        assert_eq!(second.header_value("x-extra"), Some("1"));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_outcomes() {
+        let mock = MockTransport::new();
+        mock.push_json(StatusCode::OK, json!({}))
+            .push_json(StatusCode::BAD_REQUEST, json!({}))
+            .push_json(StatusCode::SERVICE_UNAVAILABLE, json!({}))
+            .push_error(TransportError::Timeout("slow".to_string()));
+        let metrics = MetricsMiddleware::new();
+        let pipeline = Pipeline::new(mock).with(metrics.clone());
+
+        for _ in 0..4 {
+            let _ = pipeline
+                .execute(HttpRequest::new(Method::GET, "https://example.com/time"))
+                .await;
+        }
+
+        let snapshot = metrics.metrics();
+        assert_eq!(snapshot.success, 1);
+        assert_eq!(snapshot.client_errors, 1);
+        assert_eq!(snapshot.server_errors, 1);
+        assert_eq!(snapshot.transport_errors, 1);
+        assert_eq!(snapshot.requests(), 4);
+        assert_eq!(snapshot.latency_buckets.len(), LATENCY_BUCKETS_MS.len() + 1);
+        assert_eq!(snapshot.latency_buckets.first(), Some(&4));
+    }
+
+    #[tokio::test]
+    async fn test_retry_only_on_connect_failures() {
+        let mock = MockTransport::new();
+        mock.push_error(TransportError::Connect("refused".to_string()))
+            .push_json(StatusCode::OK, json!({ "ok": true }))
+            .push_error(TransportError::Timeout("slow".to_string()));
+        let pipeline = Pipeline::new(mock.clone()).with(RetryMiddleware::new(2, Duration::ZERO));
+
+        let response = pipeline
+            .execute(HttpRequest::new(Method::POST, "https://example.com/order"))
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(mock.requests().len(), 2);
+
+        let err = pipeline
+            .execute(HttpRequest::new(Method::POST, "https://example.com/order"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TransportError::Timeout(_)));
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[test]
+    fn test_redactor() {
+        let redactor = Redactor::default();
+
+        assert_eq!(
+            redactor.url("https://api.binance.com/api/v3/order?symbol=BTCUSDT&timestamp=1&signature=abc"),
+            "https://api.binance.com/api/v3/order?symbol=BTCUSDT&timestamp=1&signature=***"
+        );
+        assert_eq!(
+            redactor.headers(&[
+                ("X-MBX-APIKEY".to_string(), "key".to_string()),
+                ("OK-ACCESS-PASSPHRASE".to_string(), "pass".to_string()),
+                ("Authorization".to_string(), "Bearer jwt".to_string()),
+                ("Content-Type".to_string(), "application/json".to_string()),
+            ]),
+            [
+                ("X-MBX-APIKEY".to_string(), "***".to_string()),
+                ("OK-ACCESS-PASSPHRASE".to_string(), "***".to_string()),
+                ("Authorization".to_string(), "***".to_string()),
+                ("Content-Type".to_string(), "application/json".to_string()),
+            ]
+        );
+
+        let body: Value =
+            serde_json::from_str(&redactor.body(r#"{"method":"private/get_account","api_key":"k","sig":"s","params":{"currency":"BTC","token":"t"}}"#))
+                .unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "method": "private/get_account",
+                "api_key": "***",
+                "sig": "***",
+                "params": { "currency": "BTC", "token": "***" },
+            })
+        );
+
+        assert_eq!(
+            redactor.body("symbol=BTCUSDT&signature=abc"),
+            "symbol=BTCUSDT&signature=***"
+        );
+        assert_eq!(redactor.body("plain text"), "plain text");
+        assert_eq!(
+            Redactor::default()
+                .with_fragment("Nonce")
+                .url("https://x/y?nonce=1"),
+            "https://x/y?nonce=***"
+        );
+    }
+
+    #[test]
+    fn test_logging_truncates_long_bodies() {
+        let logging = LoggingMiddleware::default().with_max_body_len(4);
+        assert_eq!(logging.body("abcdefgh"), "abcd...");
+        assert_eq!(logging.body("abc"), "abc");
+    }
+}
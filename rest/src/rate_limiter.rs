@@ -4,23 +4,35 @@ use async_trait::async_trait;
 
 use crate::error::RestError;
 
-/// Common trait for rate limiters
+/// Common trait for rate limiters.
+///
+/// Venues describe the cost of a request differently: most group endpoints into
+/// classes with their own limits, Binance charges a weight per request, Deribit
+/// charges credits. `Key` is whatever the venue needs to find the limit a request
+/// counts against, so generic code can drive any venue's limiter.
 #[async_trait]
 pub trait RateLimiter: Send + Sync {
-    /// Check if the request can be made based on rate limits
-    async fn check_rate_limit(&self, endpoint: &str, weight: u32) -> Result<(), RestError>;
+    /// What a request is charged against (an endpoint class, a weight, ...)
+    type Key: Send + Sync;
 
-    /// Get the current rate limit status
-    fn get_rate_limit_status(&self) -> RateLimitStatus;
+    /// Check whether a request to `endpoint` can be made now without exceeding the
+    /// venue's limits
+    async fn check_limit(&self, endpoint: &str, key: &Self::Key) -> Result<(), RestError>;
+
+    /// Record that a request to `endpoint` has been sent
+    async fn record_request(&self, endpoint: &str, key: &Self::Key);
+
+    /// Get the current status of the limit that applies to `key`
+    async fn get_rate_limit_status(&self, key: &Self::Key) -> RateLimitStatus;
 }
 
 /// Status of rate limits
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RateLimitStatus {
-    /// Number of requests remaining in the current window
+    /// Number of requests (or weight, or credits) remaining in the current window
     pub remaining: u32,
 
-    /// Total number of requests allowed in the window
+    /// Total number of requests (or weight, or credits) allowed in the window
     pub limit: u32,
 
     /// Time until the rate limit window resets
@@ -1,60 +1,238 @@
 use std::time::Duration;
 
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
 
-use crate::error::RestError;
+use crate::error::{ErrorKind, RestError, VenueError};
 use crate::rate_limiter::RateLimiter;
 
-/// Common trait for venue-specific HTTP headers
-pub trait VenueHeaders: Send + Sync + std::fmt::Debug {}
+/// Key used by a client's rate limiter
+pub type RateLimitKey<C> = <<C as RestClient>::RateLimiter as RateLimiter>::Key;
 
-/// Common trait for venue-specific error responses
-pub trait VenueErrorResponse: Send + Sync + std::fmt::Debug {
-    /// Get the error code from the response
-    fn code(&self) -> i32;
+/// A venue-agnostic REST request
+#[derive(Debug, Clone)]
+pub struct RestRequest<K> {
+    /// HTTP method
+    pub method: reqwest::Method,
+
+    /// Path relative to the client's base URL, e.g. `/api/v5/market/ticker`. For
+    /// JSON-RPC venues this is the method name, e.g. `public/get_time`.
+    pub endpoint: String,
+
+    /// Request parameters, sent as the query string or body as the venue expects
+    pub params: Option<serde_json::Value>,
 
-    /// Get the error message from the response
-    fn message(&self) -> String;
+    /// What the request is charged against in the venue's rate limiter
+    pub rate_limit_key: K,
 }
 
-/// Common trait for venue-specific REST responses
+impl<K> RestRequest<K> {
+    pub fn new(method: reqwest::Method, endpoint: impl Into<String>, rate_limit_key: K) -> Self {
+        Self {
+            method,
+            endpoint: endpoint.into(),
+            params: None,
+            rate_limit_key,
+        }
+    }
+
+    /// Set the request parameters
+    pub fn with_params(mut self, params: serde_json::Value) -> Self {
+        self.params = Some(params);
+        self
+    }
+}
+
+/// A successful REST response
 #[derive(Debug, Clone)]
-pub struct RestResponse<T, H, E>
-where
-    T: Send + Sync + std::fmt::Debug,
-    H: VenueHeaders,
-    E: VenueErrorResponse,
-{
+pub struct RestResponse<T> {
     /// The actual data payload from the response
     pub data: T,
 
-    /// Time spent waiting on rate limiting
-    pub rate_limit_duration: Duration,
-
-    /// Total time spent on the request
+    /// Total time spent on the request, including waiting for the rate limiter
     pub request_duration: Duration,
+}
 
-    /// Venue-specific HTTP headers
-    pub headers: H,
-
-    /// Venue-specific error response if the request failed
-    pub error: Option<E>,
+impl<T> RestResponse<T> {
+    pub fn new(data: T, request_duration: Duration) -> Self {
+        Self {
+            data,
+            request_duration,
+        }
+    }
 }
 
-/// Common trait for venue-specific REST clients
+/// Common trait for venue-specific REST clients.
+///
+/// Every venue client implements this on top of its own request method, so
+/// middleware, metrics and test tooling can be written once against the trait.
+/// Implementations check and record rate limits and sign the request as the venue
+/// requires; errors are reported as [`RestError::Venue`].
 #[async_trait]
-pub trait RestClient<T, H, E>
-where
-    T: Send + Sync + std::fmt::Debug,
-    H: VenueHeaders,
-    E: VenueErrorResponse,
-{
-    /// Make a REST request and return a RestResponse
-    async fn request(&self, endpoint: &str, method: reqwest::Method, query: Option<&str>) -> Result<RestResponse<T, H, E>, RestError>;
+pub trait RestClient: Send + Sync {
+    /// The rate limiter used by the client
+    type RateLimiter: RateLimiter;
+
+    /// Name of the venue, as used in [`VenueError::venue`]
+    fn venue(&self) -> &'static str;
 
     /// Get the base URL for the REST API
     fn base_url(&self) -> &str;
 
     /// Get the rate limiter for the client
-    fn rate_limiter(&self) -> &dyn RateLimiter;
+    fn rate_limiter(&self) -> &Self::RateLimiter;
+
+    /// Make a REST request and return the response body as JSON
+    async fn send(&self, request: RestRequest<RateLimitKey<Self>>) -> Result<RestResponse<serde_json::Value>, RestError>;
+
+    /// Make a REST request and deserialize the response body
+    async fn request<T>(&self, request: RestRequest<RateLimitKey<Self>>) -> Result<RestResponse<T>, RestError>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let response = self.send(request).await?;
+        let data = serde_json::from_value(response.data).map_err(|err| {
+            VenueError::new(
+                self.venue(),
+                ErrorKind::Other,
+                format!("Failed to deserialize response: {}", err),
+            )
+        })?;
+        Ok(RestResponse::new(data, response.request_duration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::rate_limiter::RateLimitStatus;
+
+    /// Allows a fixed number of requests
+    struct CountingLimiter {
+        limit: u32,
+        sent: AtomicU32,
+    }
+
+    #[async_trait]
+    impl RateLimiter for CountingLimiter {
+        type Key = u32;
+
+        async fn check_limit(&self, _endpoint: &str, weight: &u32) -> Result<(), RestError> {
+            if self.sent.load(Ordering::SeqCst).saturating_add(*weight) > self.limit {
+                return Err(VenueError::new(
+                    "test",
+                    ErrorKind::RateLimited { retry_after: None },
+                    "limit reached",
+                )
+                .into());
+            }
+            Ok(())
+        }
+
+        async fn record_request(&self, _endpoint: &str, weight: &u32) {
+            self.sent.fetch_add(*weight, Ordering::SeqCst);
+        }
+
+        async fn get_rate_limit_status(&self, _weight: &u32) -> RateLimitStatus {
+            let sent = self.sent.load(Ordering::SeqCst);
+            RateLimitStatus {
+                remaining: self.limit.saturating_sub(sent),
+                limit: self.limit,
+                reset_in: Duration::from_secs(60),
+            }
+        }
+    }
+
+    /// Echoes the request back
+    struct EchoClient {
+        limiter: CountingLimiter,
+    }
+
+    #[async_trait]
+    impl RestClient for EchoClient {
+        type RateLimiter = CountingLimiter;
+
+        fn venue(&self) -> &'static str {
+            "test"
+        }
+
+        fn base_url(&self) -> &str {
+            "https://example.com"
+        }
+
+        fn rate_limiter(&self) -> &CountingLimiter {
+            &self.limiter
+        }
+
+        async fn send(&self, request: RestRequest<u32>) -> Result<RestResponse<serde_json::Value>, RestError> {
+            self.limiter
+                .check_limit(&request.endpoint, &request.rate_limit_key)
+                .await?;
+            self.limiter
+                .record_request(&request.endpoint, &request.rate_limit_key)
+                .await;
+            Ok(RestResponse::new(
+                json!({ "endpoint": request.endpoint, "params": request.params }),
+                Duration::ZERO,
+            ))
+        }
+    }
+
+    /// Generic code written only against the traits
+    async fn remaining<C: RestClient>(client: &C, key: &RateLimitKey<C>) -> u32 {
+        client
+            .rate_limiter()
+            .get_rate_limit_status(key)
+            .await
+            .remaining
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Echo {
+        endpoint: String,
+        params: serde_json::Value,
+    }
+
+    #[tokio::test]
+    async fn test_request_deserializes_response() {
+        let client = EchoClient {
+            limiter: CountingLimiter {
+                limit: 10,
+                sent: AtomicU32::new(0),
+            },
+        };
+
+        let request = RestRequest::new(reqwest::Method::GET, "/time", 4).with_params(json!({ "a": 1 }));
+        let response = client.request::<Echo>(request).await.unwrap();
+        assert_eq!(response.data.endpoint, "/time");
+        assert_eq!(response.data.params, json!({ "a": 1 }));
+        assert_eq!(remaining(&client, &0).await, 6);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_errors_are_classified() {
+        let client = EchoClient {
+            limiter: CountingLimiter {
+                limit: 1,
+                sent: AtomicU32::new(0),
+            },
+        };
+
+        let err = client
+            .send(RestRequest::new(reqwest::Method::GET, "/time", 2))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited { retry_after: None });
+
+        let err = client
+            .request::<u64>(RestRequest::new(reqwest::Method::GET, "/time", 1))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Other);
+    }
 }
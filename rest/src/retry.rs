@@ -0,0 +1,747 @@
+//! Idempotency-aware retries for venue clients
+//!
+//! [`RetryingClient`] wraps any [`RestClient`] and repeats failed requests when
+//! that cannot cause a second execution:
+//!
+//! - Rate limited and banned requests (429 and 418) were rejected before they
+//!   were executed, so they are retried whatever their method, honouring the
+//!   venue's `retry-after`.
+//! - Idempotent requests (GET, HEAD, OPTIONS) are also retried when the venue is
+//!   unavailable and on unknown outcomes.
+//! - Non-idempotent requests that failed as unavailable or with an unknown
+//!   outcome are never repeated blindly. If an [`OrderReconciler`] finds a client
+//!   order id in the request, the order is looked up by that id, up to
+//!   [`RetryPolicy::lookup_attempts`] times. If it exists, the payload of the
+//!   order query is returned in place of the placement response. If it is still
+//!   not found the venue may yet be processing it, so an
+//!   [`ErrorKind::UnknownExecutionStatus`] error carrying the client order id is
+//!   returned, unless the caller opted into [`RetryPolicy::resend_unplaced_orders`].
+//!   Without a reconciler the error is returned to the caller.
+
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde_json::Value;
+
+use crate::error::{ErrorKind, RestError, VenueError};
+use crate::request::{RateLimitKey, RestClient, RestRequest, RestResponse};
+
+/// When and how long to wait before retrying
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt. Order lookups are not counted.
+    pub max_retries: u32,
+
+    /// Delay before the first retry when the venue did not ask for one, doubled for
+    /// each retry after it
+    pub base_delay: Duration,
+
+    /// Cap on the doubled delay
+    pub max_delay: Duration,
+
+    /// Longest `retry-after` or ban the client will wait out. Longer ones are
+    /// returned to the caller.
+    pub max_retry_after: Duration,
+
+    /// Times an order with an unknown outcome is looked up before giving up on
+    /// finding it
+    pub lookup_attempts: u32,
+
+    /// Delay between lookups of an order that was not found
+    pub lookup_delay: Duration,
+
+    /// Send an order again when every lookup reports it does not exist. Off by
+    /// default: a venue still processing the order after a 5xx or timeout
+    /// reports it as not found, and resending it can fill it twice.
+    pub resend_unplaced_orders: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            max_retry_after: Duration::from_secs(30),
+            lookup_attempts: 3,
+            lookup_delay: Duration::from_secs(1),
+            resend_unplaced_orders: false,
+        }
+    }
+}
+
+/// What to do after a failed attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// Send the same request again after the delay
+    Retry(Duration),
+
+    /// The request may have been executed: look it up before doing anything else
+    Reconcile,
+
+    /// Return the error to the caller
+    Fail,
+}
+
+impl RetryPolicy {
+    /// Whether sending the request twice has the same effect as sending it once
+    pub fn is_idempotent(method: &Method) -> bool {
+        matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+    }
+
+    /// Decide what to do after attempt number `attempt` (0 for the first) of a
+    /// `method` request failed with `kind`
+    pub fn decide(&self, method: &Method, kind: &ErrorKind, attempt: u32) -> RetryDecision {
+        let idempotent = Self::is_idempotent(method);
+        if attempt >= self.max_retries {
+            return if !idempotent
+                && matches!(
+                    kind,
+                    ErrorKind::ExchangeUnavailable | ErrorKind::UnknownExecutionStatus
+                ) {
+                RetryDecision::Reconcile
+            } else {
+                RetryDecision::Fail
+            };
+        }
+
+        match kind {
+            // Rejected before execution, so safe to send again whatever the method
+            ErrorKind::RateLimited { .. } | ErrorKind::IpBanned { .. } => {
+                let wait = match (kind, kind.retry_after()) {
+                    (_, Some(wait)) => wait,
+                    (ErrorKind::RateLimited { .. }, None) => self.backoff(attempt),
+                    // A ban of unknown length is not worth waiting for
+                    _ => return RetryDecision::Fail,
+                };
+                if wait <= self.max_retry_after {
+                    RetryDecision::Retry(wait)
+                } else {
+                    RetryDecision::Fail
+                }
+            }
+            ErrorKind::ExchangeUnavailable | ErrorKind::UnknownExecutionStatus if idempotent => RetryDecision::Retry(self.backoff(attempt)),
+            // Even a venue that reports itself unavailable may have accepted the order
+            ErrorKind::ExchangeUnavailable | ErrorKind::UnknownExecutionStatus => RetryDecision::Reconcile,
+            _ => RetryDecision::Fail,
+        }
+    }
+
+    /// Exponential backoff for retry number `attempt`
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.checked_pow(attempt).unwrap_or(u32::MAX);
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+/// Finds the order a request places and builds the request that looks it up.
+///
+/// Venues implement this for their order placement endpoints; most can use
+/// [`ClientOrderIdLookup`].
+pub trait OrderReconciler<K>: Send + Sync {
+    /// The client order id `request` places an order with, if it places one
+    fn client_order_id(&self, request: &RestRequest<K>) -> Option<String>;
+
+    /// A request that returns the order with `client_order_id`, or fails with
+    /// [`ErrorKind::OrderNotFound`] if there is none
+    fn status_request(&self, request: &RestRequest<K>, client_order_id: &str) -> RestRequest<K>;
+}
+
+/// Reconciles an order placement endpoint that takes the client order id as a
+/// parameter and has a matching query endpoint.
+///
+/// For Binance `POST /api/v3/order` with `newClientOrderId` the lookup is
+/// `GET /api/v3/order` with `origClientOrderId`, copying `symbol`.
+#[derive(Debug, Clone)]
+pub struct ClientOrderIdLookup<K> {
+    /// Endpoint that places orders
+    pub place_endpoint: String,
+
+    /// Parameter of the placement request holding the client order id
+    pub id_param: String,
+
+    /// Endpoint and method that query an order
+    pub query_method: Method,
+    pub query_endpoint: String,
+
+    /// Parameter of the query request that takes the client order id
+    pub query_id_param: String,
+
+    /// Parameters copied from the placement request to the query, e.g. the symbol
+    pub copied_params: Vec<String>,
+
+    /// Rate limit key of the query request
+    pub query_rate_limit_key: K,
+}
+
+impl<K: Clone + Send + Sync> OrderReconciler<K> for ClientOrderIdLookup<K> {
+    fn client_order_id(&self, request: &RestRequest<K>) -> Option<String> {
+        if request.endpoint != self.place_endpoint {
+            return None;
+        }
+        match request.params.as_ref()?.get(&self.id_param)? {
+            Value::String(id) => Some(id.clone()),
+            Value::Number(id) => Some(id.to_string()),
+            _ => None,
+        }
+    }
+
+    fn status_request(&self, request: &RestRequest<K>, client_order_id: &str) -> RestRequest<K> {
+        let mut params = serde_json::Map::new();
+        if let Some(Value::Object(placed)) = &request.params {
+            for name in &self.copied_params {
+                if let Some(value) = placed.get(name) {
+                    params.insert(name.clone(), value.clone());
+                }
+            }
+        }
+        params.insert(
+            self.query_id_param.clone(),
+            Value::String(client_order_id.to_string()),
+        );
+        RestRequest::new(
+            self.query_method.clone(),
+            self.query_endpoint.clone(),
+            self.query_rate_limit_key.clone(),
+        )
+        .with_params(Value::Object(params))
+    }
+}
+
+/// Outcome of looking up an order whose placement had an unknown outcome
+enum Lookup {
+    /// The order exists. Holds the response of the order query.
+    Found(RestResponse<Value>),
+
+    /// Every lookup reported that the order does not exist
+    NotFound { client_order_id: String },
+}
+
+/// A [`RestClient`] that retries failed requests according to a [`RetryPolicy`]
+/// and resolves unknown order outcomes with an [`OrderReconciler`].
+///
+/// When an order is resolved by looking it up, [`RestClient::send`] returns the
+/// payload of the order query (e.g. Binance `GET /api/v3/order`) in place of the
+/// placement response, so callers should parse it as an order status.
+pub struct RetryingClient<C: RestClient> {
+    inner: C,
+    policy: RetryPolicy,
+    reconciler: Option<Box<dyn OrderReconciler<RateLimitKey<C>>>>,
+}
+
+impl<C: RestClient> RetryingClient<C> {
+    pub fn new(inner: C, policy: RetryPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            reconciler: None,
+        }
+    }
+
+    /// Look up orders with an unknown outcome using `reconciler`
+    pub fn with_reconciler(mut self, reconciler: impl OrderReconciler<RateLimitKey<C>> + 'static) -> Self {
+        self.reconciler = Some(Box::new(reconciler));
+        self
+    }
+
+    /// The wrapped client
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Look up the order placed by `request`, polling while the venue says it does
+    /// not exist. `None` if the request cannot be reconciled.
+    async fn reconcile(&self, request: &RestRequest<RateLimitKey<C>>) -> Option<Result<Lookup, RestError>> {
+        let reconciler = self.reconciler.as_ref()?;
+        let client_order_id = reconciler.client_order_id(request)?;
+        let mut lookups = 0_u32;
+        loop {
+            tracing::info!(
+                venue = self.inner.venue(),
+                %client_order_id,
+                lookups,
+                "outcome of order request unknown, looking it up"
+            );
+            let lookup = reconciler.status_request(request, &client_order_id);
+            match self.inner.send(lookup).await {
+                Ok(response) => return Some(Ok(Lookup::Found(response))),
+                Err(err) if err.kind() == ErrorKind::OrderNotFound => {}
+                Err(err) => return Some(Err(err)),
+            }
+            lookups = lookups.saturating_add(1);
+            if lookups >= self.policy.lookup_attempts {
+                return Some(Ok(Lookup::NotFound { client_order_id }));
+            }
+            tokio::time::sleep(self.policy.lookup_delay).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<C> RestClient for RetryingClient<C>
+where
+    C: RestClient,
+    RateLimitKey<C>: Clone,
+{
+    type RateLimiter = C::RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        self.inner.venue()
+    }
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    fn rate_limiter(&self) -> &Self::RateLimiter {
+        self.inner.rate_limiter()
+    }
+
+    async fn send(&self, request: RestRequest<RateLimitKey<Self>>) -> Result<RestResponse<Value>, RestError> {
+        let start = Instant::now();
+        let mut attempt = 0_u32;
+        loop {
+            let err = match self.inner.send(request.clone()).await {
+                Ok(response) => return Ok(RestResponse::new(response.data, start.elapsed())),
+                Err(err) => err,
+            };
+
+            let delay = match self.policy.decide(&request.method, &err.kind(), attempt) {
+                RetryDecision::Retry(delay) => delay,
+                RetryDecision::Fail => return Err(err),
+                RetryDecision::Reconcile => match self.reconcile(&request).await {
+                    Some(Ok(Lookup::Found(status))) => return Ok(RestResponse::new(status.data, start.elapsed())),
+                    // Only the caller can vouch that the venue has stopped processing the order
+                    Some(Ok(Lookup::NotFound { .. })) if self.policy.resend_unplaced_orders && attempt < self.policy.max_retries => {
+                        self.policy.backoff(attempt)
+                    }
+                    Some(Ok(Lookup::NotFound { client_order_id })) => {
+                        let message = format!(
+                            "order {client_order_id} not found after {} lookups: {err}",
+                            self.policy.lookup_attempts
+                        );
+                        return Err(VenueError::new(
+                            self.inner.venue(),
+                            ErrorKind::UnknownExecutionStatus,
+                            message,
+                        )
+                        .with_client_order_id(client_order_id)
+                        .into());
+                    }
+                    _ => return Err(err),
+                },
+            };
+
+            tracing::debug!(
+                venue = self.inner.venue(),
+                endpoint = %request.endpoint,
+                attempt,
+                ?delay,
+                error = %err,
+                "retrying request"
+            );
+            tokio::time::sleep(delay).await;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::rate_limiter::{RateLimitStatus, RateLimiter};
+
+    struct NoLimits;
+
+    #[async_trait]
+    impl RateLimiter for NoLimits {
+        type Key = ();
+
+        async fn check_limit(&self, _endpoint: &str, _key: &()) -> Result<(), RestError> {
+            Ok(())
+        }
+
+        async fn record_request(&self, _endpoint: &str, _key: &()) {}
+
+        async fn get_rate_limit_status(&self, _key: &()) -> RateLimitStatus {
+            RateLimitStatus {
+                remaining: 1,
+                limit: 1,
+                reset_in: Duration::ZERO,
+            }
+        }
+    }
+
+    /// Replays scripted outcomes and records what was sent
+    struct ScriptedClient {
+        limiter: NoLimits,
+        outcomes: Mutex<VecDeque<Result<Value, ErrorKind>>>,
+        sent: Mutex<Vec<(Method, String, Option<Value>)>>,
+    }
+
+    impl ScriptedClient {
+        fn new(outcomes: Vec<Result<Value, ErrorKind>>) -> Self {
+            Self {
+                limiter: NoLimits,
+                outcomes: Mutex::new(outcomes.into()),
+                sent: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl RestClient for ScriptedClient {
+        type RateLimiter = NoLimits;
+
+        fn venue(&self) -> &'static str {
+            "test"
+        }
+
+        fn base_url(&self) -> &str {
+            "https://example.com"
+        }
+
+        fn rate_limiter(&self) -> &NoLimits {
+            &self.limiter
+        }
+
+        async fn send(&self, request: RestRequest<()>) -> Result<RestResponse<Value>, RestError> {
+            self.sent
+                .lock()
+                .unwrap()
+                .push((request.method, request.endpoint, request.params));
+            match self.outcomes.lock().unwrap().pop_front() {
+                Some(Ok(data)) => Ok(RestResponse::new(data, Duration::ZERO)),
+                Some(Err(kind)) => Err(VenueError::new("test", kind, "scripted").into()),
+                None => Err(VenueError::new("test", ErrorKind::Other, "script exhausted").into()),
+            }
+        }
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            max_retry_after: Duration::from_millis(50),
+            lookup_attempts: 2,
+            lookup_delay: Duration::ZERO,
+            resend_unplaced_orders: false,
+        }
+    }
+
+    fn lookup() -> ClientOrderIdLookup<()> {
+        ClientOrderIdLookup {
+            place_endpoint: "/order".to_string(),
+            id_param: "newClientOrderId".to_string(),
+            query_method: Method::GET,
+            query_endpoint: "/order".to_string(),
+            query_id_param: "origClientOrderId".to_string(),
+            copied_params: vec!["symbol".to_string()],
+            query_rate_limit_key: (),
+        }
+    }
+
+    fn place_order() -> RestRequest<()> {
+        RestRequest::new(Method::POST, "/order", ()).with_params(json!({
+            "symbol": "BTCUSDT",
+            "side": "BUY",
+            "newClientOrderId": "abc-1",
+        }))
+    }
+
+    #[test]
+    fn test_decide() {
+        let policy = RetryPolicy {
+            max_retry_after: Duration::from_secs(10),
+            ..RetryPolicy::default()
+        };
+        let rate_limited = ErrorKind::RateLimited {
+            retry_after: Some(Duration::from_secs(3)),
+        };
+
+        assert_eq!(
+            policy.decide(&Method::GET, &rate_limited, 0),
+            RetryDecision::Retry(Duration::from_secs(3))
+        );
+        assert_eq!(
+            policy.decide(&Method::POST, &rate_limited, 0),
+            RetryDecision::Retry(Duration::from_secs(3))
+        );
+        assert_eq!(
+            policy.decide(
+                &Method::POST,
+                &ErrorKind::RateLimited { retry_after: None },
+                1
+            ),
+            RetryDecision::Retry(Duration::from_millis(400))
+        );
+        assert_eq!(
+            policy.decide(
+                &Method::GET,
+                &ErrorKind::RateLimited {
+                    retry_after: Some(Duration::from_secs(60)),
+                },
+                0
+            ),
+            RetryDecision::Fail
+        );
+        assert_eq!(
+            policy.decide(&Method::GET, &ErrorKind::IpBanned { until: None }, 0),
+            RetryDecision::Fail
+        );
+        assert_eq!(
+            policy.decide(&Method::GET, &ErrorKind::ExchangeUnavailable, 1),
+            RetryDecision::Retry(Duration::from_millis(400))
+        );
+        assert_eq!(
+            policy.decide(&Method::POST, &ErrorKind::ExchangeUnavailable, 1),
+            RetryDecision::Reconcile
+        );
+        assert_eq!(
+            policy.decide(&Method::POST, &ErrorKind::ExchangeUnavailable, 3),
+            RetryDecision::Reconcile
+        );
+        assert_eq!(
+            policy.decide(&Method::GET, &ErrorKind::UnknownExecutionStatus, 0),
+            RetryDecision::Retry(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.decide(&Method::POST, &ErrorKind::UnknownExecutionStatus, 0),
+            RetryDecision::Reconcile
+        );
+        assert_eq!(
+            policy.decide(&Method::POST, &ErrorKind::UnknownExecutionStatus, 3),
+            RetryDecision::Reconcile
+        );
+        assert_eq!(
+            policy.decide(&Method::GET, &ErrorKind::ExchangeUnavailable, 3),
+            RetryDecision::Fail
+        );
+        assert_eq!(
+            policy.decide(&Method::GET, &ErrorKind::InvalidRequest, 0),
+            RetryDecision::Fail
+        );
+        assert_eq!(policy.backoff(10), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_get_is_retried_on_transient_errors() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::RateLimited {
+                    retry_after: Some(Duration::from_millis(1)),
+                }),
+                Err(ErrorKind::UnknownExecutionStatus),
+                Ok(json!({ "serverTime": 1 })),
+            ]),
+            policy(),
+        );
+
+        let response = client
+            .send(RestRequest::new(Method::GET, "/time", ()))
+            .await
+            .unwrap();
+        assert_eq!(response.data, json!({ "serverTime": 1 }));
+        assert_eq!(client.inner().sent.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_post_without_reconciler_is_not_retried() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![Err(ErrorKind::UnknownExecutionStatus)]),
+            policy(),
+        );
+
+        let err = client.send(place_order()).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnknownExecutionStatus);
+        assert_eq!(client.inner().sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_post_is_not_retried_when_exchange_unavailable() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::ExchangeUnavailable),
+                Ok(json!({ "clientOrderId": "abc-1", "status": "NEW" })),
+            ]),
+            policy(),
+        );
+
+        let err = client.send(place_order()).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ExchangeUnavailable);
+        assert_eq!(client.inner().sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_order_request_is_looked_up() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::ExchangeUnavailable),
+                Ok(json!({ "clientOrderId": "abc-1", "status": "NEW" })),
+            ]),
+            policy(),
+        )
+        .with_reconciler(lookup());
+
+        client.send(place_order()).await.unwrap();
+
+        let sent = client.inner().sent.lock().unwrap();
+        let methods: Vec<_> = sent.iter().map(|(method, _, _)| method.clone()).collect();
+        assert_eq!(methods, [Method::POST, Method::GET]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_order_outcome_is_resolved_by_client_order_id() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::UnknownExecutionStatus),
+                Ok(json!({ "clientOrderId": "abc-1", "status": "NEW" })),
+            ]),
+            policy(),
+        )
+        .with_reconciler(lookup());
+
+        let response = client.send(place_order()).await.unwrap();
+        assert_eq!(
+            response.data,
+            json!({ "clientOrderId": "abc-1", "status": "NEW" })
+        );
+
+        let sent = client.inner().sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        let (method, endpoint, params) = sent.get(1).unwrap();
+        assert_eq!(*method, Method::GET);
+        assert_eq!(endpoint, "/order");
+        assert_eq!(
+            *params,
+            Some(json!({ "symbol": "BTCUSDT", "origClientOrderId": "abc-1" }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_order_still_being_processed_is_found_by_a_later_lookup() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::UnknownExecutionStatus),
+                Err(ErrorKind::OrderNotFound),
+                Ok(json!({ "clientOrderId": "abc-1", "status": "NEW" })),
+            ]),
+            policy(),
+        )
+        .with_reconciler(lookup());
+
+        let response = client.send(place_order()).await.unwrap();
+        assert_eq!(
+            response.data,
+            json!({ "clientOrderId": "abc-1", "status": "NEW" })
+        );
+
+        let sent = client.inner().sent.lock().unwrap();
+        let methods: Vec<_> = sent.iter().map(|(method, _, _)| method.clone()).collect();
+        assert_eq!(methods, [Method::POST, Method::GET, Method::GET]);
+    }
+
+    #[tokio::test]
+    async fn test_order_that_is_never_found_is_not_sent_again() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::UnknownExecutionStatus),
+                Err(ErrorKind::OrderNotFound),
+                Err(ErrorKind::OrderNotFound),
+                Ok(json!({ "clientOrderId": "abc-1", "status": "NEW" })),
+            ]),
+            policy(),
+        )
+        .with_reconciler(lookup());
+
+        let err = client.send(place_order()).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnknownExecutionStatus);
+        assert!(matches!(
+            &err,
+            RestError::Venue(VenueError { client_order_id: Some(id), .. }) if id == "abc-1"
+        ));
+
+        let sent = client.inner().sent.lock().unwrap();
+        let methods: Vec<_> = sent.iter().map(|(method, _, _)| method.clone()).collect();
+        assert_eq!(methods, [Method::POST, Method::GET, Method::GET]);
+    }
+
+    #[tokio::test]
+    async fn test_order_that_is_never_found_is_sent_again_when_opted_in() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::UnknownExecutionStatus),
+                Err(ErrorKind::OrderNotFound),
+                Err(ErrorKind::OrderNotFound),
+                Ok(json!({ "clientOrderId": "abc-1", "status": "NEW" })),
+            ]),
+            RetryPolicy {
+                resend_unplaced_orders: true,
+                ..policy()
+            },
+        )
+        .with_reconciler(lookup());
+
+        client.send(place_order()).await.unwrap();
+
+        let sent = client.inner().sent.lock().unwrap();
+        let methods: Vec<_> = sent.iter().map(|(method, _, _)| method.clone()).collect();
+        assert_eq!(
+            methods,
+            [Method::POST, Method::GET, Method::GET, Method::POST]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_post_is_sent_again() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::RateLimited {
+                    retry_after: Some(Duration::from_millis(1)),
+                }),
+                Ok(json!({ "clientOrderId": "abc-1", "status": "NEW" })),
+            ]),
+            policy(),
+        );
+
+        client.send(place_order()).await.unwrap();
+
+        let sent = client.inner().sent.lock().unwrap();
+        let methods: Vec<_> = sent.iter().map(|(method, _, _)| method.clone()).collect();
+        assert_eq!(methods, [Method::POST, Method::POST]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_lookup_returns_the_original_error() {
+        let client = RetryingClient::new(
+            ScriptedClient::new(vec![
+                Err(ErrorKind::UnknownExecutionStatus),
+                Err(ErrorKind::ExchangeUnavailable),
+            ]),
+            policy(),
+        )
+        .with_reconciler(lookup());
+
+        let err = client.send(place_order()).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnknownExecutionStatus);
+        assert_eq!(client.inner().sent.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_lookup_ignores_other_endpoints() {
+        let lookup = lookup();
+        assert_eq!(
+            lookup.client_order_id(&place_order()),
+            Some("abc-1".to_string())
+        );
+        let batch = RestRequest::new(Method::POST, "/batchOrders", ()).with_params(json!({ "newClientOrderId": "x" }));
+        assert_eq!(lookup.client_order_id(&batch), None);
+    }
+}
@@ -0,0 +1,232 @@
+//! Secrets encrypted at rest
+//!
+//! A [`Keystore`] is a JSON file of named secrets, each sealed with AES-256-GCM
+//! under a 32 byte key and a random nonce. The entry name is authenticated along
+//! with the ciphertext, so entries cannot be swapped between names. The key itself
+//! is not stored; it typically comes from [`SecretValue::from_fd`] or an OS keychain.
+//!
+//! [`SecretValue::from_fd`]: super::SecretValue::from_fd
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use secrecy::zeroize::Zeroize;
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+
+use super::{SecretError, SecretValue};
+
+/// Version of the keystore file format
+const VERSION: u32 = 1;
+
+/// One encrypted secret, hex encoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SealedSecret {
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    entries: BTreeMap<String, SealedSecret>,
+}
+
+/// Named secrets encrypted with AES-256-GCM
+pub struct Keystore {
+    cipher: Aes256Gcm,
+    entries: BTreeMap<String, SealedSecret>,
+}
+
+impl Keystore {
+    /// An empty keystore sealed with `key`, which must be 32 bytes long
+    pub fn new(key: &[u8]) -> Result<Self, SecretError> {
+        Ok(Self {
+            cipher: Aes256Gcm::new_from_slice(key).map_err(|_| SecretError::InvalidKey)?,
+            entries: BTreeMap::new(),
+        })
+    }
+
+    /// Reads a keystore file. Entries are only decrypted when read, so a wrong
+    /// key shows up as [`SecretError::Decryption`] from [`Keystore::secret`].
+    pub fn open(path: impl AsRef<Path>, key: &[u8]) -> Result<Self, SecretError> {
+        let contents = std::fs::read_to_string(path)?;
+        let file: KeystoreFile = serde_json::from_str(&contents).map_err(|e| SecretError::Invalid(e.to_string()))?;
+        if file.version != VERSION {
+            return Err(SecretError::Invalid(format!(
+                "unsupported keystore version {}",
+                file.version
+            )));
+        }
+        let mut keystore = Self::new(key)?;
+        keystore.entries = file.entries;
+        Ok(keystore)
+    }
+
+    /// Writes the keystore to `path`, replacing the file in one step.
+    /// On Unix the file is only readable by its owner.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SecretError> {
+        let path = path.as_ref();
+        let file = KeystoreFile {
+            version: VERSION,
+            entries: self.entries.clone(),
+        };
+        let contents = serde_json::to_string_pretty(&file).map_err(|e| SecretError::Invalid(e.to_string()))?;
+
+        let tmp_path = path.with_extension("tmp");
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        std::io::Write::write_all(&mut options.open(&tmp_path)?, contents.as_bytes())?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Encrypts `secret` and stores it as `name`, replacing any previous entry
+    pub fn insert(&mut self, name: &str, secret: &SecretString) -> Result<(), SecretError> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: secret.expose_secret().as_bytes(),
+            aad: name.as_bytes(),
+        };
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, payload)
+            .map_err(|_| SecretError::InvalidKey)?;
+        self.entries.insert(
+            name.to_string(),
+            SealedSecret {
+                nonce: hex::encode(nonce),
+                ciphertext: hex::encode(ciphertext),
+            },
+        );
+        Ok(())
+    }
+
+    /// Removes the entry `name`, returning whether it existed
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.entries.remove(name).is_some()
+    }
+
+    /// Names of the stored secrets
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Decrypts the secret stored as `name`
+    pub fn secret(&self, name: &str) -> Result<SecretValue, SecretError> {
+        let sealed = self
+            .entries
+            .get(name)
+            .ok_or_else(|| SecretError::NotFound(name.to_string()))?;
+        let nonce = hex::decode(&sealed.nonce).map_err(|e| SecretError::Invalid(e.to_string()))?;
+        if nonce.len() != 12 {
+            return Err(SecretError::Invalid(format!(
+                "nonce of {} has {} bytes",
+                name,
+                nonce.len()
+            )));
+        }
+        let ciphertext = hex::decode(&sealed.ciphertext).map_err(|e| SecretError::Invalid(e.to_string()))?;
+        let payload = Payload {
+            msg: ciphertext.as_slice(),
+            aad: name.as_bytes(),
+        };
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(&nonce), payload)
+            .map_err(|_| SecretError::Decryption(name.to_string()))?;
+        match String::from_utf8(plaintext) {
+            Ok(secret) => Ok(SecretValue::new(SecretString::from(secret))),
+            Err(err) => {
+                err.into_bytes().zeroize();
+                Err(SecretError::Invalid(format!("{} is not valid UTF-8", name)))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Keystore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keystore")
+            .field("entries", &self.entries.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::ExposableSecret;
+
+    const KEY: [u8; 32] = [7; 32];
+
+    #[test]
+    fn test_round_trip_through_file() {
+        let path = std::env::temp_dir().join(format!("rest-keystore-{}.json", std::process::id()));
+        let mut keystore = Keystore::new(&KEY).unwrap();
+        keystore
+            .insert("binance/api_secret", &SecretString::from("s3cr3t"))
+            .unwrap();
+        keystore.save(&path).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("s3cr3t"));
+
+        let keystore = Keystore::open(&path, &KEY).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(keystore.names().collect::<Vec<_>>(), ["binance/api_secret"]);
+        let secret = keystore.secret("binance/api_secret").unwrap();
+        assert_eq!(secret.expose_secret(), "s3cr3t");
+    }
+
+    #[test]
+    fn test_wrong_key_fails_to_decrypt() {
+        let mut keystore = Keystore::new(&KEY).unwrap();
+        keystore
+            .insert("okx/api_secret", &SecretString::from("s3cr3t"))
+            .unwrap();
+
+        let other = Keystore {
+            cipher: Aes256Gcm::new_from_slice(&[8; 32]).unwrap(),
+            entries: keystore.entries.clone(),
+        };
+        assert!(matches!(
+            other.secret("okx/api_secret"),
+            Err(SecretError::Decryption(_))
+        ));
+        assert!(matches!(
+            keystore.secret("okx/passphrase"),
+            Err(SecretError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_entries_cannot_be_swapped() {
+        let mut keystore = Keystore::new(&KEY).unwrap();
+        keystore
+            .insert("key", &SecretString::from("public"))
+            .unwrap();
+        keystore
+            .insert("secret", &SecretString::from("private"))
+            .unwrap();
+
+        let sealed_secret = keystore.entries.get("secret").cloned().unwrap();
+        keystore.entries.insert("key".to_string(), sealed_secret);
+        assert!(matches!(
+            keystore.secret("key"),
+            Err(SecretError::Decryption(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        assert!(matches!(
+            Keystore::new(&[0; 16]),
+            Err(SecretError::InvalidKey)
+        ));
+    }
+}
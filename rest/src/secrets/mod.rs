@@ -0,0 +1,240 @@
+//! Module for handling secure storage and retrieval of API credentials.
+//!
+//! This module provides types and traits for securely storing and retrieving
+//! API credentials like keys and secrets. It uses the `secrecy` crate to ensure
+//! credentials are handled securely and not accidentally exposed.
+//!
+//! Secrets can be loaded from the environment, a file or an inherited file
+//! descriptor ([`SecretValue`]), from a file encrypted at rest ([`Keystore`]) or
+//! from a Vault compatible server ([`VaultClient`]).
+//!
+//! Request signing goes through [`HmacSigner`], which is separate from
+//! [`ExposableSecret`]: every exposable secret can sign, but a signer such as a
+//! [`VaultTransitKey`] computes signatures without ever returning the key.
+
+pub mod keystore;
+pub mod vault;
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use secrecy::zeroize::Zeroize;
+use secrecy::{ExposeSecret, SecretString};
+use sha2::Sha256;
+use thiserror::Error;
+
+pub use self::keystore::Keystore;
+pub use self::vault::{VaultClient, VaultTransitKey};
+use crate::transport::TransportError;
+
+/// Errors raised while loading or using a secret
+#[derive(Error, Debug)]
+pub enum SecretError {
+    /// The secret does not exist in the provider
+    #[error("secret not found: {0}")]
+    NotFound(String),
+
+    /// The key cannot be used, e.g. because it has the wrong length
+    #[error("invalid key")]
+    InvalidKey,
+
+    /// The secret could not be read
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The stored data is malformed
+    #[error("invalid secret data: {0}")]
+    Invalid(String),
+
+    /// The secret could not be decrypted, because the key is wrong or the data was tampered with
+    #[error("failed to decrypt secret: {0}")]
+    Decryption(String),
+
+    /// A remote provider could not be reached
+    #[error("transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    /// A remote provider rejected the request
+    #[error("provider error: {0}")]
+    Provider(String),
+}
+
+/// A trait for types that can securely expose a secret value.
+///
+/// This trait provides a way to expose secrets while maintaining control over
+/// how and when they are exposed. Implementors should ensure that the secret
+/// is handled securely and not accidentally exposed.
+pub trait ExposableSecret: Send + Sync {
+    /// Exposes the secret value as a String.
+    ///
+    /// # Security Note
+    /// This method should be used with caution as it exposes the secret value.
+    /// The secret should be cleared from memory as soon as possible after use.
+    fn expose_secret(&self) -> String;
+}
+
+/// Computes request signatures keyed with a secret.
+///
+/// Venue clients sign through this trait only, so a provider that holds the key
+/// elsewhere (a Vault transit key, a hardware token) can sign without handing it
+/// out. Every [`ExposableSecret`] is also a signer.
+#[async_trait]
+pub trait HmacSigner: Send + Sync {
+    /// Computes the HMAC-SHA256 of `message` keyed with the secret
+    async fn hmac_sha256(&self, message: &[u8]) -> Result<[u8; 32], SecretError>;
+}
+
+/// Signs locally with the exposed secret and wipes the copy afterwards
+#[async_trait]
+impl<T: ExposableSecret + ?Sized> HmacSigner for T {
+    async fn hmac_sha256(&self, message: &[u8]) -> Result<[u8; 32], SecretError> {
+        let mut secret = self.expose_secret();
+        let signature = hmac_sha256(secret.as_bytes(), message);
+        secret.zeroize();
+        signature
+    }
+}
+
+/// HMAC-SHA256 of `message` keyed with `key`
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Result<[u8; 32], SecretError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).map_err(|_| SecretError::InvalidKey)?;
+    mac.update(message);
+    Ok(mac.finalize().into_bytes().into())
+}
+
+/// A simple implementation of ExposableSecret that wraps a SecretString.
+///
+/// This struct provides a basic implementation of ExposableSecret that can be used
+/// when you have a SecretString that needs to be exposed through the ExposableSecret trait.
+#[derive(Clone)]
+pub struct SecretValue {
+    /// The secret value, stored securely using SecretString
+    secret: SecretString,
+}
+
+impl ExposableSecret for SecretValue {
+    fn expose_secret(&self) -> String {
+        self.secret.expose_secret().to_string()
+    }
+}
+
+impl SecretValue {
+    /// Creates a new SecretValue with the given secret.
+    ///
+    /// # Arguments
+    /// * `secret` - The secret value to store
+    pub fn new(secret: SecretString) -> Self {
+        Self { secret }
+    }
+
+    /// Reads the secret from the environment variable `name`.
+    ///
+    /// The variable is left in place; callers that spawn other processes may want
+    /// to remove it once all secrets are loaded.
+    pub fn from_env(name: &str) -> Result<Self, SecretError> {
+        match std::env::var(name) {
+            Ok(secret) => Ok(Self::new(SecretString::from(secret))),
+            Err(std::env::VarError::NotPresent) => Err(SecretError::NotFound(name.to_string())),
+            Err(std::env::VarError::NotUnicode(_)) => Err(SecretError::Invalid(format!(
+                "environment variable {} is not valid UTF-8",
+                name
+            ))),
+        }
+    }
+
+    /// Reads the secret from a file, ignoring a trailing newline.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SecretError> {
+        let mut contents = std::fs::read_to_string(path)?;
+        let secret = SecretString::from(contents.trim_end_matches(['\r', '\n']));
+        contents.zeroize();
+        Ok(Self::new(secret))
+    }
+
+    /// Reads the secret from a file descriptor inherited from the parent process,
+    /// e.g. `3< api_secret.txt`, ignoring a trailing newline. This keeps the secret
+    /// out of the environment and the command line.
+    #[cfg(unix)]
+    pub fn from_fd(fd: u32) -> Result<Self, SecretError> {
+        Self::from_file(format!("/dev/fd/{}", fd))
+    }
+}
+
+/// A plain text implementation of ExposableSecret for testing purposes.
+///
+/// **WARNING**: This implementation stores the secret in plain text and should
+/// only be used for testing. Never use this in production code.
+#[cfg(test)]
+#[derive(Clone)]
+pub struct PlainTextSecret {
+    secret: String,
+}
+
+#[cfg(test)]
+impl ExposableSecret for PlainTextSecret {
+    fn expose_secret(&self) -> String {
+        self.secret.clone()
+    }
+}
+
+#[cfg(test)]
+impl PlainTextSecret {
+    /// Creates a new PlainTextSecret with the given secret.
+    ///
+    /// **WARNING**: This implementation should only be used for testing.
+    ///
+    /// # Arguments
+    /// * `secret` - The secret value to store in plain text
+    pub fn new(secret: String) -> Self {
+        Self { secret }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_hmac_matches_binance_example() {
+        // From the Binance API documentation's signing example
+        let secret = SecretValue::new(SecretString::from(
+            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+        ));
+        let query = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+        let signature = secret.hmac_sha256(query.as_bytes()).await.unwrap();
+        assert_eq!(
+            hex::encode(signature),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+
+        // Signing through a trait object gives the same result
+        let signer: Box<dyn HmacSigner> = Box::new(PlainTextSecret::new(secret.expose_secret()));
+        assert_eq!(signer.hmac_sha256(query.as_bytes()).await.unwrap(), signature);
+    }
+
+    #[test]
+    fn test_from_env() {
+        std::env::set_var("REST_SECRETS_TEST_FROM_ENV", "env_secret");
+        let secret = SecretValue::from_env("REST_SECRETS_TEST_FROM_ENV").unwrap();
+        assert_eq!(secret.expose_secret(), "env_secret");
+
+        assert!(matches!(
+            SecretValue::from_env("REST_SECRETS_TEST_MISSING"),
+            Err(SecretError::NotFound(name)) if name == "REST_SECRETS_TEST_MISSING"
+        ));
+    }
+
+    #[test]
+    fn test_from_file_strips_trailing_newline() {
+        let path = std::env::temp_dir().join(format!("rest-secret-{}.txt", std::process::id()));
+        std::fs::write(&path, "file_secret\n").unwrap();
+        let secret = SecretValue::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(secret.expose_secret(), "file_secret");
+
+        assert!(matches!(
+            SecretValue::from_file(&path),
+            Err(SecretError::Io(_))
+        ));
+    }
+}
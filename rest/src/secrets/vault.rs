@@ -0,0 +1,275 @@
+//! Secrets held by a HashiCorp Vault compatible server
+//!
+//! [`VaultClient::read`] fetches a field of a KV version 2 secret. A
+//! [`VaultTransitKey`] signs with a transit key instead, so the signing key never
+//! leaves the server; venue clients take it in place of an API secret. Requests go
+//! through an [`HttpTransport`], so tests can use a
+//! [`MockTransport`](crate::transport::MockTransport) in place of a Vault server.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{HmacSigner, SecretError, SecretValue};
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+
+/// Header carrying the Vault token
+const TOKEN_HEADER: &str = "X-Vault-Token";
+
+#[derive(Debug, Deserialize)]
+struct KvResponse {
+    data: KvData,
+}
+
+#[derive(Debug, Deserialize)]
+struct KvData {
+    data: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HmacResponse {
+    data: HmacData,
+}
+
+#[derive(Debug, Deserialize)]
+struct HmacData {
+    hmac: String,
+}
+
+/// Client for a Vault compatible server
+#[derive(Clone)]
+pub struct VaultClient {
+    transport: Arc<dyn HttpTransport>,
+    address: String,
+    token: SecretString,
+    kv_mount: String,
+    transit_mount: String,
+}
+
+impl VaultClient {
+    /// A client for the server at `address` (e.g. "http://127.0.0.1:8200"),
+    /// using the KV engine at `secret/` and the transit engine at `transit/`
+    pub fn new(address: impl Into<String>, token: SecretString, transport: impl HttpTransport + 'static) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            address: address.into().trim_end_matches('/').to_string(),
+            token,
+            kv_mount: "secret".to_string(),
+            transit_mount: "transit".to_string(),
+        }
+    }
+
+    /// Uses the KV version 2 engine mounted at `mount`
+    pub fn with_kv_mount(mut self, mount: impl Into<String>) -> Self {
+        self.kv_mount = mount.into();
+        self
+    }
+
+    /// Uses the transit engine mounted at `mount`
+    pub fn with_transit_mount(mut self, mount: impl Into<String>) -> Self {
+        self.transit_mount = mount.into();
+        self
+    }
+
+    /// Reads `field` of the latest version of the KV secret at `path`
+    pub async fn read(&self, path: &str, field: &str) -> Result<SecretValue, SecretError> {
+        let url = format!("{}/v1/{}/data/{}", self.address, self.kv_mount, path);
+        let response = self
+            .send(HttpRequest::new(reqwest::Method::GET, url), path)
+            .await?;
+        let mut kv: KvResponse = serde_json::from_str(&response.body).map_err(|e| SecretError::Invalid(e.to_string()))?;
+        match kv.data.data.remove(field) {
+            Some(serde_json::Value::String(secret)) => Ok(SecretValue::new(SecretString::from(secret))),
+            Some(_) => Err(SecretError::Invalid(format!(
+                "{}#{} is not a string",
+                path, field
+            ))),
+            None => Err(SecretError::NotFound(format!("{}#{}", path, field))),
+        }
+    }
+
+    /// The transit key `key_name`, as a signer for venue clients
+    pub fn transit_key(&self, key_name: impl Into<String>) -> VaultTransitKey {
+        VaultTransitKey {
+            vault: self.clone(),
+            key_name: key_name.into(),
+        }
+    }
+
+    /// HMAC-SHA256 of `message` computed by the server with the transit key `key_name`
+    pub async fn hmac_sha256(&self, key_name: &str, message: &[u8]) -> Result<[u8; 32], SecretError> {
+        let url = format!(
+            "{}/v1/{}/hmac/{}/sha2-256",
+            self.address, self.transit_mount, key_name
+        );
+        let request = HttpRequest::new(reqwest::Method::POST, url).json(&json!({ "input": STANDARD.encode(message) }))?;
+        let response = self.send(request, key_name).await?;
+        let hmac: HmacResponse = serde_json::from_str(&response.body).map_err(|e| SecretError::Invalid(e.to_string()))?;
+
+        // Transit returns "vault:v<key version>:<base64>"
+        let encoded = hmac
+            .data
+            .hmac
+            .rsplit(':')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let signature = STANDARD
+            .decode(encoded)
+            .map_err(|e| SecretError::Invalid(e.to_string()))?;
+        signature
+            .try_into()
+            .map_err(|_| SecretError::Invalid("HMAC is not 32 bytes".to_string()))
+    }
+
+    async fn send(&self, request: HttpRequest, name: &str) -> Result<HttpResponse, SecretError> {
+        let request = request.header(TOKEN_HEADER, self.token.expose_secret());
+        let response = self.transport.execute(request).await?;
+        match response.status.as_u16() {
+            200..=299 => Ok(response),
+            404 => Err(SecretError::NotFound(name.to_string())),
+            status => Err(SecretError::Provider(format!(
+                "HTTP {}: {}",
+                status, response.body
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Debug for VaultClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultClient")
+            .field("address", &self.address)
+            .field("kv_mount", &self.kv_mount)
+            .field("transit_mount", &self.transit_mount)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A transit key of a Vault server. It only signs: the key itself cannot be read.
+#[derive(Debug, Clone)]
+pub struct VaultTransitKey {
+    vault: VaultClient,
+    key_name: String,
+}
+
+#[async_trait]
+impl HmacSigner for VaultTransitKey {
+    async fn hmac_sha256(&self, message: &[u8]) -> Result<[u8; 32], SecretError> {
+        self.vault.hmac_sha256(&self.key_name, message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+
+    use super::*;
+    use crate::secrets::ExposableSecret;
+    use crate::transport::MockTransport;
+
+    fn client(transport: &MockTransport) -> VaultClient {
+        VaultClient::new(
+            "http://127.0.0.1:8200/",
+            SecretString::from("root-token"),
+            transport.clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_read_kv_secret() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({ "data": { "data": { "api_secret": "s3cr3t" }, "metadata": { "version": 3 } } }),
+        );
+
+        let secret = client(&transport)
+            .read("binance", "api_secret")
+            .await
+            .unwrap();
+        assert_eq!(secret.expose_secret(), "s3cr3t");
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::GET);
+        assert_eq!(sent.url, "http://127.0.0.1:8200/v1/secret/data/binance");
+        assert_eq!(sent.header_value("X-Vault-Token"), Some("root-token"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_secrets() {
+        let transport = MockTransport::new();
+        transport
+            .push_json(StatusCode::OK, json!({ "data": { "data": {} } }))
+            .push_json(StatusCode::NOT_FOUND, json!({ "errors": [] }))
+            .push_json(
+                StatusCode::FORBIDDEN,
+                json!({ "errors": ["permission denied"] }),
+            );
+        let client = client(&transport);
+
+        assert!(matches!(
+            client.read("okx", "passphrase").await,
+            Err(SecretError::NotFound(name)) if name == "okx#passphrase"
+        ));
+        assert!(matches!(
+            client.read("okx", "passphrase").await,
+            Err(SecretError::NotFound(_))
+        ));
+        assert!(matches!(
+            client.read("okx", "passphrase").await,
+            Err(SecretError::Provider(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transit_key_signs_on_the_server() {
+        let expected = [0x5a_u8; 32];
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({ "data": { "hmac": format!("vault:v2:{}", STANDARD.encode(expected)) } }),
+        );
+        let signer: Box<dyn HmacSigner> = Box::new(client(&transport).transit_key("binance"));
+
+        assert_eq!(signer.hmac_sha256(b"timestamp=1").await.unwrap(), expected);
+        assert_eq!(
+            transport.last_request().unwrap().url,
+            "http://127.0.0.1:8200/v1/transit/hmac/binance/sha2-256"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transit_hmac() {
+        let expected = [0xab_u8; 32];
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({ "data": { "hmac": format!("vault:v1:{}", STANDARD.encode(expected)) } }),
+        );
+        let client = client(&transport).with_transit_mount("signing");
+
+        let signature = client.hmac_sha256("binance", b"timestamp=1").await.unwrap();
+        assert_eq!(signature, expected);
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::POST);
+        assert_eq!(
+            sent.url,
+            "http://127.0.0.1:8200/v1/signing/hmac/binance/sha2-256"
+        );
+        assert_eq!(
+            sent.body.as_deref(),
+            Some(
+                json!({ "input": STANDARD.encode("timestamp=1") })
+                    .to_string()
+                    .as_str()
+            )
+        );
+    }
+}
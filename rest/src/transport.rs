@@ -0,0 +1,336 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::error::ErrorKind;
+
+/// An HTTP request as handed to a transport: fully built, signed and with the
+/// query string already in the URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: Method,
+
+    /// Full URL, including the query string
+    pub url: String,
+
+    /// Headers in the order they were added
+    pub headers: Vec<(String, String)>,
+
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Add a header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set the body
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Append `params` to the query string, form-encoded
+    pub fn query<T: Serialize + ?Sized>(mut self, params: &T) -> Result<Self, TransportError> {
+        let encoded = serde_urlencoded::to_string(params).map_err(|err| TransportError::InvalidRequest(err.to_string()))?;
+        if !encoded.is_empty() {
+            if !self.url.contains('?') {
+                self.url.push('?');
+            } else if !self.url.ends_with('?') && !self.url.ends_with('&') {
+                self.url.push('&');
+            }
+            self.url.push_str(&encoded);
+        }
+        Ok(self)
+    }
+
+    /// Set a JSON body, adding a `Content-Type: application/json` header unless
+    /// one is already present
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Self, TransportError> {
+        let body = serde_json::to_string(body).map_err(|err| TransportError::InvalidRequest(err.to_string()))?;
+        if self.header_value("content-type").is_none() {
+            self = self.header("Content-Type", "application/json");
+        }
+        Ok(self.body(body))
+    }
+
+    /// Value of the first header called `name`, ignoring case
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The query string of the URL, without the leading `?`
+    pub fn query_string(&self) -> Option<&str> {
+        self.url.split_once('?').map(|(_, query)| query)
+    }
+}
+
+/// An HTTP response with its body already read
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn new(status: StatusCode, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Add a header. Names or values that are not valid HTTP are ignored.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+            self.headers.append(name, value);
+        }
+        self
+    }
+
+    /// Value of the header called `name`, if it is present and valid text
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// Why a request did not produce a response
+#[derive(Error, Debug)]
+pub enum TransportError {
+    #[error("{0}")]
+    Reqwest(#[from] reqwest::Error),
+
+    /// The connection could not be established, so the request was not sent
+    #[error("connection failed: {0}")]
+    Connect(String),
+
+    /// No response arrived in time. The request may have been processed.
+    #[error("request timed out: {0}")]
+    Timeout(String),
+
+    /// The request could not be built, e.g. because of an invalid header
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+}
+
+impl TransportError {
+    /// The HTTP status, if the error came from one
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            TransportError::Reqwest(err) => err.status(),
+            _ => None,
+        }
+    }
+}
+
+impl From<&TransportError> for ErrorKind {
+    fn from(err: &TransportError) -> Self {
+        match err {
+            TransportError::Reqwest(err) => ErrorKind::from(err),
+            TransportError::Connect(_) => ErrorKind::ExchangeUnavailable,
+            TransportError::Timeout(_) => ErrorKind::UnknownExecutionStatus,
+            TransportError::InvalidRequest(_) => ErrorKind::InvalidRequest,
+        }
+    }
+}
+
+/// Sends HTTP requests for a venue client.
+///
+/// Venue clients build and sign an [`HttpRequest`] and hand it to a transport,
+/// so tests can swap the network for a [`MockTransport`]. `reqwest::Client` is
+/// the production implementation.
+#[async_trait]
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError>;
+}
+
+#[async_trait]
+impl HttpTransport for reqwest::Client {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let mut builder = self.request(request.method, &request.url);
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        (**self).execute(request).await
+    }
+}
+
+/// A scripted in-memory transport for tests.
+///
+/// Responses are returned in the order they were queued, and every request is
+/// recorded so tests can check what was sent. Clones share the same script, so
+/// keep one handle and give a clone to the client under test.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: VecDeque<Result<HttpResponse, TransportError>>,
+    requests: Vec<HttpRequest>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Queue a response
+    pub fn push_response(&self, response: HttpResponse) -> &Self {
+        self.state().responses.push_back(Ok(response));
+        self
+    }
+
+    /// Queue a response with a JSON body
+    pub fn push_json(&self, status: StatusCode, body: serde_json::Value) -> &Self {
+        self.push_response(HttpResponse::new(status, body.to_string()).with_header("content-type", "application/json"))
+    }
+
+    /// Queue a transport failure
+    pub fn push_error(&self, error: TransportError) -> &Self {
+        self.state().responses.push_back(Err(error));
+        self
+    }
+
+    /// Every request sent so far, oldest first
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.state().requests.clone()
+    }
+
+    /// The most recent request
+    pub fn last_request(&self) -> Option<HttpRequest> {
+        self.state().requests.last().cloned()
+    }
+
+    /// Number of queued responses not yet consumed
+    pub fn pending(&self) -> usize {
+        self.state().responses.len()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MockTransport {
+    /// Returns the next queued response. Fails with [`TransportError::Connect`]
+    /// once the script runs out.
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let mut state = self.state();
+        let description = format!("{} {}", request.method, request.url);
+        state.requests.push(request);
+        state.responses.pop_front().unwrap_or_else(|| {
+            Err(TransportError::Connect(format!(
+                "no scripted response for {}",
+                description
+            )))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_transport_replays_script_and_records_requests() {
+        let mock = MockTransport::new();
+        mock.push_json(StatusCode::OK, json!({ "serverTime": 1 }))
+            .push_error(TransportError::Timeout("slow".to_string()));
+        let transport: Arc<dyn HttpTransport> = Arc::new(mock.clone());
+
+        let request = HttpRequest::new(Method::GET, "https://example.com/time?a=1").header("X-Key", "k");
+        let response = transport.execute(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body, r#"{"serverTime":1}"#);
+        assert_eq!(response.header("Content-Type"), Some("application/json"));
+
+        let err = transport
+            .execute(HttpRequest::new(Method::POST, "https://example.com/order").body("x=1"))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorKind::from(&err), ErrorKind::UnknownExecutionStatus);
+
+        let err = transport
+            .execute(HttpRequest::new(Method::GET, "https://example.com/time"))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorKind::from(&err), ErrorKind::ExchangeUnavailable);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        let first = requests.first().unwrap();
+        assert_eq!(first.query_string(), Some("a=1"));
+        assert_eq!(first.header_value("x-key"), Some("k"));
+        assert_eq!(requests.get(1).unwrap().body.as_deref(), Some("x=1"));
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[test]
+    fn test_query_and_json_builders() {
+        let request = HttpRequest::new(Method::GET, "https://example.com/path")
+            .query(&[("symbol", "BTC USD")])
+            .unwrap()
+            .query(&[("limit", 5)])
+            .unwrap();
+        assert_eq!(
+            request.url,
+            "https://example.com/path?symbol=BTC+USD&limit=5"
+        );
+
+        let request = HttpRequest::new(Method::POST, "https://example.com/order")
+            .json(&serde_json::json!({ "qty": 1 }))
+            .unwrap();
+        assert_eq!(request.body.as_deref(), Some(r#"{"qty":1}"#));
+        assert_eq!(
+            request.header_value("content-type"),
+            Some("application/json")
+        );
+        assert_eq!(request.headers.len(), 1);
+    }
+}
@@ -5,7 +5,7 @@
 
 use venues::binance::spot::PrivateRestClient;
 use venues::binance::spot::RateLimiter;
-use rest::secrets::ExposableSecret;
+use rest::secrets::{ExposableSecret, HmacSigner};
 use reqwest::Client;
 
 // Test secret implementation
@@ -30,7 +30,7 @@ impl TestSecret {
 fn test_public_api_access() {
     // Test that we can create the PrivateRestClient through the public API
     let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-    let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+    let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
     let client = Client::new();
     let rate_limiter = RateLimiter::new();
 
@@ -1,4 +1,4 @@
-use rest::secrets::ExposableSecret;
+use rest::secrets::{ExposableSecret, HmacSigner};
 use serde_json::json;
 
 use crate::cryptocom::private::RestClient;
@@ -27,7 +27,7 @@ impl PlainTextSecret {
 async fn test_private_endpoints_compile() {
     // Test that all the new private endpoints compile and are accessible
     let api_key = Box::new(PlainTextSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-    let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+    let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
     let client = reqwest::Client::new();
 
     let rest_client = RestClient::new(api_key, api_secret, "https://api.crypto.com", client);
@@ -413,7 +413,7 @@ mod usage_examples {
     async fn test_private_rest_client_withdraw_method() {
         // Test that the withdraw method is accessible via PrivateRestClient
         use crate::deribit::{PrivateRestClient, AccountTier, RateLimiter};
-        use rest::secrets::ExposableSecret;
+        use rest::secrets::{ExposableSecret, HmacSigner};
 
         // Test secret implementation
         #[derive(Clone)]
@@ -434,7 +434,7 @@ mod usage_examples {
         }
 
         let api_key = Box::new(PlainTextSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let rate_limiter = RateLimiter::new(AccountTier::Tier4);
 
@@ -459,7 +459,7 @@ mod usage_examples {
     async fn test_private_rest_client_submit_transfer_between_subaccounts_method() {
         // Test that the submit_transfer_between_subaccounts method is accessible via PrivateRestClient
         use crate::deribit::{PrivateRestClient, AccountTier, RateLimiter, SubmitTransferBetweenSubaccountsRequest};
-        use rest::secrets::ExposableSecret;
+        use rest::secrets::{ExposableSecret, HmacSigner};
 
         // Test secret implementation
         #[derive(Clone)]
@@ -480,7 +480,7 @@ mod usage_examples {
         }
 
         let api_key = Box::new(PlainTextSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let rate_limiter = RateLimiter::new(AccountTier::Tier4);
 
@@ -519,7 +519,7 @@ mod usage_examples {
     async fn test_private_rest_client_get_user_trades_by_order_method() {
         // Test that the get_user_trades_by_order method is accessible via PrivateRestClient
         use crate::deribit::{PrivateRestClient, AccountTier, RateLimiter, GetUserTradesByOrderRequest, Sorting};
-        use rest::secrets::ExposableSecret;
+        use rest::secrets::{ExposableSecret, HmacSigner};
 
         // Test secret implementation
         #[derive(Clone)]
@@ -540,7 +540,7 @@ mod usage_examples {
         }
 
         let api_key = Box::new(PlainTextSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let rate_limiter = RateLimiter::new(AccountTier::Tier4);
 
@@ -391,7 +391,7 @@ mod rest_api_tests {
         use venues::deribit::{
             PrivateRestClient, CreateDepositAddressRequest, Currency, AccountTier, RateLimiter
         };
-        use rest::secrets::ExposableSecret;
+        use rest::secrets::{ExposableSecret, HmacSigner};
 
         // Mock secret implementation for testing
         #[derive(Clone)]
@@ -413,7 +413,7 @@ mod rest_api_tests {
 
         // Create a test client
         let api_key = Box::new(TestSecret::new("test_api_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_api_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_api_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let rate_limiter = RateLimiter::new(AccountTier::Tier4);
 
@@ -586,7 +586,7 @@ mod rest_api_tests {
         use venues::deribit::{
             AccountTier, RateLimiter, PrivateRestClient
         };
-        use rest::secrets::ExposableSecret;
+        use rest::secrets::{ExposableSecret, HmacSigner};
 
         // Mock secret implementation for testing
         #[derive(Clone)]
@@ -608,7 +608,7 @@ mod rest_api_tests {
 
         // Create a test client
         let api_key = Box::new(TestSecret::new("test_api_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_api_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_api_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let rate_limiter = RateLimiter::new(AccountTier::Tier4);
 
@@ -1,8 +1,9 @@
 use anyhow::Result;
+use rest::middleware::{LoggingMiddleware, Pipeline, RequestIdMiddleware, TracingMiddleware};
 use rest::secrets::SecretValue;
 use secrecy::SecretString;
-use tracing::info;
-use venues::binance::portfolio::{PrivateRestClient, RateLimiter};
+use tracing::{info, warn};
+use venues::binance::portfolio::{AccountRequest, BalancesRequest, PrivateRestClient, RateLimiter};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -14,8 +15,11 @@ async fn main() -> Result<()> {
 
     info!("Binance Portfolio Margin API Client Example");
 
-    // Create HTTP client
-    let http_client = reqwest::Client::new();
+    // Create HTTP client, with request ids, tracing spans and redacted debug logging
+    let http_client = Pipeline::new(reqwest::Client::new())
+        .with(RequestIdMiddleware::new())
+        .with(TracingMiddleware)
+        .with(LoggingMiddleware::default());
 
     // Create rate limiter
     let rate_limiter = RateLimiter::new();
@@ -24,7 +28,7 @@ async fn main() -> Result<()> {
     let api_key = std::env::var("BINANCE_API_KEY").unwrap_or_else(|_| "your_api_key".to_string());
     let api_secret = std::env::var("BINANCE_API_SECRET").unwrap_or_else(|_| "your_api_secret".to_string());
 
-    let _portfolio_margin_client = PrivateRestClient::new(
+    let portfolio_margin_client = PrivateRestClient::new(
         Box::new(SecretValue::new(SecretString::from(api_key))),
         Box::new(SecretValue::new(SecretString::from(api_secret))),
         "https://papi.binance.com",
@@ -36,8 +40,32 @@ async fn main() -> Result<()> {
     info!("Base URL: https://papi.binance.com");
     info!("Client is ready to make authenticated requests to Portfolio Margin API");
 
-    // Note: To make actual API calls, you would need to implement specific endpoint methods
-    // similar to the coinm implementation (e.g., account info, position risk, etc.)
+    // Signed requests fail with the placeholder credentials, so errors are logged rather than returned
+    match portfolio_margin_client
+        .get_account(AccountRequest::default())
+        .await
+    {
+        Ok(response) => info!(
+            "Account status: {}, uniMMR: {}, equity: {} USD",
+            response.data.account_status, response.data.uni_mmr, response.data.account_equity
+        ),
+        Err(e) => warn!("Failed to fetch account information: {}", e),
+    }
+
+    match portfolio_margin_client
+        .get_balances(BalancesRequest::default())
+        .await
+    {
+        Ok(response) => {
+            for balance in &response.data {
+                info!(
+                    "{}: wallet {}, margin free {}, UM {}, CM {}",
+                    balance.asset, balance.total_wallet_balance, balance.cross_margin_free, balance.um_wallet_balance, balance.cm_wallet_balance
+                );
+            }
+        }
+        Err(e) => warn!("Failed to fetch balances: {}", e),
+    }
 
     Ok(())
 }
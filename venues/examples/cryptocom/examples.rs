@@ -8,7 +8,7 @@
 mod example {
     use serde_json::json;
 
-    use crate::cryptocom::{ApiError, ErrorResponse, Errors, PrivateRestClient, RestResult};
+    use crate::cryptocom::{ApiError, ErrorResponse, Errors, PrivateRestClient, RateLimiter, RestResult};
 
     /// Simulates processing an API response from Crypto.com
     fn process_api_response(response_code: i32, message: String) -> RestResult<String> {
@@ -101,8 +101,8 @@ mod example {
         }
     }
 
-    #[test]
-    fn example_private_endpoint_signing() {
+    #[tokio::test]
+    async fn example_private_endpoint_signing() {
         // Example demonstrating how to use the private endpoint signing
         // Note: This is a demonstration only - you would not use PlainTextSecret in production
         use rest::secrets::SecretValue;
@@ -115,19 +115,27 @@ mod example {
         )));
         let client = reqwest::Client::new();
 
-        let rest_client = PrivateRestClient::new(api_key, api_secret, "https://api.crypto.com", client);
+        let rest_client = PrivateRestClient::new(
+            api_key,
+            api_secret,
+            "https://api.crypto.com",
+            client,
+            RateLimiter::new(),
+        );
 
         // Example 1: Sign a get-order-detail request
         let params = json!({
             "order_id": "53287421324"  // Note: Using string format as recommended
         });
 
-        let signature = rest_client.sign_request(
-            "private/get-order-detail",
-            11, // request ID
-            &params,
-            1587846358253, // nonce (timestamp in milliseconds)
-        );
+        let signature = rest_client
+            .sign_request(
+                "private/get-order-detail",
+                11, // request ID
+                &params,
+                1587846358253, // nonce (timestamp in milliseconds)
+            )
+            .await;
 
         match signature {
             Ok(sig) => {
@@ -146,23 +154,27 @@ mod example {
             "price": "50000.00"
         });
 
-        let signature = rest_client.sign_request(
-            "private/create-order",
-            42,
-            &order_params,
-            chrono::Utc::now().timestamp_millis() as u64,
-        );
+        let signature = rest_client
+            .sign_request(
+                "private/create-order",
+                42,
+                &order_params,
+                chrono::Utc::now().timestamp_millis() as u64,
+            )
+            .await;
 
         assert!(signature.is_ok());
         println!("Create order signature: {}", signature.unwrap());
 
         // Example 3: Sign a request with empty parameters
-        let signature = rest_client.sign_request(
-            "private/get-account-summary",
-            1,
-            &json!({}),
-            chrono::Utc::now().timestamp_millis() as u64,
-        );
+        let signature = rest_client
+            .sign_request(
+                "private/get-account-summary",
+                1,
+                &json!({}),
+                chrono::Utc::now().timestamp_millis() as u64,
+            )
+            .await;
 
         assert!(signature.is_ok());
         println!("Account summary signature: {}", signature.unwrap());
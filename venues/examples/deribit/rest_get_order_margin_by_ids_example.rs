@@ -26,7 +26,7 @@ async fn main() {
     let api_secret = std::env::var("DERIBIT_API_SECRET").expect("DERIBIT_API_SECRET not set");
 
     let api_key = Box::new(EnvSecret(api_key)) as Box<dyn rest::secrets::ExposableSecret>;
-    let api_secret = Box::new(EnvSecret(api_secret)) as Box<dyn rest::secrets::ExposableSecret>;
+    let api_secret = Box::new(EnvSecret(api_secret)) as Box<dyn rest::secrets::HmacSigner>;
     let client = Client::new();
     let rate_limiter = RateLimiter::default();
     let rest_client = RestClient::new(
@@ -407,3 +407,36 @@ pub enum KlineInterval {
     #[serde(rename = "1M")]
     I1M,
 }
+
+/// Represents the period of the futures statistics under `/futures/data`
+/// (open interest history, long/short ratios, taker volume and basis).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Period {
+    /// 5 minutes
+    #[serde(rename = "5m")]
+    I5m,
+    /// 15 minutes
+    #[serde(rename = "15m")]
+    I15m,
+    /// 30 minutes
+    #[serde(rename = "30m")]
+    I30m,
+    /// 1 hour
+    #[serde(rename = "1h")]
+    I1h,
+    /// 2 hours
+    #[serde(rename = "2h")]
+    I2h,
+    /// 4 hours
+    #[serde(rename = "4h")]
+    I4h,
+    /// 6 hours
+    #[serde(rename = "6h")]
+    I6h,
+    /// 12 hours
+    #[serde(rename = "12h")]
+    I12h,
+    /// 1 day
+    #[serde(rename = "1d")]
+    I1d,
+}
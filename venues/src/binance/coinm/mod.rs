@@ -9,7 +9,30 @@ mod request;
 mod public {
     mod rest;
     pub use self::rest::RestClient as PublicRestClient;
+    pub use self::rest::agg_trades::*;
+    pub use self::rest::basis::*;
+    pub use self::rest::book_ticker::*;
+    pub use self::rest::continuous_klines::*;
     pub use self::rest::exchange_info::*;
+    pub use self::rest::funding_info::*;
+    pub use self::rest::funding_rate_history::*;
+    pub use self::rest::historical_trades::*;
+    pub use self::rest::index_price_klines::*;
+    pub use self::rest::klines::*;
+    pub use self::rest::mark_price_klines::*;
+    pub use self::rest::open_interest::*;
+    pub use self::rest::open_interest_hist::*;
+    pub use self::rest::order_book::*;
+    pub use self::rest::ping::*;
+    pub use self::rest::premium_index::*;
+    pub use self::rest::premium_index_klines::*;
+    pub use self::rest::price_ticker::*;
+    pub use self::rest::server_time::*;
+    pub use self::rest::taker_buy_sell_volume::*;
+    pub use self::rest::ticker_24hr::*;
+    pub use self::rest::top_long_short_account_ratio::*;
+    pub use self::rest::top_long_short_position_ratio::*;
+    pub use self::rest::trades::*;
 }
 
 mod private {
@@ -31,9 +54,10 @@ pub use enums::*;
 pub use errors::{ApiError, Errors};
 pub use private::*;
 pub use public::*;
-pub use rate_limit::{RateLimitHeader, RateLimiter};
+pub use rate_limit::{RateLimitHeader, RateLimiter, RequestWeight};
 
 pub use crate::binance::coinm::errors::ErrorResponse;
+pub(crate) use crate::binance::coinm::errors::VENUE;
 pub(crate) use crate::binance::coinm::request::execute_request;
 
 /// Represents the relevant response headers returned by the Binance API for rate limiting and order tracking.
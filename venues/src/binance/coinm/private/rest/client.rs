@@ -25,14 +25,20 @@
 //! - **Request Signing**: For private endpoints, query parameters (including timestamp) must be
 //!   signed using HMAC-SHA256 with the API secret
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use hex;
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
-use sha2::Sha256;
+use async_trait::async_trait;
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::retry::ClientOrderIdLookup;
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::HttpTransport;
 
-use crate::binance::coinm::{Errors, RateLimiter, RestResult};
+use crate::binance::coinm::errors::VENUE;
+use crate::binance::coinm::{RateLimiter, RequestWeight, RestResult};
+use crate::binance::shared::{object_params, signed_payload};
 
 /// Represents a successful or error response from the Binance API.
 /// This enum is used to handle both successful responses and error responses
@@ -43,21 +49,6 @@ use crate::binance::coinm::{Errors, RateLimiter, RestResult};
 //     Ok(T),
 //     Err(ErrorResponse),
 // }
-/// Signs a request using the decrypted API secret
-/// Signs a query string using the decrypted API secret and returns the signature as a hex string.
-///
-/// # Arguments
-/// * `query_string` - The query string to sign
-///
-/// # Returns
-/// A result containing the signature as a hex string or a Hmac error if signing fails.
-fn sign_request(api_secret: &dyn ExposableSecret, query_string: &str) -> Result<String, Errors> {
-    let api_secret = api_secret.expose_secret();
-    let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-    mac.update(query_string.as_bytes());
-    Ok(hex::encode(mac.finalize().into_bytes()))
-}
-
 /// A client for interacting with the Binance Coin-M Futures private REST API
 ///
 /// This client handles encrypted API keys and secrets for enhanced security.
@@ -65,15 +56,17 @@ fn sign_request(api_secret: &dyn ExposableSecret, query_string: &str) -> Result<
 #[non_exhaustive]
 pub struct RestClient {
     /// The underlying HTTP client used for making requests.
-    pub(crate) client: Client,
+    pub(crate) client: Arc<dyn HttpTransport>,
     /// The rate limiter for this client.
     pub(crate) rate_limiter: RateLimiter,
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
     /// The base URL for the API.
     pub(crate) base_url: Cow<'static, str>,
+    /// The server clock signed requests are timestamped with.
+    pub(crate) clock: ServerClock,
 }
 
 impl RestClient {
@@ -81,7 +74,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `encrypted_api_key` - The encrypted API key
-    /// * `encrypted_api_secret` - The encrypted API secret
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `encryption_key` - The key used for decrypting the API credentials
     ///
@@ -89,20 +82,27 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
         rate_limiter: RateLimiter,
-        client: Client,
+        client: impl HttpTransport + 'static,
     ) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             rate_limiter,
             api_key,
             api_secret,
             base_url: base_url.into(),
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `timestamp` of signed requests from `clock` instead of the local clock
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Sends a request to the Binance API
     ///
     /// This method encapsulates all the logic for making authenticated requests to the Binance API,
@@ -146,7 +146,7 @@ impl RestClient {
             ));
         }
         let rest_response = crate::binance::coinm::rest::common::send_rest_request(
-            &self.client,
+            self.client.as_ref(),
             &url,
             method,
             headers,
@@ -166,7 +166,8 @@ impl RestClient {
     /// Sends a signed request to the Binance API
     ///
     /// This method automatically handles timestamp generation and request signing for private endpoints.
-    /// It appends the current timestamp and generates the required signature.
+    /// It stamps the request with the server clock's time, adds a `recvWindow` once the clock is
+    /// synced, and generates the required signature.
     ///
     /// # Arguments
     /// * `endpoint` - The API endpoint path (e.g., "/fapi/v1/order")
@@ -182,15 +183,7 @@ impl RestClient {
         T: serde::de::DeserializeOwned,
         R: serde::Serialize,
     {
-        let serialized = serde_urlencoded::to_string(&request).map_err(|e| {
-            crate::binance::coinm::Errors::Error(format!(
-                "Failed to encode params: {}\nBacktrace:\n{}",
-                e,
-                std::backtrace::Backtrace::capture()
-            ))
-        })?;
-        let signature = sign_request(self.api_secret.as_ref(), &serialized)?;
-        let signed = format!("{}&signature={}", serialized, signature);
+        let signed = signed_payload(&self.clock, self.api_secret.as_ref(), &request).await?;
         if method == reqwest::Method::GET {
             self.send_request(endpoint, method, Some(&signed), None, weight, is_order)
                 .await
@@ -207,3 +200,246 @@ impl RestClient {
         }
     }
 }
+
+impl RestClient {
+    /// Looks up orders placed with `POST /dapi/v1/order` by their `newClientOrderId`, so a
+    /// [`rest::retry::RetryingClient`] can resolve orders whose outcome is unknown
+    pub fn order_reconciler() -> ClientOrderIdLookup<RequestWeight> {
+        ClientOrderIdLookup {
+            place_endpoint: "/dapi/v1/order".to_string(),
+            id_param: "newClientOrderId".to_string(),
+            query_method: reqwest::Method::GET,
+            query_endpoint: "/dapi/v1/order".to_string(),
+            query_id_param: "origClientOrderId".to_string(),
+            copied_params: vec!["symbol".to_string()],
+            query_rate_limit_key: RequestWeight::new(1),
+        }
+    }
+}
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Signs every request. `send_signed_request` stamps the `timestamp` from the server clock.
+    async fn send(&self, request: RestRequest<RequestWeight>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let params = object_params(VENUE, request.params)?;
+        let response = self
+            .send_signed_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                params,
+                request.rate_limit_key.weight,
+                request.rate_limit_key.is_order,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::{Method, StatusCode};
+    use rest::error::ErrorKind;
+    use rest::rate_limiter::RateLimiter as _;
+    use rest::request::RestClient as _;
+    use rest::retry::{RetryPolicy, RetryingClient};
+    use rest::transport::{HttpResponse, MockTransport};
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::shared::sign_request;
+
+    struct TestSecret(&'static str);
+
+    impl ExposableSecret for TestSecret {
+        fn expose_secret(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    fn client(transport: &MockTransport) -> RestClient {
+        RestClient::new(
+            Box::new(TestSecret("test_key")),
+            Box::new(TestSecret("test_secret")),
+            "https://dapi.binance.com",
+            RateLimiter::new(),
+            transport.clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_send_signs_query_and_reads_used_weight() {
+        let transport = MockTransport::new();
+        transport.push_response(HttpResponse::new(StatusCode::OK, r#"{"totalWalletBalance":"1.0"}"#).with_header("X-MBX-USED-WEIGHT-1M", "42"));
+        let client = client(&transport);
+
+        let request = RestRequest::new(Method::GET, "/dapi/v1/account", RequestWeight::new(5)).with_params(json!({
+            "recvWindow": 5000,
+            "timestamp": 1_700_000_000_000_u64,
+        }));
+        let response = client.send(request).await.unwrap();
+        assert_eq!(response.data, json!({ "totalWalletBalance": "1.0" }));
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::GET);
+        assert_eq!(sent.header_value("X-MBX-APIKEY"), Some("test_key"));
+        let (unsigned, signature) = sent
+            .query_string()
+            .and_then(|query| query.split_once("&signature="))
+            .unwrap();
+        // The local timestamp is replaced with the server clock's
+        let timestamp = unsigned.strip_prefix("recvWindow=5000&timestamp=").unwrap();
+        assert_ne!(timestamp, "1700000000000");
+        assert_eq!(
+            signature,
+            sign_request(&TestSecret("test_secret"), unsigned)
+                .await
+                .unwrap()
+        );
+
+        let status = client
+            .rate_limiter()
+            .get_rate_limit_status(&RequestWeight::new(1))
+            .await;
+        assert_eq!(status.limit, 6000);
+        assert_eq!(status.remaining, 5958);
+    }
+
+    #[tokio::test]
+    async fn test_signed_requests_use_server_clock() {
+        let transport = MockTransport::new();
+        transport.push_json(StatusCode::OK, json!({}));
+        let clock = ServerClock::new();
+        // The server runs a minute behind, measured with a 100ms round trip
+        clock.record(60_000, 50, Duration::from_millis(100));
+        let client = client(&transport).with_server_clock(clock.clone());
+
+        client
+            .send(RestRequest::new(
+                Method::GET,
+                "/dapi/v1/account",
+                RequestWeight::new(5),
+            ))
+            .await
+            .unwrap();
+
+        let sent = transport.last_request().unwrap();
+        let params: Vec<(String, String)> = serde_urlencoded::from_str(sent.query_string().unwrap()).unwrap();
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(param("recvWindow"), Some("5200"));
+        let timestamp: u64 = param("timestamp").unwrap().parse().unwrap();
+        assert!(clock.now_ms().abs_diff(timestamp) < 1000);
+        let local = u64::try_from(chrono::Utc::now().timestamp_millis()).unwrap();
+        assert!(local.saturating_sub(timestamp) > 59_000);
+    }
+
+    #[tokio::test]
+    async fn test_send_maps_http_429_to_rate_limited() {
+        let transport = MockTransport::new();
+        transport.push_response(
+            HttpResponse::new(
+                StatusCode::TOO_MANY_REQUESTS,
+                r#"{"code":-1003,"msg":"Too many requests"}"#,
+            )
+            .with_header("Retry-After", "7"),
+        );
+        let client = client(&transport);
+
+        let err = client
+            .send(RestRequest::new(
+                Method::GET,
+                "/dapi/v1/openOrders",
+                RequestWeight::new(1),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::RateLimited {
+                retry_after: Some(Duration::from_secs(7)),
+            }
+        );
+        assert!(
+            transport
+                .last_request()
+                .unwrap()
+                .query_string()
+                .unwrap()
+                .contains("timestamp=")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_maps_transport_failure() {
+        let transport = MockTransport::new();
+        transport.push_error(rest::transport::TransportError::Timeout(
+            "no response".to_string(),
+        ));
+        let client = client(&transport);
+
+        let err = client
+            .send(RestRequest::new(
+                Method::POST,
+                "/dapi/v1/order",
+                RequestWeight::order(1),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnknownExecutionStatus);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_order_outcome_is_looked_up_by_client_order_id() {
+        let transport = MockTransport::new();
+        transport
+            .push_json(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                json!({ "code": -1000, "msg": "An unknown error occurred" }),
+            )
+            .push_json(
+                StatusCode::OK,
+                json!({ "clientOrderId": "my-order-1", "status": "NEW" }),
+            );
+        let client = RetryingClient::new(client(&transport), RetryPolicy::default()).with_reconciler(RestClient::order_reconciler());
+
+        let request = RestRequest::new(Method::POST, "/dapi/v1/order", RequestWeight::order(1)).with_params(json!({
+            "symbol": "BTCUSD_PERP",
+            "side": "BUY",
+            "type": "MARKET",
+            "quantity": "1",
+            "newClientOrderId": "my-order-1",
+        }));
+        let response = client.send(request).await.unwrap();
+        assert_eq!(response.data["status"], "NEW");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        let lookup = requests.get(1).unwrap();
+        assert_eq!(lookup.method, Method::GET);
+        let query = lookup.query_string().unwrap();
+        assert!(query.starts_with("origClientOrderId=my-order-1&symbol=BTCUSD_PERP&timestamp="));
+        assert!(query.contains("&signature="));
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the best bid and ask. Without a symbol or pair, all symbols are returned.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct BookTickerRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Pair (e.g., "BTCUSD"). Cannot be sent with `symbol`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+}
+
+/// Best bid and ask of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Best bid price.
+    pub bid_price: String,
+
+    /// Quantity at the best bid.
+    pub bid_qty: String,
+
+    /// Best ask price.
+    pub ask_price: String,
+
+    /// Quantity at the best ask.
+    pub ask_qty: String,
+
+    /// Time of the quote (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the best bid and ask of the matching symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Symbol-Order-Book-Ticker>
+    /// Corresponds to endpoint GET /dapi/v1/ticker/bookTicker.
+    /// Weight: 2 for a single symbol, 5 otherwise
+    pub async fn get_book_ticker(&self, params: BookTickerRequest) -> RestResult<Vec<BookTicker>> {
+        let weight = if params.symbol.is_some() { 2 } else { 5 };
+        self.send_get_request("/dapi/v1/ticker/bookTicker", params, weight)
+            .await
+    }
+}
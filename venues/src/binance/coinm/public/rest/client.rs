@@ -3,10 +3,17 @@
 // Provides access to all public REST API endpoints for Binance Coin-M Futures.
 // All requests are unauthenticated and do not require API credentials.
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use reqwest::Client;
+use async_trait::async_trait;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::transport::HttpTransport;
+use secrecy::{ExposeSecret, SecretString};
 
-use crate::binance::coinm::{RateLimiter, RestResult};
+use crate::binance::coinm::errors::VENUE;
+use crate::binance::coinm::{Errors, RateLimiter, RequestWeight, RestResult};
 
 #[non_exhaustive]
 #[derive(Debug, Clone)]
@@ -19,12 +26,15 @@ pub struct RestClient {
     /// The underlying HTTP client used for making requests.
     ///
     /// This is reused for connection pooling and performance.
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits.
     ///
     /// This is used to ensure compliance with Binance's rate limits for public endpoints.
     pub rate_limiter: RateLimiter,
+
+    /// API key sent with MARKET_DATA endpoints such as historical trades, if set.
+    pub(crate) api_key: Option<SecretString>,
 }
 
 impl RestClient {
@@ -32,14 +42,22 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `base_url` - The base URL for the Binance Coin-M public REST API (e.g., "<https://dapi.binance.com>").
-    pub fn new(base_url: impl Into<Cow<'static, str>>, client: Client, rate_limiter: RateLimiter) -> Self {
+    pub fn new(base_url: impl Into<Cow<'static, str>>, client: impl HttpTransport + 'static, rate_limiter: RateLimiter) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
+            api_key: None,
         }
     }
 
+    /// Sends `api_key` in the `X-MBX-APIKEY` header, which MARKET_DATA endpoints such as
+    /// historical trades require. Other public endpoints ignore it.
+    pub fn with_api_key(mut self, api_key: SecretString) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
     /// Send a request with form body as &[(&str, &str)]
     pub async fn send_request<T>(
         &self,
@@ -53,13 +71,16 @@ impl RestClient {
         T: serde::de::DeserializeOwned,
     {
         let url = crate::binance::coinm::rest::common::build_url(&self.base_url, endpoint, query_string)?;
-        let headers = vec![];
+        let mut headers = vec![];
+        if let Some(api_key) = &self.api_key {
+            headers.push(("X-MBX-APIKEY", api_key.expose_secret().to_string()));
+        }
         let body_data = match body {
             Some(b) => Some(serde_urlencoded::to_string(b).map_err(|e| crate::binance::coinm::Errors::Error(format!("URL encoding error: {}", e)))?),
             None => None,
         };
         let rest_response = crate::binance::coinm::rest::common::send_rest_request(
-            &self.client,
+            self.client.as_ref(),
             &url,
             method,
             headers,
@@ -75,4 +96,62 @@ impl RestClient {
             headers: rest_response.headers,
         })
     }
+
+    /// Sends a GET request with `params` encoded in the query string
+    pub(super) async fn send_get_request<T, R>(&self, endpoint: &str, params: R, weight: u32) -> RestResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+        R: serde::Serialize,
+    {
+        let query_string = serde_urlencoded::to_string(&params).map_err(|e| Errors::Error(format!("Failed to encode params: {}", e)))?;
+        let query_string = Some(query_string.as_str()).filter(|query| !query.is_empty());
+        self.send_request(endpoint, reqwest::Method::GET, query_string, None, weight)
+            .await
+    }
+}
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<RequestWeight>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+
+        // Public endpoints take their parameters in the query string
+        let query_string = request
+            .params
+            .as_ref()
+            .map(serde_urlencoded::to_string)
+            .transpose()
+            .map_err(|e| {
+                VenueError::new(
+                    VENUE,
+                    ErrorKind::InvalidRequest,
+                    format!("Failed to encode query parameters: {}", e),
+                )
+            })?;
+        let response = self
+            .send_request::<serde_json::Value>(
+                &request.endpoint,
+                request.method,
+                query_string.as_deref(),
+                None,
+                request.rate_limit_key.weight,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
 }
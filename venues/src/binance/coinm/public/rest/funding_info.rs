@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Funding parameters of a symbol whose funding rate cap, floor or interval was adjusted.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Funding rate cap.
+    pub adjusted_funding_rate_cap: String,
+
+    /// Funding rate floor.
+    pub adjusted_funding_rate_floor: String,
+
+    /// Hours between fundings.
+    pub funding_interval_hours: u32,
+
+    /// Ignored by Binance.
+    pub disclaimer: bool,
+}
+
+impl RestClient {
+    /// Fetches the funding parameters of symbols with an adjusted funding rate cap, floor or interval.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Get-Funding-Rate-Info>
+    /// Corresponds to endpoint GET /dapi/v1/fundingInfo.
+    /// Weight: 1
+    pub async fn get_funding_info(&self) -> RestResult<Vec<FundingInfo>> {
+        self.send_request("/dapi/v1/fundingInfo", reqwest::Method::GET, None, None, 1)
+            .await
+    }
+}
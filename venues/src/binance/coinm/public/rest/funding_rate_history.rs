@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the funding rate history of a perpetual contract.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Start time (ms since epoch), inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch), inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of entries (default 100, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A funding rate that was applied.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Funding time (ms since epoch).
+    pub funding_time: u64,
+
+    /// Funding rate that was applied.
+    pub funding_rate: String,
+}
+
+impl RestClient {
+    /// Fetches the funding rate history of a perpetual contract. Empty for delivery contracts.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Get-Funding-Rate-History-of-Perpetual-Futures>
+    /// Corresponds to endpoint GET /dapi/v1/fundingRate.
+    /// Weight: 1
+    pub async fn get_funding_rate_history(&self, params: FundingRateRequest) -> RestResult<Vec<FundingRate>> {
+        self.send_get_request("/dapi/v1/fundingRate", params, 1)
+            .await
+    }
+}
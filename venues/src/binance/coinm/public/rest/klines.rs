@@ -0,0 +1,141 @@
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::enums::KlineInterval;
+use crate::binance::coinm::public::rest::RestClient;
+use crate::binance::shared::klines_weight;
+
+/// Request parameters for klines of a symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of klines (default 500, max 1500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A kline, sent by Binance as an array.
+///
+/// Price klines (index, mark and premium index) report the volume fields as "0".
+#[derive(Debug, Clone, Deserialize)]
+pub struct Kline {
+    /// Open time (ms since epoch).
+    pub open_time: u64,
+
+    /// Open price.
+    pub open: String,
+
+    /// High price.
+    pub high: String,
+
+    /// Low price.
+    pub low: String,
+
+    /// Close price, or the latest price while the kline is open.
+    pub close: String,
+
+    /// Volume in contracts.
+    pub volume: String,
+
+    /// Close time (ms since epoch).
+    pub close_time: u64,
+
+    /// Volume in the base asset.
+    pub base_volume: String,
+
+    /// Number of trades.
+    pub number_of_trades: u64,
+
+    /// Taker buy volume in contracts.
+    pub taker_buy_volume: String,
+
+    /// Taker buy volume in the base asset.
+    pub taker_buy_base_volume: String,
+
+    /// Unused trailing field
+    _ignore: IgnoredAny,
+}
+
+impl RestClient {
+    /// Fetches klines of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Kline-Candlestick-Data>
+    /// Corresponds to endpoint GET /dapi/v1/klines.
+    /// Weight: 1 below 100 klines, 2 below 500, 5 up to 1000 and 10 above
+    pub async fn get_klines(&self, params: KlinesRequest) -> RestResult<Vec<Kline>> {
+        let weight = klines_weight(params.limit);
+        self.send_get_request("/dapi/v1/klines", params, weight)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::coinm::RateLimiter;
+
+    #[tokio::test]
+    async fn test_klines_are_parsed_from_arrays() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!([[
+                1499040000000_u64,
+                "0.01634790",
+                "0.80000000",
+                "0.01575800",
+                "0.01577100",
+                "148976.11427815",
+                1499644799999_u64,
+                "2434.19055334",
+                308,
+                "1756.87402397",
+                "28.46694368",
+                "0"
+            ]]),
+        );
+        let client = RestClient::new(
+            "https://dapi.binance.com",
+            transport.clone(),
+            RateLimiter::new(),
+        );
+
+        let request = KlinesRequest {
+            symbol: "BTCUSD_PERP".to_string(),
+            interval: KlineInterval::I1h,
+            start_time: None,
+            end_time: None,
+            limit: Some(100),
+        };
+        let response = client.get_klines(request).await.unwrap();
+        let kline = response.data.first().unwrap();
+        assert_eq!(kline.open_time, 1499040000000);
+        assert_eq!(kline.close, "0.01577100");
+        assert_eq!(kline.number_of_trades, 308);
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(
+            sent.query_string(),
+            Some("symbol=BTCUSD_PERP&interval=1h&limit=100")
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::enums::KlineInterval;
+use crate::binance::coinm::public::rest::RestClient;
+use crate::binance::coinm::public::rest::klines::Kline;
+use crate::binance::shared::klines_weight;
+
+/// Request parameters for mark price klines of a symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPriceKlinesRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of klines (default 500, max 1500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl RestClient {
+    /// Fetches mark price klines of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Mark-Price-Kline-Candlestick-Data>
+    /// Corresponds to endpoint GET /dapi/v1/markPriceKlines.
+    /// Weight: same as [`RestClient::get_klines`]
+    pub async fn get_mark_price_klines(&self, params: MarkPriceKlinesRequest) -> RestResult<Vec<Kline>> {
+        let weight = klines_weight(params.limit);
+        self.send_get_request("/dapi/v1/markPriceKlines", params, weight)
+            .await
+    }
+}
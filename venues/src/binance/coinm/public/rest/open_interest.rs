@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::enums::ContractType;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the current open interest of a symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct OpenInterestRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+}
+
+/// Current open interest of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Open interest in contracts.
+    pub open_interest: String,
+
+    /// Contract type, e.g. perpetual or current quarter.
+    pub contract_type: ContractType,
+
+    /// Time of the snapshot (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the current open interest of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Open-Interest>
+    /// Corresponds to endpoint GET /dapi/v1/openInterest.
+    /// Weight: 1
+    pub async fn get_open_interest(&self, params: OpenInterestRequest) -> RestResult<OpenInterest> {
+        self.send_get_request("/dapi/v1/openInterest", params, 1)
+            .await
+    }
+}
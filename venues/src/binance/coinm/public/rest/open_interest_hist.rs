@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::enums::{ContractType, Period};
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the open interest history of a contract type. Only the last 30 days
+/// are available.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHistRequest {
+    /// Pair (e.g., "BTCUSD").
+    pub pair: String,
+
+    /// PERPETUAL, CURRENT_QUARTER or NEXT_QUARTER.
+    pub contract_type: ContractType,
+
+    /// Period of each entry.
+    pub period: Period,
+
+    /// Number of entries (default 30, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+/// Open interest of a contract type at the end of a period.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHist {
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Contract type, e.g. perpetual or current quarter.
+    pub contract_type: ContractType,
+
+    /// Open interest in contracts.
+    pub sum_open_interest: String,
+
+    /// Open interest in the base asset.
+    pub sum_open_interest_value: String,
+
+    /// End of the period (ms since epoch).
+    pub timestamp: u64,
+}
+
+impl RestClient {
+    /// Fetches the open interest history of a contract type of a pair.
+    ///
+    /// The `/futures/data` statistics don't count against the request weight; Binance limits
+    /// them to 1000 requests per 5 minutes per IP instead.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Open-Interest-Statistics>
+    /// Corresponds to endpoint GET /futures/data/openInterestHist.
+    /// Weight: 0
+    pub async fn get_open_interest_hist(&self, params: OpenInterestHistRequest) -> RestResult<Vec<OpenInterestHist>> {
+        self.send_get_request("/futures/data/openInterestHist", params, 0)
+            .await
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+use crate::binance::shared::order_book_weight;
+
+/// Request parameters for the order book.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct OrderBookRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Number of levels per side: 5, 10, 20, 50, 100, 500 or 1000. Default 500.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A price level of the order book.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderBookLevel {
+    /// Price of the level.
+    pub price: String,
+
+    /// Quantity at the level.
+    pub quantity: String,
+}
+
+/// Snapshot of the order book.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderBookResponse {
+    /// Last update ID included in the snapshot, to continue from with the depth stream.
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: u64,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Message output time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    /// Transaction time.
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    /// Bids, best price first.
+    pub bids: Vec<OrderBookLevel>,
+
+    /// Asks, best price first.
+    pub asks: Vec<OrderBookLevel>,
+}
+
+impl RestClient {
+    /// Fetches the order book of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Order-Book>
+    /// Corresponds to endpoint GET /dapi/v1/depth.
+    /// Weight: 2 for up to 50 levels, 5 for 100, 10 for 500 and 20 for 1000
+    pub async fn get_order_book(&self, params: OrderBookRequest) -> RestResult<OrderBookResponse> {
+        let weight = order_book_weight(params.limit);
+        self.send_get_request("/dapi/v1/depth", params, weight)
+            .await
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Empty response of the connectivity test.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PingResponse {}
+
+impl RestClient {
+    /// Tests connectivity to the REST API.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Test-Connectivity>
+    /// Corresponds to endpoint GET /dapi/v1/ping.
+    /// Weight: 1
+    pub async fn ping(&self) -> RestResult<PingResponse> {
+        self.send_request("/dapi/v1/ping", reqwest::Method::GET, None, None, 1)
+            .await
+    }
+}
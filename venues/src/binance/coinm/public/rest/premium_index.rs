@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the mark price and funding rate.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct PremiumIndexRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Pair (e.g., "BTCUSD").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+}
+
+/// Mark price, index price and funding rate of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Mark price.
+    pub mark_price: String,
+
+    /// Index price.
+    pub index_price: String,
+
+    /// Only meaningful for delivery contracts in the last hour before settlement.
+    pub estimated_settle_price: String,
+
+    /// Empty for delivery contracts.
+    pub last_funding_rate: String,
+
+    /// Empty for delivery contracts.
+    pub interest_rate: String,
+
+    /// 0 for delivery contracts.
+    pub next_funding_time: u64,
+
+    /// Time of the snapshot (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the mark price and funding rate of the matching symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Index-Price-and-Mark-Price>
+    /// Corresponds to endpoint GET /dapi/v1/premiumIndex.
+    /// Weight: 10
+    pub async fn get_premium_index(&self, params: PremiumIndexRequest) -> RestResult<Vec<PremiumIndex>> {
+        self.send_get_request("/dapi/v1/premiumIndex", params, 10)
+            .await
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the latest price. Without a symbol or pair, all symbols are returned.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct PriceTickerRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Pair (e.g., "BTCUSD"). Cannot be sent with `symbol`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+}
+
+/// Latest price of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceTicker {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub ps: String,
+
+    /// Latest price.
+    pub price: String,
+
+    /// Time of the price (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the latest price of the matching symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Symbol-Price-Ticker>
+    /// Corresponds to endpoint GET /dapi/v1/ticker/price.
+    /// Weight: 1 for a single symbol, 2 otherwise
+    pub async fn get_price_ticker(&self, params: PriceTickerRequest) -> RestResult<Vec<PriceTicker>> {
+        let weight = if params.symbol.is_some() { 1 } else { 2 };
+        self.send_get_request("/dapi/v1/ticker/price", params, weight)
+            .await
+    }
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use rest::clock::TimeSource;
+use rest::error::{RestError, VenueError};
+use serde::Deserialize;
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTimeResponse {
+    /// Server time in milliseconds since the Unix epoch
+    pub server_time: i64,
+}
+
+impl RestClient {
+    /// Fetches the current server time.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Check-Server-time>
+    /// Corresponds to endpoint GET /dapi/v1/time.
+    /// Weight: 1
+    pub async fn get_server_time(&self) -> RestResult<ServerTimeResponse> {
+        self.send_request("/dapi/v1/time", reqwest::Method::GET, None, None, 1)
+            .await
+    }
+}
+
+#[async_trait]
+impl TimeSource for RestClient {
+    async fn server_time_ms(&self) -> Result<i64, RestError> {
+        let response = self.get_server_time().await.map_err(VenueError::from)?;
+        Ok(response.data.server_time)
+    }
+}
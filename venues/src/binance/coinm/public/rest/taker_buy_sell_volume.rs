@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::enums::{ContractType, Period};
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the taker buy and sell volume of a contract type. Only the last
+/// 30 days are available.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolumeRequest {
+    /// Pair (e.g., "BTCUSD").
+    pub pair: String,
+
+    /// PERPETUAL, CURRENT_QUARTER or NEXT_QUARTER.
+    pub contract_type: ContractType,
+
+    /// Period of each entry.
+    pub period: Period,
+
+    /// Number of entries (default 30, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+/// Taker buy and sell volume of a contract type over a period.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Contract type, e.g. perpetual or current quarter.
+    pub contract_type: ContractType,
+
+    /// Taker buy volume in contracts.
+    pub taker_buy_vol: String,
+
+    /// Taker sell volume in contracts.
+    pub taker_sell_vol: String,
+
+    /// Taker buy volume in the base asset.
+    pub taker_buy_vol_value: String,
+
+    /// Taker sell volume in the base asset.
+    pub taker_sell_vol_value: String,
+
+    /// End of the period (ms since epoch).
+    pub timestamp: u64,
+}
+
+impl RestClient {
+    /// Fetches the taker buy and sell volume of a contract type of a pair.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Taker-Buy-Sell-Volume>
+    /// Corresponds to endpoint GET /futures/data/takerBuySellVol.
+    /// Weight: 0, see [`RestClient::get_open_interest_hist`]
+    pub async fn get_taker_buy_sell_volume(&self, params: TakerBuySellVolumeRequest) -> RestResult<Vec<TakerBuySellVolume>> {
+        self.send_get_request("/futures/data/takerBuySellVol", params, 0)
+            .await
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the 24 hour price change statistics. Without a symbol or pair, all
+/// symbols are returned.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct Ticker24hrRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Pair (e.g., "BTCUSD"). Cannot be sent with `symbol`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+}
+
+/// Price change statistics over the last 24 hours.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hr {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Last price minus the open price.
+    pub price_change: String,
+
+    /// Price change in percent of the open price.
+    pub price_change_percent: String,
+
+    /// Volume weighted average price.
+    pub weighted_avg_price: String,
+
+    /// Price of the last trade.
+    pub last_price: String,
+
+    /// Quantity of the last trade.
+    pub last_qty: String,
+
+    /// Price 24 hours ago.
+    pub open_price: String,
+
+    /// High price.
+    pub high_price: String,
+
+    /// Low price.
+    pub low_price: String,
+
+    /// Volume in contracts.
+    pub volume: String,
+
+    /// Volume in the base asset.
+    pub base_volume: String,
+
+    /// Start of the window (ms since epoch).
+    pub open_time: u64,
+
+    /// End of the window (ms since epoch).
+    pub close_time: u64,
+
+    /// ID of the first trade in the window.
+    pub first_id: i64,
+
+    /// ID of the last trade in the window.
+    pub last_id: i64,
+
+    /// Number of trades in the window.
+    pub count: u64,
+}
+
+impl RestClient {
+    /// Fetches the 24 hour price change statistics of the matching symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/24hr-Ticker-Price-Change-Statistics>
+    /// Corresponds to endpoint GET /dapi/v1/ticker/24hr.
+    /// Weight: 1 for a single symbol, 40 otherwise
+    pub async fn get_ticker_24hr(&self, params: Ticker24hrRequest) -> RestResult<Vec<Ticker24hr>> {
+        let weight = if params.symbol.is_some() { 1 } else { 40 };
+        self.send_get_request("/dapi/v1/ticker/24hr", params, weight)
+            .await
+    }
+}
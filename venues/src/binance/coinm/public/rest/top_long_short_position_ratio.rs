@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::enums::Period;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for the long/short position ratio of the top traders. Only the last
+/// 30 days are available.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopLongShortPositionRatioRequest {
+    /// Pair (e.g., "BTCUSD").
+    pub pair: String,
+
+    /// Period of each entry.
+    pub period: Period,
+
+    /// Number of entries (default 30, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+/// Long/short ratio of the top accounts, weighting accounts by their position.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortPositionRatio {
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Long share divided by short share.
+    pub long_short_ratio: String,
+
+    /// Share of long positions, 0 to 1.
+    pub long_position: String,
+
+    /// Share of short positions, 0 to 1.
+    pub short_position: String,
+
+    /// End of the period (ms since epoch).
+    pub timestamp: u64,
+}
+
+impl RestClient {
+    /// Fetches the long/short ratio of the top 20% of accounts by margin balance, weighting
+    /// accounts by their position.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Top-Trader-Long-Short-Ratio>
+    /// Corresponds to endpoint GET /futures/data/topLongShortPositionRatio.
+    /// Weight: 0, see [`RestClient::get_open_interest_hist`]
+    pub async fn get_top_long_short_position_ratio(&self, params: TopLongShortPositionRatioRequest) -> RestResult<Vec<LongShortPositionRatio>> {
+        self.send_get_request("/futures/data/topLongShortPositionRatio", params, 0)
+            .await
+    }
+}
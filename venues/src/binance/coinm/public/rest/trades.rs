@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::coinm::RestResult;
+use crate::binance::coinm::public::rest::RestClient;
+
+/// Request parameters for recent trades.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct RecentTradesRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A trade filled in the order book.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// Trade ID.
+    pub id: u64,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity in contracts.
+    pub qty: String,
+
+    /// Quantity in the base asset.
+    pub base_qty: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+
+    /// Whether the buyer was the maker.
+    pub is_buyer_maker: bool,
+}
+
+impl RestClient {
+    /// Fetches recent trades of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/coin-margined-futures/market-data/rest-api/Recent-Trades-List>
+    /// Corresponds to endpoint GET /dapi/v1/trades.
+    /// Weight: 5
+    pub async fn get_recent_trades(&self, params: RecentTradesRequest) -> RestResult<Vec<Trade>> {
+        self.send_get_request("/dapi/v1/trades", params, 5).await
+    }
+}
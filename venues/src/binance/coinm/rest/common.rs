@@ -1,7 +1,8 @@
 // Shared REST client logic for Binance Coin-M public and private clients.
 // Handles URL construction, header assembly, request execution, and rate limiter update.
 
-use reqwest::{Client, Method};
+use reqwest::Method;
+use rest::transport::HttpTransport;
 use url::Url;
 
 use crate::binance::coinm::{Errors, RateLimiter, ResponseHeaders, execute_request};
@@ -18,7 +19,7 @@ pub(crate) fn build_url(base_url: &str, endpoint: &str, query: Option<&str>) ->
 
 /// Shared logic for sending a REST request and updating the rate limiter.
 pub(crate) async fn send_rest_request<T>(
-    client: &Client,
+    client: &dyn HttpTransport,
     url: &str,
     method: Method,
     headers: Vec<(&str, String)>,
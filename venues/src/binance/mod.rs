@@ -4,4 +4,6 @@ pub mod portfolio;
 pub mod spot;
 pub mod usdm;
 
+mod shared;
+
 pub use coinm::*;
@@ -98,13 +98,53 @@ impl fmt::Display for RateLimitType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RateLimitInterval {
+    Second,
     Minute,
 }
 
 impl fmt::Display for RateLimitInterval {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            RateLimitInterval::Second => write!(f, "SECOND"),
             RateLimitInterval::Minute => write!(f, "MINUTE"),
         }
     }
 }
+
+/// Exercise result of an expired option (strikeResult)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StrikeResult {
+    /// Exercised, settling the intrinsic value
+    RealisticValueStricken,
+    /// Expired out of the money
+    ExtrinsicValueExpired,
+}
+
+impl fmt::Display for StrikeResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrikeResult::RealisticValueStricken => write!(f, "REALISTIC_VALUE_STRICKEN"),
+            StrikeResult::ExtrinsicValueExpired => write!(f, "EXTRINSIC_VALUE_EXPIRED"),
+        }
+    }
+}
+
+/// Whether a trade added or removed liquidity (liquidity)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionsLiquidity {
+    /// Removed liquidity from the book
+    Taker,
+    /// Added liquidity to the book
+    Maker,
+}
+
+impl fmt::Display for OptionsLiquidity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionsLiquidity::Taker => write!(f, "TAKER"),
+            OptionsLiquidity::Maker => write!(f, "MAKER"),
+        }
+    }
+}
@@ -1,8 +1,16 @@
 use std::fmt;
+use std::time::Duration;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::binance::shared::{SigningError, banned_until};
+
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "binance-options";
+
 /// Represents all possible errors that can occur when interacting with the Binance Options API (EAPI)
 #[derive(Debug)]
 pub enum Errors {
@@ -10,7 +18,7 @@ pub enum Errors {
     InvalidApiKey(),
 
     /// Http error occurred while making a request
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the Binance Options API
     ApiError(ApiError),
@@ -32,6 +40,15 @@ impl fmt::Display for Errors {
 
 impl std::error::Error for Errors {}
 
+impl From<SigningError> for Errors {
+    fn from(err: SigningError) -> Self {
+        match err {
+            SigningError::Encode(msg) => Errors::Error(msg),
+            SigningError::Signer(err) => Errors::Error(format!("Failed to sign request: {}", err)),
+        }
+    }
+}
+
 /// Represents an error response from the Binance Options API.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ErrorResponse {
@@ -96,3 +113,112 @@ impl From<ErrorResponse> for ApiError {
         }
     }
 }
+
+impl ApiError {
+    /// The Binance error code this error was created from, or `None` for errors
+    /// derived from the HTTP status alone
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            ApiError::UnknownApiError { .. } => Some(-1000),
+            ApiError::TooManyRequests { .. } => Some(-1003),
+            ApiError::TooManyOrders { .. } => Some(-1015),
+            ApiError::Unauthorized { .. } => Some(-1002),
+            ApiError::InvalidTimestamp { .. } => Some(-1021),
+            ApiError::InvalidSignature { .. } => Some(-1022),
+            ApiError::UnmappedApiError { code, .. } => Some(*code),
+            ApiError::RateLimitExceeded { .. } | ApiError::IpAutoBanned { .. } => None,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::RateLimitExceeded { retry_after, .. } => ErrorKind::RateLimited {
+                retry_after: retry_after.map(Duration::from_secs),
+            },
+            ApiError::IpAutoBanned { msg } => ErrorKind::IpBanned {
+                until: banned_until(msg),
+            },
+            _ => self
+                .code()
+                .map_or(ErrorKind::Other, |code| error_kind(code, &self.to_string())),
+        }
+    }
+}
+
+/// Classify a Binance error code.
+///
+/// -1003 is also used for IP bans, in which case the message carries the ban expiry.
+fn error_kind(code: i32, msg: &str) -> ErrorKind {
+    match code {
+        -1003 => match banned_until(msg) {
+            Some(until) => ErrorKind::IpBanned { until: Some(until) },
+            None => ErrorKind::RateLimited { retry_after: None },
+        },
+        -1015 => ErrorKind::RateLimited { retry_after: None },
+        -1002 | -1011 | -1021 | -1022 | -2014 | -2015 | -4056 | -4057 => ErrorKind::AuthFailed,
+        -1001 | -1016 => ErrorKind::ExchangeUnavailable,
+        -1000 | -1006 | -1007 => ErrorKind::UnknownExecutionStatus,
+        -2011 | -2013 => ErrorKind::OrderNotFound,
+        -2018 | -2019 | -4050 | -4051 => ErrorKind::InsufficientFunds,
+        -1199..=-1010 | -2099..=-2010 | -4999..=-4000 => ErrorKind::InvalidRequest,
+        _ => ErrorKind::Other,
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(api_error) => {
+                let error = VenueError::new(VENUE, api_error.kind(), api_error.to_string());
+                match api_error.code() {
+                    Some(code) => error.with_code(code),
+                    None => error,
+                }
+            }
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            code: -1022,
+            msg: "Signature for this request is not valid.".to_string(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::AuthFailed);
+        assert_eq!(error.code.as_deref(), Some("-1022"));
+        assert_eq!(error.message, "Signature for this request is not valid.");
+    }
+
+    #[test]
+    fn test_venue_error_rate_limit() {
+        let error: VenueError = Errors::ApiError(ApiError::RateLimitExceeded {
+            msg: "Too many requests".to_string(),
+            used_weight_1m: None,
+            order_count_1m: None,
+            retry_after: Some(5),
+        })
+        .into();
+        assert_eq!(
+            error.kind,
+            ErrorKind::RateLimited {
+                retry_after: Some(Duration::from_secs(5))
+            }
+        );
+    }
+}
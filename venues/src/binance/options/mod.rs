@@ -1,6 +1,7 @@
 //! Binance Options API (EAPI) module
 //!
-//! This module provides rate limiting and error handling for Binance Options API endpoints.
+//! This module provides the public market data and signed trading endpoints of the Binance
+//! Options API, with rate limiting and error handling.
 //! The Options API uses /eapi/v1/ endpoints and has its own rate limiting rules.
 
 use std::time::Duration;
@@ -9,7 +10,7 @@ pub mod errors;
 pub mod rate_limit;
 
 pub use errors::*;
-pub use rate_limit::{IntervalUnit, RateLimitHeader, RateLimitHeaderKind, RateLimitUsage, RateLimiter, ResponseHeaders};
+pub use rate_limit::{IntervalUnit, RateLimitHeader, RateLimitHeaderKind, RateLimitUsage, RateLimiter, RequestWeight, ResponseHeaders};
 
 mod enums;
 
@@ -25,8 +26,46 @@ pub mod rest;
 // Public module
 pub mod public;
 
+// Private module
+pub mod private;
+
+pub use private::PrivateRestClient;
 pub use public::PublicRestClient;
 
+// Re-export the endpoint request and response types
+pub use private::rest::account::*;
+pub use private::rest::batch_orders::*;
+pub use private::rest::bill::*;
+pub use private::rest::cancel_all_orders::*;
+pub use private::rest::cancel_all_orders_by_underlying::*;
+pub use private::rest::cancel_batch_orders::*;
+pub use private::rest::cancel_order::*;
+pub use private::rest::countdown_cancel_all::*;
+pub use private::rest::countdown_cancel_all_heartbeat::*;
+pub use private::rest::exercise_record::*;
+pub use private::rest::history_orders::*;
+pub use private::rest::mmp::*;
+pub use private::rest::open_orders::*;
+pub use private::rest::order::*;
+pub use private::rest::position::*;
+pub use private::rest::query_order::*;
+pub use private::rest::reset_mmp::*;
+pub use private::rest::set_countdown_cancel_all::*;
+pub use private::rest::set_mmp::*;
+pub use private::rest::user_trades::*;
+pub use public::rest::exchange_info::*;
+pub use public::rest::exercise_history::*;
+pub use public::rest::historical_trades::*;
+pub use public::rest::index::*;
+pub use public::rest::klines::*;
+pub use public::rest::mark::*;
+pub use public::rest::open_interest::*;
+pub use public::rest::order_book::*;
+pub use public::rest::ping::*;
+pub use public::rest::server_time::*;
+pub use public::rest::ticker::*;
+pub use public::rest::trades::*;
+
 /// REST response structure for Options API
 #[derive(Debug, Clone)]
 pub struct RestResponse<T> {
@@ -0,0 +1,95 @@
+// Option Account Information (TRADE) endpoint implementation for GET /eapi/v1/account
+// See: <https://developers.binance.com/docs/derivatives/option/account/Option-Account-Information>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for the account information.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Balance of an asset of the options account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    /// Asset name.
+    pub asset: String,
+
+    /// Account balance plus unrealized profit.
+    pub margin_balance: String,
+
+    /// Account equity.
+    pub equity: String,
+
+    /// Margin available for new orders.
+    pub available: String,
+
+    /// Margin locked by open orders and positions.
+    pub locked: String,
+
+    /// Unrealized profit of the positions.
+    #[serde(rename = "unrealizedPNL")]
+    pub unrealized_pnl: String,
+}
+
+/// Aggregated greeks of the positions on an underlying.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountGreek {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Delta of the positions.
+    pub delta: String,
+
+    /// Gamma of the positions.
+    pub gamma: String,
+
+    /// Theta of the positions.
+    pub theta: String,
+
+    /// Vega of the positions.
+    pub vega: String,
+}
+
+/// Balances and greeks of the options account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountResponse {
+    /// Balances by asset.
+    pub asset: Vec<AccountAsset>,
+
+    /// Greeks by underlying.
+    pub greek: Vec<AccountGreek>,
+
+    /// Time of the snapshot (ms since epoch).
+    pub time: u64,
+
+    /// Account risk level (e.g., "NORMAL", "REDUCE_ONLY").
+    pub risk_level: String,
+}
+
+impl RestClient {
+    /// Fetches the balances and greeks of the options account.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/account/Option-Account-Information>
+    /// GET /eapi/v1/account
+    /// Weight: 3
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AccountRequest`])
+    ///
+    /// # Returns
+    /// The [`AccountResponse`] of the account.
+    pub async fn get_account(&self, params: AccountRequest) -> RestResult<AccountResponse> {
+        self.send_signed_request("/eapi/v1/account", reqwest::Method::GET, params, 3, false)
+            .await
+    }
+}
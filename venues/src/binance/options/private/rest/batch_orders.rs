@@ -0,0 +1,72 @@
+// Place Multiple Orders (TRADE) endpoint implementation for POST /eapi/v1/batchOrders
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Place-Multiple-Orders>
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::private::rest::order::{NewOrderRequest, OrderResponse};
+use crate::binance::options::{ErrorResponse, RestResult};
+
+/// Serializes a value as a JSON string for use in URL-encoded form bodies (Binance batch orders)
+pub(crate) fn as_json_string<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(&json)
+}
+
+/// Request type for placing multiple orders (batch).
+///
+/// Each order takes the parameters of a single new order; the `recv_window` of the individual
+/// orders is ignored in favour of the batch's own.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceBatchOrdersRequest {
+    /// List of orders to place (max 10).
+    #[serde(serialize_with = "as_json_string")]
+    pub orders: Vec<NewOrderRequest>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Represents a single response entry for a batch order (either success or error).
+///
+/// Entries are in the same order as the orders in the request.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BatchOrderResult {
+    /// Successful order response.
+    Ok(Box<OrderResponse>),
+    /// Error response for a failed order.
+    Err(ErrorResponse),
+}
+
+impl RestClient {
+    /// Places multiple orders in a single batch.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Place-Multiple-Orders>
+    /// POST /eapi/v1/batchOrders
+    /// Weight: 5 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`PlaceBatchOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`BatchOrderResult`], each representing either a placed order or an error for that order.
+    pub async fn place_batch_orders(&self, params: PlaceBatchOrdersRequest) -> RestResult<Vec<BatchOrderResult>> {
+        self.send_signed_request(
+            "/eapi/v1/batchOrders",
+            reqwest::Method::POST,
+            params,
+            5,
+            true, // is_order
+        )
+        .await
+    }
+}
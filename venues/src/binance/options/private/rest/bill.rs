@@ -0,0 +1,75 @@
+// Account Funding Flow (USER_DATA) endpoint implementation for GET /eapi/v1/bill
+// See: <https://developers.binance.com/docs/derivatives/option/account/Account-Funding-Flow>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for the funding flow of an asset.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BillRequest {
+    /// Asset (e.g., "USDT").
+    pub currency: String,
+
+    /// Record ID to fetch from. Default returns the most recent records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<u64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of records (default 100, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A balance change of the options account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bill {
+    /// Record ID.
+    pub id: u64,
+
+    /// Asset of the change.
+    pub asset: String,
+
+    /// Amount, negative for debits.
+    pub amount: String,
+
+    /// Kind of change (e.g., "FEE", "CONTRACT", "TRANSFER").
+    #[serde(rename = "type")]
+    pub bill_type: String,
+
+    /// Time of the change (ms since epoch).
+    pub create_date: u64,
+}
+
+impl RestClient {
+    /// Fetches the funding flow of an asset of the options account.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/account/Account-Funding-Flow>
+    /// GET /eapi/v1/bill
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`BillRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`Bill`] objects.
+    pub async fn get_bills(&self, params: BillRequest) -> RestResult<Vec<Bill>> {
+        self.send_signed_request("/eapi/v1/bill", reqwest::Method::GET, params, 1, false)
+            .await
+    }
+}
@@ -0,0 +1,55 @@
+// Cancel All Option Orders on a Symbol (TRADE) endpoint implementation for
+// DELETE /eapi/v1/allOpenOrders
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-all-Option-orders-on-specific-symbol>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for cancelling all open orders of an option.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOrdersRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Acknowledgement of a mass cancellation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelAllOrdersResponse {
+    /// 0 on success.
+    pub code: i32,
+
+    /// "success" on success, the error message otherwise.
+    pub msg: String,
+}
+
+impl RestClient {
+    /// Cancels all open orders of an option.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-all-Option-orders-on-specific-symbol>
+    /// DELETE /eapi/v1/allOpenOrders
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelAllOrdersRequest`])
+    ///
+    /// # Returns
+    /// A [`CancelAllOrdersResponse`] acknowledging the cancellation.
+    pub async fn cancel_all_orders(&self, params: CancelAllOrdersRequest) -> RestResult<CancelAllOrdersResponse> {
+        self.send_signed_request(
+            "/eapi/v1/allOpenOrders",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,46 @@
+// Cancel All Option Orders By Underlying (TRADE) endpoint implementation for
+// DELETE /eapi/v1/allOpenOrdersByUnderlying
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-All-Option-Orders-By-Underlying>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::cancel_all_orders::CancelAllOrdersResponse;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for cancelling all open orders on the options of an underlying.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOrdersByUnderlyingRequest {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels all open orders on the options of an underlying.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-All-Option-Orders-By-Underlying>
+    /// DELETE /eapi/v1/allOpenOrdersByUnderlying
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelAllOrdersByUnderlyingRequest`])
+    ///
+    /// # Returns
+    /// A [`CancelAllOrdersResponse`] acknowledging the cancellation.
+    pub async fn cancel_all_orders_by_underlying(&self, params: CancelAllOrdersByUnderlyingRequest) -> RestResult<CancelAllOrdersResponse> {
+        self.send_signed_request(
+            "/eapi/v1/allOpenOrdersByUnderlying",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
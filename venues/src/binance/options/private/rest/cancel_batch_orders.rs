@@ -0,0 +1,115 @@
+// Cancel Multiple Option Orders (TRADE) endpoint implementation for DELETE /eapi/v1/batchOrders
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-Multiple-Option-Orders>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::batch_orders::{BatchOrderResult, as_json_string};
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for cancelling multiple orders of an option.
+/// Either `order_ids` or `client_order_ids` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelBatchOrdersRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Order IDs to cancel (max 10).
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "as_json_string"
+    )]
+    pub order_ids: Option<Vec<u64>>,
+
+    /// Client order IDs to cancel (max 10).
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "as_json_string"
+    )]
+    pub client_order_ids: Option<Vec<String>>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels multiple orders of an option.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-Multiple-Option-Orders>
+    /// DELETE /eapi/v1/batchOrders
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelBatchOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`BatchOrderResult`], each representing either a cancelled order or an error for that order.
+    pub async fn cancel_batch_orders(&self, params: CancelBatchOrdersRequest) -> RestResult<Vec<BatchOrderResult>> {
+        self.send_signed_request(
+            "/eapi/v1/batchOrders",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::options::ErrorResponse;
+    use crate::binance::options::private::rest::client::tests::client;
+    use crate::binance::shared::test_support::signed_params;
+
+    #[tokio::test]
+    async fn test_cancel_batch_sends_ids_as_json_and_splits_results() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!([
+                {
+                    "orderId": 4611875134427365377_u64, "symbol": "BTC-200730-9000-C", "price": "100",
+                    "quantity": "1", "executedQty": "0", "fee": "0", "side": "BUY", "type": "LIMIT",
+                    "timeInForce": "GTC", "reduceOnly": false, "postOnly": false,
+                    "createTime": 1592465880683_u64, "updateTime": 1566818724722_u64, "status": "CANCELLED",
+                    "avgPrice": "0", "clientOrderId": "", "priceScale": 2, "quantityScale": 2,
+                    "optionSide": "CALL", "quoteAsset": "USDT", "mmp": false
+                },
+                {"code": -2011, "msg": "Unknown order sent."}
+            ]),
+        );
+        let client = client(&transport);
+
+        let request = CancelBatchOrdersRequest {
+            symbol: "BTC-200730-9000-C".to_string(),
+            order_ids: Some(vec![4611875134427365377, 4611875134427365378]),
+            ..Default::default()
+        };
+        let response = client.cancel_batch_orders(request).await.unwrap();
+        assert!(matches!(
+            response.data.first(),
+            Some(BatchOrderResult::Ok(order)) if order.status == Some(crate::binance::options::OptionsOrderStatus::Cancelled)
+        ));
+        assert!(matches!(
+            response.data.get(1),
+            Some(BatchOrderResult::Err(ErrorResponse { code: -2011, .. }))
+        ));
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::DELETE);
+        let params = signed_params(sent.body.as_deref().unwrap()).await;
+        assert!(params.contains(&(
+            "orderIds".to_string(),
+            "[4611875134427365377,4611875134427365378]".to_string()
+        )));
+    }
+}
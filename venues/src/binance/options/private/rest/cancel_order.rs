@@ -0,0 +1,47 @@
+// Cancel Option Order (TRADE) endpoint implementation for DELETE /eapi/v1/order
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-Option-Order>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::private::rest::order::OrderResponse;
+
+/// Request parameters for cancelling an order. Either `order_id` or `client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Order ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels an active order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Cancel-Option-Order>
+    /// DELETE /eapi/v1/order
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelOrderRequest`])
+    ///
+    /// # Returns
+    /// The cancelled [`OrderResponse`].
+    pub async fn cancel_order(&self, params: CancelOrderRequest) -> RestResult<OrderResponse> {
+        self.send_signed_request("/eapi/v1/order", reqwest::Method::DELETE, params, 1, false)
+            .await
+    }
+}
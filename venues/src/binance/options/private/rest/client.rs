@@ -0,0 +1,292 @@
+//! Binance Options API request handling module.
+//!
+//! This module provides functionality for making signed HTTP requests to the Binance Options
+//! (EAPI) private endpoints.
+//!
+//! - **Authentication**: Requires API key in `X-MBX-APIKEY` header for authenticated endpoints
+//!
+//! - **Timestamp Requirements**: Signed requests must include a timestamp parameter and signature.
+//!   The timestamp comes from the client's [`ServerClock`], so request structs don't carry one
+//!
+//! - **Request Signing**: Parameters (including timestamp) must be signed using HMAC-SHA256 with
+//!   the API secret. GET requests carry them in the query string, other methods in the form body
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::retry::ClientOrderIdLookup;
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::HttpTransport;
+
+use crate::binance::options::errors::VENUE;
+use crate::binance::options::{RateLimiter, RequestWeight, RestResult};
+use crate::binance::shared::{object_params, signed_payload};
+
+/// A client for interacting with the Binance Options private REST API
+///
+/// This client handles encrypted API keys and secrets for enhanced security.
+/// The API key and secret are stored in encrypted form and only decrypted when needed.
+#[non_exhaustive]
+pub struct RestClient {
+    /// The underlying HTTP client used for making requests.
+    pub(crate) client: Arc<dyn HttpTransport>,
+    /// The rate limiter for this client.
+    pub(crate) rate_limiter: RateLimiter,
+    /// The encrypted API key.
+    pub(crate) api_key: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
+    /// The base URL for the API.
+    pub(crate) base_url: Cow<'static, str>,
+    /// The server clock signed requests are timestamped with.
+    pub(crate) clock: ServerClock,
+}
+
+impl RestClient {
+    /// Creates a new RestClient with encrypted API credentials
+    ///
+    /// # Arguments
+    /// * `api_key` - The encrypted API key
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
+    /// * `base_url` - The base URL for the API (e.g., "<https://eapi.binance.com>")
+    /// * `rate_limiter` - The rate limiter shared with the venue's other clients
+    /// * `client` - The HTTP transport
+    ///
+    /// # Returns
+    /// A new RestClient instance
+    pub fn new(
+        api_key: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
+        base_url: impl Into<Cow<'static, str>>,
+        rate_limiter: RateLimiter,
+        client: impl HttpTransport + 'static,
+    ) -> Self {
+        Self {
+            client: Arc::new(client),
+            rate_limiter,
+            api_key,
+            api_secret,
+            base_url: base_url.into(),
+            clock: ServerClock::default(),
+        }
+    }
+
+    /// Takes the `timestamp` of signed requests from `clock` instead of the local clock
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sends a request to the Binance API
+    ///
+    /// This method encapsulates all the logic for making authenticated requests to the Binance API,
+    /// including rate limiting, error handling, and response parsing.
+    ///
+    /// # Arguments
+    /// * `endpoint` - The API endpoint path (e.g., "/eapi/v1/order")
+    /// * `method` - The HTTP method to use
+    /// * `query_string` - Optional query string parameters (for GET or for URL params)
+    /// * `body` - Optional x-www-form-urlencoded body (for POST/PUT/DELETE)
+    /// * `weight` - The request weight for this endpoint
+    /// * `is_order` - Whether this is an order-related endpoint
+    ///
+    /// # Returns
+    /// A result containing the parsed response data and metadata, or an error
+    pub(super) async fn send_request<T>(
+        &self,
+        endpoint: &str,
+        method: reqwest::Method,
+        query_string: Option<&str>,
+        body: Option<&str>,
+        weight: u32,
+        is_order: bool,
+    ) -> RestResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = crate::binance::options::rest::common::build_url(&self.base_url, endpoint, query_string)?;
+        let mut headers = vec![];
+        if !self.api_key.expose_secret().is_empty() {
+            headers.push(("X-MBX-APIKEY", self.api_key.expose_secret()));
+        }
+        if body.is_some() {
+            headers.push((
+                "Content-Type",
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+        let rest_response = crate::binance::options::rest::common::send_rest_request(
+            self.client.as_ref(),
+            &url,
+            method,
+            headers,
+            body,
+            &self.rate_limiter,
+            weight,
+            is_order,
+        )
+        .await?;
+        Ok(crate::binance::options::RestResponse {
+            data: rest_response.data,
+            request_duration: rest_response.request_duration,
+            headers: rest_response.headers,
+        })
+    }
+
+    /// Sends a signed request to the Binance API
+    ///
+    /// This method automatically handles timestamp generation and request signing for private endpoints.
+    /// It stamps the request with the server clock's time, adds a `recvWindow` once the clock is
+    /// synced, and generates the required signature.
+    ///
+    /// # Arguments
+    /// * `endpoint` - The API endpoint path (e.g., "/eapi/v1/order")
+    /// * `method` - The HTTP method to use
+    /// * `request` - The request parameters, sent in the query string for GET and as the form body otherwise
+    /// * `weight` - The request weight for this endpoint
+    /// * `is_order` - Whether this is an order-related endpoint
+    ///
+    /// # Returns
+    /// A result containing the parsed response data and metadata, or an error
+    pub(super) async fn send_signed_request<T, R>(&self, endpoint: &str, method: reqwest::Method, request: R, weight: u32, is_order: bool) -> RestResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+        R: serde::Serialize,
+    {
+        let signed = signed_payload(&self.clock, self.api_secret.as_ref(), &request).await?;
+        if method == reqwest::Method::GET {
+            self.send_request(endpoint, method, Some(&signed), None, weight, is_order)
+                .await
+        } else {
+            self.send_request(endpoint, method, None, Some(&signed), weight, is_order)
+                .await
+        }
+    }
+}
+
+impl RestClient {
+    /// Looks up orders placed with `POST /eapi/v1/order` by their `clientOrderId`, so a
+    /// [`rest::retry::RetryingClient`] can resolve orders whose outcome is unknown
+    pub fn order_reconciler() -> ClientOrderIdLookup<RequestWeight> {
+        ClientOrderIdLookup {
+            place_endpoint: "/eapi/v1/order".to_string(),
+            id_param: "clientOrderId".to_string(),
+            query_method: reqwest::Method::GET,
+            query_endpoint: "/eapi/v1/order".to_string(),
+            query_id_param: "clientOrderId".to_string(),
+            copied_params: vec!["symbol".to_string()],
+            query_rate_limit_key: RequestWeight::new(1),
+        }
+    }
+}
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Signs every request. `send_signed_request` stamps the `timestamp` from the server clock.
+    async fn send(&self, request: RestRequest<RequestWeight>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let params = object_params(VENUE, request.params)?;
+        let response = self
+            .send_signed_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                params,
+                request.rate_limit_key.weight,
+                request.rate_limit_key.is_order,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use reqwest::Method;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::shared::test_support::{self, API_KEY, API_SECRET, TestSecret};
+
+    pub(in crate::binance::options) fn client(transport: &MockTransport) -> RestClient {
+        RestClient::new(
+            Box::new(TestSecret(API_KEY)),
+            Box::new(TestSecret(API_SECRET)),
+            "https://eapi.binance.com",
+            RateLimiter::new(),
+            transport.clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_is_signed_in_query_string() {
+        let transport = MockTransport::new();
+        let request = RestRequest::new(Method::GET, "/eapi/v1/openOrders", RequestWeight::new(1)).with_params(json!({ "symbol": "BTC-200730-9000-C" }));
+
+        let params = test_support::send_signed_get(&client(&transport), &transport, request).await;
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["symbol", "timestamp"]);
+    }
+
+    #[tokio::test]
+    async fn test_post_is_signed_in_form_body() {
+        let transport = MockTransport::new();
+        let request = RestRequest::new(Method::POST, "/eapi/v1/order", RequestWeight::order(0)).with_params(json!({
+            "symbol": "BTC-200730-9000-C",
+            "side": "BUY",
+            "type": "LIMIT",
+            "price": "100",
+            "quantity": "0.01",
+            "recvWindow": 3000,
+        }));
+
+        let (sent, params) = test_support::send_signed_form(&client(&transport), &transport, request).await;
+        assert_eq!(sent.url, "https://eapi.binance.com/eapi/v1/order");
+        assert!(params.contains(&("quantity".to_string(), "0.01".to_string())));
+        assert!(params.contains(&("recvWindow".to_string(), "3000".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_order_outcome_is_looked_up_by_client_order_id() {
+        let transport = MockTransport::new();
+        let request = RestRequest::new(Method::POST, "/eapi/v1/order", RequestWeight::order(0)).with_params(json!({
+            "symbol": "BTC-200730-9000-C",
+            "side": "BUY",
+            "type": "LIMIT",
+            "price": "100",
+            "quantity": "0.01",
+            "clientOrderId": "my-order-1",
+        }));
+
+        let params = test_support::look_up_unknown_order(
+            client(&transport),
+            &transport,
+            RestClient::order_reconciler(),
+            request,
+        )
+        .await;
+        assert_eq!(
+            params.first(),
+            Some(&("clientOrderId".to_string(), "my-order-1".to_string()))
+        );
+    }
+}
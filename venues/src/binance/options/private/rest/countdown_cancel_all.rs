@@ -0,0 +1,57 @@
+// Get Auto-Cancel All Open Orders Config (TRADE) endpoint implementation for
+// GET /eapi/v1/countdownCancelAll
+// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Get-Auto-Cancel-All-Open-Orders-Config>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for reading the auto-cancel countdown.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAllRequest {
+    /// Underlying (e.g., "BTCUSDT"). All underlyings are returned if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub underlying: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Auto-cancel countdown shared by underlyings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAllConfig {
+    /// Underlyings sharing the countdown.
+    pub underlyings: Vec<String>,
+
+    /// Countdown in milliseconds.
+    pub countdown_time: u64,
+}
+
+impl RestClient {
+    /// Fetches the auto-cancel countdown config.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Get-Auto-Cancel-All-Open-Orders-Config>
+    /// GET /eapi/v1/countdownCancelAll
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CountdownCancelAllRequest`])
+    ///
+    /// # Returns
+    /// The [`CountdownCancelAllConfig`] of the account.
+    pub async fn get_countdown_cancel_all(&self, params: CountdownCancelAllRequest) -> RestResult<CountdownCancelAllConfig> {
+        self.send_signed_request(
+            "/eapi/v1/countdownCancelAll",
+            reqwest::Method::GET,
+            params,
+            10,
+            false,
+        )
+        .await
+    }
+}
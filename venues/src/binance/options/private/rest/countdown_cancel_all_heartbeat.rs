@@ -0,0 +1,52 @@
+// Auto-Cancel All Open Orders Heartbeat (TRADE) endpoint implementation for
+// POST /eapi/v1/countdownCancelAllHeartBeat
+// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Auto-Cancel-All-Open-Orders-Heartbeat>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for the auto-cancel heartbeat.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAllHeartbeatRequest {
+    /// Comma-separated underlyings (e.g., "BTCUSDT,ETHUSDT").
+    pub underlyings: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Underlyings whose countdown the heartbeat restarted.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CountdownCancelAllHeartbeat {
+    /// Underlyings whose countdown was restarted.
+    pub underlyings: Vec<String>,
+}
+
+impl RestClient {
+    /// Restarts the auto-cancel countdown of underlyings.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Auto-Cancel-All-Open-Orders-Heartbeat>
+    /// POST /eapi/v1/countdownCancelAllHeartBeat
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CountdownCancelAllHeartbeatRequest`])
+    ///
+    /// # Returns
+    /// The [`CountdownCancelAllHeartbeat`] listing the restarted underlyings.
+    pub async fn countdown_cancel_all_heartbeat(&self, params: CountdownCancelAllHeartbeatRequest) -> RestResult<CountdownCancelAllHeartbeat> {
+        self.send_signed_request(
+            "/eapi/v1/countdownCancelAllHeartBeat",
+            reqwest::Method::POST,
+            params,
+            10,
+            false,
+        )
+        .await
+    }
+}
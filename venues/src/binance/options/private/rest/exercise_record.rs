@@ -0,0 +1,104 @@
+// User Exercise Record (USER_DATA) endpoint implementation for GET /eapi/v1/exerciseRecord
+// See: <https://developers.binance.com/docs/derivatives/option/account/User-Exercise-Record>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::{OptionsContractType, OptionsPositionSide, RestResult};
+
+/// Request parameters for the exercise records of the account.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecordRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C"). All options are returned if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of records (default 1000, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Exercise of a position at expiry.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecord {
+    /// Record ID.
+    pub id: String,
+
+    /// Asset the exercise settled in.
+    pub currency: String,
+
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Settlement price of the underlying.
+    pub exercise_price: String,
+
+    /// Mark price of the option at expiry.
+    pub mark_price: String,
+
+    /// Quantity exercised.
+    pub quantity: String,
+
+    /// Amount settled.
+    pub amount: String,
+
+    /// Exercise fee.
+    pub fee: String,
+
+    /// Exercise time (ms since epoch).
+    pub create_date: u64,
+
+    /// Price precision.
+    pub price_scale: u32,
+
+    /// Quantity precision.
+    pub quantity_scale: u32,
+
+    /// Whether the option is a call or a put.
+    pub option_side: OptionsContractType,
+
+    /// Side of the exercised position.
+    pub position_side: OptionsPositionSide,
+
+    /// Quote asset (e.g., "USDT").
+    pub quote_asset: String,
+}
+
+impl RestClient {
+    /// Fetches the exercise records of the account.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/account/User-Exercise-Record>
+    /// GET /eapi/v1/exerciseRecord
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ExerciseRecordRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`ExerciseRecord`] objects.
+    pub async fn get_exercise_record(&self, params: ExerciseRecordRequest) -> RestResult<Vec<ExerciseRecord>> {
+        self.send_signed_request(
+            "/eapi/v1/exerciseRecord",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
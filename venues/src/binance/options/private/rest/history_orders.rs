@@ -0,0 +1,62 @@
+// Query Option Order History (TRADE) endpoint implementation for GET /eapi/v1/historyOrders
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Query-Option-Order-History>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::private::rest::order::OrderResponse;
+
+/// Request parameters for the order history of an option.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryOrdersRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Only return orders from this order ID on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch). Default 7 days ago.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch). Default now.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of orders (default 100, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches filled and cancelled orders of an option. Cancelled orders without fills are only
+    /// kept for 5 days.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Query-Option-Order-History>
+    /// GET /eapi/v1/historyOrders
+    /// Weight: 3
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`HistoryOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`OrderResponse`] objects.
+    pub async fn get_history_orders(&self, params: HistoryOrdersRequest) -> RestResult<Vec<OrderResponse>> {
+        self.send_signed_request(
+            "/eapi/v1/historyOrders",
+            reqwest::Method::GET,
+            params,
+            3,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,64 @@
+// Get Market Maker Protection Config (TRADE) endpoint implementation for GET /eapi/v1/mmp
+// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Get-Market-Maker-Protection-Config>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for reading the market maker protection config of an underlying.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MmpRequest {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Market maker protection config of an underlying.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MmpConfig {
+    /// Underlying ID.
+    pub underlying_id: u64,
+
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Window over which fills are counted.
+    pub window_time_in_milliseconds: u64,
+
+    /// How long orders are blocked once protection triggers, 0 to block until reset.
+    pub frozen_time_in_milliseconds: u64,
+
+    /// Quantity limit, in contracts.
+    pub qty_limit: String,
+
+    /// Net delta limit.
+    pub delta_limit: String,
+
+    /// Time protection last triggered (ms since epoch), 0 if it never did.
+    pub last_trigger_time: u64,
+}
+
+impl RestClient {
+    /// Fetches the market maker protection config of an underlying.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Get-Market-Maker-Protection-Config>
+    /// GET /eapi/v1/mmp
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MmpRequest`])
+    ///
+    /// # Returns
+    /// The [`MmpConfig`] of the underlying.
+    pub async fn get_mmp(&self, params: MmpRequest) -> RestResult<MmpConfig> {
+        self.send_signed_request("/eapi/v1/mmp", reqwest::Method::GET, params, 1, false)
+            .await
+    }
+}
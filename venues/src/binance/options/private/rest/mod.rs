@@ -0,0 +1,25 @@
+// Private REST endpoints module for Binance Options
+
+pub mod account;
+pub mod batch_orders;
+pub mod bill;
+pub mod cancel_all_orders;
+pub mod cancel_all_orders_by_underlying;
+pub mod cancel_batch_orders;
+pub mod cancel_order;
+pub mod client;
+pub mod countdown_cancel_all;
+pub mod countdown_cancel_all_heartbeat;
+pub mod exercise_record;
+pub mod history_orders;
+pub mod mmp;
+pub mod open_orders;
+pub mod order;
+pub mod position;
+pub mod query_order;
+pub mod reset_mmp;
+pub mod set_countdown_cancel_all;
+pub mod set_mmp;
+pub mod user_trades;
+
+pub use client::RestClient;
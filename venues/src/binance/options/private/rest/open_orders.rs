@@ -0,0 +1,60 @@
+// Query Current Open Option Orders (USER_DATA) endpoint implementation for GET /eapi/v1/openOrders
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Query-Current-Open-Option-Orders>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::private::rest::order::OrderResponse;
+
+/// Request parameters for the open orders.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    /// If not sent, will return orders for all symbols at a much higher weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Only return orders from this order ID on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the open orders of an option, or of all options.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Query-Current-Open-Option-Orders>
+    /// GET /eapi/v1/openOrders
+    /// Weight: 1 for a single symbol, 40 when the symbol is omitted
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`OpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`OrderResponse`] objects.
+    pub async fn get_open_orders(&self, params: OpenOrdersRequest) -> RestResult<Vec<OrderResponse>> {
+        let weight = if params.symbol.is_some() { 1 } else { 40 };
+        self.send_signed_request(
+            "/eapi/v1/openOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,227 @@
+// New Order (TRADE) endpoint implementation for POST /eapi/v1/order
+// See: <https://developers.binance.com/docs/derivatives/option/trade/New-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::{OptionsContractType, OptionsOrderStatus, OptionsOrderType, OrderResponseType, OrderSide, RestResult, TimeInForce};
+
+/// Request parameters for placing a new order (POST /eapi/v1/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Order type, only LIMIT is supported.
+    #[serde(rename = "type")]
+    pub order_type: OptionsOrderType,
+
+    /// Order quantity in contracts.
+    pub quantity: String,
+
+    /// Order price.
+    pub price: String,
+
+    /// Time in force (GTC, IOC, FOK). Default GTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Only reduce the position (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// Only add liquidity, rejecting the order if it would match (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+
+    /// New order response type (ACK or RESULT). Default ACK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Unique client order ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+
+    /// Whether the order is subject to market maker protection (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mmp: Option<bool>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// An options order.
+///
+/// The ACK response type of a new order leaves out the execution details, so those fields are
+/// optional.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponse {
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Order price.
+    pub price: String,
+
+    /// Order quantity in contracts.
+    pub quantity: String,
+
+    /// Quantity filled so far.
+    pub executed_qty: Option<String>,
+
+    /// Fee paid so far.
+    pub fee: Option<String>,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: OptionsOrderType,
+
+    /// Time in force.
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Whether the order only reduces the position.
+    pub reduce_only: bool,
+
+    /// Whether the order only adds liquidity.
+    pub post_only: bool,
+
+    /// Time the order was placed (ms since epoch).
+    pub create_time: u64,
+
+    /// Last update of the order (ms since epoch).
+    pub update_time: Option<u64>,
+
+    /// Order status.
+    pub status: Option<OptionsOrderStatus>,
+
+    /// Average fill price.
+    pub avg_price: Option<String>,
+
+    /// Client order ID, empty if none was sent.
+    pub client_order_id: String,
+
+    /// Price precision.
+    pub price_scale: Option<u32>,
+
+    /// Quantity precision.
+    pub quantity_scale: Option<u32>,
+
+    /// Whether the option is a call or a put.
+    pub option_side: Option<OptionsContractType>,
+
+    /// Quote asset (e.g., "USDT").
+    pub quote_asset: Option<String>,
+
+    /// Whether the order is subject to market maker protection.
+    pub mmp: bool,
+}
+
+impl RestClient {
+    /// Places a new order (TRADE) on Binance Options.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/New-Order>
+    /// POST /eapi/v1/order
+    /// Weight: 0 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewOrderRequest`])
+    ///
+    /// # Returns
+    /// An [`OrderResponse`] with as much detail as the response type asks for.
+    pub async fn post_order(&self, params: NewOrderRequest) -> RestResult<OrderResponse> {
+        self.send_signed_request(
+            "/eapi/v1/order",
+            reqwest::Method::POST,
+            params,
+            0,
+            true, // is_order
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::options::private::rest::client::tests::client;
+    use crate::binance::shared::test_support::signed_params;
+
+    #[tokio::test]
+    async fn test_new_order_is_signed_in_body_and_ack_is_parsed() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "orderId": 4611875134427365377_u64,
+                "symbol": "BTC-200730-9000-C",
+                "price": "100",
+                "quantity": "1",
+                "side": "BUY",
+                "type": "LIMIT",
+                "createDate": 1592465880683_u64,
+                "createTime": 1592465880683_u64,
+                "reduceOnly": false,
+                "postOnly": false,
+                "mmp": true,
+                "clientOrderId": "my-order-1"
+            }),
+        );
+        let client = client(&transport);
+
+        let request = NewOrderRequest {
+            symbol: "BTC-200730-9000-C".to_string(),
+            side: OrderSide::Buy,
+            order_type: OptionsOrderType::Limit,
+            quantity: "1".to_string(),
+            price: "100".to_string(),
+            time_in_force: Some(TimeInForce::GTC),
+            reduce_only: None,
+            post_only: Some(false),
+            new_order_resp_type: None,
+            client_order_id: Some("my-order-1".to_string()),
+            is_mmp: Some(true),
+            recv_window: None,
+        };
+        let response = client.post_order(request).await.unwrap();
+        assert_eq!(response.data.order_id, 4611875134427365377);
+        assert!(response.data.mmp);
+        assert!(response.data.status.is_none());
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::POST);
+        assert_eq!(sent.url, "https://eapi.binance.com/eapi/v1/order");
+        let params = signed_params(sent.body.as_deref().unwrap()).await;
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "symbol",
+                "side",
+                "type",
+                "quantity",
+                "price",
+                "timeInForce",
+                "postOnly",
+                "clientOrderId",
+                "isMmp",
+                "timestamp"
+            ]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+// Option Position Information (USER_DATA) endpoint implementation for GET /eapi/v1/position
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Option-Position-Information>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::{OptionsContractType, OptionsPositionSide, RestResult};
+
+/// Request parameters for the positions.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C"). All positions are returned if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A position in an option.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    /// Average entry price.
+    pub entry_price: String,
+
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Long or short.
+    pub side: OptionsPositionSide,
+
+    /// Position quantity, negative for short positions.
+    pub quantity: String,
+
+    /// Quantity that can be reduced.
+    pub reducible_qty: String,
+
+    /// Position value at the mark price.
+    pub mark_value: String,
+
+    /// Rate of return.
+    pub ror: String,
+
+    /// Unrealized profit.
+    #[serde(rename = "unrealizedPNL")]
+    pub unrealized_pnl: String,
+
+    /// Mark price.
+    pub mark_price: String,
+
+    /// Strike price.
+    pub strike_price: String,
+
+    /// Cost of opening the position.
+    pub position_cost: String,
+
+    /// Expiry time (ms since epoch).
+    pub expiry_date: u64,
+
+    /// Price precision.
+    pub price_scale: u32,
+
+    /// Quantity precision.
+    pub quantity_scale: u32,
+
+    /// Whether the option is a call or a put.
+    pub option_side: OptionsContractType,
+
+    /// Quote asset (e.g., "USDT").
+    pub quote_asset: String,
+}
+
+impl RestClient {
+    /// Fetches the positions in an option, or in all options.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Option-Position-Information>
+    /// GET /eapi/v1/position
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`PositionRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`Position`] objects.
+    pub async fn get_positions(&self, params: PositionRequest) -> RestResult<Vec<Position>> {
+        self.send_signed_request("/eapi/v1/position", reqwest::Method::GET, params, 5, false)
+            .await
+    }
+}
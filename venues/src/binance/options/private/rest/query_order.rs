@@ -0,0 +1,48 @@
+// Query Single Order (TRADE) endpoint implementation for GET /eapi/v1/order
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Query-Single-Order>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::private::rest::order::OrderResponse;
+
+/// Request parameters for querying an order. Either `order_id` or `client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Order ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Queries an order. Orders cancelled or filled over 5 days ago and cancelled orders without
+    /// fills over 3 hours ago are no longer returned.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Query-Single-Order>
+    /// GET /eapi/v1/order
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`QueryOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`OrderResponse`] of the order.
+    pub async fn get_order(&self, params: QueryOrderRequest) -> RestResult<OrderResponse> {
+        self.send_signed_request("/eapi/v1/order", reqwest::Method::GET, params, 1, false)
+            .await
+    }
+}
@@ -0,0 +1,39 @@
+// Reset Market Maker Protection Config (TRADE) endpoint implementation for POST /eapi/v1/mmpReset
+// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Reset-Market-Maker-Protection-Config>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::private::rest::mmp::MmpConfig;
+
+/// Request parameters for resetting the market maker protection of an underlying.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetMmpRequest {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Resets triggered market maker protection of an underlying, unblocking its orders.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Reset-Market-Maker-Protection-Config>
+    /// POST /eapi/v1/mmpReset
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ResetMmpRequest`])
+    ///
+    /// # Returns
+    /// The [`MmpConfig`] of the underlying.
+    pub async fn reset_mmp(&self, params: ResetMmpRequest) -> RestResult<MmpConfig> {
+        self.send_signed_request("/eapi/v1/mmpReset", reqwest::Method::POST, params, 1, false)
+            .await
+    }
+}
@@ -0,0 +1,60 @@
+// Set Auto-Cancel All Open Orders Config (TRADE) endpoint implementation for
+// POST /eapi/v1/countdownCancelAll
+// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Set-Auto-Cancel-All-Open-Orders-Config>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+
+/// Request parameters for setting the auto-cancel countdown of an underlying.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCountdownCancelAllRequest {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Countdown in milliseconds, at least 5000. 0 disables auto-cancel.
+    pub countdown_time: u64,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Auto-cancel countdown of an underlying.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Countdown in milliseconds, 0 when auto-cancel is disabled.
+    pub countdown_time: u64,
+}
+
+impl RestClient {
+    /// Sets the auto-cancel countdown of an underlying: unless a heartbeat arrives before the
+    /// countdown runs out, all open orders on the underlying are cancelled.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Set-Auto-Cancel-All-Open-Orders-Config>
+    /// POST /eapi/v1/countdownCancelAll
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`SetCountdownCancelAllRequest`])
+    ///
+    /// # Returns
+    /// The resulting [`CountdownCancelAll`].
+    pub async fn set_countdown_cancel_all(&self, params: SetCountdownCancelAllRequest) -> RestResult<CountdownCancelAll> {
+        self.send_signed_request(
+            "/eapi/v1/countdownCancelAll",
+            reqwest::Method::POST,
+            params,
+            10,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,101 @@
+// Set Market Maker Protection Config (TRADE) endpoint implementation for POST /eapi/v1/mmpSet
+// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Set-Market-Maker-Protection-Config>
+
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::private::rest::mmp::MmpConfig;
+
+/// Request parameters for setting the market maker protection of an underlying.
+///
+/// Protection triggers once the filled quantity or delta of orders sent with `is_mmp` exceeds
+/// the limits within the window, and then blocks such orders for the frozen time.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMmpRequest {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Window over which fills are counted, at most 5000 ms.
+    pub window_time_in_milliseconds: u64,
+
+    /// How long orders are blocked once protection triggers, 0 to block until reset.
+    pub frozen_time_in_milliseconds: u64,
+
+    /// Quantity limit, in contracts.
+    pub qty_limit: String,
+
+    /// Net delta limit.
+    pub delta_limit: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Sets the market maker protection config of an underlying.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-maker-endpoints/Set-Market-Maker-Protection-Config>
+    /// POST /eapi/v1/mmpSet
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`SetMmpRequest`])
+    ///
+    /// # Returns
+    /// The resulting [`MmpConfig`].
+    pub async fn set_mmp(&self, params: SetMmpRequest) -> RestResult<MmpConfig> {
+        self.send_signed_request("/eapi/v1/mmpSet", reqwest::Method::POST, params, 1, false)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::options::private::rest::client::tests::client;
+    use crate::binance::shared::test_support::signed_params;
+
+    #[tokio::test]
+    async fn test_set_mmp_sends_limits_in_body() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "underlyingId": 2,
+                "underlying": "BTCUSDT",
+                "windowTimeInMilliseconds": 3000,
+                "frozenTimeInMilliseconds": 300000,
+                "qtyLimit": "2",
+                "deltaLimit": "2.3",
+                "lastTriggerTime": 0
+            }),
+        );
+        let client = client(&transport);
+
+        let request = SetMmpRequest {
+            underlying: "BTCUSDT".to_string(),
+            window_time_in_milliseconds: 3000,
+            frozen_time_in_milliseconds: 300000,
+            qty_limit: "2".to_string(),
+            delta_limit: "2.3".to_string(),
+            recv_window: None,
+        };
+        let response = client.set_mmp(request).await.unwrap();
+        assert_eq!(response.data.delta_limit, "2.3");
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::POST);
+        assert_eq!(sent.url, "https://eapi.binance.com/eapi/v1/mmpSet");
+        let params = signed_params(sent.body.as_deref().unwrap()).await;
+        assert!(params.contains(&("windowTimeInMilliseconds".to_string(), "3000".to_string())));
+        assert!(params.contains(&("qtyLimit".to_string(), "2".to_string())));
+    }
+}
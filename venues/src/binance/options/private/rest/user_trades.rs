@@ -0,0 +1,118 @@
+// Account Trade List (USER_DATA) endpoint implementation for GET /eapi/v1/userTrades
+// See: <https://developers.binance.com/docs/derivatives/option/trade/Account-Trade-List>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::private::rest::client::RestClient;
+use crate::binance::options::{OptionsContractType, OptionsLiquidity, OptionsOrderType, OrderSide, RestResult};
+
+/// Request parameters for the trades of the account.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C"). All options are returned if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Trade ID to fetch from. Default returns the most recent trades.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of trades (default 100, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A trade of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    /// ID of the trade on the option.
+    pub id: u64,
+
+    /// Trade ID.
+    pub trade_id: u64,
+
+    /// ID of the order that traded.
+    pub order_id: u64,
+
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity in contracts.
+    pub quantity: String,
+
+    /// Fee paid, negative for rebates.
+    pub fee: String,
+
+    /// Profit realized by the trade.
+    pub realized_profit: String,
+
+    /// Side of the account.
+    pub side: OrderSide,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: OptionsOrderType,
+
+    /// Implied volatility of the trade price.
+    pub volatility: String,
+
+    /// Whether the account was maker or taker.
+    pub liquidity: OptionsLiquidity,
+
+    /// Quote asset (e.g., "USDT").
+    pub quote_asset: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+
+    /// Price precision.
+    pub price_scale: u32,
+
+    /// Quantity precision.
+    pub quantity_scale: u32,
+
+    /// Whether the option is a call or a put.
+    pub option_side: OptionsContractType,
+}
+
+impl RestClient {
+    /// Fetches the trades of the account.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/trade/Account-Trade-List>
+    /// GET /eapi/v1/userTrades
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`UserTradesRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`UserTrade`] objects.
+    pub async fn get_user_trades(&self, params: UserTradesRequest) -> RestResult<Vec<UserTrade>> {
+        self.send_signed_request(
+            "/eapi/v1/userTrades",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
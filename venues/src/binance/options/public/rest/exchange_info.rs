@@ -0,0 +1,257 @@
+use serde::Deserialize;
+
+use crate::binance::options::public::rest::RestClient;
+use crate::binance::options::{OptionsContractType, RateLimitInterval, RateLimitType, RestResult};
+
+/// An underlying options are listed on.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    /// Base asset of the underlying.
+    pub base_asset: String,
+
+    /// Quote asset of the underlying.
+    pub quote_asset: String,
+
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Asset the options settle in.
+    pub settle_asset: String,
+}
+
+/// An asset options settle in.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OptionAsset {
+    /// Asset name.
+    pub name: String,
+}
+
+/// Limits on the price of orders.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceFilter {
+    /// Lowest price.
+    pub min_price: String,
+    /// Highest price.
+    pub max_price: String,
+    /// Price increment.
+    pub tick_size: String,
+}
+
+/// Limits on the quantity of orders.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LotSizeFilter {
+    /// Lowest quantity.
+    pub min_qty: String,
+    /// Highest quantity.
+    pub max_qty: String,
+    /// Quantity increment.
+    pub step_size: String,
+}
+
+/// A trading rule of an option.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "filterType")]
+pub enum Filter {
+    /// Price range and tick size
+    #[serde(rename = "PRICE_FILTER")]
+    PriceFilter(PriceFilter),
+
+    /// Quantity range and step size
+    #[serde(rename = "LOT_SIZE")]
+    LotSize(LotSizeFilter),
+
+    /// A filter this client does not know
+    #[serde(other)]
+    Unknown,
+}
+
+/// An option listed for trading.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    /// Option symbol (e.g., "BTC-220815-50000-C").
+    pub symbol: String,
+
+    /// Expiry time (ms since epoch).
+    pub expiry_date: u64,
+
+    /// Trading rules orders must pass.
+    pub filters: Vec<Filter>,
+
+    /// Whether the option is a call or a put.
+    pub side: OptionsContractType,
+
+    /// Strike price.
+    pub strike_price: String,
+
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+
+    /// Contract unit, the quantity of the underlying one contract represents.
+    pub unit: u32,
+
+    /// Fee rate when trading as maker.
+    pub maker_fee_rate: String,
+
+    /// Fee rate when trading as taker.
+    pub taker_fee_rate: String,
+
+    /// Minimum order quantity.
+    pub min_qty: String,
+
+    /// Maximum order quantity.
+    pub max_qty: String,
+
+    /// Initial margin rate.
+    pub initial_margin: String,
+
+    /// Maintenance margin rate.
+    pub maintenance_margin: String,
+
+    /// Lowest initial margin rate.
+    pub min_initial_margin: String,
+
+    /// Lowest maintenance margin rate.
+    pub min_maintenance_margin: String,
+
+    /// Price precision.
+    pub price_scale: u32,
+
+    /// Quantity precision.
+    pub quantity_scale: u32,
+
+    /// Quote asset (e.g., "USDT").
+    pub quote_asset: String,
+}
+
+/// Represents a rate limit object in the exchange info response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    /// The type of rate limit (e.g., "REQUEST_WEIGHT", "ORDERS").
+    pub rate_limit_type: RateLimitType,
+
+    /// The interval for the rate limit (e.g., "MINUTE").
+    pub interval: RateLimitInterval,
+
+    /// The number of intervals.
+    pub interval_num: u32,
+
+    /// The maximum number of requests or orders allowed in the interval.
+    pub limit: u32,
+}
+
+/// Represents the response from the Binance Options Exchange Information endpoint.
+///
+/// See: <https://developers.binance.com/docs/derivatives/option/market-data/Exchange-Information>
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoResponse {
+    /// The timezone of the exchange (e.g., "UTC").
+    pub timezone: String,
+
+    /// Server time (ms since epoch).
+    pub server_time: u64,
+
+    /// Underlyings options are listed on.
+    pub option_contracts: Vec<OptionContract>,
+
+    /// Assets options settle in.
+    pub option_assets: Vec<OptionAsset>,
+
+    /// Options listed for trading.
+    pub option_symbols: Vec<OptionSymbol>,
+
+    /// The list of rate limits applied to the account or exchange.
+    pub rate_limits: Vec<RateLimit>,
+}
+
+impl RestClient {
+    /// Fetches current exchange trading rules and option information.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Exchange-Information>
+    /// Corresponds to endpoint GET /eapi/v1/exchangeInfo.
+    /// Weight: 1
+    pub async fn get_exchange_info(&self) -> RestResult<ExchangeInfoResponse> {
+        self.send_request("/eapi/v1/exchangeInfo", reqwest::Method::GET, None, None, 1)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::options::RateLimiter;
+
+    #[tokio::test]
+    async fn test_exchange_info_is_parsed() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "timezone": "UTC",
+                "serverTime": 1592387337630_u64,
+                "optionContracts": [
+                    {"baseAsset": "BTC", "quoteAsset": "USDT", "underlying": "BTCUSDT", "settleAsset": "USDT"}
+                ],
+                "optionAssets": [{"name": "USDT"}],
+                "optionSymbols": [{
+                    "expiryDate": 1660521600000_u64,
+                    "filters": [
+                        {"filterType": "PRICE_FILTER", "minPrice": "0.02", "maxPrice": "80000.01", "tickSize": "0.01"},
+                        {"filterType": "LOT_SIZE", "minQty": "0.01", "maxQty": "100", "stepSize": "0.01"},
+                        {"filterType": "PERCENT_PRICE", "multiplierUp": "1.1"}
+                    ],
+                    "symbol": "BTC-220815-50000-C",
+                    "side": "CALL",
+                    "strikePrice": "50000",
+                    "underlying": "BTCUSDT",
+                    "unit": 1,
+                    "makerFeeRate": "0.0002",
+                    "takerFeeRate": "0.0002",
+                    "minQty": "0.01",
+                    "maxQty": "100",
+                    "initialMargin": "0.15",
+                    "maintenanceMargin": "0.075",
+                    "minInitialMargin": "0.1",
+                    "minMaintenanceMargin": "0.05",
+                    "priceScale": 2,
+                    "quantityScale": 2,
+                    "quoteAsset": "USDT"
+                }],
+                "rateLimits": [
+                    {"rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 2400},
+                    {"rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 300}
+                ]
+            }),
+        );
+        let client = RestClient::new(
+            "https://eapi.binance.com",
+            transport.clone(),
+            RateLimiter::new(),
+        );
+
+        let response = client.get_exchange_info().await.unwrap();
+        let option = response.data.option_symbols.first().unwrap();
+        assert_eq!(option.side, OptionsContractType::Call);
+        assert!(matches!(
+            option.filters.first(),
+            Some(Filter::PriceFilter(_))
+        ));
+        assert!(matches!(option.filters.get(2), Some(Filter::Unknown)));
+        assert_eq!(
+            response.data.rate_limits.get(1).unwrap().interval,
+            RateLimitInterval::Second
+        );
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.url, "https://eapi.binance.com/eapi/v1/exchangeInfo");
+    }
+}
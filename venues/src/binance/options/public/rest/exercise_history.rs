@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::public::rest::RestClient;
+use crate::binance::options::{RestResult, StrikeResult};
+
+/// Request parameters for the exercise history of expired options.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseHistoryRequest {
+    /// Underlying (e.g., "BTCUSDT"). All underlyings are returned if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub underlying: Option<String>,
+
+    /// Start time (ms since epoch). Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch). Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of records (default 100, max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// Exercise outcome of an expired option.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseHistory {
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Strike price.
+    pub strike_price: String,
+
+    /// Settlement price of the underlying at expiry.
+    pub real_strike_price: String,
+
+    /// Expiry time (ms since epoch).
+    pub expiry_date: u64,
+
+    /// Whether the option was exercised or expired.
+    pub strike_result: StrikeResult,
+}
+
+impl RestClient {
+    /// Fetches the exercise history of expired options.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Historical-Exercise-Records>
+    /// Corresponds to endpoint GET /eapi/v1/exerciseHistory.
+    /// Weight: 3
+    pub async fn get_exercise_history(&self, params: ExerciseHistoryRequest) -> RestResult<Vec<ExerciseHistory>> {
+        self.send_get_request("/eapi/v1/exerciseHistory", params, 3)
+            .await
+    }
+}
@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+use crate::binance::options::public::rest::trades::Trade;
+
+/// Request parameters for older trades.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalTradesRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Trade ID to fetch from. Default returns the most recent trades.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Number of trades (default 100, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl RestClient {
+    /// Fetches older trades of an option. Requires an API key, see [`RestClient::with_api_key`].
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Old-Trades-Lookup>
+    /// Corresponds to endpoint GET /eapi/v1/historicalTrades.
+    /// Weight: 20
+    pub async fn get_historical_trades(&self, params: HistoricalTradesRequest) -> RestResult<Vec<Trade>> {
+        self.send_get_request("/eapi/v1/historicalTrades", params, 20)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use rest::transport::MockTransport;
+    use secrecy::SecretString;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::options::RateLimiter;
+
+    #[tokio::test]
+    async fn test_historical_trades_send_api_key_and_parse_string_ids() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!([{
+                "id": "1",
+                "tradeId": "159244329455993",
+                "price": "1000",
+                "qty": "-0.1",
+                "quoteQty": "-100",
+                "side": -1,
+                "time": 1592449455993_u64
+            }]),
+        );
+        let client = RestClient::new(
+            "https://eapi.binance.com",
+            transport.clone(),
+            RateLimiter::new(),
+        )
+        .with_api_key(SecretString::from("test_key".to_string()));
+
+        let request = HistoricalTradesRequest {
+            symbol: "BTC-200730-9000-C".to_string(),
+            from_id: Some(1),
+            limit: None,
+        };
+        let response = client.get_historical_trades(request).await.unwrap();
+        let trade = response.data.first().unwrap();
+        assert_eq!(trade.trade_id, 159244329455993);
+        assert_eq!(trade.side, -1);
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.header_value("X-MBX-APIKEY"), Some("test_key"));
+        assert_eq!(
+            sent.query_string(),
+            Some("symbol=BTC-200730-9000-C&fromId=1")
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+
+/// Request parameters for the index price of an underlying.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct IndexPriceRequest {
+    /// Underlying (e.g., "BTCUSDT").
+    pub underlying: String,
+}
+
+/// Spot index price of an underlying.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPrice {
+    /// Time of the price (ms since epoch).
+    pub time: u64,
+
+    /// Spot index price of the underlying.
+    pub index_price: String,
+}
+
+impl RestClient {
+    /// Fetches the spot index price of an underlying.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Symbol-Price-Ticker>
+    /// Corresponds to endpoint GET /eapi/v1/index.
+    /// Weight: 1
+    pub async fn get_index_price(&self, params: IndexPriceRequest) -> RestResult<IndexPrice> {
+        self.send_get_request("/eapi/v1/index", params, 1).await
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::public::rest::RestClient;
+use crate::binance::options::{KlineInterval, RestResult};
+
+/// Request parameters for klines of an option.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of klines (default 500, max 1500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A kline. Unlike the futures klines, options klines are sent as objects.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    /// Open price.
+    pub open: String,
+
+    /// High price.
+    pub high: String,
+
+    /// Low price.
+    pub low: String,
+
+    /// Close price, or the latest price while the kline is open.
+    pub close: String,
+
+    /// Volume in contracts.
+    pub volume: String,
+
+    /// Volume in the quote asset.
+    pub amount: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Number of trades.
+    pub trade_count: u64,
+
+    /// Taker buy volume in contracts.
+    pub taker_volume: String,
+
+    /// Taker buy volume in the quote asset.
+    pub taker_amount: String,
+
+    /// Open time (ms since epoch).
+    pub open_time: u64,
+
+    /// Close time (ms since epoch).
+    pub close_time: u64,
+}
+
+impl RestClient {
+    /// Fetches klines of an option.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Kline-Candlestick-Data>
+    /// Corresponds to endpoint GET /eapi/v1/klines.
+    /// Weight: 1
+    pub async fn get_klines(&self, params: KlinesRequest) -> RestResult<Vec<Kline>> {
+        self.send_get_request("/eapi/v1/klines", params, 1).await
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+
+/// Request parameters for the mark price and greeks.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct MarkPriceRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C"). All options are returned if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+/// Mark price, implied volatilities and greeks of an option.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Mark price.
+    pub mark_price: String,
+
+    /// Implied volatility of the best bid.
+    #[serde(rename = "bidIV")]
+    pub bid_iv: String,
+
+    /// Implied volatility of the best ask.
+    #[serde(rename = "askIV")]
+    pub ask_iv: String,
+
+    /// Implied volatility of the mark price.
+    #[serde(rename = "markIV")]
+    pub mark_iv: String,
+
+    /// Delta.
+    pub delta: String,
+
+    /// Theta.
+    pub theta: String,
+
+    /// Gamma.
+    pub gamma: String,
+
+    /// Vega.
+    pub vega: String,
+
+    /// Highest price an order can be placed at.
+    pub high_price_limit: String,
+
+    /// Lowest price an order can be placed at.
+    pub low_price_limit: String,
+
+    /// Risk-free interest rate used for pricing.
+    pub risk_free_interest: String,
+}
+
+impl RestClient {
+    /// Fetches the mark price, implied volatilities and greeks of an option, or of all options.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Option-Mark-Price>
+    /// Corresponds to endpoint GET /eapi/v1/mark.
+    /// Weight: 5
+    pub async fn get_mark_price(&self, params: MarkPriceRequest) -> RestResult<Vec<MarkPrice>> {
+        self.send_get_request("/eapi/v1/mark", params, 5).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_mark_price_parses_implied_volatilities() {
+        let marks: Vec<MarkPrice> = serde_json::from_value(json!([{
+            "symbol": "BTC-200730-9000-C",
+            "markPrice": "1343.2883",
+            "bidIV": "1.40000077",
+            "askIV": "1.50000153",
+            "markIV": "1.45000000",
+            "delta": "0.55937056",
+            "theta": "3739.82509871",
+            "gamma": "0.00010969",
+            "vega": "978.58874732",
+            "highPriceLimit": "1618.241",
+            "lowPriceLimit": "1068.3356",
+            "riskFreeInterest": "0.1"
+        }]))
+        .unwrap();
+        let mark = marks.first().unwrap();
+        assert_eq!(mark.bid_iv, "1.40000077");
+        assert_eq!(mark.mark_iv, "1.45000000");
+        assert_eq!(mark.delta, "0.55937056");
+    }
+}
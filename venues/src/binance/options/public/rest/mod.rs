@@ -1,5 +1,17 @@
 // Public REST endpoints module for Binance Options
 
 pub mod client;
+pub mod exchange_info;
+pub mod exercise_history;
+pub mod historical_trades;
+pub mod index;
+pub mod klines;
+pub mod mark;
+pub mod open_interest;
+pub mod order_book;
+pub mod ping;
+pub mod server_time;
+pub mod ticker;
+pub mod trades;
 
 pub use client::RestClient;
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+
+/// Request parameters for the open interest of the options of an expiry.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRequest {
+    /// Underlying asset (e.g., "ETH").
+    pub underlying_asset: String,
+
+    /// Expiry date as YYMMDD (e.g., "221225").
+    pub expiration: String,
+}
+
+/// Open interest of an option.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Open interest in contracts.
+    pub sum_open_interest: String,
+
+    /// Open interest in USD.
+    pub sum_open_interest_usd: String,
+
+    /// Time (ms since epoch), sent as a string.
+    pub timestamp: String,
+}
+
+impl RestClient {
+    /// Fetches the open interest of all options of an underlying asset expiring on a date.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Open-Interest>
+    /// Corresponds to endpoint GET /eapi/v1/openInterest.
+    /// Weight: 0
+    pub async fn get_open_interest(&self, params: OpenInterestRequest) -> RestResult<Vec<OpenInterest>> {
+        self.send_get_request("/eapi/v1/openInterest", params, 0)
+            .await
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+
+/// Request parameters for the order book.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct OrderBookRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Number of levels per side: 10, 20, 50, 100, 500 or 1000. Default 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A price level of the order book.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderBookLevel {
+    /// Price of the level.
+    pub price: String,
+
+    /// Quantity at the level.
+    pub quantity: String,
+}
+
+/// Snapshot of the order book.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderBookResponse {
+    /// Transaction time.
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    /// Update ID.
+    #[serde(rename = "u")]
+    pub update_id: u64,
+
+    /// Bids, best price first.
+    pub bids: Vec<OrderBookLevel>,
+
+    /// Asks, best price first.
+    pub asks: Vec<OrderBookLevel>,
+}
+
+/// Request weight of the order book for `limit` levels per side
+pub(crate) fn order_book_weight(limit: Option<u32>) -> u32 {
+    match limit.unwrap_or(100) {
+        0..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
+}
+
+impl RestClient {
+    /// Fetches the order book of an option.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Order-Book>
+    /// Corresponds to endpoint GET /eapi/v1/depth.
+    /// Weight: 2 for up to 50 levels, 5 for 100, 10 for 500 and 20 for 1000
+    pub async fn get_order_book(&self, params: OrderBookRequest) -> RestResult<OrderBookResponse> {
+        let weight = order_book_weight(params.limit);
+        self.send_get_request("/eapi/v1/depth", params, weight)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_book_weight_follows_limit() {
+        assert_eq!(order_book_weight(Some(50)), 2);
+        assert_eq!(order_book_weight(None), 5);
+        assert_eq!(order_book_weight(Some(500)), 10);
+        assert_eq!(order_book_weight(Some(1000)), 20);
+    }
+}
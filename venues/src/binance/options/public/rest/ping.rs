@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+
+/// Empty response of the connectivity test.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PingResponse {}
+
+impl RestClient {
+    /// Tests connectivity to the REST API.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Test-Connectivity>
+    /// Corresponds to endpoint GET /eapi/v1/ping.
+    /// Weight: 1
+    pub async fn ping(&self) -> RestResult<PingResponse> {
+        self.send_request("/eapi/v1/ping", reqwest::Method::GET, None, None, 1)
+            .await
+    }
+}
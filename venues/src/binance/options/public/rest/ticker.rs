@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+
+/// Request parameters for the 24 hour tickers.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct TickerRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C"). All options are returned if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+/// Price change statistics of an option over the last 24 hours.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    /// Option symbol.
+    pub symbol: String,
+
+    /// Last price minus the open price.
+    pub price_change: String,
+
+    /// Price change in percent of the open price.
+    pub price_change_percent: String,
+
+    /// Price of the last trade.
+    pub last_price: String,
+
+    /// Quantity of the last trade.
+    pub last_qty: String,
+
+    /// Price 24 hours ago.
+    pub open: String,
+
+    /// High price.
+    pub high: String,
+
+    /// Low price.
+    pub low: String,
+
+    /// Volume in contracts.
+    pub volume: String,
+
+    /// Volume in the quote asset.
+    pub amount: String,
+
+    /// Best bid price.
+    pub bid_price: String,
+
+    /// Best ask price.
+    pub ask_price: String,
+
+    /// Start of the window (ms since epoch).
+    pub open_time: u64,
+
+    /// End of the window (ms since epoch).
+    pub close_time: u64,
+
+    /// ID of the first trade in the window.
+    pub first_trade_id: u64,
+
+    /// Number of trades in the window.
+    pub trade_count: u64,
+
+    /// Strike price.
+    pub strike_price: String,
+
+    /// Estimated settlement price one hour before exercise, index price at other times.
+    pub exercise_price: String,
+}
+
+impl RestClient {
+    /// Fetches the 24 hour price change statistics of an option, or of all options.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/24hr-Ticker-Price-Change-Statistics>
+    /// Corresponds to endpoint GET /eapi/v1/ticker.
+    /// Weight: 5
+    pub async fn get_ticker(&self, params: TickerRequest) -> RestResult<Vec<Ticker>> {
+        self.send_get_request("/eapi/v1/ticker", params, 5).await
+    }
+}
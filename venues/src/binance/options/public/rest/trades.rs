@@ -0,0 +1,97 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::binance::options::RestResult;
+use crate::binance::options::public::rest::RestClient;
+
+/// Request parameters for recent trades.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct RecentTradesRequest {
+    /// Option symbol (e.g., "BTC-200730-9000-C").
+    pub symbol: String,
+
+    /// Number of trades (default 100, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// Reads a trade ID that Binance sends as a number or as a string depending on the endpoint
+fn trade_id<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum TradeId {
+        Number(u64),
+        String(String),
+    }
+
+    match TradeId::deserialize(deserializer)? {
+        TradeId::Number(id) => Ok(id),
+        TradeId::String(id) => id.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// A trade filled in the order book.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// ID of the trade on the option.
+    pub id: String,
+
+    /// Trade ID, sent as a number or a string depending on the endpoint.
+    #[serde(deserialize_with = "trade_id")]
+    pub trade_id: u64,
+
+    /// Option symbol, only returned for recent trades.
+    pub symbol: Option<String>,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity, negative when the taker sold.
+    pub qty: String,
+
+    /// Quote quantity, negative when the taker sold.
+    pub quote_qty: String,
+
+    /// Taker side: 1 when the taker bought, -1 when the taker sold.
+    pub side: i32,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches recent trades of an option.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/option/market-data/Recent-Trades-List>
+    /// Corresponds to endpoint GET /eapi/v1/trades.
+    /// Weight: 5
+    pub async fn get_recent_trades(&self, params: RecentTradesRequest) -> RestResult<Vec<Trade>> {
+        self.send_get_request("/eapi/v1/trades", params, 5).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_recent_trades_parse_numeric_ids() {
+        let trades: Vec<Trade> = serde_json::from_value(json!([{
+            "id": "616",
+            "tradeId": 3,
+            "symbol": "BTC-220722-19000-C",
+            "price": "1000",
+            "qty": "-0.1",
+            "quoteQty": "-100",
+            "side": -1,
+            "time": 1592449455993_u64
+        }]))
+        .unwrap();
+        assert_eq!(trades.first().unwrap().trade_id, 3);
+    }
+}
@@ -1,7 +1,10 @@
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use async_trait::async_trait;
+use rest::error::{RestError, VenueError};
+use rest::rate_limiter::RateLimitStatus;
 use serde::Deserialize;
 use tokio::sync::RwLock;
 
@@ -147,6 +150,33 @@ impl ResponseHeaders {
     }
 }
 
+/// What a request costs against the Binance limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestWeight {
+    /// Request weight of the endpoint, as documented for each endpoint
+    pub weight: u32,
+    /// Whether the request also counts against the order limits
+    pub is_order: bool,
+}
+
+impl RequestWeight {
+    /// A non-order request with the given weight
+    pub fn new(weight: u32) -> Self {
+        Self {
+            weight,
+            is_order: false,
+        }
+    }
+
+    /// An order request with the given weight
+    pub fn order(weight: u32) -> Self {
+        Self {
+            weight,
+            is_order: true,
+        }
+    }
+}
+
 /// Tracks the current usage of rate limits (rolling windows)
 #[derive(Debug, Default, Clone)]
 pub struct RateLimitUsage {
@@ -328,3 +358,157 @@ impl RateLimiter {
         )
     }
 }
+
+/// Status of a rolling window holding `limit` requests
+fn window_status(timestamps: &VecDeque<Instant>, limit: u32, window: Duration, now: Instant) -> RateLimitStatus {
+    let in_window = timestamps
+        .iter()
+        .filter(|&&timestamp| now.duration_since(timestamp) < window);
+    let reset_in = in_window
+        .clone()
+        .min()
+        .map(|&oldest| window.saturating_sub(now.duration_since(oldest)))
+        .unwrap_or_default();
+    RateLimitStatus {
+        remaining: limit.saturating_sub(u32::try_from(in_window.count()).unwrap_or(u32::MAX)),
+        limit,
+        reset_in,
+    }
+}
+
+/// Time until the request weight counter resets, at the start of the next minute
+fn until_next_minute() -> Duration {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let into_minute = since_epoch
+        .as_millis()
+        .checked_rem(60_000)
+        .unwrap_or_default();
+    Duration::from_secs(60).saturating_sub(Duration::from_millis(
+        u64::try_from(into_minute).unwrap_or_default(),
+    ))
+}
+
+#[async_trait]
+impl rest::rate_limiter::RateLimiter for RateLimiter {
+    type Key = RequestWeight;
+
+    async fn check_limit(&self, _endpoint: &str, weight: &RequestWeight) -> Result<(), RestError> {
+        self.check_limits(weight.weight, weight.is_order)
+            .await
+            .map_err(|err| VenueError::from(err).into())
+    }
+
+    async fn record_request(&self, _endpoint: &str, weight: &RequestWeight) {
+        self.increment_raw_request().await;
+        if weight.is_order {
+            self.increment_order().await;
+        }
+    }
+
+    /// Status of whichever of the weight, raw request and order limits is closest to being exhausted.
+    /// Used weight is only known from the latest response headers.
+    async fn get_rate_limit_status(&self, weight: &RequestWeight) -> RateLimitStatus {
+        let usage = self.usage.read().await;
+        let now = Instant::now();
+
+        let mut statuses = vec![
+            RateLimitStatus {
+                remaining: 6000_u32.saturating_sub(usage.used_weight_1m),
+                limit: 6000,
+                reset_in: until_next_minute(),
+            },
+            window_status(
+                &usage.raw_request_timestamps,
+                61000,
+                Duration::from_secs(300),
+                now,
+            ),
+        ];
+        if weight.is_order {
+            statuses.push(window_status(
+                &usage.order_timestamps_10s,
+                100,
+                Duration::from_secs(10),
+                now,
+            ));
+            statuses.push(window_status(
+                &usage.order_timestamps_1m,
+                1200,
+                Duration::from_secs(60),
+                now,
+            ));
+        }
+
+        statuses
+            .into_iter()
+            .min_by_key(|status| status.remaining)
+            .unwrap_or(RateLimitStatus {
+                remaining: 6000,
+                limit: 6000,
+                reset_in: Duration::ZERO,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rest_rate_limiter_trait() {
+        use rest::error::ErrorKind;
+        use rest::rate_limiter::RateLimiter as _;
+
+        let limiter = RateLimiter::new();
+        let order = RequestWeight::order(1);
+
+        for _ in 0..100 {
+            limiter.check_limit("/eapi/v1/order", &order).await.unwrap();
+            limiter.record_request("/eapi/v1/order", &order).await;
+        }
+
+        let status = limiter.get_rate_limit_status(&order).await;
+        assert_eq!(status.remaining, 0);
+        assert_eq!(status.limit, 100);
+        assert!(status.reset_in <= Duration::from_secs(10));
+
+        let err = limiter
+            .check_limit("/eapi/v1/order", &order)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::RateLimited { .. }));
+
+        // Non-order requests only count against the weight and raw request limits
+        let query = RequestWeight::new(2);
+        limiter
+            .check_limit("/eapi/v1/ticker", &query)
+            .await
+            .unwrap();
+        let status = limiter.get_rate_limit_status(&query).await;
+        assert_eq!(status.remaining, 6000);
+        assert_eq!(status.limit, 6000);
+
+        // Used weight comes from the response headers
+        let mut headers = ResponseHeaders::default();
+        headers.values.insert(
+            RateLimitHeader {
+                kind: RateLimitHeaderKind::UsedWeight,
+                interval_value: 1,
+                interval_unit: IntervalUnit::Minute,
+            },
+            5999,
+        );
+        limiter.update_from_headers(&headers).await;
+        let status = limiter.get_rate_limit_status(&query).await;
+        assert_eq!(status.remaining, 1);
+        assert!(status.reset_in <= Duration::from_secs(60));
+        assert!(
+            limiter
+                .check_limit("/eapi/v1/ticker", &query)
+                .await
+                .is_err()
+        );
+    }
+}
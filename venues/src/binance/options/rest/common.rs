@@ -3,7 +3,8 @@
 
 use std::time::Duration;
 
-use reqwest::{Client, Method};
+use reqwest::Method;
+use rest::transport::{HttpRequest, HttpTransport};
 use url::Url;
 
 use crate::binance::options::{ErrorResponse, Errors, RateLimiter, ResponseHeaders};
@@ -27,7 +28,7 @@ pub struct RestResponse<T> {
 
 /// Shared logic for sending a REST request and updating the rate limiter.
 pub(crate) async fn send_rest_request<T>(
-    client: &Client,
+    client: &dyn HttpTransport,
     url: &str,
     method: Method,
     headers: Vec<(&str, String)>,
@@ -57,7 +58,7 @@ where
 
 /// Internal helper to execute HTTP requests and parse responses for Options API.
 async fn execute_request<T>(
-    client: &Client,
+    client: &dyn HttpTransport,
     url: &str,
     method: Method,
     headers: Option<Vec<(&str, String)>>,
@@ -72,7 +73,7 @@ where
     use crate::binance::options::ApiError;
 
     let start = std::time::Instant::now();
-    let mut request = client.request(method, url);
+    let mut request = HttpRequest::new(method, url);
 
     // Add headers if provided
     if let Some(headers) = headers {
@@ -83,15 +84,15 @@ where
 
     // Add body if provided
     if let Some(body_str) = body {
-        request = request.body(body_str.to_string());
+        request = request.body(body_str);
         request = request.header("Content-Type", "application/x-www-form-urlencoded");
     }
 
-    let response = request.send().await.map_err(Errors::HttpError)?;
+    let response = client.execute(request).await.map_err(Errors::HttpError)?;
     let duration = start.elapsed();
-    let headers = ResponseHeaders::from_reqwest_headers(response.headers());
-    let status = response.status();
-    let response_text = response.text().await.map_err(Errors::HttpError)?;
+    let headers = ResponseHeaders::from_reqwest_headers(&response.headers);
+    let status = response.status;
+    let response_text = response.body;
 
     debug!(
         "Options API response: status={}, body={}",
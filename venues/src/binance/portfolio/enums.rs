@@ -50,6 +50,8 @@ pub enum TimeInForce {
     FOK,
     /// Good Till Crossing (Post Only)
     GTX,
+    /// Good Till Date, see `goodTillDate`
+    GTD,
 }
 
 impl fmt::Display for TimeInForce {
@@ -59,6 +61,7 @@ impl fmt::Display for TimeInForce {
             TimeInForce::IOC => write!(f, "IOC"),
             TimeInForce::FOK => write!(f, "FOK"),
             TimeInForce::GTX => write!(f, "GTX"),
+            TimeInForce::GTD => write!(f, "GTD"),
         }
     }
 }
@@ -186,6 +189,28 @@ impl fmt::Display for OrderResponseType {
     }
 }
 
+/// Margin order response type (newOrderRespType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginOrderResponseType {
+    /// IDs and transaction time only
+    Ack,
+    /// ACK with the order details
+    Result,
+    /// RESULT with the fills of the order
+    Full,
+}
+
+impl fmt::Display for MarginOrderResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarginOrderResponseType::Ack => write!(f, "ACK"),
+            MarginOrderResponseType::Result => write!(f, "RESULT"),
+            MarginOrderResponseType::Full => write!(f, "FULL"),
+        }
+    }
+}
+
 /// Order types (type)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -203,6 +228,96 @@ impl fmt::Display for OrderType {
     }
 }
 
+/// Margin order types (type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginOrderType {
+    Limit,
+    Market,
+    StopLoss,
+    StopLossLimit,
+    TakeProfit,
+    TakeProfitLimit,
+    LimitMaker,
+}
+
+impl fmt::Display for MarginOrderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarginOrderType::Limit => write!(f, "LIMIT"),
+            MarginOrderType::Market => write!(f, "MARKET"),
+            MarginOrderType::StopLoss => write!(f, "STOP_LOSS"),
+            MarginOrderType::StopLossLimit => write!(f, "STOP_LOSS_LIMIT"),
+            MarginOrderType::TakeProfit => write!(f, "TAKE_PROFIT"),
+            MarginOrderType::TakeProfitLimit => write!(f, "TAKE_PROFIT_LIMIT"),
+            MarginOrderType::LimitMaker => write!(f, "LIMIT_MAKER"),
+        }
+    }
+}
+
+/// Margin order list types (contingencyType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContingencyType {
+    /// One-Cancels-the-Other
+    Oco,
+    /// One-Triggers-the-Other
+    Oto,
+}
+
+impl fmt::Display for ContingencyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContingencyType::Oco => write!(f, "OCO"),
+            ContingencyType::Oto => write!(f, "OTO"),
+        }
+    }
+}
+
+/// Margin order list status (listStatusType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListStatusType {
+    /// The order list has been placed or there is an update to its status
+    Response,
+    /// The order list has been placed or there is an update to a list order
+    ExecStarted,
+    /// The order list has finished executing and is no longer active
+    AllDone,
+}
+
+impl fmt::Display for ListStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListStatusType::Response => write!(f, "RESPONSE"),
+            ListStatusType::ExecStarted => write!(f, "EXEC_STARTED"),
+            ListStatusType::AllDone => write!(f, "ALL_DONE"),
+        }
+    }
+}
+
+/// Status of the orders of a margin order list (listOrderStatus)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListOrderStatus {
+    /// The order list has been placed or there is an update to its status
+    Executing,
+    /// The order list has finished executing and is no longer active
+    AllDone,
+    /// The order list was rejected
+    Reject,
+}
+
+impl fmt::Display for ListOrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListOrderStatus::Executing => write!(f, "EXECUTING"),
+            ListOrderStatus::AllDone => write!(f, "ALL_DONE"),
+            ListOrderStatus::Reject => write!(f, "REJECT"),
+        }
+    }
+}
+
 /// Conditional Order types (strategyType)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -231,12 +346,14 @@ impl fmt::Display for StrategyType {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WorkingType {
     MarkPrice,
+    ContractPrice,
 }
 
 impl fmt::Display for WorkingType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             WorkingType::MarkPrice => write!(f, "MARK_PRICE"),
+            WorkingType::ContractPrice => write!(f, "CONTRACT_PRICE"),
         }
     }
 }
@@ -251,6 +368,8 @@ pub enum OrderStatus {
     PartiallyFilled,
     Filled,
     Expired,
+    /// Expired by self-trade prevention
+    ExpiredInMatch,
 }
 
 impl fmt::Display for OrderStatus {
@@ -262,6 +381,7 @@ impl fmt::Display for OrderStatus {
             OrderStatus::PartiallyFilled => write!(f, "PARTIALLY_FILLED"),
             OrderStatus::Filled => write!(f, "FILLED"),
             OrderStatus::Expired => write!(f, "EXPIRED"),
+            OrderStatus::ExpiredInMatch => write!(f, "EXPIRED_IN_MATCH"),
         }
     }
 }
@@ -291,6 +411,110 @@ impl fmt::Display for StrategyStatus {
     }
 }
 
+/// UM and CM income types (incomeType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    /// Transfer into or out of the account
+    Transfer,
+    /// Welcome bonus
+    WelcomeBonus,
+    /// Realized profit of a trade
+    RealizedPnl,
+    /// Funding fee paid or received
+    FundingFee,
+    /// Trading commission
+    Commission,
+    /// Liquidation fee
+    InsuranceClear,
+    /// Referral rebate
+    ReferralKickback,
+    /// Commission rebate
+    CommissionRebate,
+    /// API rebate
+    ApiRebate,
+    /// Trading contest reward
+    ContestReward,
+    /// Cross collateral transfer
+    CrossCollateralTransfer,
+    /// Options premium fee
+    OptionsPremiumFee,
+    /// Options settlement profit
+    OptionsSettleProfit,
+    /// Internal transfer
+    InternalTransfer,
+    /// Automatic asset exchange
+    AutoExchange,
+    /// Delivery settlement, spelled as Binance sends it
+    DeliveredSettelment,
+    /// Coin swap deposit
+    CoinSwapDeposit,
+    /// Coin swap withdrawal
+    CoinSwapWithdraw,
+    /// Fee for raising the position limit
+    PositionLimitIncreaseFee,
+}
+
+impl fmt::Display for IncomeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IncomeType::Transfer => write!(f, "TRANSFER"),
+            IncomeType::WelcomeBonus => write!(f, "WELCOME_BONUS"),
+            IncomeType::RealizedPnl => write!(f, "REALIZED_PNL"),
+            IncomeType::FundingFee => write!(f, "FUNDING_FEE"),
+            IncomeType::Commission => write!(f, "COMMISSION"),
+            IncomeType::InsuranceClear => write!(f, "INSURANCE_CLEAR"),
+            IncomeType::ReferralKickback => write!(f, "REFERRAL_KICKBACK"),
+            IncomeType::CommissionRebate => write!(f, "COMMISSION_REBATE"),
+            IncomeType::ApiRebate => write!(f, "API_REBATE"),
+            IncomeType::ContestReward => write!(f, "CONTEST_REWARD"),
+            IncomeType::CrossCollateralTransfer => write!(f, "CROSS_COLLATERAL_TRANSFER"),
+            IncomeType::OptionsPremiumFee => write!(f, "OPTIONS_PREMIUM_FEE"),
+            IncomeType::OptionsSettleProfit => write!(f, "OPTIONS_SETTLE_PROFIT"),
+            IncomeType::InternalTransfer => write!(f, "INTERNAL_TRANSFER"),
+            IncomeType::AutoExchange => write!(f, "AUTO_EXCHANGE"),
+            IncomeType::DeliveredSettelment => write!(f, "DELIVERED_SETTELMENT"),
+            IncomeType::CoinSwapDeposit => write!(f, "COIN_SWAP_DEPOSIT"),
+            IncomeType::CoinSwapWithdraw => write!(f, "COIN_SWAP_WITHDRAW"),
+            IncomeType::PositionLimitIncreaseFee => write!(f, "POSITION_LIMIT_INCREASE_FEE"),
+        }
+    }
+}
+
+/// Portfolio Margin account status (accountStatus)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountStatus {
+    /// Trading normally
+    Normal,
+    /// Margin is running low
+    MarginCall,
+    /// More margin must be supplied
+    SupplyMargin,
+    /// Only orders reducing positions are accepted
+    ReduceOnly,
+    /// Positions are being liquidated by the account holder
+    ActiveLiquidation,
+    /// Positions are being liquidated by Binance
+    ForceLiquidation,
+    /// Liabilities exceed the account equity
+    Bankrupted,
+}
+
+impl fmt::Display for AccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountStatus::Normal => write!(f, "NORMAL"),
+            AccountStatus::MarginCall => write!(f, "MARGIN_CALL"),
+            AccountStatus::SupplyMargin => write!(f, "SUPPLY_MARGIN"),
+            AccountStatus::ReduceOnly => write!(f, "REDUCE_ONLY"),
+            AccountStatus::ActiveLiquidation => write!(f, "ACTIVE_LIQUIDATION"),
+            AccountStatus::ForceLiquidation => write!(f, "FORCE_LIQUIDATION"),
+            AccountStatus::Bankrupted => write!(f, "BANKRUPTED"),
+        }
+    }
+}
+
 /// Futures Contract type (contractType)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -376,3 +600,22 @@ impl fmt::Display for RateLimitInterval {
         }
     }
 }
+
+/// Direction of a BNB transfer between the margin and UM accounts (transferSide)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferSide {
+    /// From the margin account to the UM account
+    ToUm,
+    /// From the UM account to the margin account
+    FromUm,
+}
+
+impl fmt::Display for TransferSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransferSide::ToUm => write!(f, "TO_UM"),
+            TransferSide::FromUm => write!(f, "FROM_UM"),
+        }
+    }
+}
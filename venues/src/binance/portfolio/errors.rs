@@ -1,2 +1,4 @@
 // Portfolio Margin errors - reuse COIN-M errors since they're identical for API operations
 pub use crate::binance::coinm::{ApiError, ErrorResponse, Errors};
+// Errors are reported under the COIN-M venue name, so the clients use it too
+pub(crate) use crate::binance::coinm::VENUE;
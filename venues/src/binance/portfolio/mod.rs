@@ -13,15 +13,63 @@ mod private {
     pub mod rest;
     // Re-export RestClient so it can be re-exported by the parent
     pub use self::rest::RestClient as PrivateRestClient;
+    pub use self::rest::account::*;
+    pub use self::rest::asset_balance::*;
+    pub use self::rest::asset_collection::*;
+    pub use self::rest::auto_collection::*;
+    pub use self::rest::balance::*;
+    pub use self::rest::bnb_transfer::*;
+    pub use self::rest::cm_all_orders::*;
+    pub use self::rest::cm_cancel_all_conditional_orders::*;
+    pub use self::rest::cm_cancel_all_orders::*;
+    pub use self::rest::cm_cancel_conditional_order::*;
+    pub use self::rest::cm_cancel_order::*;
+    pub use self::rest::cm_conditional_all_orders::*;
+    pub use self::rest::cm_conditional_open_orders::*;
+    pub use self::rest::cm_conditional_order::*;
+    pub use self::rest::cm_income::*;
+    pub use self::rest::cm_leverage::*;
+    pub use self::rest::cm_open_orders::*;
+    pub use self::rest::cm_order::*;
+    pub use self::rest::cm_position_risk::*;
+    pub use self::rest::cm_query_order::*;
+    pub use self::rest::cm_trades::*;
+    pub use self::rest::margin_all_orders::*;
+    pub use self::rest::margin_cancel_all_orders::*;
+    pub use self::rest::margin_cancel_order::*;
+    pub use self::rest::margin_interest_history::*;
+    pub use self::rest::margin_loan::*;
+    pub use self::rest::margin_open_orders::*;
+    pub use self::rest::margin_order::*;
+    pub use self::rest::margin_query_order::*;
+    pub use self::rest::margin_trades::*;
+    pub use self::rest::negative_balance_exchange_record::*;
+    pub use self::rest::negative_balance_interest_history::*;
+    pub use self::rest::repay_loan::*;
+    pub use self::rest::um_all_orders::*;
+    pub use self::rest::um_cancel_all_conditional_orders::*;
+    pub use self::rest::um_cancel_all_orders::*;
+    pub use self::rest::um_cancel_conditional_order::*;
+    pub use self::rest::um_cancel_order::*;
+    pub use self::rest::um_conditional_all_orders::*;
+    pub use self::rest::um_conditional_open_orders::*;
+    pub use self::rest::um_conditional_order::*;
+    pub use self::rest::um_income::*;
+    pub use self::rest::um_leverage::*;
+    pub use self::rest::um_open_orders::*;
+    pub use self::rest::um_order::*;
+    pub use self::rest::um_position_risk::*;
+    pub use self::rest::um_query_order::*;
+    pub use self::rest::um_trades::*;
 }
 
 // Re-export public modules
 pub use enums::*;
 pub use errors::{ApiError, Errors};
 // Export clients
-pub use private::PrivateRestClient;
+pub use private::*;
 pub use public::PublicRestClient;
-pub use rate_limit::{PortfolioMarginRateLimiter, RateLimitHeader, RateLimiter};
+pub use rate_limit::{PortfolioMarginRateLimiter, RateLimitHeader, RateLimiter, RequestWeight};
 
 /// Portfolio Margin specific response headers
 /// Uses the same structure as COIN-M since rate limiting works identically
@@ -0,0 +1,112 @@
+// Account Information (USER_DATA) endpoint implementation for GET /papi/v1/account
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Account-Information>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{AccountStatus, RestResult};
+
+/// Request parameters for the account information.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Portfolio Margin account information. Amounts are in USD.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    /// Unified maintenance margin ratio.
+    #[serde(rename = "uniMMR")]
+    pub uni_mmr: String,
+
+    /// Account equity with the collateral rate applied.
+    pub account_equity: String,
+
+    /// Account equity without collateral rate applied.
+    pub actual_equity: String,
+
+    /// Initial margin of the open orders and positions.
+    pub account_initial_margin: String,
+
+    /// Maintenance margin of the positions.
+    pub account_maint_margin: String,
+
+    /// Account status.
+    pub account_status: AccountStatus,
+
+    /// Maximum amount that can be transferred out.
+    pub virtual_max_withdraw_amount: String,
+
+    /// Balance available for new orders.
+    pub total_available_balance: String,
+
+    /// Open loss of the margin account.
+    pub total_margin_open_loss: String,
+
+    /// Last update of the account (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Fetches the Portfolio Margin account information.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Account-Information>
+    /// GET /papi/v1/account
+    /// Weight: 20
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AccountRequest`])
+    ///
+    /// # Returns
+    /// The [`AccountInformation`].
+    pub async fn get_account(&self, params: AccountRequest) -> RestResult<AccountInformation> {
+        self.send_signed_request("/papi/v1/account", reqwest::Method::GET, params, 20, false)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::portfolio::private::rest::client::tests::client;
+
+    #[tokio::test]
+    async fn test_get_account() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "uniMMR": "5167.92171923",
+                "accountEquity": "122607.35137903",
+                "actualEquity": "73.47428058",
+                "accountInitialMargin": "23.72469206",
+                "accountMaintMargin": "23.72469206",
+                "accountStatus": "NORMAL",
+                "virtualMaxWithdrawAmount": "1627523.32459208",
+                "totalAvailableBalance": "",
+                "totalMarginOpenLoss": "",
+                "updateTime": 1657707212154_u64
+            }),
+        );
+        let client = client(&transport);
+
+        let response = client.get_account(AccountRequest::default()).await.unwrap();
+        assert_eq!(response.data.uni_mmr, "5167.92171923");
+        assert_eq!(response.data.account_status, AccountStatus::Normal);
+
+        let sent = transport.last_request().unwrap();
+        assert!(
+            sent.url
+                .starts_with("https://papi.binance.com/papi/v1/account?")
+        );
+    }
+}
@@ -0,0 +1,88 @@
+// Account Balance of a Single Asset (USER_DATA) endpoint implementation for GET /papi/v1/balance
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Account-Balance>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::balance::Balance;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for the balance of a single asset.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetBalanceRequest {
+    /// Asset (e.g., "USDT").
+    pub asset: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the balance of a single asset.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Account-Balance>
+    /// GET /papi/v1/balance
+    /// Weight: 20
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AssetBalanceRequest`])
+    ///
+    /// # Returns
+    /// The [`Balance`] of the asset.
+    pub async fn get_asset_balance(&self, params: AssetBalanceRequest) -> RestResult<Balance> {
+        self.send_signed_request("/papi/v1/balance", reqwest::Method::GET, params, 20, false)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::portfolio::private::rest::client::tests::client;
+    use crate::binance::shared::test_support::signed_params;
+
+    #[tokio::test]
+    async fn test_get_asset_balance_returns_a_single_object() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "asset": "USDT",
+                "totalWalletBalance": "122607.35137903",
+                "crossMarginAsset": "92.27530794",
+                "crossMarginBorrowed": "10.00000000",
+                "crossMarginFree": "100.00000000",
+                "crossMarginInterest": "0.72469206",
+                "crossMarginLocked": "3.00000000",
+                "umWalletBalance": "0.00000000",
+                "umUnrealizedPNL": "23.72469206",
+                "cmWalletBalance": "23.72469206",
+                "cmUnrealizedPNL": "",
+                "updateTime": 1617939110373_u64,
+                "negativeBalance": "0"
+            }),
+        );
+        let client = client(&transport);
+
+        let request = AssetBalanceRequest {
+            asset: "USDT".to_string(),
+            recv_window: None,
+        };
+        let response = client.get_asset_balance(request).await.unwrap();
+        assert_eq!(response.data.um_unrealized_pnl, "23.72469206");
+
+        let sent = transport.last_request().unwrap();
+        let params = signed_params(sent.query_string().unwrap()).await;
+        assert_eq!(
+            params.first(),
+            Some(&("asset".to_string(), "USDT".to_string()))
+        );
+    }
+}
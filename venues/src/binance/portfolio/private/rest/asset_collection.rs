@@ -0,0 +1,45 @@
+// Fund Collection by Asset (TRADE) endpoint implementation for POST /papi/v1/asset-collection
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Fund-Collection-by-Asset>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::auto_collection::CollectionResponse;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for collecting a single asset from the futures accounts into the margin account.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetCollectionRequest {
+    /// Asset to collect (e.g., "USDT").
+    pub asset: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Collects a single asset from the UM and CM accounts into the margin account.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Fund-Collection-by-Asset>
+    /// POST /papi/v1/asset-collection
+    /// Weight: 30
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AssetCollectionRequest`])
+    ///
+    /// # Returns
+    /// A [`CollectionResponse`] acknowledging the collection.
+    pub async fn post_asset_collection(&self, params: AssetCollectionRequest) -> RestResult<CollectionResponse> {
+        self.send_signed_request(
+            "/papi/v1/asset-collection",
+            reqwest::Method::POST,
+            params,
+            30,
+            false,
+        )
+        .await
+    }
+}
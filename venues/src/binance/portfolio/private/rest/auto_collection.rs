@@ -0,0 +1,50 @@
+// Fund Auto collection (TRADE) endpoint implementation for POST /papi/v1/auto-collection
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Fund-Auto-collection>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for collecting all assets from the futures accounts into the margin account.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCollectionRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Acknowledgement of a fund collection.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CollectionResponse {
+    /// "success" once the collection is done.
+    pub msg: String,
+}
+
+impl RestClient {
+    /// Collects all assets from the UM and CM accounts into the margin account.
+    ///
+    /// Assets held as margin for open positions or orders are left in place.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Fund-Auto-collection>
+    /// POST /papi/v1/auto-collection
+    /// Weight: 750
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AutoCollectionRequest`])
+    ///
+    /// # Returns
+    /// A [`CollectionResponse`] acknowledging the collection.
+    pub async fn post_auto_collection(&self, params: AutoCollectionRequest) -> RestResult<CollectionResponse> {
+        self.send_signed_request(
+            "/papi/v1/auto-collection",
+            reqwest::Method::POST,
+            params,
+            750,
+            false,
+        )
+        .await
+    }
+}
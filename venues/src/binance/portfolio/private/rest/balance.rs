@@ -0,0 +1,82 @@
+// Account Balance (USER_DATA) endpoint implementation for GET /papi/v1/balance
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Account-Balance>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for the balances of all assets.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BalancesRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Balance of an asset across the margin, UM and CM accounts.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    /// Asset name.
+    pub asset: String,
+
+    /// Wallet balance across the margin, UM and CM accounts.
+    pub total_wallet_balance: String,
+
+    /// Cross margin balance, free plus locked.
+    pub cross_margin_asset: String,
+
+    /// Amount borrowed on cross margin.
+    pub cross_margin_borrowed: String,
+
+    /// Cross margin balance available to trade.
+    pub cross_margin_free: String,
+
+    /// Interest owed on cross margin.
+    pub cross_margin_interest: String,
+
+    /// Cross margin balance held by open orders.
+    pub cross_margin_locked: String,
+
+    /// Wallet balance of the UM account.
+    pub um_wallet_balance: String,
+
+    /// Unrealized profit of the UM positions.
+    #[serde(rename = "umUnrealizedPNL")]
+    pub um_unrealized_pnl: String,
+
+    /// Wallet balance of the CM account.
+    pub cm_wallet_balance: String,
+
+    /// Unrealized profit of the CM positions.
+    #[serde(rename = "cmUnrealizedPNL")]
+    pub cm_unrealized_pnl: String,
+
+    /// Last update of the balance (ms since epoch).
+    pub update_time: u64,
+
+    /// Negative balance of the asset, 0 if none.
+    pub negative_balance: String,
+}
+
+impl RestClient {
+    /// Fetches the balances of all assets. Binance returns an object rather than an array when
+    /// an asset is sent, hence the separate [`RestClient::get_asset_balance`].
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Account-Balance>
+    /// GET /papi/v1/balance
+    /// Weight: 20
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`BalancesRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`Balance`] objects.
+    pub async fn get_balances(&self, params: BalancesRequest) -> RestResult<Vec<Balance>> {
+        self.send_signed_request("/papi/v1/balance", reqwest::Method::GET, params, 20, false)
+            .await
+    }
+}
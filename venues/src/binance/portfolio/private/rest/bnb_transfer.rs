@@ -0,0 +1,86 @@
+// BNB Transfer (TRADE) endpoint implementation for POST /papi/v1/bnb-transfer
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/BNB-transfer>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{RestResult, TransferSide};
+
+/// Request parameters for transferring BNB between the margin and UM accounts.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BnbTransferRequest {
+    /// Amount of BNB to transfer.
+    pub amount: String,
+
+    /// Transfer direction.
+    pub transfer_side: TransferSide,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Transaction ID of a BNB transfer.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BnbTransferResponse {
+    /// Transaction ID.
+    pub tran_id: u64,
+}
+
+impl RestClient {
+    /// Transfers BNB between the margin and UM accounts, e.g. to pay UM fees in BNB.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/BNB-transfer>
+    /// POST /papi/v1/bnb-transfer
+    /// Weight: 750
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`BnbTransferRequest`])
+    ///
+    /// # Returns
+    /// The [`BnbTransferResponse`] with the transaction ID.
+    pub async fn post_bnb_transfer(&self, params: BnbTransferRequest) -> RestResult<BnbTransferResponse> {
+        self.send_signed_request(
+            "/papi/v1/bnb-transfer",
+            reqwest::Method::POST,
+            params,
+            750,
+            false,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::portfolio::private::rest::client::tests::client;
+    use crate::binance::shared::test_support::signed_params;
+
+    #[tokio::test]
+    async fn test_bnb_transfer_sends_the_transfer_side() {
+        let transport = MockTransport::new();
+        transport.push_json(StatusCode::OK, json!({"tranId": 100000001_u64}));
+        let client = client(&transport);
+
+        let request = BnbTransferRequest {
+            amount: "0.5".to_string(),
+            transfer_side: TransferSide::ToUm,
+            recv_window: None,
+        };
+        let response = client.post_bnb_transfer(request).await.unwrap();
+        assert_eq!(response.data.tran_id, 100000001);
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::POST);
+        let params = signed_params(sent.body.as_deref().unwrap()).await;
+        assert!(params.contains(&("transferSide".to_string(), "TO_UM".to_string())));
+    }
+}
@@ -25,48 +25,38 @@
 //! - **Request Signing**: For private endpoints, query parameters (including timestamp) must be
 //!   signed using HMAC-SHA256 with the API secret
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use hex;
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
-use sha2::Sha256;
+use async_trait::async_trait;
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::HttpTransport;
 
-use crate::binance::portfolio::{Errors, RateLimiter, RestResult};
-
-/// Signs a request using the decrypted API secret
-/// Signs a query string using the decrypted API secret and returns the signature as a hex string.
-///
-/// # Arguments
-/// * `query_string` - The query string to sign
-///
-/// # Returns
-/// A result containing the signature as a hex string or a Hmac error if signing fails.
-#[allow(dead_code)]
-fn sign_request(api_secret: &dyn ExposableSecret, query_string: &str) -> Result<String, Errors> {
-    let api_secret = api_secret.expose_secret();
-    let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-    mac.update(query_string.as_bytes());
-    Ok(hex::encode(mac.finalize().into_bytes()))
-}
+use crate::binance::portfolio::errors::VENUE;
+use crate::binance::portfolio::{RateLimiter, RequestWeight, RestResult};
+use crate::binance::shared::{object_params, signed_payload};
 
 /// A client for interacting with the Binance Portfolio Margin private REST API
 ///
 /// This client handles encrypted API keys and secrets for enhanced security.
 /// The API key and secret are stored in encrypted form and only decrypted when needed.
 #[non_exhaustive]
-#[allow(dead_code)]
 pub struct RestClient {
     /// The underlying HTTP client used for making requests.
-    pub(crate) client: Client,
+    pub(crate) client: Arc<dyn HttpTransport>,
     /// The rate limiter for this client.
     pub(crate) rate_limiter: RateLimiter,
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
     /// The base URL for the API.
     pub(crate) base_url: Cow<'static, str>,
+    /// The server clock signed requests are timestamped with.
+    pub(crate) clock: ServerClock,
 }
 
 impl RestClient {
@@ -74,7 +64,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `encrypted_api_key` - The encrypted API key
-    /// * `encrypted_api_secret` - The encrypted API secret
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `encryption_key` - The key used for decrypting the API credentials
     ///
@@ -82,20 +72,27 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
         rate_limiter: RateLimiter,
-        client: Client,
+        client: impl HttpTransport + 'static,
     ) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             rate_limiter,
             api_key,
             api_secret,
             base_url: base_url.into(),
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `timestamp` of signed requests from `clock` instead of the local clock
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Sends a request to the Binance Portfolio Margin API
     ///
     /// This method encapsulates all the logic for making authenticated requests to the Binance API,
@@ -105,19 +102,18 @@ impl RestClient {
     /// * `endpoint` - The API endpoint path (e.g., "/papi/v1/account")
     /// * `method` - The HTTP method to use
     /// * `query_string` - Optional query string parameters (for GET or for URL params)
-    /// * `body` - Optional body (for POST/PUT/DELETE with x-www-form-urlencoded)
+    /// * `body` - Optional x-www-form-urlencoded body (for POST/PUT/DELETE)
     /// * `weight` - The request weight for this endpoint
     /// * `is_order` - Whether this is an order-related endpoint
     ///
     /// # Returns
     /// A result containing the parsed response data and metadata, or an error
-    #[allow(dead_code)]
     pub(super) async fn send_request<T>(
         &self,
         endpoint: &str,
         method: reqwest::Method,
         query_string: Option<&str>,
-        body: Option<&[(&str, &str)]>,
+        body: Option<&str>,
         weight: u32,
         is_order: bool,
     ) -> RestResult<T>
@@ -129,21 +125,18 @@ impl RestClient {
         if !self.api_key.expose_secret().is_empty() {
             headers.push(("X-MBX-APIKEY", self.api_key.expose_secret()));
         }
-        let body_data = body
-            .map(|b| serde_urlencoded::to_string(b).map_err(|e| Errors::Error(format!("Failed to serialize body: {}", e))))
-            .transpose()?;
-        if body_data.is_some() {
+        if body.is_some() {
             headers.push((
                 "Content-Type",
                 "application/x-www-form-urlencoded".to_string(),
             ));
         }
         let rest_response = crate::binance::portfolio::rest::common::send_rest_request(
-            &self.client,
+            self.client.as_ref(),
             &url,
             method,
             headers,
-            body_data.as_deref(),
+            body,
             &self.rate_limiter,
             weight,
             is_order,
@@ -159,45 +152,108 @@ impl RestClient {
     /// Sends a signed request to the Binance Portfolio Margin API
     ///
     /// This method automatically handles timestamp generation and request signing for private endpoints.
-    /// It appends the current timestamp and generates the required signature.
+    /// It stamps the request with the server clock's time, adds a `recvWindow` once the clock is
+    /// synced, and generates the required signature.
     ///
     /// # Arguments
     /// * `endpoint` - The API endpoint path (e.g., "/papi/v1/account")
     /// * `method` - The HTTP method to use
-    /// * `request` - The request parameters implementing PrivateRequest
+    /// * `request` - The request parameters, sent in the query string for GET and as the form body otherwise
     /// * `weight` - The request weight for this endpoint
     /// * `is_order` - Whether this is an order-related endpoint
     ///
     /// # Returns
     /// A result containing the parsed response data and metadata, or an error
-    #[allow(dead_code)]
     pub(super) async fn send_signed_request<T, R>(&self, endpoint: &str, method: reqwest::Method, request: R, weight: u32, is_order: bool) -> RestResult<T>
     where
         T: serde::de::DeserializeOwned,
         R: serde::Serialize,
     {
-        let serialized = serde_urlencoded::to_string(&request).map_err(|e| {
-            crate::binance::portfolio::Errors::Error(format!(
-                "Failed to encode params: {}\nBacktrace:\n{}",
-                e,
-                std::backtrace::Backtrace::capture()
-            ))
-        })?;
-        let signature = sign_request(self.api_secret.as_ref(), &serialized)?;
-        let signed = format!("{}&signature={}", serialized, signature);
+        let signed = signed_payload(&self.clock, self.api_secret.as_ref(), &request).await?;
         if method == reqwest::Method::GET {
             self.send_request(endpoint, method, Some(&signed), None, weight, is_order)
                 .await
         } else {
-            self.send_request(
-                endpoint,
-                method,
-                None,
-                Some(&[("signed", signed.as_str())]),
-                weight,
-                is_order,
+            self.send_request(endpoint, method, None, Some(&signed), weight, is_order)
+                .await
+        }
+    }
+}
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Signs every request. `send_signed_request` stamps the `timestamp` from the server clock.
+    async fn send(&self, request: RestRequest<RequestWeight>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let params = object_params(VENUE, request.params)?;
+        let response = self
+            .send_signed_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                params,
+                request.rate_limit_key.weight,
+                request.rate_limit_key.is_order,
             )
             .await
-        }
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use reqwest::Method;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::shared::test_support::{self, API_KEY, API_SECRET, TestSecret};
+
+    pub(in crate::binance::portfolio) fn client(transport: &MockTransport) -> RestClient {
+        RestClient::new(
+            Box::new(TestSecret(API_KEY)),
+            Box::new(TestSecret(API_SECRET)),
+            "https://papi.binance.com",
+            RateLimiter::new(),
+            transport.clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_is_signed_in_query_string() {
+        let transport = MockTransport::new();
+        let request = RestRequest::new(Method::GET, "/papi/v1/um/openOrders", RequestWeight::new(1)).with_params(json!({ "symbol": "BTCUSDT" }));
+
+        let params = test_support::send_signed_get(&client(&transport), &transport, request).await;
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["symbol", "timestamp"]);
+    }
+
+    #[tokio::test]
+    async fn test_post_is_signed_in_form_body() {
+        let transport = MockTransport::new();
+        let request = RestRequest::new(Method::POST, "/papi/v1/marginLoan", RequestWeight::new(100)).with_params(json!({
+            "asset": "USDT",
+            "amount": "100",
+        }));
+
+        let (sent, params) = test_support::send_signed_form(&client(&transport), &transport, request).await;
+        assert_eq!(sent.url, "https://papi.binance.com/papi/v1/marginLoan");
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["amount", "asset", "timestamp"]);
     }
 }
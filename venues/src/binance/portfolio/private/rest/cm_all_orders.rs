@@ -0,0 +1,68 @@
+// Query All CM Orders (USER_DATA) endpoint implementation for GET /papi/v1/cm/allOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-CM-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::cm_order::CmOrder;
+
+/// Request parameters for all CM orders of a symbol or pair.
+/// Either `symbol` or `pair` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CmAllOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Underlying pair (e.g., "BTCUSD").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+
+    /// Only return orders from this order ID on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch). At most 7 days before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of orders (default 50, max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches all CM orders of a symbol or pair: open, cancelled or filled.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-CM-Orders>
+    /// GET /papi/v1/cm/allOrders
+    /// Weight: 20 with a symbol, 40 with a pair
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CmAllOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`CmOrder`] objects.
+    pub async fn get_cm_all_orders(&self, params: CmAllOrdersRequest) -> RestResult<Vec<CmOrder>> {
+        let weight = if params.symbol.is_some() { 20 } else { 40 };
+        self.send_signed_request(
+            "/papi/v1/cm/allOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
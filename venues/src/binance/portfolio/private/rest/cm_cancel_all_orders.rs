@@ -0,0 +1,45 @@
+// Cancel All CM Open Orders (TRADE) endpoint implementation for DELETE /papi/v1/cm/allOpenOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-All-CM-Open-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::um_cancel_all_orders::CancelAllOrdersResponse;
+
+/// Request parameters for cancelling all open CM orders of a symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllCmOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels all open CM orders of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-All-CM-Open-Orders>
+    /// DELETE /papi/v1/cm/allOpenOrders
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelAllCmOrdersRequest`])
+    ///
+    /// # Returns
+    /// A [`CancelAllOrdersResponse`] acknowledging the cancellation.
+    pub async fn cancel_all_cm_orders(&self, params: CancelAllCmOrdersRequest) -> RestResult<CancelAllOrdersResponse> {
+        self.send_signed_request(
+            "/papi/v1/cm/allOpenOrders",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,55 @@
+// Cancel CM Conditional Order (TRADE) endpoint implementation for
+// DELETE /papi/v1/cm/conditional/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-CM-Conditional-Order>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::cm_conditional_order::CmConditionalOrder;
+
+/// Request parameters for cancelling a CM conditional order.
+/// Either `strategy_id` or `new_client_strategy_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelCmConditionalOrderRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Strategy ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+
+    /// Client strategy ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_strategy_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels an open CM conditional order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-CM-Conditional-Order>
+    /// DELETE /papi/v1/cm/conditional/order
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelCmConditionalOrderRequest`])
+    ///
+    /// # Returns
+    /// The cancelled [`CmConditionalOrder`].
+    pub async fn cancel_cm_conditional_order(&self, params: CancelCmConditionalOrderRequest) -> RestResult<CmConditionalOrder> {
+        self.send_signed_request(
+            "/papi/v1/cm/conditional/order",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,54 @@
+// Cancel CM Order (TRADE) endpoint implementation for DELETE /papi/v1/cm/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-CM-Order>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::cm_order::CmOrder;
+
+/// Request parameters for cancelling a CM order.
+/// Either `order_id` or `orig_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelCmOrderRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Order ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels an active CM order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-CM-Order>
+    /// DELETE /papi/v1/cm/order
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelCmOrderRequest`])
+    ///
+    /// # Returns
+    /// The cancelled [`CmOrder`].
+    pub async fn cancel_cm_order(&self, params: CancelCmOrderRequest) -> RestResult<CmOrder> {
+        self.send_signed_request(
+            "/papi/v1/cm/order",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
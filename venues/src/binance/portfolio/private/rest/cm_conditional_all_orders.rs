@@ -0,0 +1,65 @@
+// Query All CM Conditional Orders (TRADE) endpoint implementation for
+// GET /papi/v1/cm/conditional/allOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-CM-Conditional-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::cm_conditional_order::CmConditionalOrder;
+
+/// Request parameters for the CM conditional order history.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CmConditionalAllOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    /// If not sent, will return orders for all symbols at a much higher weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Only return orders from this strategy ID on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+
+    /// Start time (ms since epoch). At most 7 days before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of orders (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the CM conditional order history of a symbol, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-CM-Conditional-Orders>
+    /// GET /papi/v1/cm/conditional/allOrders
+    /// Weight: 1 for a single symbol, 40 when the symbol is omitted
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CmConditionalAllOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`CmConditionalOrder`] objects.
+    pub async fn get_cm_conditional_all_orders(&self, params: CmConditionalAllOrdersRequest) -> RestResult<Vec<CmConditionalOrder>> {
+        let weight = if params.symbol.is_some() { 1 } else { 40 };
+        self.send_signed_request(
+            "/papi/v1/cm/conditional/allOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,49 @@
+// Query All Current CM Open Conditional Orders (TRADE) endpoint implementation for
+// GET /papi/v1/cm/conditional/openOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Current-CM-Open-Conditional-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::cm_conditional_order::CmConditionalOrder;
+
+/// Request parameters for the open CM conditional orders.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CmConditionalOpenOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    /// If not sent, will return orders for all symbols at a much higher weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the open CM conditional orders of a symbol, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Current-CM-Open-Conditional-Orders>
+    /// GET /papi/v1/cm/conditional/openOrders
+    /// Weight: 1 for a single symbol, 40 when the symbol is omitted
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CmConditionalOpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`CmConditionalOrder`] objects.
+    pub async fn get_cm_conditional_open_orders(&self, params: CmConditionalOpenOrdersRequest) -> RestResult<Vec<CmConditionalOrder>> {
+        let weight = if params.symbol.is_some() { 1 } else { 40 };
+        self.send_signed_request(
+            "/papi/v1/cm/conditional/openOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
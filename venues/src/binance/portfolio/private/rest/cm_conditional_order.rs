@@ -0,0 +1,165 @@
+// New CM Conditional Order (TRADE) endpoint implementation for POST /papi/v1/cm/conditional/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-CM-Conditional-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{OrderSide, OrderStatus, PositionSide, RestResult, StrategyStatus, StrategyType, TimeInForce, WorkingType};
+
+/// Request parameters for placing a new CM conditional order (POST /papi/v1/cm/conditional/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewCmConditionalOrderRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Position side (BOTH, LONG, SHORT). Must be LONG or SHORT in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+
+    /// Conditional order type.
+    pub strategy_type: StrategyType,
+
+    /// Time in force (GTC, IOC, FOK, GTX).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order quantity in contracts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+
+    /// Only reduce the position. Cannot be sent in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// Order price, for STOP and TAKE_PROFIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Price the stop price is compared with (default CONTRACT_PRICE).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_type: Option<WorkingType>,
+
+    /// Protect the trigger against mark and contract price divergence (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_protect: Option<bool>,
+
+    /// Unique client strategy ID. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_strategy_id: Option<String>,
+
+    /// Trigger price, for STOP, STOP_MARKET, TAKE_PROFIT and TAKE_PROFIT_MARKET orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+
+    /// Activation price of TRAILING_STOP_MARKET orders. Default the latest price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<String>,
+
+    /// Callback rate in percent of TRAILING_STOP_MARKET orders, 0.1 to 5.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_rate: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A CM conditional order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmConditionalOrder {
+    /// Client strategy ID.
+    pub new_client_strategy_id: String,
+
+    /// Strategy ID.
+    pub strategy_id: u64,
+
+    /// Status of the conditional order.
+    pub strategy_status: StrategyStatus,
+
+    /// Conditional order type.
+    pub strategy_type: StrategyType,
+
+    /// Order quantity in contracts.
+    pub orig_qty: String,
+
+    /// Price of the order placed on trigger.
+    pub price: String,
+
+    /// Whether the order only reduces the position.
+    pub reduce_only: bool,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Position side.
+    pub position_side: PositionSide,
+
+    /// Trigger price.
+    pub stop_price: Option<String>,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Activation price, only returned for TRAILING_STOP_MARKET orders.
+    pub activate_price: Option<String>,
+
+    /// Callback rate, only returned for TRAILING_STOP_MARKET orders.
+    pub price_rate: Option<String>,
+
+    /// Time the conditional order was placed (ms since epoch).
+    pub book_time: u64,
+
+    /// Last update of the conditional order (ms since epoch).
+    pub update_time: u64,
+
+    /// Price the stop price is compared with.
+    pub working_type: WorkingType,
+
+    /// Whether the trigger is protected against mark and contract price divergence.
+    pub price_protect: bool,
+
+    /// ID of the order placed on trigger, only returned by history queries once triggered.
+    pub order_id: Option<u64>,
+
+    /// Status of the order placed on trigger, only returned by history queries once triggered.
+    pub status: Option<OrderStatus>,
+
+    /// Trigger time, only returned by history queries once triggered.
+    pub trigger_time: Option<u64>,
+}
+
+impl RestClient {
+    /// Places a new CM conditional order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-CM-Conditional-Order>
+    /// POST /papi/v1/cm/conditional/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewCmConditionalOrderRequest`])
+    ///
+    /// # Returns
+    /// The new [`CmConditionalOrder`].
+    pub async fn post_cm_conditional_order(&self, params: NewCmConditionalOrderRequest) -> RestResult<CmConditionalOrder> {
+        self.send_signed_request(
+            "/papi/v1/cm/conditional/order",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,68 @@
+// Get CM Income History (USER_DATA) endpoint implementation for GET /papi/v1/cm/income
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Get-CM-Income-History>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::um_income::IncomeRecord;
+use crate::binance::portfolio::{IncomeType, RestResult};
+
+/// Request parameters for the CM income history.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CmIncomeHistoryRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Only return income of this type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub income_type: Option<IncomeType>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Page number, starting at 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Limit (default 100, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the CM income history, such as funding fees, commissions and realized PnL.
+    ///
+    /// Without a time range only the last 200 records of the last 7 days are returned.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Get-CM-Income-History>
+    /// GET /papi/v1/cm/income
+    /// Weight: 30
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CmIncomeHistoryRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`IncomeRecord`] objects.
+    pub async fn get_cm_income(&self, params: CmIncomeHistoryRequest) -> RestResult<Vec<IncomeRecord>> {
+        self.send_signed_request(
+            "/papi/v1/cm/income",
+            reqwest::Method::GET,
+            params,
+            30,
+            false,
+        )
+        .await
+    }
+}
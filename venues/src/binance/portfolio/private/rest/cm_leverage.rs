@@ -0,0 +1,61 @@
+// Change CM Initial Leverage (TRADE) endpoint implementation for POST /papi/v1/cm/leverage
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Change-CM-Initial-Leverage>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Response for a CM leverage change.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmLeverageResponse {
+    /// New initial leverage.
+    pub leverage: u32,
+
+    /// Maximum quantity at the new leverage.
+    pub max_qty: String,
+
+    /// Trading symbol.
+    pub symbol: String,
+}
+
+/// Request parameters for changing the initial leverage of a CM symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeCmLeverageRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Target initial leverage, 1 to 125.
+    pub leverage: u32,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Changes the initial leverage of a CM symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Change-CM-Initial-Leverage>
+    /// POST /papi/v1/cm/leverage
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ChangeCmLeverageRequest`])
+    ///
+    /// # Returns
+    /// The [`CmLeverageResponse`].
+    pub async fn post_cm_leverage(&self, params: ChangeCmLeverageRequest) -> RestResult<CmLeverageResponse> {
+        self.send_signed_request(
+            "/papi/v1/cm/leverage",
+            reqwest::Method::POST,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
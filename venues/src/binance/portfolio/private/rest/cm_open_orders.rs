@@ -0,0 +1,57 @@
+// Query All Current CM Open Orders (USER_DATA) endpoint implementation for
+// GET /papi/v1/cm/openOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Current-CM-Open-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::cm_order::CmOrder;
+
+/// Request parameters for the open CM orders.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CmOpenOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Underlying pair (e.g., "BTCUSD").
+    /// If neither `symbol` nor `pair` is sent, orders of all symbols are returned at a much higher weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the open CM orders of a symbol or pair, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Current-CM-Open-Orders>
+    /// GET /papi/v1/cm/openOrders
+    /// Weight: 1 for a single symbol or pair, 40 when both are omitted
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CmOpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`CmOrder`] objects.
+    pub async fn get_cm_open_orders(&self, params: CmOpenOrdersRequest) -> RestResult<Vec<CmOrder>> {
+        let weight = if params.symbol.is_some() || params.pair.is_some() {
+            1
+        } else {
+            40
+        };
+        self.send_signed_request(
+            "/papi/v1/cm/openOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
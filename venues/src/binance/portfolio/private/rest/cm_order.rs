@@ -0,0 +1,138 @@
+// New CM Order (TRADE) endpoint implementation for POST /papi/v1/cm/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-CM-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{OrderResponseType, OrderSide, OrderStatus, OrderType, PositionSide, RestResult, TimeInForce};
+
+/// Request parameters for placing a new CM order (POST /papi/v1/cm/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewCmOrderRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Position side (BOTH, LONG, SHORT). Must be LONG or SHORT in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+
+    /// Order type (LIMIT or MARKET).
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Time in force (GTC, IOC, FOK, GTX). Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order quantity in contracts.
+    pub quantity: String,
+
+    /// Only reduce the position. Cannot be sent in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// Order price. Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Unique client order ID. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// New order response type (ACK or RESULT). Default ACK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A CM order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmOrder {
+    /// Client order ID.
+    pub client_order_id: String,
+
+    /// Quantity filled so far, in contracts.
+    pub cum_qty: String,
+
+    /// Base asset value filled so far.
+    pub cum_base: String,
+
+    /// Quantity filled so far, in contracts.
+    pub executed_qty: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Average fill price, 0 before any fill.
+    pub avg_price: String,
+
+    /// Order quantity in contracts.
+    pub orig_qty: String,
+
+    /// Order price, 0 for market orders.
+    pub price: String,
+
+    /// Whether the order only reduces the position.
+    pub reduce_only: bool,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Position side.
+    pub position_side: PositionSide,
+
+    /// Order status.
+    pub status: OrderStatus,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Creation time, only returned by queries.
+    pub time: Option<u64>,
+
+    /// Last update of the order (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Places a new CM order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-CM-Order>
+    /// POST /papi/v1/cm/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewCmOrderRequest`])
+    ///
+    /// # Returns
+    /// The new [`CmOrder`].
+    pub async fn post_cm_order(&self, params: NewCmOrderRequest) -> RestResult<CmOrder> {
+        self.send_signed_request(
+            "/papi/v1/cm/order",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,89 @@
+// Query CM Position Information (USER_DATA) endpoint implementation for
+// GET /papi/v1/cm/positionRisk
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-CM-Position-Information>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{PositionSide, RestResult};
+
+/// Request parameters for the CM position information.
+/// `margin_asset` and `pair` cannot be sent together.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CmPositionRiskRequest {
+    /// Margin asset (e.g., "BTC").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_asset: Option<String>,
+
+    /// Underlying pair (e.g., "BTCUSD").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A CM position.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmPositionRisk {
+    /// Average entry price.
+    pub entry_price: String,
+
+    /// Initial leverage.
+    pub leverage: String,
+
+    /// Mark price.
+    pub mark_price: String,
+
+    /// Maximum quantity at the current leverage.
+    pub max_qty: String,
+
+    /// Position quantity, negative for short positions.
+    pub position_amt: String,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Unrealized profit.
+    pub un_realized_profit: String,
+
+    /// Estimated liquidation price.
+    pub liquidation_price: String,
+
+    /// Position side.
+    pub position_side: PositionSide,
+
+    /// Last update of the position (ms since epoch).
+    pub update_time: u64,
+
+    /// Position value at the mark price, in the margin asset.
+    pub notional_value: String,
+}
+
+impl RestClient {
+    /// Fetches the CM positions of a margin asset or pair, or all of them.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-CM-Position-Information>
+    /// GET /papi/v1/cm/positionRisk
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CmPositionRiskRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`CmPositionRisk`] objects.
+    pub async fn get_cm_position_risk(&self, params: CmPositionRiskRequest) -> RestResult<Vec<CmPositionRisk>> {
+        self.send_signed_request(
+            "/papi/v1/cm/positionRisk",
+            reqwest::Method::GET,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
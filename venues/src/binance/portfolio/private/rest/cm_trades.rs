@@ -0,0 +1,120 @@
+// CM Account Trade List (USER_DATA) endpoint implementation for GET /papi/v1/cm/userTrades
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/CM-Account-Trade-List>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{OrderSide, PositionSide, RestResult};
+
+/// Request parameters for the CM account trade list.
+/// Either `symbol` or `pair` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CmUserTradesRequest {
+    /// Trading symbol (e.g., "BTCUSD_PERP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Underlying pair (e.g., "BTCUSD").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+
+    /// Start time (ms since epoch). At most 7 days before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Trade ID to fetch from. Cannot be combined with `pair`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Number of trades (default 50, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A CM account trade.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CmUserTrade {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Trade ID.
+    pub id: u64,
+
+    /// ID of the order that traded.
+    pub order_id: u64,
+
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Side of the account.
+    pub side: OrderSide,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity in contracts.
+    pub qty: String,
+
+    /// Profit realized by the trade.
+    pub realized_pnl: String,
+
+    /// Margin asset.
+    pub margin_asset: String,
+
+    /// Quantity in the base asset.
+    pub base_qty: String,
+
+    /// Commission paid, negative for rebates.
+    pub commission: String,
+
+    /// Asset the commission was paid in.
+    pub commission_asset: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+
+    /// Position side.
+    pub position_side: PositionSide,
+
+    /// Whether the account bought.
+    pub buyer: bool,
+
+    /// Whether the account was the maker.
+    pub maker: bool,
+}
+
+impl RestClient {
+    /// Fetches the CM trades of a symbol or pair.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/CM-Account-Trade-List>
+    /// GET /papi/v1/cm/userTrades
+    /// Weight: 20 with a symbol, 40 with a pair
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CmUserTradesRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`CmUserTrade`] objects.
+    pub async fn get_cm_user_trades(&self, params: CmUserTradesRequest) -> RestResult<Vec<CmUserTrade>> {
+        let weight = if params.symbol.is_some() { 20 } else { 40 };
+        self.send_signed_request(
+            "/papi/v1/cm/userTrades",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,62 @@
+// Query All Margin Account Orders (USER_DATA) endpoint implementation for
+// GET /papi/v1/margin/allOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Margin-Account-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::margin_query_order::MarginOrder;
+
+/// Request parameters for all margin orders of a symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAllOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Only return orders from this order ID on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch). At most 24 hours before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of orders (default 500, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches all margin orders of a symbol: open, cancelled or filled.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Margin-Account-Orders>
+    /// GET /papi/v1/margin/allOrders
+    /// Weight: 100
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MarginAllOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`MarginOrder`] objects.
+    pub async fn get_margin_all_orders(&self, params: MarginAllOrdersRequest) -> RestResult<Vec<MarginOrder>> {
+        self.send_signed_request(
+            "/papi/v1/margin/allOrders",
+            reqwest::Method::GET,
+            params,
+            100,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,183 @@
+// Cancel Margin Account All Open Orders on a Symbol (TRADE) endpoint implementation for
+// DELETE /papi/v1/margin/allOpenOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-Margin-Account-All-Open-Orders-on-a-Symbol>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::margin_cancel_order::MarginCancelledOrder;
+use crate::binance::portfolio::{ContingencyType, ListOrderStatus, ListStatusType, RestResult};
+
+/// An order of a margin order list.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginListOrder {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Client order ID.
+    pub client_order_id: String,
+}
+
+/// A cancelled margin order list.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCancelledOrderList {
+    /// Order list ID.
+    pub order_list_id: i64,
+
+    /// Contingency type of the list.
+    pub contingency_type: ContingencyType,
+
+    /// Status of the list.
+    pub list_status_type: ListStatusType,
+
+    /// Status of the orders of the list.
+    pub list_order_status: ListOrderStatus,
+
+    /// Client ID of the order list.
+    pub list_client_order_id: String,
+
+    /// Transaction time (ms since epoch).
+    pub transaction_time: u64,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Orders of the list.
+    pub orders: Vec<MarginListOrder>,
+
+    /// Cancelled orders of the list.
+    pub order_reports: Vec<MarginCancelledOrder>,
+}
+
+/// An order or order list cancelled by [`RestClient::cancel_all_margin_orders`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum MarginCancelledOpenOrder {
+    /// A cancelled order list.
+    OrderList(MarginCancelledOrderList),
+
+    /// A cancelled order outside of any list.
+    Order(MarginCancelledOrder),
+}
+
+/// Request parameters for cancelling all open margin orders of a symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllMarginOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels all open margin orders of a symbol, including the orders of order lists.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-Margin-Account-All-Open-Orders-on-a-Symbol>
+    /// DELETE /papi/v1/margin/allOpenOrders
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelAllMarginOrdersRequest`])
+    ///
+    /// # Returns
+    /// The cancelled orders and order lists.
+    pub async fn cancel_all_margin_orders(&self, params: CancelAllMarginOrdersRequest) -> RestResult<Vec<MarginCancelledOpenOrder>> {
+        self.send_signed_request(
+            "/papi/v1/margin/allOpenOrders",
+            reqwest::Method::DELETE,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::portfolio::private::rest::client::tests::client;
+
+    #[tokio::test]
+    async fn test_cancel_all_tells_orders_and_order_lists_apart() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!([
+                {
+                    "symbol": "BTCUSDT",
+                    "origClientOrderId": "E6APeyTJvkMvLMYMqu1KQ4",
+                    "orderId": 11,
+                    "clientOrderId": "pXLV6Hz6mprAcVYpVMTGgx",
+                    "price": "0.089853",
+                    "origQty": "0.178622",
+                    "executedQty": "0.000000",
+                    "cummulativeQuoteQty": "0.000000",
+                    "status": "CANCELED",
+                    "timeInForce": "GTC",
+                    "type": "LIMIT",
+                    "side": "BUY"
+                },
+                {
+                    "orderListId": 1929,
+                    "contingencyType": "OCO",
+                    "listStatusType": "ALL_DONE",
+                    "listOrderStatus": "ALL_DONE",
+                    "listClientOrderId": "2inzWQdDvZLHbbAmAozX2N",
+                    "transactionTime": 1585230948299_u64,
+                    "symbol": "BTCUSDT",
+                    "orders": [
+                        {"symbol": "BTCUSDT", "orderId": 20, "clientOrderId": "CwOOIPHSmYywx6jZX77TdL"},
+                        {"symbol": "BTCUSDT", "orderId": 21, "clientOrderId": "461cPg51vQjV3zIMOXNz39"}
+                    ],
+                    "orderReports": [
+                        {
+                            "symbol": "BTCUSDT",
+                            "origClientOrderId": "CwOOIPHSmYywx6jZX77TdL",
+                            "orderId": 20,
+                            "clientOrderId": "pXLV6Hz6mprAcVYpVMTGgx",
+                            "price": "0.668611",
+                            "origQty": "0.690354",
+                            "executedQty": "0.000000",
+                            "cummulativeQuoteQty": "0.000000",
+                            "status": "CANCELED",
+                            "timeInForce": "GTC",
+                            "type": "STOP_LOSS_LIMIT",
+                            "side": "BUY"
+                        }
+                    ]
+                }
+            ]),
+        );
+        let client = client(&transport);
+
+        let request = CancelAllMarginOrdersRequest {
+            symbol: "BTCUSDT".to_string(),
+            recv_window: None,
+        };
+        let response = client.cancel_all_margin_orders(request).await.unwrap();
+        assert!(matches!(
+            response.data.first(),
+            Some(MarginCancelledOpenOrder::Order(order)) if order.order_id == 11
+        ));
+        assert!(matches!(
+            response.data.get(1),
+            Some(MarginCancelledOpenOrder::OrderList(list))
+                if list.contingency_type == ContingencyType::Oco && list.orders.len() == 2
+        ));
+        assert_eq!(transport.last_request().unwrap().method, Method::DELETE);
+    }
+}
@@ -0,0 +1,99 @@
+// Cancel Margin Account Order (TRADE) endpoint implementation for DELETE /papi/v1/margin/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-Margin-Account-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{MarginOrderType, OrderSide, OrderStatus, RestResult, TimeInForce};
+
+/// Request parameters for cancelling a margin order.
+/// Either `order_id` or `orig_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelMarginOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// New client order ID for the cancellation. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A cancelled margin order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCancelledOrder {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Client order ID of the cancelled order.
+    pub orig_client_order_id: String,
+
+    /// Client order ID.
+    pub client_order_id: String,
+
+    /// Order price, 0 for market orders.
+    pub price: String,
+
+    /// Order quantity.
+    pub orig_qty: String,
+
+    /// Quantity filled so far.
+    pub executed_qty: String,
+
+    /// Quote asset value filled so far.
+    pub cummulative_quote_qty: String,
+
+    /// Order status.
+    pub status: OrderStatus,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: MarginOrderType,
+
+    /// Order side.
+    pub side: OrderSide,
+}
+
+impl RestClient {
+    /// Cancels an active margin order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-Margin-Account-Order>
+    /// DELETE /papi/v1/margin/order
+    /// Weight: 2
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelMarginOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`MarginCancelledOrder`].
+    pub async fn cancel_margin_order(&self, params: CancelMarginOrderRequest) -> RestResult<MarginCancelledOrder> {
+        self.send_signed_request(
+            "/papi/v1/margin/order",
+            reqwest::Method::DELETE,
+            params,
+            2,
+            false,
+        )
+        .await
+    }
+}
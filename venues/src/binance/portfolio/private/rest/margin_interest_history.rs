@@ -0,0 +1,107 @@
+// Get Margin Borrow Loan Interest History (USER_DATA) endpoint implementation for
+// GET /papi/v1/margin/marginInterestHistory
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Get-Margin-Borrow-Loan-Interest-History>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for the margin borrow interest history.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterestHistoryRequest {
+    /// Asset (e.g., "USDT"). All assets if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+
+    /// Start time (ms since epoch). At most 30 days before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Page number, starting from 1 (default 1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<u32>,
+
+    /// Page size (default 10, max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+
+    /// Query records older than 6 months (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A margin borrow interest record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterestRecord {
+    /// Transaction ID.
+    pub tx_id: u64,
+
+    /// Accrual time. The misspelling is Binance's.
+    #[serde(rename = "interestAccuredTime")]
+    pub interest_accrued_time: u64,
+
+    /// Asset borrowed.
+    pub asset: String,
+
+    /// Original asset, only returned for isolated margin.
+    pub raw_asset: Option<String>,
+
+    /// Amount borrowed.
+    pub principal: String,
+
+    /// Interest charged.
+    pub interest: String,
+
+    /// Daily interest rate.
+    pub interest_rate: String,
+
+    /// Interest type (e.g., "PERIODIC", "ON_BORROW").
+    #[serde(rename = "type")]
+    pub interest_type: String,
+}
+
+/// A page of margin borrow interest records.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarginInterestHistory {
+    /// Records of the page.
+    pub rows: Vec<MarginInterestRecord>,
+
+    /// Number of records across all pages.
+    pub total: u64,
+}
+
+impl RestClient {
+    /// Fetches the margin borrow interest history.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Get-Margin-Borrow-Loan-Interest-History>
+    /// GET /papi/v1/margin/marginInterestHistory
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MarginInterestHistoryRequest`])
+    ///
+    /// # Returns
+    /// A [`MarginInterestHistory`] page.
+    pub async fn get_margin_interest_history(&self, params: MarginInterestHistoryRequest) -> RestResult<MarginInterestHistory> {
+        self.send_signed_request(
+            "/papi/v1/margin/marginInterestHistory",
+            reqwest::Method::GET,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
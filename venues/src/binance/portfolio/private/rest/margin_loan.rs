@@ -0,0 +1,55 @@
+// Margin Account Borrow (MARGIN) endpoint implementation for POST /papi/v1/marginLoan
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Margin-Account-Borrow>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for borrowing a margin asset.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginLoanRequest {
+    /// Asset to borrow (e.g., "USDT").
+    pub asset: String,
+
+    /// Amount to borrow.
+    pub amount: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Transaction ID of a borrow or repay.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginLoanResponse {
+    /// Transaction ID.
+    pub tran_id: u64,
+}
+
+impl RestClient {
+    /// Borrows an asset into the margin account.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Margin-Account-Borrow>
+    /// POST /papi/v1/marginLoan
+    /// Weight: 100
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MarginLoanRequest`])
+    ///
+    /// # Returns
+    /// The [`MarginLoanResponse`] with the transaction ID.
+    pub async fn post_margin_loan(&self, params: MarginLoanRequest) -> RestResult<MarginLoanResponse> {
+        self.send_signed_request(
+            "/papi/v1/marginLoan",
+            reqwest::Method::POST,
+            params,
+            100,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,48 @@
+// Query Current Margin Open Order (USER_DATA) endpoint implementation for
+// GET /papi/v1/margin/openOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-Current-Margin-Open-Order>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::margin_query_order::MarginOrder;
+
+/// Request parameters for the open margin orders.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOpenOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    /// If not sent, will return orders for all symbols.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the open margin orders of a symbol, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-Current-Margin-Open-Order>
+    /// GET /papi/v1/margin/openOrders
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MarginOpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`MarginOrder`] objects.
+    pub async fn get_margin_open_orders(&self, params: MarginOpenOrdersRequest) -> RestResult<Vec<MarginOrder>> {
+        self.send_signed_request(
+            "/papi/v1/margin/openOrders",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
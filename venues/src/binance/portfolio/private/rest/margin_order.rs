@@ -0,0 +1,166 @@
+// New Margin Order (TRADE) endpoint implementation for POST /papi/v1/margin/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-Margin-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{
+    MarginOrderResponseType, MarginOrderType, OrderSide, OrderStatus, RestResult, SelfTradePreventionMode, SideEffectType, TimeInForce,
+};
+
+/// Request parameters for placing a new margin order (POST /papi/v1/margin/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMarginOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: MarginOrderType,
+
+    /// Order quantity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+
+    /// Quote quantity to spend or receive, for MARKET orders instead of `quantity`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_order_qty: Option<String>,
+
+    /// Order price. Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Trigger price, for STOP_LOSS, STOP_LOSS_LIMIT, TAKE_PROFIT and TAKE_PROFIT_LIMIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+
+    /// Unique client order ID. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// New order response type (ACK, RESULT or FULL).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<MarginOrderResponseType>,
+
+    /// Visible quantity of an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<String>,
+
+    /// Borrow or repay along with the order (default NO_SIDE_EFFECT).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side_effect_type: Option<SideEffectType>,
+
+    /// Time in force (GTC, IOC, FOK). Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Repay the borrowed amount when an AUTO_REPAY order is cancelled (default true).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_repay_at_cancel: Option<bool>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A fill of a margin order, returned with FULL responses.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginFill {
+    /// Fill price.
+    pub price: String,
+
+    /// Fill quantity.
+    pub qty: String,
+
+    /// Commission paid.
+    pub commission: String,
+
+    /// Asset the commission was paid in.
+    pub commission_asset: String,
+}
+
+/// Response for a new margin order. Only the identifiers are returned with ACK responses.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMarginOrderResponse {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Client order ID.
+    pub client_order_id: String,
+
+    /// Transaction time (ms since epoch).
+    pub transact_time: u64,
+
+    /// Order price, 0 for market orders.
+    pub price: Option<String>,
+
+    /// Order quantity.
+    pub orig_qty: Option<String>,
+
+    /// Quantity filled so far.
+    pub executed_qty: Option<String>,
+
+    /// Quote asset value filled so far.
+    pub cummulative_quote_qty: Option<String>,
+
+    /// Order status.
+    pub status: Option<OrderStatus>,
+
+    /// Time in force.
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: Option<MarginOrderType>,
+
+    /// Order side.
+    pub side: Option<OrderSide>,
+
+    /// Borrowed amount, only returned for MARGIN_BUY orders that borrowed.
+    pub margin_buy_borrow_amount: Option<String>,
+
+    /// Borrowed asset, only returned for MARGIN_BUY orders that borrowed.
+    pub margin_buy_borrow_asset: Option<String>,
+
+    /// Fills, only returned with FULL responses.
+    #[serde(default)]
+    pub fills: Vec<MarginFill>,
+}
+
+impl RestClient {
+    /// Places a new cross margin order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-Margin-Order>
+    /// POST /papi/v1/margin/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewMarginOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`NewMarginOrderResponse`].
+    pub async fn post_margin_order(&self, params: NewMarginOrderRequest) -> RestResult<NewMarginOrderResponse> {
+        self.send_signed_request(
+            "/papi/v1/margin/order",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,119 @@
+// Query Margin Account Order (USER_DATA) endpoint implementation for GET /papi/v1/margin/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-Margin-Account-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{MarginOrderType, OrderSide, OrderStatus, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for querying a margin order.
+/// Either `order_id` or `orig_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryMarginOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A margin order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrder {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Client order ID.
+    pub client_order_id: String,
+
+    /// Order price, 0 for market orders.
+    pub price: String,
+
+    /// Order quantity.
+    pub orig_qty: String,
+
+    /// Quantity filled so far.
+    pub executed_qty: String,
+
+    /// Quote asset value filled so far.
+    pub cummulative_quote_qty: String,
+
+    /// Order status.
+    pub status: OrderStatus,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: MarginOrderType,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Trigger price, 0 for orders without one.
+    pub stop_price: String,
+
+    /// Visible quantity of an iceberg order, 0 for other orders.
+    pub iceberg_qty: String,
+
+    /// Creation time of the order (ms since epoch).
+    pub time: u64,
+
+    /// Last update of the order (ms since epoch).
+    pub update_time: u64,
+
+    /// Whether the order is on the order book.
+    pub is_working: bool,
+
+    /// Margin account ID.
+    pub account_id: Option<u64>,
+
+    /// Self-trade prevention mode.
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Only returned for orders expired by self-trade prevention.
+    pub prevented_match_id: Option<u64>,
+
+    /// Only returned for orders expired by self-trade prevention.
+    pub prevented_quantity: Option<String>,
+}
+
+impl RestClient {
+    /// Queries a margin order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-Margin-Account-Order>
+    /// GET /papi/v1/margin/order
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`QueryMarginOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`MarginOrder`].
+    pub async fn get_margin_order(&self, params: QueryMarginOrderRequest) -> RestResult<MarginOrder> {
+        self.send_signed_request(
+            "/papi/v1/margin/order",
+            reqwest::Method::GET,
+            params,
+            10,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,102 @@
+// Margin Account Trade List (USER_DATA) endpoint implementation for GET /papi/v1/margin/myTrades
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Margin-Account-Trade-List>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for the margin account trade list.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Only return the trades of this order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch). At most 24 hours before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Trade ID to fetch from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A margin account trade.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTrade {
+    /// Commission paid.
+    pub commission: String,
+
+    /// Asset the commission was paid in.
+    pub commission_asset: String,
+
+    /// Trade ID.
+    pub id: u64,
+
+    /// Whether the trade was at the best price available.
+    pub is_best_match: bool,
+
+    /// Whether the account bought.
+    pub is_buyer: bool,
+
+    /// Whether the account was the maker.
+    pub is_maker: bool,
+
+    /// ID of the order that traded.
+    pub order_id: u64,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity.
+    pub qty: String,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the margin trades of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Margin-Account-Trade-List>
+    /// GET /papi/v1/margin/myTrades
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MarginTradesRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`MarginTrade`] objects.
+    pub async fn get_margin_trades(&self, params: MarginTradesRequest) -> RestResult<Vec<MarginTrade>> {
+        self.send_signed_request(
+            "/papi/v1/margin/myTrades",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
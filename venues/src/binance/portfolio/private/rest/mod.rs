@@ -1,5 +1,53 @@
 // Private REST endpoints module for Binance Portfolio Margin
 
+pub mod account;
+pub mod asset_balance;
+pub mod asset_collection;
+pub mod auto_collection;
+pub mod balance;
+pub mod bnb_transfer;
 pub mod client;
+pub mod cm_all_orders;
+pub mod cm_cancel_all_conditional_orders;
+pub mod cm_cancel_all_orders;
+pub mod cm_cancel_conditional_order;
+pub mod cm_cancel_order;
+pub mod cm_conditional_all_orders;
+pub mod cm_conditional_open_orders;
+pub mod cm_conditional_order;
+pub mod cm_income;
+pub mod cm_leverage;
+pub mod cm_open_orders;
+pub mod cm_order;
+pub mod cm_position_risk;
+pub mod cm_query_order;
+pub mod cm_trades;
+pub mod margin_all_orders;
+pub mod margin_cancel_all_orders;
+pub mod margin_cancel_order;
+pub mod margin_interest_history;
+pub mod margin_loan;
+pub mod margin_open_orders;
+pub mod margin_order;
+pub mod margin_query_order;
+pub mod margin_trades;
+pub mod negative_balance_exchange_record;
+pub mod negative_balance_interest_history;
+pub mod repay_loan;
+pub mod um_all_orders;
+pub mod um_cancel_all_conditional_orders;
+pub mod um_cancel_all_orders;
+pub mod um_cancel_conditional_order;
+pub mod um_cancel_order;
+pub mod um_conditional_all_orders;
+pub mod um_conditional_open_orders;
+pub mod um_conditional_order;
+pub mod um_income;
+pub mod um_leverage;
+pub mod um_open_orders;
+pub mod um_order;
+pub mod um_position_risk;
+pub mod um_query_order;
+pub mod um_trades;
 
 pub use client::RestClient;
@@ -0,0 +1,86 @@
+// Query User Negative Balance Auto Exchange Record (USER_DATA) endpoint implementation for
+// GET /papi/v1/portfolio/negative-balance-exchange-record
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-User-Negative-Balance-Auto-Exchange-Record>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for the negative balance exchange record.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeBalanceExchangeRecordRequest {
+    /// Start time (ms since epoch). At most 6 months before `end_time`.
+    pub start_time: u64,
+
+    /// End time (ms since epoch).
+    pub end_time: u64,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A negative balance of an asset at the time of an exchange.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeBalanceDetail {
+    /// Asset name.
+    pub asset: String,
+
+    /// Negative balance that was exchanged.
+    pub negative_balance: String,
+
+    /// Negative balance above which the asset is exchanged.
+    pub negative_max_threshold: String,
+}
+
+/// An exchange of negative balances.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeBalanceExchange {
+    /// Start of the exchange (ms since epoch).
+    pub start_time: u64,
+
+    /// End of the exchange (ms since epoch).
+    pub end_time: u64,
+
+    /// Negative balances that were exchanged.
+    pub details: Vec<NegativeBalanceDetail>,
+}
+
+/// The negative balance exchange record.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NegativeBalanceExchangeRecord {
+    /// Number of exchanges.
+    pub total: u64,
+
+    /// Exchanges of negative balances.
+    pub rows: Vec<NegativeBalanceExchange>,
+}
+
+impl RestClient {
+    /// Fetches the record of negative balances exchanged to cover them.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-User-Negative-Balance-Auto-Exchange-Record>
+    /// GET /papi/v1/portfolio/negative-balance-exchange-record
+    /// Weight: 100
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NegativeBalanceExchangeRecordRequest`])
+    ///
+    /// # Returns
+    /// The [`NegativeBalanceExchangeRecord`].
+    pub async fn get_negative_balance_exchange_record(&self, params: NegativeBalanceExchangeRecordRequest) -> RestResult<NegativeBalanceExchangeRecord> {
+        self.send_signed_request(
+            "/papi/v1/portfolio/negative-balance-exchange-record",
+            reqwest::Method::GET,
+            params,
+            100,
+            false,
+        )
+        .await
+    }
+}
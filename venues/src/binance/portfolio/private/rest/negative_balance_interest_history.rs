@@ -0,0 +1,78 @@
+// Query Portfolio Margin Pro Negative Balance Interest History (USER_DATA) endpoint implementation for
+// GET /papi/v1/portfolio/interest-history
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-Portfolio-Margin-Pro-Negative-Balance-Interest-History>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for the negative balance interest history.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeBalanceInterestHistoryRequest {
+    /// Asset (e.g., "USDT"). All assets if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+
+    /// Start time (ms since epoch). At most 30 days before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of records (default 10, max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Interest charged on a negative balance.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NegativeBalanceInterest {
+    /// Asset of the negative balance.
+    pub asset: String,
+
+    /// Interest charged.
+    pub interest: String,
+
+    /// Accrual time (ms since epoch).
+    pub interest_accrued_time: u64,
+
+    /// Daily interest rate.
+    pub interest_rate: String,
+
+    /// Negative balance the interest was charged on.
+    pub principal: String,
+}
+
+impl RestClient {
+    /// Fetches the interest charged on negative balances.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-Portfolio-Margin-Pro-Negative-Balance-Interest-History>
+    /// GET /papi/v1/portfolio/interest-history
+    /// Weight: 50
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NegativeBalanceInterestHistoryRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`NegativeBalanceInterest`] objects.
+    pub async fn get_negative_balance_interest_history(&self, params: NegativeBalanceInterestHistoryRequest) -> RestResult<Vec<NegativeBalanceInterest>> {
+        self.send_signed_request(
+            "/papi/v1/portfolio/interest-history",
+            reqwest::Method::GET,
+            params,
+            50,
+            false,
+        )
+        .await
+    }
+}
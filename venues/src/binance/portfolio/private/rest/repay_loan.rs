@@ -0,0 +1,48 @@
+// Margin Account Repay (MARGIN) endpoint implementation for POST /papi/v1/repayLoan
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Margin-Account-Repay>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::margin_loan::MarginLoanResponse;
+
+/// Request parameters for repaying a margin asset.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RepayLoanRequest {
+    /// Asset to repay (e.g., "USDT").
+    pub asset: String,
+
+    /// Amount to repay.
+    pub amount: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Repays a margin loan.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Margin-Account-Repay>
+    /// POST /papi/v1/repayLoan
+    /// Weight: 100
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`RepayLoanRequest`])
+    ///
+    /// # Returns
+    /// The [`MarginLoanResponse`] with the transaction ID.
+    pub async fn post_repay_loan(&self, params: RepayLoanRequest) -> RestResult<MarginLoanResponse> {
+        self.send_signed_request(
+            "/papi/v1/repayLoan",
+            reqwest::Method::POST,
+            params,
+            100,
+            false,
+        )
+        .await
+    }
+}
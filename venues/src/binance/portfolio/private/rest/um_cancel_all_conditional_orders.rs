@@ -0,0 +1,46 @@
+// Cancel All UM Open Conditional Orders (TRADE) endpoint implementation for
+// DELETE /papi/v1/um/conditional/allOpenOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-All-UM-Open-Conditional-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::um_cancel_all_orders::CancelAllOrdersResponse;
+
+/// Request parameters for cancelling all open UM conditional orders of a symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllUmConditionalOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels all open UM conditional orders of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-All-UM-Open-Conditional-Orders>
+    /// DELETE /papi/v1/um/conditional/allOpenOrders
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelAllUmConditionalOrdersRequest`])
+    ///
+    /// # Returns
+    /// A [`CancelAllOrdersResponse`] acknowledging the cancellation.
+    pub async fn cancel_all_um_conditional_orders(&self, params: CancelAllUmConditionalOrdersRequest) -> RestResult<CancelAllOrdersResponse> {
+        self.send_signed_request(
+            "/papi/v1/um/conditional/allOpenOrders",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,82 @@
+// Cancel All UM Open Orders (TRADE) endpoint implementation for DELETE /papi/v1/um/allOpenOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-All-UM-Open-Orders>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for cancelling all open UM orders of a symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllUmOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Acknowledgement of a mass cancellation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelAllOrdersResponse {
+    /// 200 on success.
+    pub code: i32,
+
+    /// Result message.
+    pub msg: String,
+}
+
+impl RestClient {
+    /// Cancels all open UM orders of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-All-UM-Open-Orders>
+    /// DELETE /papi/v1/um/allOpenOrders
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelAllUmOrdersRequest`])
+    ///
+    /// # Returns
+    /// A [`CancelAllOrdersResponse`] acknowledging the cancellation.
+    pub async fn cancel_all_um_orders(&self, params: CancelAllUmOrdersRequest) -> RestResult<CancelAllOrdersResponse> {
+        self.send_signed_request(
+            "/papi/v1/um/allOpenOrders",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::portfolio::private::rest::client::tests::client;
+
+    #[tokio::test]
+    async fn test_cancel_all_acknowledgement_is_not_an_error() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({"code": 200, "msg": "The operation of cancel all open order is done."}),
+        );
+        let client = client(&transport);
+
+        let request = CancelAllUmOrdersRequest {
+            symbol: "BTCUSDT".to_string(),
+            recv_window: None,
+        };
+        let response = client.cancel_all_um_orders(request).await.unwrap();
+        assert_eq!(response.data.code, 200);
+        assert_eq!(transport.last_request().unwrap().method, Method::DELETE);
+    }
+}
@@ -0,0 +1,55 @@
+// Cancel UM Conditional Order (TRADE) endpoint implementation for
+// DELETE /papi/v1/um/conditional/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-UM-Conditional-Order>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::um_conditional_order::UmConditionalOrder;
+
+/// Request parameters for cancelling a UM conditional order.
+/// Either `strategy_id` or `new_client_strategy_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelUmConditionalOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Strategy ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+
+    /// Client strategy ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_strategy_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels an open UM conditional order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Cancel-UM-Conditional-Order>
+    /// DELETE /papi/v1/um/conditional/order
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelUmConditionalOrderRequest`])
+    ///
+    /// # Returns
+    /// The cancelled [`UmConditionalOrder`].
+    pub async fn cancel_um_conditional_order(&self, params: CancelUmConditionalOrderRequest) -> RestResult<UmConditionalOrder> {
+        self.send_signed_request(
+            "/papi/v1/um/conditional/order",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,49 @@
+// Query All Current UM Open Conditional Orders (TRADE) endpoint implementation for
+// GET /papi/v1/um/conditional/openOrders
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Current-UM-Open-Conditional-Orders>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::um_conditional_order::UmConditionalOrder;
+
+/// Request parameters for the open UM conditional orders.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UmConditionalOpenOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    /// If not sent, will return orders for all symbols at a much higher weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the open UM conditional orders of a symbol, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-All-Current-UM-Open-Conditional-Orders>
+    /// GET /papi/v1/um/conditional/openOrders
+    /// Weight: 1 for a single symbol, 40 when the symbol is omitted
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`UmConditionalOpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`UmConditionalOrder`] objects.
+    pub async fn get_um_conditional_open_orders(&self, params: UmConditionalOpenOrdersRequest) -> RestResult<Vec<UmConditionalOrder>> {
+        let weight = if params.symbol.is_some() { 1 } else { 40 };
+        self.send_signed_request(
+            "/papi/v1/um/conditional/openOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,185 @@
+// New UM Conditional Order (TRADE) endpoint implementation for POST /papi/v1/um/conditional/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-UM-Conditional-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{
+    OrderSide, OrderStatus, PositionSide, PriceMatch, RestResult, SelfTradePreventionMode, StrategyStatus, StrategyType, TimeInForce, WorkingType,
+};
+
+/// Request parameters for placing a new UM conditional order (POST /papi/v1/um/conditional/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewUmConditionalOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Position side (BOTH, LONG, SHORT). Must be LONG or SHORT in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+
+    /// Conditional order type.
+    pub strategy_type: StrategyType,
+
+    /// Time in force (GTC, IOC, FOK, GTX, GTD).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order quantity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+
+    /// Only reduce the position. Cannot be sent in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// Order price, for STOP and TAKE_PROFIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Price the stop price is compared with (default CONTRACT_PRICE).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_type: Option<WorkingType>,
+
+    /// Protect the trigger against mark and contract price divergence (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_protect: Option<bool>,
+
+    /// Unique client strategy ID. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_strategy_id: Option<String>,
+
+    /// Trigger price, for STOP, STOP_MARKET, TAKE_PROFIT and TAKE_PROFIT_MARKET orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+
+    /// Activation price of TRAILING_STOP_MARKET orders. Default the latest price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<String>,
+
+    /// Callback rate in percent of TRAILING_STOP_MARKET orders, 0.1 to 5.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_rate: Option<String>,
+
+    /// Price the triggered order at a level of the order book instead of `price`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Auto-cancel time (ms since epoch) of GTD orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub good_till_date: Option<u64>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A UM conditional order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmConditionalOrder {
+    /// Client strategy ID.
+    pub new_client_strategy_id: String,
+
+    /// Strategy ID.
+    pub strategy_id: u64,
+
+    /// Status of the conditional order.
+    pub strategy_status: StrategyStatus,
+
+    /// Conditional order type.
+    pub strategy_type: StrategyType,
+
+    /// Order quantity.
+    pub orig_qty: String,
+
+    /// Price of the order placed on trigger.
+    pub price: String,
+
+    /// Whether the order only reduces the position.
+    pub reduce_only: bool,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Position side.
+    pub position_side: PositionSide,
+
+    /// Trigger price.
+    pub stop_price: Option<String>,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Activation price, only returned for TRAILING_STOP_MARKET orders.
+    pub activate_price: Option<String>,
+
+    /// Callback rate, only returned for TRAILING_STOP_MARKET orders.
+    pub price_rate: Option<String>,
+
+    /// Time the conditional order was placed (ms since epoch).
+    pub book_time: u64,
+
+    /// Last update of the conditional order (ms since epoch).
+    pub update_time: u64,
+
+    /// Price the stop price is compared with.
+    pub working_type: WorkingType,
+
+    /// Whether the trigger is protected against mark and contract price divergence.
+    pub price_protect: bool,
+
+    /// Self-trade prevention mode.
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Auto-cancel time of GTD orders, 0 otherwise.
+    pub good_till_date: Option<u64>,
+
+    /// Order book level the triggered order is priced at, if any.
+    pub price_match: Option<PriceMatch>,
+
+    /// ID of the order placed on trigger, only returned by history queries once triggered.
+    pub order_id: Option<u64>,
+
+    /// Status of the order placed on trigger, only returned by history queries once triggered.
+    pub status: Option<OrderStatus>,
+
+    /// Trigger time, only returned by history queries once triggered.
+    pub trigger_time: Option<u64>,
+}
+
+impl RestClient {
+    /// Places a new UM conditional order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-UM-Conditional-Order>
+    /// POST /papi/v1/um/conditional/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewUmConditionalOrderRequest`])
+    ///
+    /// # Returns
+    /// The new [`UmConditionalOrder`].
+    pub async fn post_um_conditional_order(&self, params: NewUmConditionalOrderRequest) -> RestResult<UmConditionalOrder> {
+        self.send_signed_request(
+            "/papi/v1/um/conditional/order",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
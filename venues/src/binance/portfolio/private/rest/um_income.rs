@@ -0,0 +1,96 @@
+// Get UM Income History (USER_DATA) endpoint implementation for GET /papi/v1/um/income
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Get-UM-Income-History>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{IncomeType, RestResult};
+
+/// Request parameters for the UM income history.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UmIncomeHistoryRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Only return income of this type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub income_type: Option<IncomeType>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Page number, starting at 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Limit (default 100, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A single entry of the income history.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeRecord {
+    /// Trading symbol, empty for income not tied to a symbol (e.g. transfers).
+    pub symbol: String,
+
+    /// Kind of income.
+    pub income_type: IncomeType,
+
+    /// Amount, negative for payments.
+    pub income: String,
+
+    /// Asset of the income.
+    pub asset: String,
+
+    /// Extra information, e.g. the transfer ID.
+    pub info: String,
+
+    /// Time in milliseconds since epoch.
+    pub time: u64,
+
+    /// Transaction ID.
+    pub tran_id: u64,
+
+    /// Trade ID, empty if the income is not tied to a trade.
+    pub trade_id: String,
+}
+
+impl RestClient {
+    /// Fetches the UM income history, such as funding fees, commissions and realized PnL.
+    ///
+    /// Without a time range only the last 7 days are returned, and at most 3 months can be queried.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Get-UM-Income-History>
+    /// GET /papi/v1/um/income
+    /// Weight: 30
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`UmIncomeHistoryRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`IncomeRecord`] objects.
+    pub async fn get_um_income(&self, params: UmIncomeHistoryRequest) -> RestResult<Vec<IncomeRecord>> {
+        self.send_signed_request(
+            "/papi/v1/um/income",
+            reqwest::Method::GET,
+            params,
+            30,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,61 @@
+// Change UM Initial Leverage (TRADE) endpoint implementation for POST /papi/v1/um/leverage
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Change-UM-Initial-Leverage>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+
+/// Request parameters for changing the initial leverage of a UM symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeUmLeverageRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Target initial leverage, 1 to 125.
+    pub leverage: u32,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Response for a UM leverage change.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmLeverageResponse {
+    /// New initial leverage.
+    pub leverage: u32,
+
+    /// Maximum notional value at the new leverage.
+    pub max_notional_value: String,
+
+    /// Trading symbol.
+    pub symbol: String,
+}
+
+impl RestClient {
+    /// Changes the initial leverage of a UM symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Change-UM-Initial-Leverage>
+    /// POST /papi/v1/um/leverage
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ChangeUmLeverageRequest`])
+    ///
+    /// # Returns
+    /// The [`UmLeverageResponse`].
+    pub async fn post_um_leverage(&self, params: ChangeUmLeverageRequest) -> RestResult<UmLeverageResponse> {
+        self.send_signed_request(
+            "/papi/v1/um/leverage",
+            reqwest::Method::POST,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
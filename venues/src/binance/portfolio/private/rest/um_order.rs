@@ -0,0 +1,238 @@
+// New UM Order (TRADE) endpoint implementation for POST /papi/v1/um/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-UM-Order>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{
+    OrderResponseType, OrderSide, OrderStatus, OrderType, PositionSide, PriceMatch, RestResult, SelfTradePreventionMode, TimeInForce,
+};
+
+/// Request parameters for placing a new UM order (POST /papi/v1/um/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewUmOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Position side (BOTH, LONG, SHORT). Must be LONG or SHORT in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+
+    /// Order type (LIMIT or MARKET).
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Time in force (GTC, IOC, FOK, GTX, GTD). Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order quantity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+
+    /// Only reduce the position. Cannot be sent in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// Order price. Required for limit orders without `price_match`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Unique client order ID. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// New order response type (ACK or RESULT). Default ACK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Price the order at a level of the order book instead of `price`. Limit orders only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Auto-cancel time (ms since epoch) of GTD orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub good_till_date: Option<u64>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A UM order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmOrder {
+    /// Client order ID.
+    pub client_order_id: String,
+
+    /// Quantity filled so far.
+    pub cum_qty: String,
+
+    /// Quote asset value filled so far.
+    pub cum_quote: String,
+
+    /// Quantity filled so far.
+    pub executed_qty: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Average fill price, 0 before any fill.
+    pub avg_price: String,
+
+    /// Order quantity.
+    pub orig_qty: String,
+
+    /// Order price, 0 for market orders.
+    pub price: String,
+
+    /// Whether the order only reduces the position.
+    pub reduce_only: bool,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Position side.
+    pub position_side: PositionSide,
+
+    /// Order status.
+    pub status: OrderStatus,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Self-trade prevention mode.
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Auto-cancel time of GTD orders, 0 otherwise.
+    pub good_till_date: Option<u64>,
+
+    /// Order book level the order is priced at, if any.
+    pub price_match: Option<PriceMatch>,
+
+    /// Creation time, only returned by queries.
+    pub time: Option<u64>,
+
+    /// Last update of the order (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Places a new UM order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/New-UM-Order>
+    /// POST /papi/v1/um/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewUmOrderRequest`])
+    ///
+    /// # Returns
+    /// The new [`UmOrder`].
+    pub async fn post_um_order(&self, params: NewUmOrderRequest) -> RestResult<UmOrder> {
+        self.send_signed_request(
+            "/papi/v1/um/order",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::portfolio::private::rest::client::tests::client;
+    use crate::binance::shared::test_support::signed_params;
+
+    #[tokio::test]
+    async fn test_new_um_order_is_signed_in_body() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "clientOrderId": "testOrder",
+                "cumQty": "0",
+                "cumQuote": "0",
+                "executedQty": "0",
+                "orderId": 22542179,
+                "avgPrice": "0.00000",
+                "origQty": "10",
+                "price": "0",
+                "reduceOnly": false,
+                "side": "BUY",
+                "positionSide": "SHORT",
+                "status": "NEW",
+                "symbol": "BTCUSDT",
+                "timeInForce": "GTD",
+                "type": "MARKET",
+                "selfTradePreventionMode": "NONE",
+                "goodTillDate": 1693207680000_u64,
+                "updateTime": 1566818724722_u64,
+                "priceMatch": "NONE"
+            }),
+        );
+        let client = client(&transport);
+
+        let request = NewUmOrderRequest {
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Buy,
+            position_side: Some(PositionSide::Short),
+            order_type: OrderType::Market,
+            time_in_force: None,
+            quantity: Some("10".to_string()),
+            reduce_only: None,
+            price: None,
+            new_client_order_id: Some("testOrder".to_string()),
+            new_order_resp_type: None,
+            price_match: None,
+            self_trade_prevention_mode: None,
+            good_till_date: None,
+            recv_window: None,
+        };
+        let response = client.post_um_order(request).await.unwrap();
+        assert_eq!(response.data.order_id, 22542179);
+        assert_eq!(response.data.time_in_force, TimeInForce::GTD);
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::POST);
+        assert_eq!(sent.url, "https://papi.binance.com/papi/v1/um/order");
+        let params = signed_params(sent.body.as_deref().unwrap()).await;
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "symbol",
+                "side",
+                "positionSide",
+                "type",
+                "quantity",
+                "newClientOrderId",
+                "timestamp"
+            ]
+        );
+    }
+}
@@ -0,0 +1,84 @@
+// Query UM Position Information (USER_DATA) endpoint implementation for
+// GET /papi/v1/um/positionRisk
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-UM-Position-Information>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{PositionSide, RestResult};
+
+/// Request parameters for the UM position information.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UmPositionRiskRequest {
+    /// Trading symbol (e.g., "BTCUSDT"). All symbols if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A UM position.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmPositionRisk {
+    /// Average entry price.
+    pub entry_price: String,
+
+    /// Initial leverage.
+    pub leverage: String,
+
+    /// Mark price.
+    pub mark_price: String,
+
+    /// Maximum notional value at the current leverage.
+    pub max_notional_value: String,
+
+    /// Position quantity, negative for short positions.
+    pub position_amt: String,
+
+    /// Position value at the mark price.
+    pub notional: String,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Unrealized profit.
+    pub un_realized_profit: String,
+
+    /// Estimated liquidation price.
+    pub liquidation_price: String,
+
+    /// Position side.
+    pub position_side: PositionSide,
+
+    /// Last update of the position (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Fetches the UM positions of a symbol, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/account/Query-UM-Position-Information>
+    /// GET /papi/v1/um/positionRisk
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`UmPositionRiskRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`UmPositionRisk`] objects.
+    pub async fn get_um_position_risk(&self, params: UmPositionRiskRequest) -> RestResult<Vec<UmPositionRisk>> {
+        self.send_signed_request(
+            "/papi/v1/um/positionRisk",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
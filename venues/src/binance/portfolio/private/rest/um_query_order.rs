@@ -0,0 +1,48 @@
+// Query UM Order (USER_DATA) endpoint implementation for GET /papi/v1/um/order
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-UM-Order>
+
+use serde::Serialize;
+
+use crate::binance::portfolio::RestResult;
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::private::rest::um_order::UmOrder;
+
+/// Request parameters for querying a UM order.
+/// Either `order_id` or `orig_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryUmOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID assigned by Binance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID sent with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Queries a UM order.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/Query-UM-Order>
+    /// GET /papi/v1/um/order
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`QueryUmOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`UmOrder`].
+    pub async fn get_um_order(&self, params: QueryUmOrderRequest) -> RestResult<UmOrder> {
+        self.send_signed_request("/papi/v1/um/order", reqwest::Method::GET, params, 1, false)
+            .await
+    }
+}
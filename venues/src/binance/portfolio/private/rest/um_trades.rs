@@ -0,0 +1,110 @@
+// UM Account Trade List (USER_DATA) endpoint implementation for GET /papi/v1/um/userTrades
+// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/UM-Account-Trade-List>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::portfolio::private::rest::client::RestClient;
+use crate::binance::portfolio::{OrderSide, PositionSide, RestResult};
+
+/// Request parameters for the UM account trade list.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UmUserTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Start time (ms since epoch). At most 7 days before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Trade ID to fetch from. Cannot be combined with `start_time` or `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A UM account trade.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UmUserTrade {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Trade ID.
+    pub id: u64,
+
+    /// ID of the order that traded.
+    pub order_id: u64,
+
+    /// Side of the account.
+    pub side: OrderSide,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity.
+    pub qty: String,
+
+    /// Profit realized by the trade.
+    pub realized_pnl: String,
+
+    /// Margin asset.
+    pub margin_asset: Option<String>,
+
+    /// Quantity in the quote asset.
+    pub quote_qty: String,
+
+    /// Commission paid, negative for rebates.
+    pub commission: String,
+
+    /// Asset the commission was paid in.
+    pub commission_asset: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+
+    /// Whether the account bought.
+    pub buyer: bool,
+
+    /// Whether the account was the maker.
+    pub maker: bool,
+
+    /// Position side.
+    pub position_side: PositionSide,
+}
+
+impl RestClient {
+    /// Fetches the UM trades of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/portfolio-margin/trade/UM-Account-Trade-List>
+    /// GET /papi/v1/um/userTrades
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`UmUserTradesRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`UmUserTrade`] objects.
+    pub async fn get_um_user_trades(&self, params: UmUserTradesRequest) -> RestResult<Vec<UmUserTrade>> {
+        self.send_signed_request(
+            "/papi/v1/um/userTrades",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
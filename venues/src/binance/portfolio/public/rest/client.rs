@@ -3,10 +3,16 @@
 // Provides access to all public REST API endpoints for Binance Portfolio Margin.
 // All requests are unauthenticated and do not require API credentials.
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use reqwest::Client;
+use async_trait::async_trait;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::transport::HttpTransport;
 
-use crate::binance::portfolio::{RateLimiter, RestResult};
+use crate::binance::portfolio::errors::VENUE;
+use crate::binance::portfolio::{RateLimiter, RequestWeight, RestResult};
 
 #[non_exhaustive]
 #[derive(Debug, Clone)]
@@ -19,7 +25,7 @@ pub struct RestClient {
     /// The underlying HTTP client used for making requests.
     ///
     /// This is reused for connection pooling and performance.
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits.
     ///
@@ -32,10 +38,10 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `base_url` - The base URL for the Binance Portfolio Margin public REST API (e.g., "<https://papi.binance.com>").
-    pub fn new(base_url: impl Into<Cow<'static, str>>, client: Client, rate_limiter: RateLimiter) -> Self {
+    pub fn new(base_url: impl Into<Cow<'static, str>>, client: impl HttpTransport + 'static, rate_limiter: RateLimiter) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
         }
     }
@@ -59,7 +65,7 @@ impl RestClient {
             None => None,
         };
         let rest_response = crate::binance::portfolio::rest::common::send_rest_request(
-            &self.client,
+            self.client.as_ref(),
             &url,
             method,
             headers,
@@ -76,3 +82,49 @@ impl RestClient {
         })
     }
 }
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<RequestWeight>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+
+        // Public endpoints take their parameters in the query string
+        let query_string = request
+            .params
+            .as_ref()
+            .map(serde_urlencoded::to_string)
+            .transpose()
+            .map_err(|e| {
+                VenueError::new(
+                    VENUE,
+                    ErrorKind::InvalidRequest,
+                    format!("Failed to encode query parameters: {}", e),
+                )
+            })?;
+        let response = self
+            .send_request::<serde_json::Value>(
+                &request.endpoint,
+                request.method,
+                query_string.as_deref(),
+                None,
+                request.rate_limit_key.weight,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
+}
@@ -6,7 +6,7 @@
 //
 // This module reuses the COIN-M rate limiting implementation since the limits are identical.
 
-pub use crate::binance::coinm::{RateLimitHeader, RateLimiter};
+pub use crate::binance::coinm::{RateLimitHeader, RateLimiter, RequestWeight};
 
 /// Portfolio Margin Rate Limiter
 ///
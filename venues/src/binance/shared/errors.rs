@@ -0,0 +1,29 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Extract the ban expiry from a message of the form `... banned until <epoch millis> ...`,
+/// which is how Binance reports IP auto-bans.
+pub(crate) fn banned_until(message: &str) -> Option<SystemTime> {
+    let (_, rest) = message.split_once("banned until ")?;
+    let millis: u64 = rest
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .ok()?;
+    UNIX_EPOCH.checked_add(Duration::from_millis(millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_banned_until() {
+        let until = banned_until("Way too much request weight used; IP banned until 1659146011110. Please use WebSocket Streams.");
+        assert_eq!(
+            until,
+            UNIX_EPOCH.checked_add(Duration::from_millis(1_659_146_011_110))
+        );
+        assert_eq!(banned_until("Too many requests"), None);
+    }
+}
@@ -0,0 +1,15 @@
+//! Code shared by the Binance product clients (Spot, USD-M, COIN-M, Options, Portfolio Margin)
+
+mod errors;
+mod request;
+mod signing;
+#[cfg(test)]
+pub(crate) mod test_support;
+mod weights;
+
+pub(crate) use errors::banned_until;
+pub(crate) use request::object_params;
+pub(crate) use signing::{SigningError, signed_payload};
+pub(crate) use weights::{klines_weight, order_book_weight};
+#[cfg(test)]
+pub(crate) use signing::sign_request;
@@ -0,0 +1,19 @@
+use rest::error::{ErrorKind, VenueError};
+use serde_json::{Map, Value};
+
+/// The parameters of a signed [`rest::request::RestRequest`], which must be a JSON object
+/// so they can be URL encoded
+pub(crate) fn object_params(venue: &'static str, params: Option<Value>) -> Result<Map<String, Value>, VenueError> {
+    match params {
+        Some(Value::Object(params)) => Ok(params),
+        None => Ok(Map::new()),
+        Some(params) => Err(VenueError::new(
+            venue,
+            ErrorKind::InvalidRequest,
+            format!(
+                "Signed request parameters must be a JSON object, got {}",
+                params
+            ),
+        )),
+    }
+}
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use rest::clock::ServerClock;
+use rest::secrets::{HmacSigner, SecretError};
+use serde::Serialize;
+
+/// Receive window used once the server clock is synced, before adding its uncertainty
+const RECV_WINDOW: Duration = Duration::from_secs(5);
+
+/// Largest receive window Binance accepts
+const MAX_RECV_WINDOW: Duration = Duration::from_secs(60);
+
+/// Stamps an encoded request with the server clock's time, ready to be signed.
+///
+/// The clock always provides the `timestamp`: one already in the request (the COIN-M
+/// request structs still carry one) is overridden, since a local timestamp is what gets
+/// requests rejected with `-1021` once the clocks drift. A `recvWindow` covering the
+/// clock's uncertainty is added unless the request sets its own.
+///
+/// # Errors
+/// A message describing why `query_string` could not be decoded or re-encoded.
+pub(crate) fn stamp_request(clock: &ServerClock, query_string: &str) -> Result<String, String> {
+    let mut params: Vec<(String, String)> = serde_urlencoded::from_str(query_string).map_err(|e| format!("Invalid query string: {}", e))?;
+    params.retain(|(key, _)| key != "timestamp");
+    let has_recv_window = params
+        .iter()
+        .any(|(key, _)| key == "recvWindow" || key == "recv_window");
+    if let Some(recv_window) = clock
+        .recv_window(RECV_WINDOW, MAX_RECV_WINDOW)
+        .filter(|_| !has_recv_window)
+    {
+        params.push((
+            "recvWindow".to_string(),
+            recv_window.as_millis().to_string(),
+        ));
+    }
+    params.push(("timestamp".to_string(), clock.now_ms().to_string()));
+    serde_urlencoded::to_string(&params).map_err(|e| format!("Failed to encode query string: {}", e))
+}
+
+/// Signs a stamped request with `signer` and returns the `signature` parameter, hex encoded.
+///
+/// # Errors
+/// The signer's error, e.g. when a remote signer cannot be reached.
+pub(crate) async fn sign_request(signer: &dyn HmacSigner, payload: &str) -> Result<String, SecretError> {
+    let signature = signer.hmac_sha256(payload.as_bytes()).await?;
+    Ok(hex::encode(signature))
+}
+
+/// Why a request could not be signed
+#[derive(Debug)]
+pub(crate) enum SigningError {
+    /// The request parameters could not be URL encoded
+    Encode(String),
+    /// The signer failed, e.g. a remote signer could not be reached
+    Signer(SecretError),
+}
+
+/// Encodes `request`, stamps it with [`stamp_request`] and appends its `signature`.
+///
+/// The result is sent as the query string of GET requests and as the form body of the
+/// others.
+pub(crate) async fn signed_payload<R: Serialize>(clock: &ServerClock, signer: &dyn HmacSigner, request: &R) -> Result<String, SigningError> {
+    let encoded = serde_urlencoded::to_string(request).map_err(|e| SigningError::Encode(format!("Failed to encode params: {}", e)))?;
+    let stamped = stamp_request(clock, &encoded).map_err(SigningError::Encode)?;
+    let signature = sign_request(signer, &stamped)
+        .await
+        .map_err(SigningError::Signer)?;
+    Ok(format!("{}&signature={}", stamped, signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use rest::secrets::SecretValue;
+    use secrecy::SecretString;
+
+    use super::*;
+
+    #[test]
+    fn test_caller_timestamp_is_overridden() {
+        let clock = ServerClock::new();
+        let stamped = stamp_request(&clock, "symbol=BTCUSDT&timestamp=1").unwrap();
+
+        let params: Vec<(String, String)> = serde_urlencoded::from_str(&stamped).unwrap();
+        let timestamps: Vec<&str> = params
+            .iter()
+            .filter(|(key, _)| key == "timestamp")
+            .map(|(_, value)| value.as_str())
+            .collect();
+        assert_eq!(timestamps.len(), 1);
+        assert_ne!(timestamps.first(), Some(&"1"));
+        assert!(stamped.starts_with("symbol=BTCUSDT&"));
+    }
+
+    #[test]
+    fn test_recv_window_follows_clock_uncertainty_unless_set() {
+        let clock = ServerClock::new();
+        clock.record(0, 0, Duration::from_millis(100));
+
+        let stamped = stamp_request(&clock, "symbol=BTCUSDT").unwrap();
+        assert!(stamped.starts_with("symbol=BTCUSDT&recvWindow=5200&timestamp="));
+
+        let stamped = stamp_request(&clock, "symbol=BTCUSDT&recvWindow=1000").unwrap();
+        assert!(stamped.starts_with("symbol=BTCUSDT&recvWindow=1000&timestamp="));
+    }
+
+    #[tokio::test]
+    async fn test_signature_matches_binance_example() {
+        let secret = SecretValue::new(SecretString::from(
+            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+        ));
+        let payload = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+
+        assert_eq!(
+            sign_request(&secret, payload).await.unwrap(),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_payload_signs_the_stamped_params() {
+        let secret = SecretValue::new(SecretString::from("test_secret"));
+        let clock = ServerClock::new();
+
+        let payload = signed_payload(&clock, &secret, &[("symbol", "BTCUSDT")])
+            .await
+            .unwrap();
+        let (unsigned, signature) = payload.split_once("&signature=").unwrap();
+        assert!(unsigned.starts_with("symbol=BTCUSDT&timestamp="));
+        assert_eq!(signature, sign_request(&secret, unsigned).await.unwrap());
+    }
+}
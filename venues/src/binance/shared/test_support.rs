@@ -0,0 +1,117 @@
+//! Fixtures for testing the Binance private clients against a [`MockTransport`]
+//!
+//! Each client builds itself with [`TestSecret`]s and runs the shared signing and
+//! reconciliation checks with its own endpoints, keeping only venue-specific
+//! assertions in its own tests.
+
+use reqwest::{Method, StatusCode};
+use rest::request::{RateLimitKey, RestClient, RestRequest};
+use rest::retry::{ClientOrderIdLookup, RetryPolicy, RetryingClient};
+use rest::secrets::ExposableSecret;
+use rest::transport::{HttpRequest, MockTransport};
+use serde_json::json;
+
+use super::sign_request;
+
+/// API key the test clients are built with
+pub(crate) const API_KEY: &str = "test_key";
+
+/// API secret the test clients are built with
+pub(crate) const API_SECRET: &str = "test_secret";
+
+/// A secret held in plain text
+pub(crate) struct TestSecret(pub(crate) &'static str);
+
+impl ExposableSecret for TestSecret {
+    fn expose_secret(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Splits a signed payload into its parameters, checking the signature on the way
+pub(crate) async fn signed_params(payload: &str) -> Vec<(String, String)> {
+    let (unsigned, signature) = payload.split_once("&signature=").unwrap();
+    assert_eq!(
+        signature,
+        sign_request(&TestSecret(API_SECRET), unsigned)
+            .await
+            .unwrap()
+    );
+    serde_urlencoded::from_str(unsigned).unwrap()
+}
+
+/// Sends a GET `request` and checks it was signed in the query string, returning the
+/// signed parameters
+pub(crate) async fn send_signed_get<C: RestClient>(client: &C, transport: &MockTransport, request: RestRequest<RateLimitKey<C>>) -> Vec<(String, String)> {
+    transport.push_json(StatusCode::OK, json!([]));
+    client.send(request).await.unwrap();
+
+    let sent = transport.last_request().unwrap();
+    assert_eq!(sent.method, Method::GET);
+    assert_eq!(sent.header_value("X-MBX-APIKEY"), Some(API_KEY));
+    assert_eq!(sent.body, None);
+    let params = signed_params(sent.query_string().unwrap()).await;
+    assert_eq!(
+        params.last().map(|(name, _)| name.as_str()),
+        Some("timestamp")
+    );
+    params
+}
+
+/// Sends a POST, PUT or DELETE `request` and checks it was signed in the form body,
+/// returning the request that was sent and its signed parameters
+pub(crate) async fn send_signed_form<C: RestClient>(
+    client: &C,
+    transport: &MockTransport,
+    request: RestRequest<RateLimitKey<C>>,
+) -> (HttpRequest, Vec<(String, String)>) {
+    transport.push_json(StatusCode::OK, json!({}));
+    client.send(request).await.unwrap();
+
+    let sent = transport.last_request().unwrap();
+    assert_ne!(sent.method, Method::GET);
+    assert_eq!(sent.header_value("X-MBX-APIKEY"), Some(API_KEY));
+    assert_eq!(
+        sent.header_value("Content-Type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(sent.query_string(), None);
+    let params = signed_params(sent.body.as_deref().unwrap()).await;
+    assert_eq!(
+        params.last().map(|(name, _)| name.as_str()),
+        Some("timestamp")
+    );
+    (sent, params)
+}
+
+/// Places an order whose outcome is unknown (-1000 with a 503) through a
+/// [`RetryingClient`] using `reconciler`, checks the order found by the lookup is
+/// returned, and returns the signed parameters of the lookup
+pub(crate) async fn look_up_unknown_order<C>(
+    client: C,
+    transport: &MockTransport,
+    reconciler: ClientOrderIdLookup<RateLimitKey<C>>,
+    place_order: RestRequest<RateLimitKey<C>>,
+) -> Vec<(String, String)>
+where
+    C: RestClient,
+    RateLimitKey<C>: Clone + 'static,
+{
+    transport
+        .push_json(
+            StatusCode::SERVICE_UNAVAILABLE,
+            json!({ "code": -1000, "msg": "Unknown error, please check your request or try again later." }),
+        )
+        .push_json(
+            StatusCode::OK,
+            json!({ "clientOrderId": "my-order-1", "status": "FILLED" }),
+        );
+    let client = RetryingClient::new(client, RetryPolicy::default()).with_reconciler(reconciler);
+
+    let response = client.send(place_order).await.unwrap();
+    assert_eq!(response.data["status"], "FILLED");
+
+    let lookup = transport.last_request().unwrap();
+    assert_eq!(lookup.method, Method::GET);
+    signed_params(lookup.query_string().unwrap()).await
+}
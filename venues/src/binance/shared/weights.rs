@@ -0,0 +1,41 @@
+/// Request weight of the USD-M and COIN-M order book for `limit` levels per side
+pub(crate) fn order_book_weight(limit: Option<u32>) -> u32 {
+    match limit.unwrap_or(500) {
+        0..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
+}
+
+/// Request weight of the USD-M and COIN-M kline endpoints for `limit` klines
+pub(crate) fn klines_weight(limit: Option<u32>) -> u32 {
+    match limit.unwrap_or(500) {
+        0..=99 => 1,
+        100..=499 => 2,
+        500..=1000 => 5,
+        _ => 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_book_weight_follows_limit() {
+        assert_eq!(order_book_weight(Some(50)), 2);
+        assert_eq!(order_book_weight(Some(100)), 5);
+        assert_eq!(order_book_weight(None), 10);
+        assert_eq!(order_book_weight(Some(1000)), 20);
+    }
+
+    #[test]
+    fn test_klines_weight_follows_limit() {
+        assert_eq!(klines_weight(Some(99)), 1);
+        assert_eq!(klines_weight(Some(100)), 2);
+        assert_eq!(klines_weight(None), 5);
+        assert_eq!(klines_weight(Some(1000)), 5);
+        assert_eq!(klines_weight(Some(1500)), 10);
+    }
+}
@@ -192,3 +192,106 @@ pub enum KlineInterval {
     #[serde(rename = "1M")]
     I1M,
 }
+
+/// What to do when cancelling the order of a cancel-replace fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceMode {
+    /// Don't place the new order if the cancel fails.
+    StopOnFailure,
+    /// Place the new order even if the cancel fails.
+    AllowFailure,
+}
+
+impl fmt::Display for CancelReplaceMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CancelReplaceMode::StopOnFailure => write!(f, "STOP_ON_FAILURE"),
+            CancelReplaceMode::AllowFailure => write!(f, "ALLOW_FAILURE"),
+        }
+    }
+}
+
+/// Restricts a cancel to orders in the given status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelRestrictions {
+    /// Only cancel the order if it is new.
+    OnlyNew,
+    /// Only cancel the order if it is partially filled.
+    OnlyPartiallyFilled,
+}
+
+impl fmt::Display for CancelRestrictions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CancelRestrictions::OnlyNew => write!(f, "ONLY_NEW"),
+            CancelRestrictions::OnlyPartiallyFilled => write!(f, "ONLY_PARTIALLY_FILLED"),
+        }
+    }
+}
+
+/// Whether a cancel-replace may still cancel the order once the unfilled order count is exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderRateLimitExceededMode {
+    /// Fail the request without cancelling.
+    DoNothing,
+    /// Cancel the order without placing the new one.
+    CancelOnly,
+}
+
+impl fmt::Display for OrderRateLimitExceededMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderRateLimitExceededMode::DoNothing => write!(f, "DO_NOTHING"),
+            OrderRateLimitExceededMode::CancelOnly => write!(f, "CANCEL_ONLY"),
+        }
+    }
+}
+
+/// Outcome of either half of a cancel-replace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceResult {
+    /// The step succeeded.
+    Success,
+    /// The step failed.
+    Failure,
+    /// The step was skipped because the other step failed.
+    NotAttempted,
+}
+
+/// Represents the kind of order list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ContingencyType {
+    /// One-Cancels-the-Other
+    OCO,
+    /// One-Triggers-the-Other, also used for OTOCO lists
+    OTO,
+}
+
+/// Represents the status of an order list, as reported with each list update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListStatusType {
+    /// The order list has been placed or there is an update to its status.
+    Response,
+    /// The order list has been placed or there is an update to a list order.
+    ExecStarted,
+    /// The order list has finished executing and is no longer active.
+    AllDone,
+}
+
+/// Represents the status of the orders of an order list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListOrderStatus {
+    /// The order list has been placed or there is an update to its status.
+    Executing,
+    /// The order list has finished executing and is no longer active.
+    AllDone,
+    /// The order list was rejected.
+    Reject,
+}
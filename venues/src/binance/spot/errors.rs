@@ -1,8 +1,16 @@
 use std::fmt;
+use std::time::Duration;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::binance::shared::{SigningError, banned_until};
+
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "binance-spot";
+
 /// Represents all possible errors that can occur when interacting with the Binance API
 #[derive(Debug)]
 pub enum Errors {
@@ -14,7 +22,7 @@ pub enum Errors {
     /// such as network issues or HTTP errors.
     /// It can be used to wrap any error that occurs during the request process.
     /// This variant is not used for errors returned by the Binance API itself.
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the Binance API
     ApiError(ApiError),
@@ -36,6 +44,15 @@ impl fmt::Display for Errors {
 
 impl std::error::Error for Errors {}
 
+impl From<SigningError> for Errors {
+    fn from(err: SigningError) -> Self {
+        match err {
+            SigningError::Encode(msg) => Errors::Error(msg),
+            SigningError::Signer(err) => Errors::Error(format!("Failed to sign request: {}", err)),
+        }
+    }
+}
+
 /// Represents an error response from the Binance API.
 ///
 /// This is public as it is used by Batch responses.
@@ -374,3 +391,240 @@ impl From<ErrorResponse> for ApiError {
         }
     }
 }
+
+impl ApiError {
+    /// The Binance error code this error was created from, or `None` for errors
+    /// derived from the HTTP status alone
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            ApiError::UnknownApiError { .. } => Some(-1000),
+            ApiError::Disconnected { .. } => Some(-1001),
+            ApiError::Unauthorized { .. } => Some(-1002),
+            ApiError::TooManyRequests { .. } => Some(-1003),
+            ApiError::DuplicateIp { .. } => Some(-1004),
+            ApiError::NoSuchIp { .. } => Some(-1005),
+            ApiError::UnexpectedResponse { .. } => Some(-1006),
+            ApiError::Timeout { .. } => Some(-1007),
+            ApiError::ErrorMsgReceived { .. } => Some(-1010),
+            ApiError::NonWhiteList { .. } => Some(-1011),
+            ApiError::InvalidMessage { .. } => Some(-1013),
+            ApiError::UnknownOrderComposition { .. } => Some(-1014),
+            ApiError::TooManyOrders { .. } => Some(-1015),
+            ApiError::ServiceShuttingDown { .. } => Some(-1016),
+            ApiError::UnsupportedOperation { .. } => Some(-1020),
+            ApiError::InvalidTimestamp { .. } => Some(-1021),
+            ApiError::InvalidSignature { .. } => Some(-1022),
+            ApiError::StartTimeGreaterThanEndTime { .. } => Some(-1023),
+            ApiError::IllegalChars { .. } => Some(-1100),
+            ApiError::TooManyParameters { .. } => Some(-1101),
+            ApiError::MandatoryParamEmptyOrMalformed { .. } => Some(-1102),
+            ApiError::UnknownParam { .. } => Some(-1103),
+            ApiError::UnreadParameters { .. } => Some(-1104),
+            ApiError::ParamEmpty { .. } => Some(-1105),
+            ApiError::ParamNotRequired { .. } => Some(-1106),
+            ApiError::BadAsset { .. } => Some(-1108),
+            ApiError::BadAccount { .. } => Some(-1109),
+            ApiError::BadInstrumentType { .. } => Some(-1110),
+            ApiError::BadPrecision { .. } => Some(-1111),
+            ApiError::NoDepth { .. } => Some(-1112),
+            ApiError::WithdrawNotNegative { .. } => Some(-1113),
+            ApiError::TifNotRequired { .. } => Some(-1114),
+            ApiError::InvalidTif { .. } => Some(-1115),
+            ApiError::InvalidOrderType { .. } => Some(-1116),
+            ApiError::InvalidSide { .. } => Some(-1117),
+            ApiError::EmptyNewClOrdId { .. } => Some(-1118),
+            ApiError::EmptyOrgClOrdId { .. } => Some(-1119),
+            ApiError::BadInterval { .. } => Some(-1120),
+            ApiError::BadSymbol { .. } => Some(-1121),
+            ApiError::InvalidListenKey { .. } => Some(-1125),
+            ApiError::MoreThanXxHours { .. } => Some(-1127),
+            ApiError::OptionalParamsBadCombo { .. } => Some(-1128),
+            ApiError::InvalidParameter { .. } => Some(-1130),
+            ApiError::InvalidNewOrderRespType { .. } => Some(-1136),
+            ApiError::NewOrderRejected { .. } => Some(-2010),
+            ApiError::CancelRejected { .. } => Some(-2011),
+            ApiError::NoSuchOrder { .. } => Some(-2013),
+            ApiError::BadApiKeyFmt { .. } => Some(-2014),
+            ApiError::RejectedMbxKey { .. } => Some(-2015),
+            ApiError::NoTradingWindow { .. } => Some(-2016),
+            ApiError::BalanceNotSufficient { .. } => Some(-2018),
+            ApiError::MarginNotSufficient { .. } => Some(-2019),
+            ApiError::UnableToFill { .. } => Some(-2020),
+            ApiError::OrderWouldImmediatelyTrigger { .. } => Some(-2021),
+            ApiError::ReduceOnlyReject { .. } => Some(-2022),
+            ApiError::UserInLiquidation { .. } => Some(-2023),
+            ApiError::PositionNotSufficient { .. } => Some(-2024),
+            ApiError::MaxOpenOrderExceeded { .. } => Some(-2025),
+            ApiError::ReduceOnlyOrderTypeNotSupported { .. } => Some(-2026),
+            ApiError::MaxLeverageRatio { .. } => Some(-2027),
+            ApiError::MinLeverageRatio { .. } => Some(-2028),
+            ApiError::UnmappedApiError { code, .. } => Some(*code),
+            ApiError::IpBanned { .. }
+            | ApiError::RateLimitExceeded { .. }
+            | ApiError::WafLimitViolated { .. }
+            | ApiError::RequestTimeout { .. }
+            | ApiError::IpAutoBanned { .. }
+            | ApiError::InternalServerError { .. }
+            | ApiError::ServiceUnavailable { .. } => None,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::RateLimitExceeded { retry_after, .. } => ErrorKind::RateLimited {
+                retry_after: retry_after.map(Duration::from_secs),
+            },
+            ApiError::IpBanned { msg } | ApiError::IpAutoBanned { msg } => ErrorKind::IpBanned {
+                until: banned_until(msg),
+            },
+            ApiError::WafLimitViolated { .. } => ErrorKind::Other,
+            ApiError::RequestTimeout { .. } | ApiError::InternalServerError { .. } | ApiError::ServiceUnavailable { .. } => ErrorKind::UnknownExecutionStatus,
+            _ => self
+                .code()
+                .map_or(ErrorKind::Other, |code| error_kind(code, &self.to_string())),
+        }
+    }
+}
+
+/// Classify a Binance error code.
+///
+/// -1003 is also used for IP bans, in which case the message carries the ban expiry.
+fn error_kind(code: i32, msg: &str) -> ErrorKind {
+    match code {
+        -1003 => match banned_until(msg) {
+            Some(until) => ErrorKind::IpBanned { until: Some(until) },
+            None => ErrorKind::RateLimited { retry_after: None },
+        },
+        -1015 => ErrorKind::RateLimited { retry_after: None },
+        -1002 | -1011 | -1021 | -1022 | -2014 | -2015 | -4056 | -4057 => ErrorKind::AuthFailed,
+        -1001 | -1016 => ErrorKind::ExchangeUnavailable,
+        -1000 | -1006 | -1007 => ErrorKind::UnknownExecutionStatus,
+        -2011 | -2013 => ErrorKind::OrderNotFound,
+        -2018 | -2019 | -4050 | -4051 => ErrorKind::InsufficientFunds,
+        -1199..=-1010 | -2099..=-2010 | -4999..=-4000 => ErrorKind::InvalidRequest,
+        _ => ErrorKind::Other,
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(api_error) => {
+                let error = VenueError::new(VENUE, api_error.kind(), api_error.to_string());
+                match api_error.code() {
+                    Some(code) => error.with_code(code),
+                    None => error,
+                }
+            }
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    fn venue_error(code: i32, msg: &str) -> VenueError {
+        Errors::ApiError(ApiError::from(ErrorResponse {
+            code,
+            msg: msg.to_string(),
+        }))
+        .into()
+    }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error = venue_error(-2013, "Order does not exist.");
+        assert_eq!(error.kind, ErrorKind::OrderNotFound);
+        assert_eq!(error.code.as_deref(), Some("-2013"));
+        assert_eq!(error.message, "Order does not exist.");
+        assert_eq!(error.venue, VENUE);
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(
+            venue_error(-2019, "Margin is insufficient.").kind,
+            ErrorKind::InsufficientFunds
+        );
+        assert_eq!(
+            venue_error(-1022, "Signature for this request is not valid.").kind,
+            ErrorKind::AuthFailed
+        );
+        assert_eq!(
+            venue_error(-1121, "Invalid symbol.").kind,
+            ErrorKind::InvalidRequest
+        );
+        assert_eq!(
+            venue_error(-1007, "Timeout waiting for response from backend server.").kind,
+            ErrorKind::UnknownExecutionStatus
+        );
+        assert_eq!(
+            venue_error(-1003, "Too many requests.").kind,
+            ErrorKind::RateLimited { retry_after: None }
+        );
+    }
+
+    #[test]
+    fn test_venue_error_ip_ban_expiry() {
+        let error = venue_error(
+            -1003,
+            "Way too much request weight used; IP banned until 1659146011110.",
+        );
+        let until = UNIX_EPOCH.checked_add(Duration::from_millis(1_659_146_011_110));
+        assert_eq!(error.kind, ErrorKind::IpBanned { until });
+    }
+
+    #[test]
+    fn test_venue_error_from_http_status_errors() {
+        let error: VenueError = Errors::ApiError(ApiError::RateLimitExceeded {
+            msg: "Too many requests".to_string(),
+            used_weight_1m: Some(2400),
+            order_count_1m: None,
+            retry_after: Some(30),
+        })
+        .into();
+        assert_eq!(
+            error.kind,
+            ErrorKind::RateLimited {
+                retry_after: Some(Duration::from_secs(30))
+            }
+        );
+        assert_eq!(error.code, None);
+
+        let error: VenueError = Errors::ApiError(ApiError::ServiceUnavailable {
+            msg: "Unknown error, please check your request or try again later.".to_string(),
+        })
+        .into();
+        assert_eq!(error.kind, ErrorKind::UnknownExecutionStatus);
+    }
+
+    #[test]
+    fn test_venue_error_unmapped_code() {
+        let error = venue_error(-9999, "Something new");
+        assert_eq!(error.kind, ErrorKind::Other);
+        assert_eq!(error.code.as_deref(), Some("-9999"));
+    }
+
+    #[test]
+    fn test_venue_error_from_errors() {
+        let error = VenueError::from(Errors::InvalidApiKey());
+        assert_eq!(error.kind, ErrorKind::AuthFailed);
+
+        let error = VenueError::from(Errors::Error("unexpected".to_string()));
+        assert_eq!(error.kind, ErrorKind::Other);
+        assert_eq!(error.message, "unexpected");
+    }
+}
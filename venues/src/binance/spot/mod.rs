@@ -11,6 +11,13 @@ pub mod private_impl {
     pub use self::rest::RestClient as PrivateRestClient;
 }
 
+// Public module with re-exports
+pub mod public_impl {
+    pub mod rest;
+    // Re-export RestClient so it can be re-exported by the parent
+    pub use self::rest::RestClient as PublicRestClient;
+}
+
 // Re-export the PrivateRestClient at the spot level
 // Re-export key components
 pub use enums::*;
@@ -18,7 +25,50 @@ pub use errors::{ApiError, Errors};
 // Re-export for backward compatibility
 pub use private_impl as private;
 pub use private_impl::PrivateRestClient;
-pub use rate_limit::{RateLimitHeader, RateLimiter};
+pub use public_impl as public;
+pub use public_impl::PublicRestClient;
+// Re-export the endpoint request and response types
+pub use private_impl::rest::account::*;
+pub use private_impl::rest::all_order_lists::*;
+pub use private_impl::rest::all_orders::*;
+pub use private_impl::rest::cancel_open_orders::*;
+pub use private_impl::rest::cancel_order::*;
+pub use private_impl::rest::cancel_order_list::*;
+pub use private_impl::rest::cancel_replace::*;
+pub use private_impl::rest::commission::*;
+pub use private_impl::rest::my_trades::*;
+pub use private_impl::rest::oco::*;
+pub use private_impl::rest::open_order_lists::*;
+pub use private_impl::rest::open_orders::*;
+pub use private_impl::rest::order::*;
+pub use private_impl::rest::order_test::*;
+pub use private_impl::rest::oto::*;
+pub use private_impl::rest::otoco::*;
+pub use private_impl::rest::prevented_matches::*;
+pub use private_impl::rest::query_order::*;
+pub use private_impl::rest::query_order_list::*;
+pub use private_impl::rest::rate_limit_order::*;
+pub use private_impl::rest::sor_order::*;
+pub use private_impl::rest::sor_order_test::*;
+pub use public_impl::rest::agg_trades::*;
+pub use public_impl::rest::avg_price::*;
+pub use public_impl::rest::book_ticker::*;
+pub use public_impl::rest::book_tickers::*;
+pub use public_impl::rest::exchange_info::*;
+pub use public_impl::rest::historical_trades::*;
+pub use public_impl::rest::klines::*;
+pub use public_impl::rest::order_book::*;
+pub use public_impl::rest::ping::*;
+pub use public_impl::rest::price_ticker::*;
+pub use public_impl::rest::price_tickers::*;
+pub use public_impl::rest::rolling_window_ticker::*;
+pub use public_impl::rest::rolling_window_tickers::*;
+pub use public_impl::rest::server_time::*;
+pub use public_impl::rest::ticker_24hr::*;
+pub use public_impl::rest::tickers_24hr::*;
+pub use public_impl::rest::trades::*;
+pub use public_impl::rest::ui_klines::*;
+pub use rate_limit::{RateLimitHeader, RateLimitInterval, RateLimitType, RateLimiter, RequestWeight};
 
 pub use crate::binance::spot::errors::ErrorResponse;
 // Internal re-export for private client usage
@@ -49,6 +99,7 @@ impl ResponseHeaders {
 #[derive(Debug, Clone)]
 pub struct RestResponse<T> {
     pub data: T,
+    pub request_duration: std::time::Duration,
     pub headers: ResponseHeaders,
 }
 
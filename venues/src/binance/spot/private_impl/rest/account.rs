@@ -0,0 +1,121 @@
+// Account Information (USER_DATA) endpoint implementation for GET /api/v3/account
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#account-information-user_data>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+
+/// Request parameters for the account information (GET /api/v3/account).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRequest {
+    /// Whether to leave out assets without a balance (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub omit_zero_balances: Option<bool>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Commission rates by role in a trade, as fractions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommissionRates {
+    /// Rate when trading as maker.
+    pub maker: String,
+
+    /// Rate when trading as taker.
+    pub taker: String,
+
+    /// Rate when buying.
+    pub buyer: String,
+
+    /// Rate when selling.
+    pub seller: String,
+}
+
+/// Balance of an asset.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Balance {
+    /// Asset name.
+    pub asset: String,
+
+    /// Amount available to trade.
+    pub free: String,
+
+    /// Amount held by open orders.
+    pub locked: String,
+}
+
+/// Commission rates, permissions and balances of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountResponse {
+    /// Maker commission in basis points.
+    pub maker_commission: u32,
+
+    /// Taker commission in basis points.
+    pub taker_commission: u32,
+
+    /// Buyer commission in basis points.
+    pub buyer_commission: u32,
+
+    /// Seller commission in basis points.
+    pub seller_commission: u32,
+
+    /// Commission rates as fractions.
+    pub commission_rates: CommissionRates,
+
+    /// Whether the account can trade.
+    pub can_trade: bool,
+
+    /// Whether the account can withdraw.
+    pub can_withdraw: bool,
+
+    /// Whether the account can deposit.
+    pub can_deposit: bool,
+
+    /// Whether the account is managed by a broker.
+    pub brokered: bool,
+
+    /// Whether orders must set a self-trade prevention mode.
+    pub require_self_trade_prevention: bool,
+
+    /// Whether the smart order router is disabled for the account.
+    pub prevent_sor: bool,
+
+    /// Last update of the account (ms since epoch).
+    pub update_time: u64,
+
+    /// Account type (e.g., "SPOT").
+    pub account_type: String,
+
+    /// Balances of the assets.
+    pub balances: Vec<Balance>,
+
+    /// Permissions of the account (e.g., "SPOT").
+    pub permissions: Vec<String>,
+
+    /// User ID.
+    pub uid: u64,
+}
+
+impl RestClient {
+    /// Fetches the current account information and balances.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#account-information-user_data>
+    /// GET /api/v3/account
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AccountRequest`])
+    ///
+    /// # Returns
+    /// The [`AccountResponse`].
+    pub async fn get_account(&self, params: AccountRequest) -> RestResult<AccountResponse> {
+        self.send_signed_request("/api/v3/account", reqwest::Method::GET, params, 10, false)
+            .await
+    }
+}
@@ -0,0 +1,58 @@
+// Query All Order Lists (USER_DATA) endpoint implementation for GET /api/v3/allOrderList
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-all-order-lists-user_data>
+
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order_list::OrderListResponse;
+
+/// Request parameters for all order lists (GET /api/v3/allOrderList).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrderListsRequest {
+    /// Return order lists from this ID on. Cannot be sent with `start_time` or `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<i64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch), at most 24 hours after `start_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of order lists (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches all order lists, or those within a time range.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-all-order-lists-user_data>
+    /// GET /api/v3/allOrderList
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AllOrderListsRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`OrderListResponse`] objects.
+    pub async fn get_all_order_lists(&self, params: AllOrderListsRequest) -> RestResult<Vec<OrderListResponse>> {
+        self.send_signed_request(
+            "/api/v3/allOrderList",
+            reqwest::Method::GET,
+            params,
+            10,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,55 @@
+// All Orders (USER_DATA) endpoint implementation for GET /api/v3/allOrders
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#all-orders-user_data>
+
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order::Order;
+
+/// Request parameters for all orders of a symbol (GET /api/v3/allOrders).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Return orders from this order ID on. Otherwise the most recent orders are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch), at most 24 hours after `start_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of orders (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches all orders of a symbol: active, cancelled or filled.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#all-orders-user_data>
+    /// GET /api/v3/allOrders
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AllOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`Order`] objects.
+    pub async fn get_all_orders(&self, params: AllOrdersRequest) -> RestResult<Vec<Order>> {
+        self.send_signed_request("/api/v3/allOrders", reqwest::Method::GET, params, 10, false)
+            .await
+    }
+}
@@ -0,0 +1,125 @@
+// Cancel All Open Orders on a Symbol (TRADE) endpoint implementation for DELETE /api/v3/openOrders
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-all-open-orders-on-a-symbol-trade>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::cancel_order::CancelOrderResponse;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order_list::OrderListResponse;
+
+/// Request parameters for cancelling all open orders of a symbol (DELETE /api/v3/openOrders).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOpenOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// An order or order list cancelled by [`RestClient::delete_open_orders`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CancelledOpenOrder {
+    /// A cancelled order list, with the orders it held
+    OrderList(OrderListResponse),
+
+    /// A cancelled order that wasn't part of an order list
+    Order(CancelOrderResponse),
+}
+
+impl RestClient {
+    /// Cancels all open orders on a symbol, including the orders of order lists.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-all-open-orders-on-a-symbol-trade>
+    /// DELETE /api/v3/openOrders
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelOpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// The cancelled orders and order lists.
+    pub async fn delete_open_orders(&self, params: CancelOpenOrdersRequest) -> RestResult<Vec<CancelledOpenOrder>> {
+        self.send_signed_request(
+            "/api/v3/openOrders",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_cancelled_orders_and_order_lists_are_told_apart() {
+        let cancelled: Vec<CancelledOpenOrder> = serde_json::from_value(json!([
+            {
+                "symbol": "BTCUSDT",
+                "origClientOrderId": "E6APeyTJvkMvLMYMqu1KQ4",
+                "orderId": 11,
+                "orderListId": -1,
+                "clientOrderId": "pXLV6Hz6mprAcVYpVMTGgx",
+                "transactTime": 1684804350068_u64,
+                "price": "0.089853",
+                "origQty": "0.178622",
+                "executedQty": "0.000000",
+                "cummulativeQuoteQty": "0.000000",
+                "status": "CANCELED",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "BUY",
+                "selfTradePreventionMode": "NONE"
+            },
+            {
+                "orderListId": 1929,
+                "contingencyType": "OCO",
+                "listStatusType": "ALL_DONE",
+                "listOrderStatus": "ALL_DONE",
+                "listClientOrderId": "2inzWQdDvZLHbbAmAozX2N",
+                "transactionTime": 1585230948299_u64,
+                "symbol": "BTCUSDT",
+                "orders": [
+                    {"symbol": "BTCUSDT", "orderId": 20, "clientOrderId": "CwOOIPHSmYywx6jZX77TdL"},
+                    {"symbol": "BTCUSDT", "orderId": 21, "clientOrderId": "461cPg51vQjV3zIMOXNz39"}
+                ],
+                "orderReports": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "origClientOrderId": "CwOOIPHSmYywx6jZX77TdL",
+                        "orderId": 20,
+                        "orderListId": 1929,
+                        "clientOrderId": "pXLV6Hz6mprAcVYpVMTGgx",
+                        "transactTime": 1688005070874_u64,
+                        "price": "0.668611",
+                        "origQty": "0.690354",
+                        "executedQty": "0.000000",
+                        "cummulativeQuoteQty": "0.000000",
+                        "status": "CANCELED",
+                        "timeInForce": "GTC",
+                        "type": "STOP_LOSS_LIMIT",
+                        "side": "BUY",
+                        "stopPrice": "0.378131",
+                        "icebergQty": "0.017083",
+                        "selfTradePreventionMode": "NONE"
+                    }
+                ]
+            }
+        ]))
+        .unwrap();
+
+        assert!(matches!(cancelled.first(), Some(CancelledOpenOrder::Order(order)) if order.order_id == 11));
+        assert!(matches!(cancelled.get(1), Some(CancelledOpenOrder::OrderList(list)) if list.orders.len() == 2 && list.order_reports.len() == 1));
+    }
+}
@@ -0,0 +1,122 @@
+// Cancel Order (TRADE) endpoint implementation for DELETE /api/v3/order
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-order-trade>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::{CancelRestrictions, OrderSide, OrderStatus, OrderType, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for cancelling an order (DELETE /api/v3/order).
+///
+/// Either `order_id` or `orig_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// New client ID for the cancel. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// Only cancel the order if it is in this status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_restrictions: Option<CancelRestrictions>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A cancelled order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderResponse {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Client order ID of the cancelled order.
+    pub orig_client_order_id: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// ID of the order list the order belongs to, -1 otherwise.
+    pub order_list_id: i64,
+
+    /// Client ID of the cancel.
+    pub client_order_id: String,
+
+    /// Time of the cancel (ms since epoch).
+    pub transact_time: u64,
+
+    /// Order price, 0 for market orders.
+    pub price: String,
+
+    /// Order quantity in the base asset.
+    pub orig_qty: String,
+
+    /// Quantity filled so far.
+    pub executed_qty: String,
+
+    /// Order quantity in the quote asset, 0 unless sized with `quoteOrderQty`.
+    pub orig_quote_order_qty: Option<String>,
+
+    /// Quote asset spent or received by the fills so far.
+    pub cummulative_quote_qty: String,
+
+    /// Order status.
+    pub status: OrderStatus,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Stop price, only returned for stop orders.
+    pub stop_price: Option<String>,
+
+    /// Visible quantity, only returned for iceberg orders.
+    pub iceberg_qty: Option<String>,
+
+    /// Self-trade prevention mode.
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+impl RestClient {
+    /// Cancels an active order.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-order-trade>
+    /// DELETE /api/v3/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`CancelOrderResponse`].
+    pub async fn delete_order(&self, params: CancelOrderRequest) -> RestResult<CancelOrderResponse> {
+        self.send_signed_request(
+            "/api/v3/order",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,59 @@
+// Cancel Order List (TRADE) endpoint implementation for DELETE /api/v3/orderList
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-order-list-trade>
+
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order_list::OrderListResponse;
+
+/// Request parameters for cancelling an order list (DELETE /api/v3/orderList).
+///
+/// Either `order_list_id` or `list_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderListRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order list ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_list_id: Option<i64>,
+
+    /// Client ID of the order list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_client_order_id: Option<String>,
+
+    /// New client ID for the cancel. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels an entire order list.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-order-list-trade>
+    /// DELETE /api/v3/orderList
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelOrderListRequest`])
+    ///
+    /// # Returns
+    /// The cancelled [`OrderListResponse`].
+    pub async fn delete_order_list(&self, params: CancelOrderListRequest) -> RestResult<OrderListResponse> {
+        self.send_signed_request(
+            "/api/v3/orderList",
+            reqwest::Method::DELETE,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,150 @@
+// Cancel an Existing Order and Send a New Order (TRADE) endpoint implementation for
+// POST /api/v3/order/cancelReplace
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-an-existing-order-and-send-a-new-order-trade>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::private_impl::rest::cancel_order::CancelOrderResponse;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::order::NewOrderResponse;
+use crate::binance::spot::{
+    CancelReplaceMode, CancelReplaceResult, CancelRestrictions, OrderRateLimitExceededMode, OrderResponseType, OrderSide, OrderType, RestResult,
+    SelfTradePreventionMode, TimeInForce,
+};
+
+/// Request parameters for cancelling an order and placing a new one (POST /api/v3/order/cancelReplace).
+///
+/// Either `cancel_order_id` or `cancel_orig_client_order_id` must be sent. The remaining
+/// parameters describe the new order, as for [`RestClient::post_order`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Side of the new order.
+    pub side: OrderSide,
+
+    /// Type of the new order.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Whether to place the new order if the cancel fails.
+    pub cancel_replace_mode: CancelReplaceMode,
+
+    /// Time in force of the new order. Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Quantity of the new order in the base asset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+
+    /// Quantity of a new MARKET order in the quote asset. Cannot be sent with `quantity`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_order_qty: Option<String>,
+
+    /// Price of the new order. Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// New client ID for the cancel. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_new_client_order_id: Option<String>,
+
+    /// Client order ID of the order to cancel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_orig_client_order_id: Option<String>,
+
+    /// Order ID of the order to cancel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_order_id: Option<u64>,
+
+    /// Client order ID of the new order. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// Arbitrary numeric value identifying the new order within an order strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+
+    /// Arbitrary numeric value identifying the order strategy, at least 1000000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<u32>,
+
+    /// Stop price of a new stop or take profit order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+
+    /// Trailing delta in basis points of a new trailing stop order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u32>,
+
+    /// Visible quantity of a new iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<String>,
+
+    /// Response type of the new order (ACK, RESULT, FULL).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Self-trade prevention mode of the new order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Only cancel the order if it is in this status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_restrictions: Option<CancelRestrictions>,
+
+    /// Whether to still cancel the order once the unfilled order count is exceeded (default DO_NOTHING).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Outcome of a cancel-replace where both the cancel and the new order succeeded.
+///
+/// If either fails, Binance answers with an error carrying both outcomes instead.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOrderResponse {
+    /// Outcome of the cancel, always a success.
+    pub cancel_result: CancelReplaceResult,
+
+    /// Outcome of the new order, always a success.
+    pub new_order_result: CancelReplaceResult,
+
+    /// The cancelled order.
+    pub cancel_response: CancelOrderResponse,
+
+    /// The new order.
+    pub new_order_response: NewOrderResponse,
+}
+
+impl RestClient {
+    /// Cancels an existing order and places a new order on the same symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#cancel-an-existing-order-and-send-a-new-order-trade>
+    /// POST /api/v3/order/cancelReplace
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelReplaceOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`CancelReplaceOrderResponse`] with the cancelled and the new order.
+    pub async fn post_cancel_replace_order(&self, params: CancelReplaceOrderRequest) -> RestResult<CancelReplaceOrderResponse> {
+        self.send_signed_request(
+            "/api/v3/order/cancelReplace",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -21,32 +21,23 @@
 //! - **Timestamp Requirements**: Signed requests must include a timestamp parameter and signature
 //!   based on the current UTC timestamp in milliseconds
 //!
-//! - **Request Signing**: For private endpoints, query parameters (including timestamp) must be
-//!   signed using HMAC-SHA256 with the API secret
+//! - **Request Signing**: For private endpoints, the parameters (including timestamp) must be
+//!   signed using HMAC-SHA256 with the API secret. They are sent in the query string for GET
+//!   and as the form body otherwise
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use hex;
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
-use sha2::Sha256;
+use async_trait::async_trait;
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::HttpTransport;
 
-use crate::binance::spot::{Errors, RateLimiter, RestResult};
-
-/// Signs a request using the decrypted API secret
-/// Signs a query string using the decrypted API secret and returns the signature as a hex string.
-///
-/// # Arguments
-/// * `query_string` - The query string to sign
-///
-/// # Returns
-/// A result containing the signature as a hex string or a Hmac error if signing fails.
-fn sign_request(api_secret: &dyn ExposableSecret, query_string: &str) -> Result<String, Errors> {
-    let api_secret = api_secret.expose_secret();
-    let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-    mac.update(query_string.as_bytes());
-    Ok(hex::encode(mac.finalize().into_bytes()))
-}
+use crate::binance::shared::{object_params, signed_payload};
+use crate::binance::spot::errors::VENUE;
+use crate::binance::spot::{RateLimiter, RequestWeight, RestResult};
 
 /// A client for interacting with the Binance Spot private REST API
 ///
@@ -55,15 +46,17 @@ fn sign_request(api_secret: &dyn ExposableSecret, query_string: &str) -> Result<
 #[non_exhaustive]
 pub struct RestClient {
     /// The underlying HTTP client used for making requests.
-    pub(crate) client: Client,
+    pub(crate) client: Arc<dyn HttpTransport>,
     /// The rate limiter for this client.
     pub(crate) rate_limiter: RateLimiter,
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
     /// The base URL for the API.
     pub(crate) base_url: Cow<'static, str>,
+    /// The server clock signed requests are timestamped with.
+    pub(crate) clock: ServerClock,
 }
 
 impl RestClient {
@@ -71,7 +64,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `rate_limiter` - The rate limiter instance
     /// * `client` - The HTTP client to use
@@ -80,20 +73,27 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
         rate_limiter: RateLimiter,
-        client: Client,
+        client: impl HttpTransport + 'static,
     ) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             rate_limiter,
             api_key,
             api_secret,
             base_url: base_url.into(),
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `timestamp` of signed requests from `clock` instead of the local clock
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Sends a request to the Binance Spot API
     ///
     /// This method encapsulates all the logic for making authenticated requests to the Binance API,
@@ -103,7 +103,7 @@ impl RestClient {
     /// * `endpoint` - The API endpoint path (e.g., "/api/v3/order")
     /// * `method` - The HTTP method to use
     /// * `query_string` - Optional query string parameters (for GET or for URL params)
-    /// * `body` - Optional body (for POST/PUT/DELETE with x-www-form-urlencoded)
+    /// * `body` - Optional x-www-form-urlencoded body (for POST/PUT/DELETE)
     /// * `weight` - The request weight for this endpoint
     /// * `is_order` - Whether this is an order-related endpoint
     ///
@@ -114,56 +114,20 @@ impl RestClient {
         endpoint: &str,
         method: reqwest::Method,
         query_string: Option<&str>,
-        body: Option<&[(&str, &str)]>,
+        body: Option<&str>,
         weight: u32,
         is_order: bool,
     ) -> RestResult<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        // Add timestamp to query params for signing
-        let timestamp = chrono::Utc::now().timestamp_millis().to_string();
-        let mut query_params = vec![("timestamp".to_string(), timestamp)];
-
-        // Add any additional query parameters
-        if let Some(qs) = query_string {
-            // Parse existing query string and add to params
-            let parsed: Vec<(String, String)> = serde_urlencoded::from_str(qs).map_err(|e| Errors::Error(format!("Invalid query string: {}", e)))?;
-            query_params.extend(parsed);
-        }
-
-        // Add body parameters to query params for signing
-        if let Some(body_params) = body {
-            for (k, v) in body_params {
-                query_params.push((k.to_string(), v.to_string()));
-            }
-        }
-
-        // Build query string for signing
-        let query_for_signing = serde_urlencoded::to_string(&query_params).map_err(|e| Errors::Error(format!("Failed to encode query string: {}", e)))?;
-
-        // Sign the request
-        let signature = sign_request(self.api_secret.as_ref(), &query_for_signing)?;
-        query_params.push(("signature".to_string(), signature));
-
-        // Final query string with signature
-        let final_query_string =
-            serde_urlencoded::to_string(&query_params).map_err(|e| Errors::Error(format!("Failed to encode final query string: {}", e)))?;
-
-        let url = crate::binance::spot::rest::common::build_url(&self.base_url, endpoint, Some(&final_query_string))?;
-
+        let url = crate::binance::spot::rest::common::build_url(&self.base_url, endpoint, query_string)?;
         let mut headers = vec![];
         let api_key = self.api_key.expose_secret();
         if !api_key.is_empty() {
             headers.push(("X-MBX-APIKEY", api_key));
         }
-
-        let body_data = match body {
-            Some(b) => Some(serde_urlencoded::to_string(b).map_err(|e| Errors::Error(format!("URL encoding error: {}", e)))?),
-            None => None,
-        };
-
-        if body_data.is_some() {
+        if body.is_some() {
             headers.push((
                 "Content-Type",
                 "application/x-www-form-urlencoded".to_string(),
@@ -171,11 +135,11 @@ impl RestClient {
         }
 
         let rest_response = crate::binance::spot::rest::common::send_rest_request(
-            &self.client,
+            self.client.as_ref(),
             &url,
             method,
             headers,
-            body_data.as_deref(),
+            body,
             &self.rate_limiter,
             weight,
             is_order,
@@ -184,66 +148,128 @@ impl RestClient {
 
         Ok(crate::binance::spot::RestResponse {
             data: rest_response.data,
+            request_duration: rest_response.request_duration,
             headers: rest_response.headers,
         })
     }
+
+    /// Sends a signed request to the Binance Spot API
+    ///
+    /// Stamps the request with the server clock's time, adds a `recvWindow` once the clock is
+    /// synced, and signs it.
+    ///
+    /// # Arguments
+    /// * `endpoint` - The API endpoint path (e.g., "/api/v3/order")
+    /// * `method` - The HTTP method to use
+    /// * `request` - The request parameters, sent in the query string for GET and as the form body otherwise
+    /// * `weight` - The request weight for this endpoint
+    /// * `is_order` - Whether this is an order-related endpoint
+    ///
+    /// # Returns
+    /// A result containing the parsed response data and metadata, or an error
+    pub(super) async fn send_signed_request<T, R>(&self, endpoint: &str, method: reqwest::Method, request: R, weight: u32, is_order: bool) -> RestResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+        R: serde::Serialize,
+    {
+        let signed = signed_payload(&self.clock, self.api_secret.as_ref(), &request).await?;
+        if method == reqwest::Method::GET {
+            self.send_request(endpoint, method, Some(&signed), None, weight, is_order)
+                .await
+        } else {
+            self.send_request(endpoint, method, None, Some(&signed), weight, is_order)
+                .await
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use rest::secrets::ExposableSecret;
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
 
-    use super::*;
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
 
-    // Create a simple test secret implementation
-    #[derive(Clone)]
-    struct TestSecret {
-        value: String,
+    fn base_url(&self) -> &str {
+        &self.base_url
     }
 
-    impl ExposableSecret for TestSecret {
-        fn expose_secret(&self) -> String {
-            self.value.clone()
-        }
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
     }
 
-    impl TestSecret {
-        fn new(value: String) -> Self {
-            Self { value }
-        }
+    /// Signs every request. `send_signed_request` stamps the `timestamp` from the server clock.
+    async fn send(&self, request: RestRequest<RequestWeight>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let params = object_params(VENUE, request.params)?;
+        let response = self
+            .send_signed_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                params,
+                request.rate_limit_key.weight,
+                request.rate_limit_key.is_order,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use reqwest::{Client, Method};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::shared::test_support::{self, API_KEY, API_SECRET, TestSecret};
+
+    pub(in crate::binance::spot) fn client(transport: &MockTransport) -> RestClient {
+        RestClient::new(
+            Box::new(TestSecret(API_KEY)),
+            Box::new(TestSecret(API_SECRET)),
+            "https://api.binance.com",
+            RateLimiter::new(),
+            transport.clone(),
+        )
     }
 
     #[test]
     fn test_private_client_creation() {
-        let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
-        let client = Client::new();
-        let rate_limiter = RateLimiter::new();
-
         let rest_client = RestClient::new(
-            api_key,
-            api_secret,
+            Box::new(TestSecret(API_KEY)),
+            Box::new(TestSecret(API_SECRET)),
             "https://api.binance.com",
-            rate_limiter,
-            client,
+            RateLimiter::new(),
+            Client::new(),
         );
 
         assert_eq!(rest_client.base_url, "https://api.binance.com");
     }
 
-    #[test]
-    fn test_request_signing() {
-        let secret = TestSecret::new("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j".to_string());
-        let query_string = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
-
-        let result = sign_request(&secret, query_string);
-        assert!(result.is_ok());
-
-        let signature = result.unwrap();
-        // This is the expected signature from Binance API documentation
-        assert_eq!(
-            signature,
-            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
-        );
+    #[tokio::test]
+    async fn test_get_is_signed_in_query_string() {
+        let transport = MockTransport::new();
+        let request = RestRequest::new(Method::GET, "/api/v3/openOrders", RequestWeight::new(6)).with_params(json!({ "symbol": "BTCUSDT" }));
+
+        let params = test_support::send_signed_get(&client(&transport), &transport, request).await;
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["symbol", "timestamp"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_is_signed_in_form_body() {
+        let transport = MockTransport::new();
+        let request = RestRequest::new(Method::DELETE, "/api/v3/order", RequestWeight::new(1)).with_params(json!({
+            "symbol": "BTCUSDT",
+            "origClientOrderId": "my-order-1",
+        }));
+
+        let (sent, params) = test_support::send_signed_form(&client(&transport), &transport, request).await;
+        assert_eq!(sent.method, Method::DELETE);
+        assert_eq!(sent.url, "https://api.binance.com/api/v3/order");
+        assert!(params.contains(&("origClientOrderId".to_string(), "my-order-1".to_string())));
     }
 }
@@ -0,0 +1,73 @@
+// Query Commission Rates (USER_DATA) endpoint implementation for GET /api/v3/account/commission
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-commission-rates-user_data>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::account::CommissionRates;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+
+/// Request parameters for the commission rates of a symbol (GET /api/v3/account/commission).
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct CommissionRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Discount on the standard commission when it is paid in the discount asset.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionDiscount {
+    /// Whether the account has the discount enabled.
+    pub enabled_for_account: bool,
+
+    /// Whether the symbol offers the discount.
+    pub enabled_for_symbol: bool,
+
+    /// Asset the commission must be paid in for the discount (e.g., "BNB").
+    pub discount_asset: String,
+
+    /// Discount as a fraction of the standard commission.
+    pub discount: String,
+}
+
+/// Commission rates of the account on a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionResponse {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Commission rates applied to every trade.
+    pub standard_commission: CommissionRates,
+
+    /// Tax rates applied on top of the standard commission.
+    pub tax_commission: CommissionRates,
+
+    /// Discount for paying the commission in the discount asset.
+    pub discount: CommissionDiscount,
+}
+
+impl RestClient {
+    /// Fetches the current account commission rates of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-commission-rates-user_data>
+    /// GET /api/v3/account/commission
+    /// Weight: 20
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CommissionRequest`])
+    ///
+    /// # Returns
+    /// The [`CommissionResponse`].
+    pub async fn get_commission(&self, params: CommissionRequest) -> RestResult<CommissionResponse> {
+        self.send_signed_request(
+            "/api/v3/account/commission",
+            reqwest::Method::GET,
+            params,
+            20,
+            false,
+        )
+        .await
+    }
+}
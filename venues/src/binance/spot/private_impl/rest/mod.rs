@@ -1,5 +1,27 @@
 // Private REST endpoints module for Binance Spot
 
+pub mod account;
+pub mod all_order_lists;
+pub mod all_orders;
+pub mod cancel_open_orders;
+pub mod cancel_order;
+pub mod cancel_order_list;
+pub mod cancel_replace;
 pub mod client;
+pub mod commission;
+pub mod my_trades;
+pub mod oco;
+pub mod open_order_lists;
+pub mod open_orders;
+pub mod order;
+pub mod order_test;
+pub mod oto;
+pub mod otoco;
+pub mod prevented_matches;
+pub mod query_order;
+pub mod query_order_list;
+pub mod rate_limit_order;
+pub mod sor_order;
+pub mod sor_order_test;
 
 pub use client::RestClient;
@@ -0,0 +1,102 @@
+// Account Trade List (USER_DATA) endpoint implementation for GET /api/v3/myTrades
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#account-trade-list-user_data>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+
+/// Request parameters for the account's trades on a symbol (GET /api/v3/myTrades).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MyTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Only return the trades of this order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch), at most 24 hours after `start_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Return trades from this trade ID on. Otherwise the most recent trades are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A trade of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTrade {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Trade ID.
+    pub id: u64,
+
+    /// ID of the order that traded.
+    pub order_id: u64,
+
+    /// ID of the order list the order belongs to, -1 otherwise.
+    pub order_list_id: i64,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity in the base asset.
+    pub qty: String,
+
+    /// Quantity in the quote asset.
+    pub quote_qty: String,
+
+    /// Commission paid.
+    pub commission: String,
+
+    /// Asset the commission was paid in.
+    pub commission_asset: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+
+    /// Whether the account bought.
+    pub is_buyer: bool,
+
+    /// Whether the account was the maker.
+    pub is_maker: bool,
+
+    /// Whether the trade was the best price match.
+    pub is_best_match: bool,
+}
+
+impl RestClient {
+    /// Fetches the account's trades on a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#account-trade-list-user_data>
+    /// GET /api/v3/myTrades
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MyTradesRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`AccountTrade`] objects.
+    pub async fn get_my_trades(&self, params: MyTradesRequest) -> RestResult<Vec<AccountTrade>> {
+        self.send_signed_request("/api/v3/myTrades", reqwest::Method::GET, params, 10, false)
+            .await
+    }
+}
@@ -0,0 +1,120 @@
+// New Order List - OCO (TRADE) endpoint implementation for POST /api/v3/orderList/oco
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-list---oco-trade>
+
+use serde::Serialize;
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order_list::OrderListResponse;
+use crate::binance::spot::{OrderResponseType, OrderSide, OrderType, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for placing a one-cancels-the-other order list (POST /api/v3/orderList/oco).
+///
+/// The above order is priced above the market and the below order below it. Either is a
+/// LIMIT_MAKER order and the other a stop or take profit order.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOcoOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Unique client ID of the order list. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_client_order_id: Option<String>,
+
+    /// Side of both orders.
+    pub side: OrderSide,
+
+    /// Quantity of both orders.
+    pub quantity: String,
+
+    /// STOP_LOSS_LIMIT, STOP_LOSS, LIMIT_MAKER, TAKE_PROFIT or TAKE_PROFIT_LIMIT.
+    pub above_type: OrderType,
+
+    /// Client order ID of the above order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_client_order_id: Option<String>,
+
+    /// Visible quantity, if the above order is an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_iceberg_qty: Option<String>,
+
+    /// Price of a limit above order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_price: Option<String>,
+
+    /// Stop price of a stop above order. Either this or `above_trailing_delta` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_stop_price: Option<String>,
+
+    /// Trailing delta in basis points of a stop above order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_trailing_delta: Option<u32>,
+
+    /// Time in force of a limit above order other than LIMIT_MAKER.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_time_in_force: Option<TimeInForce>,
+
+    /// STOP_LOSS_LIMIT, STOP_LOSS, LIMIT_MAKER, TAKE_PROFIT or TAKE_PROFIT_LIMIT.
+    pub below_type: OrderType,
+
+    /// Client order ID of the below order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_client_order_id: Option<String>,
+
+    /// Visible quantity, if the below order is an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_iceberg_qty: Option<String>,
+
+    /// Price of a limit below order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_price: Option<String>,
+
+    /// Stop price of a stop below order. Either this or `below_trailing_delta` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_stop_price: Option<String>,
+
+    /// Trailing delta in basis points of a stop below order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_trailing_delta: Option<u32>,
+
+    /// Time in force of a limit below order other than LIMIT_MAKER.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub below_time_in_force: Option<TimeInForce>,
+
+    /// New order response type (ACK, RESULT, FULL).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Places a one-cancels-the-other order list: when either order fills, the other is cancelled.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-list---oco-trade>
+    /// POST /api/v3/orderList/oco
+    /// Weight: 1 (order rate limit, where the list counts as 2 orders)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewOcoOrderRequest`])
+    ///
+    /// # Returns
+    /// The placed [`OrderListResponse`].
+    pub async fn post_oco_order(&self, params: NewOcoOrderRequest) -> RestResult<OrderListResponse> {
+        self.send_signed_request(
+            "/api/v3/orderList/oco",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,42 @@
+// Query Open Order Lists (USER_DATA) endpoint implementation for GET /api/v3/openOrderList
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-open-order-lists-user_data>
+
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order_list::OrderListResponse;
+
+/// Request parameters for the open order lists (GET /api/v3/openOrderList).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrderListsRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the open order lists.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-open-order-lists-user_data>
+    /// GET /api/v3/openOrderList
+    /// Weight: 3
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`OpenOrderListsRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`OrderListResponse`] objects.
+    pub async fn get_open_order_lists(&self, params: OpenOrderListsRequest) -> RestResult<Vec<OrderListResponse>> {
+        self.send_signed_request(
+            "/api/v3/openOrderList",
+            reqwest::Method::GET,
+            params,
+            3,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,48 @@
+// Current Open Orders (USER_DATA) endpoint implementation for GET /api/v3/openOrders
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#current-open-orders-user_data>
+
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order::Order;
+
+/// Request parameters for the open orders (GET /api/v3/openOrders).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest {
+    /// The trading symbol (e.g., "BTCUSDT").
+    /// If not sent, will return orders for all symbols at a much higher weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches all open orders on a symbol, or on all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#current-open-orders-user_data>
+    /// GET /api/v3/openOrders
+    /// Weight: 3 for a single symbol, 40 when the symbol is omitted
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`OpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`Order`] objects.
+    pub async fn get_open_orders(&self, params: OpenOrdersRequest) -> RestResult<Vec<Order>> {
+        let weight = if params.symbol.is_some() { 3 } else { 40 };
+        self.send_signed_request(
+            "/api/v3/openOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,195 @@
+// New Order (TRADE) endpoint implementation for POST /api/v3/order
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-trade>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::{OrderResponseType, OrderSide, OrderStatus, OrderType, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for placing a new order (POST /api/v3/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Order type (LIMIT, MARKET, etc.).
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Time in force (GTC, IOC, FOK). Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order quantity in the base asset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+
+    /// Order quantity in the quote asset, for MARKET orders. Cannot be sent with `quantity`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_order_qty: Option<String>,
+
+    /// Order price. Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Unique client order ID. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// Arbitrary numeric value identifying the order within an order strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+
+    /// Arbitrary numeric value identifying the order strategy, at least 1000000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<u32>,
+
+    /// Stop price for STOP_LOSS, STOP_LOSS_LIMIT, TAKE_PROFIT and TAKE_PROFIT_LIMIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+
+    /// Trailing delta in basis points for trailing stop orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u32>,
+
+    /// Visible quantity of an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<String>,
+
+    /// New order response type (ACK, RESULT, FULL). Defaults to FULL for MARKET and LIMIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A fill of a newly placed order, returned with the FULL response type.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fill {
+    /// Price of the fill.
+    pub price: String,
+
+    /// Quantity of the fill.
+    pub qty: String,
+
+    /// Commission paid.
+    pub commission: String,
+
+    /// Asset the commission was paid in.
+    pub commission_asset: String,
+
+    /// Trade ID, -1 for fills of smart order routing orders.
+    pub trade_id: i64,
+
+    /// Allocation ID, only returned for fills of smart order routing orders.
+    pub alloc_id: Option<u64>,
+}
+
+/// A newly placed order.
+///
+/// The ACK response type only returns the IDs and the transaction time; RESULT adds the order
+/// details and FULL the fills.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderResponse {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// ID of the order list the order belongs to, -1 otherwise.
+    pub order_list_id: i64,
+
+    /// Client order ID.
+    pub client_order_id: String,
+
+    /// Time the order was placed or updated (ms since epoch).
+    pub transact_time: u64,
+
+    /// Order price, 0 for market orders.
+    pub price: Option<String>,
+
+    /// Order quantity in the base asset.
+    pub orig_qty: Option<String>,
+
+    /// Quantity filled so far.
+    pub executed_qty: Option<String>,
+
+    /// Order quantity in the quote asset, 0 unless sized with `quoteOrderQty`.
+    pub orig_quote_order_qty: Option<String>,
+
+    /// Quote asset spent or received by the fills so far.
+    pub cummulative_quote_qty: Option<String>,
+
+    /// Order status.
+    pub status: Option<OrderStatus>,
+
+    /// Time in force.
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: Option<OrderType>,
+
+    /// Order side.
+    pub side: Option<OrderSide>,
+
+    /// Stop price, only returned for stop orders.
+    pub stop_price: Option<String>,
+
+    /// Visible quantity, only returned for iceberg orders.
+    pub iceberg_qty: Option<String>,
+
+    /// Time the order was put on the book.
+    pub working_time: Option<u64>,
+
+    /// Self-trade prevention mode.
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Order book the order was placed on, only returned for smart order routing orders.
+    pub working_floor: Option<String>,
+
+    /// Whether the order was routed by smart order routing.
+    pub used_sor: Option<bool>,
+
+    /// Fills, only returned with the FULL response type.
+    #[serde(default)]
+    pub fills: Vec<Fill>,
+}
+
+impl RestClient {
+    /// Places a new order (TRADE) on Binance Spot.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-trade>
+    /// POST /api/v3/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewOrderRequest`])
+    ///
+    /// # Returns
+    /// A [`NewOrderResponse`] with as much detail as the response type asks for.
+    pub async fn post_order(&self, params: NewOrderRequest) -> RestResult<NewOrderResponse> {
+        self.send_signed_request(
+            "/api/v3/order",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
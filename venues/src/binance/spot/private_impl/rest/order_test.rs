@@ -0,0 +1,168 @@
+// Test New Order (TRADE) endpoint implementation for POST /api/v3/order/test
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#test-new-order-trade>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::commission::CommissionDiscount;
+use crate::binance::spot::private_impl::rest::order::NewOrderRequest;
+
+/// Request parameters for testing a new order (POST /api/v3/order/test).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestOrderRequest {
+    /// The order to test.
+    #[serde(flatten)]
+    pub order: NewOrderRequest,
+
+    /// Whether to return the commission rates the order would pay (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_commission_rates: Option<bool>,
+}
+
+/// Commission rates of an order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderCommissionRates {
+    /// Rate when the order trades as maker.
+    pub maker: String,
+
+    /// Rate when the order trades as taker.
+    pub taker: String,
+}
+
+/// Response of a test order, empty unless commission rates were requested.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestOrderResponse {
+    /// Standard commission rates.
+    pub standard_commission_for_order: Option<OrderCommissionRates>,
+
+    /// Tax rates applied on top of the standard commission.
+    pub tax_commission_for_order: Option<OrderCommissionRates>,
+
+    /// Discount for paying the commission in the discount asset.
+    pub discount: Option<CommissionDiscount>,
+}
+
+/// Request weight of a test order: computing the commission rates costs more
+pub(crate) fn test_order_weight(compute_commission_rates: Option<bool>) -> u32 {
+    if compute_commission_rates.unwrap_or(false) {
+        20
+    } else {
+        1
+    }
+}
+
+impl RestClient {
+    /// Validates a new order without sending it to the matching engine.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#test-new-order-trade>
+    /// POST /api/v3/order/test
+    /// Weight: 1, or 20 when computing the commission rates
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`TestOrderRequest`])
+    ///
+    /// # Returns
+    /// A [`TestOrderResponse`], empty unless commission rates were requested.
+    pub async fn post_order_test(&self, params: TestOrderRequest) -> RestResult<TestOrderResponse> {
+        let weight = test_order_weight(params.compute_commission_rates);
+        self.send_signed_request(
+            "/api/v3/order/test",
+            reqwest::Method::POST,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::shared::test_support::signed_params;
+    use crate::binance::spot::private_impl::rest::client::tests::client;
+    use crate::binance::spot::{OrderSide, OrderType, TimeInForce};
+
+    #[tokio::test]
+    async fn test_order_test_sends_flattened_order_and_parses_commission() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "standardCommissionForOrder": {"maker": "0.00000112", "taker": "0.00000114"},
+                "taxCommissionForOrder": {"maker": "0.00000112", "taker": "0.00000114"},
+                "discount": {
+                    "enabledForAccount": true,
+                    "enabledForSymbol": true,
+                    "discountAsset": "BNB",
+                    "discount": "0.25000000"
+                }
+            }),
+        );
+        let client = client(&transport);
+
+        let request = TestOrderRequest {
+            order: NewOrderRequest {
+                symbol: "BTCUSDT".to_string(),
+                side: OrderSide::Buy,
+                order_type: OrderType::Limit,
+                time_in_force: Some(TimeInForce::GTC),
+                quantity: Some("1".to_string()),
+                quote_order_qty: None,
+                price: Some("0.1".to_string()),
+                new_client_order_id: None,
+                strategy_id: None,
+                strategy_type: None,
+                stop_price: None,
+                trailing_delta: None,
+                iceberg_qty: None,
+                new_order_resp_type: None,
+                self_trade_prevention_mode: None,
+                recv_window: None,
+            },
+            compute_commission_rates: Some(true),
+        };
+        let response = client.post_order_test(request).await.unwrap();
+        assert_eq!(
+            response.data.standard_commission_for_order.unwrap().taker,
+            "0.00000114"
+        );
+        assert_eq!(response.data.discount.unwrap().discount_asset, "BNB");
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(sent.method, Method::POST);
+        let params = signed_params(sent.body.as_deref().unwrap()).await;
+        let params: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        assert_eq!(
+            params.get(..7),
+            Some(
+                [
+                    ("symbol", "BTCUSDT"),
+                    ("side", "BUY"),
+                    ("type", "LIMIT"),
+                    ("timeInForce", "GTC"),
+                    ("quantity", "1"),
+                    ("price", "0.1"),
+                    ("computeCommissionRates", "true"),
+                ]
+                .as_slice()
+            )
+        );
+    }
+
+    #[test]
+    fn test_order_test_weight_follows_commission_rates() {
+        assert_eq!(test_order_weight(None), 1);
+        assert_eq!(test_order_weight(Some(true)), 20);
+    }
+}
@@ -0,0 +1,117 @@
+// New Order List - OTO (TRADE) endpoint implementation for POST /api/v3/orderList/oto
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-list---oto-trade>
+
+use serde::Serialize;
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order_list::OrderListResponse;
+use crate::binance::spot::{OrderResponseType, OrderSide, OrderType, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for placing a one-triggers-the-other order list (POST /api/v3/orderList/oto).
+///
+/// The pending order is only placed once the working order fills.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOtoOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Unique client ID of the order list. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_client_order_id: Option<String>,
+
+    /// New order response type (ACK, RESULT, FULL).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// LIMIT or LIMIT_MAKER.
+    pub working_type: OrderType,
+
+    /// Side of the working order.
+    pub working_side: OrderSide,
+
+    /// Client order ID of the working order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_client_order_id: Option<String>,
+
+    /// Price of the working order.
+    pub working_price: String,
+
+    /// Quantity of the working order.
+    pub working_quantity: String,
+
+    /// Visible quantity, if the working order is an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_iceberg_qty: Option<String>,
+
+    /// Time in force of a LIMIT working order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_time_in_force: Option<TimeInForce>,
+
+    /// Type of the pending order. Any order type but MARKET orders using `quote_order_qty`.
+    pub pending_type: OrderType,
+
+    /// Side of the pending order.
+    pub pending_side: OrderSide,
+
+    /// Client order ID of the pending order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_client_order_id: Option<String>,
+
+    /// Price of a limit pending order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_price: Option<String>,
+
+    /// Stop price of a stop pending order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_stop_price: Option<String>,
+
+    /// Trailing delta in basis points of a stop pending order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_trailing_delta: Option<u32>,
+
+    /// Quantity of the pending order.
+    pub pending_quantity: String,
+
+    /// Visible quantity, if the pending order is an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_iceberg_qty: Option<String>,
+
+    /// Time in force of a limit pending order other than LIMIT_MAKER.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_time_in_force: Option<TimeInForce>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Places a one-triggers-the-other order list: a working order and a pending order placed
+    /// once the working order fills.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-list---oto-trade>
+    /// POST /api/v3/orderList/oto
+    /// Weight: 1 (order rate limit, where the list counts as 2 orders)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewOtoOrderRequest`])
+    ///
+    /// # Returns
+    /// The placed [`OrderListResponse`].
+    pub async fn post_oto_order(&self, params: NewOtoOrderRequest) -> RestResult<OrderListResponse> {
+        self.send_signed_request(
+            "/api/v3/orderList/oto",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
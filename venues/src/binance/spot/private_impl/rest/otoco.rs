@@ -0,0 +1,147 @@
+// New Order List - OTOCO (TRADE) endpoint implementation for POST /api/v3/orderList/otoco
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-list---otoco-trade>
+
+use serde::Serialize;
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::query_order_list::OrderListResponse;
+use crate::binance::spot::{OrderResponseType, OrderSide, OrderType, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for placing a one-triggers-a-one-cancels-the-other order list
+/// (POST /api/v3/orderList/otoco).
+///
+/// The pending above and below orders form an OCO pair that is only placed once the working
+/// order fills.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOtocoOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Unique client ID of the order list. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_client_order_id: Option<String>,
+
+    /// New order response type (ACK, RESULT, FULL).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// LIMIT or LIMIT_MAKER.
+    pub working_type: OrderType,
+
+    /// Side of the working order.
+    pub working_side: OrderSide,
+
+    /// Client order ID of the working order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_client_order_id: Option<String>,
+
+    /// Price of the working order.
+    pub working_price: String,
+
+    /// Quantity of the working order.
+    pub working_quantity: String,
+
+    /// Visible quantity, if the working order is an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_iceberg_qty: Option<String>,
+
+    /// Time in force of a LIMIT working order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_time_in_force: Option<TimeInForce>,
+
+    /// Side of both pending orders.
+    pub pending_side: OrderSide,
+
+    /// Quantity of both pending orders.
+    pub pending_quantity: String,
+
+    /// STOP_LOSS_LIMIT, STOP_LOSS, LIMIT_MAKER, TAKE_PROFIT or TAKE_PROFIT_LIMIT.
+    pub pending_above_type: OrderType,
+
+    /// Client order ID of the pending above order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_above_client_order_id: Option<String>,
+
+    /// Price of a limit pending above order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_above_price: Option<String>,
+
+    /// Stop price of a stop pending above order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_above_stop_price: Option<String>,
+
+    /// Trailing delta in basis points of a stop pending above order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_above_trailing_delta: Option<u32>,
+
+    /// Visible quantity, if the pending above order is an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_above_iceberg_qty: Option<String>,
+
+    /// Time in force of a limit pending above order other than LIMIT_MAKER.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_above_time_in_force: Option<TimeInForce>,
+
+    /// STOP_LOSS_LIMIT, STOP_LOSS, LIMIT_MAKER, TAKE_PROFIT or TAKE_PROFIT_LIMIT.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_below_type: Option<OrderType>,
+
+    /// Client order ID of the pending below order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_below_client_order_id: Option<String>,
+
+    /// Price of a limit pending below order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_below_price: Option<String>,
+
+    /// Stop price of a stop pending below order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_below_stop_price: Option<String>,
+
+    /// Trailing delta in basis points of a stop pending below order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_below_trailing_delta: Option<u32>,
+
+    /// Visible quantity, if the pending below order is an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_below_iceberg_qty: Option<String>,
+
+    /// Time in force of a limit pending below order other than LIMIT_MAKER.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_below_time_in_force: Option<TimeInForce>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Places a one-triggers-a-one-cancels-the-other order list: a working order and an OCO pair
+    /// placed once the working order fills.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-list---otoco-trade>
+    /// POST /api/v3/orderList/otoco
+    /// Weight: 1 (order rate limit, where the list counts as 3 orders)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewOtocoOrderRequest`])
+    ///
+    /// # Returns
+    /// The placed [`OrderListResponse`].
+    pub async fn post_otoco_order(&self, params: NewOtocoOrderRequest) -> RestResult<OrderListResponse> {
+        self.send_signed_request(
+            "/api/v3/orderList/otoco",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
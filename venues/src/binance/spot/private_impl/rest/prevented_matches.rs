@@ -0,0 +1,104 @@
+// Query Prevented Matches (USER_DATA) endpoint implementation for GET /api/v3/myPreventedMatches
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-prevented-matches-user_data>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::{RestResult, SelfTradePreventionMode};
+
+/// Request parameters for the orders that expired due to self-trade prevention
+/// (GET /api/v3/myPreventedMatches).
+///
+/// Either `prevented_match_id` or `order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatchesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Prevented match ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevented_match_id: Option<u64>,
+
+    /// Order ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Return prevented matches from this ID on, with `order_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_prevented_match_id: Option<u64>,
+
+    /// Number of prevented matches (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// A match prevented by self-trade prevention.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatch {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Prevented match ID.
+    pub prevented_match_id: u64,
+
+    /// ID of the taker order that expired.
+    pub taker_order_id: u64,
+
+    /// Trading symbol of the maker order.
+    pub maker_symbol: String,
+
+    /// ID of the maker order.
+    pub maker_order_id: u64,
+
+    /// Trade group ID the orders share.
+    pub trade_group_id: u64,
+
+    /// Self-trade prevention mode that expired the order.
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+
+    /// Price the orders would have matched at.
+    pub price: String,
+
+    /// Quantity of the maker order that was prevented from matching.
+    pub maker_prevented_quantity: String,
+
+    /// Time of the prevented match (ms since epoch).
+    pub transact_time: u64,
+}
+
+/// Request weight of the prevented matches: 1 by prevented match ID, 10 by order ID
+pub(crate) fn prevented_matches_weight(prevented_match_id: Option<u64>) -> u32 {
+    if prevented_match_id.is_some() { 1 } else { 10 }
+}
+
+impl RestClient {
+    /// Fetches the orders that expired due to self-trade prevention.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-prevented-matches-user_data>
+    /// GET /api/v3/myPreventedMatches
+    /// Weight: 1 by prevented match ID, 10 by order ID
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`PreventedMatchesRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`PreventedMatch`] objects.
+    pub async fn get_prevented_matches(&self, params: PreventedMatchesRequest) -> RestResult<Vec<PreventedMatch>> {
+        let weight = prevented_matches_weight(params.prevented_match_id);
+        self.send_signed_request(
+            "/api/v3/myPreventedMatches",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,114 @@
+// Query Order (USER_DATA) endpoint implementation for GET /api/v3/order
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-order-user_data>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::{OrderSide, OrderStatus, OrderType, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for querying an order (GET /api/v3/order).
+///
+/// Either `order_id` or `orig_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Client order ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// An order as returned by the query, open orders and all orders endpoints.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// ID of the order list the order belongs to, -1 otherwise.
+    pub order_list_id: i64,
+
+    /// Client order ID.
+    pub client_order_id: String,
+
+    /// Order price, 0 for market orders.
+    pub price: String,
+
+    /// Order quantity in the base asset.
+    pub orig_qty: String,
+
+    /// Quantity filled so far.
+    pub executed_qty: String,
+
+    /// Quote asset spent or received by the fills so far.
+    pub cummulative_quote_qty: String,
+
+    /// Order status.
+    pub status: OrderStatus,
+
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Order side.
+    pub side: OrderSide,
+
+    /// Stop price, 0 unless a stop order.
+    pub stop_price: String,
+
+    /// Visible quantity, 0 unless an iceberg order.
+    pub iceberg_qty: String,
+
+    /// Time the order was placed (ms since epoch).
+    pub time: u64,
+
+    /// Last update of the order (ms since epoch).
+    pub update_time: u64,
+
+    /// Whether the order is on the book.
+    pub is_working: bool,
+
+    /// Time the order was put on the book, -1 if it isn't yet.
+    pub working_time: i64,
+
+    /// Order quantity in the quote asset, 0 unless sized with `quoteOrderQty`.
+    pub orig_quote_order_qty: String,
+
+    /// Self-trade prevention mode.
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+impl RestClient {
+    /// Checks an order's status.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-order-user_data>
+    /// GET /api/v3/order
+    /// Weight: 2
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`QueryOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`Order`].
+    pub async fn get_order(&self, params: QueryOrderRequest) -> RestResult<Order> {
+        self.send_signed_request("/api/v3/order", reqwest::Method::GET, params, 2, false)
+            .await
+    }
+}
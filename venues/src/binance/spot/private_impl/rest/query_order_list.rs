@@ -0,0 +1,93 @@
+// Query Order List (USER_DATA) endpoint implementation for GET /api/v3/orderList
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-order-list-user_data>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::order::NewOrderResponse;
+use crate::binance::spot::{ContingencyType, ListOrderStatus, ListStatusType, RestResult};
+
+/// An order of an order list.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Order ID.
+    pub order_id: u64,
+
+    /// Client order ID.
+    pub client_order_id: String,
+}
+
+/// An order list, as returned when placing, cancelling or querying it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListResponse {
+    /// Order list ID.
+    pub order_list_id: i64,
+
+    /// Kind of the order list.
+    pub contingency_type: ContingencyType,
+
+    /// Status of the order list.
+    pub list_status_type: ListStatusType,
+
+    /// Status of the orders of the list.
+    pub list_order_status: ListOrderStatus,
+
+    /// Client ID of the order list.
+    pub list_client_order_id: String,
+
+    /// Time of the last update of the list (ms since epoch).
+    pub transaction_time: u64,
+
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// IDs of the orders of the list.
+    pub orders: Vec<OrderListOrder>,
+
+    /// Details of the orders, only returned when placing or cancelling the list.
+    #[serde(default)]
+    pub order_reports: Vec<NewOrderResponse>,
+}
+
+/// Request parameters for querying an order list (GET /api/v3/orderList).
+///
+/// Either `order_list_id` or `orig_client_order_id` must be sent.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderListRequest {
+    /// Order list ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_list_id: Option<i64>,
+
+    /// Client ID of the order list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches an order list.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-order-list-user_data>
+    /// GET /api/v3/orderList
+    /// Weight: 2
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`QueryOrderListRequest`])
+    ///
+    /// # Returns
+    /// The [`OrderListResponse`].
+    pub async fn get_order_list(&self, params: QueryOrderListRequest) -> RestResult<OrderListResponse> {
+        self.send_signed_request("/api/v3/orderList", reqwest::Method::GET, params, 2, false)
+            .await
+    }
+}
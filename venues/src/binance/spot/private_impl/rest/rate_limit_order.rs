@@ -0,0 +1,61 @@
+// Query Unfilled Order Count (USER_DATA) endpoint implementation for GET /api/v3/rateLimit/order
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-unfilled-order-count-user_data>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::{RateLimitInterval, RateLimitType, RestResult};
+
+/// Request parameters for the unfilled order count (GET /api/v3/rateLimit/order).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfilledOrderCountRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Usage of an order rate limit.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRateLimitUsage {
+    /// Type of the limit, always orders.
+    pub rate_limit_type: RateLimitType,
+
+    /// Unit of the interval.
+    pub interval: RateLimitInterval,
+
+    /// Length of the interval in `interval` units.
+    pub interval_num: u32,
+
+    /// Most unfilled orders allowed in the interval.
+    pub limit: u32,
+
+    /// Unfilled orders placed in the current interval.
+    pub count: u32,
+}
+
+impl RestClient {
+    /// Fetches the account's unfilled order count for each order rate limit.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/account-endpoints#query-unfilled-order-count-user_data>
+    /// GET /api/v3/rateLimit/order
+    /// Weight: 20
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`UnfilledOrderCountRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`OrderRateLimitUsage`] objects.
+    pub async fn get_rate_limit_order(&self, params: UnfilledOrderCountRequest) -> RestResult<Vec<OrderRateLimitUsage>> {
+        self.send_signed_request(
+            "/api/v3/rateLimit/order",
+            reqwest::Method::GET,
+            params,
+            20,
+            false,
+        )
+        .await
+    }
+}
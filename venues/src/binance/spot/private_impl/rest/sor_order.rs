@@ -0,0 +1,90 @@
+// New Order Using SOR (TRADE) endpoint implementation for POST /api/v3/sor/order
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-using-sor-trade>
+
+use serde::Serialize;
+
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::order::NewOrderResponse;
+use crate::binance::spot::{OrderResponseType, OrderSide, OrderType, RestResult, SelfTradePreventionMode, TimeInForce};
+
+/// Request parameters for placing an order using smart order routing (POST /api/v3/sor/order).
+///
+/// Only LIMIT and MARKET orders are supported, and MARKET orders can't use `quoteOrderQty`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSorOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// LIMIT or MARKET.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Time in force. Required for LIMIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order quantity in the base asset.
+    pub quantity: String,
+
+    /// Order price. Required for LIMIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Unique client order ID. Generated by Binance if not sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// Arbitrary numeric value identifying the order within an order strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+
+    /// Arbitrary numeric value identifying the order strategy, at least 1000000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<u32>,
+
+    /// Visible quantity of an iceberg order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iceberg_qty: Option<String>,
+
+    /// New order response type (ACK, RESULT, FULL).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Self-trade prevention mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Places an order using smart order routing, which may fill it on the order books of
+    /// interchangeable symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-using-sor-trade>
+    /// POST /api/v3/sor/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewSorOrderRequest`])
+    ///
+    /// # Returns
+    /// A [`NewOrderResponse`] whose fills carry their allocation IDs.
+    pub async fn post_sor_order(&self, params: NewSorOrderRequest) -> RestResult<NewOrderResponse> {
+        self.send_signed_request(
+            "/api/v3/sor/order",
+            reqwest::Method::POST,
+            params,
+            1,
+            true, // is_order
+        )
+        .await
+    }
+}
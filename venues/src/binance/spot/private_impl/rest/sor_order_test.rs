@@ -0,0 +1,47 @@
+// Test New Order Using SOR (TRADE) endpoint implementation for POST /api/v3/sor/order/test
+// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#test-new-order-using-sor-trade>
+
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::private_impl::rest::client::RestClient;
+use crate::binance::spot::private_impl::rest::order_test::{TestOrderResponse, test_order_weight};
+use crate::binance::spot::private_impl::rest::sor_order::NewSorOrderRequest;
+
+/// Request parameters for testing an order using smart order routing (POST /api/v3/sor/order/test).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestSorOrderRequest {
+    /// The order to test.
+    #[serde(flatten)]
+    pub order: NewSorOrderRequest,
+
+    /// Whether to return the commission rates the order would pay (default false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_commission_rates: Option<bool>,
+}
+
+impl RestClient {
+    /// Validates an order using smart order routing without sending it to the matching engine.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#test-new-order-using-sor-trade>
+    /// POST /api/v3/sor/order/test
+    /// Weight: 1, or 20 when computing the commission rates
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`TestSorOrderRequest`])
+    ///
+    /// # Returns
+    /// A [`TestOrderResponse`], empty unless commission rates were requested.
+    pub async fn post_sor_order_test(&self, params: TestSorOrderRequest) -> RestResult<TestOrderResponse> {
+        let weight = test_order_weight(params.compute_commission_rates);
+        self.send_signed_request(
+            "/api/v3/sor/order/test",
+            reqwest::Method::POST,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Request parameters for the average price.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct AvgPriceRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Average price of a symbol over a window of minutes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvgPrice {
+    /// Length of the averaging window in minutes.
+    pub mins: u32,
+
+    /// Average price over the window.
+    pub price: String,
+
+    /// Time of the last trade in the window.
+    pub close_time: u64,
+}
+
+impl RestClient {
+    /// Fetches the current average price of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#current-average-price>
+    /// Corresponds to endpoint GET /api/v3/avgPrice.
+    /// Weight: 1
+    pub async fn get_avg_price(&self, params: AvgPriceRequest) -> RestResult<AvgPrice> {
+        self.send_get_request("/api/v3/avgPrice", params, 1).await
+    }
+}
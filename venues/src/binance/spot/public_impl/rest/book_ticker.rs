@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Best bid and ask of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Best bid price.
+    pub bid_price: String,
+
+    /// Quantity at the best bid.
+    pub bid_qty: String,
+
+    /// Best ask price.
+    pub ask_price: String,
+
+    /// Quantity at the best ask.
+    pub ask_qty: String,
+}
+
+/// Request parameters for the best bid and ask of a single symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct BookTickerRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+impl RestClient {
+    /// Fetches the best bid and ask of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#symbol-order-book-ticker>
+    /// Corresponds to endpoint GET /api/v3/ticker/bookTicker.
+    /// Weight: 1
+    pub async fn get_book_ticker(&self, params: BookTickerRequest) -> RestResult<BookTicker> {
+        self.send_get_request("/api/v3/ticker/bookTicker", params, 1)
+            .await
+    }
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::public_impl::rest::book_ticker::BookTicker;
+use crate::binance::spot::public_impl::rest::client::as_json_string;
+
+/// Request parameters for the best bid and ask of several symbols.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct BookTickersRequest {
+    /// Trading symbols. All symbols are returned if not sent.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "as_json_string"
+    )]
+    pub symbols: Option<Vec<String>>,
+}
+
+impl RestClient {
+    /// Fetches the best bid and ask of several symbols, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#symbol-order-book-ticker>
+    /// Corresponds to endpoint GET /api/v3/ticker/bookTicker.
+    /// Weight: 2
+    pub async fn get_book_tickers(&self, params: BookTickersRequest) -> RestResult<Vec<BookTicker>> {
+        self.send_get_request("/api/v3/ticker/bookTicker", params, 2)
+            .await
+    }
+}
@@ -0,0 +1,141 @@
+// REST client for Binance Spot public endpoints.
+//
+// Provides access to the public market data endpoints of the Binance Spot API.
+// All requests are unauthenticated and do not require API credentials.
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::transport::HttpTransport;
+use serde::{Serialize, Serializer};
+
+use crate::binance::spot::errors::VENUE;
+use crate::binance::spot::{Errors, RateLimiter, RequestWeight, RestResult};
+
+/// Serializes a list parameter such as `symbols` as the JSON array Binance expects
+pub(super) fn as_json_string<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(&json)
+}
+
+/// A client for the Binance Spot public REST API
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RestClient {
+    /// The base URL for the Binance Spot public REST API (e.g., "<https://api.binance.com>").
+    ///
+    /// This is used as the prefix for all endpoint requests.
+    pub base_url: Cow<'static, str>,
+
+    /// The underlying HTTP client used for making requests.
+    ///
+    /// This is reused for connection pooling and performance.
+    pub client: Arc<dyn HttpTransport>,
+
+    /// The rate limiter used to manage request rates and prevent hitting API limits.
+    ///
+    /// This is used to ensure compliance with Binance's rate limits for public endpoints.
+    pub rate_limiter: RateLimiter,
+}
+
+impl RestClient {
+    /// Creates a new Binance Spot public REST client.
+    ///
+    /// # Arguments
+    /// * `base_url` - The base URL for the Binance Spot public REST API (e.g., "<https://api.binance.com>").
+    pub fn new(base_url: impl Into<Cow<'static, str>>, client: impl HttpTransport + 'static, rate_limiter: RateLimiter) -> Self {
+        Self {
+            base_url: base_url.into(),
+            client: Arc::new(client),
+            rate_limiter,
+        }
+    }
+
+    /// Sends an unauthenticated request with an optional query string
+    pub async fn send_request<T>(&self, endpoint: &str, method: reqwest::Method, query_string: Option<&str>, weight: u32) -> RestResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = crate::binance::spot::rest::common::build_url(&self.base_url, endpoint, query_string)?;
+        let rest_response = crate::binance::spot::rest::common::send_rest_request(
+            self.client.as_ref(),
+            &url,
+            method,
+            vec![],
+            None,
+            &self.rate_limiter,
+            weight,
+            false,
+        )
+        .await?;
+        Ok(crate::binance::spot::RestResponse {
+            data: rest_response.data,
+            request_duration: rest_response.request_duration,
+            headers: rest_response.headers,
+        })
+    }
+
+    /// Sends a GET request with `params` encoded in the query string
+    pub(super) async fn send_get_request<T, R>(&self, endpoint: &str, params: R, weight: u32) -> RestResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+        R: serde::Serialize,
+    {
+        let query_string = serde_urlencoded::to_string(&params).map_err(|e| Errors::Error(format!("Failed to encode params: {}", e)))?;
+        let query_string = Some(query_string.as_str()).filter(|query| !query.is_empty());
+        self.send_request(endpoint, reqwest::Method::GET, query_string, weight)
+            .await
+    }
+}
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<RequestWeight>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+
+        // Public endpoints take their parameters in the query string
+        let query_string = request
+            .params
+            .as_ref()
+            .map(serde_urlencoded::to_string)
+            .transpose()
+            .map_err(|e| {
+                VenueError::new(
+                    VENUE,
+                    ErrorKind::InvalidRequest,
+                    format!("Failed to encode query parameters: {}", e),
+                )
+            })?;
+        let response = self
+            .send_request::<serde_json::Value>(
+                &request.endpoint,
+                request.method,
+                query_string.as_deref(),
+                request.rate_limit_key.weight,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
+}
@@ -0,0 +1,407 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::public_impl::rest::client::as_json_string;
+use crate::binance::spot::{OrderType, RateLimitInterval, RateLimitType, RestResult, SelfTradePreventionMode, SymbolStatus};
+
+/// Request parameters for the exchange information. Without parameters, all symbols are returned.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoRequest {
+    /// Trading symbol (e.g., "BTCUSDT"). Cannot be sent with `symbols` or `permissions`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Trading symbols. Cannot be sent with `symbol` or `permissions`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "as_json_string"
+    )]
+    pub symbols: Option<Vec<String>>,
+
+    /// Only return symbols with any of these permissions (e.g., "SPOT", "MARGIN").
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "as_json_string"
+    )]
+    pub permissions: Option<Vec<String>>,
+
+    /// Whether to return the permission sets of each symbol (default true).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_permission_sets: Option<bool>,
+
+    /// Only return symbols with this trading status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_status: Option<SymbolStatus>,
+}
+
+/// Trading rules and permissions of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Trading status.
+    pub status: SymbolStatus,
+
+    /// Base asset.
+    pub base_asset: String,
+
+    /// Decimal places of the base asset.
+    pub base_asset_precision: u32,
+
+    /// Quote asset.
+    pub quote_asset: String,
+
+    /// Decimal places of the quote asset, superseded by `quote_asset_precision`.
+    pub quote_precision: u32,
+
+    /// Decimal places of the quote asset.
+    pub quote_asset_precision: u32,
+
+    /// Decimal places of commissions paid in the base asset.
+    pub base_commission_precision: u32,
+
+    /// Decimal places of commissions paid in the quote asset.
+    pub quote_commission_precision: u32,
+
+    /// Order types the symbol accepts.
+    pub order_types: Vec<OrderType>,
+
+    /// Whether iceberg orders are allowed.
+    pub iceberg_allowed: bool,
+
+    /// Whether OCO order lists are allowed.
+    pub oco_allowed: bool,
+
+    /// Whether OTO and OTOCO order lists are allowed.
+    #[serde(default)]
+    pub oto_allowed: bool,
+
+    /// Whether market orders may be sized with `quoteOrderQty`.
+    pub quote_order_qty_market_allowed: bool,
+
+    /// Whether trailing stops are allowed.
+    pub allow_trailing_stop: bool,
+
+    /// Whether cancel-replace is allowed.
+    pub cancel_replace_allowed: bool,
+
+    /// Whether spot trading is allowed.
+    pub is_spot_trading_allowed: bool,
+
+    /// Whether margin trading is allowed.
+    pub is_margin_trading_allowed: bool,
+
+    /// Trading rules orders must pass.
+    pub filters: Vec<Filter>,
+
+    /// Empty since permission sets were introduced, see `permission_sets`.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+
+    /// The symbol can be traded by accounts holding all permissions of any of these sets.
+    #[serde(default)]
+    pub permission_sets: Vec<Vec<String>>,
+
+    /// Self-trade prevention mode of orders that do not set one.
+    pub default_self_trade_prevention_mode: SelfTradePreventionMode,
+
+    /// Self-trade prevention modes orders may set.
+    pub allowed_self_trade_prevention_modes: Vec<SelfTradePreventionMode>,
+}
+
+/// Limits on the price of orders.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceFilter {
+    /// Lowest price, 0 when disabled.
+    pub min_price: String,
+    /// Highest price, 0 when disabled.
+    pub max_price: String,
+    /// Price increment, 0 when disabled.
+    pub tick_size: String,
+}
+
+/// Limits on the price of orders relative to the average price.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PercentPriceFilter {
+    /// Highest price as a multiple of the average price.
+    pub multiplier_up: String,
+    /// Lowest price as a multiple of the average price.
+    pub multiplier_down: String,
+    /// Minutes the average price is taken over, 0 for the last price.
+    pub avg_price_mins: u32,
+}
+
+/// Limits on the price of orders relative to the average price, per side.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PercentPriceBySideFilter {
+    /// Highest buy price as a multiple of the average price.
+    pub bid_multiplier_up: String,
+    /// Lowest buy price as a multiple of the average price.
+    pub bid_multiplier_down: String,
+    /// Highest sell price as a multiple of the average price.
+    pub ask_multiplier_up: String,
+    /// Lowest sell price as a multiple of the average price.
+    pub ask_multiplier_down: String,
+    /// Minutes the average price is taken over, 0 for the last price.
+    pub avg_price_mins: u32,
+}
+
+/// Limits on the quantity of orders.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LotSizeFilter {
+    /// Lowest quantity.
+    pub min_qty: String,
+    /// Highest quantity.
+    pub max_qty: String,
+    /// Quantity increment.
+    pub step_size: String,
+}
+
+/// Limits on the notional value (price times quantity) of orders.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotionalFilter {
+    /// Lowest notional value.
+    pub min_notional: String,
+    /// Whether `min_notional` applies to market orders.
+    pub apply_min_to_market: bool,
+    /// Highest notional value.
+    pub max_notional: String,
+    /// Whether `max_notional` applies to market orders.
+    pub apply_max_to_market: bool,
+    /// Minutes the average price used for market orders is taken over, 0 for the last price.
+    pub avg_price_mins: u32,
+}
+
+/// Lower limit on the notional value (price times quantity) of orders.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MinNotionalFilter {
+    /// Lowest notional value.
+    pub min_notional: String,
+    /// Whether the limit applies to market orders.
+    pub apply_to_market: bool,
+    /// Minutes the average price used for market orders is taken over, 0 for the last price.
+    pub avg_price_mins: u32,
+}
+
+/// Limit on the number of parts of an iceberg order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitFilter {
+    /// Most parts an iceberg order may have.
+    pub limit: u32,
+}
+
+/// Limit on the open orders of an account on a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxNumOrdersFilter {
+    /// Most open orders, including those in order lists.
+    pub max_num_orders: u32,
+}
+
+/// Limit on the open stop and take profit orders of an account on a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxNumAlgoOrdersFilter {
+    /// Most open stop and take profit orders.
+    pub max_num_algo_orders: u32,
+}
+
+/// Limit on the position of an account in the base asset.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxPositionFilter {
+    /// Largest balance of the base asset, including open buy orders.
+    pub max_position: String,
+}
+
+/// Limits on the trailing delta of trailing stop orders, in basis points.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailingDeltaFilter {
+    /// Lowest delta of orders triggering above the market price.
+    pub min_trailing_above_delta: u32,
+    /// Highest delta of orders triggering above the market price.
+    pub max_trailing_above_delta: u32,
+    /// Lowest delta of orders triggering below the market price.
+    pub min_trailing_below_delta: u32,
+    /// Highest delta of orders triggering below the market price.
+    pub max_trailing_below_delta: u32,
+}
+
+/// A trading rule of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "filterType")]
+pub enum Filter {
+    /// Price range and tick size
+    #[serde(rename = "PRICE_FILTER")]
+    PriceFilter(PriceFilter),
+
+    /// Price range relative to the average price
+    #[serde(rename = "PERCENT_PRICE")]
+    PercentPrice(PercentPriceFilter),
+
+    /// Price range relative to the average price, per side
+    #[serde(rename = "PERCENT_PRICE_BY_SIDE")]
+    PercentPriceBySide(PercentPriceBySideFilter),
+
+    /// Quantity range and step size
+    #[serde(rename = "LOT_SIZE")]
+    LotSize(LotSizeFilter),
+
+    /// Quantity range and step size of market orders
+    #[serde(rename = "MARKET_LOT_SIZE")]
+    MarketLotSize(LotSizeFilter),
+
+    /// Lowest notional value
+    #[serde(rename = "MIN_NOTIONAL")]
+    MinNotional(MinNotionalFilter),
+
+    /// Notional value range
+    #[serde(rename = "NOTIONAL")]
+    Notional(NotionalFilter),
+
+    /// Most parts of an iceberg order
+    #[serde(rename = "ICEBERG_PARTS")]
+    IcebergParts(LimitFilter),
+
+    /// Most open orders
+    #[serde(rename = "MAX_NUM_ORDERS")]
+    MaxNumOrders(MaxNumOrdersFilter),
+
+    /// Most open stop and take profit orders
+    #[serde(rename = "MAX_NUM_ALGO_ORDERS")]
+    MaxNumAlgoOrders(MaxNumAlgoOrdersFilter),
+
+    /// Largest position in the base asset
+    #[serde(rename = "MAX_POSITION")]
+    MaxPosition(MaxPositionFilter),
+
+    /// Trailing delta range
+    #[serde(rename = "TRAILING_DELTA")]
+    TrailingDelta(TrailingDeltaFilter),
+
+    /// A filter this client does not know
+    #[serde(other)]
+    Unknown,
+}
+
+/// Represents a rate limit object in the exchange info response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    /// The type of rate limit (e.g., "REQUEST_WEIGHT", "ORDERS").
+    pub rate_limit_type: RateLimitType,
+
+    /// The interval for the rate limit (e.g., "MINUTE").
+    pub interval: RateLimitInterval,
+
+    /// The number of intervals.
+    pub interval_num: u32,
+
+    /// The maximum number of requests or orders allowed in the interval.
+    pub limit: u32,
+}
+
+/// Represents the response from the Binance Spot Exchange Information endpoint.
+///
+/// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/general-endpoints#exchange-information>
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoResponse {
+    /// The timezone of the exchange (e.g., "UTC").
+    pub timezone: String,
+
+    /// Server time (ms since epoch).
+    pub server_time: u64,
+
+    /// The list of rate limits applied to the account or exchange.
+    pub rate_limits: Vec<RateLimit>,
+
+    /// The list of exchange-wide filters.
+    pub exchange_filters: Vec<serde_json::Value>,
+
+    /// Trading rules of the requested symbols.
+    pub symbols: Vec<Symbol>,
+}
+
+impl RestClient {
+    /// Fetches current exchange trading rules and symbol information.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/general-endpoints#exchange-information>
+    /// Corresponds to endpoint GET /api/v3/exchangeInfo.
+    /// Weight: 10
+    pub async fn get_exchange_info(&self, params: ExchangeInfoRequest) -> RestResult<ExchangeInfoResponse> {
+        self.send_get_request("/api/v3/exchangeInfo", params, 10)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::spot::RateLimiter;
+
+    #[tokio::test]
+    async fn test_symbols_are_sent_as_json_array() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({
+                "timezone": "UTC",
+                "serverTime": 1565246363776_u64,
+                "rateLimits": [
+                    {"rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 1200}
+                ],
+                "exchangeFilters": [],
+                "symbols": []
+            }),
+        );
+        let client = RestClient::new(
+            "https://api.binance.com",
+            transport.clone(),
+            RateLimiter::new(),
+        );
+
+        let request = ExchangeInfoRequest {
+            symbols: Some(vec!["BTCUSDT".to_string(), "BNBBTC".to_string()]),
+            ..Default::default()
+        };
+        let response = client.get_exchange_info(request).await.unwrap();
+        assert_eq!(
+            response.data.rate_limits.first().unwrap().rate_limit_type,
+            RateLimitType::RequestWeight
+        );
+
+        let sent = transport.last_request().unwrap();
+        let query: Vec<(String, String)> = serde_urlencoded::from_str(sent.query_string().unwrap()).unwrap();
+        assert_eq!(
+            query,
+            vec![("symbols".to_string(), r#"["BTCUSDT","BNBBTC"]"#.to_string())]
+        );
+    }
+
+    #[test]
+    fn test_unknown_filters_are_tolerated() {
+        let filters: Vec<Filter> = serde_json::from_value(json!([
+            {"filterType": "LOT_SIZE", "minQty": "0.00100000", "maxQty": "100000.00000000", "stepSize": "0.00100000"},
+            {"filterType": "MAX_NUM_ICEBERG_ORDERS", "maxNumIcebergOrders": 5}
+        ]))
+        .unwrap();
+        assert!(matches!(filters.first(), Some(Filter::LotSize(_))));
+        assert!(matches!(filters.get(1), Some(Filter::Unknown)));
+    }
+}
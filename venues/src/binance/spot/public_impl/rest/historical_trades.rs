@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::public_impl::rest::trades::Trade;
+
+/// Request parameters for older trades.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Trade ID to fetch from. Default returns the most recent trades.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches older trades of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#old-trade-lookup>
+    /// Corresponds to endpoint GET /api/v3/historicalTrades.
+    /// Weight: 5
+    pub async fn get_historical_trades(&self, params: HistoricalTradesRequest) -> RestResult<Vec<Trade>> {
+        self.send_get_request("/api/v3/historicalTrades", params, 5)
+            .await
+    }
+}
@@ -0,0 +1,83 @@
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::{KlineInterval, RestResult};
+
+/// Request parameters for klines.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Time zone the daily and longer intervals are aligned to, from "-12:00" to "+14:00" (default "0", UTC).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    /// Number of klines (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A kline, sent by Binance as an array.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Kline {
+    /// Open time (ms since epoch).
+    pub open_time: u64,
+
+    /// Open price.
+    pub open: String,
+
+    /// High price.
+    pub high: String,
+
+    /// Low price.
+    pub low: String,
+
+    /// Close price, or the latest price while the kline is open.
+    pub close: String,
+
+    /// Volume in the base asset.
+    pub volume: String,
+
+    /// Close time (ms since epoch).
+    pub close_time: u64,
+
+    /// Volume in the quote asset.
+    pub quote_volume: String,
+
+    /// Number of trades.
+    pub number_of_trades: u64,
+
+    /// Taker buy volume in the base asset.
+    pub taker_buy_base_volume: String,
+
+    /// Taker buy volume in the quote asset.
+    pub taker_buy_quote_volume: String,
+
+    /// Unused trailing field
+    _ignore: IgnoredAny,
+}
+
+impl RestClient {
+    /// Fetches klines of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#klinecandlestick-data>
+    /// Corresponds to endpoint GET /api/v3/klines.
+    /// Weight: 1
+    pub async fn get_klines(&self, params: KlinesRequest) -> RestResult<Vec<Kline>> {
+        self.send_get_request("/api/v3/klines", params, 1).await
+    }
+}
@@ -0,0 +1,23 @@
+// Public REST endpoints module for Binance Spot
+
+pub mod agg_trades;
+pub mod avg_price;
+pub mod book_ticker;
+pub mod book_tickers;
+pub mod client;
+pub mod exchange_info;
+pub mod historical_trades;
+pub mod klines;
+pub mod order_book;
+pub mod ping;
+pub mod price_ticker;
+pub mod price_tickers;
+pub mod rolling_window_ticker;
+pub mod rolling_window_tickers;
+pub mod server_time;
+pub mod ticker_24hr;
+pub mod tickers_24hr;
+pub mod trades;
+pub mod ui_klines;
+
+pub use client::RestClient;
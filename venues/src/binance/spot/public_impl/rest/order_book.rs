@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Request parameters for the order book.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct OrderBookRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Number of levels per side (default 100, max 5000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A price level of the order book.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderBookLevel {
+    /// Price of the level.
+    pub price: String,
+
+    /// Quantity at the level.
+    pub quantity: String,
+}
+
+/// Snapshot of the order book.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookResponse {
+    /// Last update ID included in the snapshot, to continue from with the depth stream.
+    pub last_update_id: u64,
+
+    /// Bids, best price first.
+    pub bids: Vec<OrderBookLevel>,
+
+    /// Asks, best price first.
+    pub asks: Vec<OrderBookLevel>,
+}
+
+/// Request weight of the order book for `limit` levels per side
+pub(crate) fn order_book_weight(limit: Option<u32>) -> u32 {
+    match limit.unwrap_or(100) {
+        0..=100 => 1,
+        101..=500 => 5,
+        501..=1000 => 10,
+        _ => 50,
+    }
+}
+
+impl RestClient {
+    /// Fetches the order book of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#order-book>
+    /// Corresponds to endpoint GET /api/v3/depth.
+    /// Weight: 1 for up to 100 levels, 5 up to 500, 10 up to 1000 and 50 above
+    pub async fn get_order_book(&self, params: OrderBookRequest) -> RestResult<OrderBookResponse> {
+        let weight = order_book_weight(params.limit);
+        self.send_get_request("/api/v3/depth", params, weight).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_book_weight_follows_limit() {
+        assert_eq!(order_book_weight(None), 1);
+        assert_eq!(order_book_weight(Some(500)), 5);
+        assert_eq!(order_book_weight(Some(1000)), 10);
+        assert_eq!(order_book_weight(Some(5000)), 50);
+    }
+}
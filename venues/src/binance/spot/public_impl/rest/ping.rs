@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Empty response of the connectivity test.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PingResponse {}
+
+impl RestClient {
+    /// Tests connectivity to the REST API.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/general-endpoints#test-connectivity>
+    /// Corresponds to endpoint GET /api/v3/ping.
+    /// Weight: 1
+    pub async fn ping(&self) -> RestResult<PingResponse> {
+        self.send_request("/api/v3/ping", reqwest::Method::GET, None, 1)
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Latest price of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceTicker {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Latest price.
+    pub price: String,
+}
+
+/// Request parameters for the latest price of a single symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct PriceTickerRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+impl RestClient {
+    /// Fetches the latest price of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#symbol-price-ticker>
+    /// Corresponds to endpoint GET /api/v3/ticker/price.
+    /// Weight: 1
+    pub async fn get_price_ticker(&self, params: PriceTickerRequest) -> RestResult<PriceTicker> {
+        self.send_get_request("/api/v3/ticker/price", params, 1)
+            .await
+    }
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::public_impl::rest::client::as_json_string;
+use crate::binance::spot::public_impl::rest::price_ticker::PriceTicker;
+
+/// Request parameters for the latest price of several symbols.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct PriceTickersRequest {
+    /// Trading symbols. All symbols are returned if not sent.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "as_json_string"
+    )]
+    pub symbols: Option<Vec<String>>,
+}
+
+impl RestClient {
+    /// Fetches the latest price of several symbols, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#symbol-price-ticker>
+    /// Corresponds to endpoint GET /api/v3/ticker/price.
+    /// Weight: 2
+    pub async fn get_price_tickers(&self, params: PriceTickersRequest) -> RestResult<Vec<PriceTicker>> {
+        self.send_get_request("/api/v3/ticker/price", params, 2)
+            .await
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Request parameters for the rolling window ticker of a single symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowTickerRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Window size: "1m" to "59m", "1h" to "23h" or "1d" to "7d" (default "1d").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<String>,
+}
+
+/// Price change statistics over a rolling window.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowTicker {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Last price minus the open price.
+    pub price_change: String,
+
+    /// Price change in percent of the open price.
+    pub price_change_percent: String,
+
+    /// Volume weighted average price.
+    pub weighted_avg_price: String,
+
+    /// Price at the start of the window.
+    pub open_price: String,
+
+    /// High price.
+    pub high_price: String,
+
+    /// Low price.
+    pub low_price: String,
+
+    /// Price of the last trade.
+    pub last_price: String,
+
+    /// Volume in the base asset.
+    pub volume: String,
+
+    /// Volume in the quote asset.
+    pub quote_volume: String,
+
+    /// Start of the window (ms since epoch).
+    pub open_time: u64,
+
+    /// End of the window (ms since epoch).
+    pub close_time: u64,
+
+    /// First trade ID, -1 without trades.
+    pub first_id: i64,
+
+    /// Last trade ID, -1 without trades.
+    pub last_id: i64,
+
+    /// Number of trades in the window.
+    pub count: u64,
+}
+
+impl RestClient {
+    /// Fetches the price change statistics of a symbol over a rolling window.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#rolling-window-price-change-statistics>
+    /// Corresponds to endpoint GET /api/v3/ticker.
+    /// Weight: 2
+    pub async fn get_rolling_window_ticker(&self, params: RollingWindowTickerRequest) -> RestResult<RollingWindowTicker> {
+        self.send_get_request("/api/v3/ticker", params, 2).await
+    }
+}
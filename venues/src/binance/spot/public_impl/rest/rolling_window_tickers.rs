@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::public_impl::rest::client::as_json_string;
+use crate::binance::spot::public_impl::rest::rolling_window_ticker::RollingWindowTicker;
+
+/// Request parameters for the rolling window tickers of several symbols.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowTickersRequest {
+    /// Trading symbols, at most 100.
+    #[serde(serialize_with = "as_json_string")]
+    pub symbols: Vec<String>,
+
+    /// Window size: "1m" to "59m", "1h" to "23h" or "1d" to "7d" (default "1d").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<String>,
+}
+
+/// Request weight of the rolling window tickers: 2 per symbol, capped at 100
+pub(crate) fn rolling_window_tickers_weight(symbols: &[String]) -> u32 {
+    u32::try_from(symbols.len())
+        .unwrap_or(u32::MAX)
+        .saturating_mul(2)
+        .min(100)
+}
+
+impl RestClient {
+    /// Fetches the price change statistics of several symbols over a rolling window.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#rolling-window-price-change-statistics>
+    /// Corresponds to endpoint GET /api/v3/ticker.
+    /// Weight: 2 per symbol, at most 100
+    pub async fn get_rolling_window_tickers(&self, params: RollingWindowTickersRequest) -> RestResult<Vec<RollingWindowTicker>> {
+        let weight = rolling_window_tickers_weight(&params.symbols);
+        self.send_get_request("/api/v3/ticker", params, weight)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("SYM{i}")).collect()
+    }
+
+    #[test]
+    fn test_rolling_window_tickers_weight_is_capped() {
+        assert_eq!(rolling_window_tickers_weight(&symbols(3)), 6);
+        assert_eq!(rolling_window_tickers_weight(&symbols(80)), 100);
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Request parameters for the 24 hour ticker of a single symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct Ticker24hrRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Price change statistics over the last 24 hours.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hr {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Last price minus the open price.
+    pub price_change: String,
+
+    /// Price change in percent of the open price.
+    pub price_change_percent: String,
+
+    /// Volume weighted average price.
+    pub weighted_avg_price: String,
+
+    /// Close price of the previous 24 hours.
+    pub prev_close_price: String,
+
+    /// Price of the last trade.
+    pub last_price: String,
+
+    /// Quantity of the last trade.
+    pub last_qty: String,
+
+    /// Best bid price.
+    pub bid_price: String,
+
+    /// Quantity at the best bid.
+    pub bid_qty: String,
+
+    /// Best ask price.
+    pub ask_price: String,
+
+    /// Quantity at the best ask.
+    pub ask_qty: String,
+
+    /// Price at the start of the window.
+    pub open_price: String,
+
+    /// High price.
+    pub high_price: String,
+
+    /// Low price.
+    pub low_price: String,
+
+    /// Volume in the base asset.
+    pub volume: String,
+
+    /// Volume in the quote asset.
+    pub quote_volume: String,
+
+    /// Start of the window (ms since epoch).
+    pub open_time: u64,
+
+    /// End of the window (ms since epoch).
+    pub close_time: u64,
+
+    /// First trade ID, -1 without trades.
+    pub first_id: i64,
+
+    /// Last trade ID, -1 without trades.
+    pub last_id: i64,
+
+    /// Number of trades in the window.
+    pub count: u64,
+}
+
+impl RestClient {
+    /// Fetches the 24 hour price change statistics of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#24hr-ticker-price-change-statistics>
+    /// Corresponds to endpoint GET /api/v3/ticker/24hr.
+    /// Weight: 1
+    pub async fn get_ticker_24hr(&self, params: Ticker24hrRequest) -> RestResult<Ticker24hr> {
+        self.send_get_request("/api/v3/ticker/24hr", params, 1)
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+use serde::Serialize;
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::public_impl::rest::client::as_json_string;
+use crate::binance::spot::public_impl::rest::ticker_24hr::Ticker24hr;
+
+/// Request parameters for the 24 hour tickers of several symbols.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct Tickers24hrRequest {
+    /// Trading symbols. All symbols are returned if not sent.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "as_json_string"
+    )]
+    pub symbols: Option<Vec<String>>,
+}
+
+/// Request weight of the 24 hour tickers for the requested symbols
+pub(crate) fn tickers_24hr_weight(symbols: Option<&[String]>) -> u32 {
+    match symbols.map(<[String]>::len) {
+        Some(0..=20) => 1,
+        Some(21..=100) => 20,
+        _ => 40,
+    }
+}
+
+impl RestClient {
+    /// Fetches the 24 hour price change statistics of several symbols, or of all symbols.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#24hr-ticker-price-change-statistics>
+    /// Corresponds to endpoint GET /api/v3/ticker/24hr.
+    /// Weight: 1 for up to 20 symbols, 20 for up to 100 and 40 for more or all symbols
+    pub async fn get_tickers_24hr(&self, params: Tickers24hrRequest) -> RestResult<Vec<Ticker24hr>> {
+        let weight = tickers_24hr_weight(params.symbols.as_deref());
+        self.send_get_request("/api/v3/ticker/24hr", params, weight)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("SYM{i}")).collect()
+    }
+
+    #[test]
+    fn test_tickers_24hr_weight_follows_symbol_count() {
+        assert_eq!(tickers_24hr_weight(Some(&symbols(20))), 1);
+        assert_eq!(tickers_24hr_weight(Some(&symbols(21))), 20);
+        assert_eq!(tickers_24hr_weight(Some(&symbols(101))), 40);
+        assert_eq!(tickers_24hr_weight(None), 40);
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::spot::RestResult;
+use crate::binance::spot::public_impl::rest::RestClient;
+
+/// Request parameters for recent trades.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct RecentTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A trade filled in the order book.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// Trade ID.
+    pub id: u64,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity in the base asset.
+    pub qty: String,
+
+    /// Quantity in the quote asset.
+    pub quote_qty: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+
+    /// Whether the buyer was the maker.
+    pub is_buyer_maker: bool,
+
+    /// Whether the trade was the best price match.
+    pub is_best_match: bool,
+}
+
+impl RestClient {
+    /// Fetches recent trades of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#recent-trades-list>
+    /// Corresponds to endpoint GET /api/v3/trades.
+    /// Weight: 1
+    pub async fn get_recent_trades(&self, params: RecentTradesRequest) -> RestResult<Vec<Trade>> {
+        self.send_get_request("/api/v3/trades", params, 1).await
+    }
+}
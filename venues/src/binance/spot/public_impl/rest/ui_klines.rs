@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+use crate::binance::spot::public_impl::rest::RestClient;
+use crate::binance::spot::public_impl::rest::klines::Kline;
+use crate::binance::spot::{KlineInterval, RestResult};
+
+/// Request parameters for UI klines.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiKlinesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Time zone the daily and longer intervals are aligned to, from "-12:00" to "+14:00" (default "0", UTC).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    /// Number of klines (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl RestClient {
+    /// Fetches klines of a symbol, modified for the presentation of candlestick charts.
+    ///
+    /// See: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#uiklines>
+    /// Corresponds to endpoint GET /api/v3/uiKlines.
+    /// Weight: 1
+    pub async fn get_ui_klines(&self, params: UiKlinesRequest) -> RestResult<Vec<Kline>> {
+        self.send_get_request("/api/v3/uiKlines", params, 1).await
+    }
+}
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use reqwest::StatusCode;
+use rest::transport::{HttpRequest, HttpTransport};
 use tracing::debug;
 
 use crate::binance::spot::errors::ErrorResponse;
@@ -28,7 +29,7 @@ pub(crate) struct ParsedResponse<T> {
 /// Internal helper to execute HTTP requests and parse responses for both public and private clients.
 /// This function encapsulates the shared HTTP logic (building, sending, and parsing the response to idiomatic Rust types).
 pub(crate) async fn execute_request<T>(
-    client: &reqwest::Client,
+    client: &dyn HttpTransport,
     url: &str,
     method: reqwest::Method,
     headers: Option<Vec<(&str, String)>>,
@@ -41,35 +42,28 @@ where
 
     let start = Instant::now();
 
-    let mut request_builder = client.request(method.clone(), url);
+    let mut request = HttpRequest::new(method.clone(), url);
 
     // Add headers
     if let Some(headers) = headers {
         for (key, value) in headers {
-            request_builder = request_builder.header(key, value);
+            request = request.header(key, value);
         }
     }
 
     // Add body if provided
     if let Some(body) = body {
-        request_builder = request_builder.body(body.to_string());
+        request = request.body(body);
     }
 
     debug!("Sending {} request to {}", method, url);
 
-    let response = request_builder
-        .send()
-        .await
-        .map_err(|e| Errors::Error(format!("Failed to send HTTP request: {}", e)))?;
+    let response = client.execute(request).await.map_err(Errors::HttpError)?;
 
     let duration = start.elapsed();
-    let status = response.status();
-    let response_headers = ResponseHeaders::from_reqwest_headers(response.headers());
-
-    let response_text = response
-        .text()
-        .await
-        .map_err(|e| Errors::Error(format!("Failed to read response body: {}", e)))?;
+    let status = response.status;
+    let response_headers = ResponseHeaders::from_reqwest_headers(&response.headers);
+    let response_text = response.body;
 
     debug!("Response status: {}, body: {}", status, response_text);
 
@@ -73,6 +73,8 @@ pub enum TimeInForce {
     FOK,
     /// Good Till Crossing (Post Only)
     GTX,
+    /// Good Till Date, canceled at `goodTillDate`
+    GTD,
 }
 
 impl fmt::Display for TimeInForce {
@@ -82,6 +84,7 @@ impl fmt::Display for TimeInForce {
             TimeInForce::IOC => write!(f, "IOC"),
             TimeInForce::FOK => write!(f, "FOK"),
             TimeInForce::GTX => write!(f, "GTX"),
+            TimeInForce::GTD => write!(f, "GTD"),
         }
     }
 }
@@ -115,6 +118,8 @@ pub enum OrderStatus {
     Canceled,
     /// The order has expired.
     Expired,
+    /// The order was expired by self-trade prevention.
+    ExpiredInMatch,
 }
 
 impl fmt::Display for OrderStatus {
@@ -125,6 +130,7 @@ impl fmt::Display for OrderStatus {
             OrderStatus::Filled => write!(f, "FILLED"),
             OrderStatus::Canceled => write!(f, "CANCELED"),
             OrderStatus::Expired => write!(f, "EXPIRED"),
+            OrderStatus::ExpiredInMatch => write!(f, "EXPIRED_IN_MATCH"),
         }
     }
 }
@@ -191,6 +197,15 @@ pub enum IncomeType {
     TokenReward,
     TransferIn,
     TransferOut,
+    InternalTransfer,
+    AutoExchange,
+    DeliveredSettelment,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    StrategyUmfuturesTransfer,
+    FeeReturn,
+    BfuturesReward,
 }
 
 impl fmt::Display for IncomeType {
@@ -211,6 +226,15 @@ impl fmt::Display for IncomeType {
             IncomeType::TokenReward => write!(f, "TOKEN_REWARD"),
             IncomeType::TransferIn => write!(f, "TRANSFER_IN"),
             IncomeType::TransferOut => write!(f, "TRANSFER_OUT"),
+            IncomeType::InternalTransfer => write!(f, "INTERNAL_TRANSFER"),
+            IncomeType::AutoExchange => write!(f, "AUTO_EXCHANGE"),
+            IncomeType::DeliveredSettelment => write!(f, "DELIVERED_SETTELMENT"),
+            IncomeType::CoinSwapDeposit => write!(f, "COIN_SWAP_DEPOSIT"),
+            IncomeType::CoinSwapWithdraw => write!(f, "COIN_SWAP_WITHDRAW"),
+            IncomeType::PositionLimitIncreaseFee => write!(f, "POSITION_LIMIT_INCREASE_FEE"),
+            IncomeType::StrategyUmfuturesTransfer => write!(f, "STRATEGY_UMFUTURES_TRANSFER"),
+            IncomeType::FeeReturn => write!(f, "FEE_RETURN"),
+            IncomeType::BfuturesReward => write!(f, "BFUTURES_REWARD"),
         }
     }
 }
@@ -345,12 +369,18 @@ pub enum SymbolFilterType {
 pub enum PriceMatch {
     None,
     Opponent,
+    #[serde(rename = "OPPONENT_5")]
     Opponent5,
+    #[serde(rename = "OPPONENT_10")]
     Opponent10,
+    #[serde(rename = "OPPONENT_20")]
     Opponent20,
     Queue,
+    #[serde(rename = "QUEUE_5")]
     Queue5,
+    #[serde(rename = "QUEUE_10")]
     Queue10,
+    #[serde(rename = "QUEUE_20")]
     Queue20,
 }
 
@@ -407,3 +437,36 @@ pub enum KlineInterval {
     #[serde(rename = "1M")]
     I1M,
 }
+
+/// Represents the period of the futures statistics under `/futures/data`
+/// (open interest history, long/short ratios, taker volume and basis).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Period {
+    /// 5 minutes
+    #[serde(rename = "5m")]
+    I5m,
+    /// 15 minutes
+    #[serde(rename = "15m")]
+    I15m,
+    /// 30 minutes
+    #[serde(rename = "30m")]
+    I30m,
+    /// 1 hour
+    #[serde(rename = "1h")]
+    I1h,
+    /// 2 hours
+    #[serde(rename = "2h")]
+    I2h,
+    /// 4 hours
+    #[serde(rename = "4h")]
+    I4h,
+    /// 6 hours
+    #[serde(rename = "6h")]
+    I6h,
+    /// 12 hours
+    #[serde(rename = "12h")]
+    I12h,
+    /// 1 day
+    #[serde(rename = "1d")]
+    I1d,
+}
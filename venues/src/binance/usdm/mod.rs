@@ -3,8 +3,8 @@
 //! This module provides access to the Binance USD-M Futures API, including:
 //!
 //! - **Rate Limiting**: Automatic rate limiting for RAW_REQUEST, REQUEST_WEIGHT, and ORDER limits
-//! - **Public Endpoints**: Market data, exchange info, etc. via `/fapi/v1/` endpoints  
-//! - **Private Endpoints**: Account data, trading, etc. (basic structure provided)
+//! - **Public Endpoints**: Market data, exchange info, etc. via `/fapi/` and `/futures/data/` endpoints
+//! - **Private Endpoints**: Trading, account, position and income data via signed `/fapi/` endpoints
 //! - **Error Handling**: Comprehensive error types for API responses
 //!
 //! # Rate Limiting
@@ -49,13 +49,58 @@ mod request;
 mod public {
     mod rest;
     pub use self::rest::RestClient as PublicRestClient;
+    pub use self::rest::agg_trades::*;
+    pub use self::rest::basis::*;
+    pub use self::rest::book_ticker::*;
+    pub use self::rest::continuous_klines::*;
     pub use self::rest::exchange_info::*;
+    pub use self::rest::funding_info::*;
+    pub use self::rest::funding_rate_history::*;
+    pub use self::rest::historical_trades::*;
+    pub use self::rest::index_price_klines::*;
+    pub use self::rest::klines::*;
+    pub use self::rest::mark_price_klines::*;
+    pub use self::rest::open_interest::*;
+    pub use self::rest::open_interest_hist::*;
+    pub use self::rest::order_book::*;
+    pub use self::rest::ping::*;
+    pub use self::rest::premium_index::*;
+    pub use self::rest::premium_index_klines::*;
+    pub use self::rest::price_ticker::*;
+    pub use self::rest::server_time::*;
+    pub use self::rest::taker_buy_sell_volume::*;
+    pub use self::rest::ticker_24hr::*;
+    pub use self::rest::top_long_short_account_ratio::*;
+    pub use self::rest::top_long_short_position_ratio::*;
+    pub use self::rest::trades::*;
 }
 
 mod private {
     mod rest;
     // Re-export RestClient so it can be re-exported by the parent
     pub use self::rest::RestClient as PrivateRestClient;
+    pub use self::rest::account::*;
+    pub use self::rest::account_trades::*;
+    pub use self::rest::account_v2::*;
+    pub use self::rest::adl_quantile::*;
+    pub use self::rest::all_orders::*;
+    pub use self::rest::balance::*;
+    pub use self::rest::batch_order::*;
+    pub use self::rest::cancel_all_orders::*;
+    pub use self::rest::cancel_order::*;
+    pub use self::rest::change_multi_assets_mode::*;
+    pub use self::rest::change_position_mode::*;
+    pub use self::rest::countdown_cancel_all::*;
+    pub use self::rest::income::*;
+    pub use self::rest::leverage::*;
+    pub use self::rest::margin_type::*;
+    pub use self::rest::modify_order::*;
+    pub use self::rest::multi_assets_mode::*;
+    pub use self::rest::open_orders::*;
+    pub use self::rest::order::*;
+    pub use self::rest::position_mode::*;
+    pub use self::rest::position_risk::*;
+    pub use self::rest::query_order::*;
 }
 
 // Only expose RestClient at the usdm level, not via private::rest
@@ -63,7 +108,7 @@ pub use enums::*;
 pub use errors::{ApiError, Errors};
 pub use private::*;
 pub use public::*;
-pub use rate_limit::{RateLimitHeader, RateLimiter};
+pub use rate_limit::{RateLimitHeader, RateLimiter, RequestWeight};
 
 pub use crate::binance::usdm::errors::ErrorResponse;
 pub(crate) use crate::binance::usdm::request::execute_request;
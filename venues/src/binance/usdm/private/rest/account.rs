@@ -0,0 +1,172 @@
+// Account Information V3 endpoint implementation for GET /fapi/v3/account
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Account-Information-V3>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::PositionSide;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for fetching account information.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Margin totals shared by both account versions. All amounts are in USD; in single-asset mode
+/// only USDT assets are counted.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTotals {
+    /// Initial margin of positions and open orders.
+    pub total_initial_margin: String,
+
+    /// Maintenance margin of positions.
+    pub total_maint_margin: String,
+
+    /// Wallet balance.
+    pub total_wallet_balance: String,
+
+    /// Unrealized profit of all positions.
+    pub total_unrealized_profit: String,
+
+    /// Wallet balance plus unrealized profit.
+    pub total_margin_balance: String,
+
+    /// Initial margin of positions at the current mark price.
+    pub total_position_initial_margin: String,
+
+    /// Initial margin of open orders at the current mark price.
+    pub total_open_order_initial_margin: String,
+
+    /// Wallet balance available to crossed positions.
+    pub total_cross_wallet_balance: String,
+
+    /// Unrealized profit of crossed positions.
+    pub total_cross_un_pnl: String,
+
+    /// Balance available for new orders.
+    pub available_balance: String,
+
+    /// Maximum amount that can be transferred out of the futures account.
+    pub max_withdraw_amount: String,
+}
+
+/// Account information from GET /fapi/v3/account, listing only assets and positions in use.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountResponse {
+    /// Margin totals of the account.
+    #[serde(flatten)]
+    pub totals: AccountTotals,
+
+    /// Assets with a balance or margin in use.
+    pub assets: Vec<AccountAsset>,
+
+    /// Open positions.
+    pub positions: Vec<AccountPosition>,
+}
+
+/// Balance and margin of one asset.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    /// Asset name, e.g. "USDT".
+    pub asset: String,
+
+    /// Wallet balance.
+    pub wallet_balance: String,
+
+    /// Unrealized profit of positions margined in the asset.
+    pub unrealized_profit: String,
+
+    /// Wallet balance plus unrealized profit.
+    pub margin_balance: String,
+
+    /// Maintenance margin required.
+    pub maint_margin: String,
+
+    /// Initial margin of positions and open orders.
+    pub initial_margin: String,
+
+    /// Initial margin of positions at the current mark price.
+    pub position_initial_margin: String,
+
+    /// Initial margin of open orders at the current mark price.
+    pub open_order_initial_margin: String,
+
+    /// Wallet balance available to crossed positions.
+    pub cross_wallet_balance: String,
+
+    /// Unrealized profit of crossed positions.
+    pub cross_un_pnl: String,
+
+    /// Balance available for new orders.
+    pub available_balance: String,
+
+    /// Maximum amount that can be transferred out.
+    pub max_withdraw_amount: String,
+
+    /// Whether the asset can be used as margin in multi-assets mode. Only returned by v2.
+    pub margin_available: Option<bool>,
+
+    /// Last update time (ms since epoch).
+    pub update_time: u64,
+}
+
+/// An open position, as returned by GET /fapi/v3/account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    /// Trading symbol, e.g. "BTCUSDT".
+    pub symbol: String,
+
+    /// Side of the position; BOTH in One-way Mode.
+    pub position_side: PositionSide,
+
+    /// Position size, negative for short positions in One-way Mode.
+    pub position_amt: String,
+
+    /// Unrealized profit at the current mark price.
+    pub unrealized_profit: String,
+
+    /// Margin of an isolated position, "0" for crossed ones.
+    pub isolated_margin: String,
+
+    /// Notional value at the current mark price.
+    pub notional: String,
+
+    /// Wallet balance of an isolated position.
+    pub isolated_wallet: String,
+
+    /// Initial margin of the position and its open orders.
+    pub initial_margin: String,
+
+    /// Maintenance margin required.
+    pub maint_margin: String,
+
+    /// Last update time (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Fetches current account information, listing only assets and positions in use.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Account-Information-V3>
+    /// GET /fapi/v3/account
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `request` - The request parameters (see [`AccountRequest`])
+    ///
+    /// # Returns
+    /// An [`AccountResponse`] with balances and open positions.
+    pub async fn get_account(&self, request: AccountRequest) -> RestResult<AccountResponse> {
+        self.send_signed_request("/fapi/v3/account", reqwest::Method::GET, request, 5, false)
+            .await
+    }
+}
@@ -0,0 +1,115 @@
+// Account Trade List endpoint implementation for GET /fapi/v1/userTrades
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Account-Trade-List>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::{OrderSide, PositionSide};
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for the Account Trade List endpoint (GET /fapi/v1/userTrades).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTradeListRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Only return trades of this order. Can only be sent together with `symbol`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch). At most 7 days after `start_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Trade ID to fetch from. Cannot be sent with `start_time` or `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Limit (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Represents a single trade returned by the Account Trade List endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTrade {
+    /// Trading symbol, e.g. "BTCUSDT".
+    pub symbol: String,
+
+    /// Trade ID.
+    pub id: u64,
+
+    /// Order ID associated with this trade.
+    pub order_id: u64,
+
+    /// Side of the order that traded.
+    pub side: OrderSide,
+
+    /// Fill price.
+    pub price: String,
+
+    /// Filled quantity.
+    #[serde(rename = "qty")]
+    pub quantity: String,
+
+    /// Profit realized by the trade.
+    pub realized_pnl: String,
+
+    /// Quote asset quantity of the trade.
+    pub quote_qty: String,
+
+    /// Fee paid, negative for rebates.
+    pub commission: String,
+
+    /// Asset the fee was paid in.
+    pub commission_asset: String,
+
+    /// Trade time in milliseconds since epoch.
+    pub time: u64,
+
+    /// Position the trade belongs to; BOTH in One-way Mode.
+    pub position_side: PositionSide,
+
+    /// Whether the buyer is the taker.
+    pub buyer: bool,
+
+    /// Whether the trade was a maker trade.
+    pub maker: bool,
+}
+
+impl RestClient {
+    /// Fetches the account's trades of a symbol.
+    ///
+    /// Only trades from the last 6 months can be queried.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Account-Trade-List>
+    /// GET /fapi/v1/userTrades
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AccountTradeListRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`AccountTrade`] objects.
+    pub async fn get_account_trades(&self, params: AccountTradeListRequest) -> RestResult<Vec<AccountTrade>> {
+        self.send_signed_request(
+            "/fapi/v1/userTrades",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,130 @@
+// Account Information V2 endpoint implementation for GET /fapi/v2/account
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Account-Information-V2>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::PositionSide;
+use crate::binance::usdm::private::rest::account::{AccountAsset, AccountTotals};
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for fetching account information with the V2 endpoint.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountV2Request {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Account information from GET /fapi/v2/account, including account permissions and
+/// per-position leverage.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountV2Response {
+    /// Fee tier, 0 to 9.
+    pub fee_tier: i32,
+
+    /// Whether BNB is used to pay fees.
+    pub fee_burn: Option<bool>,
+
+    /// Whether the account can trade.
+    pub can_trade: bool,
+
+    /// Whether the account can transfer in.
+    pub can_deposit: bool,
+
+    /// Whether the account can transfer out.
+    pub can_withdraw: bool,
+
+    /// Reserved by Binance, always 0.
+    pub update_time: u64,
+
+    /// Whether multi-assets mode is enabled.
+    pub multi_assets_margin: Option<bool>,
+
+    /// Trade group of the account, -1 if it is in none.
+    pub trade_group_id: Option<i64>,
+
+    /// Margin totals of the account.
+    #[serde(flatten)]
+    pub totals: AccountTotals,
+
+    /// Balances of every asset.
+    pub assets: Vec<AccountAsset>,
+
+    /// Positions of every symbol, including empty ones.
+    pub positions: Vec<AccountPositionV2>,
+}
+
+/// A position, as returned by GET /fapi/v2/account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPositionV2 {
+    /// Trading symbol, e.g. "BTCUSDT".
+    pub symbol: String,
+
+    /// Initial margin of the position and its open orders.
+    pub initial_margin: String,
+
+    /// Maintenance margin required.
+    pub maint_margin: String,
+
+    /// Unrealized profit at the current mark price.
+    pub unrealized_profit: String,
+
+    /// Initial margin of the position at the current mark price.
+    pub position_initial_margin: String,
+
+    /// Initial margin of open orders at the current mark price.
+    pub open_order_initial_margin: String,
+
+    /// Current initial leverage.
+    pub leverage: String,
+
+    /// Whether the position uses isolated margin.
+    pub isolated: bool,
+
+    /// Average entry price.
+    pub entry_price: String,
+
+    /// Price at which the position breaks even after fees.
+    pub break_even_price: Option<String>,
+
+    /// Largest notional value allowed at the current leverage.
+    pub max_notional: String,
+
+    /// Notional value of open buy orders.
+    pub bid_notional: Option<String>,
+
+    /// Notional value of open sell orders.
+    pub ask_notional: Option<String>,
+
+    /// Side of the position; BOTH in One-way Mode.
+    pub position_side: PositionSide,
+
+    /// Position size, negative for short positions in One-way Mode.
+    pub position_amt: String,
+
+    /// Last update time (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Fetches current account information including permissions and all symbols' positions.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Account-Information-V2>
+    /// GET /fapi/v2/account
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `request` - The request parameters (see [`AccountV2Request`])
+    ///
+    /// # Returns
+    /// An [`AccountV2Response`] with permissions, balances and positions.
+    pub async fn get_account_v2(&self, request: AccountV2Request) -> RestResult<AccountV2Response> {
+        self.send_signed_request("/fapi/v2/account", reqwest::Method::GET, request, 5, false)
+            .await
+    }
+}
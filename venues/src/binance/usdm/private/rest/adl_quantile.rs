@@ -0,0 +1,78 @@
+// Position ADL Quantile Estimation endpoint implementation for GET /fapi/v1/adlQuantile
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Position-ADL-Quantile-Estimation>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for the ADL quantile estimation.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantileRequest {
+    /// Trading symbol (e.g., "BTCUSDT"). If omitted, all symbols with positions are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// ADL quantiles of a symbol's positions, 0 (lowest priority) to 4 (highest).
+///
+/// In One-way Mode only `both` is set. In Hedge Mode `long`, `short` and `hedge` are set,
+/// where `hedge` only matters when both sides hold a position.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct AdlQuantiles {
+    /// Quantile of the long position in Hedge Mode.
+    pub long: Option<u8>,
+
+    /// Quantile of the short position in Hedge Mode.
+    pub short: Option<u8>,
+
+    /// Quantile of the position in One-way Mode.
+    pub both: Option<u8>,
+
+    /// Quantile used when both sides of a Hedge Mode position are open.
+    pub hedge: Option<u8>,
+}
+
+/// ADL quantile estimation of one symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdlQuantile {
+    /// Trading symbol, e.g. "BTCUSDT".
+    pub symbol: String,
+
+    /// Quantiles of the symbol's positions.
+    #[serde(rename = "adlQuantile")]
+    pub adl_quantile: AdlQuantiles,
+}
+
+impl RestClient {
+    /// Fetches the auto-deleveraging quantile estimation of open positions.
+    ///
+    /// Values are updated every 30 seconds.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Position-ADL-Quantile-Estimation>
+    /// GET /fapi/v1/adlQuantile
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AdlQuantileRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`AdlQuantile`] objects.
+    pub async fn get_adl_quantile(&self, params: AdlQuantileRequest) -> RestResult<Vec<AdlQuantile>> {
+        self.send_signed_request(
+            "/fapi/v1/adlQuantile",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
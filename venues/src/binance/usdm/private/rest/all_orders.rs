@@ -0,0 +1,64 @@
+// All Orders endpoint implementation for GET /fapi/v1/allOrders
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/All-Orders>
+
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::private::rest::order::OrderResponse;
+
+/// Request parameters for all orders (GET /fapi/v1/allOrders).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Return orders with an ID of at least this one, otherwise the most recent orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch). At most 7 days after `start_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Limit (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches all account orders of a symbol: active, canceled, or filled.
+    ///
+    /// Only orders from the last 90 days can be queried.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/All-Orders>
+    /// GET /fapi/v1/allOrders
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`AllOrdersRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`OrderResponse`] objects.
+    pub async fn get_all_orders(&self, params: AllOrdersRequest) -> RestResult<Vec<OrderResponse>> {
+        let weight = 5;
+        self.send_signed_request(
+            "/fapi/v1/allOrders",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
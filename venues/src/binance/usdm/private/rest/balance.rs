@@ -0,0 +1,67 @@
+// Futures Account Balance endpoint implementation for GET /fapi/v3/balance
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Futures-Account-Balance-V3>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for fetching the futures account balance.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Balance of one asset in the futures account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    /// Unique account code.
+    pub account_alias: String,
+
+    /// Asset name, e.g. "USDT".
+    pub asset: String,
+
+    /// Wallet balance of the asset.
+    pub balance: String,
+
+    /// Wallet balance available to crossed positions.
+    pub cross_wallet_balance: String,
+
+    /// Unrealized profit of crossed positions.
+    pub cross_un_pnl: String,
+
+    /// Balance available for new orders and transfers.
+    pub available_balance: String,
+
+    /// Maximum amount that can be transferred out of the futures account.
+    pub max_withdraw_amount: String,
+
+    /// Whether the asset can be used as margin in multi-assets mode.
+    pub margin_available: bool,
+
+    /// Last update time (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Fetches the balance of every asset in the futures account.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Futures-Account-Balance-V3>
+    /// GET /fapi/v3/balance
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `request` - The request parameters (see [`BalanceRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`Balance`] objects.
+    pub async fn get_balance(&self, request: BalanceRequest) -> RestResult<Vec<Balance>> {
+        self.send_signed_request("/fapi/v3/balance", reqwest::Method::GET, request, 5, false)
+            .await
+    }
+}
@@ -0,0 +1,160 @@
+// Place Multiple Orders (TRADE) endpoint implementation for POST /fapi/v1/batchOrders
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Place-Multiple-Orders>
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::private::rest::order::{NewOrderRequest, OrderResponse};
+use crate::binance::usdm::{ErrorResponse, RestResult};
+
+/// Serializes a value as a JSON string for use in URL-encoded form bodies (Binance batch orders)
+fn as_json_string<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(&json)
+}
+
+/// Request type for placing multiple orders (batch).
+///
+/// Each order takes the parameters of a single new order; the `recv_window` of the individual
+/// orders is ignored in favour of the batch's own.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceBatchOrdersRequest {
+    /// List of orders to place (max 5).
+    #[serde(serialize_with = "as_json_string")]
+    pub batch_orders: Vec<NewOrderRequest>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Represents a single response entry for a batch order (either success or error).
+///
+/// Entries are in the same order as the orders in the request.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BatchOrderResult {
+    /// Successful order response.
+    Ok(Box<OrderResponse>),
+    /// Error response for a failed order.
+    Err(ErrorResponse),
+}
+
+impl RestClient {
+    /// Places multiple orders in a single batch.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Place-Multiple-Orders>
+    ///
+    /// POST /fapi/v1/batchOrders
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `request` - PlaceBatchOrdersRequest containing the orders and required parameters
+    ///
+    /// # Returns
+    /// A vector of BatchOrderResult, each representing either a successful order or an error for that order.
+    pub async fn place_batch_orders(&self, request: PlaceBatchOrdersRequest) -> RestResult<Vec<BatchOrderResult>> {
+        self.send_signed_request(
+            "/fapi/v1/batchOrders",
+            reqwest::Method::POST,
+            request,
+            5,    // weight
+            true, // is_order
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
+    use super::*;
+    use crate::binance::shared::test_support::signed_params;
+    use crate::binance::usdm::private::rest::client::tests::client;
+    use crate::binance::usdm::{OrderSide, OrderType, PriceMatch};
+
+    fn limit_order(side: OrderSide) -> NewOrderRequest {
+        NewOrderRequest {
+            symbol: "BTCUSDT".to_string(),
+            side,
+            position_side: None,
+            order_type: OrderType::Limit,
+            time_in_force: None,
+            quantity: Some("0.010".to_string()),
+            reduce_only: None,
+            price: None,
+            new_client_order_id: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            new_order_resp_type: None,
+            price_match: Some(PriceMatch::Opponent5),
+            self_trade_prevention_mode: None,
+            good_till_date: None,
+            recv_window: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_orders_are_sent_as_json_and_results_split() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!([
+                {
+                    "clientOrderId": "a1", "cumQty": "0", "cumQuote": "0", "executedQty": "0",
+                    "orderId": 22542179, "avgPrice": "0.00000", "origQty": "0.010", "price": "0",
+                    "reduceOnly": false, "side": "BUY", "positionSide": "BOTH", "status": "NEW",
+                    "stopPrice": "0", "closePosition": false, "symbol": "BTCUSDT", "timeInForce": "GTC",
+                    "type": "LIMIT", "origType": "LIMIT", "updateTime": 1566818724722_u64,
+                    "workingType": "CONTRACT_PRICE", "priceProtect": false, "priceMatch": "OPPONENT_5",
+                    "selfTradePreventionMode": "NONE", "goodTillDate": 0
+                },
+                { "code": -2022, "msg": "ReduceOnly Order is rejected." }
+            ]),
+        );
+        let client = client(&transport);
+
+        let request = PlaceBatchOrdersRequest {
+            batch_orders: vec![
+                limit_order(OrderSide::Buy),
+                NewOrderRequest {
+                    reduce_only: Some(true),
+                    ..limit_order(OrderSide::Sell)
+                },
+            ],
+            recv_window: None,
+        };
+        let response = client.place_batch_orders(request).await.unwrap();
+        assert!(matches!(response.data.first(), Some(BatchOrderResult::Ok(order)) if order.order_id == 22542179));
+        assert!(matches!(response.data.get(1), Some(BatchOrderResult::Err(err)) if err.code == -2022));
+
+        let sent = transport.last_request().unwrap();
+        let params = signed_params(sent.body.as_deref().unwrap()).await;
+        let (_, batch) = params
+            .iter()
+            .find(|(name, _)| name == "batchOrders")
+            .unwrap();
+        let batch: serde_json::Value = serde_json::from_str(batch).unwrap();
+        assert_eq!(
+            batch,
+            json!([
+                { "symbol": "BTCUSDT", "side": "BUY", "type": "LIMIT", "quantity": "0.010", "priceMatch": "OPPONENT_5" },
+                { "symbol": "BTCUSDT", "side": "SELL", "type": "LIMIT", "quantity": "0.010", "reduceOnly": "true", "priceMatch": "OPPONENT_5" }
+            ])
+        );
+    }
+}
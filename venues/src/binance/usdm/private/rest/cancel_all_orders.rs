@@ -0,0 +1,55 @@
+// Cancel All Open Orders (TRADE) endpoint implementation for DELETE /fapi/v1/allOpenOrders
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Cancel-All-Open-Orders>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for canceling all open orders of a symbol (DELETE /fapi/v1/allOpenOrders).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOpenOrdersRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Response for canceling all open orders.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelAllOpenOrdersResponse {
+    /// 200 on success.
+    pub code: i32,
+
+    /// Human readable result, e.g. "The operation of cancel all open order is done.".
+    pub msg: String,
+}
+
+impl RestClient {
+    /// Cancels all open orders of a symbol on Binance USD-M Futures.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Cancel-All-Open-Orders>
+    /// DELETE /fapi/v1/allOpenOrders
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelAllOpenOrdersRequest`])
+    ///
+    /// # Returns
+    /// A [`CancelAllOpenOrdersResponse`] confirming the cancelation.
+    pub async fn delete_all_open_orders(&self, params: CancelAllOpenOrdersRequest) -> RestResult<CancelAllOpenOrdersResponse> {
+        let weight = 1;
+        self.send_signed_request(
+            "/fapi/v1/allOpenOrders",
+            reqwest::Method::DELETE,
+            params,
+            weight,
+            false, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,54 @@
+// Cancel Order (TRADE) endpoint implementation for DELETE /fapi/v1/order
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Cancel-Order>
+
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::private::rest::order::OrderResponse;
+
+/// Request parameters for canceling an active order (DELETE /fapi/v1/order).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID to cancel. Either `order_id` or `orig_client_order_id` must be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Original client order ID. Either `order_id` or `orig_client_order_id` must be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Cancels an active order on Binance USD-M Futures.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Cancel-Order>
+    /// DELETE /fapi/v1/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CancelOrderRequest`])
+    ///
+    /// # Returns
+    /// The canceled [`OrderResponse`].
+    pub async fn delete_order(&self, params: CancelOrderRequest) -> RestResult<OrderResponse> {
+        let weight = 1;
+        self.send_signed_request(
+            "/fapi/v1/order",
+            reqwest::Method::DELETE,
+            params,
+            weight,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,45 @@
+// Change Multi-Assets Mode (TRADE) endpoint implementation for POST /fapi/v1/multiAssetsMargin
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Multi-Assets-Mode>
+
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::private::rest::margin_type::SuccessResponse;
+
+/// Request parameters for changing the multi-assets mode.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeMultiAssetsModeRequest {
+    /// True for Multi-Assets Mode, false for Single-Asset Mode.
+    pub multi_assets_margin: bool,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Switches between Multi-Assets Mode and Single-Asset Mode for every symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Multi-Assets-Mode>
+    /// POST /fapi/v1/multiAssetsMargin
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ChangeMultiAssetsModeRequest`])
+    ///
+    /// # Returns
+    /// A [`SuccessResponse`] on success.
+    pub async fn post_multi_assets_mode(&self, params: ChangeMultiAssetsModeRequest) -> RestResult<SuccessResponse> {
+        self.send_signed_request(
+            "/fapi/v1/multiAssetsMargin",
+            reqwest::Method::POST,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
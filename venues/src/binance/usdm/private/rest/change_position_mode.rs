@@ -0,0 +1,47 @@
+// Change Position Mode (TRADE) endpoint implementation for POST /fapi/v1/positionSide/dual
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Position-Mode>
+
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::private::rest::margin_type::SuccessResponse;
+
+/// Request parameters for changing the position mode of every symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePositionModeRequest {
+    /// True for Hedge Mode, false for One-way Mode.
+    pub dual_side_position: bool,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Switches every symbol between Hedge Mode and One-way Mode.
+    ///
+    /// Cannot be changed while any symbol has open orders or positions.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Position-Mode>
+    /// POST /fapi/v1/positionSide/dual
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ChangePositionModeRequest`])
+    ///
+    /// # Returns
+    /// A [`SuccessResponse`] on success.
+    pub async fn post_position_mode(&self, params: ChangePositionModeRequest) -> RestResult<SuccessResponse> {
+        self.send_signed_request(
+            "/fapi/v1/positionSide/dual",
+            reqwest::Method::POST,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,64 @@
+// Auto-Cancel All Open Orders (TRADE) endpoint implementation for POST /fapi/v1/countdownCancelAll
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Auto-Cancel-All-Open-Orders>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for the countdown that cancels all open orders of a symbol
+/// (POST /fapi/v1/countdownCancelAll).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAllRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Countdown in milliseconds, 0 to stop it.
+    pub countdown_time: u64,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Response for setting the countdown.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAllResponse {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// The countdown that was set, in milliseconds.
+    pub countdown_time: String,
+}
+
+impl RestClient {
+    /// Starts, renews or stops the countdown that cancels all open orders of a symbol.
+    ///
+    /// Acts as a dead man's switch: the countdown has to be renewed before it runs out,
+    /// otherwise all open orders of the symbol are canceled. The countdown is checked
+    /// every 10ms, so it should be a few seconds at least.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Auto-Cancel-All-Open-Orders>
+    /// POST /fapi/v1/countdownCancelAll
+    /// Weight: 10
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`CountdownCancelAllRequest`])
+    ///
+    /// # Returns
+    /// A [`CountdownCancelAllResponse`] with the countdown that was set.
+    pub async fn post_countdown_cancel_all(&self, params: CountdownCancelAllRequest) -> RestResult<CountdownCancelAllResponse> {
+        let weight = 10;
+        self.send_signed_request(
+            "/fapi/v1/countdownCancelAll",
+            reqwest::Method::POST,
+            params,
+            weight,
+            false, // is_order
+        )
+        .await
+    }
+}
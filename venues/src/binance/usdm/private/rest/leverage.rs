@@ -0,0 +1,55 @@
+// Change Initial Leverage (TRADE) endpoint implementation for POST /fapi/v1/leverage
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Initial-Leverage>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for changing a symbol's initial leverage.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Target initial leverage, 1 to 125.
+    pub leverage: u32,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Response for changing the initial leverage.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    /// Trading symbol, e.g. "BTCUSDT".
+    pub symbol: String,
+
+    /// Initial leverage now in effect.
+    pub leverage: u32,
+
+    /// Largest notional value allowed at this leverage.
+    pub max_notional_value: String,
+}
+
+impl RestClient {
+    /// Changes the initial leverage of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Initial-Leverage>
+    /// POST /fapi/v1/leverage
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ChangeLeverageRequest`])
+    ///
+    /// # Returns
+    /// A [`ChangeLeverageResponse`] with the new leverage.
+    pub async fn post_leverage(&self, params: ChangeLeverageRequest) -> RestResult<ChangeLeverageResponse> {
+        self.send_signed_request("/fapi/v1/leverage", reqwest::Method::POST, params, 1, false)
+            .await
+    }
+}
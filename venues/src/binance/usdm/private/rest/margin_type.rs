@@ -0,0 +1,75 @@
+// Change Margin Type (TRADE) endpoint implementation for POST /fapi/v1/marginType
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Margin-Type>
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::MarginType;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Serializes a margin type the way this endpoint expects it ("CROSSED" or "ISOLATED"),
+/// which differs from the lowercase form positions report it in
+fn as_request_margin_type<S>(margin_type: &MarginType, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(match margin_type {
+        MarginType::Cross => "CROSSED",
+        MarginType::Isolated => "ISOLATED",
+    })
+}
+
+/// Request parameters for changing a symbol's margin type.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeMarginTypeRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Target margin type.
+    #[serde(serialize_with = "as_request_margin_type")]
+    pub margin_type: MarginType,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Result of a successful account setting change.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SuccessResponse {
+    /// 200 on success.
+    pub code: i32,
+
+    /// "success".
+    pub msg: String,
+}
+
+impl RestClient {
+    /// Changes the margin type of a symbol between cross and isolated.
+    ///
+    /// Fails with -4046 if the symbol already uses the margin type, and cannot be changed
+    /// while the symbol has open orders or positions.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Change-Margin-Type>
+    /// POST /fapi/v1/marginType
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ChangeMarginTypeRequest`])
+    ///
+    /// # Returns
+    /// A [`SuccessResponse`] on success.
+    pub async fn post_margin_type(&self, params: ChangeMarginTypeRequest) -> RestResult<SuccessResponse> {
+        self.send_signed_request(
+            "/fapi/v1/marginType",
+            reqwest::Method::POST,
+            params,
+            1,
+            false,
+        )
+        .await
+    }
+}
@@ -1,13 +1,27 @@
+// Private REST endpoints module for Binance USD-M
+
+pub mod account;
+pub mod account_trades;
+pub mod account_v2;
+pub mod adl_quantile;
+pub mod all_orders;
+pub mod balance;
+pub mod batch_order;
+pub mod cancel_all_orders;
+pub mod cancel_order;
+pub mod change_multi_assets_mode;
+pub mod change_position_mode;
 pub mod client;
+pub mod countdown_cancel_all;
+pub mod income;
+pub mod leverage;
+pub mod margin_type;
+pub mod modify_order;
+pub mod multi_assets_mode;
+pub mod open_orders;
+pub mod order;
+pub mod position_mode;
+pub mod position_risk;
+pub mod query_order;
 
 pub use client::RestClient;
-
-// Re-export placeholder modules for endpoints that will be implemented later
-pub mod account {}
-pub mod account_trades {}
-pub mod batch_order {}
-pub mod order {}
-pub mod open_orders {}
-pub mod position_risk {}
-pub mod query_order {}
-pub mod all_orders {}
@@ -0,0 +1,71 @@
+// Modify Order (TRADE) endpoint implementation for PUT /fapi/v1/order
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Modify-Order>
+
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::private::rest::order::OrderResponse;
+use crate::binance::usdm::{OrderSide, PriceMatch};
+
+/// Request parameters for modifying the price or quantity of an open LIMIT order (PUT /fapi/v1/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID to modify. Either `order_id` or `orig_client_order_id` must be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Original client order ID. Either `order_id` or `orig_client_order_id` must be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Order side, which must match the original order.
+    pub side: OrderSide,
+
+    /// New order quantity.
+    pub quantity: String,
+
+    /// New order price. Cannot be sent with `price_match`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Price match mode. Cannot be sent with `price`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Modifies an open LIMIT order on Binance USD-M Futures.
+    ///
+    /// The order keeps its place in the queue only if the quantity is reduced and the price is unchanged.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Modify-Order>
+    /// PUT /fapi/v1/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`ModifyOrderRequest`])
+    ///
+    /// # Returns
+    /// The modified [`OrderResponse`].
+    pub async fn put_order(&self, params: ModifyOrderRequest) -> RestResult<OrderResponse> {
+        let weight = 1;
+        self.send_signed_request(
+            "/fapi/v1/order",
+            reqwest::Method::PUT,
+            params,
+            weight,
+            true, // is_order
+        )
+        .await
+    }
+}
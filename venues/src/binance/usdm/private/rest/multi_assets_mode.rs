@@ -0,0 +1,50 @@
+// Get Current Multi-Assets Mode (USER_DATA) endpoint implementation for
+// GET /fapi/v1/multiAssetsMargin
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Get-Current-Multi-Assets-Mode>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for fetching the current multi-assets mode.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiAssetsModeRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Current multi-assets mode.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiAssetsModeResponse {
+    /// True in Multi-Assets Mode, false in Single-Asset Mode.
+    pub multi_assets_margin: bool,
+}
+
+impl RestClient {
+    /// Fetches the current multi-assets mode.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Get-Current-Multi-Assets-Mode>
+    /// GET /fapi/v1/multiAssetsMargin
+    /// Weight: 30
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`MultiAssetsModeRequest`])
+    ///
+    /// # Returns
+    /// A [`MultiAssetsModeResponse`] with the current mode.
+    pub async fn get_multi_assets_mode(&self, params: MultiAssetsModeRequest) -> RestResult<MultiAssetsModeResponse> {
+        self.send_signed_request(
+            "/fapi/v1/multiAssetsMargin",
+            reqwest::Method::GET,
+            params,
+            30,
+            false,
+        )
+        .await
+    }
+}
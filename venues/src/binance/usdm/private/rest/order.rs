@@ -0,0 +1,219 @@
+// New Order (TRADE) endpoint implementation for POST /fapi/v1/order
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api>
+
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::{OrderResponseType, OrderSide, OrderStatus, OrderType, PositionSide, PriceMatch, SelfTradePreventionMode, TimeInForce, WorkingType};
+
+/// Serializes a flag as "true" or "false", which batch orders expect as a JSON string too
+fn as_flag<S>(flag: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match flag {
+        Some(true) => serializer.serialize_str("true"),
+        Some(false) => serializer.serialize_str("false"),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Request parameters for placing a new order (POST /fapi/v1/order).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order side (BUY or SELL).
+    pub side: OrderSide,
+
+    /// Position side (BOTH, LONG, SHORT). Must be LONG or SHORT in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+
+    /// Order type (LIMIT, MARKET, etc.).
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Time in force (GTC, IOC, FOK, GTX, GTD).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Order quantity. Cannot be sent with `close_position`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+
+    /// Only reduce the position, never increase it. Cannot be sent in Hedge Mode.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_flag")]
+    pub reduce_only: Option<bool>,
+
+    /// Limit price, required for LIMIT, STOP and TAKE_PROFIT orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Unique client order ID, used to look the order up if its outcome is unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+
+    /// Stop price for STOP/STOP_MARKET/TAKE_PROFIT/TAKE_PROFIT_MARKET orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+
+    /// Close the whole position when triggered, for STOP_MARKET and TAKE_PROFIT_MARKET.
+    /// Cannot be sent with `quantity` or `reduce_only`.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_flag")]
+    pub close_position: Option<bool>,
+
+    /// Activation price (for TRAILING_STOP_MARKET).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<String>,
+
+    /// Callback rate in percent (for TRAILING_STOP_MARKET), 0.1 to 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_rate: Option<String>,
+
+    /// Price that triggers conditional orders, CONTRACT_PRICE by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_type: Option<WorkingType>,
+
+    /// Don't trigger a conditional order while the mark and contract prices diverge beyond
+    /// the symbol's `triggerProtect` threshold.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_flag")]
+    pub price_protect: Option<bool>,
+
+    /// Response detail, ACK by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+
+    /// Price match mode. Cannot be sent with `price`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+
+    /// What to do when the order would match an order of the same account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Auto cancel time (ms since epoch) of GTD orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub good_till_date: Option<u64>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// An order as returned by the order endpoints (new, modify, cancel, query, open and all orders).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponse {
+    /// Client order ID, generated by Binance if none was sent.
+    pub client_order_id: String,
+
+    /// Filled quantity. Not returned by every endpoint; `executed_qty` always is.
+    pub cum_qty: Option<String>,
+
+    /// Filled amount in the quote asset.
+    pub cum_quote: String,
+
+    /// Filled quantity.
+    pub executed_qty: String,
+
+    /// Order ID assigned by Binance, unique per symbol.
+    pub order_id: u64,
+
+    /// Average fill price, "0" until the order fills.
+    pub avg_price: String,
+
+    /// Quantity the order was placed with.
+    pub orig_qty: String,
+
+    /// Limit price, "0" for market orders.
+    pub price: String,
+
+    /// Whether the order can only reduce the position.
+    pub reduce_only: bool,
+
+    /// Buy or sell.
+    pub side: OrderSide,
+
+    /// Position the order belongs to; BOTH in One-way Mode.
+    pub position_side: PositionSide,
+
+    /// Current order status.
+    pub status: OrderStatus,
+
+    /// Trigger price of conditional orders, "0" for others.
+    pub stop_price: Option<String>,
+
+    /// Whether a triggered conditional order closes the whole position.
+    pub close_position: Option<bool>,
+
+    /// Trading symbol, e.g. "BTCUSDT".
+    pub symbol: String,
+
+    /// How long the order stays on the book.
+    pub time_in_force: TimeInForce,
+
+    /// Current order type; a triggered STOP_MARKET order becomes MARKET.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    /// Order type the order was placed with.
+    pub orig_type: OrderType,
+
+    /// Activation price, only returned for TRAILING_STOP_MARKET orders.
+    pub activate_price: Option<String>,
+
+    /// Callback rate, only returned for TRAILING_STOP_MARKET orders.
+    pub price_rate: Option<String>,
+
+    /// Order creation time, returned when querying orders.
+    pub time: Option<u64>,
+
+    /// Last update time (ms since epoch).
+    pub update_time: u64,
+
+    /// Price that triggers conditional orders.
+    pub working_type: WorkingType,
+
+    /// Whether the conditional order is price protected.
+    pub price_protect: bool,
+
+    /// Price match mode, NONE if the price was set explicitly.
+    pub price_match: Option<PriceMatch>,
+
+    /// Self-trade prevention mode of the order.
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+
+    /// Auto cancel time of GTD orders, 0 otherwise.
+    pub good_till_date: Option<u64>,
+}
+
+impl RestClient {
+    /// Places a new order (TRADE) on Binance USD-M Futures.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api>
+    /// POST /fapi/v1/order
+    /// Weight: 1 (order rate limit)
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`NewOrderRequest`])
+    ///
+    /// # Returns
+    /// An [`OrderResponse`] object with order details.
+    pub async fn post_order(&self, params: NewOrderRequest) -> RestResult<OrderResponse> {
+        let weight = 1;
+        self.send_signed_request(
+            "/fapi/v1/order",
+            reqwest::Method::POST,
+            params,
+            weight,
+            true, // is_order
+        )
+        .await
+    }
+}
@@ -0,0 +1,49 @@
+// Get Current Position Mode (USER_DATA) endpoint implementation for GET /fapi/v1/positionSide/dual
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Get-Current-Position-Mode>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for fetching the current position mode.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionModeRequest {
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Current position mode.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionModeResponse {
+    /// True in Hedge Mode, false in One-way Mode.
+    pub dual_side_position: bool,
+}
+
+impl RestClient {
+    /// Fetches the current position mode.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/account/rest-api/Get-Current-Position-Mode>
+    /// GET /fapi/v1/positionSide/dual
+    /// Weight: 30
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`PositionModeRequest`])
+    ///
+    /// # Returns
+    /// A [`PositionModeResponse`] with the current mode.
+    pub async fn get_position_mode(&self, params: PositionModeRequest) -> RestResult<PositionModeResponse> {
+        self.send_signed_request(
+            "/fapi/v1/positionSide/dual",
+            reqwest::Method::GET,
+            params,
+            30,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,111 @@
+// Position Information endpoint implementation for GET /fapi/v3/positionRisk
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Position-Information-V3>
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::PositionSide;
+use crate::binance::usdm::private::rest::client::RestClient;
+
+/// Request parameters for fetching position information.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRiskRequest {
+    /// Trading symbol (e.g., "BTCUSDT"). If omitted, positions of all symbols are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+/// Risk information of an open position.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    /// Trading symbol, e.g. "BTCUSDT".
+    pub symbol: String,
+
+    /// Side of the position; BOTH in One-way Mode.
+    pub position_side: PositionSide,
+
+    /// Position size, negative for short positions in One-way Mode.
+    pub position_amt: String,
+
+    /// Average entry price.
+    pub entry_price: String,
+
+    /// Price at which the position breaks even after fees.
+    pub break_even_price: String,
+
+    /// Current mark price.
+    pub mark_price: String,
+
+    /// Unrealized profit at the mark price.
+    pub un_realized_profit: String,
+
+    /// Price at which the position is liquidated, "0" if it cannot be.
+    pub liquidation_price: String,
+
+    /// Margin of an isolated position, "0" for crossed ones.
+    pub isolated_margin: String,
+
+    /// Notional value at the mark price.
+    pub notional: String,
+
+    /// Asset the position is margined in.
+    pub margin_asset: String,
+
+    /// Wallet balance of an isolated position.
+    pub isolated_wallet: String,
+
+    /// Initial margin of the position and its open orders.
+    pub initial_margin: String,
+
+    /// Maintenance margin required.
+    pub maint_margin: String,
+
+    /// Initial margin of the position at the mark price.
+    pub position_initial_margin: String,
+
+    /// Initial margin of open orders at the mark price.
+    pub open_order_initial_margin: String,
+
+    /// Auto-deleveraging quantile, 0 (lowest priority) to 4 (highest).
+    pub adl: u8,
+
+    /// Notional value of open buy orders.
+    pub bid_notional: String,
+
+    /// Notional value of open sell orders.
+    pub ask_notional: String,
+
+    /// Last update time (ms since epoch).
+    pub update_time: u64,
+}
+
+impl RestClient {
+    /// Fetches current position information, only for symbols with a position or open orders.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Position-Information-V3>
+    /// GET /fapi/v3/positionRisk
+    /// Weight: 5
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`PositionRiskRequest`])
+    ///
+    /// # Returns
+    /// A vector of [`PositionRisk`] objects.
+    pub async fn get_position_risk(&self, params: PositionRiskRequest) -> RestResult<Vec<PositionRisk>> {
+        self.send_signed_request(
+            "/fapi/v3/positionRisk",
+            reqwest::Method::GET,
+            params,
+            5,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,56 @@
+// Query Order endpoint implementation for GET /fapi/v1/order
+// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Query-Order>
+
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::private::rest::client::RestClient;
+use crate::binance::usdm::private::rest::order::OrderResponse;
+
+/// Request parameters for querying an order (GET /fapi/v1/order).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Order ID. Either `order_id` or `orig_client_order_id` must be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+
+    /// Original client order ID. Either `order_id` or `orig_client_order_id` must be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+
+    /// Milliseconds the request stays valid after its `timestamp`, at most 60000. When `None`,
+    /// the client covers its server clock's uncertainty once synced; Binance defaults to 5000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+}
+
+impl RestClient {
+    /// Queries an order's status on Binance USD-M Futures.
+    ///
+    /// Canceled or expired orders without fills are only returned for 3 days after they were created.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/trade/rest-api/Query-Order>
+    /// GET /fapi/v1/order
+    /// Weight: 1
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (see [`QueryOrderRequest`])
+    ///
+    /// # Returns
+    /// The [`OrderResponse`] of the order.
+    pub async fn get_query_order(&self, params: QueryOrderRequest) -> RestResult<OrderResponse> {
+        let weight = 1;
+        self.send_signed_request(
+            "/fapi/v1/order",
+            reqwest::Method::GET,
+            params,
+            weight,
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for compressed, aggregate trades.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AggTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Aggregate trade ID to fetch from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+
+    /// Start time (ms since epoch). At most 1 hour before `end_time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// Trades that filled at the same time, from the same taker order, at the same price.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AggTrade {
+    /// Aggregate trade ID.
+    #[serde(rename = "a")]
+    pub agg_trade_id: u64,
+
+    /// Price.
+    #[serde(rename = "p")]
+    pub price: String,
+
+    /// Total quantity of the trades.
+    #[serde(rename = "q")]
+    pub quantity: String,
+
+    /// ID of the first trade.
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+
+    /// ID of the last trade.
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+
+    /// Trade time (ms since epoch).
+    #[serde(rename = "T")]
+    pub timestamp: u64,
+
+    /// Whether the buyer was the maker.
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+impl RestClient {
+    /// Fetches compressed, aggregate trades of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Compressed-Aggregate-Trades-List>
+    /// Corresponds to endpoint GET /fapi/v1/aggTrades.
+    /// Weight: 20
+    pub async fn get_agg_trades(&self, params: AggTradesRequest) -> RestResult<Vec<AggTrade>> {
+        self.send_get_request("/fapi/v1/aggTrades", params, 20)
+            .await
+    }
+}
@@ -0,0 +1,21 @@
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::book_ticker::BookTicker;
+
+impl RestClient {
+    /// Fetches the best bid and ask of every symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Symbol-Order-Book-Ticker>
+    /// Corresponds to endpoint GET /fapi/v1/ticker/bookTicker.
+    /// Weight: 5
+    pub async fn get_all_book_tickers(&self) -> RestResult<Vec<BookTicker>> {
+        self.send_request(
+            "/fapi/v1/ticker/bookTicker",
+            reqwest::Method::GET,
+            None,
+            None,
+            5,
+        )
+        .await
+    }
+}
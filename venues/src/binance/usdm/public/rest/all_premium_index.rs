@@ -0,0 +1,21 @@
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::premium_index::PremiumIndex;
+
+impl RestClient {
+    /// Fetches the mark price and funding rate of every symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Mark-Price>
+    /// Corresponds to endpoint GET /fapi/v1/premiumIndex.
+    /// Weight: 10
+    pub async fn get_all_premium_index(&self) -> RestResult<Vec<PremiumIndex>> {
+        self.send_request(
+            "/fapi/v1/premiumIndex",
+            reqwest::Method::GET,
+            None,
+            None,
+            10,
+        )
+        .await
+    }
+}
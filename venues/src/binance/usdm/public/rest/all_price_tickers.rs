@@ -0,0 +1,15 @@
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::price_ticker::PriceTicker;
+
+impl RestClient {
+    /// Fetches the latest price of every symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Symbol-Price-Ticker-v2>
+    /// Corresponds to endpoint GET /fapi/v2/ticker/price.
+    /// Weight: 2
+    pub async fn get_all_price_tickers(&self) -> RestResult<Vec<PriceTicker>> {
+        self.send_request("/fapi/v2/ticker/price", reqwest::Method::GET, None, None, 2)
+            .await
+    }
+}
@@ -0,0 +1,15 @@
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::ticker_24hr::Ticker24hr;
+
+impl RestClient {
+    /// Fetches the 24 hour price change statistics of every symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/24hr-Ticker-Price-Change-Statistics>
+    /// Corresponds to endpoint GET /fapi/v1/ticker/24hr.
+    /// Weight: 40
+    pub async fn get_all_tickers_24hr(&self) -> RestResult<Vec<Ticker24hr>> {
+        self.send_request("/fapi/v1/ticker/24hr", reqwest::Method::GET, None, None, 40)
+            .await
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::{ContractType, Period};
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the basis of a contract type of a pair. Only the last 30 days are
+/// available.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasisRequest {
+    /// Pair (e.g., "BTCUSDT").
+    pub pair: String,
+
+    /// PERPETUAL, CURRENT_QUARTER or NEXT_QUARTER.
+    pub contract_type: ContractType,
+
+    /// Period of each entry.
+    pub period: Period,
+
+    /// Number of entries (default 30, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+/// Difference between the futures price and the index price at the end of a period.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    /// Underlying pair.
+    pub pair: String,
+
+    /// Contract type, e.g. perpetual or current quarter.
+    pub contract_type: ContractType,
+
+    /// Index price.
+    pub index_price: String,
+
+    /// Price of the contract.
+    pub futures_price: String,
+
+    /// Futures price minus index price.
+    pub basis: String,
+
+    /// Basis divided by the index price.
+    pub basis_rate: String,
+
+    /// Basis rate scaled to a year, empty for perpetual contracts.
+    pub annualized_basis_rate: String,
+
+    /// End of the period (ms since epoch).
+    pub timestamp: u64,
+}
+
+impl RestClient {
+    /// Fetches the basis of a contract type of a pair.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Basis>
+    /// Corresponds to endpoint GET /futures/data/basis.
+    /// Weight: 0, see [`RestClient::get_open_interest_hist`]
+    pub async fn get_basis(&self, params: BasisRequest) -> RestResult<Vec<Basis>> {
+        self.send_get_request("/futures/data/basis", params, 0)
+            .await
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the best bid and ask of one symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct BookTickerRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Best bid and ask of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Best bid price.
+    pub bid_price: String,
+
+    /// Quantity at the best bid.
+    pub bid_qty: String,
+
+    /// Best ask price.
+    pub ask_price: String,
+
+    /// Quantity at the best ask.
+    pub ask_qty: String,
+
+    /// Transaction time.
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the best bid and ask of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Symbol-Order-Book-Ticker>
+    /// Corresponds to endpoint GET /fapi/v1/ticker/bookTicker.
+    /// Weight: 2
+    pub async fn get_book_ticker(&self, params: BookTickerRequest) -> RestResult<BookTicker> {
+        self.send_get_request("/fapi/v1/ticker/bookTicker", params, 2)
+            .await
+    }
+}
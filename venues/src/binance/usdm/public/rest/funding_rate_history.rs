@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the funding rate history.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Start time (ms since epoch), inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch), inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of entries (default 100, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A funding rate that was applied.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Funding rate that was applied.
+    pub funding_rate: String,
+
+    /// Funding time (ms since epoch).
+    pub funding_time: u64,
+
+    /// Mark price at funding time, empty for older entries.
+    pub mark_price: String,
+}
+
+impl RestClient {
+    /// Fetches the funding rate history.
+    ///
+    /// Binance additionally limits this endpoint and [`RestClient::get_funding_info`] to a shared
+    /// 500 requests per 5 minutes per IP.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Get-Funding-Rate-History>
+    /// Corresponds to endpoint GET /fapi/v1/fundingRate.
+    /// Weight: 1
+    pub async fn get_funding_rate_history(&self, params: FundingRateRequest) -> RestResult<Vec<FundingRate>> {
+        self.send_get_request("/fapi/v1/fundingRate", params, 1)
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::trades::Trade;
+
+/// Request parameters for older trades.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Number of trades (default 100, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Trade ID to fetch from. Default returns the most recent trades.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches older trades of a symbol. Requires an API key, see [`RestClient::with_api_key`].
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Old-Trades-Lookup>
+    /// Corresponds to endpoint GET /fapi/v1/historicalTrades.
+    /// Weight: 20
+    pub async fn get_historical_trades(&self, params: HistoricalTradesRequest) -> RestResult<Vec<Trade>> {
+        self.send_get_request("/fapi/v1/historicalTrades", params, 20)
+            .await
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+use crate::binance::shared::klines_weight;
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::KlineInterval;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::klines::Kline;
+
+/// Request parameters for index price klines of a pair.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPriceKlinesRequest {
+    /// Pair (e.g., "BTCUSDT").
+    pub pair: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of klines (default 500, max 1500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl RestClient {
+    /// Fetches index price klines of a pair.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Index-Price-Kline-Candlestick-Data>
+    /// Corresponds to endpoint GET /fapi/v1/indexPriceKlines.
+    /// Weight: same as [`RestClient::get_klines`]
+    pub async fn get_index_price_klines(&self, params: IndexPriceKlinesRequest) -> RestResult<Vec<Kline>> {
+        let weight = klines_weight(params.limit);
+        self.send_get_request("/fapi/v1/indexPriceKlines", params, weight)
+            .await
+    }
+}
@@ -1,4 +1,31 @@
+pub mod agg_trades;
+pub mod all_book_tickers;
+pub mod all_premium_index;
+pub mod all_price_tickers;
+pub mod all_tickers_24hr;
+pub mod basis;
+pub mod book_ticker;
 pub mod client;
+pub mod continuous_klines;
 pub mod exchange_info;
+pub mod funding_info;
+pub mod funding_rate_history;
+pub mod historical_trades;
+pub mod index_price_klines;
+pub mod klines;
+pub mod mark_price_klines;
+pub mod open_interest;
+pub mod open_interest_hist;
+pub mod order_book;
+pub mod ping;
+pub mod premium_index;
+pub mod premium_index_klines;
+pub mod price_ticker;
+pub mod server_time;
+pub mod taker_buy_sell_volume;
+pub mod ticker_24hr;
+pub mod top_long_short_account_ratio;
+pub mod top_long_short_position_ratio;
+pub mod trades;
 
 pub use client::RestClient;
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the current open interest of a symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct OpenInterestRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Current open interest of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Open interest in the base asset.
+    pub open_interest: String,
+
+    /// Time of the snapshot (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the current open interest of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Open-Interest>
+    /// Corresponds to endpoint GET /fapi/v1/openInterest.
+    /// Weight: 1
+    pub async fn get_open_interest(&self, params: OpenInterestRequest) -> RestResult<OpenInterest> {
+        self.send_get_request("/fapi/v1/openInterest", params, 1)
+            .await
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Empty response of the connectivity test.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PingResponse {}
+
+impl RestClient {
+    /// Tests connectivity to the REST API.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Test-Connectivity>
+    /// Corresponds to endpoint GET /fapi/v1/ping.
+    /// Weight: 1
+    pub async fn ping(&self) -> RestResult<PingResponse> {
+        self.send_request("/fapi/v1/ping", reqwest::Method::GET, None, None, 1)
+            .await
+    }
+}
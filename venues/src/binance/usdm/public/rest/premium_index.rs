@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the mark price and funding rate of one symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct PremiumIndexRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Mark price, index price and funding rate of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Mark price.
+    pub mark_price: String,
+
+    /// Index price.
+    pub index_price: String,
+
+    /// Only meaningful for delivery contracts in the last hour before settlement.
+    pub estimated_settle_price: String,
+
+    /// Funding rate of the current period.
+    pub last_funding_rate: String,
+
+    /// Interest rate used in the funding rate.
+    pub interest_rate: String,
+
+    /// Next funding time (ms since epoch).
+    pub next_funding_time: u64,
+
+    /// Time of the snapshot (ms since epoch).
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the mark price and funding rate of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Mark-Price>
+    /// Corresponds to endpoint GET /fapi/v1/premiumIndex.
+    /// Weight: 1
+    pub async fn get_premium_index(&self, params: PremiumIndexRequest) -> RestResult<PremiumIndex> {
+        self.send_get_request("/fapi/v1/premiumIndex", params, 1)
+            .await
+    }
+}
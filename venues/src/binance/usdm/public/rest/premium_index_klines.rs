@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+use crate::binance::shared::klines_weight;
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::KlineInterval;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::klines::Kline;
+
+/// Request parameters for premium index klines of a symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndexKlinesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Kline interval.
+    pub interval: KlineInterval,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+
+    /// Number of klines (default 500, max 1500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl RestClient {
+    /// Fetches premium index klines of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Premium-Index-Kline-Data>
+    /// Corresponds to endpoint GET /fapi/v1/premiumIndexKlines.
+    /// Weight: same as [`RestClient::get_klines`]
+    pub async fn get_premium_index_klines(&self, params: PremiumIndexKlinesRequest) -> RestResult<Vec<Kline>> {
+        let weight = klines_weight(params.limit);
+        self.send_get_request("/fapi/v1/premiumIndexKlines", params, weight)
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the latest price of one symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct PriceTickerRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Latest price of a symbol.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceTicker {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Latest price.
+    pub price: String,
+
+    /// Transaction time.
+    pub time: u64,
+}
+
+impl RestClient {
+    /// Fetches the latest price of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Symbol-Price-Ticker-v2>
+    /// Corresponds to endpoint GET /fapi/v2/ticker/price.
+    /// Weight: 1
+    pub async fn get_price_ticker(&self, params: PriceTickerRequest) -> RestResult<PriceTicker> {
+        self.send_get_request("/fapi/v2/ticker/price", params, 1)
+            .await
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::Period;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the taker buy and sell volume. Only the last 30 days are available.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolumeRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Period of each entry.
+    pub period: Period,
+
+    /// Number of entries (default 30, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+/// Taker buy and sell volume of a symbol over a period.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    /// Taker buy volume divided by taker sell volume.
+    pub buy_sell_ratio: String,
+
+    /// Taker buy volume in the base asset.
+    pub buy_vol: String,
+
+    /// Taker sell volume in the base asset.
+    pub sell_vol: String,
+
+    /// End of the period (ms since epoch).
+    pub timestamp: u64,
+}
+
+impl RestClient {
+    /// Fetches the taker buy and sell volume of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Taker-BuySell-Volume>
+    /// Corresponds to endpoint GET /futures/data/takerlongshortRatio.
+    /// Weight: 0, see [`RestClient::get_open_interest_hist`]
+    pub async fn get_taker_buy_sell_volume(&self, params: TakerBuySellVolumeRequest) -> RestResult<Vec<TakerBuySellVolume>> {
+        self.send_get_request("/futures/data/takerlongshortRatio", params, 0)
+            .await
+    }
+}
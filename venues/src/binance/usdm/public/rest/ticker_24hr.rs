@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for the 24 hour price change statistics of one symbol.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct Ticker24hrRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+}
+
+/// Price change statistics over the last 24 hours.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hr {
+    /// Trading symbol.
+    pub symbol: String,
+
+    /// Last price minus the open price.
+    pub price_change: String,
+
+    /// Price change in percent of the open price.
+    pub price_change_percent: String,
+
+    /// Volume weighted average price.
+    pub weighted_avg_price: String,
+
+    /// Price of the last trade.
+    pub last_price: String,
+
+    /// Quantity of the last trade.
+    pub last_qty: String,
+
+    /// Price 24 hours ago.
+    pub open_price: String,
+
+    /// High price.
+    pub high_price: String,
+
+    /// Low price.
+    pub low_price: String,
+
+    /// Volume in the base asset.
+    pub volume: String,
+
+    /// Volume in the quote asset.
+    pub quote_volume: String,
+
+    /// Start of the window (ms since epoch).
+    pub open_time: u64,
+
+    /// End of the window (ms since epoch).
+    pub close_time: u64,
+
+    /// ID of the first trade in the window.
+    pub first_id: i64,
+
+    /// ID of the last trade in the window.
+    pub last_id: i64,
+
+    /// Number of trades in the window.
+    pub count: u64,
+}
+
+impl RestClient {
+    /// Fetches the 24 hour price change statistics of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/24hr-Ticker-Price-Change-Statistics>
+    /// Corresponds to endpoint GET /fapi/v1/ticker/24hr.
+    /// Weight: 1
+    pub async fn get_ticker_24hr(&self, params: Ticker24hrRequest) -> RestResult<Ticker24hr> {
+        self.send_get_request("/fapi/v1/ticker/24hr", params, 1)
+            .await
+    }
+}
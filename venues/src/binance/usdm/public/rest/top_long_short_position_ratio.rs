@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::enums::Period;
+use crate::binance::usdm::public::rest::RestClient;
+use crate::binance::usdm::public::rest::top_long_short_account_ratio::LongShortRatio;
+
+/// Request parameters for the long/short position ratio of the top traders. Only the last
+/// 30 days are available.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopLongShortPositionRatioRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Period of each entry.
+    pub period: Period,
+
+    /// Number of entries (default 30, max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Start time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// End time (ms since epoch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+impl RestClient {
+    /// Fetches the long/short ratio of the top 20% of accounts by margin balance, weighting
+    /// accounts by their position.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Top-Trader-Long-Short-Ratio>
+    /// Corresponds to endpoint GET /futures/data/topLongShortPositionRatio.
+    /// Weight: 0, see [`RestClient::get_open_interest_hist`]
+    pub async fn get_top_long_short_position_ratio(&self, params: TopLongShortPositionRatioRequest) -> RestResult<Vec<LongShortRatio>> {
+        self.send_get_request("/futures/data/topLongShortPositionRatio", params, 0)
+            .await
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::binance::usdm::RestResult;
+use crate::binance::usdm::public::rest::RestClient;
+
+/// Request parameters for recent trades.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct RecentTradesRequest {
+    /// Trading symbol (e.g., "BTCUSDT").
+    pub symbol: String,
+
+    /// Number of trades (default 500, max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A trade filled in the order book.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// Trade ID.
+    pub id: u64,
+
+    /// Price.
+    pub price: String,
+
+    /// Quantity in the base asset.
+    pub qty: String,
+
+    /// Quantity in the quote asset.
+    pub quote_qty: String,
+
+    /// Trade time (ms since epoch).
+    pub time: u64,
+
+    /// Whether the buyer was the maker.
+    pub is_buyer_maker: bool,
+}
+
+impl RestClient {
+    /// Fetches recent trades of a symbol.
+    ///
+    /// See: <https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Recent-Trades-List>
+    /// Corresponds to endpoint GET /fapi/v1/trades.
+    /// Weight: 5
+    pub async fn get_recent_trades(&self, params: RecentTradesRequest) -> RestResult<Vec<Trade>> {
+        self.send_get_request("/fapi/v1/trades", params, 5).await
+    }
+}
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use reqwest::StatusCode;
+use rest::transport::{HttpRequest, HttpTransport};
 use tracing::debug;
 
 use crate::binance::usdm::errors::ErrorResponse;
@@ -48,7 +49,7 @@ pub(crate) struct ParsedResponse<T> {
 /// Internal helper to execute HTTP requests and parse responses for both public and private clients.
 /// This function encapsulates the shared HTTP logic (building, sending, and parsing the response to idiomatic Rust types).
 pub(crate) async fn execute_request<T>(
-    client: &reqwest::Client,
+    client: &dyn HttpTransport,
     url: &str,
     method: reqwest::Method,
     headers: Option<Vec<(&str, String)>>,
@@ -58,21 +59,21 @@ where
     T: serde::de::DeserializeOwned,
 {
     use std::time::Instant;
-    let mut request = client.request(method.clone(), url);
+    let mut request = HttpRequest::new(method, url);
     if let Some(hdrs) = headers {
         for (k, v) in hdrs {
             request = request.header(k, v);
         }
     }
     if let Some(b) = body {
-        request = request.body(b.to_owned());
+        request = request.body(b);
     }
     let start = Instant::now();
-    let response = request.send().await.map_err(Errors::HttpError)?;
+    let response = client.execute(request).await.map_err(Errors::HttpError)?;
     let duration = start.elapsed();
-    let status = response.status();
-    let headers = response.headers().clone();
-    let text = response.text().await.map_err(Errors::HttpError)?;
+    let status = response.status;
+    let headers = response.headers;
+    let text = response.body;
     // Parse relevant headers into ResponseHeaders
     let values = headers
         .iter()
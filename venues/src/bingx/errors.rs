@@ -1,6 +1,11 @@
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "bingx";
+
 /// Common BingX API errors
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum Errors {
@@ -45,6 +50,12 @@ impl From<reqwest::Error> for Errors {
     }
 }
 
+impl From<TransportError> for Errors {
+    fn from(err: TransportError) -> Self {
+        Errors::NetworkError(err.to_string())
+    }
+}
+
 impl From<serde_json::Error> for Errors {
     fn from(err: serde_json::Error) -> Self {
         Errors::ParseError(err.to_string())
@@ -66,3 +77,93 @@ impl From<ErrorResponse> for Errors {
         }
     }
 }
+
+/// Classify a BingX error code that has no dedicated [`Errors`] variant
+fn error_kind(code: i32) -> ErrorKind {
+    match code {
+        100001 | 100413 | 100419 | 80014 | 100421 => ErrorKind::AuthFailed,
+        100410 => ErrorKind::RateLimited { retry_after: None },
+        100202 | 101204 => ErrorKind::InsufficientFunds,
+        80016 => ErrorKind::OrderNotFound,
+        80012 | 100503 => ErrorKind::ExchangeUnavailable,
+        100500 => ErrorKind::UnknownExecutionStatus,
+        80001 | 100400 | 109400 => ErrorKind::InvalidRequest,
+        _ => ErrorKind::Other,
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::AuthenticationError(msg) | Errors::InvalidTimestamp(msg) => VenueError::new(VENUE, ErrorKind::AuthFailed, msg.clone()),
+            Errors::InvalidApiKey => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::IpWhitelistError(msg) => VenueError::new(VENUE, ErrorKind::AuthFailed, msg.clone()).with_code(100419),
+            Errors::RateLimitExceeded(msg) => VenueError::new(
+                VENUE,
+                ErrorKind::RateLimited { retry_after: None },
+                msg.clone(),
+            ),
+            // The request may have reached the exchange before the connection failed
+            Errors::NetworkError(msg) => VenueError::new(VENUE, ErrorKind::UnknownExecutionStatus, msg.clone()),
+            Errors::ApiError { code, msg } => VenueError::new(VENUE, error_kind(*code), msg.clone()).with_code(code),
+            Errors::ParseError(msg) | Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn venue_error(code: i32, msg: &str) -> VenueError {
+        Errors::from(ErrorResponse {
+            code,
+            msg: msg.to_string(),
+        })
+        .into()
+    }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error = venue_error(100202, "Insufficient balance");
+        assert_eq!(error.kind, ErrorKind::InsufficientFunds);
+        assert_eq!(error.code.as_deref(), Some("100202"));
+        assert_eq!(error.message, "Insufficient balance");
+        assert_eq!(error.venue, VENUE);
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(
+            venue_error(100410, "rate limited").kind,
+            ErrorKind::RateLimited { retry_after: None }
+        );
+        assert_eq!(
+            venue_error(100413, "Incorrect apiKey").kind,
+            ErrorKind::AuthFailed
+        );
+        assert_eq!(
+            venue_error(80014, "timestamp is invalid").kind,
+            ErrorKind::AuthFailed
+        );
+        assert_eq!(
+            venue_error(100419, "IP not in whitelist").code.as_deref(),
+            Some("100419")
+        );
+        assert_eq!(
+            venue_error(80016, "order does not exist").kind,
+            ErrorKind::OrderNotFound
+        );
+        assert_eq!(
+            venue_error(100500, "internal error").kind,
+            ErrorKind::UnknownExecutionStatus
+        );
+        assert_eq!(venue_error(12345, "something new").kind, ErrorKind::Other);
+    }
+}
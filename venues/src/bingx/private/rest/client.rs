@@ -1,12 +1,17 @@
 use std::borrow::Cow;
-
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use sha2::Sha256;
 
+use crate::bingx::errors::VENUE;
 use crate::bingx::{EndpointType, Errors, RateLimiter, RestResult};
 
 /// Private REST client for BingX exchange
@@ -22,7 +27,7 @@ pub struct RestClient {
     /// The underlying HTTP client used for making requests.
     ///
     /// This is reused for connection pooling and performance.
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits.
     ///
@@ -32,8 +37,11 @@ pub struct RestClient {
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
 
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
+
+    /// The server clock signed requests are timestamped with.
+    pub(crate) clock: ServerClock,
 }
 
 impl RestClient {
@@ -41,23 +49,36 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The API key for authentication
-    /// * `api_secret` - The API secret for signing requests
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the BingX API (e.g., "https://open-api.bingx.com")
     /// * `client` - The HTTP client to use for requests
     /// * `rate_limiter` - The rate limiter to use for request throttling
     ///
     /// # Returns
     /// A new RestClient instance
-    pub fn new(api_key: Box<dyn ExposableSecret>, api_secret: Box<dyn ExposableSecret>, base_url: &str, client: Client, rate_limiter: RateLimiter) -> Self {
+    pub fn new(
+        api_key: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
+        base_url: &str,
+        client: impl HttpTransport + 'static,
+        rate_limiter: RateLimiter,
+    ) -> Self {
         Self {
             api_key,
             api_secret,
             base_url: Cow::Owned(base_url.to_string()),
-            client,
+            client: Arc::new(client),
             rate_limiter,
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `timestamp` query parameter of signed requests from `clock`
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Sign a request using HMAC-SHA256
     ///
     /// BingX uses HMAC-SHA256 for request signing. The signature is generated by:
@@ -70,14 +91,16 @@ impl RestClient {
     ///
     /// # Returns
     /// A result containing the signature as a hex string or an error
-    pub fn sign_request(&self, query_string: &str) -> Result<String, Errors> {
+    pub async fn sign_request(&self, query_string: &str) -> Result<String, Errors> {
         // Sign with HMAC SHA256
-        let api_secret = self.api_secret.expose_secret();
-        let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey)?;
-        mac.update(query_string.as_bytes());
+        let signature = self
+            .api_secret
+            .hmac_sha256(query_string.as_bytes())
+            .await
+            .map_err(|_| Errors::InvalidApiKey)?;
 
         // Return as hex string
-        Ok(hex::encode(mac.finalize().into_bytes()))
+        Ok(hex::encode(signature))
     }
 
     /// Send a request to a private endpoint
@@ -102,7 +125,7 @@ impl RestClient {
             .map_err(|e| Errors::RateLimitExceeded(e.to_string()))?;
 
         // Build query string with required parameters
-        let timestamp = chrono::Utc::now().timestamp_millis();
+        let timestamp = self.clock.now_ms();
         let mut query_params = vec![("timestamp".to_string(), timestamp.to_string())];
 
         // Add optional parameters
@@ -126,7 +149,7 @@ impl RestClient {
             .join("&");
 
         // Generate signature
-        let signature = self.sign_request(&query_string)?;
+        let signature = self.sign_request(&query_string).await?;
         query_params.push(("signature".to_string(), signature));
 
         // Build final query string
@@ -140,7 +163,7 @@ impl RestClient {
         let url = format!("{}{}?{}", self.base_url, endpoint, final_query_string);
 
         // Prepare request
-        let mut request_builder = self.client.request(method, &url);
+        let mut request_builder = HttpRequest::new(method, url);
 
         // Add required headers
         let api_key = self.api_key.expose_secret();
@@ -149,22 +172,18 @@ impl RestClient {
             .header("Content-Type", "application/json");
 
         // Send request
-        let response = request_builder.send().await?;
+        let response = self.client.execute(request_builder).await?;
 
         // Record the request for rate limiting
         self.rate_limiter.increment_request(endpoint_type).await;
 
         // Check if request was successful
-        if response.status().is_success() {
-            let response_text = response.text().await?;
-            let parsed_response: T = serde_json::from_str(&response_text)?;
+        if response.status.is_success() {
+            let parsed_response: T = serde_json::from_str(&response.body)?;
             Ok(parsed_response)
         } else {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
+            let status = response.status;
+            let error_text = response.body;
 
             // Try to parse as BingX error response
             if let Ok(error_response) = serde_json::from_str::<crate::bingx::ErrorResponse>(&error_text) {
@@ -176,8 +195,40 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
     use rest::secrets::ExposableSecret;
 
     use super::*;
@@ -202,7 +253,7 @@ mod tests {
     #[test]
     fn test_private_client_creation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
@@ -217,10 +268,10 @@ mod tests {
         assert_eq!(rest_client.base_url, "https://open-api.bingx.com");
     }
 
-    #[test]
-    fn test_sign_request() {
+    #[tokio::test]
+    async fn test_sign_request() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
@@ -233,7 +284,7 @@ mod tests {
         );
 
         let query_string = "timestamp=1234567890";
-        let signature = rest_client.sign_request(query_string).unwrap();
+        let signature = rest_client.sign_request(query_string).await.unwrap();
 
         // Signature should be a valid hex string
         assert!(signature.chars().all(|c| c.is_ascii_hexdigit()));
@@ -1,9 +1,15 @@
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use reqwest::Client;
+use async_trait::async_trait;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::bingx::errors::VENUE;
 use crate::bingx::{EndpointType, Errors, RateLimiter, RestResult};
 
 /// Public REST client for BingX exchange
@@ -20,7 +26,7 @@ pub struct RestClient {
     /// The underlying HTTP client used for making requests.
     ///
     /// This is reused for connection pooling and performance.
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits.
     ///
@@ -40,12 +46,12 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         base_url: impl Into<Cow<'static, str>>,
-        client: Client,
+        client: impl HttpTransport + 'static,
         rate_limiter: RateLimiter,
     ) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
         }
     }
@@ -79,30 +85,26 @@ impl RestClient {
         let url = format!("{}{}", self.base_url, endpoint);
         
         // Build the request
-        let mut request = self.client.get(&url);
+        let mut request = HttpRequest::new(reqwest::Method::GET, url);
         
         // Add query parameters if provided
         if let Some(params) = params {
-            request = request.query(params);
+            request = request.query(params)?;
         }
 
         // Send the request
-        let response = request.send().await?;
+        let response = self.client.execute(request).await?;
         
         // Record the request for rate limiting
         self.rate_limiter.increment_request(endpoint_type).await;
 
         // Check if request was successful
-        if response.status().is_success() {
-            let response_text = response.text().await?;
-            let parsed_response: T = serde_json::from_str(&response_text)?;
+        if response.status.is_success() {
+            let parsed_response: T = serde_json::from_str(&response.body)?;
             Ok(parsed_response)
         } else {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
+            let status = response.status;
+            let error_text = response.body;
 
             // Try to parse as BingX error response
             if let Ok(error_response) = serde_json::from_str::<crate::bingx::ErrorResponse>(&error_text) {
@@ -114,8 +116,52 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        if request.method != reqwest::Method::GET {
+            return Err(VenueError::new(
+                VENUE,
+                ErrorKind::InvalidRequest,
+                format!(
+                    "{} is not supported by the public REST client",
+                    request.method
+                ),
+            )
+            .into());
+        }
+
+        let start = Instant::now();
+        let data = self
+            .send_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
+
     use super::*;
 
     #[test]
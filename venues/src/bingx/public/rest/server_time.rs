@@ -1,3 +1,6 @@
+use async_trait::async_trait;
+use rest::clock::TimeSource;
+use rest::error::{RestError, VenueError};
 use serde::{Deserialize, Serialize};
 
 use crate::bingx::{EndpointType, RestResult};
@@ -40,6 +43,14 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl TimeSource for RestClient {
+    async fn server_time_ms(&self) -> Result<i64, RestError> {
+        let response = self.get_server_time().await.map_err(VenueError::from)?;
+        Ok(response.server_time)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
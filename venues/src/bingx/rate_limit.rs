@@ -1,9 +1,14 @@
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::rate_limiter::RateLimitStatus;
 use thiserror::Error;
 use tokio::sync::RwLock;
 
+use crate::bingx::errors::VENUE;
+
 /// Types of endpoints for rate limiting based on BingX API documentation
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EndpointType {
@@ -102,6 +107,61 @@ impl Default for RateLimiter {
     }
 }
 
+#[async_trait]
+impl rest::rate_limiter::RateLimiter for RateLimiter {
+    type Key = EndpointType;
+
+    async fn check_limit(&self, _endpoint: &str, endpoint_type: &EndpointType) -> Result<(), RestError> {
+        if let Err(err) = self.check_limits(endpoint_type.clone()).await {
+            let status = self.get_rate_limit_status(endpoint_type).await;
+            let retry_after = Some(status.reset_in);
+            return Err(VenueError::new(
+                VENUE,
+                ErrorKind::RateLimited { retry_after },
+                err.to_string(),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    async fn record_request(&self, _endpoint: &str, endpoint_type: &EndpointType) {
+        self.increment_request(endpoint_type.clone()).await;
+    }
+
+    async fn get_rate_limit_status(&self, endpoint_type: &EndpointType) -> RateLimitStatus {
+        let rate_limit = Self::get_rate_limit(endpoint_type);
+        let history = self.request_history.read().await;
+        let now = Instant::now();
+
+        let in_window: Vec<Instant> = history
+            .get(endpoint_type)
+            .map(|timestamps| {
+                timestamps
+                    .iter()
+                    .copied()
+                    .filter(|&timestamp| now.duration_since(timestamp) < rate_limit.window)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // The window frees up a slot when the oldest request in it expires
+        let reset_in = in_window
+            .iter()
+            .min()
+            .map(|&oldest| rate_limit.window.saturating_sub(now.duration_since(oldest)))
+            .unwrap_or_default();
+
+        RateLimitStatus {
+            remaining: rate_limit
+                .max_requests
+                .saturating_sub(u32::try_from(in_window.len()).unwrap_or(u32::MAX)),
+            limit: rate_limit.max_requests,
+            reset_in,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,4 +234,42 @@ mod tests {
         );
         rate_limiter.increment_request(EndpointType::Trading).await;
     }
+
+    #[tokio::test]
+    async fn test_rest_rate_limiter_trait() {
+        use rest::rate_limiter::RateLimiter as _;
+
+        let limiter = RateLimiter::new();
+        let endpoint_type = EndpointType::Account;
+
+        for _ in 0..5 {
+            limiter
+                .check_limit("/openApi/spot/v1/account/balance", &endpoint_type)
+                .await
+                .unwrap();
+            limiter
+                .record_request("/openApi/spot/v1/account/balance", &endpoint_type)
+                .await;
+        }
+
+        let status = limiter.get_rate_limit_status(&endpoint_type).await;
+        assert_eq!(status.remaining, 0);
+        assert_eq!(status.limit, 5);
+        assert!(status.reset_in <= Duration::from_secs(1));
+
+        let err = limiter
+            .check_limit("/openApi/spot/v1/account/balance", &endpoint_type)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::RateLimited {
+                retry_after: Some(_)
+            }
+        ));
+
+        // Other endpoint types are unaffected
+        let status = limiter.get_rate_limit_status(&EndpointType::Trading).await;
+        assert_eq!(status.remaining, 10);
+    }
 }
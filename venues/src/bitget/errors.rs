@@ -1,8 +1,14 @@
 use std::fmt;
+use std::time::Duration;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::Deserialize;
 use thiserror::Error;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "bitget";
+
 /// Represents all possible errors that can occur when interacting with the Bitget API
 #[derive(Debug)]
 pub enum Errors {
@@ -14,7 +20,7 @@ pub enum Errors {
     /// such as network issues or HTTP errors.
     /// It can be used to wrap any error that occurs during the request process.
     /// This variant is not used for errors returned by the Bitget API itself.
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the Bitget API
     ApiError(ApiError),
@@ -232,3 +238,178 @@ impl From<ErrorResponse> for ApiError {
         }
     }
 }
+
+impl ApiError {
+    /// The Bitget error code this error was created from, or `None` for errors
+    /// derived from the HTTP status alone.
+    ///
+    /// `ParamError` is produced by both 30016 and 400172 and reports 30016.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            ApiError::ChannelDoesNotExist { .. } => Some("30001"),
+            ApiError::IllegalRequest { .. } => Some("30002"),
+            ApiError::InvalidOp { .. } => Some("30003"),
+            ApiError::UserNeedsToLogin { .. } => Some("30004"),
+            ApiError::LoginFailed { .. } => Some("30005"),
+            ApiError::RequestTooMany { .. } => Some("30006"),
+            ApiError::RequestOverLimit { .. } => Some("30007"),
+            ApiError::InvalidAccessKey { .. } => Some("30011"),
+            ApiError::InvalidAccessPassphrase { .. } => Some("30012"),
+            ApiError::InvalidTimestamp { .. } => Some("30013"),
+            ApiError::RequestExpired { .. } => Some("30014"),
+            ApiError::InvalidSignature { .. } => Some("30015"),
+            ApiError::ParamError { .. } => Some("30016"),
+            ApiError::BadSymbol { .. } => Some("70001"),
+            ApiError::InvalidParameter { .. } => Some("70002"),
+            ApiError::MandatoryParamEmptyOrMalformed { .. } => Some("70003"),
+            ApiError::UnknownParam { .. } => Some("70004"),
+            ApiError::ParamEmpty { .. } => Some("70005"),
+            ApiError::BadAsset { .. } => Some("70006"),
+            ApiError::BadApiKeyFmt { .. } => Some("70007"),
+            ApiError::Unauthorized { .. } => Some("70008"),
+            ApiError::TooManyRequests { .. } => Some("70009"),
+            ApiError::NewOrderRejected { .. } => Some("70010"),
+            ApiError::CancelRejected { .. } => Some("70011"),
+            ApiError::NoSuchOrder { .. } => Some("70012"),
+            ApiError::BalanceNotSufficient { .. } => Some("70013"),
+            ApiError::UnableToFill { .. } => Some("70014"),
+            ApiError::MaxOpenOrderExceeded { .. } => Some("70015"),
+            ApiError::UnmappedApiError { code, .. } => Some(code),
+            ApiError::UnknownApiError { .. }
+            | ApiError::Disconnected { .. }
+            | ApiError::IpBanned { .. }
+            | ApiError::RateLimitExceeded { .. }
+            | ApiError::Forbidden { .. }
+            | ApiError::RequestTimeout { .. }
+            | ApiError::InternalServerError { .. }
+            | ApiError::ServiceUnavailable { .. } => None,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::RateLimitExceeded { retry_after, .. } => ErrorKind::RateLimited {
+                retry_after: retry_after.map(Duration::from_secs),
+            },
+            ApiError::TooManyRequests { .. } | ApiError::RequestTooMany { .. } | ApiError::RequestOverLimit { .. } => {
+                ErrorKind::RateLimited { retry_after: None }
+            }
+            ApiError::IpBanned { .. } => ErrorKind::IpBanned { until: None },
+            ApiError::Unauthorized { .. }
+            | ApiError::InvalidTimestamp { .. }
+            | ApiError::InvalidSignature { .. }
+            | ApiError::BadApiKeyFmt { .. }
+            | ApiError::InvalidAccessKey { .. }
+            | ApiError::InvalidAccessPassphrase { .. }
+            | ApiError::RequestExpired { .. }
+            | ApiError::UserNeedsToLogin { .. }
+            | ApiError::LoginFailed { .. }
+            | ApiError::Forbidden { .. } => ErrorKind::AuthFailed,
+            ApiError::BalanceNotSufficient { .. } => ErrorKind::InsufficientFunds,
+            ApiError::NoSuchOrder { .. } => ErrorKind::OrderNotFound,
+            ApiError::Disconnected { .. } => ErrorKind::ExchangeUnavailable,
+            ApiError::UnknownApiError { .. } | ApiError::RequestTimeout { .. } | ApiError::InternalServerError { .. } | ApiError::ServiceUnavailable { .. } => {
+                ErrorKind::UnknownExecutionStatus
+            }
+            ApiError::UnmappedApiError { code, .. } => error_kind(code),
+            _ => ErrorKind::InvalidRequest,
+        }
+    }
+}
+
+/// Classify a Bitget error code that has no dedicated [`ApiError`] variant
+fn error_kind(code: &str) -> ErrorKind {
+    match code {
+        "429" => ErrorKind::RateLimited { retry_after: None },
+        "40006" | "40008" | "40009" | "40012" | "40014" => ErrorKind::AuthFailed,
+        "40010" => ErrorKind::UnknownExecutionStatus,
+        "40762" | "43012" => ErrorKind::InsufficientFunds,
+        "43001" | "43025" => ErrorKind::OrderNotFound,
+        _ => ErrorKind::Other,
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(ApiError::UnmappedApiError { code, msg }) => VenueError::new(VENUE, error_kind(code), msg.clone()).with_code(code),
+            Errors::ApiError(api_error) => {
+                let error = VenueError::new(VENUE, api_error.kind(), api_error.to_string());
+                match api_error.code() {
+                    Some(code) => error.with_code(code),
+                    None => error,
+                }
+            }
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn venue_error(code: &str, msg: &str) -> VenueError {
+        Errors::ApiError(ApiError::from(ErrorResponse {
+            code: code.to_string(),
+            msg: msg.to_string(),
+        }))
+        .into()
+    }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error = venue_error("70012", "Order does not exist");
+        assert_eq!(error.kind, ErrorKind::OrderNotFound);
+        assert_eq!(error.code.as_deref(), Some("70012"));
+        assert_eq!(error.message, "Order does not exist");
+        assert_eq!(error.venue, VENUE);
+
+        let error = venue_error("43012", "Insufficient balance");
+        assert_eq!(error.kind, ErrorKind::InsufficientFunds);
+        assert_eq!(error.code.as_deref(), Some("43012"));
+        assert_eq!(error.message, "Insufficient balance");
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(
+            venue_error("30015", "Invalid sign").kind,
+            ErrorKind::AuthFailed
+        );
+        assert_eq!(
+            venue_error("30007", "Request over limit").kind,
+            ErrorKind::RateLimited { retry_after: None }
+        );
+        assert_eq!(
+            venue_error("70002", "Invalid parameter").kind,
+            ErrorKind::InvalidRequest
+        );
+        assert_eq!(venue_error("99999", "Something new").kind, ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_venue_error_from_http_status_errors() {
+        let error: VenueError = Errors::ApiError(ApiError::RateLimitExceeded {
+            msg: "Too many requests".to_string(),
+            retry_after: Some(2),
+        })
+        .into();
+        assert_eq!(
+            error.kind,
+            ErrorKind::RateLimited {
+                retry_after: Some(Duration::from_secs(2))
+            }
+        );
+        assert_eq!(error.code, None);
+    }
+}
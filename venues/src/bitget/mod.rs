@@ -11,7 +11,7 @@ pub use errors::{ApiError, Errors};
 // Export clients and endpoint types
 pub use private::PrivateRestClient;
 pub use private::{AssetInfo, GetAccountAssetsRequest, GetAccountAssetsResponse};
-pub use rate_limit::{RateLimitHeader, RateLimiter};
+pub use rate_limit::{EndpointLimit, RateLimitHeader, RateLimitInterval, RateLimitType, RateLimiter};
 
 pub use crate::bitget::errors::ErrorResponse;
 
@@ -21,15 +21,19 @@
 //! - UID-based limits for private endpoints
 
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
+use async_trait::async_trait;
 use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use chrono::Utc;
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
-use sha2::Sha256;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 
-use crate::bitget::rate_limit::RateLimiter;
+use crate::bitget::errors::VENUE;
+use crate::bitget::rate_limit::{EndpointLimit, RateLimiter};
 use crate::bitget::{Errors, RestResult};
 
 /// A client for interacting with the Bitget private REST API
@@ -39,13 +43,13 @@ use crate::bitget::{Errors, RestResult};
 #[non_exhaustive]
 pub struct RestClient {
     /// The underlying HTTP client used for making requests.
-    pub(crate) client: Client,
+    pub(crate) client: Arc<dyn HttpTransport>,
     /// The rate limiter for this client.
     pub(crate) rate_limiter: RateLimiter,
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
     /// The encrypted API passphrase.
     pub(crate) api_passphrase: Box<dyn ExposableSecret>,
     /// The base URL for the API.
@@ -57,7 +61,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret  
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `api_passphrase` - The encrypted API passphrase
     /// * `base_url` - The base URL for the API
     /// * `rate_limiter` - The rate limiter instance
@@ -67,14 +71,14 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         api_passphrase: Box<dyn ExposableSecret>,
         base_url: impl Into<Cow<'static, str>>,
         rate_limiter: RateLimiter,
-        client: Client,
+        client: impl HttpTransport + 'static,
     ) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             rate_limiter,
             api_key,
             api_secret,
@@ -89,7 +93,14 @@ impl RestClient {
     /// 1. Creating the string: timestamp + method + requestPath + queryString + body
     /// 2. HMAC SHA256 with the API secret
     /// 3. Base64 encoding the result
-    fn generate_signature(&self, timestamp: i64, method: &str, request_path: &str, query_string: Option<&str>, body: Option<&str>) -> Result<String, Errors> {
+    async fn generate_signature(
+        &self,
+        timestamp: i64,
+        method: &str,
+        request_path: &str,
+        query_string: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<String, Errors> {
         let query_part = match query_string {
             Some(q) if !q.is_empty() => format!("?{}", q),
             _ => String::new(),
@@ -106,12 +117,12 @@ impl RestClient {
             body_part
         );
 
-        let mut mac =
-            Hmac::<Sha256>::new_from_slice(self.api_secret.expose_secret().as_bytes()).map_err(|e| Errors::Error(format!("Failed to create HMAC: {}", e)))?;
-
-        mac.update(sign_string.as_bytes());
-        let result = mac.finalize();
-        let signature = BASE64.encode(result.into_bytes());
+        let signature = self
+            .api_secret
+            .hmac_sha256(sign_string.as_bytes())
+            .await
+            .map_err(|e| Errors::Error(format!("Failed to create HMAC: {}", e)))?;
+        let signature = BASE64.encode(signature);
 
         Ok(signature)
     }
@@ -153,7 +164,9 @@ impl RestClient {
         let timestamp = Utc::now().timestamp_millis();
 
         // Generate signature
-        let signature = self.generate_signature(timestamp, method.as_str(), endpoint, query_string, body)?;
+        let signature = self
+            .generate_signature(timestamp, method.as_str(), endpoint, query_string, body)
+            .await?;
 
         // Build URL
         let url = match query_string {
@@ -162,9 +175,7 @@ impl RestClient {
         };
 
         // Build request
-        let mut request_builder = self
-            .client
-            .request(method, &url)
+        let mut request_builder = HttpRequest::new(method, url)
             .header("ACCESS-KEY", self.api_key.expose_secret())
             .header("ACCESS-SIGN", signature)
             .header("ACCESS-TIMESTAMP", timestamp.to_string())
@@ -180,7 +191,11 @@ impl RestClient {
 
         // Execute request
         let start_time = std::time::Instant::now();
-        let response = request_builder.send().await.map_err(Errors::HttpError)?;
+        let response = self
+            .client
+            .execute(request_builder)
+            .await
+            .map_err(Errors::HttpError)?;
         let _request_duration = start_time.elapsed();
 
         // Update rate limiter counters
@@ -190,8 +205,8 @@ impl RestClient {
         }
 
         // Handle HTTP status codes
-        let status = response.status();
-        let response_text = response.text().await.map_err(Errors::HttpError)?;
+        let status = response.status;
+        let response_text = response.body;
 
         // Parse response
         if status.is_success() {
@@ -267,7 +282,57 @@ impl RestClient {
 struct BitgetResponse<T> {
     code: String,
     msg: String,
-    #[serde(rename = "requestTime")]
-    request_time: i64,
     data: T,
 }
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointLimit>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+
+        // GET parameters go in the query string, everything else in a JSON body
+        let (query_string, body) = match &request.params {
+            Some(params) if request.method == reqwest::Method::GET => {
+                let query_string = serde_urlencoded::to_string(params).map_err(|e| {
+                    VenueError::new(
+                        VENUE,
+                        ErrorKind::InvalidRequest,
+                        format!("Failed to encode query parameters: {}", e),
+                    )
+                })?;
+                (Some(query_string), None)
+            }
+            Some(params) => (None, Some(params.to_string())),
+            None => (None, None),
+        };
+
+        let limit = request.rate_limit_key;
+        let response = self
+            .send_signed_request::<serde_json::Value>(
+                &request.endpoint,
+                request.method,
+                query_string.as_deref(),
+                body.as_deref(),
+                limit.per_second,
+                limit.is_order(),
+                limit.order_per_second,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(response.data, start.elapsed()))
+    }
+}
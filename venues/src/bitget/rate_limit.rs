@@ -2,6 +2,9 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
+use rest::error::{RestError, VenueError};
+use rest::rate_limiter::RateLimitStatus;
 use serde::Deserialize;
 use tokio::sync::RwLock;
 
@@ -113,6 +116,37 @@ impl std::fmt::Display for RateLimitHeader {
     }
 }
 
+/// The limits a Bitget request counts against, as documented for each endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndpointLimit {
+    /// Requests per second allowed for the endpoint
+    pub per_second: u32,
+    /// Orders per second allowed (per UID), for order endpoints
+    pub order_per_second: Option<u32>,
+}
+
+impl EndpointLimit {
+    /// A non-order endpoint limited to `per_second` requests per second
+    pub fn new(per_second: u32) -> Self {
+        Self {
+            per_second,
+            order_per_second: None,
+        }
+    }
+
+    /// An order endpoint limited to `per_second` requests and `order_per_second` orders per second
+    pub fn order(per_second: u32, order_per_second: u32) -> Self {
+        Self {
+            per_second,
+            order_per_second: Some(order_per_second),
+        }
+    }
+
+    pub fn is_order(&self) -> bool {
+        self.order_per_second.is_some()
+    }
+}
+
 /// Tracks the current usage of rate limits for Bitget API (rolling windows)
 #[derive(Debug, Default, Clone)]
 pub struct RateLimitUsage {
@@ -156,6 +190,7 @@ impl RateLimiter {
 
         // Track for 1-second window (endpoint-specific limits)
         usage.request_timestamps_1s.push_back(now);
+        #[allow(clippy::arithmetic_side_effects)]
         Self::trim_older_than(
             &mut usage.request_timestamps_1s,
             now - Duration::from_secs(1),
@@ -163,6 +198,7 @@ impl RateLimiter {
 
         // Track for 1-minute window (overall IP limit)
         usage.request_timestamps_1m.push_back(now);
+        #[allow(clippy::arithmetic_side_effects)]
         Self::trim_older_than(
             &mut usage.request_timestamps_1m,
             now - Duration::from_secs(60),
@@ -175,6 +211,7 @@ impl RateLimiter {
         let now = Instant::now();
         usage.order_timestamps_1s.push_back(now);
         // Remove timestamps older than 1 second
+        #[allow(clippy::arithmetic_side_effects)]
         Self::trim_older_than(&mut usage.order_timestamps_1s, now - Duration::from_secs(1));
     }
 
@@ -209,20 +246,93 @@ impl RateLimiter {
         }
 
         // Order-specific limits (UID-based)
-        if is_order {
-            if let Some(order_limit) = order_limit_per_second {
-                if usage.order_timestamps_1s.len() >= order_limit as usize {
-                    return Err(Errors::ApiError(ApiError::TooManyRequests {
-                        msg: format!("Order rate limit ({}/1s) exceeded", order_limit),
-                    }));
-                }
-            }
+        if is_order
+            && let Some(order_limit) = order_limit_per_second
+            && usage.order_timestamps_1s.len() >= order_limit as usize
+        {
+            return Err(Errors::ApiError(ApiError::TooManyRequests {
+                msg: format!("Order rate limit ({}/1s) exceeded", order_limit),
+            }));
         }
 
         Ok(())
     }
 }
 
+/// Remaining requests in a rolling window, the window's limit and the time until
+/// the oldest request in it expires
+fn window_status(timestamps: &VecDeque<Instant>, limit: u32, window: Duration, now: Instant) -> RateLimitStatus {
+    let in_window = timestamps
+        .iter()
+        .filter(|&&timestamp| now.duration_since(timestamp) < window);
+    let reset_in = in_window
+        .clone()
+        .min()
+        .map(|&oldest| window.saturating_sub(now.duration_since(oldest)))
+        .unwrap_or_default();
+    RateLimitStatus {
+        remaining: limit.saturating_sub(u32::try_from(in_window.count()).unwrap_or(u32::MAX)),
+        limit,
+        reset_in,
+    }
+}
+
+#[async_trait]
+impl rest::rate_limiter::RateLimiter for RateLimiter {
+    type Key = EndpointLimit;
+
+    async fn check_limit(&self, _endpoint: &str, limit: &EndpointLimit) -> Result<(), RestError> {
+        self.check_limits(limit.per_second, limit.is_order(), limit.order_per_second)
+            .await
+            .map_err(|err| VenueError::from(err).into())
+    }
+
+    async fn record_request(&self, _endpoint: &str, limit: &EndpointLimit) {
+        self.increment_request().await;
+        if limit.is_order() {
+            self.increment_order().await;
+        }
+    }
+
+    /// Status of whichever of the IP, endpoint and order limits is closest to being exhausted
+    async fn get_rate_limit_status(&self, limit: &EndpointLimit) -> RateLimitStatus {
+        let usage = self.usage.read().await;
+        let now = Instant::now();
+
+        let mut statuses = vec![
+            window_status(
+                &usage.request_timestamps_1m,
+                6000,
+                Duration::from_secs(60),
+                now,
+            ),
+            window_status(
+                &usage.request_timestamps_1s,
+                limit.per_second,
+                Duration::from_secs(1),
+                now,
+            ),
+        ];
+        if let Some(order_per_second) = limit.order_per_second {
+            statuses.push(window_status(
+                &usage.order_timestamps_1s,
+                order_per_second,
+                Duration::from_secs(1),
+                now,
+            ));
+        }
+
+        statuses
+            .into_iter()
+            .min_by_key(|status| status.remaining)
+            .unwrap_or(RateLimitStatus {
+                remaining: limit.per_second,
+                limit: limit.per_second,
+                reset_in: Duration::ZERO,
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,4 +421,40 @@ mod tests {
         assert_eq!(order_1s.interval_value, 1);
         assert_eq!(order_1s.interval_unit, IntervalUnit::Second);
     }
+
+    #[tokio::test]
+    async fn test_rest_rate_limiter_trait() {
+        use rest::error::ErrorKind;
+        use rest::rate_limiter::RateLimiter as _;
+
+        let limiter = RateLimiter::new();
+        let limit = EndpointLimit::order(10, 2);
+
+        for _ in 0..2 {
+            limiter
+                .check_limit("/api/v2/spot/trade/place-order", &limit)
+                .await
+                .unwrap();
+            limiter
+                .record_request("/api/v2/spot/trade/place-order", &limit)
+                .await;
+        }
+
+        // The order limit is the tightest
+        let status = limiter.get_rate_limit_status(&limit).await;
+        assert_eq!(status.remaining, 0);
+        assert_eq!(status.limit, 2);
+        assert!(status.reset_in <= Duration::from_secs(1));
+
+        let err = limiter
+            .check_limit("/api/v2/spot/trade/place-order", &limit)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::RateLimited { .. }));
+
+        // Non-order requests only count against the endpoint limit
+        let status = limiter.get_rate_limit_status(&EndpointLimit::new(10)).await;
+        assert_eq!(status.remaining, 8);
+        assert_eq!(status.limit, 10);
+    }
 }
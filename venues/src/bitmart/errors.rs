@@ -1,8 +1,13 @@
 use std::fmt;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::Deserialize;
 use thiserror::Error;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "bitmart";
+
 /// Represents all possible errors that can occur when interacting with the BitMart API
 #[derive(Debug)]
 pub enum Errors {
@@ -14,7 +19,7 @@ pub enum Errors {
     /// such as network issues or HTTP errors.
     /// It can be used to wrap any error that occurs during the request process.
     /// This variant is not used for errors returned by the BitMart API itself.
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the BitMart API
     ApiError(ApiError),
@@ -36,9 +41,15 @@ impl fmt::Display for Errors {
 
 impl std::error::Error for Errors {}
 
+impl From<TransportError> for Errors {
+    fn from(err: TransportError) -> Self {
+        Errors::HttpError(err)
+    }
+}
+
 impl From<reqwest::Error> for Errors {
     fn from(err: reqwest::Error) -> Self {
-        Errors::HttpError(err)
+        Errors::HttpError(TransportError::from(err))
     }
 }
 
@@ -200,6 +211,94 @@ impl From<ErrorResponse> for ApiError {
     }
 }
 
+impl ApiError {
+    /// The BitMart error code this error was created from
+    pub fn code(&self) -> i32 {
+        match self {
+            ApiError::Success => 1000,
+            ApiError::NotFound => 30000,
+            ApiError::EmptyApiKey => 30001,
+            ApiError::ApiKeyNotFound => 30002,
+            ApiError::ApiKeyFrozen => 30003,
+            ApiError::EmptySignature => 30004,
+            ApiError::InvalidSignature => 30005,
+            ApiError::EmptyTimestamp => 30006,
+            ApiError::TimestampOutOfRange => 30007,
+            ApiError::InvalidTimestampFormat => 30008,
+            ApiError::IpForbidden => 30010,
+            ApiError::ApiKeyExpired => 30011,
+            ApiError::ApiKeyRequestForbidden => 30012,
+            ApiError::TooManyRequests => 30013,
+            ApiError::ServiceUnavailable => 30014,
+            ApiError::ServiceMaintenance => 30016,
+            ApiError::AccountRequestRejected => 30017,
+            ApiError::InvalidJsonFormat => 30018,
+            ApiError::InsufficientPermissions => 30019,
+            ApiError::EndpointDeprecated => 30031,
+            ApiError::InvalidParameter => 50000,
+            ApiError::InsufficientBalance => 50101,
+            ApiError::OrderNotFound => 50104,
+            ApiError::InvalidOrderType => 50004,
+            ApiError::InvalidOrderSide => 50005,
+            ApiError::InvalidPrice => 50007,
+            ApiError::InvalidSize => 50006,
+            ApiError::SymbolNotFound => 50002,
+            ApiError::UnmappedApiError { code, .. } => *code,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::Success | ApiError::UnmappedApiError { .. } => ErrorKind::Other,
+            ApiError::EmptyApiKey
+            | ApiError::ApiKeyNotFound
+            | ApiError::ApiKeyFrozen
+            | ApiError::EmptySignature
+            | ApiError::InvalidSignature
+            | ApiError::EmptyTimestamp
+            | ApiError::TimestampOutOfRange
+            | ApiError::InvalidTimestampFormat
+            | ApiError::IpForbidden
+            | ApiError::ApiKeyExpired
+            | ApiError::ApiKeyRequestForbidden
+            | ApiError::AccountRequestRejected
+            | ApiError::InsufficientPermissions => ErrorKind::AuthFailed,
+            ApiError::TooManyRequests => ErrorKind::RateLimited { retry_after: None },
+            ApiError::ServiceUnavailable | ApiError::ServiceMaintenance => ErrorKind::ExchangeUnavailable,
+            ApiError::InsufficientBalance => ErrorKind::InsufficientFunds,
+            ApiError::OrderNotFound => ErrorKind::OrderNotFound,
+            ApiError::NotFound
+            | ApiError::InvalidJsonFormat
+            | ApiError::EndpointDeprecated
+            | ApiError::InvalidParameter
+            | ApiError::InvalidOrderType
+            | ApiError::InvalidOrderSide
+            | ApiError::InvalidPrice
+            | ApiError::InvalidSize
+            | ApiError::SymbolNotFound => ErrorKind::InvalidRequest,
+        }
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(ApiError::UnmappedApiError { code, message }) => VenueError::new(VENUE, ErrorKind::Other, message.clone()).with_code(code),
+            Errors::ApiError(api_error) => VenueError::new(VENUE, api_error.kind(), api_error.to_string()).with_code(api_error.code()),
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,4 +356,45 @@ mod tests {
         let error_string = format!("{}", invalid_key_error);
         assert_eq!(error_string, "Invalid API key or signature");
     }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            code: 50104,
+            message: "Order not found".to_string(),
+            trace: String::new(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::OrderNotFound);
+        assert_eq!(error.code.as_deref(), Some("50104"));
+        assert_eq!(error.venue, VENUE);
+
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            code: 60000,
+            message: "Something new".to_string(),
+            trace: String::new(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::Other);
+        assert_eq!(error.code.as_deref(), Some("60000"));
+        assert_eq!(error.message, "Something new");
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(
+            ApiError::TooManyRequests.kind(),
+            ErrorKind::RateLimited { retry_after: None }
+        );
+        assert_eq!(ApiError::InvalidSignature.kind(), ErrorKind::AuthFailed);
+        assert_eq!(
+            ApiError::InsufficientBalance.kind(),
+            ErrorKind::InsufficientFunds
+        );
+        assert_eq!(
+            ApiError::ServiceMaintenance.kind(),
+            ErrorKind::ExchangeUnavailable
+        );
+        assert_eq!(ApiError::InvalidPrice.kind(), ErrorKind::InvalidRequest);
+    }
 }
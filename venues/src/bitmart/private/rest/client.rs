@@ -15,15 +15,20 @@
 //!   - `X-BM-RateLimit-Reset`: Current time window in seconds
 
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
+use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose};
-use hmac::{Hmac, Mac};
-use reqwest::{Client, Method};
-use rest::secrets::ExposableSecret;
+use reqwest::Method;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Deserialize;
 use serde::de::DeserializeOwned;
-use sha2::Sha256;
 
+use crate::bitmart::errors::VENUE;
 use crate::bitmart::rate_limit::{EndpointType, RateLimiter};
 use crate::bitmart::{Errors, RestResult};
 
@@ -31,12 +36,12 @@ use crate::bitmart::{Errors, RestResult};
 pub struct RestClient {
     /// The encrypted API key
     api_key: Box<dyn ExposableSecret>,
-    /// The encrypted API secret for signing requests
-    api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    api_secret: Box<dyn HmacSigner>,
     /// The base URL for the BitMart private REST API
     base_url: Cow<'static, str>,
     /// HTTP client for making requests
-    client: Client,
+    client: Arc<dyn HttpTransport>,
     /// Rate limiter for managing API limits
     rate_limiter: RateLimiter,
 }
@@ -56,7 +61,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `client` - The HTTP client to use for requests
     /// * `rate_limiter` - The rate limiter for managing API limits
@@ -65,16 +70,16 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
-        client: Client,
+        client: impl HttpTransport + 'static,
         rate_limiter: RateLimiter,
     ) -> Self {
         Self {
             api_key,
             api_secret,
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
         }
     }
@@ -94,17 +99,19 @@ impl RestClient {
     ///
     /// # Returns
     /// A result containing the signature as a base64 string or an error
-    pub fn sign_request(&self, timestamp: &str, method: &str, request_path: &str, body: &str) -> Result<String, Errors> {
+    pub async fn sign_request(&self, timestamp: &str, method: &str, request_path: &str, body: &str) -> Result<String, Errors> {
         // Create the message string: timestamp + method + requestPath + body
         let message = format!("{}{}{}{}", timestamp, method, request_path, body);
 
         // Sign with HMAC SHA256
-        let api_secret = self.api_secret.expose_secret();
-        let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-        mac.update(message.as_bytes());
+        let signature = self
+            .api_secret
+            .hmac_sha256(message.as_bytes())
+            .await
+            .map_err(|_| Errors::InvalidApiKey())?;
 
         // Encode as Base64
-        Ok(general_purpose::STANDARD.encode(mac.finalize().into_bytes()))
+        Ok(general_purpose::STANDARD.encode(signature))
     }
 
     /// Send a request to a private endpoint
@@ -163,30 +170,30 @@ impl RestClient {
         let timestamp = chrono::Utc::now().timestamp_millis().to_string();
 
         // Create signature
-        let signature = self.sign_request(&timestamp, method.as_str(), &request_path, &body_str)?;
+        let signature = self
+            .sign_request(&timestamp, method.as_str(), &request_path, &body_str)
+            .await?;
 
         // Build request
-        let mut request_builder = self
-            .client
-            .request(method.clone(), &url)
+        let mut request_builder = HttpRequest::new(method.clone(), url)
             .header("X-BM-KEY", self.api_key.expose_secret())
             .header("X-BM-SIGN", signature)
             .header("X-BM-TIMESTAMP", timestamp)
             .header("Content-Type", "application/json");
 
         // Add body if present and not GET/DELETE
-        if !body_str.is_empty() && !(method == Method::GET || method == Method::DELETE) {
+        if !(body_str.is_empty() || method == Method::GET || method == Method::DELETE) {
             request_builder = request_builder.body(body_str.clone());
         }
 
         // Send request
-        let response = request_builder.send().await?;
+        let response = self.client.execute(request_builder).await?;
 
         // Record the request for rate limiting
         self.rate_limiter.increment_request(endpoint_type).await;
 
         // Parse response
-        let response_text = response.text().await?;
+        let response_text = response.body;
         let bitmart_response: BitMartResponse<T> = serde_json::from_str(&response_text).map_err(|e| {
             Errors::Error(format!(
                 "Failed to parse response: {} - Response: {}",
@@ -211,8 +218,40 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_request::<serde_json::Value, serde_json::Value>(
+                &request.endpoint,
+                request.method,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
     use rest::secrets::ExposableSecret;
 
     use super::*;
@@ -238,7 +277,7 @@ mod tests {
     #[test]
     fn test_private_client_creation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
@@ -253,10 +292,10 @@ mod tests {
         assert_eq!(rest_client.base_url, "https://api-cloud.bitmart.com");
     }
 
-    #[test]
-    fn test_signature_generation() {
+    #[tokio::test]
+    async fn test_signature_generation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
@@ -275,6 +314,7 @@ mod tests {
 
         let signature = rest_client
             .sign_request(timestamp, method, request_path, body)
+            .await
             .unwrap();
 
         // Verify the signature is a valid base64 string
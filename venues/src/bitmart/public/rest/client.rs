@@ -13,11 +13,18 @@
 //! - **Base URL**: Uses https://api-cloud.bitmart.com for public endpoints
 
 use std::borrow::Cow;
-
-use reqwest::{Client, Method};
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use reqwest::Method;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Deserialize;
 use serde::de::DeserializeOwned;
 
+use crate::bitmart::errors::VENUE;
 use crate::bitmart::rate_limit::{EndpointType, RateLimiter};
 use crate::bitmart::{Errors, RestResult};
 
@@ -26,7 +33,7 @@ pub struct RestClient {
     /// The base URL for the BitMart public REST API
     base_url: Cow<'static, str>,
     /// HTTP client for making requests
-    client: Client,
+    client: Arc<dyn HttpTransport>,
     /// Rate limiter for managing API limits
     rate_limiter: RateLimiter,
 }
@@ -51,12 +58,12 @@ impl RestClient {
     /// * `rate_limiter` - Rate limiter for managing API limits
     pub fn new(
         base_url: impl Into<Cow<'static, str>>,
-        client: Client,
+        client: impl HttpTransport + 'static,
         rate_limiter: RateLimiter,
     ) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
         }
     }
@@ -91,8 +98,8 @@ impl RestClient {
         let url = format!("{}{}", self.base_url, endpoint);
 
         // Build URL with query parameters for GET requests
-        let final_url = if method == Method::GET && request.is_some() {
-            let query_params = serde_urlencoded::to_string(request.unwrap())
+        let final_url = if let Some(request) = request.filter(|_| method == Method::GET) {
+            let query_params = serde_urlencoded::to_string(request)
                 .map_err(|e| Errors::Error(format!("Failed to serialize query parameters: {}", e)))?;
             
             if query_params.is_empty() {
@@ -105,23 +112,23 @@ impl RestClient {
         };
 
         // Build request
-        let mut request_builder = self.client.request(method.clone(), &final_url);
+        let mut request_builder = HttpRequest::new(method.clone(), final_url);
 
         // Add body for non-GET requests
-        if method != Method::GET && request.is_some() {
+        if let Some(request) = request.filter(|_| method != Method::GET) {
             request_builder = request_builder
                 .header("Content-Type", "application/json")
-                .json(request.unwrap());
+                .json(request)?;
         }
 
         // Send request
-        let response = request_builder.send().await?;
+        let response = self.client.execute(request_builder).await?;
 
         // Record the request for rate limiting
         self.rate_limiter.increment_request(endpoint_type).await;
 
         // Parse response
-        let response_text = response.text().await?;
+        let response_text = response.body;
         let bitmart_response: BitMartResponse<T> = serde_json::from_str(&response_text).map_err(|e| {
             Errors::Error(format!(
                 "Failed to parse response: {} - Response: {}",
@@ -146,8 +153,41 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_request::<serde_json::Value, serde_json::Value>(
+                &request.endpoint,
+                request.method,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
+
     use super::*;
 
     #[test]
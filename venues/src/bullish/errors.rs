@@ -2,9 +2,14 @@
 
 use std::fmt;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "bullish";
+
 /// Comprehensive error type for Bullish API operations
 #[derive(Error, Debug)]
 pub enum Errors {
@@ -12,7 +17,7 @@ pub enum Errors {
     ApiError(#[from] ApiError),
 
     #[error("HTTP Error: {0}")]
-    HttpError(#[from] reqwest::Error),
+    HttpError(#[from] TransportError),
 
     #[error("JSON Parsing Error: {0}")]
     JsonError(#[from] serde_json::Error),
@@ -30,6 +35,12 @@ pub enum Errors {
     Error(String),
 }
 
+impl From<reqwest::Error> for Errors {
+    fn from(err: reqwest::Error) -> Self {
+        Errors::HttpError(TransportError::from(err))
+    }
+}
+
 /// API error from Bullish exchange
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiError {
@@ -57,6 +68,52 @@ pub struct ErrorResponse {
     pub error: ApiError,
 }
 
+impl ApiError {
+    /// The venue-agnostic classification of this error, based on the error code name
+    pub fn kind(&self) -> ErrorKind {
+        let code = self.code.to_ascii_uppercase();
+        if code.contains("RATE_LIMIT") || code.contains("TOO_MANY") {
+            ErrorKind::RateLimited { retry_after: None }
+        } else if code.contains("UNAUTHORIZED") || code.contains("AUTH") || code.contains("JWT") || code.contains("SIGNATURE") || code.contains("API_KEY") {
+            ErrorKind::AuthFailed
+        } else if code.contains("INSUFFICIENT") {
+            ErrorKind::InsufficientFunds
+        } else if code.contains("ORDER_NOT_FOUND") {
+            ErrorKind::OrderNotFound
+        } else if code.contains("MAINTENANCE") || code.contains("UNAVAILABLE") {
+            ErrorKind::ExchangeUnavailable
+        } else if code.contains("INVALID") || code.contains("NOT_FOUND") {
+            ErrorKind::InvalidRequest
+        } else {
+            ErrorKind::Other
+        }
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::ApiError(api_error) => VenueError::new(VENUE, api_error.kind(), api_error.message.clone()).with_code(&api_error.code),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            // Rejected locally before anything was sent
+            Errors::RateLimitError(msg) => VenueError::new(
+                VENUE,
+                ErrorKind::RateLimited { retry_after: None },
+                msg.clone(),
+            ),
+            Errors::AuthenticationError(msg) => VenueError::new(VENUE, ErrorKind::AuthFailed, msg.clone()),
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::JsonError(_) | Errors::Error(_) => VenueError::new(VENUE, ErrorKind::Other, err.to_string()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -121,4 +178,41 @@ mod tests {
         let invalid_key_error = Errors::InvalidApiKey();
         assert!(invalid_key_error.to_string().contains("Invalid API Key"));
     }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error: VenueError = Errors::ApiError(ApiError {
+            code: "INSUFFICIENT_BALANCE".to_string(),
+            message: "Insufficient balance for trade".to_string(),
+            details: None,
+        })
+        .into();
+        assert_eq!(error.kind, ErrorKind::InsufficientFunds);
+        assert_eq!(error.code.as_deref(), Some("INSUFFICIENT_BALANCE"));
+        assert_eq!(error.message, "Insufficient balance for trade");
+        assert_eq!(error.venue, VENUE);
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        let kind = |code: &str| {
+            ApiError {
+                code: code.to_string(),
+                message: String::new(),
+                details: None,
+            }
+            .kind()
+        };
+        assert_eq!(kind("INVALID_SYMBOL"), ErrorKind::InvalidRequest);
+        assert_eq!(kind("ORDER_NOT_FOUND"), ErrorKind::OrderNotFound);
+        assert_eq!(
+            kind("RATE_LIMIT_EXCEEDED"),
+            ErrorKind::RateLimited { retry_after: None }
+        );
+        assert_eq!(kind("INVALID_JWT"), ErrorKind::AuthFailed);
+        assert_eq!(kind("SOMETHING_NEW"), ErrorKind::Other);
+
+        let error = VenueError::from(Errors::RateLimitError("Rate limit exceeded".to_string()));
+        assert_eq!(error.kind, ErrorKind::RateLimited { retry_after: None });
+    }
 }
@@ -1,16 +1,21 @@
 //! Bullish Private REST API client
 
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
+use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose};
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use sha2::Sha256;
+use tokio::sync::RwLock;
 
+use crate::bullish::errors::VENUE;
 use crate::bullish::{EndpointType, Errors, RateLimiter, RestResult};
 
 /// Private REST client for Bullish exchange
@@ -19,17 +24,17 @@ use crate::bullish::{EndpointType, Errors, RateLimiter, RestResult};
 /// It provides automatic rate limiting, error handling, and JWT token management.
 pub struct RestClient {
     /// The underlying HTTP client used for making requests
-    pub(crate) client: Client,
+    pub(crate) client: Arc<dyn HttpTransport>,
     /// The API key for authentication
     pub(crate) api_key: Box<dyn ExposableSecret>,
-    /// The API secret for HMAC signing
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
     /// The base URL for the API
     pub(crate) base_url: Cow<'static, str>,
     /// Rate limiter for API requests
     pub(crate) rate_limiter: RateLimiter,
     /// Current JWT token (cached)
-    pub(crate) jwt_token: Option<String>,
+    pub(crate) jwt_token: RwLock<Option<String>>,
 }
 
 impl RestClient {
@@ -37,7 +42,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The API key for authentication
-    /// * `api_secret` - The API secret for HMAC signing
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `client` - The HTTP client to use
     /// * `rate_limiter` - Rate limiter for requests
@@ -46,18 +51,18 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
-        client: Client,
+        client: impl HttpTransport + 'static,
         rate_limiter: RateLimiter,
     ) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             api_key,
             api_secret,
             base_url: base_url.into(),
             rate_limiter,
-            jwt_token: None,
+            jwt_token: RwLock::new(None),
         }
     }
 
@@ -68,7 +73,7 @@ impl RestClient {
     ///
     /// # Returns
     /// A JWT token valid for 24 hours
-    pub async fn get_jwt_token(&mut self) -> RestResult<String> {
+    pub async fn get_jwt_token(&self) -> RestResult<String> {
         // Check rate limits
         self.rate_limiter
             .check_limits(EndpointType::PrivateLogin)
@@ -79,37 +84,36 @@ impl RestClient {
         let message = format!("GET/trading-api/v1/users/hmac/login{}", nonce);
 
         // Sign the message with HMAC-SHA256
-        let mut mac = Hmac::<Sha256>::new_from_slice(self.api_secret.expose_secret().as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-        mac.update(message.as_bytes());
-        let signature = general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+        let signature = self
+            .api_secret
+            .hmac_sha256(message.as_bytes())
+            .await
+            .map_err(|_| Errors::InvalidApiKey())?;
+        let signature = general_purpose::STANDARD.encode(signature);
 
         let url = format!("{}/trading-api/v1/users/hmac/login", self.base_url);
 
-        let response = self
-            .client
-            .get(&url)
+        let request = HttpRequest::new(reqwest::Method::GET, url)
             .header("BX-KEY", self.api_key.expose_secret())
             .header("BX-SIGNATURE", signature)
-            .header("BX-NONCE", nonce.to_string())
-            .send()
-            .await?;
+            .header("BX-NONCE", nonce.to_string());
+        let response = self.client.execute(request).await?;
 
         self.rate_limiter
             .increment_request(EndpointType::PrivateLogin)
             .await;
 
-        if !response.status().is_success() {
-            let error_text = response.text().await?;
+        if !response.status.is_success() {
             return Err(Errors::AuthenticationError(format!(
                 "Login failed: {}",
-                error_text
+                response.body
             )));
         }
 
-        let result: Value = response.json().await?;
+        let result: Value = serde_json::from_str(&response.body)?;
 
         if let Some(token) = result.get("token").and_then(|t| t.as_str()) {
-            self.jwt_token = Some(token.to_string());
+            *self.jwt_token.write().await = Some(token.to_string());
             Ok(token.to_string())
         } else {
             Err(Errors::AuthenticationError(
@@ -131,7 +135,7 @@ impl RestClient {
     /// # Returns
     /// The deserialized response or an error
     pub async fn send_authenticated_request<T, B>(
-        &mut self,
+        &self,
         endpoint: &str,
         method: reqwest::Method,
         body: Option<&B>,
@@ -148,72 +152,107 @@ impl RestClient {
             .map_err(|e| Errors::RateLimitError(e.to_string()))?;
 
         // Ensure we have a valid JWT token
-        if self.jwt_token.is_none() {
-            self.get_jwt_token().await?;
-        }
+        let cached_token = self.jwt_token.read().await.clone();
+        let token = match cached_token {
+            Some(token) => token,
+            None => self.get_jwt_token().await?,
+        };
 
         let url = format!("{}/trading-api{}", self.base_url, endpoint);
-        let token = self.jwt_token.as_ref().unwrap();
 
-        let mut request = self
-            .client
-            .request(method.clone(), &url)
+        let mut request = HttpRequest::new(method.clone(), url.clone())
             .header("Authorization", format!("Bearer {}", token))
             .header("Content-Type", "application/json");
 
         if let Some(body_data) = body {
-            request = request.json(body_data);
+            request = request.json(body_data)?;
         }
 
-        let response = request.send().await?;
+        let response = self.client.execute(request).await?;
 
         self.rate_limiter.increment_request(endpoint_type).await;
 
         // Handle 401 Unauthorized - token might be expired
-        if response.status() == 401 {
+        if response.status == 401 {
             // Try to refresh token once
-            self.jwt_token = None;
-            self.get_jwt_token().await?;
+            *self.jwt_token.write().await = None;
+            let token = self.get_jwt_token().await?;
 
             // Retry the request with new token
-            let token = self.jwt_token.as_ref().unwrap();
-            let mut retry_request = self
-                .client
-                .request(method, &url)
+            let mut retry_request = HttpRequest::new(method, url)
                 .header("Authorization", format!("Bearer {}", token))
                 .header("Content-Type", "application/json");
 
             if let Some(body_data) = body {
-                retry_request = retry_request.json(body_data);
+                retry_request = retry_request.json(body_data)?;
             }
 
-            let retry_response = retry_request.send().await?;
+            let retry_response = self.client.execute(retry_request).await?;
             self.rate_limiter.increment_request(endpoint_type).await;
 
-            if !retry_response.status().is_success() {
-                let error_text = retry_response.text().await?;
+            if !retry_response.status.is_success() {
+                let error_text = retry_response.body;
+                if let Ok(error_response) = serde_json::from_str::<crate::bullish::ErrorResponse>(&error_text) {
+                    return Err(Errors::ApiError(error_response.error));
+                }
                 return Err(Errors::Error(format!(
                     "Request failed after token refresh: {}",
                     error_text
                 )));
             }
 
-            let result: T = retry_response.json().await?;
+            let result: T = serde_json::from_str(&retry_response.body)?;
             return Ok(result);
         }
 
-        if !response.status().is_success() {
-            let error_text = response.text().await?;
+        if !response.status.is_success() {
+            let error_text = response.body;
+            if let Ok(error_response) = serde_json::from_str::<crate::bullish::ErrorResponse>(&error_text) {
+                return Err(Errors::ApiError(error_response.error));
+            }
             return Err(Errors::Error(format!("Request failed: {}", error_text)));
         }
 
-        let result: T = response.json().await?;
+        let result: T = serde_json::from_str(&response.body)?;
         Ok(result)
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_authenticated_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
+
     use super::*;
 
     /// A plain text implementation of ExposableSecret for testing purposes.
@@ -237,7 +276,7 @@ mod tests {
     #[test]
     fn test_private_client_creation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
@@ -250,7 +289,7 @@ mod tests {
         );
 
         assert_eq!(rest_client.base_url, "https://api.exchange.bullish.com");
-        assert!(rest_client.jwt_token.is_none());
+        assert!(rest_client.jwt_token.try_read().unwrap().is_none());
     }
 
     #[test]
@@ -264,7 +303,7 @@ mod tests {
     #[tokio::test]
     async fn test_rate_limiting_integration() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
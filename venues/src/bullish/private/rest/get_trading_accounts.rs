@@ -87,7 +87,7 @@ impl RestClient {
     ///
     /// # Returns
     /// Trading accounts information including balances, borrowing status, and fee rates
-    pub async fn get_trading_accounts(&mut self) -> RestResult<TradingAccountsResponse> {
+    pub async fn get_trading_accounts(&self) -> RestResult<TradingAccountsResponse> {
         self.send_authenticated_request(
             "/v1/accounts/trading-accounts",
             reqwest::Method::GET,
@@ -106,7 +106,7 @@ impl RestClient {
     ///
     /// # Returns
     /// Trading account information for the specified account
-    pub async fn get_trading_account(&mut self, trading_account_id: &str) -> RestResult<TradingAccount> {
+    pub async fn get_trading_account(&self, trading_account_id: &str) -> RestResult<TradingAccount> {
         let endpoint = format!("/v1/accounts/trading-accounts/{}", trading_account_id);
 
         self.send_authenticated_request(
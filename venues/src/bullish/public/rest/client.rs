@@ -1,10 +1,17 @@
 //! Bullish Public REST API client
 
 use std::borrow::Cow;
-
-use reqwest::Client;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use reqwest::Method;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::de::DeserializeOwned;
 
+use crate::bullish::errors::VENUE;
 use crate::bullish::{EndpointType, RateLimiter, RestResult};
 
 /// Public REST client for Bullish exchange
@@ -13,7 +20,7 @@ use crate::bullish::{EndpointType, RateLimiter, RestResult};
 /// It provides automatic rate limiting and error handling.
 pub struct RestClient {
     /// The underlying HTTP client used for making requests
-    pub(crate) client: Client,
+    pub(crate) client: Arc<dyn HttpTransport>,
     /// The base URL for the API
     pub(crate) base_url: Cow<'static, str>,
     /// Rate limiter for API requests
@@ -30,9 +37,9 @@ impl RestClient {
     ///
     /// # Returns
     /// A new RestClient instance
-    pub fn new(base_url: impl Into<Cow<'static, str>>, client: Client, rate_limiter: RateLimiter) -> Self {
+    pub fn new(base_url: impl Into<Cow<'static, str>>, client: impl HttpTransport + 'static, rate_limiter: RateLimiter) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             base_url: base_url.into(),
             rate_limiter,
         }
@@ -60,25 +67,86 @@ impl RestClient {
 
         let url = format!("{}{}", self.base_url, endpoint);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self
+            .client
+            .execute(HttpRequest::new(Method::GET, url))
+            .await?;
 
         self.rate_limiter.increment_request(endpoint_type).await;
 
-        if !response.status().is_success() {
-            let error_text = response.text().await?;
+        if !response.status.is_success() {
+            let error_text = response.body;
+            if let Ok(error_response) = serde_json::from_str::<crate::bullish::ErrorResponse>(&error_text) {
+                return Err(crate::bullish::Errors::ApiError(error_response.error));
+            }
             return Err(crate::bullish::Errors::Error(format!(
                 "Request failed: {}",
                 error_text
             )));
         }
 
-        let result: T = response.json().await?;
+        let result: T = serde_json::from_str(&response.body)?;
         Ok(result)
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        if request.method != reqwest::Method::GET {
+            return Err(VenueError::new(
+                VENUE,
+                ErrorKind::InvalidRequest,
+                format!(
+                    "{} is not supported by the public REST client",
+                    request.method
+                ),
+            )
+            .into());
+        }
+
+        let start = Instant::now();
+
+        // Public endpoints take their parameters in the query string
+        let endpoint = match &request.params {
+            Some(params) => {
+                let query = serde_urlencoded::to_string(params).map_err(|e| {
+                    VenueError::new(
+                        VENUE,
+                        ErrorKind::InvalidRequest,
+                        format!("Failed to encode query parameters: {}", e),
+                    )
+                })?;
+                format!("{}?{}", request.endpoint, query)
+            }
+            None => request.endpoint,
+        };
+        let data = self
+            .send_request::<serde_json::Value>(&endpoint, request.rate_limit_key)
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
+
     use super::*;
 
     #[test]
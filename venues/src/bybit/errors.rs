@@ -1,6 +1,11 @@
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "bybit";
+
 /// ByBit API error response structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
@@ -29,11 +34,20 @@ pub struct ApiError {
 /// Comprehensive error types for ByBit API operations
 #[derive(Error, Debug)]
 pub enum Errors {
-    #[error("ByBit API error: {0}")]
-    ApiError(String),
+    /// Error answered by ByBit, with the HTTP status if the request failed at the HTTP level
+    #[error("ByBit API error {code}: {msg}")]
+    ApiError {
+        code: i32,
+        msg: String,
+        status: Option<u16>,
+    },
+
+    /// Non-success HTTP status without a ByBit error body
+    #[error("HTTP {status}: {body}")]
+    HttpStatus { status: u16, body: String },
 
     #[error("HTTP request failed: {0}")]
-    HttpError(#[from] reqwest::Error),
+    HttpError(#[from] TransportError),
 
     #[error("JSON serialization/deserialization error: {0}")]
     SerdeError(#[from] serde_json::Error),
@@ -57,12 +71,94 @@ pub enum Errors {
     Unknown(String),
 }
 
+impl From<reqwest::Error> for Errors {
+    fn from(err: reqwest::Error) -> Self {
+        Errors::HttpError(TransportError::from(err))
+    }
+}
+
 impl From<ErrorResponse> for Errors {
     fn from(error_response: ErrorResponse) -> Self {
-        Errors::ApiError(format!(
-            "Code: {}, Message: {}",
-            error_response.ret_code, error_response.ret_msg
-        ))
+        Errors::ApiError {
+            code: error_response.ret_code,
+            msg: error_response.ret_msg,
+            status: None,
+        }
+    }
+}
+
+impl Errors {
+    /// Error for a response with a non-success HTTP status, keeping ByBit's error
+    /// response if the body is one
+    pub(crate) fn from_http_response(status: u16, body: &str) -> Self {
+        match serde_json::from_str::<ErrorResponse>(body) {
+            Ok(error_response) => Errors::ApiError {
+                code: error_response.ret_code,
+                msg: error_response.ret_msg,
+                status: Some(status),
+            },
+            Err(_) => Errors::HttpStatus {
+                status,
+                body: body.to_string(),
+            },
+        }
+    }
+}
+
+/// Classify a ByBit `retCode`
+fn error_kind(ret_code: i32) -> ErrorKind {
+    match ret_code {
+        10006 | 10018 => ErrorKind::RateLimited { retry_after: None },
+        10002 | 10003 | 10004 | 10005 | 10007 | 10009 | 10010 | 33004 => ErrorKind::AuthFailed,
+        110004 | 110007 | 110012 | 110044 | 170131 => ErrorKind::InsufficientFunds,
+        110001 | 170213 => ErrorKind::OrderNotFound,
+        10000 | 10016 => ErrorKind::UnknownExecutionStatus,
+        10001 | 110000..=199999 => ErrorKind::InvalidRequest,
+        _ => ErrorKind::Other,
+    }
+}
+
+/// Classify an HTTP error status. ByBit answers 403 when the IP rate limit has been
+/// breached, and bans the IP for a while after that.
+fn http_error_kind(status: u16) -> ErrorKind {
+    match status {
+        403 => ErrorKind::IpBanned { until: None },
+        _ => ErrorKind::from_http_status(status, None),
+    }
+}
+
+impl From<&ErrorResponse> for VenueError {
+    fn from(err: &ErrorResponse) -> Self {
+        VenueError::new(VENUE, error_kind(err.ret_code), err.ret_msg.clone()).with_code(err.ret_code)
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::ApiError { code, msg, .. } => VenueError::new(VENUE, error_kind(*code), msg.clone()).with_code(code),
+            Errors::HttpStatus { status, body } => VenueError::new(VENUE, http_error_kind(*status), body.clone()).with_code(status),
+            Errors::HttpError(err) => match err.status() {
+                Some(status) => VenueError::new(VENUE, http_error_kind(status.as_u16()), err.to_string()).with_code(status.as_u16()),
+                None => VenueError::from_http_error(VENUE, err),
+            },
+            // Rejected locally before anything was sent
+            Errors::RateLimitError(err) => VenueError::new(
+                VENUE,
+                ErrorKind::RateLimited { retry_after: None },
+                err.to_string(),
+            ),
+            Errors::AuthError(msg) => VenueError::new(VENUE, ErrorKind::AuthFailed, msg.clone()),
+            Errors::InvalidParameter(msg) => VenueError::new(VENUE, ErrorKind::InvalidRequest, msg.clone()),
+            Errors::Timeout => VenueError::new(VENUE, ErrorKind::UnknownExecutionStatus, err.to_string()),
+            Errors::SerdeError(_) | Errors::UrlEncodingError(_) | Errors::Unknown(_) => VenueError::new(VENUE, ErrorKind::Other, err.to_string()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
     }
 }
 
@@ -109,11 +205,69 @@ mod tests {
 
         let error: Errors = error_response.into();
         match error {
-            Errors::ApiError(msg) => {
-                assert!(msg.contains("10001"));
-                assert!(msg.contains("Invalid API key"));
+            Errors::ApiError { code, msg, status } => {
+                assert_eq!(code, 10001);
+                assert_eq!(msg, "Invalid API key");
+                assert_eq!(status, None);
             }
             _ => panic!("Expected ApiError"),
         }
     }
+
+    #[test]
+    fn test_venue_error_from_error_response() {
+        let error_response = ErrorResponse {
+            ret_code: 110001,
+            ret_msg: "Order does not exist".to_string(),
+            ret_ext_info: serde_json::Value::Null,
+            time: None,
+        };
+
+        let error = VenueError::from(&error_response);
+        assert_eq!(error.kind, ErrorKind::OrderNotFound);
+        assert_eq!(error.code.as_deref(), Some("110001"));
+        assert_eq!(error.message, "Order does not exist");
+
+        // The code and message survive the conversion into `Errors`
+        let error: VenueError = Errors::from(error_response).into();
+        assert_eq!(error.kind, ErrorKind::OrderNotFound);
+        assert_eq!(error.code.as_deref(), Some("110001"));
+        assert_eq!(error.message, "Order does not exist");
+    }
+
+    #[test]
+    fn test_venue_error_from_http_response() {
+        let body = r#"{"retCode":10006,"retMsg":"Too many visits!","retExtInfo":{},"time":1672738134824}"#;
+        let error = Errors::from_http_response(429, body);
+        assert!(matches!(
+            error,
+            Errors::ApiError {
+                code: 10006,
+                status: Some(429),
+                ..
+            }
+        ));
+        let error = VenueError::from(error);
+        assert_eq!(error.kind, ErrorKind::RateLimited { retry_after: None });
+        assert_eq!(error.code.as_deref(), Some("10006"));
+        assert_eq!(error.message, "Too many visits!");
+
+        let error = Errors::from_http_response(403, "access too frequent");
+        assert!(matches!(error, Errors::HttpStatus { status: 403, .. }));
+        let error = VenueError::from(error);
+        assert_eq!(error.kind, ErrorKind::IpBanned { until: None });
+        assert_eq!(error.code.as_deref(), Some("403"));
+        assert_eq!(error.message, "access too frequent");
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(error_kind(10004), ErrorKind::AuthFailed);
+        assert_eq!(error_kind(110007), ErrorKind::InsufficientFunds);
+        assert_eq!(error_kind(110017), ErrorKind::InvalidRequest);
+        assert_eq!(error_kind(10016), ErrorKind::UnknownExecutionStatus);
+
+        let error = VenueError::from(Errors::Timeout);
+        assert_eq!(error.kind, ErrorKind::UnknownExecutionStatus);
+    }
 }
@@ -4,14 +4,18 @@
 // All requests are authenticated and require API credentials.
 
 use std::borrow::Cow;
-
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use sha2::Sha256;
 
+use crate::bybit::errors::VENUE;
 use crate::bybit::{EndpointType, Errors, RateLimiter, RestResult};
 
 /// Private REST client for ByBit V5 exchange
@@ -27,7 +31,7 @@ pub struct RestClient {
     /// The underlying HTTP client used for making requests.
     ///
     /// This is reused for connection pooling and performance.
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits.
     ///
@@ -37,8 +41,8 @@ pub struct RestClient {
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
 
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
 }
 
 impl RestClient {
@@ -46,7 +50,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `rate_limiter` - The rate limiter instance
     /// * `client` - The HTTP client instance
@@ -55,17 +59,17 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
         rate_limiter: RateLimiter,
-        client: Client,
+        client: impl HttpTransport + 'static,
     ) -> Self {
         Self {
             api_key,
             api_secret,
             base_url: base_url.into(),
             rate_limiter,
-            client,
+            client: Arc::new(client),
         }
     }
 
@@ -118,64 +122,92 @@ impl RestClient {
         }
 
         // Generate HMAC-SHA256 signature
-        let signature = self.sign_payload(&payload)?;
+        let signature = self.sign_payload(&payload).await?;
 
         // Build the URL
         let url = format!("{}{}", self.base_url, endpoint);
-        let mut request_builder = self.client.request(method.clone(), &url);
-
-        // Add headers
-        request_builder = request_builder
+        let mut request_builder = HttpRequest::new(method.clone(), url)
             .header("X-BAPI-API-KEY", self.api_key.expose_secret())
-            .header("X-BAPI-TIMESTAMP", &timestamp)
-            .header("X-BAPI-SIGN", &signature)
+            .header("X-BAPI-TIMESTAMP", timestamp)
+            .header("X-BAPI-SIGN", signature)
             .header("X-BAPI-RECV-WINDOW", recv_window)
             .header("Content-Type", "application/json");
 
-        // Add query parameters for GET or body for POST
+        // Add the signed query string for GET or body for POST
         if method == reqwest::Method::GET && !query_string.is_empty() {
-            request_builder = request_builder.query(&[("", &query_string)]);
+            request_builder.url = format!("{}?{}", request_builder.url, query_string);
         } else if method == reqwest::Method::POST {
-            request_builder = request_builder.json(&request);
+            request_builder = request_builder.json(&request)?;
         }
 
         // Send the request
-        let response = request_builder.send().await?;
+        let response = self.client.execute(request_builder).await?;
 
         // Record the request for rate limiting
         self.rate_limiter.increment_request(endpoint_type).await;
 
         // Check for HTTP errors
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(Errors::ApiError(format!("HTTP {}: {}", status, error_text)));
+        if !response.status.is_success() {
+            return Err(Errors::from_http_response(
+                response.status.as_u16(),
+                &response.body,
+            ));
         }
 
         // Parse the response
-        let response_text = response.text().await?;
-        let parsed_response: T = serde_json::from_str(&response_text)?;
+        let parsed_response: T = serde_json::from_str(&response.body)?;
 
         Ok(parsed_response)
     }
 
     /// Generate HMAC-SHA256 signature for ByBit V5 API
-    fn sign_payload(&self, payload: &str) -> Result<String, Errors> {
-        let secret_key = self.api_secret.expose_secret();
-        let mut mac = Hmac::<Sha256>::new_from_slice(secret_key.as_bytes()).map_err(|e| Errors::AuthError(format!("Invalid secret key: {}", e)))?;
+    async fn sign_payload(&self, payload: &str) -> Result<String, Errors> {
+        let signature = self
+            .api_secret
+            .hmac_sha256(payload.as_bytes())
+            .await
+            .map_err(|e| Errors::AuthError(format!("Invalid secret key: {}", e)))?;
+        Ok(hex::encode(signature))
+    }
+}
+
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
 
-        mac.update(payload.as_bytes());
-        let result = mac.finalize();
-        Ok(hex::encode(result.into_bytes()))
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_signed_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                request.params.unwrap_or_else(|| serde_json::json!({})),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use reqwest::{Client, StatusCode};
     use rest::secrets::ExposableSecret;
+    use rest::transport::MockTransport;
+    use serde_json::json;
 
     use super::*;
 
@@ -198,7 +230,7 @@ mod tests {
     #[test]
     fn test_private_client_creation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
@@ -213,10 +245,10 @@ mod tests {
         assert_eq!(rest_client.base_url, "https://api.bybit.com");
     }
 
-    #[test]
-    fn test_sign_payload() {
+    #[tokio::test]
+    async fn test_sign_payload() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
 
@@ -229,8 +261,81 @@ mod tests {
         );
 
         let payload = "test_payload";
-        let signature = rest_client.sign_payload(payload);
+        let signature = rest_client.sign_payload(payload).await;
         assert!(signature.is_ok());
         assert!(!signature.unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn test_signed_get_sends_the_signed_query_string() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({ "retCode": 0, "retMsg": "OK", "result": {} }),
+        );
+        let rest_client = RestClient::new(
+            Box::new(TestSecret::new("test_key".to_string())),
+            Box::new(TestSecret::new("test_secret".to_string())),
+            "https://api.bybit.com",
+            RateLimiter::new(),
+            transport.clone(),
+        );
+
+        let _: serde_json::Value = rest_client
+            .send_signed_request(
+                "/v5/account/wallet-balance",
+                reqwest::Method::GET,
+                json!({ "accountType": "UNIFIED", "coin": "BTC" }),
+                EndpointType::Account,
+            )
+            .await
+            .unwrap();
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(
+            sent.url,
+            "https://api.bybit.com/v5/account/wallet-balance?accountType=UNIFIED&coin=BTC"
+        );
+        assert_eq!(sent.header_value("X-BAPI-API-KEY"), Some("test_key"));
+        let timestamp = sent.header_value("X-BAPI-TIMESTAMP").unwrap();
+        let payload = format!("{}test_key5000accountType=UNIFIED&coin=BTC", timestamp);
+        assert_eq!(
+            sent.header_value("X-BAPI-SIGN").unwrap(),
+            rest_client.sign_payload(&payload).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_error_keeps_code_and_status() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::TOO_MANY_REQUESTS,
+            json!({ "retCode": 10006, "retMsg": "Too many visits!", "retExtInfo": {} }),
+        );
+        let rest_client = RestClient::new(
+            Box::new(TestSecret::new("test_key".to_string())),
+            Box::new(TestSecret::new("test_secret".to_string())),
+            "https://api.bybit.com",
+            RateLimiter::new(),
+            transport.clone(),
+        );
+
+        let result: RestResult<serde_json::Value> = rest_client
+            .send_signed_request(
+                "/v5/account/wallet-balance",
+                reqwest::Method::GET,
+                json!({ "accountType": "UNIFIED" }),
+                EndpointType::Account,
+            )
+            .await;
+
+        match result {
+            Err(Errors::ApiError { code, msg, status }) => {
+                assert_eq!(code, 10006);
+                assert_eq!(msg, "Too many visits!");
+                assert_eq!(status, Some(429));
+            }
+            other => panic!("Expected ApiError, got {:?}", other.err()),
+        }
+    }
 }
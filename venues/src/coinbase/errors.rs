@@ -1,7 +1,12 @@
 use std::fmt;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::Deserialize;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "coinbase";
+
 /// Represents all possible errors that can occur when interacting with the Coinbase API
 #[derive(Debug)]
 pub enum Errors {
@@ -11,7 +16,7 @@ pub enum Errors {
     /// HTTP error occurred while making a request
     /// This variant is used to represent errors that are not specific to the Coinbase API,
     /// such as network issues or HTTP errors.
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the Coinbase API
     ApiError(ApiError),
@@ -37,9 +42,15 @@ impl fmt::Display for Errors {
 
 impl std::error::Error for Errors {}
 
+impl From<TransportError> for Errors {
+    fn from(err: TransportError) -> Self {
+        Errors::HttpError(err)
+    }
+}
+
 impl From<reqwest::Error> for Errors {
     fn from(err: reqwest::Error) -> Self {
-        Errors::HttpError(err)
+        Errors::HttpError(TransportError::from(err))
     }
 }
 
@@ -189,3 +200,125 @@ impl From<ErrorResponse> for ApiError {
         }
     }
 }
+
+impl ApiError {
+    /// The HTTP status code this error was created from, if known.
+    ///
+    /// Coinbase does not return error codes in its body; errors recognised from
+    /// the message alone have no code.
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            ApiError::BadRequest { .. } => Some(400),
+            ApiError::Unauthorized { .. } => Some(401),
+            ApiError::Forbidden { .. } => Some(403),
+            ApiError::NotFound { .. } => Some(404),
+            ApiError::TooManyRequests { .. } => Some(429),
+            ApiError::InternalServerError { .. } => Some(500),
+            ApiError::UnknownApiError { code, .. } => *code,
+            _ => None,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::Unauthorized { .. }
+            | ApiError::Forbidden { .. }
+            | ApiError::TimestampInvalid { .. }
+            | ApiError::SignatureInvalid { .. }
+            | ApiError::PassphraseInvalid { .. } => ErrorKind::AuthFailed,
+            ApiError::TooManyRequests { .. } => ErrorKind::RateLimited { retry_after: None },
+            ApiError::InternalServerError { .. } => ErrorKind::UnknownExecutionStatus,
+            ApiError::InsufficientFunds { .. } => ErrorKind::InsufficientFunds,
+            ApiError::OrderNotFound { .. } => ErrorKind::OrderNotFound,
+            ApiError::BadRequest { .. }
+            | ApiError::NotFound { .. }
+            | ApiError::InvalidPrice { .. }
+            | ApiError::InvalidOrderSize { .. }
+            | ApiError::InvalidProduct { .. }
+            | ApiError::OrderAlreadyCancelled { .. }
+            | ApiError::OrderAlreadyFilled { .. }
+            | ApiError::PostOnlyOrderWouldTrade { .. }
+            | ApiError::ProfileNotFound { .. }
+            | ApiError::AccountNotFound { .. } => ErrorKind::InvalidRequest,
+            ApiError::UnknownApiError { code, .. } => code
+                .and_then(|code| u16::try_from(code).ok())
+                .map_or(ErrorKind::Other, |status| {
+                    ErrorKind::from_http_status(status, None)
+                }),
+        }
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(api_error) => {
+                let error = VenueError::new(VENUE, api_error.kind(), api_error.to_string());
+                match api_error.code() {
+                    Some(code) => error.with_code(code),
+                    None => error,
+                }
+            }
+            // Rejected locally before anything was sent
+            Errors::RateLimitError(err) => VenueError::new(
+                VENUE,
+                ErrorKind::RateLimited { retry_after: None },
+                err.to_string(),
+            ),
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error: VenueError = Errors::ApiError(ApiError::Unauthorized {
+            msg: "invalid signature".to_string(),
+        })
+        .into();
+        assert_eq!(error.kind, ErrorKind::AuthFailed);
+        assert_eq!(error.code.as_deref(), Some("401"));
+        assert_eq!(error.message, "Unauthorized: invalid signature");
+        assert_eq!(error.venue, VENUE);
+    }
+
+    #[test]
+    fn test_venue_error_from_message() {
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            message: "Insufficient funds".to_string(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::InsufficientFunds);
+        assert_eq!(error.code, None);
+
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            message: "Order not found".to_string(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::OrderNotFound);
+    }
+
+    #[test]
+    fn test_venue_error_unknown_status() {
+        let error: VenueError = Errors::ApiError(ApiError::UnknownApiError {
+            code: Some(503),
+            msg: "Service Unavailable".to_string(),
+        })
+        .into();
+        assert_eq!(error.kind, ErrorKind::UnknownExecutionStatus);
+        assert_eq!(error.code.as_deref(), Some("503"));
+    }
+}
@@ -4,16 +4,22 @@
 //! All requests are authenticated and require API credentials.
 
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
+use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose};
 use chrono::Utc;
 use hmac::{Hmac, Mac};
-use reqwest::Client;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
 use rest::secrets::ExposableSecret;
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use sha2::Sha256;
 
+use crate::coinbase::errors::VENUE;
 use crate::coinbase::{EndpointType, Errors, RateLimiter, RestResult};
 use super::get_account_balances::PaginationInfo;
 
@@ -26,7 +32,7 @@ pub struct RestClient {
     pub base_url: Cow<'static, str>,
 
     /// The underlying HTTP client used for making requests
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits
     pub rate_limiter: RateLimiter,
@@ -56,12 +62,12 @@ impl RestClient {
         api_secret: Box<dyn ExposableSecret>,
         api_passphrase: Box<dyn ExposableSecret>,
         base_url: impl Into<Cow<'static, str>>,
-        client: Client,
+        client: impl HttpTransport + 'static,
         rate_limiter: RateLimiter,
     ) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
             api_key,
             api_secret,
@@ -131,7 +137,7 @@ impl RestClient {
 
         // Build URL and request
         let url = format!("{}/{}", self.base_url, endpoint);
-        let mut request_builder = self.client.request(method.clone(), &url);
+        let mut request_builder = HttpRequest::new(method.clone(), url);
 
         // Handle request body and path
         let (request_path, body) = if method == reqwest::Method::GET {
@@ -139,12 +145,8 @@ impl RestClient {
             if let Some(params) = params {
                 let query_string = serde_urlencoded::to_string(params).map_err(|e| Errors::Error(format!("Failed to serialize query parameters: {}", e)))?;
                 if !query_string.is_empty() {
-                    // Parse the query string and add individual parameters
-                    let parsed_params: Vec<(String, String)> =
-                        serde_urlencoded::from_str(&query_string).map_err(|e| Errors::Error(format!("Failed to parse query parameters: {}", e)))?;
-                    for (key, value) in &parsed_params {
-                        request_builder = request_builder.query(&[(key, value)]);
-                    }
+                    // Send exactly the query string that is signed
+                    request_builder.url = format!("{}?{}", request_builder.url, query_string);
                     (format!("/{}?{}", endpoint, query_string), String::new())
                 } else {
                     (format!("/{}", endpoint), String::new())
@@ -161,8 +163,7 @@ impl RestClient {
             };
 
             if !body.is_empty() {
-                request_builder = request_builder.body(body.clone());
-                request_builder = request_builder.header("Content-Type", "application/json");
+                request_builder = request_builder.body(body.clone()).header("Content-Type", "application/json");
             }
 
             (format!("/{}", endpoint), body)
@@ -183,12 +184,12 @@ impl RestClient {
             .header("User-Agent", "ccrxt/0.1.0");
 
         // Send request
-        let response = request_builder.send().await?;
+        let response = self.client.execute(request_builder).await?;
 
         // Check response status and capture headers
-        let status = response.status();
-        let headers = response.headers().clone();
-        let response_text = response.text().await?;
+        let status = response.status;
+        let headers = response.headers;
+        let response_text = response.body;
 
         if status.is_success() {
             // Parse successful response
@@ -304,7 +305,7 @@ impl RestClient {
 
         // Build URL and request
         let url = format!("{}/{}", self.base_url, endpoint);
-        let mut request_builder = self.client.request(method.clone(), &url);
+        let mut request_builder = HttpRequest::new(method.clone(), url);
 
         // Handle request body and path
         let (request_path, body) = if method == reqwest::Method::GET {
@@ -312,12 +313,8 @@ impl RestClient {
             if let Some(params) = params {
                 let query_string = serde_urlencoded::to_string(params).map_err(|e| Errors::Error(format!("Failed to serialize query parameters: {}", e)))?;
                 if !query_string.is_empty() {
-                    // Parse the query string and add individual parameters
-                    let parsed_params: Vec<(String, String)> =
-                        serde_urlencoded::from_str(&query_string).map_err(|e| Errors::Error(format!("Failed to parse query parameters: {}", e)))?;
-                    for (key, value) in &parsed_params {
-                        request_builder = request_builder.query(&[(key, value)]);
-                    }
+                    // Send exactly the query string that is signed
+                    request_builder.url = format!("{}?{}", request_builder.url, query_string);
                     (format!("/{}?{}", endpoint, query_string), String::new())
                 } else {
                     (format!("/{}", endpoint), String::new())
@@ -334,8 +331,7 @@ impl RestClient {
             };
 
             if !body.is_empty() {
-                request_builder = request_builder.body(body.clone());
-                request_builder = request_builder.header("Content-Type", "application/json");
+                request_builder = request_builder.body(body.clone()).header("Content-Type", "application/json");
             }
 
             (format!("/{}", endpoint), body)
@@ -356,11 +352,11 @@ impl RestClient {
             .header("User-Agent", "ccrxt/0.1.0");
 
         // Send request
-        let response = request_builder.send().await?;
+        let response = self.client.execute(request_builder).await?;
 
         // Check response status
-        let status = response.status();
-        let response_text = response.text().await?;
+        let status = response.status;
+        let response_text = response.body;
 
         if status.is_success() {
             // Parse successful response
@@ -405,8 +401,41 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
+
     use super::*;
 
     // Create a simple test secret implementation
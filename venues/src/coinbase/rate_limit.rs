@@ -1,9 +1,14 @@
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
+use rest::error::{ErrorKind, RestError, VenueError};
+use rest::rate_limiter::RateLimitStatus;
 use thiserror::Error;
 use tokio::sync::RwLock;
 
+use crate::coinbase::errors::VENUE;
+
 /// Types of endpoints for rate limiting based on Coinbase documentation
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EndpointType {
@@ -66,9 +71,8 @@ impl RequestTracker {
     /// Clean up old request timestamps beyond the window
     fn cleanup(&mut self, window: Duration) {
         let now = Instant::now();
-        let cutoff = now - window;
-
-        self.request_times.retain(|&time| time > cutoff);
+        self.request_times
+            .retain(|&time| now.duration_since(time) < window);
         self.last_cleanup = now;
     }
 
@@ -87,11 +91,10 @@ impl RequestTracker {
         }
 
         // Check rate limit (requests per second)
-        let one_second_ago = now - Duration::from_secs(1);
         let recent_requests = self
             .request_times
             .iter()
-            .filter(|&&time| time > one_second_ago)
+            .filter(|&&time| now.duration_since(time) < Duration::from_secs(1))
             .count();
 
         recent_requests < rate_limit.max_requests_per_second as usize
@@ -186,3 +189,128 @@ impl Default for RateLimiter {
         Self::new()
     }
 }
+
+#[async_trait]
+impl rest::rate_limiter::RateLimiter for RateLimiter {
+    type Key = EndpointType;
+
+    async fn check_limit(&self, _endpoint: &str, endpoint_type: &EndpointType) -> Result<(), RestError> {
+        let allowed = match self.rate_limits.get(endpoint_type) {
+            Some(rate_limit) => {
+                let mut trackers = self.trackers.write().await;
+                trackers
+                    .entry(endpoint_type.clone())
+                    .or_insert_with(RequestTracker::new)
+                    .can_make_request(rate_limit)
+            }
+            None => false,
+        };
+
+        if !allowed {
+            let status = self.get_rate_limit_status(endpoint_type).await;
+            let err = RateLimitError::Exceeded {
+                endpoint_type: endpoint_type.clone(),
+            };
+            let retry_after = Some(status.reset_in);
+            return Err(VenueError::new(
+                VENUE,
+                ErrorKind::RateLimited { retry_after },
+                err.to_string(),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    async fn record_request(&self, _endpoint: &str, endpoint_type: &EndpointType) {
+        let mut trackers = self.trackers.write().await;
+        trackers
+            .entry(endpoint_type.clone())
+            .or_insert_with(RequestTracker::new)
+            .record_request();
+    }
+
+    async fn get_rate_limit_status(&self, endpoint_type: &EndpointType) -> RateLimitStatus {
+        let Some(rate_limit) = self.rate_limits.get(endpoint_type) else {
+            return RateLimitStatus {
+                remaining: 0,
+                limit: 0,
+                reset_in: Duration::ZERO,
+            };
+        };
+
+        let trackers = self.trackers.read().await;
+        let now = Instant::now();
+        let one_second = Duration::from_secs(1);
+
+        let recent: Vec<Instant> = trackers
+            .get(endpoint_type)
+            .map(|tracker| {
+                tracker
+                    .request_times
+                    .iter()
+                    .copied()
+                    .filter(|&time| now.duration_since(time) < one_second)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // The per-second limit frees up a slot when the oldest request in the last second expires
+        let reset_in = recent
+            .iter()
+            .min()
+            .map(|&oldest| one_second.saturating_sub(now.duration_since(oldest)))
+            .unwrap_or_default();
+
+        RateLimitStatus {
+            remaining: rate_limit
+                .max_requests_per_second
+                .saturating_sub(u32::try_from(recent.len()).unwrap_or(u32::MAX)),
+            limit: rate_limit.max_requests_per_second,
+            reset_in,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rest_rate_limiter_trait() {
+        let limiter = RateLimiter::new();
+        // The inherent methods share names with the trait's, so call through the trait
+        let limiter: &dyn rest::rate_limiter::RateLimiter<Key = EndpointType> = &limiter;
+        let endpoint_type = EndpointType::PrivateLoans;
+
+        // Checking alone does not consume capacity
+        for _ in 0..20 {
+            limiter.check_limit("loans", &endpoint_type).await.unwrap();
+        }
+
+        for _ in 0..10 {
+            limiter.check_limit("loans", &endpoint_type).await.unwrap();
+            limiter.record_request("loans", &endpoint_type).await;
+        }
+
+        let status = limiter.get_rate_limit_status(&endpoint_type).await;
+        assert_eq!(status.remaining, 0);
+        assert_eq!(status.limit, 10);
+        assert!(status.reset_in <= Duration::from_secs(1));
+
+        let err = limiter
+            .check_limit("loans", &endpoint_type)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::RateLimited {
+                retry_after: Some(_)
+            }
+        ));
+
+        // Other endpoint types are unaffected
+        let status = limiter.get_rate_limit_status(&EndpointType::Public).await;
+        assert_eq!(status.remaining, 10);
+    }
+}
@@ -1,8 +1,13 @@
 use std::fmt;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::Deserialize;
 use thiserror::Error;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "cryptocom";
+
 /// Represents all possible errors that can occur when interacting with the Crypto.com API
 #[derive(Debug)]
 pub enum Errors {
@@ -14,7 +19,7 @@ pub enum Errors {
     /// such as network issues or HTTP errors.
     /// It can be used to wrap any error that occurs during the request process.
     /// This variant is not used for errors returned by the Crypto.com API itself.
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the Crypto.com API
     ApiError(ApiError),
@@ -36,9 +41,15 @@ impl fmt::Display for Errors {
 
 impl std::error::Error for Errors {}
 
+impl From<TransportError> for Errors {
+    fn from(err: TransportError) -> Self {
+        Errors::HttpError(err)
+    }
+}
+
 impl From<reqwest::Error> for Errors {
     fn from(err: reqwest::Error) -> Self {
-        Errors::HttpError(err)
+        Errors::HttpError(TransportError::from(err))
     }
 }
 
@@ -434,6 +445,139 @@ impl From<ErrorResponse> for ApiError {
     }
 }
 
+impl ApiError {
+    /// The Crypto.com response code this error was created from
+    pub fn code(&self) -> i32 {
+        match self {
+            ApiError::Success => 0,
+            ApiError::NoPosition => 201,
+            ApiError::AccountIsSuspended => 202,
+            ApiError::AccountsDoNotMatch => 203,
+            ApiError::DuplicateClientOrderId => 204,
+            ApiError::DuplicateOrderId => 205,
+            ApiError::InstrumentExpired => 206,
+            ApiError::NoMarkPrice => 207,
+            ApiError::InstrumentNotTradable => 208,
+            ApiError::InvalidInstrument => 209,
+            ApiError::InvalidAccount => 210,
+            ApiError::InvalidCurrency => 211,
+            ApiError::InvalidOrderId => 212,
+            ApiError::InvalidOrderQuantity => 213,
+            ApiError::InvalidSettleCurrency => 214,
+            ApiError::InvalidFeeCurrency => 215,
+            ApiError::InvalidPositionQuantity => 216,
+            ApiError::InvalidOpenQuantity => 217,
+            ApiError::InvalidOrderType => 218,
+            ApiError::InvalidExecInst => 219,
+            ApiError::InvalidSide => 220,
+            ApiError::InvalidTimeInForce => 221,
+            ApiError::StaleMarkPrice => 222,
+            ApiError::NoClientOrderId => 223,
+            ApiError::RejectedByMatchingEngine => 224,
+            ApiError::ExceedMaximumEntryLeverage => 225,
+            ApiError::InvalidLeverage => 226,
+            ApiError::InvalidSlippage => 227,
+            ApiError::InvalidFloorPrice => 228,
+            ApiError::InvalidRefPrice => 229,
+            ApiError::InvalidTriggerType => 230,
+            ApiError::AccountIsInMarginCall => 301,
+            ApiError::ExceedsAccountRiskLimit => 302,
+            ApiError::ExceedsPositionRiskLimit => 303,
+            ApiError::OrderWillLeadToImmediateLiquidation => 304,
+            ApiError::OrderWillTriggerMarginCall => 305,
+            ApiError::InsufficientAvailableBalance => 306,
+            ApiError::InvalidOrderStatus => 307,
+            ApiError::InvalidPrice => 308,
+            ApiError::MarketIsNotOpen => 309,
+            ApiError::OrderPriceBeyondLiquidationPrice => 310,
+            ApiError::PositionIsInLiquidation => 311,
+            ApiError::OrderPriceGreaterThanLimitUpPrice => 312,
+            ApiError::OrderPriceLessThanLimitDownPrice => 313,
+            ApiError::ExceedsMaxOrderSize => 314,
+            ApiError::FarAwayLimitPrice => 315,
+            ApiError::NoActiveOrder => 316,
+            ApiError::PositionNoExist => 317,
+            ApiError::ExceedsMaxAllowedOrders => 318,
+            ApiError::ExceedsMaxPositionSize => 319,
+            ApiError::ExceedsInitialMargin => 320,
+            ApiError::ExceedsMaxAvailableBalance => 321,
+            ApiError::AccountDoesNotExist => 401,
+            ApiError::AccountIsNotActive => 406,
+            ApiError::MarginUnitDoesNotExist => 407,
+            ApiError::MarginUnitIsSuspended => 408,
+            ApiError::InvalidUser => 409,
+            ApiError::UserIsNotActive => 410,
+            ApiError::UserNoDerivAccess => 411,
+            ApiError::AccountNoDerivAccess => 412,
+            ApiError::BelowMinOrderSize => 415,
+            ApiError::ExceedMaximumEffectiveLeverage => 501,
+            ApiError::InvalidCollateralPrice => 604,
+            ApiError::InvalidMarginCalc => 605,
+            ApiError::ExceedAllowedSlippage => 606,
+            ApiError::MaxAmountViolated => 30024,
+            ApiError::BadRequest => 40001,
+            ApiError::MethodNotFound => 40002,
+            ApiError::InvalidRequest => 40003,
+            ApiError::MissingOrInvalidArgument => 40004,
+            ApiError::InvalidDate => 40005,
+            ApiError::DuplicateRequest => 40006,
+            ApiError::Unauthorized => 40101,
+            ApiError::InvalidNonce => 40102,
+            ApiError::IpIllegal => 40103,
+            ApiError::UserTierInvalid => 40104,
+            ApiError::ExceedMaxSubscriptions => 40107,
+            ApiError::NotFound => 40401,
+            ApiError::RequestTimeout => 40801,
+            ApiError::TooManyRequests => 42901,
+            ApiError::FillOrKill => 43003,
+            ApiError::ImmediateOrCancel => 43004,
+            ApiError::PostOnlyRej => 43005,
+            ApiError::SelfTradePrevention => 43012,
+            ApiError::DwCreditLineNotMaintained | ApiError::ErrInternal => 50001,
+            ApiError::UnmappedApiError { code, .. } => *code,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::DwCreditLineNotMaintained => ErrorKind::InvalidRequest,
+            _ => error_kind(self.code()),
+        }
+    }
+}
+
+/// Classify a Crypto.com response code
+fn error_kind(code: i32) -> ErrorKind {
+    match code {
+        42901 => ErrorKind::RateLimited { retry_after: None },
+        202 | 409 | 410 | 411 | 412 | 40101 | 40102 | 40103 | 40104 => ErrorKind::AuthFailed,
+        306 | 320 | 321 => ErrorKind::InsufficientFunds,
+        212 | 316 => ErrorKind::OrderNotFound,
+        40801 | 50001 => ErrorKind::UnknownExecutionStatus,
+        201..=699 | 30000..=49999 => ErrorKind::InvalidRequest,
+        _ => ErrorKind::Other,
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(ApiError::UnmappedApiError { code, message }) => VenueError::new(VENUE, error_kind(*code), message.clone()).with_code(code),
+            Errors::ApiError(api_error) => VenueError::new(VENUE, api_error.kind(), api_error.to_string()).with_code(api_error.code()),
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::assertions_on_constants)]
 mod tests {
@@ -681,4 +825,45 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            code: 306,
+            message: "INSUFFICIENT_AVAILABLE_BALANCE".to_string(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::InsufficientFunds);
+        assert_eq!(error.code.as_deref(), Some("306"));
+        assert_eq!(error.venue, VENUE);
+
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            code: 99999,
+            message: "Something new".to_string(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::Other);
+        assert_eq!(error.code.as_deref(), Some("99999"));
+        assert_eq!(error.message, "Something new");
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(
+            ApiError::TooManyRequests.kind(),
+            ErrorKind::RateLimited { retry_after: None }
+        );
+        assert_eq!(ApiError::Unauthorized.kind(), ErrorKind::AuthFailed);
+        assert_eq!(ApiError::NoActiveOrder.kind(), ErrorKind::OrderNotFound);
+        assert_eq!(ApiError::InvalidPrice.kind(), ErrorKind::InvalidRequest);
+        assert_eq!(
+            ApiError::ErrInternal.kind(),
+            ErrorKind::UnknownExecutionStatus
+        );
+        assert_eq!(
+            ApiError::DwCreditLineNotMaintained.kind(),
+            ErrorKind::InvalidRequest
+        );
+        assert_eq!(ApiError::DwCreditLineNotMaintained.code(), 50001);
+    }
 }
@@ -1,13 +1,19 @@
 use std::borrow::Cow;
 use std::collections::BTreeMap;
-
-use chrono::Utc;
-use hmac::{Hmac, Mac};
-use rest::secrets::ExposableSecret;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use reqwest::Method;
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde_json::{Value, json};
-use sha2::Sha256;
 
-use crate::cryptocom::Errors;
+use crate::cryptocom::errors::VENUE;
+use crate::cryptocom::{EndpointType, Errors, RateLimiter};
 
 /// Signs a request using the Crypto.com signing algorithm
 ///
@@ -28,7 +34,7 @@ use crate::cryptocom::Errors;
 ///
 /// # Returns
 /// A result containing the signature as a hex string or an error if signing fails.
-fn sign_request(api_secret: &dyn ExposableSecret, method: &str, id: u64, api_key: &str, params: &Value, nonce: u64) -> Result<String, Errors> {
+async fn sign_request(api_secret: &dyn HmacSigner, method: &str, id: u64, api_key: &str, params: &Value, nonce: u64) -> Result<String, Errors> {
     // Convert params to string using the Crypto.com algorithm
     let params_string = params_to_string(params);
 
@@ -36,11 +42,12 @@ fn sign_request(api_secret: &dyn ExposableSecret, method: &str, id: u64, api_key
     let sig_payload = format!("{}{}{}{}{}", method, id, api_key, params_string, nonce);
 
     // Sign with HMAC-SHA256
-    let api_secret = api_secret.expose_secret();
-    let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-    mac.update(sig_payload.as_bytes());
+    let signature = api_secret
+        .hmac_sha256(sig_payload.as_bytes())
+        .await
+        .map_err(|_| Errors::InvalidApiKey())?;
 
-    Ok(hex::encode(mac.finalize().into_bytes()))
+    Ok(hex::encode(signature))
 }
 
 /// Converts a JSON Value to a string following Crypto.com's algorithm
@@ -82,13 +89,17 @@ fn params_to_string(value: &Value) -> String {
 /// The API key and secret are stored in encrypted form and only decrypted when needed.
 pub struct RestClient {
     /// The underlying HTTP client used for making requests.
-    pub(crate) client: reqwest::Client,
+    pub(crate) client: Arc<dyn HttpTransport>,
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
     /// The base URL for the API.
     pub(crate) base_url: Cow<'static, str>,
+    /// Rate limiter for API requests.
+    pub(crate) rate_limiter: RateLimiter,
+    /// The server clock signed requests are timestamped with.
+    pub(crate) clock: ServerClock,
 }
 
 impl RestClient {
@@ -96,26 +107,36 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `client` - The HTTP client to use
+    /// * `rate_limiter` - The rate limiter for managing API limits
     ///
     /// # Returns
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
-        client: reqwest::Client,
+        client: impl HttpTransport + 'static,
+        rate_limiter: RateLimiter,
     ) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             api_key,
             api_secret,
             base_url: base_url.into(),
+            rate_limiter,
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `nonce` of signed requests from `clock`
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Signs a request for Crypto.com private endpoints
     ///
     /// # Arguments
@@ -126,7 +147,7 @@ impl RestClient {
     ///
     /// # Returns
     /// A result containing the signature as a hex string or an error
-    pub fn sign_request(&self, method: &str, id: u64, params: &Value, nonce: u64) -> Result<String, Errors> {
+    pub async fn sign_request(&self, method: &str, id: u64, params: &Value, nonce: u64) -> Result<String, Errors> {
         let api_key = self.api_key.expose_secret();
         sign_request(
             self.api_secret.as_ref(),
@@ -136,6 +157,7 @@ impl RestClient {
             params,
             nonce,
         )
+        .await
     }
 
     /// Sends a signed request to the Crypto.com private REST API
@@ -164,6 +186,7 @@ impl RestClient {
     /// Sends a signed request to the Crypto.com private REST API
     ///
     /// Builds, signs, and sends the request, returning the parsed JSON value.
+    /// The request is rate limited according to the endpoint type derived from `method`.
     ///
     /// # Arguments
     /// * `method` - The API method name (e.g., "private/amend-order")
@@ -175,9 +198,23 @@ impl RestClient {
     where
         T: serde::de::DeserializeOwned,
     {
-        let nonce = Utc::now().timestamp_millis() as u64;
+        self.send_signed_request_with_type(method, params, EndpointType::from_path(method))
+            .await
+    }
+
+    /// Sends a signed request, rate limited against the given endpoint type
+    async fn send_signed_request_with_type<T>(&self, method: &str, params: Value, endpoint_type: EndpointType) -> crate::cryptocom::RestResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.rate_limiter
+            .check_limits(endpoint_type)
+            .await
+            .map_err(|e| Errors::Error(e.to_string()))?;
+
+        let nonce = self.clock.now_ms();
         let id = 1;
-        let signature = self.sign_request(method, id, &params, nonce)?;
+        let signature = self.sign_request(method, id, &params, nonce).await?;
 
         let request_body = json!({
             "id": id,
@@ -188,18 +225,47 @@ impl RestClient {
             "api_key": self.api_key.expose_secret(),
         });
 
-        let response = self
-            .client
-            .post(format!("{}/v1/{}", self.base_url, method))
-            .json(&request_body)
-            .send()
-            .await?;
+        let request = HttpRequest::new(Method::POST, format!("{}/v1/{}", self.base_url, method)).json(&request_body)?;
+        let response = self.client.execute(request).await?;
+
+        self.rate_limiter.increment_request(endpoint_type).await;
 
-        let result = response.json().await?;
+        let result = serde_json::from_str(&response.body)?;
         Ok(result)
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Crypto.com private methods are all sent as signed POSTs, so `request.method` is ignored
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_signed_request_with_type::<serde_json::Value>(
+                &request.endpoint,
+                request.params.unwrap_or_else(|| json!({})),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -315,10 +381,10 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_sign_request() {
+    #[tokio::test]
+    async fn test_sign_request() {
         let _api_key = Box::new(PlainTextSecret::new("test_api_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
 
         let method = "private/get-order-detail";
         let id = 11;
@@ -335,6 +401,7 @@ mod tests {
             &params,
             nonce,
         )
+        .await
         .unwrap();
 
         // Verify the signature is a hex string of the expected length (64 chars for SHA256)
@@ -345,21 +412,33 @@ mod tests {
     #[test]
     fn test_client_creation() {
         let api_key = Box::new(PlainTextSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
 
-        let rest_client = RestClient::new(api_key, api_secret, "https://api.crypto.com", client);
+        let rest_client = RestClient::new(
+            api_key,
+            api_secret,
+            "https://api.crypto.com",
+            client,
+            RateLimiter::new(),
+        );
 
         assert_eq!(rest_client.base_url, "https://api.crypto.com");
     }
 
-    #[test]
-    fn test_client_sign_request() {
+    #[tokio::test]
+    async fn test_client_sign_request() {
         let api_key = Box::new(PlainTextSecret::new("test_api_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
 
-        let rest_client = RestClient::new(api_key, api_secret, "https://api.crypto.com", client);
+        let rest_client = RestClient::new(
+            api_key,
+            api_secret,
+            "https://api.crypto.com",
+            client,
+            RateLimiter::new(),
+        );
 
         let params = json!({
             "order_id": 53287421324_u64
@@ -367,17 +446,18 @@ mod tests {
 
         let signature = rest_client
             .sign_request("private/get-order-detail", 11, &params, 1587846358253)
+            .await
             .unwrap();
 
         assert_eq!(signature.len(), 64);
         assert!(signature.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
-    #[test]
-    fn test_crypto_com_example_signing() {
+    #[tokio::test]
+    async fn test_crypto_com_example_signing() {
         // Test the exact example from the Crypto.com documentation
         let api_key = "test_api_key";
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
 
         let method = "private/get-order-detail";
         let id = 11;
@@ -386,7 +466,9 @@ mod tests {
         });
         let nonce = 1587846358253_u64;
 
-        let signature = sign_request(api_secret.as_ref(), method, id, api_key, &params, nonce).unwrap();
+        let signature = sign_request(api_secret.as_ref(), method, id, api_key, &params, nonce)
+            .await
+            .unwrap();
 
         // Verify the signature is a valid hex string
         assert_eq!(signature.len(), 64);
@@ -403,9 +485,9 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_empty_params_signing() {
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+    #[tokio::test]
+    async fn test_empty_params_signing() {
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
 
         let signature = sign_request(
             api_secret.as_ref(),
@@ -415,15 +497,16 @@ mod tests {
             &json!({}),
             1234567890,
         )
+        .await
         .unwrap();
 
         assert_eq!(signature.len(), 64);
         assert!(signature.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
-    #[test]
-    fn test_complex_params_signing() {
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+    #[tokio::test]
+    async fn test_complex_params_signing() {
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
 
         let params = json!({
             "instrument_name": "BTC_USDT",
@@ -445,6 +528,7 @@ mod tests {
             &params,
             1640995200000,
         )
+        .await
         .unwrap();
 
         // Verify signature format
@@ -0,0 +1,403 @@
+//! Typed Deribit subscription channels
+//!
+//! Builds the channel names passed to `public/subscribe` and `private/subscribe`
+//! (e.g., `book.BTC-PERPETUAL.100ms`) from typed parameters.
+//!
+//! See: <https://docs.deribit.com/v2/#subscriptions>
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::deribit::enums::{BookDepth, Currency, CurrencyPair, InstrumentKind, Resolution, SubscriptionInterval};
+
+/// Instrument selector used by channels that accept either a single instrument or a kind/currency pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstrumentSelector {
+    /// A single instrument (e.g., "BTC-PERPETUAL")
+    Instrument(String),
+
+    /// All instruments of a kind and currency (either may be `any`)
+    KindAndCurrency {
+        kind: InstrumentKind,
+        currency: Currency,
+    },
+}
+
+impl Display for InstrumentSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            InstrumentSelector::Instrument(instrument_name) => write!(f, "{}", instrument_name),
+            InstrumentSelector::KindAndCurrency { kind, currency } => write!(f, "{}.{}", kind, currency),
+        }
+    }
+}
+
+/// A Deribit subscription channel
+///
+/// Use `to_string()` (or `String::from`) to get the channel name expected by the subscribe methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Channel {
+    /// `book.{instrument_name}.{interval}` - order book changes; `raw` requires an authorized connection
+    Book {
+        instrument_name: String,
+        interval: SubscriptionInterval,
+    },
+
+    /// `book.{instrument_name}.{group}.{depth}.{interval}` - grouped order book snapshots
+    GroupedBook {
+        instrument_name: String,
+        /// Price grouping; `None` means no grouping ("none")
+        group: Option<u32>,
+        depth: BookDepth,
+        interval: SubscriptionInterval,
+    },
+
+    /// `trades.{instrument_name}.{interval}` or `trades.{kind}.{currency}.{interval}` - public trades
+    Trades {
+        selector: InstrumentSelector,
+        interval: SubscriptionInterval,
+    },
+
+    /// `ticker.{instrument_name}.{interval}` - ticker
+    Ticker {
+        instrument_name: String,
+        interval: SubscriptionInterval,
+    },
+
+    /// `incremental_ticker.{instrument_name}` - ticker snapshot followed by changed fields only
+    IncrementalTicker { instrument_name: String },
+
+    /// `deribit_price_index.{index_name}` - index price
+    PriceIndex { index_name: CurrencyPair },
+
+    /// `markprice.options.{index_name}` - mark price of all options on an index
+    MarkPriceOptions { index_name: CurrencyPair },
+
+    /// `perpetual.{instrument_name}.{interval}` - perpetual interest rate
+    Perpetual {
+        instrument_name: String,
+        interval: SubscriptionInterval,
+    },
+
+    /// `quote.{instrument_name}` - best bid and ask
+    Quote { instrument_name: String },
+
+    /// `chart.trades.{instrument_name}.{resolution}` - candles
+    ChartTrades {
+        instrument_name: String,
+        resolution: Resolution,
+    },
+
+    /// `user.orders.{instrument_name}.{interval}` or `user.orders.{kind}.{currency}.{interval}` - own order updates
+    UserOrders {
+        selector: InstrumentSelector,
+        interval: SubscriptionInterval,
+    },
+
+    /// `user.trades.{instrument_name}.{interval}` or `user.trades.{kind}.{currency}.{interval}` - own trades
+    UserTrades {
+        selector: InstrumentSelector,
+        interval: SubscriptionInterval,
+    },
+
+    /// `user.portfolio.{currency}` - portfolio and margin state
+    UserPortfolio { currency: Currency },
+
+    /// `user.changes.{instrument_name}.{interval}` or `user.changes.{kind}.{currency}.{interval}` - orders, trades and positions changed together
+    UserChanges {
+        selector: InstrumentSelector,
+        interval: SubscriptionInterval,
+    },
+}
+
+impl Channel {
+    /// Order book changes for one instrument
+    pub fn book(instrument_name: impl Into<String>, interval: SubscriptionInterval) -> Self {
+        Channel::Book {
+            instrument_name: instrument_name.into(),
+            interval,
+        }
+    }
+
+    /// Grouped order book snapshots for one instrument
+    pub fn grouped_book(instrument_name: impl Into<String>, group: Option<u32>, depth: BookDepth, interval: SubscriptionInterval) -> Self {
+        Channel::GroupedBook {
+            instrument_name: instrument_name.into(),
+            group,
+            depth,
+            interval,
+        }
+    }
+
+    /// Public trades for one instrument
+    pub fn trades(instrument_name: impl Into<String>, interval: SubscriptionInterval) -> Self {
+        Channel::Trades {
+            selector: InstrumentSelector::Instrument(instrument_name.into()),
+            interval,
+        }
+    }
+
+    /// Public trades for all instruments of a kind and currency
+    pub fn trades_by_kind(kind: InstrumentKind, currency: Currency, interval: SubscriptionInterval) -> Self {
+        Channel::Trades {
+            selector: InstrumentSelector::KindAndCurrency { kind, currency },
+            interval,
+        }
+    }
+
+    /// Ticker for one instrument
+    pub fn ticker(instrument_name: impl Into<String>, interval: SubscriptionInterval) -> Self {
+        Channel::Ticker {
+            instrument_name: instrument_name.into(),
+            interval,
+        }
+    }
+
+    /// Incremental ticker for one instrument
+    pub fn incremental_ticker(instrument_name: impl Into<String>) -> Self {
+        Channel::IncrementalTicker {
+            instrument_name: instrument_name.into(),
+        }
+    }
+
+    /// Index price
+    pub fn price_index(index_name: CurrencyPair) -> Self {
+        Channel::PriceIndex { index_name }
+    }
+
+    /// Mark prices of all options on an index
+    pub fn mark_price_options(index_name: CurrencyPair) -> Self {
+        Channel::MarkPriceOptions { index_name }
+    }
+
+    /// Perpetual interest rate for one instrument
+    pub fn perpetual(instrument_name: impl Into<String>, interval: SubscriptionInterval) -> Self {
+        Channel::Perpetual {
+            instrument_name: instrument_name.into(),
+            interval,
+        }
+    }
+
+    /// Best bid and ask for one instrument
+    pub fn quote(instrument_name: impl Into<String>) -> Self {
+        Channel::Quote {
+            instrument_name: instrument_name.into(),
+        }
+    }
+
+    /// Candles for one instrument
+    pub fn chart_trades(instrument_name: impl Into<String>, resolution: Resolution) -> Self {
+        Channel::ChartTrades {
+            instrument_name: instrument_name.into(),
+            resolution,
+        }
+    }
+
+    /// Own order updates
+    pub fn user_orders(selector: InstrumentSelector, interval: SubscriptionInterval) -> Self {
+        Channel::UserOrders { selector, interval }
+    }
+
+    /// Own trades
+    pub fn user_trades(selector: InstrumentSelector, interval: SubscriptionInterval) -> Self {
+        Channel::UserTrades { selector, interval }
+    }
+
+    /// Portfolio and margin state of a currency
+    pub fn user_portfolio(currency: Currency) -> Self {
+        Channel::UserPortfolio { currency }
+    }
+
+    /// Combined order, trade and position changes
+    pub fn user_changes(selector: InstrumentSelector, interval: SubscriptionInterval) -> Self {
+        Channel::UserChanges { selector, interval }
+    }
+
+    /// Whether the channel can only be subscribed on an authenticated connection
+    pub fn is_private(&self) -> bool {
+        matches!(
+            self,
+            Channel::UserOrders { .. } | Channel::UserTrades { .. } | Channel::UserPortfolio { .. } | Channel::UserChanges { .. }
+        )
+    }
+}
+
+impl Display for Channel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Channel::Book {
+                instrument_name,
+                interval,
+            } => write!(f, "book.{}.{}", instrument_name, interval),
+            Channel::GroupedBook {
+                instrument_name,
+                group,
+                depth,
+                interval,
+            } => match group {
+                Some(group) => write!(
+                    f,
+                    "book.{}.{}.{}.{}",
+                    instrument_name, group, depth, interval
+                ),
+                None => write!(f, "book.{}.none.{}.{}", instrument_name, depth, interval),
+            },
+            Channel::Trades { selector, interval } => write!(f, "trades.{}.{}", selector, interval),
+            Channel::Ticker {
+                instrument_name,
+                interval,
+            } => write!(f, "ticker.{}.{}", instrument_name, interval),
+            Channel::IncrementalTicker { instrument_name } => write!(f, "incremental_ticker.{}", instrument_name),
+            Channel::PriceIndex { index_name } => write!(f, "deribit_price_index.{}", index_name),
+            Channel::MarkPriceOptions { index_name } => write!(f, "markprice.options.{}", index_name),
+            Channel::Perpetual {
+                instrument_name,
+                interval,
+            } => write!(f, "perpetual.{}.{}", instrument_name, interval),
+            Channel::Quote { instrument_name } => write!(f, "quote.{}", instrument_name),
+            Channel::ChartTrades {
+                instrument_name,
+                resolution,
+            } => write!(f, "chart.trades.{}.{}", instrument_name, resolution),
+            Channel::UserOrders { selector, interval } => write!(f, "user.orders.{}.{}", selector, interval),
+            Channel::UserTrades { selector, interval } => write!(f, "user.trades.{}.{}", selector, interval),
+            Channel::UserPortfolio { currency } => write!(f, "user.portfolio.{}", currency.to_string().to_lowercase()),
+            Channel::UserChanges { selector, interval } => write!(f, "user.changes.{}.{}", selector, interval),
+        }
+    }
+}
+
+impl From<Channel> for String {
+    fn from(channel: Channel) -> Self {
+        channel.to_string()
+    }
+}
+
+impl From<&Channel> for String {
+    fn from(channel: &Channel) -> Self {
+        channel.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_book_channels() {
+        assert_eq!(
+            Channel::book("BTC-PERPETUAL", SubscriptionInterval::Ms100).to_string(),
+            "book.BTC-PERPETUAL.100ms"
+        );
+        assert_eq!(
+            Channel::book("BTC-PERPETUAL", SubscriptionInterval::Raw).to_string(),
+            "book.BTC-PERPETUAL.raw"
+        );
+        assert_eq!(
+            Channel::grouped_book(
+                "ETH-PERPETUAL",
+                Some(5),
+                BookDepth::Ten,
+                SubscriptionInterval::Ms100
+            )
+            .to_string(),
+            "book.ETH-PERPETUAL.5.10.100ms"
+        );
+        assert_eq!(
+            Channel::grouped_book(
+                "ETH-PERPETUAL",
+                None,
+                BookDepth::One,
+                SubscriptionInterval::Agg2
+            )
+            .to_string(),
+            "book.ETH-PERPETUAL.none.1.agg2"
+        );
+    }
+
+    #[test]
+    fn test_market_data_channels() {
+        assert_eq!(
+            Channel::trades("BTC-PERPETUAL", SubscriptionInterval::Raw).to_string(),
+            "trades.BTC-PERPETUAL.raw"
+        );
+        assert_eq!(
+            Channel::trades_by_kind(
+                InstrumentKind::Option,
+                Currency::BTC,
+                SubscriptionInterval::Ms100
+            )
+            .to_string(),
+            "trades.option.BTC.100ms"
+        );
+        assert_eq!(
+            Channel::ticker("BTC-PERPETUAL", SubscriptionInterval::Ms100).to_string(),
+            "ticker.BTC-PERPETUAL.100ms"
+        );
+        assert_eq!(
+            Channel::incremental_ticker("BTC-PERPETUAL").to_string(),
+            "incremental_ticker.BTC-PERPETUAL"
+        );
+        assert_eq!(
+            Channel::price_index(CurrencyPair::BtcUsd).to_string(),
+            "deribit_price_index.btc_usd"
+        );
+        assert_eq!(
+            Channel::mark_price_options(CurrencyPair::EthUsd).to_string(),
+            "markprice.options.eth_usd"
+        );
+        assert_eq!(
+            Channel::perpetual("BTC-PERPETUAL", SubscriptionInterval::Raw).to_string(),
+            "perpetual.BTC-PERPETUAL.raw"
+        );
+        assert_eq!(
+            Channel::quote("BTC-PERPETUAL").to_string(),
+            "quote.BTC-PERPETUAL"
+        );
+        assert_eq!(
+            Channel::chart_trades("BTC-PERPETUAL", Resolution::OneDay).to_string(),
+            "chart.trades.BTC-PERPETUAL.1D"
+        );
+    }
+
+    #[test]
+    fn test_user_channels() {
+        let any_future = InstrumentSelector::KindAndCurrency {
+            kind: InstrumentKind::Future,
+            currency: Currency::Any,
+        };
+
+        assert_eq!(
+            Channel::user_orders(any_future.clone(), SubscriptionInterval::Raw).to_string(),
+            "user.orders.future.any.raw"
+        );
+        assert_eq!(
+            Channel::user_trades(
+                InstrumentSelector::Instrument("BTC-PERPETUAL".to_string()),
+                SubscriptionInterval::Ms100
+            )
+            .to_string(),
+            "user.trades.BTC-PERPETUAL.100ms"
+        );
+        assert_eq!(
+            Channel::user_portfolio(Currency::BTC).to_string(),
+            "user.portfolio.btc"
+        );
+        assert_eq!(
+            Channel::user_changes(any_future, SubscriptionInterval::Raw).to_string(),
+            "user.changes.future.any.raw"
+        );
+    }
+
+    #[test]
+    fn test_is_private() {
+        assert!(!Channel::quote("BTC-PERPETUAL").is_private());
+        assert!(Channel::user_portfolio(Currency::ETH).is_private());
+    }
+
+    #[test]
+    fn test_channel_into_string() {
+        let name: String = Channel::quote("BTC-PERPETUAL").into();
+
+        assert_eq!(name, "quote.BTC-PERPETUAL");
+    }
+}
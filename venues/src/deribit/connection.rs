@@ -0,0 +1,424 @@
+//! Deribit JSON-RPC connection shared by the public and private WebSocket clients
+//!
+//! A connection owns the writer and reader tasks of one socket. Requests get their id when
+//! they are built and are registered before the frame is written; the reader routes each
+//! response back to its request by id, answers `test_request` heartbeats and forwards the
+//! remaining notifications to the client's message stream. The clients only add what is
+//! specific to them, such as authentication.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, Stream, StreamExt};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio::net::TcpStream;
+use tokio::sync::{Mutex, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+use websockets::BoxResult;
+
+use crate::deribit::message::{HeartbeatNotification, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse};
+use crate::deribit::notification::ChannelNotification;
+use crate::deribit::{DeribitWebSocketError, EndpointType, HeartbeatType, RateLimiter};
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Stream of server initiated messages yielded by a client's `message_stream`
+pub(crate) type MessageStream<M> = Pin<Box<dyn Stream<Item = BoxResult<M>> + Send>>;
+
+/// Server initiated message type of a client
+pub(crate) trait RpcMessage: Send + 'static {
+    /// Wrap typed data pushed for a subscribed channel
+    fn subscription(notification: ChannelNotification) -> Self;
+
+    /// Wrap a heartbeat notification
+    fn heartbeat(heartbeat: HeartbeatNotification) -> Self;
+}
+
+/// State shared by a client and the tasks of its connections
+///
+/// Outlives individual connections, so request ids keep increasing and the message stream
+/// survives reconnects.
+pub(crate) struct RpcState<M> {
+    /// Connection state flag
+    connected: AtomicBool,
+
+    /// Rate limiter applied to every request
+    pub(crate) rate_limiter: RateLimiter,
+
+    /// Request ID counter for JSON-RPC
+    request_id: AtomicU64,
+
+    /// Pending requests awaiting responses, keyed by JSON-RPC id
+    pub(crate) pending_requests: Mutex<HashMap<u64, oneshot::Sender<JsonRpcResponse>>>,
+
+    /// Sender side of the notification channel
+    notification_tx: mpsc::UnboundedSender<BoxResult<M>>,
+}
+
+impl<M: RpcMessage> RpcState<M> {
+    /// Create the state and the receiver side of its notification channel
+    pub(crate) fn new(rate_limiter: RateLimiter) -> (Self, mpsc::UnboundedReceiver<BoxResult<M>>) {
+        let (notification_tx, notification_rx) = mpsc::unbounded_channel();
+        let state = Self {
+            connected: AtomicBool::new(false),
+            rate_limiter,
+            request_id: AtomicU64::new(1),
+            pending_requests: Mutex::new(HashMap::new()),
+            notification_tx,
+        };
+        (state, notification_rx)
+    }
+
+    /// Get the next request ID
+    pub(crate) fn next_request_id(&self) -> u64 {
+        self.request_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Check if a connection is open
+    pub(crate) fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Publish a message on the notification stream; a dropped receiver is not an error
+    pub(crate) fn notify(&self, message: BoxResult<M>) {
+        let _ = self.notification_tx.send(message);
+    }
+
+    /// Mark the connection as closed and fail every outstanding request
+    async fn close(&self) {
+        self.connected.store(false, Ordering::SeqCst);
+        // Dropping the senders wakes all waiters with a receive error
+        self.pending_requests.lock().await.clear();
+    }
+
+    /// Route a single text frame to the pending request or the notification stream
+    async fn dispatch(&self, text: &str, outbound: &mpsc::UnboundedSender<Message>) {
+        let value: serde_json::Value = match serde_json::from_str(text) {
+            Ok(value) => value,
+            Err(err) => {
+                self.notify(Err(Box::new(err)));
+                return;
+            }
+        };
+
+        if value.get("id").is_some_and(|id| !id.is_null()) {
+            match serde_json::from_value::<JsonRpcResponse>(value) {
+                Ok(response) => {
+                    if let Some(tx) = self.pending_requests.lock().await.remove(&response.id) {
+                        let _ = tx.send(response);
+                    }
+                }
+                Err(err) => self.notify(Err(Box::new(err))),
+            }
+            return;
+        }
+
+        let message = match serde_json::from_value::<JsonRpcNotification>(value) {
+            Ok(JsonRpcNotification::Subscription(notification)) => ChannelNotification::try_from(notification)
+                .map(M::subscription)
+                .map_err(|err| Box::new(err) as websockets::BoxError),
+            Ok(JsonRpcNotification::Heartbeat(heartbeat)) => {
+                if heartbeat.heartbeat_type == HeartbeatType::TestRequest {
+                    self.answer_test_request(outbound);
+                }
+                Ok(M::heartbeat(heartbeat))
+            }
+            Err(err) => Err(Box::new(err) as websockets::BoxError),
+        };
+        self.notify(message);
+    }
+
+    /// Reply to a `test_request` heartbeat so the server keeps the connection open
+    fn answer_test_request(&self, outbound: &mpsc::UnboundedSender<Message>) {
+        let id = self.next_request_id();
+        if let Ok(frame) = serde_json::to_string(&JsonRpcRequest::test(id)) {
+            let _ = outbound.send(Message::Text(frame.into()));
+        }
+    }
+}
+
+/// Cloneable handle to send requests over one connection
+pub(crate) struct RpcHandle<M> {
+    /// State shared with the connection tasks
+    state: Arc<RpcState<M>>,
+
+    /// Outbound frame queue drained by the writer task
+    outbound: mpsc::UnboundedSender<Message>,
+}
+
+impl<M> Clone for RpcHandle<M> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            outbound: self.outbound.clone(),
+        }
+    }
+}
+
+impl<M: RpcMessage> RpcHandle<M> {
+    /// Send a JSON-RPC request and wait up to `timeout` for the matching response
+    ///
+    /// # Returns
+    /// The deserialized `result` field, or `DeribitWebSocketError::JsonRpc` if Deribit
+    /// answered with an `error` object.
+    pub(crate) async fn send_request<P, R>(&self, method: &str, params: &P, timeout: Duration) -> Result<R, DeribitWebSocketError>
+    where
+        P: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let state = &self.state;
+        if !state.is_connected() {
+            return Err(DeribitWebSocketError::NotConnected);
+        }
+
+        let params = serde_json::to_value(params)?;
+        let endpoint_type = EndpointType::from_path(method);
+        let instrument_name = params.get("instrument_name").and_then(|name| name.as_str());
+        state
+            .rate_limiter
+            .admit(method, endpoint_type, instrument_name)
+            .await?;
+
+        let id = state.next_request_id();
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id,
+            method: method.to_string(),
+            params: Some(params),
+        };
+        let frame = serde_json::to_string(&request)?;
+
+        let (tx, rx) = oneshot::channel();
+        state.pending_requests.lock().await.insert(id, tx);
+
+        if self.outbound.send(Message::Text(frame.into())).is_err() {
+            state.pending_requests.lock().await.remove(&id);
+            return Err(DeribitWebSocketError::NotConnected);
+        }
+
+        let response = match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => {
+                return Err(DeribitWebSocketError::Connection(
+                    "Connection closed before response was received".to_string(),
+                ));
+            }
+            Err(_) => {
+                state.pending_requests.lock().await.remove(&id);
+                return Err(DeribitWebSocketError::Timeout { id });
+            }
+        };
+
+        if let Some(error) = response.error {
+            state.rate_limiter.record_error(error.code).await;
+            return Err(DeribitWebSocketError::JsonRpc {
+                code: error.code,
+                message: error.message,
+            });
+        }
+
+        let result = response
+            .result
+            .ok_or(DeribitWebSocketError::InvalidResponse { id })?;
+        Ok(serde_json::from_value(result)?)
+    }
+}
+
+/// One open socket and its background tasks
+///
+/// Dropping the connection stops its tasks without a close handshake; use `close` for an
+/// orderly shutdown.
+pub(crate) struct RpcConnection<M> {
+    /// Request handle of this connection
+    handle: RpcHandle<M>,
+
+    /// Background tasks (writer first, then reader and any tasks added by the client)
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl<M: RpcMessage> RpcConnection<M> {
+    /// Open a socket to `url` and start its writer and reader tasks
+    ///
+    /// `on_close` runs once the reader sees the socket close, after outstanding requests
+    /// have been failed.
+    pub(crate) async fn open<F, Fut>(url: &str, state: Arc<RpcState<M>>, on_close: F) -> BoxResult<Self>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (ws_stream, _) = connect_async(url).await?;
+        let (sink, stream) = ws_stream.split();
+        let (outbound_tx, outbound_rx) = mpsc::unbounded_channel();
+
+        let tasks = vec![
+            tokio::spawn(write_loop(sink, outbound_rx)),
+            tokio::spawn(read_loop(
+                stream,
+                state.clone(),
+                outbound_tx.clone(),
+                on_close,
+            )),
+        ];
+        state.connected.store(true, Ordering::SeqCst);
+        Ok(Self {
+            handle: RpcHandle {
+                state,
+                outbound: outbound_tx,
+            },
+            tasks,
+        })
+    }
+
+    /// Request handle of this connection
+    pub(crate) fn handle(&self) -> &RpcHandle<M> {
+        &self.handle
+    }
+
+    /// Tie a task to the lifetime of this connection
+    pub(crate) fn spawn(&mut self, task: impl Future<Output = ()> + Send + 'static) {
+        self.tasks.push(tokio::spawn(task));
+    }
+
+    /// Send a close frame, stop the tasks and fail every outstanding request
+    pub(crate) async fn close(mut self) {
+        let _ = self.handle.outbound.send(Message::Close(None));
+        // Give the writer the chance to flush the close frame before the reader is stopped
+        if let Some(writer) = self.tasks.first_mut() {
+            let _ = tokio::time::timeout(Duration::from_secs(1), writer).await;
+        }
+        self.shutdown().await;
+    }
+
+    /// Stop the tasks without a close handshake and fail every outstanding request
+    ///
+    /// Waits until the tasks have actually stopped, so a reader that was still running its
+    /// close handling cannot mark a connection opened afterwards as closed.
+    pub(crate) async fn shutdown(mut self) {
+        for task in self.tasks.drain(..) {
+            task.abort();
+            if !task.is_finished() {
+                let _ = task.await;
+            }
+        }
+        self.handle.state.close().await;
+    }
+}
+
+impl<M> RpcConnection<M> {
+    /// Stop the background tasks
+    fn abort_tasks(&mut self) {
+        for task in self.tasks.drain(..) {
+            task.abort();
+        }
+    }
+}
+
+impl<M> Drop for RpcConnection<M> {
+    fn drop(&mut self) {
+        self.abort_tasks();
+    }
+}
+
+/// Turn the notification receiver into a client's message stream
+///
+/// The stream survives reconnects. It can only be taken once; subsequent calls return an
+/// empty stream.
+pub(crate) fn message_stream<M: Send + 'static>(notification_rx: &mut Option<mpsc::UnboundedReceiver<BoxResult<M>>>) -> MessageStream<M> {
+    match notification_rx.take() {
+        Some(rx) => Box::pin(futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|message| (message, rx))
+        })),
+        None => Box::pin(futures::stream::empty()),
+    }
+}
+
+/// Read frames until the socket closes, dispatching each to its consumer
+async fn read_loop<M, F, Fut>(mut stream: SplitStream<WsStream>, state: Arc<RpcState<M>>, outbound: mpsc::UnboundedSender<Message>, on_close: F)
+where
+    M: RpcMessage,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    while let Some(frame) = stream.next().await {
+        match frame {
+            Ok(Message::Text(text)) => state.dispatch(text.as_str(), &outbound).await,
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(err) => {
+                state.notify(Err(Box::new(err)));
+                break;
+            }
+        }
+    }
+    state.close().await;
+    on_close().await;
+}
+
+/// Write queued frames to the socket; a close frame ends the task
+async fn write_loop(mut sink: SplitSink<WsStream, Message>, mut outbound: mpsc::UnboundedReceiver<Message>) {
+    while let Some(message) = outbound.recv().await {
+        let is_close = matches!(message, Message::Close(_));
+        if sink.send(message).await.is_err() || is_close {
+            break;
+        }
+    }
+    let _ = sink.close().await;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    use super::*;
+
+    /// Minimal stand-in for the Deribit JSON-RPC server.
+    ///
+    /// Accepts any number of connections. Every request is passed to `handler`, which returns
+    /// the frames to send back. Frames received by the server are forwarded on the returned channel.
+    pub(crate) async fn spawn_server<F>(handler: F) -> (String, mpsc::UnboundedReceiver<Message>)
+    where
+        F: Fn(&serde_json::Value) -> Vec<serde_json::Value> + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (seen_tx, seen_rx) = mpsc::unbounded_channel();
+        let handler = Arc::new(handler);
+
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let seen_tx = seen_tx.clone();
+                tokio::spawn(async move {
+                    let mut ws = accept_async(socket).await.unwrap();
+                    while let Some(Ok(message)) = ws.next().await {
+                        let _ = seen_tx.send(message.clone());
+                        if let Message::Text(text) = message {
+                            let request: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                            for reply in handler(&request) {
+                                ws.send(Message::Text(reply.to_string().into()))
+                                    .await
+                                    .unwrap();
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        (format!("ws://{}", addr), seen_rx)
+    }
+
+    /// Echo the request params back as the result
+    pub(crate) fn echo_reply(request: &serde_json::Value) -> serde_json::Value {
+        serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": request["params"]})
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Currency types supported by Deribit
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -555,8 +555,10 @@ impl Display for Sorting {
     }
 }
 
-/// Tick direction for trades  
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Tick direction for trades
+///
+/// Deribit sends the tick direction as a number (0-3); the string form is accepted as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum TickDirection {
     #[serde(rename = "0")]
     PlusTick,
@@ -568,6 +570,29 @@ pub enum TickDirection {
     ZeroMinusTick,
 }
 
+impl<'de> Deserialize<'de> for TickDirection {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawTickDirection {
+            Number(u8),
+            Text(String),
+        }
+
+        let code = match RawTickDirection::deserialize(deserializer)? {
+            RawTickDirection::Number(code) => code,
+            RawTickDirection::Text(text) => text.parse().map_err(serde::de::Error::custom)?,
+        };
+        match code {
+            0 => Ok(TickDirection::PlusTick),
+            1 => Ok(TickDirection::ZeroPlusTick),
+            2 => Ok(TickDirection::MinusTick),
+            3 => Ok(TickDirection::ZeroMinusTick),
+            other => Err(serde::de::Error::custom(format!("invalid tick direction: {}", other))),
+        }
+    }
+}
+
 impl Display for TickDirection {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -1319,6 +1344,129 @@ mod tests {
         assert_eq!(format!("{}", CurrencyPair::PaxgBtc), "paxg_btc");
         assert_eq!(format!("{}", CurrencyPair::StethEth), "steth_eth");
     }
+
+    #[test]
+    fn test_tick_direction_from_number() {
+        let plus_tick: TickDirection = serde_json::from_str("0").unwrap();
+        let zero_minus_tick: TickDirection = serde_json::from_str("3").unwrap();
+
+        assert_eq!(plus_tick, TickDirection::PlusTick);
+        assert_eq!(zero_minus_tick, TickDirection::ZeroMinusTick);
+        assert!(serde_json::from_str::<TickDirection>("4").is_err());
+    }
+
+    #[test]
+    fn test_subscription_interval_serialization() {
+        assert_eq!(serde_json::to_string(&SubscriptionInterval::Raw).unwrap(), "\"raw\"");
+        assert_eq!(serde_json::to_string(&SubscriptionInterval::Ms100).unwrap(), "\"100ms\"");
+        assert_eq!(serde_json::to_string(&SubscriptionInterval::Agg2).unwrap(), "\"agg2\"");
+
+        let interval: SubscriptionInterval = serde_json::from_str("\"100ms\"").unwrap();
+        assert_eq!(interval, SubscriptionInterval::Ms100);
+    }
+
+    #[test]
+    fn test_subscription_interval_display() {
+        assert_eq!(format!("{}", SubscriptionInterval::Raw), "raw");
+        assert_eq!(format!("{}", SubscriptionInterval::Ms100), "100ms");
+        assert_eq!(format!("{}", SubscriptionInterval::Agg2), "agg2");
+    }
+
+    #[test]
+    fn test_book_depth_display() {
+        assert_eq!(format!("{}", BookDepth::One), "1");
+        assert_eq!(format!("{}", BookDepth::Ten), "10");
+        assert_eq!(format!("{}", BookDepth::Twenty), "20");
+    }
+
+    #[test]
+    fn test_resolution_serialization() {
+        assert_eq!(serde_json::to_string(&Resolution::OneMinute).unwrap(), "\"1\"");
+        assert_eq!(serde_json::to_string(&Resolution::OneDay).unwrap(), "\"1D\"");
+
+        let resolution: Resolution = serde_json::from_str("\"720\"").unwrap();
+        assert_eq!(resolution, Resolution::TwelveHours);
+    }
+
+    #[test]
+    fn test_resolution_display() {
+        assert_eq!(format!("{}", Resolution::FiveMinutes), "5");
+        assert_eq!(format!("{}", Resolution::OneHour), "60");
+        assert_eq!(format!("{}", Resolution::OneDay), "1D");
+    }
+
+    #[test]
+    fn test_position_direction_serialization() {
+        assert_eq!(serde_json::to_string(&PositionDirection::Zero).unwrap(), "\"zero\"");
+
+        let direction: PositionDirection = serde_json::from_str("\"sell\"").unwrap();
+        assert_eq!(direction, PositionDirection::Sell);
+        assert_eq!(format!("{}", PositionDirection::Buy), "buy");
+    }
+
+    #[test]
+    fn test_book_change_enums_deserialization() {
+        let update_type: BookUpdateType = serde_json::from_str("\"snapshot\"").unwrap();
+        let action: BookAction = serde_json::from_str("\"delete\"").unwrap();
+
+        assert_eq!(update_type, BookUpdateType::Snapshot);
+        assert_eq!(action, BookAction::Delete);
+    }
+
+    #[test]
+    fn test_instrument_enums_serialization() {
+        assert_eq!(serde_json::to_string(&OptionType::Call).unwrap(), "\"call\"");
+        assert_eq!(serde_json::to_string(&SettlementPeriod::Perpetual).unwrap(), "\"perpetual\"");
+        assert_eq!(serde_json::to_string(&InstrumentType::Reversed).unwrap(), "\"reversed\"");
+
+        let option_type: OptionType = serde_json::from_str("\"put\"").unwrap();
+        let period: SettlementPeriod = serde_json::from_str("\"week\"").unwrap();
+        let instrument_type: InstrumentType = serde_json::from_str("\"linear\"").unwrap();
+        assert_eq!(option_type, OptionType::Put);
+        assert_eq!(period, SettlementPeriod::Week);
+        assert_eq!(instrument_type, InstrumentType::Linear);
+        assert_eq!(format!("{}", SettlementPeriod::Month), "month");
+    }
+
+    #[test]
+    fn test_chart_enums_serialization() {
+        assert_eq!(serde_json::to_string(&VolatilityIndexResolution::OneHour).unwrap(), "\"3600\"");
+        assert_eq!(format!("{}", VolatilityIndexResolution::OneDay), "1D");
+
+        let status: ChartDataStatus = serde_json::from_str("\"no_data\"").unwrap();
+        assert_eq!(status, ChartDataStatus::NoData);
+    }
+
+    #[test]
+    fn test_order_entry_enums_serialization() {
+        assert_eq!(serde_json::to_string(&OrderEntryType::StopLimit).unwrap(), "\"stop_limit\"");
+        assert_eq!(serde_json::to_string(&OrderEntryType::MarketLimit).unwrap(), "\"market_limit\"");
+        assert_eq!(
+            serde_json::to_string(&LinkedOrderType::OneTriggersOneCancelsOther).unwrap(),
+            "\"one_triggers_one_cancels_other\""
+        );
+
+        let order_type: OrderEntryType = serde_json::from_str("\"trailing_stop\"").unwrap();
+        assert_eq!(order_type, OrderEntryType::TrailingStop);
+        assert_eq!(format!("{}", OrderEntryType::TakeMarket), "take_market");
+        assert_eq!(format!("{}", LinkedOrderType::OneCancelsOther), "one_cancels_other");
+    }
+
+    #[test]
+    fn test_account_type_serialization() {
+        assert_eq!(serde_json::to_string(&AccountType::Main).unwrap(), "\"main\"");
+        let account_type: AccountType = serde_json::from_str("\"subaccount\"").unwrap();
+        assert_eq!(account_type, AccountType::Subaccount);
+        assert_eq!(format!("{}", AccountType::Subaccount), "subaccount");
+    }
+
+    #[test]
+    fn test_quote_side_serialization() {
+        assert_eq!(serde_json::to_string(&QuoteSide::Bid).unwrap(), "\"bid\"");
+        let side: QuoteSide = serde_json::from_str("\"ask\"").unwrap();
+        assert_eq!(side, QuoteSide::Ask);
+        assert_eq!(format!("{}", QuoteSide::Ask), "ask");
+    }
 }
 
 /// Order time in force options for Deribit.
@@ -1362,3 +1510,465 @@ pub enum TriggerFillCondition {
     #[serde(rename = "incremental")]
     Incremental,
 }
+
+/// Grant type used to authenticate a connection via `public/auth`.
+///
+/// Valid values: "client_credentials", "client_signature", "refresh_token"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum GrantType {
+    /// Authenticate by sending the client id and client secret in plain form
+    #[serde(rename = "client_credentials")]
+    ClientCredentials,
+
+    /// Authenticate with an HMAC-SHA256 signature so the client secret never leaves the process
+    #[default]
+    #[serde(rename = "client_signature")]
+    ClientSignature,
+
+    /// Exchange a refresh token from a previous authentication for a new access token
+    #[serde(rename = "refresh_token")]
+    RefreshToken,
+}
+
+/// Type of a heartbeat notification pushed by Deribit.
+///
+/// - "heartbeat": Informational heartbeat, no action required.
+/// - "test_request": The server expects a `public/test` request in reply, otherwise the connection is closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeartbeatType {
+    /// Informational heartbeat, no action required
+    #[serde(rename = "heartbeat")]
+    Heartbeat,
+
+    /// The server expects a `public/test` request in reply
+    #[serde(rename = "test_request")]
+    TestRequest,
+}
+
+/// Notification interval of a subscription channel.
+///
+/// Valid values: "raw" (every change, requires an authorized connection for most channels), "100ms", "agg2"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubscriptionInterval {
+    /// Every change is sent as it happens
+    #[serde(rename = "raw")]
+    Raw,
+
+    /// Changes are aggregated and sent every 100 milliseconds
+    #[serde(rename = "100ms")]
+    Ms100,
+
+    /// Changes are aggregated over a longer, platform defined interval
+    #[serde(rename = "agg2")]
+    Agg2,
+}
+
+impl Display for SubscriptionInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            SubscriptionInterval::Raw => write!(f, "raw"),
+            SubscriptionInterval::Ms100 => write!(f, "100ms"),
+            SubscriptionInterval::Agg2 => write!(f, "agg2"),
+        }
+    }
+}
+
+/// Number of price levels in a grouped order book subscription.
+///
+/// Valid values: 1, 10, 20
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookDepth {
+    #[serde(rename = "1")]
+    One,
+    #[serde(rename = "10")]
+    Ten,
+    #[serde(rename = "20")]
+    Twenty,
+}
+
+impl Display for BookDepth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            BookDepth::One => write!(f, "1"),
+            BookDepth::Ten => write!(f, "10"),
+            BookDepth::Twenty => write!(f, "20"),
+        }
+    }
+}
+
+/// Candle resolution, in minutes or "1D" for daily candles.
+///
+/// Valid values: "1", "3", "5", "10", "15", "30", "60", "120", "180", "360", "720", "1D"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Resolution {
+    #[serde(rename = "1")]
+    OneMinute,
+    #[serde(rename = "3")]
+    ThreeMinutes,
+    #[serde(rename = "5")]
+    FiveMinutes,
+    #[serde(rename = "10")]
+    TenMinutes,
+    #[serde(rename = "15")]
+    FifteenMinutes,
+    #[serde(rename = "30")]
+    ThirtyMinutes,
+    #[serde(rename = "60")]
+    OneHour,
+    #[serde(rename = "120")]
+    TwoHours,
+    #[serde(rename = "180")]
+    ThreeHours,
+    #[serde(rename = "360")]
+    SixHours,
+    #[serde(rename = "720")]
+    TwelveHours,
+    #[serde(rename = "1D")]
+    OneDay,
+}
+
+impl Display for Resolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Resolution::OneMinute => write!(f, "1"),
+            Resolution::ThreeMinutes => write!(f, "3"),
+            Resolution::FiveMinutes => write!(f, "5"),
+            Resolution::TenMinutes => write!(f, "10"),
+            Resolution::FifteenMinutes => write!(f, "15"),
+            Resolution::ThirtyMinutes => write!(f, "30"),
+            Resolution::OneHour => write!(f, "60"),
+            Resolution::TwoHours => write!(f, "120"),
+            Resolution::ThreeHours => write!(f, "180"),
+            Resolution::SixHours => write!(f, "360"),
+            Resolution::TwelveHours => write!(f, "720"),
+            Resolution::OneDay => write!(f, "1D"),
+        }
+    }
+}
+
+/// Whether an order book or incremental ticker notification is a full snapshot or a change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookUpdateType {
+    #[serde(rename = "snapshot")]
+    Snapshot,
+    #[serde(rename = "change")]
+    Change,
+}
+
+/// Action applied to a price level in an order book change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookAction {
+    /// A new price level
+    #[serde(rename = "new")]
+    New,
+
+    /// The amount of an existing price level changed
+    #[serde(rename = "change")]
+    Change,
+
+    /// The price level was removed
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+/// Direction of a position; "zero" when the position is flat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PositionDirection {
+    #[serde(rename = "buy")]
+    Buy,
+    #[serde(rename = "sell")]
+    Sell,
+    #[serde(rename = "zero")]
+    Zero,
+}
+
+impl Display for PositionDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PositionDirection::Buy => write!(f, "buy"),
+            PositionDirection::Sell => write!(f, "sell"),
+            PositionDirection::Zero => write!(f, "zero"),
+        }
+    }
+}
+
+/// Trading state of an instrument's order book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TickerState {
+    /// The order book accepts orders
+    #[serde(rename = "open")]
+    Open,
+
+    /// The order book is closed, e.g. after expiry or during settlement
+    #[serde(rename = "closed")]
+    Closed,
+}
+
+impl Display for TickerState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            TickerState::Open => write!(f, "open"),
+            TickerState::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+/// Margin model enabled for an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarginModel {
+    /// Portfolio margin across all currencies
+    #[serde(rename = "cross_pm")]
+    CrossPm,
+
+    /// Standard margin across all currencies
+    #[serde(rename = "cross_sm")]
+    CrossSm,
+
+    /// Portfolio margin computed per currency
+    #[serde(rename = "segregated_pm")]
+    SegregatedPm,
+
+    /// Standard margin computed per currency
+    #[serde(rename = "segregated_sm")]
+    SegregatedSm,
+}
+
+impl Display for MarginModel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            MarginModel::CrossPm => write!(f, "cross_pm"),
+            MarginModel::CrossSm => write!(f, "cross_sm"),
+            MarginModel::SegregatedPm => write!(f, "segregated_pm"),
+            MarginModel::SegregatedSm => write!(f, "segregated_sm"),
+        }
+    }
+}
+
+/// Option type of an option instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptionType {
+    /// Right to buy the underlying at the strike price
+    #[serde(rename = "call")]
+    Call,
+
+    /// Right to sell the underlying at the strike price
+    #[serde(rename = "put")]
+    Put,
+}
+
+impl Display for OptionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            OptionType::Call => write!(f, "call"),
+            OptionType::Put => write!(f, "put"),
+        }
+    }
+}
+
+/// Settlement period of an instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SettlementPeriod {
+    /// Daily expiry
+    #[serde(rename = "day")]
+    Day,
+
+    /// Weekly expiry, on Fridays
+    #[serde(rename = "week")]
+    Week,
+
+    /// Monthly expiry, on the last Friday of the month
+    #[serde(rename = "month")]
+    Month,
+
+    /// Quarterly expiry, on the last Friday of the quarter
+    #[serde(rename = "quarter")]
+    Quarter,
+
+    /// No expiry; settled every 8 hours through funding
+    #[serde(rename = "perpetual")]
+    Perpetual,
+}
+
+impl Display for SettlementPeriod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            SettlementPeriod::Day => write!(f, "day"),
+            SettlementPeriod::Week => write!(f, "week"),
+            SettlementPeriod::Month => write!(f, "month"),
+            SettlementPeriod::Quarter => write!(f, "quarter"),
+            SettlementPeriod::Perpetual => write!(f, "perpetual"),
+        }
+    }
+}
+
+/// Whether an instrument is margined in the quote currency (linear) or the base currency (reversed).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstrumentType {
+    /// Margined and settled in the quote currency (e.g. USDC)
+    #[serde(rename = "linear")]
+    Linear,
+
+    /// Margined and settled in the base currency (e.g. BTC)
+    #[serde(rename = "reversed")]
+    Reversed,
+}
+
+impl Display for InstrumentType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            InstrumentType::Linear => write!(f, "linear"),
+            InstrumentType::Reversed => write!(f, "reversed"),
+        }
+    }
+}
+
+/// Resolution of volatility index candles, in seconds or "1D" for daily candles.
+///
+/// Valid values: "1", "60", "3600", "43200", "1D"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VolatilityIndexResolution {
+    /// One second candles
+    #[serde(rename = "1")]
+    OneSecond,
+
+    /// One minute candles
+    #[serde(rename = "60")]
+    OneMinute,
+
+    /// One hour candles
+    #[serde(rename = "3600")]
+    OneHour,
+
+    /// Twelve hour candles
+    #[serde(rename = "43200")]
+    TwelveHours,
+
+    /// Daily candles
+    #[serde(rename = "1D")]
+    OneDay,
+}
+
+impl Display for VolatilityIndexResolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            VolatilityIndexResolution::OneSecond => write!(f, "1"),
+            VolatilityIndexResolution::OneMinute => write!(f, "60"),
+            VolatilityIndexResolution::OneHour => write!(f, "3600"),
+            VolatilityIndexResolution::TwelveHours => write!(f, "43200"),
+            VolatilityIndexResolution::OneDay => write!(f, "1D"),
+        }
+    }
+}
+
+/// Status of a TradingView chart data query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChartDataStatus {
+    /// The requested range contains data
+    #[serde(rename = "ok")]
+    Ok,
+
+    /// There is no data in the requested range
+    #[serde(rename = "no_data")]
+    NoData,
+}
+
+/// Order type used when placing or closing an order.
+///
+/// Valid values: "limit", "stop_limit", "take_limit", "market", "stop_market", "take_market",
+/// "market_limit", "trailing_stop"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderEntryType {
+    #[serde(rename = "limit")]
+    Limit,
+    #[serde(rename = "stop_limit")]
+    StopLimit,
+    #[serde(rename = "take_limit")]
+    TakeLimit,
+    #[serde(rename = "market")]
+    Market,
+    #[serde(rename = "stop_market")]
+    StopMarket,
+    #[serde(rename = "take_market")]
+    TakeMarket,
+    #[serde(rename = "market_limit")]
+    MarketLimit,
+    #[serde(rename = "trailing_stop")]
+    TrailingStop,
+}
+
+impl Display for OrderEntryType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            OrderEntryType::Limit => write!(f, "limit"),
+            OrderEntryType::StopLimit => write!(f, "stop_limit"),
+            OrderEntryType::TakeLimit => write!(f, "take_limit"),
+            OrderEntryType::Market => write!(f, "market"),
+            OrderEntryType::StopMarket => write!(f, "stop_market"),
+            OrderEntryType::TakeMarket => write!(f, "take_market"),
+            OrderEntryType::MarketLimit => write!(f, "market_limit"),
+            OrderEntryType::TrailingStop => write!(f, "trailing_stop"),
+        }
+    }
+}
+
+/// Type of a linked order group (OTO, OCO or OTOCO).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LinkedOrderType {
+    /// The secondary orders are placed once the primary order is filled
+    #[serde(rename = "one_triggers_other")]
+    OneTriggersOther,
+
+    /// Filling one order of the pair cancels the other
+    #[serde(rename = "one_cancels_other")]
+    OneCancelsOther,
+
+    /// The primary order places an OCO pair once filled
+    #[serde(rename = "one_triggers_one_cancels_other")]
+    OneTriggersOneCancelsOther,
+}
+
+impl Display for LinkedOrderType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            LinkedOrderType::OneTriggersOther => write!(f, "one_triggers_other"),
+            LinkedOrderType::OneCancelsOther => write!(f, "one_cancels_other"),
+            LinkedOrderType::OneTriggersOneCancelsOther => write!(f, "one_triggers_one_cancels_other"),
+        }
+    }
+}
+
+/// Type of a Deribit account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountType {
+    #[serde(rename = "main")]
+    Main,
+    #[serde(rename = "subaccount")]
+    Subaccount,
+}
+
+impl Display for AccountType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            AccountType::Main => write!(f, "main"),
+            AccountType::Subaccount => write!(f, "subaccount"),
+        }
+    }
+}
+
+/// Side of a quote in a mass quote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuoteSide {
+    #[serde(rename = "bid")]
+    Bid,
+    #[serde(rename = "ask")]
+    Ask,
+}
+
+impl Display for QuoteSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            QuoteSide::Bid => write!(f, "bid"),
+            QuoteSide::Ask => write!(f, "ask"),
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use std::fmt;
 
-use serde::Deserialize;
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::deribit::rate_limit::RateLimitError;
+
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "deribit";
+
 /// Represents all possible errors that can occur when interacting with the Deribit API
 #[derive(Debug)]
 pub enum Errors {
@@ -12,7 +19,7 @@ pub enum Errors {
     /// Http error occurred while making a request
     /// This variant is used to represent errors that are not specific to the Deribit API,
     /// such as network issues or HTTP errors.
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the Deribit API
     ApiError(ApiError),
@@ -42,9 +49,15 @@ impl fmt::Display for Errors {
 
 impl std::error::Error for Errors {}
 
+impl From<TransportError> for Errors {
+    fn from(err: TransportError) -> Self {
+        Errors::HttpError(err)
+    }
+}
+
 impl From<reqwest::Error> for Errors {
     fn from(err: reqwest::Error) -> Self {
-        Errors::HttpError(err)
+        Errors::HttpError(TransportError::from(err))
     }
 }
 
@@ -63,7 +76,7 @@ impl From<crate::deribit::rate_limit::RateLimitError> for Errors {
 /// Represents an error response from the Deribit API.
 ///
 /// Deribit uses JSON-RPC 2.0 format for errors.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
     /// Error code
     pub code: i32,
@@ -73,6 +86,18 @@ pub struct ErrorResponse {
     pub data: Option<serde_json::Value>,
 }
 
+impl ErrorResponse {
+    /// The `error` object of a JSON-RPC response body, if it has one
+    pub fn from_body(body: &str) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            error: Option<ErrorResponse>,
+        }
+
+        serde_json::from_str::<Envelope>(body).ok()?.error
+    }
+}
+
 /// Deribit API error codes as documented in their API specification
 #[derive(Error, Debug, Clone)]
 pub enum ApiError {
@@ -126,6 +151,9 @@ pub enum ApiError {
     #[error("Internal server error")]
     InternalServerError, // e.g., 10003, distinct from JSON-RPC's InternalError if codes differ
 
+    #[error("Too many requests")]
+    TooManyRequests, // 10028
+
     /// Unmapped API error - for error codes not explicitly handled
     #[error("API error (code: {code}): {message}")]
     UnmappedApiError { code: i32, message: String },
@@ -155,6 +183,7 @@ impl From<ErrorResponse> for ApiError {
             10001 => ApiError::InvalidCredentials,
             10002 => ApiError::RateLimitExceeded,
             10003 => ApiError::InternalServerError,
+            10028 => ApiError::TooManyRequests,
             // Add more specific Deribit error codes here
             _ => ApiError::UnmappedApiError {
                 code: err.code,
@@ -164,8 +193,105 @@ impl From<ErrorResponse> for ApiError {
     }
 }
 
+impl ApiError {
+    /// The JSON-RPC error code this error was created from
+    pub fn code(&self) -> i32 {
+        match self {
+            ApiError::Success => 0,
+            ApiError::InsufficientFunds => 1000,
+            ApiError::InvalidDestination => 1001,
+            ApiError::TransferLimitExceeded => 1002,
+            ApiError::InvalidCurrency => 1003,
+            ApiError::InvalidAmount => 1004,
+            ApiError::Unauthorized => 1005,
+            ApiError::ParseError => -32700,
+            ApiError::InvalidRequest => -32600,
+            ApiError::MethodNotFound => -32601,
+            ApiError::InvalidParams => -32602,
+            ApiError::InternalError => -32603,
+            ApiError::AuthenticationRequired => 10000,
+            ApiError::InvalidCredentials => 10001,
+            ApiError::RateLimitExceeded => 10002,
+            ApiError::InternalServerError => 10003,
+            ApiError::TooManyRequests => 10028,
+            ApiError::UnmappedApiError { code, .. } => *code,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::Success => ErrorKind::Other,
+            ApiError::InsufficientFunds => ErrorKind::InsufficientFunds,
+            ApiError::Unauthorized | ApiError::AuthenticationRequired | ApiError::InvalidCredentials => ErrorKind::AuthFailed,
+            ApiError::RateLimitExceeded | ApiError::TooManyRequests => ErrorKind::RateLimited { retry_after: None },
+            ApiError::InternalError | ApiError::InternalServerError => ErrorKind::UnknownExecutionStatus,
+            ApiError::InvalidDestination
+            | ApiError::TransferLimitExceeded
+            | ApiError::InvalidCurrency
+            | ApiError::InvalidAmount
+            | ApiError::InvalidRequest
+            | ApiError::MethodNotFound
+            | ApiError::InvalidParams
+            | ApiError::ParseError => ErrorKind::InvalidRequest,
+            ApiError::UnmappedApiError { code, .. } => error_kind(*code),
+        }
+    }
+}
+
+/// Classify a Deribit error code that has no dedicated [`ApiError`] variant
+fn error_kind(code: i32) -> ErrorKind {
+    match code {
+        // not_enough_funds
+        10009 => ErrorKind::InsufficientFunds,
+        // order_not_found, not_open_order
+        10004 | 11044 => ErrorKind::OrderNotFound,
+        // invalid_credentials, unauthorized, forbidden
+        13004 | 13009 | 13021 => ErrorKind::AuthFailed,
+        // matching_engine_queue_full, temporarily_unavailable
+        10047 | 13028 => ErrorKind::ExchangeUnavailable,
+        // timed_out
+        13888 => ErrorKind::UnknownExecutionStatus,
+        10000..=13999 => ErrorKind::InvalidRequest,
+        _ => ErrorKind::Other,
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(ApiError::UnmappedApiError { code, message }) => VenueError::new(VENUE, error_kind(*code), message.clone()).with_code(code),
+            Errors::ApiError(api_error) => VenueError::new(VENUE, api_error.kind(), api_error.to_string()).with_code(api_error.code()),
+            // Rejected locally before anything was sent
+            Errors::RateLimitError(rate_limit_error) => {
+                let retry_after = match rate_limit_error {
+                    RateLimitError::BackingOff { remaining } => Some(*remaining),
+                    _ => None,
+                };
+                VenueError::new(
+                    VENUE,
+                    ErrorKind::RateLimited { retry_after },
+                    rate_limit_error.to_string(),
+                )
+            }
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+            Errors::SerdeJsonError(err) => VenueError::new(VENUE, ErrorKind::Other, err.to_string()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
 
     #[test]
@@ -197,4 +323,71 @@ mod tests {
             _ => panic!("Expected UnmappedApiError"),
         }
     }
+
+    #[test]
+    fn test_too_many_requests_error() {
+        let body = r#"{"jsonrpc":"2.0","id":8,"error":{"code":10028,"message":"too_many_requests"}}"#;
+
+        let error_response = ErrorResponse::from_body(body).unwrap();
+        assert_eq!(error_response.code, 10028);
+        assert!(matches!(
+            ApiError::from(error_response),
+            ApiError::TooManyRequests
+        ));
+    }
+
+    #[test]
+    fn test_error_response_from_successful_body() {
+        assert!(ErrorResponse::from_body(r#"{"jsonrpc":"2.0","id":8,"result":1}"#).is_none());
+        assert!(ErrorResponse::from_body("not json").is_none());
+    }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            code: 10009,
+            message: "not_enough_funds".to_string(),
+            data: None,
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::InsufficientFunds);
+        assert_eq!(error.code.as_deref(), Some("10009"));
+        assert_eq!(error.message, "not_enough_funds");
+        assert_eq!(error.venue, VENUE);
+
+        let error: VenueError = Errors::ApiError(ApiError::TooManyRequests).into();
+        assert_eq!(error.kind, ErrorKind::RateLimited { retry_after: None });
+        assert_eq!(error.code.as_deref(), Some("10028"));
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(
+            ApiError::AuthenticationRequired.kind(),
+            ErrorKind::AuthFailed
+        );
+        assert_eq!(ApiError::InvalidParams.kind(), ErrorKind::InvalidRequest);
+        assert_eq!(
+            ApiError::InternalError.kind(),
+            ErrorKind::UnknownExecutionStatus
+        );
+        assert_eq!(error_kind(10004), ErrorKind::OrderNotFound);
+        assert_eq!(error_kind(13009), ErrorKind::AuthFailed);
+        assert_eq!(error_kind(13028), ErrorKind::ExchangeUnavailable);
+        assert_eq!(error_kind(11029), ErrorKind::InvalidRequest);
+        assert_eq!(error_kind(99999), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_venue_error_from_local_backoff() {
+        let remaining = Duration::from_millis(400);
+        let error: VenueError = Errors::RateLimitError(RateLimitError::BackingOff { remaining }).into();
+        assert_eq!(
+            error.kind,
+            ErrorKind::RateLimited {
+                retry_after: Some(remaining)
+            }
+        );
+        assert_eq!(error.code, None);
+    }
 }
@@ -54,6 +54,37 @@ pub struct JsonRpcError {
     pub data: Option<serde_json::Value>,
 }
 
+/// Parameters of a `subscription` notification pushed for an active channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionNotification {
+    /// Channel the notification belongs to (e.g., "user.orders.BTC-PERPETUAL.raw")
+    pub channel: String,
+
+    /// Channel specific payload
+    pub data: serde_json::Value,
+}
+
+/// Parameters of a `heartbeat` notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeartbeatNotification {
+    /// Heartbeat type: "heartbeat" or "test_request"
+    #[serde(rename = "type")]
+    pub heartbeat_type: crate::deribit::HeartbeatType,
+}
+
+/// Server initiated JSON-RPC notification (a message without an `id`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params")]
+pub enum JsonRpcNotification {
+    /// Data pushed for a subscribed channel
+    #[serde(rename = "subscription")]
+    Subscription(SubscriptionNotification),
+
+    /// Heartbeat sent after `public/set_heartbeat` has been enabled
+    #[serde(rename = "heartbeat")]
+    Heartbeat(HeartbeatNotification),
+}
+
 impl JsonRpcRequest {
     /// Create a new disable_heartbeat request
     pub fn disable_heartbeat(id: u64) -> Self {
@@ -65,6 +96,16 @@ impl JsonRpcRequest {
         }
     }
 
+    /// Create a new public/test request, used to answer a `test_request` heartbeat
+    pub fn test(id: u64) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            method: "public/test".to_string(),
+            params: None,
+        }
+    }
+
     /// Create a new private trading request (e.g., private/buy, private/sell, etc.)
     pub fn private_trading<M: Into<String>, P: serde::Serialize>(id: u64, method: M, params: P) -> Self {
         Self {
@@ -107,6 +148,40 @@ mod tests {
         assert!(request.params.is_none());
     }
 
+    #[test]
+    fn test_json_rpc_request_test() {
+        let request = JsonRpcRequest::test(5);
+
+        assert_eq!(request.id, 5);
+        assert_eq!(request.method, "public/test");
+        assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"jsonrpc":"2.0","id":5,"method":"public/test"}"#);
+    }
+
+    #[test]
+    fn test_subscription_notification_deserialization() {
+        let json = r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"user.orders.BTC-PERPETUAL.raw","data":{"order_id":"123"}}}"#;
+        let notification: JsonRpcNotification = serde_json::from_str(json).unwrap();
+
+        match notification {
+            JsonRpcNotification::Subscription(n) => {
+                assert_eq!(n.channel, "user.orders.BTC-PERPETUAL.raw");
+                assert_eq!(n.data["order_id"], "123");
+            }
+            other => panic!("Expected subscription notification, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_heartbeat_notification_deserialization() {
+        let json = r#"{"jsonrpc":"2.0","method":"heartbeat","params":{"type":"test_request"}}"#;
+        let notification: JsonRpcNotification = serde_json::from_str(json).unwrap();
+
+        match notification {
+            JsonRpcNotification::Heartbeat(h) => assert_eq!(h.heartbeat_type, crate::deribit::HeartbeatType::TestRequest),
+            other => panic!("Expected heartbeat notification, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_json_rpc_response_deserialization() {
         let json = r#"{"jsonrpc":"2.0","id":123,"result":"ok"}"#;
@@ -35,6 +35,7 @@
 //! }
 //! ```
 
+mod connection;
 mod enums;
 mod errors;
 pub mod rate_limit;
@@ -43,18 +44,70 @@ pub mod public {
     pub mod rest;
     pub mod websocket;
 
+    pub use self::rest::BookSummary;
+    pub use self::rest::CurrencyInfo;
+    pub use self::rest::CurrencyWithdrawalPriority;
+    pub use self::rest::DeliveryPrice;
+    pub use self::rest::DeliveryPrices;
+    pub use self::rest::FundingRate;
+    pub use self::rest::GetBookSummaryByCurrencyRequest;
+    pub use self::rest::GetBookSummaryByCurrencyResponse;
+    pub use self::rest::GetBookSummaryByInstrumentRequest;
+    pub use self::rest::GetBookSummaryByInstrumentResponse;
     pub use self::rest::GetComboIdsRequest;
     pub use self::rest::GetComboIdsResponse;
+    pub use self::rest::GetCurrenciesRequest;
+    pub use self::rest::GetCurrenciesResponse;
+    pub use self::rest::GetDeliveryPricesRequest;
+    pub use self::rest::GetDeliveryPricesResponse;
+    pub use self::rest::GetFundingRateHistoryRequest;
+    pub use self::rest::GetFundingRateHistoryResponse;
+    pub use self::rest::GetHistoricalVolatilityRequest;
+    pub use self::rest::GetHistoricalVolatilityResponse;
+    pub use self::rest::GetIndexPriceNamesRequest;
+    pub use self::rest::GetIndexPriceNamesResponse;
+    pub use self::rest::GetIndexPriceRequest;
+    pub use self::rest::GetIndexPriceResponse;
+    pub use self::rest::GetInstrumentRequest;
+    pub use self::rest::GetInstrumentResponse;
+    pub use self::rest::GetInstrumentsRequest;
+    pub use self::rest::GetInstrumentsResponse;
+    pub use self::rest::GetLastTradesByCurrencyRequest;
+    pub use self::rest::GetLastTradesByCurrencyResponse;
+    pub use self::rest::GetLastTradesByInstrumentAndTimeRequest;
+    pub use self::rest::GetLastTradesByInstrumentAndTimeResponse;
+    pub use self::rest::GetLastTradesByInstrumentRequest;
+    pub use self::rest::GetLastTradesByInstrumentResponse;
+    pub use self::rest::GetOrderBookByInstrumentIdRequest;
+    pub use self::rest::GetOrderBookByInstrumentIdResponse;
+    pub use self::rest::GetOrderBookRequest;
+    pub use self::rest::GetOrderBookResponse;
     pub use self::rest::GetStatusRequest;
     pub use self::rest::GetStatusResponse;
     pub use self::rest::GetStatusResult;
     pub use self::rest::GetTimeRequest;
     pub use self::rest::GetTimeResponse;
+    pub use self::rest::GetTradingviewChartDataRequest;
+    pub use self::rest::GetTradingviewChartDataResponse;
+    pub use self::rest::GetVolatilityIndexDataRequest;
+    pub use self::rest::GetVolatilityIndexDataResponse;
+    pub use self::rest::HistoricalVolatility;
+    pub use self::rest::IndexPrice;
+    pub use self::rest::Instrument;
+    pub use self::rest::LastTradesResult;
+    pub use self::rest::OrderBook;
     pub use self::rest::RestClient;
+    pub use self::rest::TickSizeStep;
+    pub use self::rest::TickerRequest;
+    pub use self::rest::TickerResponse;
+    pub use self::rest::TradingviewChartData;
+    pub use self::rest::VolatilityCandle;
+    pub use self::rest::VolatilityIndexData;
+    pub use self::websocket::DeribitPublicMessage;
     pub use self::websocket::HelloRequest;
     pub use self::websocket::HelloResponse;
     pub use self::websocket::HelloResult;
-    pub use self::websocket::PrivateWebSocketClient;
+    pub use self::websocket::PublicWebSocketClient;
     pub use self::websocket::SubscribeRequest;
     pub use self::websocket::SubscribeResponse;
     pub use self::websocket::client::DeribitWebSocketError;
@@ -62,10 +115,19 @@ pub mod public {
 
 pub mod private {
     pub mod rest;
+    pub mod websocket;
 
+    pub use self::rest::AccessLog;
+    pub use self::rest::AccessLogEntry;
+    pub use self::rest::AccountDetails;
+    pub use self::rest::AccountLimits;
+    pub use self::rest::AccountSummaries;
+    pub use self::rest::AccountSummary;
     pub use self::rest::AddToAddressBookRequest;
     pub use self::rest::AddToAddressBookResponse;
     pub use self::rest::AddressBookEntry;
+    pub use self::rest::BuyRequest;
+    pub use self::rest::BuyResponse;
     pub use self::rest::CancelAllByCurrencyPairRequest;
     pub use self::rest::CancelAllByCurrencyPairResponse;
     pub use self::rest::CancelAllByCurrencyRequest;
@@ -84,6 +146,8 @@ pub mod private {
     pub use self::rest::CancelWithdrawalRequest;
     pub use self::rest::CancelWithdrawalResponse;
     pub use self::rest::CancelledOrder;
+    pub use self::rest::ClosePositionRequest;
+    pub use self::rest::ClosePositionResponse;
     pub use self::rest::CreateComboLeg;
     pub use self::rest::CreateComboRequest;
     pub use self::rest::CreateComboResponse;
@@ -96,8 +160,18 @@ pub mod private {
     pub use self::rest::DepositId;
     pub use self::rest::DisableCancelOnDisconnectRequest;
     pub use self::rest::DisableCancelOnDisconnectResponse;
+    pub use self::rest::EditByLabelRequest;
+    pub use self::rest::EditByLabelResponse;
+    pub use self::rest::EditRequest;
+    pub use self::rest::EditResponse;
     pub use self::rest::EnableCancelOnDisconnectRequest;
     pub use self::rest::EnableCancelOnDisconnectResponse;
+    pub use self::rest::GetAccessLogRequest;
+    pub use self::rest::GetAccessLogResponse;
+    pub use self::rest::GetAccountSummariesRequest;
+    pub use self::rest::GetAccountSummariesResponse;
+    pub use self::rest::GetAccountSummaryRequest;
+    pub use self::rest::GetAccountSummaryResponse;
     pub use self::rest::GetAddressBookRequest;
     pub use self::rest::GetAddressBookResponse;
     pub use self::rest::GetCancelOnDisconnectRequest;
@@ -109,8 +183,26 @@ pub mod private {
     pub use self::rest::GetDepositsResult;
     pub use self::rest::GetOpenOrdersByCurrencyRequest;
     pub use self::rest::GetOpenOrdersByCurrencyResponse;
+    pub use self::rest::GetOrderHistoryByCurrencyRequest;
+    pub use self::rest::GetOrderHistoryByCurrencyResponse;
+    pub use self::rest::GetOrderHistoryByInstrumentRequest;
+    pub use self::rest::GetOrderHistoryByInstrumentResponse;
     pub use self::rest::GetOrderMarginByIdsRequest;
     pub use self::rest::GetOrderMarginByIdsResponse;
+    pub use self::rest::GetOrderStateRequest;
+    pub use self::rest::GetOrderStateResponse;
+    pub use self::rest::GetPositionRequest;
+    pub use self::rest::GetPositionResponse;
+    pub use self::rest::GetPositionsRequest;
+    pub use self::rest::GetPositionsResponse;
+    pub use self::rest::GetSubaccountsDetailsRequest;
+    pub use self::rest::GetSubaccountsDetailsResponse;
+    pub use self::rest::GetSubaccountsRequest;
+    pub use self::rest::GetSubaccountsResponse;
+    pub use self::rest::GetTransactionLogRequest;
+    pub use self::rest::GetTransactionLogResponse;
+    pub use self::rest::GetUserLocksRequest;
+    pub use self::rest::GetUserLocksResponse;
     pub use self::rest::GetUserTradesByCurrencyAndTimeRequest;
     pub use self::rest::GetUserTradesByCurrencyAndTimeResponse;
     pub use self::rest::GetUserTradesByCurrencyAndTimeResult;
@@ -119,6 +211,12 @@ pub mod private {
     pub use self::rest::GetUserTradesByCurrencyResult;
     pub use self::rest::InvalidateBlockTradeSignatureRequest;
     pub use self::rest::InvalidateBlockTradeSignatureResponse;
+    pub use self::rest::MassQuoteEntry;
+    pub use self::rest::MassQuoteError;
+    pub use self::rest::MassQuoteRequest;
+    pub use self::rest::MassQuoteResponse;
+    pub use self::rest::MassQuoteResult;
+    pub use self::rest::MatchingEngineLimits;
     pub use self::rest::MovePositionTrade;
     pub use self::rest::MovePositionTradeResult;
     pub use self::rest::MovePositionsRequest;
@@ -127,16 +225,27 @@ pub mod private {
     pub use self::rest::OpenOrder;
     pub use self::rest::OpenOrderType;
     pub use self::rest::OrderMarginInfo;
+    pub use self::rest::OrderRequest;
+    pub use self::rest::OrderResponse;
+    pub use self::rest::OrderResult;
     pub use self::rest::Originator;
+    pub use self::rest::OtocoConfig;
+    pub use self::rest::QuoteLevel;
+    pub use self::rest::RateLimitBucket;
     pub use self::rest::RemoveFromAddressBookRequest;
     pub use self::rest::RemoveFromAddressBookResponse;
     pub use self::rest::RestClient;
+    pub use self::rest::SellRequest;
+    pub use self::rest::SellResponse;
     pub use self::rest::SendRfqRequest;
     pub use self::rest::SendRfqResponse;
     pub use self::rest::SetClearanceOriginatorRequest;
     pub use self::rest::SetClearanceOriginatorResponse;
     pub use self::rest::SetClearanceOriginatorResult;
     pub use self::rest::Side;
+    pub use self::rest::Subaccount;
+    pub use self::rest::SubaccountDetails;
+    pub use self::rest::SubaccountPortfolio;
     pub use self::rest::SubaccountTransferData;
     pub use self::rest::SubmitTransferBetweenSubaccountsRequest;
     pub use self::rest::SubmitTransferBetweenSubaccountsResponse;
@@ -145,23 +254,52 @@ pub mod private {
     pub use self::rest::SubmitTransferToUserRequest;
     pub use self::rest::SubmitTransferToUserResponse;
     pub use self::rest::Trade;
+    pub use self::rest::TransactionLog;
+    pub use self::rest::TransactionLogEntry;
     pub use self::rest::TransferData;
     pub use self::rest::UpdateInAddressBookRequest;
     pub use self::rest::UpdateInAddressBookResponse;
+    pub use self::rest::UserLock;
     pub use self::rest::WithdrawRequest;
     pub use self::rest::WithdrawResponse;
     pub use self::rest::WithdrawalData;
     pub use self::rest::{IndexName, MmpConfig, ResetMmpRequest, ResetMmpResponse, SetMmpConfigRequest, SetMmpConfigResponse};
+    pub use self::websocket::AuthRequest;
+    pub use self::websocket::AuthResponse;
+    pub use self::websocket::AuthResult;
+    pub use self::websocket::AuthSession;
+    pub use self::websocket::AuthenticationLostReason;
+    pub use self::websocket::DeribitPrivateMessage;
+    pub use self::websocket::PrivateSubscribeRequest;
+    pub use self::websocket::PrivateUnsubscribeRequest;
+    pub use self::websocket::PrivateWebSocketClient;
 }
 
+pub mod channel;
 pub mod message;
+pub mod notification;
 
+pub use channel::*;
 pub use enums::*;
 pub use errors::{ApiError, ErrorResponse, Errors};
 pub use message::*;
+pub use notification::*;
+pub use private::AccessLog;
+pub use private::AccessLogEntry;
+pub use private::AccountDetails;
+pub use private::AccountLimits;
+pub use private::AccountSummaries;
+pub use private::AccountSummary;
 pub use private::AddToAddressBookRequest;
 pub use private::AddToAddressBookResponse;
 pub use private::AddressBookEntry;
+pub use private::AuthRequest;
+pub use private::AuthResponse;
+pub use private::AuthResult;
+pub use private::AuthSession;
+pub use private::AuthenticationLostReason;
+pub use private::BuyRequest;
+pub use private::BuyResponse;
 pub use private::CancelAllByCurrencyPairRequest;
 pub use private::CancelAllByCurrencyPairResponse;
 pub use private::CancelAllByCurrencyRequest;
@@ -180,6 +318,8 @@ pub use private::CancelType;
 pub use private::CancelWithdrawalRequest;
 pub use private::CancelWithdrawalResponse;
 pub use private::CancelledOrder;
+pub use private::ClosePositionRequest;
+pub use private::ClosePositionResponse;
 pub use private::CreateComboLeg;
 pub use private::CreateComboRequest;
 pub use private::CreateComboResponse;
@@ -188,12 +328,23 @@ pub use private::CreateComboTrade;
 pub use private::CreateDepositAddressRequest;
 pub use private::CreateDepositAddressResponse;
 pub use private::DepositAddress;
+pub use private::DeribitPrivateMessage;
 pub use private::DepositData;
 pub use private::DepositId;
 pub use private::DisableCancelOnDisconnectRequest;
 pub use private::DisableCancelOnDisconnectResponse;
+pub use private::EditByLabelRequest;
+pub use private::EditByLabelResponse;
+pub use private::EditRequest;
+pub use private::EditResponse;
 pub use private::EnableCancelOnDisconnectRequest;
 pub use private::EnableCancelOnDisconnectResponse;
+pub use private::GetAccessLogRequest;
+pub use private::GetAccessLogResponse;
+pub use private::GetAccountSummariesRequest;
+pub use private::GetAccountSummariesResponse;
+pub use private::GetAccountSummaryRequest;
+pub use private::GetAccountSummaryResponse;
 pub use private::GetAddressBookRequest;
 pub use private::GetAddressBookResponse;
 pub use private::GetCancelOnDisconnectRequest;
@@ -205,6 +356,24 @@ pub use private::GetDepositsResponse;
 pub use private::GetDepositsResult;
 pub use private::GetOpenOrdersByCurrencyRequest;
 pub use private::GetOpenOrdersByCurrencyResponse;
+pub use private::GetOrderHistoryByCurrencyRequest;
+pub use private::GetOrderHistoryByCurrencyResponse;
+pub use private::GetOrderHistoryByInstrumentRequest;
+pub use private::GetOrderHistoryByInstrumentResponse;
+pub use private::GetOrderStateRequest;
+pub use private::GetOrderStateResponse;
+pub use private::GetPositionRequest;
+pub use private::GetPositionResponse;
+pub use private::GetPositionsRequest;
+pub use private::GetPositionsResponse;
+pub use private::GetSubaccountsDetailsRequest;
+pub use private::GetSubaccountsDetailsResponse;
+pub use private::GetSubaccountsRequest;
+pub use private::GetSubaccountsResponse;
+pub use private::GetTransactionLogRequest;
+pub use private::GetTransactionLogResponse;
+pub use private::GetUserLocksRequest;
+pub use private::GetUserLocksResponse;
 pub use private::GetUserTradesByCurrencyAndTimeRequest;
 pub use private::GetUserTradesByCurrencyAndTimeResponse;
 pub use private::GetUserTradesByCurrencyAndTimeResult;
@@ -214,6 +383,12 @@ pub use private::GetUserTradesByCurrencyResult;
 pub use private::IndexName;
 pub use private::InvalidateBlockTradeSignatureRequest;
 pub use private::InvalidateBlockTradeSignatureResponse;
+pub use private::MassQuoteEntry;
+pub use private::MassQuoteError;
+pub use private::MassQuoteRequest;
+pub use private::MassQuoteResponse;
+pub use private::MassQuoteResult;
+pub use private::MatchingEngineLimits;
 pub use private::MovePositionTrade;
 pub use private::MovePositionTradeResult;
 pub use private::MovePositionsRequest;
@@ -221,18 +396,32 @@ pub use private::MovePositionsResponse;
 pub use private::MovePositionsResult;
 pub use private::OpenOrder;
 pub use private::OpenOrderType;
+pub use private::OrderRequest;
+pub use private::OrderResponse;
+pub use private::OrderResult;
 pub use private::Originator;
+pub use private::OtocoConfig;
+pub use private::PrivateSubscribeRequest;
+pub use private::PrivateUnsubscribeRequest;
+pub use private::PrivateWebSocketClient;
+pub use private::QuoteLevel;
+pub use private::RateLimitBucket;
 pub use private::RemoveFromAddressBookRequest;
 pub use private::RemoveFromAddressBookResponse;
 pub use private::ResetMmpRequest;
 pub use private::ResetMmpResponse;
 pub use private::RestClient as PrivateRestClient;
+pub use private::SellRequest;
+pub use private::SellResponse;
 pub use private::SendRfqRequest;
 pub use private::SendRfqResponse;
 pub use private::SetClearanceOriginatorRequest;
 pub use private::SetClearanceOriginatorResponse;
 pub use private::SetClearanceOriginatorResult;
 pub use private::Side;
+pub use private::Subaccount;
+pub use private::SubaccountDetails;
+pub use private::SubaccountPortfolio;
 pub use private::SubaccountTransferData;
 pub use private::SubmitTransferBetweenSubaccountsRequest;
 pub use private::SubmitTransferBetweenSubaccountsResponse;
@@ -241,26 +430,81 @@ pub use private::SubmitTransferToSubaccountResponse;
 pub use private::SubmitTransferToUserRequest;
 pub use private::SubmitTransferToUserResponse;
 pub use private::Trade;
+pub use private::TransactionLog;
+pub use private::TransactionLogEntry;
 pub use private::TransferData;
 pub use private::UpdateInAddressBookRequest;
 pub use private::UpdateInAddressBookResponse;
+pub use private::UserLock;
 pub use private::WithdrawRequest;
 pub use private::WithdrawResponse;
 pub use private::WithdrawalData;
+pub use public::BookSummary;
+pub use public::CurrencyInfo;
+pub use public::CurrencyWithdrawalPriority;
+pub use public::DeliveryPrice;
+pub use public::DeliveryPrices;
+pub use public::DeribitPublicMessage;
+pub use public::FundingRate;
+pub use public::GetBookSummaryByCurrencyRequest;
+pub use public::GetBookSummaryByCurrencyResponse;
+pub use public::GetBookSummaryByInstrumentRequest;
+pub use public::GetBookSummaryByInstrumentResponse;
 pub use public::GetComboIdsRequest;
 pub use public::GetComboIdsResponse;
+pub use public::GetCurrenciesRequest;
+pub use public::GetCurrenciesResponse;
+pub use public::GetDeliveryPricesRequest;
+pub use public::GetDeliveryPricesResponse;
+pub use public::GetFundingRateHistoryRequest;
+pub use public::GetFundingRateHistoryResponse;
+pub use public::GetHistoricalVolatilityRequest;
+pub use public::GetHistoricalVolatilityResponse;
+pub use public::GetIndexPriceNamesRequest;
+pub use public::GetIndexPriceNamesResponse;
+pub use public::GetIndexPriceRequest;
+pub use public::GetIndexPriceResponse;
+pub use public::GetInstrumentRequest;
+pub use public::GetInstrumentResponse;
+pub use public::GetInstrumentsRequest;
+pub use public::GetInstrumentsResponse;
+pub use public::GetLastTradesByCurrencyRequest;
+pub use public::GetLastTradesByCurrencyResponse;
+pub use public::GetLastTradesByInstrumentAndTimeRequest;
+pub use public::GetLastTradesByInstrumentAndTimeResponse;
+pub use public::GetLastTradesByInstrumentRequest;
+pub use public::GetLastTradesByInstrumentResponse;
+pub use public::GetOrderBookByInstrumentIdRequest;
+pub use public::GetOrderBookByInstrumentIdResponse;
+pub use public::GetOrderBookRequest;
+pub use public::GetOrderBookResponse;
 pub use public::GetStatusRequest;
 pub use public::GetStatusResponse;
 pub use public::GetStatusResult;
 pub use public::GetTimeRequest;
 pub use public::GetTimeResponse;
+pub use public::GetTradingviewChartDataRequest;
+pub use public::GetTradingviewChartDataResponse;
+pub use public::GetVolatilityIndexDataRequest;
+pub use public::GetVolatilityIndexDataResponse;
 pub use public::HelloRequest;
 pub use public::HelloResponse;
 pub use public::HelloResult;
-pub use public::PrivateWebSocketClient;
-pub use public::RestClient as PublicRestClient;
+pub use public::HistoricalVolatility;
+pub use public::IndexPrice;
+pub use public::Instrument;
+pub use public::LastTradesResult;
+pub use public::OrderBook;
+pub use public::PublicWebSocketClient;
 pub use public::SubscribeRequest;
 pub use public::SubscribeResponse;
+pub use public::TickSizeStep;
+pub use public::TickerRequest;
+pub use public::TickerResponse;
+pub use public::TradingviewChartData;
+pub use public::VolatilityCandle;
+pub use public::VolatilityIndexData;
+pub use public::RestClient as PublicRestClient;
 pub use public::websocket::client::DeribitWebSocketError;
 pub use rate_limit::*;
 
@@ -0,0 +1,1140 @@
+//! Typed payloads of Deribit subscription notifications
+//!
+//! A `subscription` notification carries the channel name and a channel specific `data`
+//! object. `Notification::parse` selects the payload type from the channel name so
+//! consumers can `match` on the variant instead of inspecting JSON.
+//!
+//! See: <https://docs.deribit.com/v2/#subscriptions>
+
+use serde::{Deserialize, Serialize};
+
+use crate::deribit::enums::{
+    BookAction, BookUpdateType, Currency, InstrumentKind, LiquidationSide, MarginModel, OrderDirection, PositionDirection, TickDirection, TickerState,
+};
+use crate::deribit::message::SubscriptionNotification;
+use crate::deribit::{OpenOrder, Trade};
+
+/// One price level change in an order book update, sent as `[action, price, amount]`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(from = "(BookAction, f64, f64)")]
+pub struct BookLevelChange {
+    /// What happened to the price level
+    pub action: BookAction,
+
+    /// Price of the level
+    pub price: f64,
+
+    /// Amount at the level after the change (0 for deletes)
+    pub amount: f64,
+}
+
+impl From<(BookAction, f64, f64)> for BookLevelChange {
+    fn from((action, price, amount): (BookAction, f64, f64)) -> Self {
+        Self {
+            action,
+            price,
+            amount,
+        }
+    }
+}
+
+/// One price level of a grouped order book, sent as `[price, amount]`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(from = "(f64, f64)")]
+pub struct BookLevel {
+    /// Price of the level
+    pub price: f64,
+
+    /// Amount at the level
+    pub amount: f64,
+}
+
+impl From<(f64, f64)> for BookLevel {
+    fn from((price, amount): (f64, f64)) -> Self {
+        Self { price, amount }
+    }
+}
+
+/// Order book change from `book.{instrument_name}.{interval}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct BookUpdate {
+    /// Whether this is the initial snapshot or a change
+    #[serde(rename = "type")]
+    pub update_type: BookUpdateType,
+
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The timestamp of the change (milliseconds since the UNIX epoch)
+    pub timestamp: i64,
+
+    /// Identifier of this change
+    pub change_id: i64,
+
+    /// Identifier of the previous change; absent in snapshots. A gap means an update was missed.
+    #[serde(default)]
+    pub prev_change_id: Option<i64>,
+
+    /// Changed bid levels
+    pub bids: Vec<BookLevelChange>,
+
+    /// Changed ask levels
+    pub asks: Vec<BookLevelChange>,
+}
+
+/// Grouped order book snapshot from `book.{instrument_name}.{group}.{depth}.{interval}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupedBookUpdate {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The timestamp of the snapshot (milliseconds since the UNIX epoch)
+    pub timestamp: i64,
+
+    /// Identifier of the last change included in the snapshot
+    pub change_id: i64,
+
+    /// Bid levels, best first
+    pub bids: Vec<BookLevel>,
+
+    /// Ask levels, best first
+    pub asks: Vec<BookLevel>,
+}
+
+/// Public trade from `trades.*`
+#[derive(Debug, Clone, Deserialize)]
+pub struct PublicTrade {
+    /// Unique (per currency) trade identifier
+    pub trade_id: String,
+
+    /// The sequence number of the trade within instrument
+    pub trade_seq: i64,
+
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The timestamp of the trade (milliseconds since the UNIX epoch)
+    pub timestamp: i64,
+
+    /// Direction of the "tick"
+    pub tick_direction: TickDirection,
+
+    /// Price in base currency
+    pub price: f64,
+
+    /// Trade amount
+    pub amount: f64,
+
+    /// Trade size in contract units
+    #[serde(default)]
+    pub contracts: Option<f64>,
+
+    /// Direction of the taker: buy, or sell
+    pub direction: OrderDirection,
+
+    /// Mark Price at the moment of trade
+    pub mark_price: f64,
+
+    /// Index Price at the moment of trade
+    pub index_price: f64,
+
+    /// Option implied volatility for the price (Option only)
+    #[serde(default)]
+    pub iv: Option<f64>,
+
+    /// Present when the trade was a liquidation
+    #[serde(default)]
+    pub liquidation: Option<LiquidationSide>,
+
+    /// Block trade id - when trade was part of a block trade
+    #[serde(default)]
+    pub block_trade_id: Option<String>,
+}
+
+/// 24h statistics of a ticker
+#[derive(Debug, Clone, Deserialize)]
+pub struct TickerStats {
+    /// Highest price during 24h
+    #[serde(default)]
+    pub high: Option<f64>,
+
+    /// Lowest price during 24h
+    #[serde(default)]
+    pub low: Option<f64>,
+
+    /// 24-hour price change expressed as a percentage
+    #[serde(default)]
+    pub price_change: Option<f64>,
+
+    /// Volume during last 24h in base currency
+    #[serde(default)]
+    pub volume: Option<f64>,
+
+    /// Volume in USD
+    #[serde(default)]
+    pub volume_usd: Option<f64>,
+}
+
+/// Option greeks
+#[derive(Debug, Clone, Deserialize)]
+pub struct Greeks {
+    /// The delta value for the option
+    pub delta: f64,
+
+    /// The gamma value for the option
+    pub gamma: f64,
+
+    /// The vega value for the option
+    pub vega: f64,
+
+    /// The theta value for the option
+    pub theta: f64,
+
+    /// The rho value for the option
+    pub rho: f64,
+}
+
+/// Ticker from `ticker.{instrument_name}.{interval}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Ticker {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+
+    /// The state of the order book
+    pub state: TickerState,
+
+    /// Current index price
+    pub index_price: f64,
+
+    /// The mark price for the instrument
+    pub mark_price: f64,
+
+    /// The price for the last trade
+    #[serde(default)]
+    pub last_price: Option<f64>,
+
+    /// The current best bid price, absent if there aren't any bids
+    #[serde(default)]
+    pub best_bid_price: Option<f64>,
+
+    /// The requested order size of all best bids
+    pub best_bid_amount: f64,
+
+    /// The current best ask price, absent if there aren't any asks
+    #[serde(default)]
+    pub best_ask_price: Option<f64>,
+
+    /// The requested order size of all best asks
+    pub best_ask_amount: f64,
+
+    /// The minimum price for the future. Any sell orders you submit lower than this price will be clamped to this minimum
+    pub min_price: f64,
+
+    /// The maximum price for the future. Any buy orders you submit higher than this price, will be clamped to this maximum
+    pub max_price: f64,
+
+    /// The total amount of outstanding contracts in the corresponding amount units
+    pub open_interest: f64,
+
+    /// The settlement price for the instrument. Only when state = open
+    #[serde(default)]
+    pub settlement_price: Option<f64>,
+
+    /// Estimated delivery price for the market
+    #[serde(default)]
+    pub estimated_delivery_price: Option<f64>,
+
+    /// 24h statistics
+    pub stats: TickerStats,
+
+    /// Funding 8h (perpetual only)
+    #[serde(default)]
+    pub funding_8h: Option<f64>,
+
+    /// Current funding (perpetual only)
+    #[serde(default)]
+    pub current_funding: Option<f64>,
+
+    /// Value used to calculate realized_funding in positions (perpetual only)
+    #[serde(default)]
+    pub interest_value: Option<f64>,
+
+    /// Implied volatility for mark price (options only)
+    #[serde(default)]
+    pub mark_iv: Option<f64>,
+
+    /// Implied volatility for best bid (options only)
+    #[serde(default)]
+    pub bid_iv: Option<f64>,
+
+    /// Implied volatility for best ask (options only)
+    #[serde(default)]
+    pub ask_iv: Option<f64>,
+
+    /// Underlying price for implied volatility calculations (options only)
+    #[serde(default)]
+    pub underlying_price: Option<f64>,
+
+    /// Name of the underlying future, or `index_price` (options only)
+    #[serde(default)]
+    pub underlying_index: Option<String>,
+
+    /// Interest rate used in implied volatility calculations (options only)
+    #[serde(default)]
+    pub interest_rate: Option<f64>,
+
+    /// Option greeks (options only)
+    #[serde(default)]
+    pub greeks: Option<Greeks>,
+}
+
+/// Ticker snapshot or change from `incremental_ticker.{instrument_name}`
+///
+/// The first notification is a full snapshot; changes only contain the fields that changed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IncrementalTicker {
+    /// Whether this is the initial snapshot or a change
+    #[serde(rename = "type")]
+    pub update_type: BookUpdateType,
+
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+
+    /// Current index price
+    #[serde(default)]
+    pub index_price: Option<f64>,
+
+    /// The mark price for the instrument
+    #[serde(default)]
+    pub mark_price: Option<f64>,
+
+    /// The price for the last trade
+    #[serde(default)]
+    pub last_price: Option<f64>,
+
+    /// The current best bid price
+    #[serde(default)]
+    pub best_bid_price: Option<f64>,
+
+    /// The requested order size of all best bids
+    #[serde(default)]
+    pub best_bid_amount: Option<f64>,
+
+    /// The current best ask price
+    #[serde(default)]
+    pub best_ask_price: Option<f64>,
+
+    /// The requested order size of all best asks
+    #[serde(default)]
+    pub best_ask_amount: Option<f64>,
+
+    /// The minimum price for the future
+    #[serde(default)]
+    pub min_price: Option<f64>,
+
+    /// The maximum price for the future
+    #[serde(default)]
+    pub max_price: Option<f64>,
+
+    /// The total amount of outstanding contracts
+    #[serde(default)]
+    pub open_interest: Option<f64>,
+
+    /// Estimated delivery price for the market
+    #[serde(default)]
+    pub estimated_delivery_price: Option<f64>,
+
+    /// Changed 24h statistics
+    #[serde(default)]
+    pub stats: Option<TickerStats>,
+
+    /// Funding 8h (perpetual only)
+    #[serde(default)]
+    pub funding_8h: Option<f64>,
+
+    /// Current funding (perpetual only)
+    #[serde(default)]
+    pub current_funding: Option<f64>,
+
+    /// Implied volatility for mark price (options only)
+    #[serde(default)]
+    pub mark_iv: Option<f64>,
+
+    /// Implied volatility for best bid (options only)
+    #[serde(default)]
+    pub bid_iv: Option<f64>,
+
+    /// Implied volatility for best ask (options only)
+    #[serde(default)]
+    pub ask_iv: Option<f64>,
+
+    /// Underlying price for implied volatility calculations (options only)
+    #[serde(default)]
+    pub underlying_price: Option<f64>,
+
+    /// Option greeks (options only)
+    #[serde(default)]
+    pub greeks: Option<Greeks>,
+}
+
+/// Index price from `deribit_price_index.{index_name}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceIndex {
+    /// Index identifier, matches (base) cryptocurrency with quote currency
+    pub index_name: String,
+
+    /// Current index price
+    pub price: f64,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+}
+
+/// Option mark price from `markprice.options.{index_name}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarkPriceOption {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The mark price for the instrument
+    pub mark_price: f64,
+
+    /// Value of the volatility of the underlying instrument
+    pub iv: f64,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+}
+
+/// Perpetual interest from `perpetual.{instrument_name}.{interval}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct PerpetualUpdate {
+    /// Current interest
+    pub interest: f64,
+
+    /// Current index price
+    pub index_price: f64,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+}
+
+/// Best bid and ask from `quote.{instrument_name}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuoteUpdate {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+
+    /// The current best bid price, absent if there aren't any bids
+    #[serde(default)]
+    pub best_bid_price: Option<f64>,
+
+    /// The requested order size of all best bids
+    pub best_bid_amount: f64,
+
+    /// The current best ask price, absent if there aren't any asks
+    #[serde(default)]
+    pub best_ask_price: Option<f64>,
+
+    /// The requested order size of all best asks
+    pub best_ask_amount: f64,
+}
+
+/// Candle from `chart.trades.{instrument_name}.{resolution}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Candle {
+    /// The timestamp of the candle start (milliseconds since the Unix epoch)
+    pub tick: i64,
+
+    /// The open price for the candle
+    pub open: f64,
+
+    /// The highest price level for the candle
+    pub high: f64,
+
+    /// The lowest price level for the candle
+    pub low: f64,
+
+    /// The close price for the candle
+    pub close: f64,
+
+    /// Volume data for the candle
+    pub volume: f64,
+
+    /// Cost data for the candle
+    pub cost: f64,
+}
+
+/// Portfolio and margin state from `user.portfolio.{currency}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortfolioUpdate {
+    /// The selected currency
+    pub currency: Currency,
+
+    /// The account's balance
+    pub balance: f64,
+
+    /// The account's current equity
+    pub equity: f64,
+
+    /// The account's available funds
+    pub available_funds: f64,
+
+    /// The account's available to withdrawal funds
+    pub available_withdrawal_funds: f64,
+
+    /// The account's margin balance
+    pub margin_balance: f64,
+
+    /// The account's initial margin
+    pub initial_margin: f64,
+
+    /// The account's maintenance margin
+    pub maintenance_margin: f64,
+
+    /// Projected initial margin
+    #[serde(default)]
+    pub projected_initial_margin: Option<f64>,
+
+    /// Projected maintenance margin
+    #[serde(default)]
+    pub projected_maintenance_margin: Option<f64>,
+
+    /// Profit and loss
+    pub total_pl: f64,
+
+    /// Session realized profit and loss
+    pub session_rpl: f64,
+
+    /// Session unrealized profit and loss
+    pub session_upl: f64,
+
+    /// The sum of position deltas
+    #[serde(default)]
+    pub delta_total: Option<f64>,
+
+    /// Options summary delta
+    #[serde(default)]
+    pub options_delta: Option<f64>,
+
+    /// Options summary gamma
+    #[serde(default)]
+    pub options_gamma: Option<f64>,
+
+    /// Options summary vega
+    #[serde(default)]
+    pub options_vega: Option<f64>,
+
+    /// Options summary theta
+    #[serde(default)]
+    pub options_theta: Option<f64>,
+
+    /// Options value
+    #[serde(default)]
+    pub options_value: Option<f64>,
+
+    /// Options profit and loss
+    #[serde(default)]
+    pub options_pl: Option<f64>,
+
+    /// Futures profit and loss
+    #[serde(default)]
+    pub futures_pl: Option<f64>,
+
+    /// The deposit fee balance
+    #[serde(default)]
+    pub fee_balance: Option<f64>,
+
+    /// Estimated liquidation ratio (cross margin only)
+    #[serde(default)]
+    pub estimated_liquidation_ratio: Option<f64>,
+
+    /// Name of the user's currently enabled margin model
+    #[serde(default)]
+    pub margin_model: Option<MarginModel>,
+
+    /// When `true` cross collateral is enabled for the user
+    #[serde(default)]
+    pub cross_collateral_enabled: Option<bool>,
+}
+
+/// Position of the user in one instrument
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Position {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// Instrument kind: "future", "option", "spot", "future_combo", "option_combo"
+    pub kind: InstrumentKind,
+
+    /// Direction: buy, sell or zero
+    pub direction: PositionDirection,
+
+    /// Position size for futures size in quote currency (e.g. USD), for options size is in base currency (e.g. BTC)
+    pub size: f64,
+
+    /// Only for futures, position size in base currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_currency: Option<f64>,
+
+    /// Average price of trades that built this position
+    pub average_price: f64,
+
+    /// Only for options, average price in USD
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_price_usd: Option<f64>,
+
+    /// Current mark price for position's instrument
+    pub mark_price: f64,
+
+    /// Current index price
+    pub index_price: f64,
+
+    /// Floating profit or loss
+    pub floating_profit_loss: f64,
+
+    /// Realized profit or loss
+    pub realized_profit_loss: f64,
+
+    /// Profit or loss from position
+    pub total_profit_loss: f64,
+
+    /// Initial margin
+    pub initial_margin: f64,
+
+    /// Maintenance margin
+    pub maintenance_margin: f64,
+
+    /// Open orders margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_orders_margin: Option<f64>,
+
+    /// Delta parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<f64>,
+
+    /// Only for options, gamma parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gamma: Option<f64>,
+
+    /// Only for options, vega parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vega: Option<f64>,
+
+    /// Only for options, theta parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theta: Option<f64>,
+
+    /// Current available leverage for future position
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leverage: Option<i64>,
+
+    /// Estimated liquidation price, added only for futures, for users with segregated_sm margin model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_liquidation_price: Option<f64>,
+
+    /// Optional (only for perpetual). Realized funding in current session included in session realized profit or loss
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realized_funding: Option<f64>,
+
+    /// Optional (only for perpetual). Value used to calculate realized_funding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_value: Option<f64>,
+
+    /// Optional (not added for spot). Last settlement price for position's instrument, 0 if instrument wasn't settled yet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settlement_price: Option<f64>,
+}
+
+/// Combined changes from `user.changes.*`
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserChanges {
+    /// Unique instrument identifier
+    #[serde(default)]
+    pub instrument_name: Option<String>,
+
+    /// Orders that changed
+    #[serde(default)]
+    pub orders: Vec<OpenOrder>,
+
+    /// Trades that were executed
+    #[serde(default)]
+    pub trades: Vec<Trade>,
+
+    /// Positions that changed
+    #[serde(default)]
+    pub positions: Vec<Position>,
+}
+
+/// `raw` user channels send a single object, aggregated intervals send an array
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(Box<T>),
+}
+
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(value: OneOrMany<T>) -> Self {
+        match value {
+            OneOrMany::Many(items) => items,
+            OneOrMany::One(item) => vec![*item],
+        }
+    }
+}
+
+/// Strongly typed payload of a subscription notification
+#[derive(Debug, Clone)]
+pub enum Notification {
+    /// `book.{instrument_name}.{interval}`
+    BookUpdate(BookUpdate),
+
+    /// `book.{instrument_name}.{group}.{depth}.{interval}`
+    GroupedBook(GroupedBookUpdate),
+
+    /// `trades.*`
+    Trades(Vec<PublicTrade>),
+
+    /// `ticker.{instrument_name}.{interval}`
+    Ticker(Box<Ticker>),
+
+    /// `incremental_ticker.{instrument_name}`
+    IncrementalTicker(Box<IncrementalTicker>),
+
+    /// `deribit_price_index.{index_name}`
+    PriceIndex(PriceIndex),
+
+    /// `markprice.options.{index_name}`
+    MarkPriceOptions(Vec<MarkPriceOption>),
+
+    /// `perpetual.{instrument_name}.{interval}`
+    Perpetual(PerpetualUpdate),
+
+    /// `quote.{instrument_name}`
+    Quote(QuoteUpdate),
+
+    /// `chart.trades.{instrument_name}.{resolution}`
+    Candle(Candle),
+
+    /// `user.orders.*`
+    Orders(Vec<OpenOrder>),
+
+    /// `user.trades.*`
+    UserTrades(Vec<Trade>),
+
+    /// `user.portfolio.{currency}`
+    Portfolio(Box<PortfolioUpdate>),
+
+    /// `user.changes.*`
+    Changes(Box<UserChanges>),
+
+    /// A channel without a typed payload; the raw data is kept
+    Other(serde_json::Value),
+}
+
+impl Notification {
+    /// Deserialize the `data` of a notification into the payload type of its channel
+    pub fn parse(channel: &str, data: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let notification = if let Some(rest) = channel.strip_prefix("book.") {
+            // book.{instrument}.{interval} vs book.{instrument}.{group}.{depth}.{interval}
+            if rest.split('.').count() > 2 {
+                Notification::GroupedBook(serde_json::from_value(data)?)
+            } else {
+                Notification::BookUpdate(serde_json::from_value(data)?)
+            }
+        } else if channel.starts_with("trades.") {
+            Notification::Trades(serde_json::from_value(data)?)
+        } else if channel.starts_with("ticker.") {
+            Notification::Ticker(serde_json::from_value(data)?)
+        } else if channel.starts_with("incremental_ticker.") {
+            Notification::IncrementalTicker(serde_json::from_value(data)?)
+        } else if channel.starts_with("deribit_price_index.") {
+            Notification::PriceIndex(serde_json::from_value(data)?)
+        } else if channel.starts_with("markprice.options.") {
+            Notification::MarkPriceOptions(serde_json::from_value(data)?)
+        } else if channel.starts_with("perpetual.") {
+            Notification::Perpetual(serde_json::from_value(data)?)
+        } else if channel.starts_with("quote.") {
+            Notification::Quote(serde_json::from_value(data)?)
+        } else if channel.starts_with("chart.trades.") {
+            Notification::Candle(serde_json::from_value(data)?)
+        } else if channel.starts_with("user.orders.") {
+            Notification::Orders(serde_json::from_value::<OneOrMany<OpenOrder>>(data)?.into())
+        } else if channel.starts_with("user.trades.") {
+            Notification::UserTrades(serde_json::from_value::<OneOrMany<Trade>>(data)?.into())
+        } else if channel.starts_with("user.portfolio.") {
+            Notification::Portfolio(serde_json::from_value(data)?)
+        } else if channel.starts_with("user.changes.") {
+            Notification::Changes(serde_json::from_value(data)?)
+        } else {
+            Notification::Other(data)
+        };
+        Ok(notification)
+    }
+}
+
+/// A subscription notification with its typed payload
+#[derive(Debug, Clone)]
+pub struct ChannelNotification {
+    /// Channel the notification belongs to (e.g., "book.BTC-PERPETUAL.100ms")
+    pub channel: String,
+
+    /// Typed payload
+    pub data: Notification,
+}
+
+impl TryFrom<SubscriptionNotification> for ChannelNotification {
+    type Error = serde_json::Error;
+
+    fn try_from(notification: SubscriptionNotification) -> Result<Self, Self::Error> {
+        let data = Notification::parse(&notification.channel, notification.data)?;
+        Ok(Self {
+            channel: notification.channel,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse(channel: &str, data: serde_json::Value) -> Notification {
+        Notification::parse(channel, data).unwrap()
+    }
+
+    #[test]
+    fn test_book_update() {
+        let data = json!({
+            "type": "change",
+            "timestamp": 1554373962454i64,
+            "prev_change_id": 297217,
+            "instrument_name": "BTC-PERPETUAL",
+            "change_id": 297218,
+            "bids": [["delete", 5032.0, 0]],
+            "asks": [["new", 5042.64, 40], ["change", 5043.0, 10.5]]
+        });
+
+        match parse("book.BTC-PERPETUAL.100ms", data) {
+            Notification::BookUpdate(book) => {
+                assert_eq!(book.update_type, BookUpdateType::Change);
+                assert_eq!(book.prev_change_id, Some(297217));
+                assert_eq!(book.bids[0].action, BookAction::Delete);
+                assert_eq!(book.asks.len(), 2);
+                assert_eq!(book.asks[1].price, 5043.0);
+                assert_eq!(book.asks[1].amount, 10.5);
+            }
+            other => panic!("Expected BookUpdate, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_book_snapshot_without_prev_change_id() {
+        let data = json!({
+            "type": "snapshot",
+            "timestamp": 1554373962454i64,
+            "instrument_name": "BTC-PERPETUAL",
+            "change_id": 297217,
+            "bids": [["new", 5032.0, 10]],
+            "asks": []
+        });
+
+        match parse("book.BTC-PERPETUAL.raw", data) {
+            Notification::BookUpdate(book) => {
+                assert_eq!(book.update_type, BookUpdateType::Snapshot);
+                assert!(book.prev_change_id.is_none());
+            }
+            other => panic!("Expected BookUpdate, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_grouped_book() {
+        let data = json!({
+            "timestamp": 1554375447971i64,
+            "instrument_name": "ETH-PERPETUAL",
+            "change_id": 109615,
+            "bids": [[160, 40]],
+            "asks": [[161, 20]]
+        });
+
+        match parse("book.ETH-PERPETUAL.none.1.100ms", data) {
+            Notification::GroupedBook(book) => {
+                assert_eq!(
+                    book.bids[0],
+                    BookLevel {
+                        price: 160.0,
+                        amount: 40.0
+                    }
+                );
+                assert_eq!(book.asks[0].price, 161.0);
+            }
+            other => panic!("Expected GroupedBook, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trades() {
+        let data = json!([{
+            "trade_seq": 30289432,
+            "trade_id": "48079254",
+            "timestamp": 1590484156350i64,
+            "tick_direction": 0,
+            "price": 8950.0,
+            "mark_price": 8948.9,
+            "instrument_name": "BTC-PERPETUAL",
+            "index_price": 8955.88,
+            "direction": "sell",
+            "amount": 10
+        }]);
+
+        match parse("trades.BTC-PERPETUAL.raw", data) {
+            Notification::Trades(trades) => {
+                assert_eq!(trades[0].trade_id, "48079254");
+                assert_eq!(trades[0].tick_direction, TickDirection::PlusTick);
+                assert_eq!(trades[0].direction, OrderDirection::Sell);
+            }
+            other => panic!("Expected Trades, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ticker() {
+        let data = json!({
+            "timestamp": 1623060194301i64,
+            "stats": {"volume_usd": 284061480, "volume": 7871.02139035, "price_change": 0.7229, "low": 35213.5, "high": 36824.5},
+            "state": "open",
+            "settlement_price": 36169.49,
+            "open_interest": 502097590,
+            "min_price": 35898.37,
+            "max_price": 36991.72,
+            "mark_price": 36446.51,
+            "last_price": 36457.5,
+            "interest_value": 1.7362511643080387,
+            "instrument_name": "BTC-PERPETUAL",
+            "index_price": 36441.64,
+            "funding_8h": 0.0000211,
+            "estimated_delivery_price": 36441.64,
+            "current_funding": 0,
+            "best_bid_price": 36442.5,
+            "best_bid_amount": 5000,
+            "best_ask_price": 36443,
+            "best_ask_amount": 100
+        });
+
+        match parse("ticker.BTC-PERPETUAL.100ms", data) {
+            Notification::Ticker(ticker) => {
+                assert_eq!(ticker.instrument_name, "BTC-PERPETUAL");
+                assert_eq!(ticker.state, TickerState::Open);
+                assert_eq!(ticker.best_bid_price, Some(36442.5));
+                assert_eq!(ticker.stats.high, Some(36824.5));
+                assert!(ticker.greeks.is_none());
+            }
+            other => panic!("Expected Ticker, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_incremental_ticker_change() {
+        let data = json!({
+            "type": "change",
+            "timestamp": 1623060194301i64,
+            "instrument_name": "BTC-PERPETUAL",
+            "best_bid_price": 36443.0
+        });
+
+        match parse("incremental_ticker.BTC-PERPETUAL", data) {
+            Notification::IncrementalTicker(ticker) => {
+                assert_eq!(ticker.update_type, BookUpdateType::Change);
+                assert_eq!(ticker.best_bid_price, Some(36443.0));
+                assert!(ticker.mark_price.is_none());
+            }
+            other => panic!("Expected IncrementalTicker, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_index_mark_perpetual_quote_and_candle() {
+        match parse(
+            "deribit_price_index.btc_usd",
+            json!({"timestamp": 1550588002899i64, "price": 3937.89, "index_name": "btc_usd"}),
+        ) {
+            Notification::PriceIndex(index) => assert_eq!(index.price, 3937.89),
+            other => panic!("Expected PriceIndex, got {:?}", other),
+        }
+        match parse(
+            "markprice.options.btc_usd",
+            json!([{"timestamp": 1622470378005i64, "mark_price": 0.0333, "iv": 0.9, "instrument_name": "BTC-2JUN21-37000-P"}]),
+        ) {
+            Notification::MarkPriceOptions(marks) => assert_eq!(marks[0].instrument_name, "BTC-2JUN21-37000-P"),
+            other => panic!("Expected MarkPriceOptions, got {:?}", other),
+        }
+        match parse(
+            "perpetual.BTC-PERPETUAL.raw",
+            json!({"timestamp": 1571386349530i64, "interest": 0.004999511380756577, "index_price": 7872.88}),
+        ) {
+            Notification::Perpetual(perpetual) => assert_eq!(perpetual.index_price, 7872.88),
+            other => panic!("Expected Perpetual, got {:?}", other),
+        }
+        match parse(
+            "quote.BTC-PERPETUAL",
+            json!({"timestamp": 1550658624149i64, "instrument_name": "BTC-PERPETUAL", "best_bid_price": 3914.97, "best_bid_amount": 40, "best_ask_price": 3996.61, "best_ask_amount": 50}),
+        ) {
+            Notification::Quote(quote) => assert_eq!(quote.best_ask_price, Some(3996.61)),
+            other => panic!("Expected Quote, got {:?}", other),
+        }
+        match parse(
+            "chart.trades.BTC-PERPETUAL.1",
+            json!({"volume": 0.05219351, "tick": 1573645080000i64, "open": 8869.79, "low": 8788.25, "high": 8870.31, "cost": 460, "close": 8791.25}),
+        ) {
+            Notification::Candle(candle) => assert_eq!(candle.tick, 1573645080000),
+            other => panic!("Expected Candle, got {:?}", other),
+        }
+    }
+
+    fn order_json() -> serde_json::Value {
+        json!({
+            "time_in_force": "good_til_cancelled",
+            "replaced": false,
+            "reduce_only": false,
+            "price": 10502.52,
+            "post_only": false,
+            "order_type": "limit",
+            "order_state": "open",
+            "order_id": "ETH-100234",
+            "max_show": 1,
+            "last_update_timestamp": 1634138080000i64,
+            "label": "",
+            "is_liquidation": false,
+            "instrument_name": "ETH-PERPETUAL",
+            "filled_amount": 0,
+            "direction": "buy",
+            "creation_timestamp": 1634138080000i64,
+            "api": true,
+            "amount": 1
+        })
+    }
+
+    #[test]
+    fn test_user_orders_single_and_array() {
+        match parse("user.orders.ETH-PERPETUAL.raw", order_json()) {
+            Notification::Orders(orders) => assert_eq!(orders[0].order_id, "ETH-100234"),
+            other => panic!("Expected Orders, got {:?}", other),
+        }
+        match parse(
+            "user.orders.future.ETH.100ms",
+            json!([order_json(), order_json()]),
+        ) {
+            Notification::Orders(orders) => assert_eq!(orders.len(), 2),
+            other => panic!("Expected Orders, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_user_changes() {
+        let data = json!({
+            "instrument_name": "BTC-PERPETUAL",
+            "trades": [],
+            "positions": [{
+                "total_profit_loss": 0.000507746,
+                "size_currency": -0.001,
+                "size": -40,
+                "settlement_price": 38150.38,
+                "realized_profit_loss": 0,
+                "open_orders_margin": 0,
+                "mark_price": 38105.5,
+                "maintenance_margin": 0.000019,
+                "kind": "future",
+                "instrument_name": "BTC-PERPETUAL",
+                "initial_margin": 0.000036,
+                "index_price": 38104.65,
+                "floating_profit_loss": 0.000002,
+                "direction": "sell",
+                "delta": -0.001,
+                "average_price": 36826.54
+            }],
+            "orders": [order_json()]
+        });
+
+        match parse("user.changes.BTC-PERPETUAL.raw", data) {
+            Notification::Changes(changes) => {
+                assert_eq!(changes.positions[0].direction, PositionDirection::Sell);
+                assert_eq!(changes.positions[0].kind, InstrumentKind::Future);
+                assert_eq!(changes.orders.len(), 1);
+                assert!(changes.trades.is_empty());
+            }
+            other => panic!("Expected Changes, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_user_portfolio() {
+        let data = json!({
+            "total_pl": 0.00000425,
+            "session_upl": 0.00000425,
+            "session_rpl": -2.25e-7,
+            "projected_maintenance_margin": 0.00009141,
+            "projected_initial_margin": 0.00012542,
+            "options_value": 0,
+            "margin_balance": 0.1,
+            "maintenance_margin": 0.00009141,
+            "initial_margin": 0.00012542,
+            "equity": 0.1,
+            "delta_total": 0.0014,
+            "currency": "BTC",
+            "balance": 0.1,
+            "available_withdrawal_funds": 0.09,
+            "available_funds": 0.09,
+            "margin_model": "segregated_sm"
+        });
+
+        match parse("user.portfolio.btc", data) {
+            Notification::Portfolio(portfolio) => {
+                assert_eq!(portfolio.currency, Currency::BTC);
+                assert_eq!(portfolio.margin_model, Some(MarginModel::SegregatedSm));
+                assert_eq!(portfolio.equity, 0.1);
+                assert_eq!(portfolio.delta_total, Some(0.0014));
+            }
+            other => panic!("Expected Portfolio, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_channel_keeps_raw_data() {
+        match parse("platform_state", json!({"locked": false})) {
+            Notification::Other(data) => assert_eq!(data["locked"], false),
+            other => panic!("Expected Other, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_malformed_payload_is_an_error() {
+        let result = Notification::parse("quote.BTC-PERPETUAL", json!({"timestamp": "yesterday"}));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_channel_notification_from_subscription() {
+        let notification = SubscriptionNotification {
+            channel: "deribit_price_index.eth_usd".to_string(),
+            data: json!({"timestamp": 1550588002899i64, "price": 140.5, "index_name": "eth_usd"}),
+        };
+
+        let typed = ChannelNotification::try_from(notification).unwrap();
+
+        assert_eq!(typed.channel, "deribit_price_index.eth_usd");
+        assert!(matches!(typed.data, Notification::PriceIndex(_)));
+    }
+}
@@ -208,7 +208,7 @@ impl RestClient {
 
 #[cfg(test)]
 mod tests {
-    use rest::secrets::ExposableSecret;
+    use rest::secrets::{ExposableSecret, HmacSigner};
     use serde_json::{Value, json};
 
     use super::*;
@@ -425,7 +425,7 @@ mod tests {
     async fn test_add_block_rfq_quote_method_exists() {
         // Test that the method exists and compiles without needing to call it
         let api_key = Box::new(PlainTextSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let rate_limiter = crate::deribit::RateLimiter::new(AccountTier::Tier4);
 
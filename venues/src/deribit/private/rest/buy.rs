@@ -0,0 +1,476 @@
+//! Places a buy order via /private/buy
+//!
+//! The request and response types defined here are shared by the other order entry
+//! endpoints: `private/sell` takes the same parameters, and `private/buy`, `private/sell`,
+//! `private/edit`, `private/edit_by_label` and `private/close_position` all return the
+//! order together with the trades it produced immediately.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{
+    AdvancedType, EndpointType, LinkedOrderType, OpenOrder, OrderDirection, OrderEntryType, RestResult, TimeInForce, Trade, TriggerFillCondition, TriggerType,
+};
+
+/// Request parameters for placing an order with /private/buy or /private/sell
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OrderRequest {
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// Order size in currency units (USD for perpetual and inverse futures, base currency
+    /// for options and linear futures). Either `amount` or `contracts` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+
+    /// Order size in contract units. If both are given, `amount` must match `contracts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts: Option<f64>,
+
+    /// The order type, default: "limit"
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<OrderEntryType>,
+
+    /// User defined label for the order (maximum 64 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// The order price in base currency (only for limit and stop_limit orders).
+    /// When `advanced` is set this is the price in USD or the implied volatility in percent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+
+    /// Specifies how long the order remains in effect, default: "good_til_cancelled"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// Maximum amount within an order to be shown to other customers (iceberg orders)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_show: Option<f64>,
+
+    /// If true, the order is considered post-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+
+    /// If true and `post_only` is set, the order is rejected instead of repriced when it would take liquidity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_post_only: Option<bool>,
+
+    /// If true, the order is considered reduce-only which is intended to only reduce a current position
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// Trigger price, required for trigger orders only (stop_limit, stop_market, take_limit, take_market)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<f64>,
+
+    /// The maximum deviation from the price peak beyond which the order will be triggered (trailing_stop only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_offset: Option<f64>,
+
+    /// Defines the trigger type, required for trigger orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<TriggerType>,
+
+    /// Advanced option order type, "usd" or "implv" (options only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advanced: Option<AdvancedType>,
+
+    /// Order MMP flag, only for order_type "limit"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mmp: Option<bool>,
+
+    /// Timestamp (milliseconds since the UNIX epoch) after which the server rejects the
+    /// request with error 10041 instead of processing it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<i64>,
+
+    /// The type of the linked order group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_order_type: Option<LinkedOrderType>,
+
+    /// The fill condition of the linked order, default: "first_hit"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_fill_condition: Option<TriggerFillCondition>,
+
+    /// The secondary orders of an OTO/OCO/OTOCO group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub otoco_config: Option<Vec<OtocoConfig>>,
+}
+
+impl OrderRequest {
+    /// A limit order for `amount` at `price`
+    pub fn limit(instrument_name: impl Into<String>, amount: f64, price: f64) -> Self {
+        Self {
+            instrument_name: instrument_name.into(),
+            amount: Some(amount),
+            order_type: Some(OrderEntryType::Limit),
+            price: Some(price),
+            ..Self::default()
+        }
+    }
+
+    /// A market order for `amount`
+    pub fn market(instrument_name: impl Into<String>, amount: f64) -> Self {
+        Self {
+            instrument_name: instrument_name.into(),
+            amount: Some(amount),
+            order_type: Some(OrderEntryType::Market),
+            ..Self::default()
+        }
+    }
+}
+
+/// Request parameters for /private/buy
+pub type BuyRequest = OrderRequest;
+
+/// A secondary order of a linked order group
+#[derive(Debug, Clone, Serialize)]
+pub struct OtocoConfig {
+    /// Order size in currency units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+
+    /// Direction of the secondary order
+    pub direction: OrderDirection,
+
+    /// The order type, default: "limit"
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<OrderEntryType>,
+
+    /// User defined label for the order (maximum 64 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// The order price in base currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+
+    /// If true, the order is considered reduce-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// Specifies how long the order remains in effect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+
+    /// If true, the order is considered post-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+
+    /// If true and `post_only` is set, the order is rejected instead of repriced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_post_only: Option<bool>,
+
+    /// Trigger price, required for trigger orders only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<f64>,
+
+    /// The maximum deviation from the price peak beyond which the order will be triggered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_offset: Option<f64>,
+
+    /// Defines the trigger type, required for trigger orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<TriggerType>,
+}
+
+impl OtocoConfig {
+    /// A secondary order in the given direction with all other fields unset
+    pub fn new(direction: OrderDirection) -> Self {
+        Self {
+            amount: None,
+            direction,
+            order_type: None,
+            label: None,
+            price: None,
+            reduce_only: None,
+            time_in_force: None,
+            post_only: None,
+            reject_post_only: None,
+            trigger_price: None,
+            trigger_offset: None,
+            trigger: None,
+        }
+    }
+}
+
+/// The order and the trades it produced immediately
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderResult {
+    /// The order after processing the request
+    pub order: OpenOrder,
+
+    /// Trades executed as a direct result of the request
+    pub trades: Vec<Trade>,
+}
+
+/// Response for the order entry endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The order and its trades
+    pub result: OrderResult,
+}
+
+/// Response for /private/buy
+pub type BuyResponse = OrderResponse;
+
+impl RestClient {
+    /// Places a buy order for an instrument.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read_write`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-buy>
+    ///
+    /// Rate limit: Matching engine rate limits apply
+    ///
+    /// # Arguments
+    /// * `request` - The order parameters
+    ///
+    /// # Returns
+    /// The order and the trades it produced immediately
+    pub async fn buy(&self, request: BuyRequest) -> RestResult<BuyResponse> {
+        self.send_signed_request("private/buy", &request, EndpointType::MatchingEngine)
+            .await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use rest::secrets::{ExposableSecret, HmacSigner};
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, OrderState, RateLimiter};
+
+    #[derive(Clone)]
+    pub(crate) struct PlainTextSecret {
+        pub(crate) secret: String,
+    }
+
+    impl ExposableSecret for PlainTextSecret {
+        fn expose_secret(&self) -> String {
+            self.secret.clone()
+        }
+    }
+
+    pub(crate) fn order_response_json() -> serde_json::Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 5275,
+            "result": {
+                "trades": [
+                    {
+                        "trade_seq": 1966056,
+                        "trade_id": "ETH-2696083",
+                        "timestamp": 1590483938456i64,
+                        "tick_direction": 0,
+                        "state": "filled",
+                        "reduce_only": false,
+                        "price": 8946.0,
+                        "post_only": false,
+                        "order_type": "market",
+                        "order_id": "ETH-584849853",
+                        "matching_id": null,
+                        "mark_price": 8952.86,
+                        "liquidity": "T",
+                        "label": "market0000234",
+                        "instrument_name": "ETH-PERPETUAL",
+                        "index_price": 8956.73,
+                        "fee_currency": "ETH",
+                        "fee": 0.00000168,
+                        "direction": "buy",
+                        "amount": 40.0,
+                        "profit_loss": 0.0
+                    }
+                ],
+                "order": {
+                    "web": false,
+                    "time_in_force": "good_til_cancelled",
+                    "replaced": false,
+                    "reduce_only": false,
+                    "price": 8947.0,
+                    "post_only": false,
+                    "order_type": "market",
+                    "order_state": "filled",
+                    "order_id": "ETH-584849853",
+                    "max_show": 40.0,
+                    "last_update_timestamp": 1590483938456i64,
+                    "label": "market0000234",
+                    "is_liquidation": false,
+                    "instrument_name": "ETH-PERPETUAL",
+                    "filled_amount": 40.0,
+                    "direction": "buy",
+                    "creation_timestamp": 1590483938456i64,
+                    "average_price": 8946.0,
+                    "api": true,
+                    "amount": 40.0
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_limit_request_serialization() {
+        let request = BuyRequest {
+            label: Some("entry".to_string()),
+            post_only: Some(true),
+            reject_post_only: Some(true),
+            mmp: Some(true),
+            valid_until: Some(1700000000000),
+            ..BuyRequest::limit("BTC-PERPETUAL", 100.0, 50000.0)
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({
+                "instrument_name": "BTC-PERPETUAL",
+                "amount": 100.0,
+                "type": "limit",
+                "price": 50000.0,
+                "label": "entry",
+                "post_only": true,
+                "reject_post_only": true,
+                "mmp": true,
+                "valid_until": 1700000000000i64
+            })
+        );
+    }
+
+    #[test]
+    fn test_trigger_request_serialization() {
+        let request = BuyRequest {
+            instrument_name: "ETH-PERPETUAL".to_string(),
+            contracts: Some(5.0),
+            order_type: Some(OrderEntryType::StopMarket),
+            trigger_price: Some(3100.0),
+            trigger: Some(TriggerType::MarkPrice),
+            reduce_only: Some(true),
+            ..BuyRequest::default()
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value["type"], "stop_market");
+        assert_eq!(json_value["trigger"], "mark_price");
+        assert_eq!(json_value["trigger_price"], 3100.0);
+        assert_eq!(json_value["contracts"], 5.0);
+        assert!(json_value.get("amount").is_none());
+    }
+
+    #[test]
+    fn test_advanced_option_request_serialization() {
+        let request = BuyRequest {
+            advanced: Some(AdvancedType::Implv),
+            ..BuyRequest::limit("BTC-27JUN25-60000-C", 1.0, 55.0)
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value["advanced"], "implv");
+        assert_eq!(json_value["price"], 55.0);
+    }
+
+    #[test]
+    fn test_linked_order_request_serialization() {
+        let request = BuyRequest {
+            linked_order_type: Some(LinkedOrderType::OneTriggersOneCancelsOther),
+            trigger_fill_condition: Some(TriggerFillCondition::Incremental),
+            otoco_config: Some(vec![
+                OtocoConfig {
+                    amount: Some(100.0),
+                    order_type: Some(OrderEntryType::TakeLimit),
+                    price: Some(52000.0),
+                    trigger_price: Some(51900.0),
+                    trigger: Some(TriggerType::LastPrice),
+                    reduce_only: Some(true),
+                    ..OtocoConfig::new(OrderDirection::Sell)
+                },
+                OtocoConfig {
+                    amount: Some(100.0),
+                    order_type: Some(OrderEntryType::StopMarket),
+                    trigger_price: Some(48000.0),
+                    trigger: Some(TriggerType::MarkPrice),
+                    ..OtocoConfig::new(OrderDirection::Sell)
+                },
+            ]),
+            ..BuyRequest::limit("BTC-PERPETUAL", 100.0, 50000.0)
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value["linked_order_type"],
+            "one_triggers_one_cancels_other"
+        );
+        assert_eq!(json_value["trigger_fill_condition"], "incremental");
+        assert_eq!(json_value["otoco_config"][0]["type"], "take_limit");
+        assert_eq!(json_value["otoco_config"][0]["direction"], "sell");
+        assert_eq!(json_value["otoco_config"][1]["trigger"], "mark_price");
+        assert!(json_value["otoco_config"][1].get("price").is_none());
+    }
+
+    #[test]
+    fn test_market_request_serialization() {
+        let request = BuyRequest::market("ETH-PERPETUAL", 40.0);
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"instrument_name": "ETH-PERPETUAL", "amount": 40.0, "type": "market"})
+        );
+    }
+
+    #[test]
+    fn test_response_deserialization() {
+        let response: BuyResponse = serde_json::from_value(order_response_json()).unwrap();
+
+        assert_eq!(response.id, 5275);
+        assert_eq!(response.result.order.order_id, "ETH-584849853");
+        assert_eq!(response.result.order.order_state, OrderState::Filled);
+        assert_eq!(response.result.order.filled_amount, 40.0);
+        assert_eq!(response.result.trades.len(), 1);
+        assert_eq!(response.result.trades[0].order_id, "ETH-584849853");
+        assert_eq!(response.result.trades[0].price, 8946.0);
+    }
+
+    pub(crate) fn test_client() -> RestClient {
+        let api_key = Box::new(PlainTextSecret {
+            secret: "test_key".to_string(),
+        }) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret {
+            secret: "test_secret".to_string(),
+        }) as Box<dyn HmacSigner>;
+
+        RestClient::new(
+            api_key,
+            api_secret,
+            "https://test.deribit.com",
+            RateLimiter::new(AccountTier::Tier4),
+            reqwest::Client::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_buy_uses_matching_engine_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::buy;
+        assert_eq!(
+            EndpointType::from_path("private/buy"),
+            EndpointType::MatchingEngine
+        );
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::MatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -64,7 +64,7 @@ impl RestClient {
 
 #[cfg(test)]
 mod tests {
-    use rest::secrets::ExposableSecret;
+    use rest::secrets::{ExposableSecret, HmacSigner};
     use serde_json::{json, Value};
 
     use super::*;
@@ -181,7 +181,7 @@ mod tests {
     async fn test_cancel_all_block_rfq_quotes_method_exists() {
         // Test that the method exists and compiles without needing to call it
         let api_key = Box::new(PlainTextSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let rate_limiter = crate::deribit::RateLimiter::new(AccountTier::Tier4);
 
@@ -1,15 +1,20 @@
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use chrono::Utc;
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
+use async_trait::async_trait;
+use reqwest::Method;
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::json;
-use sha2::Sha256;
 
-use crate::deribit::{EndpointType, Errors, RateLimiter, RestResult};
+use crate::deribit::errors::VENUE;
+use crate::deribit::{EndpointType, ErrorResponse, Errors, RateLimiter, RestResult};
 
 /// Private REST client for Deribit exchange
 ///
@@ -20,7 +25,7 @@ pub struct RestClient {
     pub base_url: Cow<'static, str>,
 
     /// The underlying HTTP client used for making requests
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates
     pub rate_limiter: RateLimiter,
@@ -28,8 +33,11 @@ pub struct RestClient {
     /// The encrypted API key
     pub(crate) api_key: Box<dyn ExposableSecret>,
 
-    /// The encrypted API secret
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
+
+    /// The server clock signed requests are timestamped with
+    pub(crate) clock: ServerClock,
 }
 
 impl RestClient {
@@ -37,7 +45,7 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `base_url` - The base URL for the API
     /// * `rate_limiter` - The rate limiter instance
     /// * `client` - The HTTP client instance
@@ -46,20 +54,27 @@ impl RestClient {
     /// A new RestClient instance
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         base_url: impl Into<Cow<'static, str>>,
         rate_limiter: RateLimiter,
-        client: Client,
+        client: impl HttpTransport + 'static,
     ) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
             api_key,
             api_secret,
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `nonce` of signed requests from `clock`, as Deribit rejects stale ones
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Signs a request for Deribit private endpoints
     ///
     /// The Deribit signing algorithm:
@@ -74,16 +89,18 @@ impl RestClient {
     ///
     /// # Returns
     /// A result containing the signature as a hex string or an error
-    pub fn sign_request(&self, request_data: &str, nonce: u64, request_id: u64) -> Result<String, Errors> {
+    pub async fn sign_request(&self, request_data: &str, nonce: u64, request_id: u64) -> Result<String, Errors> {
         // Create the signature payload: request_data + nonce + request_id
         let sig_payload = format!("{}{}{}", request_data, nonce, request_id);
 
         // Sign with HMAC-SHA256
-        let api_secret = self.api_secret.expose_secret();
-        let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-        mac.update(sig_payload.as_bytes());
+        let signature = self
+            .api_secret
+            .hmac_sha256(sig_payload.as_bytes())
+            .await
+            .map_err(|_| Errors::InvalidApiKey())?;
 
-        Ok(hex::encode(mac.finalize().into_bytes()))
+        Ok(hex::encode(signature))
     }
 
     /// Send a signed private request to Deribit API, handling serialization and rate limiting.
@@ -92,10 +109,15 @@ impl RestClient {
         T: DeserializeOwned,
         P: Serialize + ?Sized,
     {
+        let params = serde_json::to_value(params).map_err(Errors::SerdeJsonError)?;
+
         // Rate limiting
-        self.rate_limiter.check_limits(endpoint_type).await?;
+        let instrument_name = params.get("instrument_name").and_then(|name| name.as_str());
+        self.rate_limiter
+            .admit(method, endpoint_type, instrument_name)
+            .await?;
 
-        let nonce = Utc::now().timestamp_millis() as u64;
+        let nonce = self.clock.now_ms();
         let request_id = 1;
 
         // Prepare the JSON-RPC request body for signing
@@ -106,7 +128,9 @@ impl RestClient {
             "params": params,
         });
         let request_data_str = serde_json::to_string(&request_data).map_err(Errors::SerdeJsonError)?;
-        let signature = self.sign_request(&request_data_str, nonce, request_id)?;
+        let signature = self
+            .sign_request(&request_data_str, nonce, request_id)
+            .await?;
 
         // Prepare authenticated request
         let authenticated_request = json!({
@@ -120,18 +144,16 @@ impl RestClient {
         });
 
         // Send HTTP request
-        let resp = self
-            .client
-            .post(format!("{}/api/v2/{}", self.base_url, method))
-            .json(&authenticated_request)
-            .send()
-            .await?;
-
-        // Record request for rate limiting
-        self.rate_limiter.record_request(endpoint_type).await;
+        let request = HttpRequest::new(Method::POST, format!("{}/api/v2/{}", self.base_url, method)).json(&authenticated_request)?;
+        let resp = self.client.execute(request).await?;
 
-        // Deserialize response
-        let result = resp.json::<T>().await?;
+        // Deserialize response, letting the rate limiter see JSON-RPC errors
+        let body = resp.body;
+        if let Some(error) = ErrorResponse::from_body(&body) {
+            self.rate_limiter.record_error(error.code).await;
+            return Err(Errors::ApiError(error.into()));
+        }
+        let result = serde_json::from_str::<T>(&body)?;
         Ok(result)
     }
 
@@ -150,8 +172,41 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Deribit private methods are JSON-RPC calls, so `request.method` is ignored
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_signed_request::<serde_json::Value, _>(
+                &request.endpoint,
+                &request.params.unwrap_or_else(|| json!({})),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::Client;
+
     use super::*;
     use crate::deribit::AccountTier;
 
@@ -176,7 +231,7 @@ mod tests {
     #[test]
     fn test_private_client_creation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new(AccountTier::Tier4);
 
@@ -191,10 +246,10 @@ mod tests {
         assert_eq!(rest_client.base_url, "https://test.deribit.com");
     }
 
-    #[test]
-    fn test_signature_generation() {
+    #[tokio::test]
+    async fn test_signature_generation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new(AccountTier::Tier4);
 
@@ -206,7 +261,7 @@ mod tests {
             client,
         );
 
-        let result = rest_client.sign_request("test_data", 1234567890, 1);
+        let result = rest_client.sign_request("test_data", 1234567890, 1).await;
 
         assert!(result.is_ok());
         let signature = result.unwrap();
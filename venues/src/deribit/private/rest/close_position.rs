@@ -0,0 +1,111 @@
+//! Closes a position with a limit or market order via /private/close_position
+
+use serde::Serialize;
+
+pub use super::buy::OrderResponse;
+use super::client::RestClient;
+use crate::deribit::{EndpointType, OrderEntryType, RestResult};
+
+/// Request parameters for /private/close_position
+#[derive(Debug, Clone, Serialize)]
+pub struct ClosePositionRequest {
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// The order type used to close the position, "limit" or "market"
+    #[serde(rename = "type")]
+    pub order_type: OrderEntryType,
+
+    /// Optional price for a limit order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+}
+
+/// Response for /private/close_position
+pub type ClosePositionResponse = OrderResponse;
+
+impl RestClient {
+    /// Makes a reduce-only order that closes the current position on an instrument.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read_write`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-close_position>
+    ///
+    /// Rate limit: Matching engine rate limits apply
+    ///
+    /// # Arguments
+    /// * `request` - The instrument, order type and optional limit price
+    ///
+    /// # Returns
+    /// The closing order and the trades it produced immediately
+    pub async fn close_position(&self, request: ClosePositionRequest) -> RestResult<ClosePositionResponse> {
+        self.send_signed_request(
+            "private/close_position",
+            &request,
+            EndpointType::MatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::{order_response_json, test_client};
+
+    #[test]
+    fn test_close_position_request_serialization() {
+        let request = ClosePositionRequest {
+            instrument_name: "ETH-PERPETUAL".to_string(),
+            order_type: OrderEntryType::Limit,
+            price: Some(145.17),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"instrument_name": "ETH-PERPETUAL", "type": "limit", "price": 145.17})
+        );
+    }
+
+    #[test]
+    fn test_close_position_market_request_serialization() {
+        let request = ClosePositionRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+            order_type: OrderEntryType::Market,
+            price: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert!(json_value.get("price").is_none());
+        assert_eq!(json_value["type"], "market");
+    }
+
+    #[test]
+    fn test_close_position_response_deserialization() {
+        let response: ClosePositionResponse = serde_json::from_value(order_response_json()).unwrap();
+
+        assert_eq!(response.result.trades[0].amount, 40.0);
+    }
+
+    #[tokio::test]
+    async fn test_close_position_uses_matching_engine_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::close_position;
+        assert_eq!(
+            EndpointType::from_path("private/close_position"),
+            EndpointType::MatchingEngine
+        );
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::MatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
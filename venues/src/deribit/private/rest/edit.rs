@@ -0,0 +1,139 @@
+//! Changes price, amount and/or other properties of an order via /private/edit
+
+use serde::Serialize;
+
+pub use super::buy::OrderResponse;
+use super::client::RestClient;
+use crate::deribit::{AdvancedType, EndpointType, RestResult};
+
+/// Request parameters for /private/edit
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditRequest {
+    /// The order id
+    pub order_id: String,
+
+    /// New order size in currency units. Either `amount` or `contracts` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+
+    /// New order size in contract units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts: Option<f64>,
+
+    /// The new order price in base currency (or USD / implied volatility for advanced orders)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+
+    /// If true, the order is considered post-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+
+    /// If true, the order is considered reduce-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// If true and `post_only` is set, the order is rejected instead of repriced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_post_only: Option<bool>,
+
+    /// Advanced option order type, "usd" or "implv" (options only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advanced: Option<AdvancedType>,
+
+    /// Trigger price, for trigger orders only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<f64>,
+
+    /// The maximum deviation from the price peak beyond which the order will be triggered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_offset: Option<f64>,
+
+    /// Order MMP flag, only for order_type "limit"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mmp: Option<bool>,
+
+    /// Timestamp (milliseconds since the UNIX epoch) after which the server rejects the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<i64>,
+}
+
+/// Response for /private/edit
+pub type EditResponse = OrderResponse;
+
+impl RestClient {
+    /// Changes price, amount and/or other properties of an order.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read_write`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-edit>
+    ///
+    /// Rate limit: Matching engine rate limits apply
+    ///
+    /// # Arguments
+    /// * `request` - The order id and the properties to change
+    ///
+    /// # Returns
+    /// The edited order and the trades it produced immediately
+    pub async fn edit(&self, request: EditRequest) -> RestResult<EditResponse> {
+        self.send_signed_request("private/edit", &request, EndpointType::MatchingEngine)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::{order_response_json, test_client};
+
+    #[test]
+    fn test_edit_request_serialization() {
+        let request = EditRequest {
+            order_id: "ETH-584849853".to_string(),
+            amount: Some(20.0),
+            price: Some(8950.0),
+            post_only: Some(true),
+            valid_until: Some(1700000000000),
+            ..EditRequest::default()
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({
+                "order_id": "ETH-584849853",
+                "amount": 20.0,
+                "price": 8950.0,
+                "post_only": true,
+                "valid_until": 1700000000000i64
+            })
+        );
+    }
+
+    #[test]
+    fn test_edit_response_deserialization() {
+        let response: EditResponse = serde_json::from_value(order_response_json()).unwrap();
+
+        assert_eq!(response.result.order.order_id, "ETH-584849853");
+    }
+
+    #[tokio::test]
+    async fn test_edit_uses_matching_engine_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::edit;
+        assert_eq!(
+            EndpointType::from_path("private/edit"),
+            EndpointType::MatchingEngine
+        );
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::MatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
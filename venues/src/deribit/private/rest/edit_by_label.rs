@@ -0,0 +1,146 @@
+//! Changes price, amount and/or other properties of an order identified by its label
+//! via /private/edit_by_label
+
+use serde::Serialize;
+
+pub use super::buy::OrderResponse;
+use super::client::RestClient;
+use crate::deribit::{AdvancedType, EndpointType, RestResult};
+
+/// Request parameters for /private/edit_by_label
+///
+/// The label must identify exactly one open order on the instrument.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditByLabelRequest {
+    /// User defined label of the order (maximum 64 characters)
+    pub label: String,
+
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// New order size in currency units. Either `amount` or `contracts` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+
+    /// New order size in contract units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts: Option<f64>,
+
+    /// The new order price in base currency (or USD / implied volatility for advanced orders)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+
+    /// If true, the order is considered post-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+
+    /// If true, the order is considered reduce-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+
+    /// If true and `post_only` is set, the order is rejected instead of repriced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_post_only: Option<bool>,
+
+    /// Advanced option order type, "usd" or "implv" (options only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advanced: Option<AdvancedType>,
+
+    /// Trigger price, for trigger orders only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<f64>,
+
+    /// Order MMP flag, only for order_type "limit"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mmp: Option<bool>,
+
+    /// Timestamp (milliseconds since the UNIX epoch) after which the server rejects the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<i64>,
+}
+
+/// Response for /private/edit_by_label
+pub type EditByLabelResponse = OrderResponse;
+
+impl RestClient {
+    /// Changes price, amount and/or other properties of an order identified by its label.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read_write`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-edit_by_label>
+    ///
+    /// Rate limit: Matching engine rate limits apply
+    ///
+    /// # Arguments
+    /// * `request` - The label, instrument and the properties to change
+    ///
+    /// # Returns
+    /// The edited order and the trades it produced immediately
+    pub async fn edit_by_label(&self, request: EditByLabelRequest) -> RestResult<EditByLabelResponse> {
+        self.send_signed_request(
+            "private/edit_by_label",
+            &request,
+            EndpointType::MatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::{order_response_json, test_client};
+
+    #[test]
+    fn test_edit_by_label_request_serialization() {
+        let request = EditByLabelRequest {
+            label: "market0000234".to_string(),
+            instrument_name: "ETH-PERPETUAL".to_string(),
+            contracts: Some(4.0),
+            advanced: Some(AdvancedType::Usd),
+            ..EditByLabelRequest::default()
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({
+                "label": "market0000234",
+                "instrument_name": "ETH-PERPETUAL",
+                "contracts": 4.0,
+                "advanced": "usd"
+            })
+        );
+    }
+
+    #[test]
+    fn test_edit_by_label_response_deserialization() {
+        let response: EditByLabelResponse = serde_json::from_value(order_response_json()).unwrap();
+
+        assert_eq!(
+            response.result.order.label.as_deref(),
+            Some("market0000234")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_by_label_uses_matching_engine_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::edit_by_label;
+        assert_eq!(
+            EndpointType::from_path("private/edit_by_label"),
+            EndpointType::MatchingEngine
+        );
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::MatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
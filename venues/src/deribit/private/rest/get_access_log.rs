@@ -0,0 +1,154 @@
+//! Retrieves the account access history via /private/get_access_log
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for /private/get_access_log
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetAccessLogRequest {
+    /// The offset for pagination, default - 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+
+    /// Number of requested items, default - 10
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+/// One access to the account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessLogEntry {
+    /// Unique identifier
+    pub id: i64,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+
+    /// Action description, e.g. "success", "failure", "enabled_tfa"
+    pub log: String,
+
+    /// IP address of the source
+    pub ip: String,
+
+    /// City where the IP address is registered (estimated)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// Country where the IP address is registered (estimated)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// Optional additional information about the action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+/// A page of the access log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessLog {
+    /// Log entries
+    pub data: Vec<AccessLogEntry>,
+
+    /// Total number of entries
+    pub records_total: u64,
+}
+
+/// Response for /private/get_access_log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccessLogResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// A page of the access log
+    pub result: AccessLog,
+}
+
+impl RestClient {
+    /// Lists the access history of the account.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `account:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_access_log>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - Optional pagination parameters
+    ///
+    /// # Returns
+    /// A page of access log entries and the total number of entries
+    pub async fn get_access_log(&self, request: GetAccessLogRequest) -> RestResult<GetAccessLogResponse> {
+        self.send_signed_request(
+            "private/get_access_log",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+
+    #[test]
+    fn test_get_access_log_request_serialization() {
+        let request = GetAccessLogRequest {
+            offset: Some(10),
+            count: Some(2),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"offset": 10, "count": 2}));
+    }
+
+    #[test]
+    fn test_get_access_log_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "records_total": 1,
+                "data": [
+                    {
+                        "timestamp": 1575876682576i64,
+                        "log": "success",
+                        "ip": "255.255.255.255",
+                        "id": 243343,
+                        "country": "Nowhere",
+                        "city": "Gotham"
+                    }
+                ]
+            }
+        });
+
+        let response: GetAccessLogResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.records_total, 1);
+        assert_eq!(response.result.data[0].log, "success");
+        assert_eq!(response.result.data[0].city.as_deref(), Some("Gotham"));
+        assert!(response.result.data[0].data.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_access_log_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_access_log;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
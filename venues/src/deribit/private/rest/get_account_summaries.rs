@@ -0,0 +1,142 @@
+//! Retrieves the account summaries for all currencies via /private/get_account_summaries
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+pub use super::get_account_summary::{AccountDetails, AccountSummary};
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for /private/get_account_summaries
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetAccountSummariesRequest {
+    /// The user id for the subaccount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subaccount_id: Option<i64>,
+
+    /// Include additional fields (account details and limits)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended: Option<bool>,
+}
+
+/// Account details with one summary per currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSummaries {
+    /// Account details, only fully populated when `extended` is requested
+    #[serde(flatten)]
+    pub details: AccountDetails,
+
+    /// Per currency summaries
+    pub summaries: Vec<AccountSummary>,
+}
+
+/// Response for /private/get_account_summaries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccountSummariesResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The account summaries
+    pub result: AccountSummaries,
+}
+
+impl RestClient {
+    /// Retrieves the user account summary for all currencies.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `account:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_account_summaries>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - Optional subaccount and whether to include account details
+    ///
+    /// When the response for the account itself includes `limits` (with `extended`), the
+    /// client's rate limiter is synced to them.
+    ///
+    /// # Returns
+    /// The account details and one summary per currency
+    pub async fn get_account_summaries(&self, request: GetAccountSummariesRequest) -> RestResult<GetAccountSummariesResponse> {
+        let own_account = request.subaccount_id.is_none();
+        let response: GetAccountSummariesResponse = self
+            .send_signed_request(
+                "private/get_account_summaries",
+                &request,
+                EndpointType::NonMatchingEngine,
+            )
+            .await?;
+
+        if let Some(limits) = response.result.details.limits.as_ref().filter(|_| own_account) {
+            self.rate_limiter.sync_account_limits(limits).await;
+        }
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+    use crate::deribit::private::rest::get_account_summary::tests::account_summary_json;
+    use crate::deribit::{AccountType, Currency};
+
+    #[test]
+    fn test_get_account_summaries_request_serialization() {
+        let request = GetAccountSummariesRequest {
+            subaccount_id: Some(42),
+            extended: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"subaccount_id": 42}));
+        assert_eq!(
+            serde_json::to_value(GetAccountSummariesRequest::default()).unwrap(),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn test_get_account_summaries_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 2515,
+            "result": {
+                "id": 10,
+                "username": "user",
+                "type": "main",
+                "mmp_enabled": true,
+                "summaries": [account_summary_json()]
+            }
+        });
+
+        let response: GetAccountSummariesResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.details.id, Some(10));
+        assert_eq!(
+            response.result.details.account_type,
+            Some(AccountType::Main)
+        );
+        assert_eq!(response.result.details.mmp_enabled, Some(true));
+        assert_eq!(response.result.summaries.len(), 1);
+        assert_eq!(response.result.summaries[0].currency, Currency::BTC);
+    }
+
+    #[tokio::test]
+    async fn test_get_account_summaries_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_account_summaries;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
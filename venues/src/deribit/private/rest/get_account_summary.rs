@@ -0,0 +1,503 @@
+//! Retrieves the account summary for a currency via /private/get_account_summary
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{AccountType, Currency, EndpointType, RestResult};
+
+/// Request parameters for /private/get_account_summary
+#[derive(Debug, Clone, Serialize)]
+pub struct GetAccountSummaryRequest {
+    /// The currency symbol
+    pub currency: Currency,
+
+    /// The user id for the subaccount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subaccount_id: Option<i64>,
+
+    /// Include additional fields (account details and limits)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended: Option<bool>,
+}
+
+/// Credit bucket of a rate limit group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitBucket {
+    /// Number of requests per second
+    pub rate: u32,
+
+    /// Maximum number of requests allowed in a burst
+    pub burst: u32,
+}
+
+/// Matching engine rate limit groups
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MatchingEngineLimits {
+    /// Trading limits, keyed by "total" or by currency when limits are per currency
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trading: Option<HashMap<String, RateLimitBucket>>,
+
+    /// Spot trading limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spot: Option<RateLimitBucket>,
+
+    /// Quote limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quotes: Option<RateLimitBucket>,
+
+    /// Maximum quotes limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_quotes: Option<RateLimitBucket>,
+
+    /// Guaranteed quotes limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_quotes: Option<RateLimitBucket>,
+
+    /// Cancel all limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancel_all: Option<RateLimitBucket>,
+}
+
+/// Rate limits applied to the account
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountLimits {
+    /// When true, matching engine limits are applied per currency
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits_per_currency: Option<bool>,
+
+    /// Non-matching engine limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub non_matching_engine: Option<RateLimitBucket>,
+
+    /// Matching engine limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matching_engine: Option<MatchingEngineLimits>,
+}
+
+/// Account details returned when `extended` is requested
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountDetails {
+    /// Account id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Account name (given by user)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// User email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Account type
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<AccountType>,
+
+    /// System generated user nickname
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_name: Option<String>,
+
+    /// Time at which the account was created (milliseconds since the Unix epoch)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creation_timestamp: Option<i64>,
+
+    /// Optional identifier of the referrer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referrer_id: Option<String>,
+
+    /// Whether login to the account is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login_enabled: Option<bool>,
+
+    /// Whether security keys are enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_keys_enabled: Option<bool>,
+
+    /// Whether MMP is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mmp_enabled: Option<bool>,
+
+    /// Whether transfers between users are enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interuser_transfers_enabled: Option<bool>,
+
+    /// Self trading rejection behavior, "reject_taker" or "cancel_maker"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trading_reject_mode: Option<String>,
+
+    /// Whether self trading prevention covers subaccounts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trading_extended_to_subaccounts: Option<bool>,
+
+    /// Rate limits applied to the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<AccountLimits>,
+}
+
+/// Balances, margins and greeks of an account in one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSummary {
+    /// The selected currency
+    pub currency: Currency,
+
+    /// The account's balance
+    pub balance: f64,
+
+    /// The account's current equity
+    pub equity: f64,
+
+    /// The account's available funds
+    pub available_funds: f64,
+
+    /// The account's available to withdrawal funds
+    pub available_withdrawal_funds: f64,
+
+    /// The account's margin balance
+    pub margin_balance: f64,
+
+    /// The account's initial margin
+    pub initial_margin: f64,
+
+    /// The account's maintenance margin
+    pub maintenance_margin: f64,
+
+    /// Projected initial margin
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projected_initial_margin: Option<f64>,
+
+    /// Projected maintenance margin
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projected_maintenance_margin: Option<f64>,
+
+    /// Profit and loss
+    pub total_pl: f64,
+
+    /// Session realized profit and loss
+    pub session_rpl: f64,
+
+    /// Session unrealized profit and loss
+    pub session_upl: f64,
+
+    /// The sum of position deltas
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta_total: Option<f64>,
+
+    /// The sum of position deltas without positions that will expire during the closest expiration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projected_delta_total: Option<f64>,
+
+    /// Options summary delta
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_delta: Option<f64>,
+
+    /// Options summary gamma
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_gamma: Option<f64>,
+
+    /// Options summary vega
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_vega: Option<f64>,
+
+    /// Options summary theta
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_theta: Option<f64>,
+
+    /// Options value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_value: Option<f64>,
+
+    /// Options profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_pl: Option<f64>,
+
+    /// Options session realized profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_session_rpl: Option<f64>,
+
+    /// Options session unrealized profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_session_upl: Option<f64>,
+
+    /// Futures profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub futures_pl: Option<f64>,
+
+    /// Futures session realized profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub futures_session_rpl: Option<f64>,
+
+    /// Futures session unrealized profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub futures_session_upl: Option<f64>,
+
+    /// Delta per index name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta_total_map: Option<HashMap<String, f64>>,
+
+    /// Options gamma per index name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_gamma_map: Option<HashMap<String, f64>>,
+
+    /// Options vega per index name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_vega_map: Option<HashMap<String, f64>>,
+
+    /// Options theta per index name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_theta_map: Option<HashMap<String, f64>>,
+
+    /// The deposit fee balance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_balance: Option<f64>,
+
+    /// Balance locked by pending operations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_balance: Option<f64>,
+
+    /// The account's balance reserved in active spot orders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spot_reserve: Option<f64>,
+
+    /// The account's balance reserved in other orders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_reserve: Option<f64>,
+
+    /// Estimated liquidation ratio (cross margin only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_liquidation_ratio: Option<f64>,
+
+    /// Estimated liquidation ratio per currency pair
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_liquidation_ratio_map: Option<HashMap<String, f64>>,
+
+    /// Name of the user's currently enabled margin model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_model: Option<String>,
+
+    /// When `true` cross collateral is enabled for the user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cross_collateral_enabled: Option<bool>,
+
+    /// When `true` portfolio margining is enabled for the user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub portfolio_margining_enabled: Option<bool>,
+
+    /// Account details, only present when `extended` is requested
+    #[serde(flatten)]
+    pub details: AccountDetails,
+}
+
+/// Response for /private/get_account_summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccountSummaryResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The account summary
+    pub result: AccountSummary,
+}
+
+impl RestClient {
+    /// Retrieves the user account summary for a currency.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `account:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_account_summary>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - The currency, optional subaccount and whether to include account details
+    ///
+    /// When the response for the account itself includes `limits` (with `extended`), the
+    /// client's rate limiter is synced to them.
+    ///
+    /// # Returns
+    /// Balances, margins and greeks of the account in the currency
+    pub async fn get_account_summary(&self, request: GetAccountSummaryRequest) -> RestResult<GetAccountSummaryResponse> {
+        let own_account = request.subaccount_id.is_none();
+        let response: GetAccountSummaryResponse = self
+            .send_signed_request(
+                "private/get_account_summary",
+                &request,
+                EndpointType::NonMatchingEngine,
+            )
+            .await?;
+
+        if let Some(limits) = response.result.details.limits.as_ref().filter(|_| own_account) {
+            self.rate_limiter.sync_account_limits(limits).await;
+        }
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+
+    /// A BTC account summary as returned with `extended=true`
+    pub(crate) fn account_summary_json() -> serde_json::Value {
+        let mut summary = json!({
+            "currency": "BTC",
+            "balance": 302.60065765,
+            "equity": 302.61869214,
+            "available_funds": 301.38059622,
+            "available_withdrawal_funds": 301.35396172,
+            "margin_balance": 302.62283943,
+            "initial_margin": 1.24669592,
+            "maintenance_margin": 0.8857841,
+            "projected_initial_margin": 1.24669592,
+            "projected_maintenance_margin": 0.8857841,
+            "total_pl": -0.33084225,
+            "session_rpl": -0.03258105,
+            "session_upl": 0.05271555,
+            "delta_total": 31.602958,
+            "projected_delta_total": 32.613978,
+            "options_delta": -1.01962,
+            "options_gamma": 0.00001,
+            "options_vega": 0.0858,
+            "options_theta": 15.97071,
+            "options_value": -0.0000021,
+            "options_pl": 0.0,
+            "futures_pl": -0.32434225,
+            "delta_total_map": {"btc_usd": 31.594357699},
+            "options_gamma_map": {"btc_usd": 0.00001},
+            "fee_balance": 0.0,
+            "estimated_liquidation_ratio": 0.1009872222854525,
+            "margin_model": "segregated_sm",
+            "cross_collateral_enabled": false,
+            "portfolio_margining_enabled": false
+        });
+        let details = json!({
+            "id": 10,
+            "username": "user",
+            "email": "user@example.com",
+            "type": "main",
+            "system_name": "user",
+            "creation_timestamp": 1687352432143i64,
+            "login_enabled": false,
+            "security_keys_enabled": false,
+            "mmp_enabled": false,
+            "interuser_transfers_enabled": false,
+            "self_trading_reject_mode": "cancel_maker",
+            "self_trading_extended_to_subaccounts": false,
+            "limits": {
+                "limits_per_currency": false,
+                "non_matching_engine": {"rate": 20, "burst": 100},
+                "matching_engine": {
+                    "trading": {"total": {"rate": 5, "burst": 20}},
+                    "spot": {"rate": 5, "burst": 20},
+                    "quotes": {"rate": 500, "burst": 500},
+                    "max_quotes": {"rate": 10, "burst": 10},
+                    "guaranteed_quotes": {"rate": 2, "burst": 2},
+                    "cancel_all": {"rate": 5, "burst": 20}
+                }
+            }
+        });
+
+        summary
+            .as_object_mut()
+            .unwrap()
+            .extend(details.as_object().unwrap().clone());
+        summary
+    }
+
+    #[test]
+    fn test_get_account_summary_request_serialization() {
+        let request = GetAccountSummaryRequest {
+            currency: Currency::BTC,
+            subaccount_id: None,
+            extended: Some(true),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"currency": "BTC", "extended": true}));
+    }
+
+    #[test]
+    fn test_get_account_summary_response_deserialization() {
+        let response_json = json!({"jsonrpc": "2.0", "id": 2515, "result": account_summary_json()});
+
+        let response: GetAccountSummaryResponse = serde_json::from_value(response_json).unwrap();
+        let summary = response.result;
+        assert_eq!(summary.currency, Currency::BTC);
+        assert_eq!(summary.equity, 302.61869214);
+        assert_eq!(summary.maintenance_margin, 0.8857841);
+        assert_eq!(summary.options_vega, Some(0.0858));
+        assert_eq!(
+            summary.delta_total_map.unwrap().get("btc_usd"),
+            Some(&31.594357699)
+        );
+        assert_eq!(summary.details.account_type, Some(AccountType::Main));
+
+        let limits = summary.details.limits.unwrap();
+        assert_eq!(
+            limits.non_matching_engine,
+            Some(RateLimitBucket {
+                rate: 20,
+                burst: 100
+            })
+        );
+        let matching_engine = limits.matching_engine.unwrap();
+        assert_eq!(
+            matching_engine.trading.unwrap().get("total"),
+            Some(&RateLimitBucket { rate: 5, burst: 20 })
+        );
+        assert_eq!(
+            matching_engine.cancel_all,
+            Some(RateLimitBucket { rate: 5, burst: 20 })
+        );
+    }
+
+    #[test]
+    fn test_get_account_summary_without_extended_fields() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "currency": "ETH",
+                "balance": 1.0,
+                "equity": 1.0,
+                "available_funds": 1.0,
+                "available_withdrawal_funds": 1.0,
+                "margin_balance": 1.0,
+                "initial_margin": 0.0,
+                "maintenance_margin": 0.0,
+                "total_pl": 0.0,
+                "session_rpl": 0.0,
+                "session_upl": 0.0
+            }
+        });
+
+        let response: GetAccountSummaryResponse = serde_json::from_value(response_json).unwrap();
+        assert!(response.result.details.id.is_none());
+        assert!(response.result.details.limits.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_account_summary_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_account_summary;
+        assert_eq!(
+            EndpointType::from_path("private/get_account_summary"),
+            EndpointType::NonMatchingEngine
+        );
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -251,7 +251,7 @@ impl RestClient {
 
 #[cfg(test)]
 mod tests {
-    use rest::secrets::ExposableSecret;
+    use rest::secrets::{ExposableSecret, HmacSigner};
     use serde_json::{Value, json};
 
     use super::*;
@@ -330,7 +330,7 @@ mod tests {
     #[tokio::test]
     async fn test_method_exists() {
         let api_key = Box::new(PlainTextSecret::new("key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(PlainTextSecret::new("secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(PlainTextSecret::new("secret".to_string())) as Box<dyn HmacSigner>;
         let client = reqwest::Client::new();
         let limiter = crate::deribit::RateLimiter::new(AccountTier::Tier4);
         let rest_client = RestClient::new(
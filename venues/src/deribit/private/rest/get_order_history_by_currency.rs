@@ -0,0 +1,152 @@
+//! Retrieves the history of orders for a currency via /private/get_order_history_by_currency
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, InstrumentKind, OpenOrder, RestResult};
+
+/// Request parameters for /private/get_order_history_by_currency
+#[derive(Debug, Clone, Serialize)]
+pub struct GetOrderHistoryByCurrencyRequest {
+    /// The currency symbol
+    pub currency: Currency,
+
+    /// Instrument kind, if not provided instruments of all kinds are considered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<InstrumentKind>,
+
+    /// Number of requested items, default - 20
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// The offset for pagination, default - 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+
+    /// Include in result orders older than 2 days, default - false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_old: Option<bool>,
+
+    /// Include in result fully unfilled closed orders, default - false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_unfilled: Option<bool>,
+
+    /// Determines whether historical trade and order records should be retrieved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub historical: Option<bool>,
+}
+
+/// Response for /private/get_order_history_by_currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetOrderHistoryByCurrencyResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Historical orders, most recent first
+    pub result: Vec<OpenOrder>,
+}
+
+impl RestClient {
+    /// Retrieves the history of orders filtered by currency.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_order_history_by_currency>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - The currency and optional kind and pagination filters
+    ///
+    /// # Returns
+    /// The matching historical orders
+    pub async fn get_order_history_by_currency(&self, request: GetOrderHistoryByCurrencyRequest) -> RestResult<GetOrderHistoryByCurrencyResponse> {
+        self.send_signed_request(
+            "private/get_order_history_by_currency",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::OrderState;
+    use crate::deribit::private::rest::buy::tests::test_client;
+
+    #[test]
+    fn test_get_order_history_by_currency_request_serialization() {
+        let request = GetOrderHistoryByCurrencyRequest {
+            currency: Currency::BTC,
+            kind: Some(InstrumentKind::Future),
+            count: Some(1),
+            offset: None,
+            include_old: Some(true),
+            include_unfilled: None,
+            historical: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"currency": "BTC", "kind": "future", "count": 1, "include_old": true})
+        );
+    }
+
+    #[test]
+    fn test_get_order_history_by_currency_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 9305,
+            "result": [
+                {
+                    "time_in_force": "good_til_cancelled",
+                    "reduce_only": false,
+                    "price": 9138.5,
+                    "post_only": false,
+                    "order_type": "limit",
+                    "order_state": "filled",
+                    "order_id": "3008394551",
+                    "max_show": 40.0,
+                    "last_update_timestamp": 1590485132574i64,
+                    "label": "",
+                    "is_liquidation": false,
+                    "instrument_name": "BTC-PERPETUAL",
+                    "filled_amount": 40.0,
+                    "direction": "buy",
+                    "creation_timestamp": 1590485132574i64,
+                    "average_price": 9138.5,
+                    "api": false,
+                    "amount": 40.0
+                }
+            ]
+        });
+
+        let response: GetOrderHistoryByCurrencyResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 1);
+        assert_eq!(response.result[0].order_state, OrderState::Filled);
+        assert_eq!(response.result[0].average_price, Some(9138.5));
+    }
+
+    #[tokio::test]
+    async fn test_get_order_history_by_currency_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_order_history_by_currency;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
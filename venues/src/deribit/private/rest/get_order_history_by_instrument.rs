@@ -0,0 +1,145 @@
+//! Retrieves the history of orders for an instrument via /private/get_order_history_by_instrument
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, OpenOrder, RestResult};
+
+/// Request parameters for /private/get_order_history_by_instrument
+#[derive(Debug, Clone, Serialize)]
+pub struct GetOrderHistoryByInstrumentRequest {
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// Number of requested items, default - 20
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// The offset for pagination, default - 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+
+    /// Include in result orders older than 2 days, default - false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_old: Option<bool>,
+
+    /// Include in result fully unfilled closed orders, default - false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_unfilled: Option<bool>,
+
+    /// Determines whether historical trade and order records should be retrieved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub historical: Option<bool>,
+}
+
+/// Response for /private/get_order_history_by_instrument
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetOrderHistoryByInstrumentResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Historical orders, most recent first
+    pub result: Vec<OpenOrder>,
+}
+
+impl RestClient {
+    /// Retrieves the history of orders filtered by instrument.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_order_history_by_instrument>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - The instrument and optional pagination filters
+    ///
+    /// # Returns
+    /// The matching historical orders
+    pub async fn get_order_history_by_instrument(&self, request: GetOrderHistoryByInstrumentRequest) -> RestResult<GetOrderHistoryByInstrumentResponse> {
+        self.send_signed_request(
+            "private/get_order_history_by_instrument",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+    use crate::deribit::{CancelReason, OrderState};
+
+    #[test]
+    fn test_get_order_history_by_instrument_request_serialization() {
+        let request = GetOrderHistoryByInstrumentRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+            count: None,
+            offset: Some(20),
+            include_old: None,
+            include_unfilled: Some(true),
+            historical: Some(false),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"instrument_name": "BTC-PERPETUAL", "offset": 20, "include_unfilled": true, "historical": false})
+        );
+    }
+
+    #[test]
+    fn test_get_order_history_by_instrument_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 1032,
+            "result": [
+                {
+                    "time_in_force": "good_til_cancelled",
+                    "reduce_only": false,
+                    "price": 9100.0,
+                    "post_only": true,
+                    "order_type": "limit",
+                    "order_state": "cancelled",
+                    "cancel_reason": "user_request",
+                    "order_id": "3010835241",
+                    "last_update_timestamp": 1590485140000i64,
+                    "instrument_name": "BTC-PERPETUAL",
+                    "filled_amount": 0.0,
+                    "direction": "buy",
+                    "creation_timestamp": 1590485132574i64,
+                    "amount": 10.0
+                }
+            ]
+        });
+
+        let response: GetOrderHistoryByInstrumentResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result[0].order_state, OrderState::Cancelled);
+        assert_eq!(
+            response.result[0].cancel_reason,
+            Some(CancelReason::UserRequest)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_order_history_by_instrument_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_order_history_by_instrument;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Retrieves the current state of an order via /private/get_order_state
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, OpenOrder, RestResult};
+
+/// Request parameters for /private/get_order_state
+#[derive(Debug, Clone, Serialize)]
+pub struct GetOrderStateRequest {
+    /// The order id
+    pub order_id: String,
+}
+
+/// Response for /private/get_order_state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetOrderStateResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The order
+    pub result: OpenOrder,
+}
+
+impl RestClient {
+    /// Retrieves the current state of an order.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_order_state>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - The order id
+    ///
+    /// # Returns
+    /// The order in its current state
+    pub async fn get_order_state(&self, request: GetOrderStateRequest) -> RestResult<GetOrderStateResponse> {
+        self.send_signed_request(
+            "private/get_order_state",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+    use crate::deribit::{OrderDirection, OrderState};
+
+    #[test]
+    fn test_get_order_state_request_serialization() {
+        let request = GetOrderStateRequest {
+            order_id: "ETH-331562".to_string(),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"order_id": "ETH-331562"}));
+    }
+
+    #[test]
+    fn test_get_order_state_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 4316,
+            "result": {
+                "time_in_force": "good_til_cancelled",
+                "reduce_only": false,
+                "price": 118.94,
+                "post_only": false,
+                "order_type": "limit",
+                "order_state": "open",
+                "order_id": "ETH-331562",
+                "max_show": 37.0,
+                "last_update_timestamp": 1550219810944i64,
+                "label": "",
+                "is_liquidation": false,
+                "instrument_name": "ETH-PERPETUAL",
+                "filled_amount": 0.0,
+                "direction": "sell",
+                "creation_timestamp": 1550219749176i64,
+                "average_price": 0.0,
+                "api": false,
+                "amount": 37.0
+            }
+        });
+
+        let response: GetOrderStateResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.order_id, "ETH-331562");
+        assert_eq!(response.result.order_state, OrderState::Open);
+        assert_eq!(response.result.direction, OrderDirection::Sell);
+        assert_eq!(response.result.amount, 37.0);
+    }
+
+    #[tokio::test]
+    async fn test_get_order_state_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_order_state;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Retrieves the user's position in one instrument via /private/get_position
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, Position, RestResult};
+
+/// Request parameters for /private/get_position
+#[derive(Debug, Clone, Serialize)]
+pub struct GetPositionRequest {
+    /// Instrument name
+    pub instrument_name: String,
+}
+
+/// Response for /private/get_position
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPositionResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The position in the instrument
+    pub result: Position,
+}
+
+impl RestClient {
+    /// Retrieves the user's position in an instrument.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_position>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - The instrument name
+    ///
+    /// # Returns
+    /// The position in the instrument
+    pub async fn get_position(&self, request: GetPositionRequest) -> RestResult<GetPositionResponse> {
+        self.send_signed_request(
+            "private/get_position",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::InstrumentKind;
+    use crate::deribit::private::rest::buy::tests::test_client;
+    use crate::deribit::private::rest::get_positions::tests::position_json;
+
+    #[test]
+    fn test_get_position_request_serialization() {
+        let request = GetPositionRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"instrument_name": "BTC-PERPETUAL"}));
+    }
+
+    #[test]
+    fn test_get_position_response_deserialization() {
+        let response_json = json!({"jsonrpc": "2.0", "id": 404, "result": position_json()});
+
+        let response: GetPositionResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.kind, InstrumentKind::Future);
+        assert_eq!(response.result.mark_price, 7476.65);
+        assert_eq!(response.result.size_currency, Some(-0.006687487));
+    }
+
+    #[tokio::test]
+    async fn test_get_position_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_position;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
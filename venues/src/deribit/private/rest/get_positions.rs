@@ -0,0 +1,138 @@
+//! Retrieves the user's open positions via /private/get_positions
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, InstrumentKind, Position, RestResult};
+
+/// Request parameters for /private/get_positions
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetPositionsRequest {
+    /// The currency symbol, all currencies when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// Instrument kind, if not provided instruments of all kinds are considered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<InstrumentKind>,
+
+    /// The user id for the subaccount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subaccount_id: Option<i64>,
+}
+
+/// Response for /private/get_positions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPositionsResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Open positions
+    pub result: Vec<Position>,
+}
+
+impl RestClient {
+    /// Retrieves the user's open positions.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_positions>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - Optional currency, kind and subaccount filters
+    ///
+    /// # Returns
+    /// The open positions
+    pub async fn get_positions(&self, request: GetPositionsRequest) -> RestResult<GetPositionsResponse> {
+        self.send_signed_request(
+            "private/get_positions",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::PositionDirection;
+    use crate::deribit::private::rest::buy::tests::test_client;
+
+    /// A BTC-PERPETUAL short position
+    pub(crate) fn position_json() -> serde_json::Value {
+        json!({
+            "average_price": 7440.18,
+            "delta": 0.006687487,
+            "direction": "sell",
+            "estimated_liquidation_price": 1.74,
+            "floating_profit_loss": 0.000030554,
+            "index_price": 7466.79,
+            "initial_margin": 0.000197283,
+            "instrument_name": "BTC-PERPETUAL",
+            "interest_value": 1.7362511643080387,
+            "kind": "future",
+            "leverage": 34,
+            "maintenance_margin": 0.000143783,
+            "mark_price": 7476.65,
+            "open_orders_margin": 0.000197288,
+            "realized_funding": -1e-8,
+            "realized_profit_loss": -9e-9,
+            "settlement_price": 7476.65,
+            "size": -50,
+            "size_currency": -0.006687487,
+            "total_profit_loss": 0.000032781
+        })
+    }
+
+    #[test]
+    fn test_get_positions_request_serialization() {
+        let request = GetPositionsRequest {
+            currency: Some(Currency::BTC),
+            kind: Some(InstrumentKind::Future),
+            subaccount_id: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"currency": "BTC", "kind": "future"}));
+        assert_eq!(
+            serde_json::to_value(GetPositionsRequest::default()).unwrap(),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn test_get_positions_response_deserialization() {
+        let response_json = json!({"jsonrpc": "2.0", "id": 2236, "result": [position_json()]});
+
+        let response: GetPositionsResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 1);
+        let position = &response.result[0];
+        assert_eq!(position.instrument_name, "BTC-PERPETUAL");
+        assert_eq!(position.direction, PositionDirection::Sell);
+        assert_eq!(position.size, -50.0);
+        assert_eq!(position.leverage, Some(34));
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_positions;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
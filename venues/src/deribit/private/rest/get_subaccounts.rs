@@ -0,0 +1,229 @@
+//! Retrieves the subaccounts of the main account via /private/get_subaccounts
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{AccountType, EndpointType, RestResult};
+
+/// Request parameters for /private/get_subaccounts
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetSubaccountsRequest {
+    /// Include portfolio information per currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_portfolio: Option<bool>,
+}
+
+/// Balances of a subaccount in one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubaccountPortfolio {
+    /// The account's available funds
+    pub available_funds: f64,
+
+    /// The account's available to withdrawal funds
+    pub available_withdrawal_funds: f64,
+
+    /// The account's balance
+    pub balance: f64,
+
+    /// The selected currency
+    pub currency: String,
+
+    /// The account's current equity
+    pub equity: f64,
+
+    /// The account's initial margin
+    pub initial_margin: f64,
+
+    /// The account's maintenance margin
+    pub maintenance_margin: f64,
+
+    /// The account's margin balance
+    pub margin_balance: f64,
+
+    /// The account's balance reserved in active spot orders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spot_reserve: Option<f64>,
+
+    /// The account's balance reserved in other orders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_reserve: Option<f64>,
+}
+
+/// A subaccount (or the main account) as returned by /private/get_subaccounts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subaccount {
+    /// User email
+    pub email: String,
+
+    /// Account id
+    pub id: i64,
+
+    /// `true` when the password for the subaccount has been configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_password: Option<bool>,
+
+    /// Informs whether login to the subaccount is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login_enabled: Option<bool>,
+
+    /// Margin model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_model: Option<String>,
+
+    /// New email address that has not yet been confirmed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_confirmed_email: Option<String>,
+
+    /// Balances per currency, only present when `with_portfolio` is requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub portfolio: Option<HashMap<String, SubaccountPortfolio>>,
+
+    /// When `true` notifications are sent to the subaccount email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receive_notifications: Option<bool>,
+
+    /// Names of assignments with security keys assigned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_keys_assignments: Option<Vec<String>>,
+
+    /// Whether security keys authentication is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_keys_enabled: Option<bool>,
+
+    /// System generated user nickname
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_name: Option<String>,
+
+    /// Account type
+    #[serde(rename = "type")]
+    pub account_type: AccountType,
+
+    /// Account name (given by user)
+    pub username: String,
+}
+
+/// Response for /private/get_subaccounts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSubaccountsResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The main account followed by its subaccounts
+    pub result: Vec<Subaccount>,
+}
+
+impl RestClient {
+    /// Retrieves information about the main account and its subaccounts.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `account:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_subaccounts>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - Whether to include per currency balances
+    ///
+    /// # Returns
+    /// The main account and its subaccounts
+    pub async fn get_subaccounts(&self, request: GetSubaccountsRequest) -> RestResult<GetSubaccountsResponse> {
+        self.send_signed_request(
+            "private/get_subaccounts",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+
+    #[test]
+    fn test_get_subaccounts_request_serialization() {
+        let request = GetSubaccountsRequest {
+            with_portfolio: Some(true),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"with_portfolio": true}));
+    }
+
+    #[test]
+    fn test_get_subaccounts_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 4947,
+            "result": [
+                {
+                    "email": "user_AAA@email.com",
+                    "id": 2,
+                    "is_password": true,
+                    "login_enabled": true,
+                    "portfolio": {
+                        "eth": {
+                            "available_funds": 5.0,
+                            "available_withdrawal_funds": 5.0,
+                            "balance": 5.0,
+                            "currency": "eth",
+                            "equity": 5.0,
+                            "initial_margin": 0.0,
+                            "maintenance_margin": 0.0,
+                            "margin_balance": 5.0
+                        }
+                    },
+                    "receive_notifications": false,
+                    "system_name": "user_1",
+                    "security_keys_enabled": false,
+                    "type": "main",
+                    "username": "user_1"
+                },
+                {
+                    "email": "user_AAA@gmail.com",
+                    "id": 7,
+                    "is_password": true,
+                    "login_enabled": false,
+                    "receive_notifications": false,
+                    "system_name": "user_1_1",
+                    "security_keys_enabled": false,
+                    "type": "subaccount",
+                    "username": "user_1_1"
+                }
+            ]
+        });
+
+        let response: GetSubaccountsResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 2);
+        assert_eq!(response.result[0].account_type, AccountType::Main);
+        assert_eq!(
+            response.result[0].portfolio.as_ref().unwrap()["eth"].balance,
+            5.0
+        );
+        assert_eq!(response.result[1].account_type, AccountType::Subaccount);
+        assert!(response.result[1].portfolio.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_subaccounts_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_subaccounts;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Retrieves positions and open orders of all subaccounts via /private/get_subaccounts_details
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, OpenOrder, Position, RestResult};
+
+/// Request parameters for /private/get_subaccounts_details
+#[derive(Debug, Clone, Serialize)]
+pub struct GetSubaccountsDetailsRequest {
+    /// The currency symbol
+    pub currency: Currency,
+
+    /// Include open orders of each subaccount, default - false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_open_orders: Option<bool>,
+}
+
+/// Positions and open orders of one subaccount
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubaccountDetails {
+    /// Subaccount user id
+    pub uid: i64,
+
+    /// Open positions of the subaccount
+    pub positions: Vec<Position>,
+
+    /// Open orders, only present when `with_open_orders` is requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_orders: Option<Vec<OpenOrder>>,
+}
+
+/// Response for /private/get_subaccounts_details
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSubaccountsDetailsResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Details of each subaccount
+    pub result: Vec<SubaccountDetails>,
+}
+
+impl RestClient {
+    /// Retrieves the positions and optionally the open orders of all subaccounts.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `account:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_subaccounts_details>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - The currency and whether to include open orders
+    ///
+    /// # Returns
+    /// The positions and open orders of each subaccount
+    pub async fn get_subaccounts_details(&self, request: GetSubaccountsDetailsRequest) -> RestResult<GetSubaccountsDetailsResponse> {
+        self.send_signed_request(
+            "private/get_subaccounts_details",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+    use crate::deribit::private::rest::get_positions::tests::position_json;
+
+    #[test]
+    fn test_get_subaccounts_details_request_serialization() {
+        let request = GetSubaccountsDetailsRequest {
+            currency: Currency::BTC,
+            with_open_orders: Some(true),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"currency": "BTC", "with_open_orders": true})
+        );
+    }
+
+    #[test]
+    fn test_get_subaccounts_details_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [
+                {"uid": 3, "positions": [position_json()]},
+                {"uid": 10, "positions": [], "open_orders": []}
+            ]
+        });
+
+        let response: GetSubaccountsDetailsResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result[0].uid, 3);
+        assert_eq!(
+            response.result[0].positions[0].instrument_name,
+            "BTC-PERPETUAL"
+        );
+        assert!(response.result[0].open_orders.is_none());
+        assert_eq!(
+            response.result[1].open_orders.as_ref().map(Vec::len),
+            Some(0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_subaccounts_details_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_subaccounts_details;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Retrieves the transaction log via /private/get_transaction_log
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, RestResult};
+
+/// Request parameters for /private/get_transaction_log
+#[derive(Debug, Clone, Serialize)]
+pub struct GetTransactionLogRequest {
+    /// The currency symbol
+    pub currency: Currency,
+
+    /// The earliest timestamp to return results from (milliseconds since the UNIX epoch)
+    pub start_timestamp: i64,
+
+    /// The most recent timestamp to return results from (milliseconds since the UNIX epoch)
+    pub end_timestamp: i64,
+
+    /// Filter by transaction type, e.g. "trade", "maker", "taker", "settlement", "deposit"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Number of requested items, default - 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// The user id for the subaccount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subaccount_id: Option<i64>,
+
+    /// Continuation token for pagination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<i64>,
+}
+
+/// One entry of the transaction log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionLogEntry {
+    /// Unique identifier
+    pub id: i64,
+
+    /// Currency
+    pub currency: String,
+
+    /// Transaction type, e.g. "trade", "deposit", "settlement", "delivery"
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+
+    /// Sequential identifier of the user transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_seq: Option<i64>,
+
+    /// Unique user identifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// System name or user defined subaccount alias
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Unique instrument identifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument_name: Option<String>,
+
+    /// Trade id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_id: Option<String>,
+
+    /// Order id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+
+    /// One of: short or long in case of settlements, close sell or close buy in case of deliveries,
+    /// open sell, open buy, close sell, close buy in case of trades
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+
+    /// Amount of the transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+
+    /// Settlement, delivery or trade price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+
+    /// Currency of the price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_currency: Option<String>,
+
+    /// Mark price at the time of the transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mark_price: Option<f64>,
+
+    /// Updated position size after the transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+
+    /// Change in cash balance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change: Option<f64>,
+
+    /// For futures and perpetual contracts: realized session PnL since the last settlement
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cashflow: Option<f64>,
+
+    /// Cash balance after the transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<f64>,
+
+    /// Updated equity value after the transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equity: Option<f64>,
+
+    /// Commission paid so far
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commission: Option<f64>,
+
+    /// The deposit fee balance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_balance: Option<f64>,
+
+    /// Actual funding rate of trades and settlements on perpetual instruments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interest_pl: Option<f64>,
+
+    /// Total session funding rate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_interest_pl: Option<f64>,
+
+    /// Session realized profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_rpl: Option<f64>,
+
+    /// Session unrealized profit and loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_upl: Option<f64>,
+
+    /// Whether profit is reported as cashflow
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profit_as_cashflow: Option<bool>,
+
+    /// Trade role of the user: maker or taker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_role: Option<String>,
+
+    /// Additional information about the transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<serde_json::Value>,
+}
+
+/// A page of the transaction log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionLog {
+    /// Continuation token for the next page, `None` when there are no more entries
+    #[serde(default)]
+    pub continuation: Option<i64>,
+
+    /// Log entries
+    pub logs: Vec<TransactionLogEntry>,
+}
+
+/// Response for /private/get_transaction_log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTransactionLogResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// A page of the transaction log
+    pub result: TransactionLog,
+}
+
+impl RestClient {
+    /// Retrieves the transaction log of the account.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `account:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_transaction_log>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Arguments
+    /// * `request` - The currency, time range and optional filters
+    ///
+    /// # Returns
+    /// A page of log entries and the continuation token for the next one
+    pub async fn get_transaction_log(&self, request: GetTransactionLogRequest) -> RestResult<GetTransactionLogResponse> {
+        self.send_signed_request(
+            "private/get_transaction_log",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+
+    #[test]
+    fn test_get_transaction_log_request_serialization() {
+        let request = GetTransactionLogRequest {
+            currency: Currency::BTC,
+            start_timestamp: 1613657734000,
+            end_timestamp: 1613660407000,
+            query: Some("trade".to_string()),
+            count: Some(5),
+            subaccount_id: None,
+            continuation: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({
+                "currency": "BTC",
+                "start_timestamp": 1613657734000i64,
+                "end_timestamp": 1613660407000i64,
+                "query": "trade",
+                "count": 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_transaction_log_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 4,
+            "result": {
+                "logs": [
+                    {
+                        "username": "TestUser",
+                        "user_seq": 6009,
+                        "user_id": 7,
+                        "type": "transfer",
+                        "trade_id": null,
+                        "timestamp": 1613659830333i64,
+                        "side": "-",
+                        "price": null,
+                        "position": null,
+                        "order_id": null,
+                        "interest_pl": null,
+                        "instrument_name": null,
+                        "info": {"transfer_type": "subaccount", "other_user_id": 27, "other_user": "Subaccount"},
+                        "id": 61312,
+                        "equity": 3000.9275869,
+                        "currency": "BTC",
+                        "commission": 0,
+                        "change": -2.5,
+                        "cashflow": -2.5,
+                        "balance": 3001.22270418
+                    }
+                ],
+                "continuation": 61282
+            }
+        });
+
+        let response: GetTransactionLogResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.continuation, Some(61282));
+        let entry = &response.result.logs[0];
+        assert_eq!(entry.transaction_type, "transfer");
+        assert_eq!(entry.change, Some(-2.5));
+        assert!(entry.trade_id.is_none());
+        assert_eq!(entry.info.as_ref().unwrap()["other_user_id"], 27);
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_log_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_transaction_log;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
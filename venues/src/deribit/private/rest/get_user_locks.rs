@@ -0,0 +1,111 @@
+//! Retrieves the locks on the user's account via /private/get_user_locks
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for /private/get_user_locks
+#[derive(Debug, Clone, Serialize)]
+pub struct GetUserLocksRequest {
+    // This endpoint takes no parameters
+}
+
+/// Lock state of the account in one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserLock {
+    /// Currency on which the lock applies
+    pub currency: String,
+
+    /// `true` when the account is locked in the currency
+    pub enabled: bool,
+
+    /// Optional cause of the lock
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Response for /private/get_user_locks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetUserLocksResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Lock state per currency
+    pub result: Vec<UserLock>,
+}
+
+impl RestClient {
+    /// Retrieves information about locks on the user's account.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `account:read`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-get_user_locks>
+    ///
+    /// Rate limit: Non-matching engine rate limits apply (500 credits)
+    ///
+    /// # Returns
+    /// The lock state of the account per currency
+    pub async fn get_user_locks(&self) -> RestResult<GetUserLocksResponse> {
+        let request = GetUserLocksRequest {};
+        self.send_signed_request(
+            "private/get_user_locks",
+            &request,
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+
+    #[test]
+    fn test_get_user_locks_request_serialization() {
+        let json_value = serde_json::to_value(GetUserLocksRequest {}).unwrap();
+        assert_eq!(json_value, json!({}));
+    }
+
+    #[test]
+    fn test_get_user_locks_response_deserialization() {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": 74,
+            "result": [
+                {"message": "Withdrawals are locked", "enabled": true, "currency": "BTC"},
+                {"enabled": false, "currency": "ETH"}
+            ]
+        });
+
+        let response: GetUserLocksResponse = serde_json::from_value(response_json).unwrap();
+        assert!(response.result[0].enabled);
+        assert_eq!(
+            response.result[0].message.as_deref(),
+            Some("Withdrawals are locked")
+        );
+        assert!(!response.result[1].enabled);
+        assert!(response.result[1].message.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_user_locks_uses_credit_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::get_user_locks;
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::NonMatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,291 @@
+//! Places or replaces a set of market maker quotes via /private/mass_quote
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{ApiError, EndpointType, ErrorResponse, OpenOrder, QuoteSide, RestResult, Trade};
+
+/// One side of a quote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteLevel {
+    /// Price of the quote
+    pub price: f64,
+
+    /// Amount of the quote
+    pub amount: f64,
+
+    /// If true, the quote is considered post-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+
+    /// If true and `post_only` is set, the quote is rejected instead of repriced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_post_only: Option<bool>,
+}
+
+impl QuoteLevel {
+    /// A quote level with the default post-only behavior
+    pub fn new(price: f64, amount: f64) -> Self {
+        Self {
+            price,
+            amount,
+            post_only: None,
+            reject_post_only: None,
+        }
+    }
+}
+
+/// Bid and/or ask for one instrument
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MassQuoteEntry {
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// Identifier of the quote set, used by `cancel_quotes` with `quote_set_id`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_set_id: Option<String>,
+
+    /// Bid side of the quote
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid: Option<QuoteLevel>,
+
+    /// Ask side of the quote
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ask: Option<QuoteLevel>,
+}
+
+/// Request parameters for /private/mass_quote
+#[derive(Debug, Clone, Serialize)]
+pub struct MassQuoteRequest {
+    /// Identifier of this mass quote, echoed in the resulting orders
+    pub quote_id: String,
+
+    /// Name of the MMP group the quotes belong to
+    pub mmp_group: String,
+
+    /// Quotes to place or replace
+    pub quotes: Vec<MassQuoteEntry>,
+
+    /// If true, failures of individual quotes are returned in `errors` instead of failing the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detailed: Option<bool>,
+
+    /// If false, the server responds before the quotes are processed by the matching engine
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_for_response: Option<bool>,
+
+    /// Timestamp (milliseconds since the UNIX epoch) after which the server rejects the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<i64>,
+}
+
+/// Failure of one side of one quote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MassQuoteError {
+    /// Instrument name of the rejected quote
+    pub instrument_name: String,
+
+    /// Side of the rejected quote
+    pub side: QuoteSide,
+
+    /// Error returned by the matching engine
+    pub error: ErrorResponse,
+}
+
+impl MassQuoteError {
+    /// The error mapped to the typed Deribit API error
+    pub fn api_error(&self) -> ApiError {
+        ApiError::from(self.error.clone())
+    }
+}
+
+/// Orders, trades and per-quote failures of a mass quote
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MassQuoteResult {
+    /// Orders created or replaced by the quotes
+    #[serde(default)]
+    pub orders: Vec<OpenOrder>,
+
+    /// Trades executed immediately by the quotes
+    #[serde(default)]
+    pub trades: Vec<Trade>,
+
+    /// Quotes that were rejected, only reported when `detailed` is requested
+    #[serde(default)]
+    pub errors: Vec<MassQuoteError>,
+}
+
+/// Response for /private/mass_quote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MassQuoteResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Orders, trades and per-quote failures
+    pub result: MassQuoteResult,
+}
+
+impl RestClient {
+    /// Places or replaces quotes on several instruments in a single request.
+    ///
+    /// Quotes must belong to an MMP group configured with `set_mmp_config`, and can be
+    /// pulled with `cancel_quotes`.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read_write`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-mass_quote>
+    ///
+    /// Rate limit: Matching engine rate limits apply
+    ///
+    /// # Arguments
+    /// * `request` - The quote id, MMP group and the quotes
+    ///
+    /// # Returns
+    /// The resulting orders and trades, and the quotes that were rejected
+    pub async fn mass_quote(&self, request: MassQuoteRequest) -> RestResult<MassQuoteResponse> {
+        self.send_signed_request("private/mass_quote", &request, EndpointType::MatchingEngine)
+            .await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::test_client;
+    use crate::deribit::{OrderDirection, OrderState};
+
+    pub(crate) fn mass_quote_request() -> MassQuoteRequest {
+        MassQuoteRequest {
+            quote_id: "quote-1".to_string(),
+            mmp_group: "btc_options".to_string(),
+            quotes: vec![MassQuoteEntry {
+                instrument_name: "BTC-27JUN25-100000-C".to_string(),
+                quote_set_id: Some("front".to_string()),
+                bid: Some(QuoteLevel::new(0.0405, 1.0)),
+                ask: Some(QuoteLevel {
+                    post_only: Some(true),
+                    ..QuoteLevel::new(0.042, 1.0)
+                }),
+            }],
+            detailed: Some(true),
+            wait_for_response: None,
+            valid_until: None,
+        }
+    }
+
+    pub(crate) fn mass_quote_result_json() -> serde_json::Value {
+        json!({
+            "orders": [
+                {
+                    "quote": true,
+                    "quote_id": "quote-1",
+                    "quote_set_id": "front",
+                    "mmp_group": "btc_options",
+                    "mmp": true,
+                    "order_id": "BTC-7729",
+                    "order_state": "open",
+                    "order_type": "limit",
+                    "instrument_name": "BTC-27JUN25-100000-C",
+                    "direction": "buy",
+                    "price": 0.0405,
+                    "amount": 1.0,
+                    "filled_amount": 0.0,
+                    "creation_timestamp": 1717000000000i64,
+                    "last_update_timestamp": 1717000000000i64
+                }
+            ],
+            "trades": [],
+            "errors": [
+                {
+                    "instrument_name": "BTC-27JUN25-100000-C",
+                    "side": "ask",
+                    "error": {"code": -32602, "message": "Invalid params"}
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_mass_quote_request_serialization() {
+        let json_value = serde_json::to_value(mass_quote_request()).unwrap();
+
+        assert_eq!(
+            json_value,
+            json!({
+                "quote_id": "quote-1",
+                "mmp_group": "btc_options",
+                "quotes": [{
+                    "instrument_name": "BTC-27JUN25-100000-C",
+                    "quote_set_id": "front",
+                    "bid": {"price": 0.0405, "amount": 1.0},
+                    "ask": {"price": 0.042, "amount": 1.0, "post_only": true}
+                }],
+                "detailed": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_mass_quote_one_sided_entry_serialization() {
+        let entry = MassQuoteEntry {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+            quote_set_id: None,
+            bid: None,
+            ask: Some(QuoteLevel::new(65000.0, 100.0)),
+        };
+
+        let json_value = serde_json::to_value(&entry).unwrap();
+        assert!(json_value.get("bid").is_none());
+        assert_eq!(json_value["ask"]["price"], 65000.0);
+    }
+
+    #[test]
+    fn test_mass_quote_response_deserialization() {
+        let response_json = json!({"jsonrpc": "2.0", "id": 7, "result": mass_quote_result_json()});
+
+        let response: MassQuoteResponse = serde_json::from_value(response_json).unwrap();
+        let order = &response.result.orders[0];
+        assert_eq!(order.quote_id.as_deref(), Some("quote-1"));
+        assert_eq!(order.mmp_group.as_deref(), Some("btc_options"));
+        assert_eq!(order.order_state, OrderState::Open);
+        assert_eq!(order.direction, OrderDirection::Buy);
+        assert!(response.result.trades.is_empty());
+
+        let error = &response.result.errors[0];
+        assert_eq!(error.side, QuoteSide::Ask);
+        assert_eq!(error.error.code, -32602);
+        assert!(matches!(error.api_error(), ApiError::InvalidParams));
+    }
+
+    #[test]
+    fn test_mass_quote_response_without_errors() {
+        let response_json = json!({"jsonrpc": "2.0", "id": 7, "result": {"orders": [], "trades": []}});
+
+        let response: MassQuoteResponse = serde_json::from_value(response_json).unwrap();
+        assert!(response.result.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mass_quote_uses_matching_engine_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::mass_quote;
+        assert_eq!(
+            EndpointType::from_path("private/mass_quote"),
+            EndpointType::MatchingEngine
+        );
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::MatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 pub mod add_block_rfq_quote;
 pub mod add_to_address_book;
 pub mod approve_block_trade;
+pub mod buy;
 pub mod cancel_all;
 pub mod cancel_all_block_rfq_quotes;
 pub mod cancel_all_by_currency;
@@ -14,13 +15,19 @@ pub mod cancel_order;
 pub mod cancel_quotes;
 pub mod cancel_withdrawal;
 pub mod client;
+pub mod close_position;
 pub mod create_block_rfq;
 pub mod create_combo;
 pub mod create_deposit_address;
 pub mod disable_cancel_on_disconnect;
+pub mod edit;
 pub mod edit_block_rfq_quote;
+pub mod edit_by_label;
 pub mod enable_cancel_on_disconnect;
 pub mod execute_block_trade;
+pub mod get_access_log;
+pub mod get_account_summaries;
+pub mod get_account_summary;
 pub mod get_address_book;
 pub mod get_block_rfq_makers;
 pub mod get_block_rfq_quotes;
@@ -31,12 +38,21 @@ pub mod get_deposits;
 pub mod get_mmp_status;
 pub mod get_open_orders_by_currency;
 pub mod get_open_orders_by_instrument;
+pub mod get_order_history_by_currency;
+pub mod get_order_history_by_instrument;
 pub mod get_order_margin_by_ids;
+pub mod get_order_state;
 pub mod get_order_state_by_label;
 pub mod get_pending_block_trades;
+pub mod get_position;
+pub mod get_positions;
 pub mod get_settlement_history_by_instrument;
+pub mod get_subaccounts;
+pub mod get_subaccounts_details;
+pub mod get_transaction_log;
 pub mod get_transfers;
 pub mod get_trigger_order_history;
+pub mod get_user_locks;
 pub mod get_user_trades_by_currency;
 pub mod get_user_trades_by_currency_and_time;
 pub mod get_user_trades_by_instrument;
@@ -44,9 +60,11 @@ pub mod get_user_trades_by_instrument_and_time;
 pub mod get_user_trades_by_order;
 pub mod get_withdrawals;
 pub mod invalidate_block_trade_signature;
+pub mod mass_quote;
 pub mod move_positions;
 pub mod remove_from_address_book;
 pub mod reset_mmp;
+pub mod sell;
 pub mod send_rfq;
 pub mod set_clearance_originator;
 pub mod set_mmp_config;
@@ -63,6 +81,7 @@ pub use add_block_rfq_quote::{
 };
 pub use add_to_address_book::{AddToAddressBookRequest, AddToAddressBookResponse, AddressBookEntry};
 pub use approve_block_trade::{ApproveBlockTradeRequest, ApproveBlockTradeResponse, Role};
+pub use buy::{BuyRequest, BuyResponse, OrderRequest, OrderResponse, OrderResult, OtocoConfig};
 pub use cancel_all::{CancelAllRequest, CancelAllResponse};
 pub use cancel_all_block_rfq_quotes::{CancelAllBlockRfqQuotesRequest, CancelAllBlockRfqQuotesResponse};
 pub use cancel_all_by_currency::{CancelAllByCurrencyRequest, CancelAllByCurrencyResponse};
@@ -76,6 +95,7 @@ pub use cancel_order::{CancelOrderRequest, CancelOrderResponse, CancelledOrder};
 pub use cancel_quotes::{CancelQuotesRequest, CancelQuotesResponse, CancelType};
 pub use cancel_withdrawal::{CancelWithdrawalRequest, CancelWithdrawalResponse};
 pub use client::RestClient;
+pub use close_position::{ClosePositionRequest, ClosePositionResponse};
 pub use create_block_rfq::{
     CreateBlockRfqLeg, CreateBlockRfqRequest, CreateBlockRfqResponse, CreateBlockRfqResult, Quote, ResponseHedge as CreateBlockRfqResponseHedge,
     ResponseLeg as CreateBlockRfqResponseLeg,
@@ -83,12 +103,17 @@ pub use create_block_rfq::{
 pub use create_combo::{CreateComboLeg, CreateComboRequest, CreateComboResponse, CreateComboResult, CreateComboTrade};
 pub use create_deposit_address::{CreateDepositAddressRequest, CreateDepositAddressResponse};
 pub use disable_cancel_on_disconnect::{DisableCancelOnDisconnectRequest, DisableCancelOnDisconnectResponse};
+pub use edit::{EditRequest, EditResponse};
+pub use edit_by_label::{EditByLabelRequest, EditByLabelResponse};
 pub use edit_block_rfq_quote::{EditBlockRfqQuoteRequest, EditBlockRfqQuoteResponse};
 pub use enable_cancel_on_disconnect::{CancelOnDisconnectScope, EnableCancelOnDisconnectRequest, EnableCancelOnDisconnectResponse};
 pub use execute_block_trade::{
     Direction as ExecuteBlockTradeDirection, ExecuteBlockTradeRequest, ExecuteBlockTradeResponse, ExecuteBlockTradeResult, ExecutedTrade,
     Role as ExecuteBlockTradeRole, Trade as ExecuteBlockTrade,
 };
+pub use get_access_log::{AccessLog, AccessLogEntry, GetAccessLogRequest, GetAccessLogResponse};
+pub use get_account_summaries::{AccountSummaries, GetAccountSummariesRequest, GetAccountSummariesResponse};
+pub use get_account_summary::{AccountDetails, AccountLimits, AccountSummary, GetAccountSummaryRequest, GetAccountSummaryResponse, MatchingEngineLimits, RateLimitBucket};
 pub use get_address_book::{GetAddressBookRequest, GetAddressBookResponse};
 pub use get_block_rfq_makers::{GetBlockRfqMakersRequest, GetBlockRfqMakersResponse};
 pub use get_block_rfq_user_info::{GetBlockRfqUserInfoRequest, GetBlockRfqUserInfoResponse, GetBlockRfqUserInfoResult, ParentIdentity, UserInfo};
@@ -98,14 +123,23 @@ pub use get_deposits::{DepositData, GetDepositsRequest, GetDepositsResponse, Get
 pub use get_mmp_status::{GetMmpStatusRequest, GetMmpStatusResponse, MmpStatus};
 pub use get_open_orders_by_currency::{GetOpenOrdersByCurrencyRequest, GetOpenOrdersByCurrencyResponse, OpenOrder, OpenOrderType};
 pub use get_open_orders_by_instrument::{GetOpenOrdersByInstrumentRequest, GetOpenOrdersByInstrumentResponse};
+pub use get_order_history_by_currency::{GetOrderHistoryByCurrencyRequest, GetOrderHistoryByCurrencyResponse};
+pub use get_order_history_by_instrument::{GetOrderHistoryByInstrumentRequest, GetOrderHistoryByInstrumentResponse};
 pub use get_order_margin_by_ids::{GetOrderMarginByIdsRequest, GetOrderMarginByIdsResponse, OrderMarginInfo};
+pub use get_order_state::{GetOrderStateRequest, GetOrderStateResponse};
 pub use get_order_state_by_label::{GetOrderStateByLabelRequest, GetOrderStateByLabelResponse};
 pub use get_pending_block_trades::{
     GetPendingBlockTradesRequest, GetPendingBlockTradesResponse, PendingBlockTrade, PendingBlockTradeRole, PendingBlockTradeState, PendingBlockTradeTrade,
 };
+pub use get_position::{GetPositionRequest, GetPositionResponse};
+pub use get_positions::{GetPositionsRequest, GetPositionsResponse};
 pub use get_settlement_history_by_instrument::{GetSettlementHistoryByInstrumentRequest, GetSettlementHistoryByInstrumentResponse, SettlementEvent};
+pub use get_subaccounts::{GetSubaccountsRequest, GetSubaccountsResponse, Subaccount, SubaccountPortfolio};
+pub use get_subaccounts_details::{GetSubaccountsDetailsRequest, GetSubaccountsDetailsResponse, SubaccountDetails};
+pub use get_transaction_log::{GetTransactionLogRequest, GetTransactionLogResponse, TransactionLog, TransactionLogEntry};
 pub use get_transfers::{GetTransfersRequest, GetTransfersResponse, GetTransfersResult};
 pub use get_trigger_order_history::{GetTriggerOrderHistoryRequest, GetTriggerOrderHistoryResponse, GetTriggerOrderHistoryResult, TriggerOrderEntry};
+pub use get_user_locks::{GetUserLocksRequest, GetUserLocksResponse, UserLock};
 pub use get_user_trades_by_currency::{GetUserTradesByCurrencyRequest, GetUserTradesByCurrencyResponse, GetUserTradesByCurrencyResult, Trade};
 pub use get_user_trades_by_currency_and_time::{
     GetUserTradesByCurrencyAndTimeRequest, GetUserTradesByCurrencyAndTimeResponse, GetUserTradesByCurrencyAndTimeResult,
@@ -117,10 +151,12 @@ pub use get_user_trades_by_instrument_and_time::{
 pub use get_user_trades_by_order::{GetUserTradesByOrderRequest, GetUserTradesByOrderResponse, GetUserTradesByOrderResult};
 pub use get_withdrawals::{GetWithdrawalsRequest, GetWithdrawalsResponse, GetWithdrawalsResult};
 pub use invalidate_block_trade_signature::{InvalidateBlockTradeSignatureRequest, InvalidateBlockTradeSignatureResponse};
+pub use mass_quote::{MassQuoteEntry, MassQuoteError, MassQuoteRequest, MassQuoteResponse, MassQuoteResult, QuoteLevel};
 pub use move_positions::{MovePositionTrade, MovePositionTradeResult, MovePositionsRequest, MovePositionsResponse, MovePositionsResult};
 pub use remove_from_address_book::{RemoveFromAddressBookRequest, RemoveFromAddressBookResponse};
 pub use reset_mmp::{IndexName, ResetMmpRequest, ResetMmpResponse};
 pub use send_rfq::{SendRfqRequest, SendRfqResponse, Side};
+pub use sell::{SellRequest, SellResponse};
 pub use set_clearance_originator::{DepositId, Originator, SetClearanceOriginatorRequest, SetClearanceOriginatorResponse, SetClearanceOriginatorResult};
 pub use set_mmp_config::{MmpConfig, SetMmpConfigRequest, SetMmpConfigResponse};
 pub use simulate_block_trade::{Direction, SimulateBlockTradeRequest, SimulateBlockTradeResponse, Trade as BlockTrade};
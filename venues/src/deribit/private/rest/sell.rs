@@ -0,0 +1,94 @@
+//! Places a sell order via /private/sell
+//!
+//! Takes the same parameters as /private/buy; see [`super::buy::OrderRequest`].
+
+pub use super::buy::{OrderRequest, OrderResponse};
+use super::client::RestClient;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for /private/sell
+pub type SellRequest = OrderRequest;
+
+/// Response for /private/sell
+pub type SellResponse = OrderResponse;
+
+impl RestClient {
+    /// Places a sell order for an instrument.
+    ///
+    /// This is a private method; it can only be used after authentication.
+    /// Scope: `trade:read_write`
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-sell>
+    ///
+    /// Rate limit: Matching engine rate limits apply
+    ///
+    /// # Arguments
+    /// * `request` - The order parameters
+    ///
+    /// # Returns
+    /// The order and the trades it produced immediately
+    pub async fn sell(&self, request: SellRequest) -> RestResult<SellResponse> {
+        self.send_signed_request("private/sell", &request, EndpointType::MatchingEngine)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::private::rest::buy::tests::{order_response_json, test_client};
+    use crate::deribit::{OrderEntryType, TimeInForce, TriggerType};
+
+    #[test]
+    fn test_sell_request_serialization() {
+        let request = SellRequest {
+            order_type: Some(OrderEntryType::TrailingStop),
+            trigger_offset: Some(50.0),
+            trigger: Some(TriggerType::IndexPrice),
+            time_in_force: Some(TimeInForce::GoodTilDay),
+            price: None,
+            ..SellRequest::limit("BTC-PERPETUAL", 10.0, 0.0)
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({
+                "instrument_name": "BTC-PERPETUAL",
+                "amount": 10.0,
+                "type": "trailing_stop",
+                "time_in_force": "good_til_day",
+                "trigger_offset": 50.0,
+                "trigger": "index_price"
+            })
+        );
+    }
+
+    #[test]
+    fn test_sell_response_deserialization() {
+        let response: SellResponse = serde_json::from_value(order_response_json()).unwrap();
+
+        assert_eq!(response.result.order.instrument_name, "ETH-PERPETUAL");
+        assert_eq!(response.result.trades.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sell_uses_matching_engine_limits() {
+        let rest_client = test_client();
+
+        let _ = RestClient::sell;
+        assert_eq!(
+            EndpointType::from_path("private/sell"),
+            EndpointType::MatchingEngine
+        );
+        assert!(
+            rest_client
+                .rate_limiter
+                .check_limits(EndpointType::MatchingEngine)
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -1,26 +1,81 @@
+//! Request and response structures for the public/auth WebSocket method
+//!
+//! Authenticates the connection so private methods and private channels can be used.
+//! Supports the `client_credentials` and `client_signature` grant types, and keeps the
+//! session alive by exchanging the refresh token (`refresh_token` grant) before expiry.
+
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use rest::clock::ServerClock;
+use rest::secrets::{ExposableSecret, HmacSigner};
+use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
-use crate::deribit::DeribitWebSocketError;
+use websockets::WebSocketConnection;
+
+use super::client::{AuthenticationLostReason, DeribitPrivateMessage, PrivateWebSocketClient, Requester};
+use crate::deribit::{DeribitWebSocketError, GrantType};
+
+/// Fraction of the token lifetime after which the refresh token is exchanged
+const REFRESH_AT_FRACTION: f64 = 0.8;
+
+/// Delay between refresh attempts once the scheduled refresh has failed
+const REFRESH_RETRY_DELAY: Duration = Duration::from_secs(5);
 
-/// Request for private/auth method
+/// Request parameters for the public/auth method
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthRequest {
-    /// API key for authentication
-    pub key: String,
-    /// Signature for authentication
-    pub signature: String,
-    /// Timestamp for the signature
-    pub timestamp: u64,
-    /// Nonce for the signature
-    pub nonce: String,
+    /// Method of authentication: "client_credentials", "client_signature" or "refresh_token"
+    pub grant_type: GrantType,
+
+    /// Required for grant type `client_credentials` and `client_signature`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Required for grant type `client_credentials`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// Required for grant type `client_signature`, milliseconds since the UNIX epoch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+
+    /// Required for grant type `client_signature`, hex encoded HMAC-SHA256 of `timestamp\nnonce\ndata`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+
+    /// Optional for grant type `client_signature`; random string to make the signature unique
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+
+    /// Optional for grant type `client_signature`; user specified data included in the signature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+
+    /// Required for grant type `refresh_token`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+
+    /// Will be passed back in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Describes type of the access for assigned token (e.g., "session:name expires:60")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
 }
 
-/// Response for private/auth method
+/// Response for the public/auth method
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthResponse {
     /// JSON-RPC version
     pub jsonrpc: String,
+
     /// Request ID
     pub id: u64,
+
     /// Authentication result
     pub result: AuthResult,
 }
@@ -28,167 +83,611 @@ pub struct AuthResponse {
 /// Result data for authentication
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthResult {
-    /// Indicates successful authentication
-    pub success: bool,
-    /// Token for authenticated session (if applicable)
-    pub token: Option<String>,
+    /// Access token used to authenticate requests
+    pub access_token: String,
+
+    /// Token lifetime in seconds
+    pub expires_in: i64,
+
+    /// Can be used to request a new token (with a new lifetime)
+    pub refresh_token: String,
+
+    /// Type of the access for assigned token
+    pub scope: String,
+
+    /// Optional Session id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+
+    /// Copied from the input (if applicable)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Authorization type, allowed value - "bearer"
+    pub token_type: String,
+
+    /// List of enabled advanced on-key features
+    #[serde(default)]
+    pub enabled_features: Vec<String>,
+}
+
+/// Scope and lifetime of the access token of an authenticated connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthSession {
+    /// Type of the access for the assigned token (e.g., "connection mainaccount")
+    pub scope: String,
+
+    /// Authorization type, allowed value - "bearer"
+    pub token_type: String,
+
+    /// Session id, present for session scoped tokens
+    pub sid: Option<String>,
+
+    /// Token lifetime in seconds, as reported by the last authentication
+    pub expires_in: i64,
+
+    /// When the access token expires
+    pub expires_at: DateTime<Utc>,
+
+    /// List of enabled advanced on-key features
+    pub enabled_features: Vec<String>,
+}
+
+/// Session metadata plus the refresh token, which never leaves the client
+pub(crate) struct TokenState {
+    /// Publicly visible session details
+    pub(crate) session: AuthSession,
+
+    /// Token used for the `refresh_token` grant
+    refresh_token: SecretString,
+}
+
+impl TokenState {
+    /// Build the token state from a successful `public/auth` result
+    fn from_result(result: &AuthResult) -> Self {
+        let now = Utc::now();
+        let expires_at = TimeDelta::try_seconds(result.expires_in)
+            .and_then(|lifetime| now.checked_add_signed(lifetime))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        Self {
+            session: AuthSession {
+                scope: result.scope.clone(),
+                token_type: result.token_type.clone(),
+                sid: result.sid.clone(),
+                expires_in: result.expires_in,
+                expires_at,
+                enabled_features: result.enabled_features.clone(),
+            },
+            refresh_token: SecretString::from(result.refresh_token.clone()),
+        }
+    }
+}
+
+/// API credentials and the grant type used to authenticate
+#[derive(Clone)]
+pub(crate) struct Credentials {
+    /// Encrypted API key (Deribit client id)
+    pub(crate) api_key: Arc<dyn ExposableSecret>,
+
+    /// Encrypted API secret (Deribit client secret)
+    pub(crate) api_secret: Arc<dyn ExposableSecret>,
+
+    /// Grant type used for full authentication
+    pub(crate) grant_type: GrantType,
+
+    /// Server clock the `client_signature` timestamp is taken from
+    pub(crate) clock: ServerClock,
+}
+
+impl Credentials {
+    /// Build the public/auth parameters for the configured grant type
+    pub(crate) async fn build_auth_request(&self) -> Result<AuthRequest, DeribitWebSocketError> {
+        let client_id = Some(self.api_key.expose_secret());
+        match self.grant_type {
+            GrantType::ClientCredentials => Ok(AuthRequest {
+                grant_type: GrantType::ClientCredentials,
+                client_id,
+                client_secret: Some(self.api_secret.expose_secret()),
+                timestamp: None,
+                signature: None,
+                nonce: None,
+                data: None,
+                refresh_token: None,
+                state: None,
+                scope: None,
+            }),
+            GrantType::ClientSignature => {
+                let timestamp = i64::try_from(self.clock.now_ms()).unwrap_or(i64::MAX);
+                let nonce = uuid::Uuid::new_v4().simple().to_string();
+                let data = String::new();
+                let signature = self.create_auth_signature(timestamp, &nonce, &data).await?;
+                Ok(AuthRequest {
+                    grant_type: GrantType::ClientSignature,
+                    client_id,
+                    client_secret: None,
+                    timestamp: Some(timestamp),
+                    signature: Some(signature),
+                    nonce: Some(nonce),
+                    data: Some(data),
+                    refresh_token: None,
+                    state: None,
+                    scope: None,
+                })
+            }
+            GrantType::RefreshToken => Err(DeribitWebSocketError::AuthenticationFailed(
+                "refresh_token grant can only be used to refresh an existing session".to_string(),
+            )),
+        }
+    }
+
+    /// Build the public/auth parameters exchanging a refresh token for a new access token
+    pub(crate) fn build_refresh_request(refresh_token: &SecretString) -> AuthRequest {
+        AuthRequest {
+            grant_type: GrantType::RefreshToken,
+            client_id: None,
+            client_secret: None,
+            timestamp: None,
+            signature: None,
+            nonce: None,
+            data: None,
+            refresh_token: Some(refresh_token.expose_secret().to_string()),
+            state: None,
+            scope: None,
+        }
+    }
+
+    /// Create the `client_signature` signature
+    ///
+    /// Deribit signs `timestamp + "\n" + nonce + "\n" + data` with HMAC-SHA256 using the
+    /// client secret as key, hex encoded.
+    pub(crate) async fn create_auth_signature(&self, timestamp: i64, nonce: &str, data: &str) -> Result<String, DeribitWebSocketError> {
+        let string_to_sign = format!("{}\n{}\n{}", timestamp, nonce, data);
+        let signature = self
+            .api_secret
+            .hmac_sha256(string_to_sign.as_bytes())
+            .await
+            .map_err(|_| DeribitWebSocketError::AuthenticationFailed("Invalid API secret".to_string()))?;
+
+        Ok(hex::encode(signature))
+    }
+}
+
+/// Performs authentication and token refresh for one connection
+///
+/// Cloneable so the refresh task can run independently of the client.
+#[derive(Clone)]
+pub(crate) struct Authenticator {
+    /// Request handle of the connection being authenticated
+    pub(crate) requester: Requester,
+
+    /// API credentials
+    pub(crate) credentials: Credentials,
+}
+
+impl Authenticator {
+    /// Authenticate with the API credentials
+    pub(crate) async fn authenticate(&self) -> Result<AuthResult, DeribitWebSocketError> {
+        let request = self.credentials.build_auth_request().await?;
+        self.send_auth_request(&request).await
+    }
+
+    /// Exchange the refresh token of the current session for a new access token
+    pub(crate) async fn refresh(&self) -> Result<AuthResult, DeribitWebSocketError> {
+        let request = {
+            let session = self.requester.state.session.read().await;
+            let token = session
+                .as_ref()
+                .ok_or(DeribitWebSocketError::NotAuthenticated)?;
+            Credentials::build_refresh_request(&token.refresh_token)
+        };
+        self.send_auth_request(&request).await
+    }
+
+    /// Send a public/auth request and record the resulting session
+    async fn send_auth_request(&self, request: &AuthRequest) -> Result<AuthResult, DeribitWebSocketError> {
+        let state = &self.requester.state;
+        let result: AuthResult = self
+            .requester
+            .send_request("public/auth", request)
+            .await
+            .map_err(|err| match err {
+                DeribitWebSocketError::JsonRpc { code, message } => DeribitWebSocketError::AuthenticationFailed(format!("{} (code: {})", message, code)),
+                other => other,
+            })?;
+
+        *state.session.write().await = Some(TokenState::from_result(&result));
+        state.authenticated.store(true, Ordering::SeqCst);
+        // The reader task may have seen the socket close while the response was in flight
+        if !state.is_connected() {
+            state.authenticated.store(false, Ordering::SeqCst);
+            *state.session.write().await = None;
+            return Err(DeribitWebSocketError::NotConnected);
+        }
+        Ok(result)
+    }
+
+    /// Keep the session alive until the connection closes
+    ///
+    /// The refresh token is exchanged once 80% of the token lifetime has elapsed. If that
+    /// fails, a full authentication with the API credentials is attempted; attempts are
+    /// retried until the token expires, after which `AuthenticationLost` is published.
+    pub(crate) async fn refresh_loop(self, expires_in: i64) {
+        let state = self.requester.state.clone();
+        let mut lifetime = token_lifetime(expires_in);
+        loop {
+            let refresh_after = lifetime.mul_f64(REFRESH_AT_FRACTION);
+            tokio::time::sleep(refresh_after).await;
+            let remaining = lifetime.saturating_sub(refresh_after);
+            let deadline = tokio::time::Instant::now()
+                .checked_add(remaining)
+                .unwrap_or_else(tokio::time::Instant::now);
+
+            let refreshed = loop {
+                if !state.is_connected() {
+                    return;
+                }
+                let outcome = match self.refresh().await {
+                    Ok(result) => Ok(result),
+                    Err(err) => {
+                        tracing::warn!("Deribit token refresh failed, re-authenticating: {}", err);
+                        self.authenticate().await
+                    }
+                };
+                match outcome {
+                    Ok(result) => break Ok(result),
+                    Err(err) if deadline.saturating_duration_since(tokio::time::Instant::now()) <= REFRESH_RETRY_DELAY => break Err(err),
+                    Err(err) => {
+                        tracing::warn!("Deribit re-authentication failed, retrying: {}", err);
+                        tokio::time::sleep(REFRESH_RETRY_DELAY).await;
+                    }
+                }
+            };
+
+            match refreshed {
+                Ok(result) => lifetime = token_lifetime(result.expires_in),
+                Err(err) => {
+                    tokio::time::sleep_until(deadline).await;
+                    if state.authenticated.swap(false, Ordering::SeqCst) {
+                        *state.session.write().await = None;
+                        state.notify(Ok(DeribitPrivateMessage::AuthenticationLost(
+                            AuthenticationLostReason::RefreshFailed(err.to_string()),
+                        )));
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Token lifetime as a duration; negative lifetimes are treated as already expired
+fn token_lifetime(expires_in: i64) -> Duration {
+    Duration::from_secs(u64::try_from(expires_in).unwrap_or(0))
 }
 
-impl super::client::PrivateWebSocketClient {
-    /// Send authentication request to Deribit API
+impl PrivateWebSocketClient {
+    /// Authenticate the connection using the API credentials provided at construction
     ///
-    /// This method must be called after connecting and before using any private methods.
-    /// It signs the authentication request using the API credentials provided during client creation.
+    /// Called automatically by `connect`, which also schedules the token refresh. Uses the
+    /// grant type configured with `with_grant_type` (`client_signature` by default, so the
+    /// client secret is never sent).
+    ///
+    /// See: <https://docs.deribit.com/v2/#public-auth>
     ///
     /// # Returns
-    /// * `Ok(AuthResponse)` - Successful authentication
+    /// * `Ok(AuthResult)` - Successful authentication
     /// * `Err(DeribitWebSocketError)` - Authentication failed or connection error
-    ///
-    /// # Example
-    /// ```ignore
-    /// let mut client = PrivateWebSocketClient::new(api_key, api_secret, None, rate_limiter);
-    /// client.connect().await?;
-    /// let auth_response = client.send_auth().await?;
-    /// assert!(auth_response.result.success);
-    /// ```
-    pub async fn send_auth(&mut self) -> Result<AuthResponse, DeribitWebSocketError> {
-        // Check if connected
+    pub async fn send_auth(&self) -> Result<AuthResult, DeribitWebSocketError> {
         if !self.is_connected() {
             return Err(DeribitWebSocketError::NotConnected);
         }
+        self.authenticator()?.authenticate().await
+    }
 
-        // Generate authentication parameters
-        let timestamp = chrono::Utc::now().timestamp_millis() as u64;
-        let nonce = uuid::Uuid::new_v4().to_string();
-        
-        // Create signature (simplified - actual implementation would follow Deribit signing rules)
-        let api_key = self.api_key.expose_secret();
-        let api_secret = self.api_secret.expose_secret();
-        let signature = self.create_auth_signature(api_key, api_secret, timestamp, &nonce)?;
-        
-        // Create auth request
-        let auth_request = AuthRequest {
-            key: api_key.to_string(),
-            signature,
-            timestamp,
-            nonce,
-        };
-
-        // Create JSON-RPC request
-        let request_id = self.next_request_id();
-        let json_rpc_request = crate::deribit::message::JsonRpcRequest {
-            jsonrpc: "2.0".to_string(),
-            id: request_id,
-            method: "private/auth".to_string(),
-            params: serde_json::to_value(auth_request).ok(),
-        };
-
-        // Send request
-        let request_json = serde_json::to_string(&json_rpc_request)?;
-        let response_json = self.send_message(&request_json).await?;
-        
-        // Parse response
-        let auth_response: AuthResponse = serde_json::from_str(&response_json)?;
-        
-        // Update authentication state
-        if auth_response.result.success {
-            self.authenticated.store(true, std::sync::atomic::Ordering::Relaxed);
+    /// Exchange the refresh token of the current session for a new access token
+    ///
+    /// Normally not needed: the client refreshes automatically before the token expires.
+    ///
+    /// See: <https://docs.deribit.com/v2/#public-auth>
+    pub async fn refresh_auth(&self) -> Result<AuthResult, DeribitWebSocketError> {
+        if !self.is_connected() {
+            return Err(DeribitWebSocketError::NotConnected);
         }
-        
-        Ok(auth_response)
-    }
-
-    /// Create authentication signature following Deribit's signing algorithm
-    fn create_auth_signature(
-        &self,
-        api_key: &str,
-        api_secret: &str,
-        timestamp: u64,
-        nonce: &str,
-    ) -> Result<String, DeribitWebSocketError> {
-        // Simplified signature creation - actual implementation would follow Deribit spec
-        use hmac::{Hmac, Mac};
-        use sha2::Sha256;
-        
-        let message = format!("{}{}{}", timestamp, nonce, api_key);
-        let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes())
-            .map_err(|_| DeribitWebSocketError::AuthenticationFailed("Invalid API secret".to_string()))?;
-        mac.update(message.as_bytes());
-        
-        Ok(hex::encode(mac.finalize().into_bytes()))
+        self.authenticator()?.refresh().await
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use futures::StreamExt;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+    use tokio_tungstenite::tungstenite::Message;
+
     use super::*;
+    use crate::deribit::private::websocket::client::tests::{auth_reply, auth_reply_expiring, spawn_server, test_client};
 
-    #[test]
-    fn test_auth_request_structure() {
-        let auth_request = AuthRequest {
-            key: "test_key".to_string(),
-            signature: "test_signature".to_string(),
-            timestamp: 1234567890,
-            nonce: "test_nonce".to_string(),
-        };
+    /// Wait for the next `public/auth` request seen by the test server and return its params
+    async fn next_auth_params(seen: &mut tokio::sync::mpsc::UnboundedReceiver<Message>) -> serde_json::Value {
+        loop {
+            let message = tokio::time::timeout(Duration::from_secs(3), seen.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            if let Message::Text(text) = message {
+                let request: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                if request["method"] == "public/auth" {
+                    return request["params"].clone();
+                }
+            }
+        }
+    }
 
-        assert_eq!(auth_request.key, "test_key");
-        assert_eq!(auth_request.signature, "test_signature");
-        assert_eq!(auth_request.timestamp, 1234567890);
-        assert_eq!(auth_request.nonce, "test_nonce");
+    fn auth_error(request: &serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "error": {"code": 13009, "message": "unauthorized"}
+        })
     }
 
-    #[test]
-    fn test_auth_request_serialization() {
-        let auth_request = AuthRequest {
-            key: "test_key".to_string(),
-            signature: "test_signature".to_string(),
-            timestamp: 1234567890,
-            nonce: "test_nonce".to_string(),
-        };
+    #[tokio::test]
+    async fn test_auth_request_client_credentials_serialization() {
+        let client = test_client(None).with_grant_type(GrantType::ClientCredentials);
+
+        let request = client.credentials().build_auth_request().await.unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["grant_type"], "client_credentials");
+        assert_eq!(json["client_id"], "test_key");
+        assert_eq!(json["client_secret"], "test_secret");
+        assert!(json.get("signature").is_none());
+        assert!(json.get("timestamp").is_none());
+    }
 
-        let json = serde_json::to_string(&auth_request).unwrap();
-        let deserialized: AuthRequest = serde_json::from_str(&json).unwrap();
+    #[tokio::test]
+    async fn test_auth_request_client_signature_serialization() {
+        let client = test_client(None);
 
-        assert_eq!(auth_request.key, deserialized.key);
-        assert_eq!(auth_request.signature, deserialized.signature);
-        assert_eq!(auth_request.timestamp, deserialized.timestamp);
-        assert_eq!(auth_request.nonce, deserialized.nonce);
+        let request = client.credentials().build_auth_request().await.unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["grant_type"], "client_signature");
+        assert_eq!(json["client_id"], "test_key");
+        assert!(json.get("client_secret").is_none());
+        assert!(json["timestamp"].is_i64());
+        assert_eq!(json["data"], "");
+
+        let expected = client
+            .credentials()
+            .create_auth_signature(
+                request.timestamp.unwrap(),
+                request.nonce.as_deref().unwrap(),
+                "",
+            )
+            .await
+            .unwrap();
+        assert_eq!(request.signature.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_auth_request_client_signature_uses_server_clock() {
+        let clock = ServerClock::new();
+        // The server runs a minute behind, measured with a 100ms round trip
+        clock.record(60_000, 50, Duration::from_millis(100));
+        let client = test_client(None).with_server_clock(clock);
+
+        let request = client.credentials().build_auth_request().await.unwrap();
+
+        let offset = request
+            .timestamp
+            .unwrap()
+            .saturating_sub(Utc::now().timestamp_millis());
+        assert!(offset.saturating_add(60_000).abs() < 1000);
+    }
+
+    #[tokio::test]
+    async fn test_auth_signature_known_value() {
+        let client = test_client(None);
+
+        let signature = client
+            .credentials()
+            .create_auth_signature(1576074319000, "1iqt2wls", "")
+            .await
+            .unwrap();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"test_secret").unwrap();
+        mac.update(b"1576074319000\n1iqt2wls\n");
+        assert_eq!(signature, hex::encode(mac.finalize().into_bytes()));
+        assert_eq!(signature.len(), 64);
     }
 
     #[test]
     fn test_auth_response_deserialization() {
         let json = r#"{
             "jsonrpc": "2.0",
-            "id": 1,
+            "id": 9929,
             "result": {
-                "success": true,
-                "token": "test_token"
+                "access_token": "1582628593469.1MbQ-J_4.CBP-OqOwm_FBdMYj4cRK2dMXyHPfBtXGpzLxhWg31nHu3H_Q60FpE5_vqUBEQGSiMrIGzw3nC37NMb9d1tpBNqBOM_Ql9pXOmgtV9Yj3Pq1c6BqC6dU6eTxHMFO67x8GpJxqw_QcKP5IepwGBD-gfKSHfAv9AEnLJkNu3JkMJBdLToY1lrBnuedF3dU_uARm",
+                "enabled_features": [],
+                "expires_in": 31536000,
+                "refresh_token": "1582628593469.1GP4rQd0.A9Wa78o5kFRIUP49mScaD1CqHgiK50HOl2VA6kCtWa8BQZU5Dr03BhcbXPNvEh3I_MVixKZXnyoBeKJwLl8LXnfo180ckAiPj3zOclcUu4zkXuF3NNP3sTPcDf1B3C1CwMKkJ1NOcf1yPmRbsrd7hbgQ-hLa40tfx6Oa-85ymm_3Z65LZcnCeLrqlj_A9jM",
+                "scope": "connection mainaccount",
+                "token_type": "bearer"
             }
         }"#;
 
         let auth_response: AuthResponse = serde_json::from_str(json).unwrap();
         assert_eq!(auth_response.jsonrpc, "2.0");
-        assert_eq!(auth_response.id, 1);
-        assert!(auth_response.result.success);
-        assert_eq!(auth_response.result.token, Some("test_token".to_string()));
+        assert_eq!(auth_response.id, 9929);
+        assert_eq!(auth_response.result.expires_in, 31536000);
+        assert_eq!(auth_response.result.scope, "connection mainaccount");
+        assert_eq!(auth_response.result.token_type, "bearer");
+        assert!(auth_response.result.sid.is_none());
+        assert!(auth_response.result.enabled_features.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_auth_requires_connection() {
+        let client = test_client(None);
+
+        let result = client.send_auth().await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn test_auth_failure_is_reported() {
+        let (url, _seen) = spawn_server(|request| {
+            vec![serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": {"code": 13004, "message": "invalid_credentials"}
+            })]
+        })
+        .await;
+        let mut client = test_client(Some(url));
+
+        let result = client.connect().await;
+
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("invalid_credentials"));
+        assert!(!client.is_authenticated());
+    }
+
+    #[tokio::test]
+    async fn test_auth_message_flow() {
+        let (url, mut seen) = spawn_server(|request| vec![auth_reply(request)]).await;
+        let mut client = test_client(Some(url));
+
+        client.connect().await.unwrap();
+
+        assert!(client.is_authenticated());
+        match seen.recv().await.unwrap() {
+            tokio_tungstenite::tungstenite::Message::Text(text) => {
+                let request: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                assert_eq!(request["method"], "public/auth");
+                assert_eq!(request["params"]["grant_type"], "client_signature");
+                assert_eq!(request["params"]["client_id"], "test_key");
+            }
+            other => panic!("Expected text frame, got {:?}", other),
+        }
     }
 
     #[test]
-    fn test_auth_response_without_token() {
-        let json = r#"{
-            "jsonrpc": "2.0", 
-            "id": 2,
-            "result": {
-                "success": false,
-                "token": null
+    fn test_refresh_request_serialization() {
+        let request = Credentials::build_refresh_request(&SecretString::from("refresh".to_string()));
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["grant_type"], "refresh_token");
+        assert_eq!(json["refresh_token"], "refresh");
+        assert!(json.get("client_id").is_none());
+        assert!(json.get("signature").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_grant_cannot_authenticate() {
+        let client = test_client(None).with_grant_type(GrantType::RefreshToken);
+
+        let result = client.credentials().build_auth_request().await;
+
+        assert!(matches!(
+            result,
+            Err(DeribitWebSocketError::AuthenticationFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_token_is_refreshed_before_expiry() {
+        let (url, mut seen) = spawn_server(|request| match request["params"]["grant_type"].as_str() {
+            Some("refresh_token") => vec![auth_reply(request)],
+            _ => vec![auth_reply_expiring(request, 1)],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+        assert_eq!(client.auth_session().await.unwrap().expires_in, 1);
+
+        assert_eq!(
+            next_auth_params(&mut seen).await["grant_type"],
+            "client_signature"
+        );
+        let refresh = next_auth_params(&mut seen).await;
+
+        assert_eq!(refresh["grant_type"], "refresh_token");
+        assert_eq!(refresh["refresh_token"], "refresh");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(client.is_authenticated());
+        assert_eq!(client.auth_session().await.unwrap().expires_in, 31536000);
+    }
+
+    #[tokio::test]
+    async fn test_failed_refresh_falls_back_to_authentication() {
+        let (url, mut seen) = spawn_server(|request| match request["params"]["grant_type"].as_str() {
+            Some("refresh_token") => vec![auth_error(request)],
+            _ => vec![auth_reply_expiring(request, 1)],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        assert_eq!(
+            next_auth_params(&mut seen).await["grant_type"],
+            "client_signature"
+        );
+        assert_eq!(
+            next_auth_params(&mut seen).await["grant_type"],
+            "refresh_token"
+        );
+        assert_eq!(
+            next_auth_params(&mut seen).await["grant_type"],
+            "client_signature"
+        );
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(client.is_authenticated());
+    }
+
+    #[tokio::test]
+    async fn test_authentication_lost_when_refresh_fails() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let server_attempts = attempts.clone();
+        let (url, _seen) = spawn_server(move |request| {
+            if server_attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                vec![auth_reply_expiring(request, 1)]
+            } else {
+                vec![auth_error(request)]
             }
-        }"#;
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        let mut stream = client.message_stream();
+        client.connect().await.unwrap();
 
-        let auth_response: AuthResponse = serde_json::from_str(json).unwrap();
-        assert_eq!(auth_response.jsonrpc, "2.0");
-        assert_eq!(auth_response.id, 2);
-        assert!(!auth_response.result.success);
-        assert_eq!(auth_response.result.token, None);
+        let event = tokio::time::timeout(Duration::from_secs(3), stream.next())
+            .await
+            .unwrap();
+
+        match event {
+            Some(Ok(DeribitPrivateMessage::AuthenticationLost(AuthenticationLostReason::RefreshFailed(message)))) => {
+                assert!(message.contains("unauthorized"))
+            }
+            other => panic!("Expected AuthenticationLost, got {:?}", other),
+        }
+        assert!(!client.is_authenticated());
+        assert!(client.auth_session().await.is_none());
+        assert!(attempts.load(Ordering::SeqCst) >= 3);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_refresh_auth_requires_connection() {
+        let client = test_client(None);
+
+        let result = client.refresh_auth().await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::NotConnected)));
+    }
+}
@@ -1,214 +1,672 @@
-use std::collections::HashMap;
-use std::pin::Pin;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Mutex};
+//! Deribit Private WebSocket client: connection management only
+//!
+//! This file only manages the connection and its authentication state. The socket tasks and
+//! the routing of JSON-RPC responses and notifications live in the shared Deribit connection
+//! module; endpoint logic lives in the per-method files next to it (e.g., `auth.rs`, `subscribe.rs`).
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use async_trait::async_trait;
-use futures::Stream;
+use rest::clock::ServerClock;
 use rest::secrets::ExposableSecret;
-use tokio_tungstenite::{connect_async, WebSocketStream, MaybeTlsStream};
-use tokio::net::TcpStream;
-
-use crate::deribit::{DeribitWebSocketError, RateLimiter};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio::sync::{RwLock, mpsc};
 use websockets::{BoxResult, VenueMessage, WebSocketConnection};
 
+use super::auth::{AuthSession, Authenticator, Credentials, TokenState};
+use crate::deribit::connection::{self, MessageStream, RpcConnection, RpcHandle, RpcMessage, RpcState};
+use crate::deribit::message::HeartbeatNotification;
+use crate::deribit::notification::ChannelNotification;
+use crate::deribit::{DeribitWebSocketError, GrantType, RateLimiter};
+
+/// Default time to wait for a JSON-RPC response before giving up
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Message type for Deribit private WebSocket messages
+///
+/// These are the server initiated messages and authentication loss events yielded by
+/// `message_stream`. Responses to requests are returned directly from the endpoint methods.
 #[derive(Debug, Clone)]
 pub enum DeribitPrivateMessage {
-    AuthResponse(super::auth::AuthResponse),
-    BuyResponse(super::buy::BuyResponse),
-    SellResponse(super::sell::SellResponse),
-    CancelResponse(super::cancel::CancelResponse),
-    // Add other message types as implemented
+    /// Typed data pushed for a subscribed channel
+    Subscription(ChannelNotification),
+
+    /// Heartbeat notification (test requests are answered automatically)
+    Heartbeat(HeartbeatNotification),
+
+    /// The connection is no longer authenticated; private methods will fail until `connect` succeeds again
+    AuthenticationLost(AuthenticationLostReason),
 }
 
 impl VenueMessage for DeribitPrivateMessage {}
 
+impl RpcMessage for DeribitPrivateMessage {
+    fn subscription(notification: ChannelNotification) -> Self {
+        DeribitPrivateMessage::Subscription(notification)
+    }
+
+    fn heartbeat(heartbeat: HeartbeatNotification) -> Self {
+        DeribitPrivateMessage::Heartbeat(heartbeat)
+    }
+}
+
+/// Why a private connection stopped being authenticated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthenticationLostReason {
+    /// The socket was closed by the server or the network
+    ConnectionClosed,
+
+    /// Neither the refresh token exchange nor a full re-authentication succeeded before expiry
+    RefreshFailed(String),
+}
+
+/// State shared by the client, the tasks of the current connection and the token refresh task
+pub(crate) struct ClientState {
+    /// JSON-RPC state shared with the connection tasks
+    pub(crate) rpc: Arc<RpcState<DeribitPrivateMessage>>,
+
+    /// Authentication state flag
+    pub(crate) authenticated: AtomicBool,
+
+    /// Tokens and metadata of the current authenticated session
+    pub(crate) session: RwLock<Option<TokenState>>,
+}
+
+impl ClientState {
+    /// Check if a connection is open
+    pub(crate) fn is_connected(&self) -> bool {
+        self.rpc.is_connected()
+    }
+
+    /// Publish a message on the notification stream; a dropped receiver is not an error
+    pub(crate) fn notify(&self, message: BoxResult<DeribitPrivateMessage>) {
+        self.rpc.notify(message);
+    }
+
+    /// Drop the session of a connection closed by the server or the network
+    async fn connection_closed(&self) {
+        if self.authenticated.swap(false, Ordering::SeqCst) {
+            self.notify(Ok(DeribitPrivateMessage::AuthenticationLost(
+                AuthenticationLostReason::ConnectionClosed,
+            )));
+        }
+        *self.session.write().await = None;
+    }
+}
+
+/// Cloneable handle to send requests over the current connection
+#[derive(Clone)]
+pub(crate) struct Requester {
+    /// Shared client state
+    pub(crate) state: Arc<ClientState>,
+
+    /// Request handle of the connection
+    rpc: RpcHandle<DeribitPrivateMessage>,
+
+    /// Maximum time to wait for a response to a request
+    request_timeout: Duration,
+}
+
+impl Requester {
+    /// Send a JSON-RPC request and wait for the matching response
+    pub(crate) async fn send_request<P, R>(&self, method: &str, params: &P) -> Result<R, DeribitWebSocketError>
+    where
+        P: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        self.rpc
+            .send_request(method, params, self.request_timeout)
+            .await
+    }
+}
+
 /// Private WebSocket client for Deribit exchange
-/// 
+///
 /// This client handles all private API endpoints that require authentication.
 /// It provides automatic rate limiting, error handling, and request signing.
+/// Access tokens are refreshed before they expire and every `connect` re-authenticates.
 pub struct PrivateWebSocketClient {
-    /// The WebSocket connection
-    websocket: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>,
-    
-    /// Connection state flag  
-    connected: Arc<AtomicBool>,
-    
-    /// Authentication state flag
-    authenticated: Arc<AtomicBool>,
-    
-    /// Rate limiter for private endpoints
-    rate_limiter: Arc<RateLimiter>,
-    
-    /// Request ID counter for JSON-RPC
-    request_id: Arc<AtomicU64>,
-    
-    /// Pending requests waiting for responses
-    pending_requests: Arc<Mutex<HashMap<u64, tokio::sync::oneshot::Sender<String>>>>,
-    
+    /// Current connection, if any; also owns the token refresh task
+    connection: Option<RpcConnection<DeribitPrivateMessage>>,
+
+    /// Request handle of the current connection
+    requester: Option<Requester>,
+
+    /// State shared with the background tasks
+    pub(crate) state: Arc<ClientState>,
+
+    /// Receiver side of the notification channel, taken by `message_stream`
+    notification_rx: Option<mpsc::UnboundedReceiver<BoxResult<DeribitPrivateMessage>>>,
+
+    /// Maximum time to wait for a response to a request
+    request_timeout: Duration,
+
+    /// Grant type used by `public/auth`
+    pub(crate) grant_type: GrantType,
+
     /// WebSocket URL
     url: String,
-    
+
     /// Encrypted API key
-    api_key: Box<dyn ExposableSecret>,
-    
-    /// Encrypted API secret  
-    api_secret: Box<dyn ExposableSecret>,
+    pub(crate) api_key: Arc<dyn ExposableSecret>,
+
+    /// Encrypted API secret
+    pub(crate) api_secret: Arc<dyn ExposableSecret>,
+
+    /// The server clock `client_signature` authentication is timestamped with
+    pub(crate) clock: ServerClock,
 }
 
 impl PrivateWebSocketClient {
     /// Create a new Deribit Private WebSocket client
     ///
     /// # Arguments
-    /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret  
+    /// * `api_key` - The encrypted API key (Deribit client id)
+    /// * `api_secret` - The encrypted API secret (Deribit client secret)
     /// * `url` - Optional custom WebSocket URL
     /// * `rate_limiter` - Rate limiter instance
     ///
     /// # Returns
     /// A new PrivateWebSocketClient instance
-    pub fn new(
-        api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
-        url: Option<String>,
-        rate_limiter: RateLimiter,
-    ) -> Self {
+    pub fn new(api_key: Box<dyn ExposableSecret>, api_secret: Box<dyn ExposableSecret>, url: Option<String>, rate_limiter: RateLimiter) -> Self {
+        let (rpc, notification_rx) = RpcState::new(rate_limiter);
         Self {
-            websocket: None,
-            connected: Arc::new(AtomicBool::new(false)),
-            authenticated: Arc::new(AtomicBool::new(false)),
-            rate_limiter: Arc::new(rate_limiter),
-            request_id: Arc::new(AtomicU64::new(1)),
-            pending_requests: Arc::new(Mutex::new(HashMap::new())),
+            connection: None,
+            requester: None,
+            state: Arc::new(ClientState {
+                rpc: Arc::new(rpc),
+                authenticated: AtomicBool::new(false),
+                session: RwLock::new(None),
+            }),
+            notification_rx: Some(notification_rx),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            grant_type: GrantType::default(),
             url: url.unwrap_or_else(|| "wss://www.deribit.com/ws/api/v2".to_string()),
-            api_key,
-            api_secret,
+            api_key: Arc::from(api_key),
+            api_secret: Arc::from(api_secret),
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `client_signature` timestamp from `clock`, as Deribit rejects stale ones
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set the grant type used when authenticating (defaults to `client_signature`)
+    pub fn with_grant_type(mut self, grant_type: GrantType) -> Self {
+        self.grant_type = grant_type;
+        self
+    }
+
+    /// Set the maximum time to wait for the response to a request
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
     /// Check if the client is authenticated
     pub fn is_authenticated(&self) -> bool {
-        self.authenticated.load(Ordering::Relaxed)
+        self.state.authenticated.load(Ordering::SeqCst)
     }
 
-    /// Generate the next request ID
-    pub(crate) fn next_request_id(&self) -> u64 {
-        self.request_id.fetch_add(1, Ordering::Relaxed)
+    /// Scope, type and expiry of the current access token, if authenticated
+    pub async fn auth_session(&self) -> Option<AuthSession> {
+        self.state
+            .session
+            .read()
+            .await
+            .as_ref()
+            .map(|token| token.session.clone())
     }
 
-    /// Send a raw JSON-RPC message and wait for response
-    pub(crate) async fn send_message(&mut self, _message: &str) -> Result<String, DeribitWebSocketError> {
-        // Implementation would send message via WebSocket and wait for response
-        // This is a placeholder for the actual implementation
-        todo!("Implement WebSocket message sending")
+    /// Send a JSON-RPC request over the current connection and wait for the matching response
+    ///
+    /// Private methods are rejected until the connection has been authenticated.
+    ///
+    /// # Returns
+    /// The deserialized `result` field, or `DeribitWebSocketError::JsonRpc` if Deribit
+    /// answered with an `error` object.
+    pub(crate) async fn send_request<P, R>(&self, method: &str, params: &P) -> Result<R, DeribitWebSocketError>
+    where
+        P: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let requester = self
+            .requester
+            .as_ref()
+            .ok_or(DeribitWebSocketError::NotConnected)?;
+        if method.starts_with("private/") && !self.is_authenticated() {
+            return Err(DeribitWebSocketError::NotAuthenticated);
+        }
+        requester.send_request(method, params).await
+    }
+
+    /// Authenticator for the current connection
+    pub(crate) fn authenticator(&self) -> Result<Authenticator, DeribitWebSocketError> {
+        let requester = self
+            .requester
+            .clone()
+            .ok_or(DeribitWebSocketError::NotConnected)?;
+        Ok(Authenticator {
+            requester,
+            credentials: self.credentials(),
+        })
+    }
+
+    /// API credentials and grant type used to authenticate
+    pub(crate) fn credentials(&self) -> Credentials {
+        Credentials {
+            api_key: self.api_key.clone(),
+            api_secret: self.api_secret.clone(),
+            grant_type: self.grant_type,
+            clock: self.clock.clone(),
+        }
+    }
+
+    /// Drop the current connection and establish a new, re-authenticated one
+    pub async fn reconnect(&mut self) -> BoxResult<()> {
+        self.disconnect().await?;
+        self.connect().await
     }
 }
 
 #[async_trait]
 impl WebSocketConnection<DeribitPrivateMessage> for PrivateWebSocketClient {
     /// Connect to the Deribit private WebSocket and authenticate
+    ///
+    /// Also used to recover from a dropped connection: any previous connection is torn
+    /// down, a full authentication is performed and token refresh is rescheduled.
     async fn connect(&mut self) -> BoxResult<()> {
-        // Connect to WebSocket
-        let (ws_stream, _) = connect_async(&self.url).await?;
-        self.websocket = Some(ws_stream);
-        self.connected.store(true, Ordering::Relaxed);
-        
-        // Authenticate immediately after connection
-        let _auth_result = self.authenticate().await?;
-        
-        Ok(())
+        // Stop the tasks of any previous connection before opening the new one
+        self.requester = None;
+        if let Some(connection) = self.connection.take() {
+            connection.shutdown().await;
+        }
+
+        let state = self.state.clone();
+        let connection = RpcConnection::open(&self.url, self.state.rpc.clone(), move || async move {
+            state.connection_closed().await;
+        })
+        .await?;
+        self.requester = Some(Requester {
+            state: self.state.clone(),
+            rpc: connection.handle().clone(),
+            request_timeout: self.request_timeout,
+        });
+        self.connection = Some(connection);
+
+        // Authenticate immediately after connection; an unauthenticated private socket is useless
+        let authenticator = self.authenticator()?;
+        match authenticator.authenticate().await {
+            Ok(result) => {
+                if let Some(connection) = self.connection.as_mut() {
+                    connection.spawn(authenticator.refresh_loop(result.expires_in));
+                }
+                Ok(())
+            }
+            Err(err) => {
+                self.disconnect().await?;
+                Err(Box::new(err))
+            }
+        }
     }
 
-    /// Disconnect from the WebSocket
+    /// Disconnect from the WebSocket, sending a close frame to the server
     async fn disconnect(&mut self) -> BoxResult<()> {
-        if let Some(_ws) = self.websocket.take() {
-            // Close WebSocket connection
-            // Implementation would properly close the connection
+        self.requester = None;
+        if let Some(connection) = self.connection.take() {
+            connection.close().await;
         }
-        self.connected.store(false, Ordering::Relaxed);
-        self.authenticated.store(false, Ordering::Relaxed);
+        self.state.authenticated.store(false, Ordering::SeqCst);
+        *self.state.session.write().await = None;
         Ok(())
     }
 
     /// Check if connected to the WebSocket
     fn is_connected(&self) -> bool {
-        self.connected.load(Ordering::Relaxed)
+        self.state.is_connected()
     }
 
-    /// Get message stream (placeholder)
-    fn message_stream(&mut self) -> Pin<Box<dyn Stream<Item = BoxResult<DeribitPrivateMessage>> + Send>> {
-        // Implementation would return actual message stream
-        todo!("Implement message stream")
-    }
-}
-
-impl PrivateWebSocketClient {
-    /// Authenticate with the Deribit API
-    async fn authenticate(&mut self) -> Result<super::auth::AuthResponse, DeribitWebSocketError> {
-        // This will be implemented in auth.rs
-        // For now, just set authenticated flag
-        self.authenticated.store(true, Ordering::Relaxed);
-        todo!("Implement authentication - will be in auth.rs")
+    /// Get the stream of subscription notifications and authentication loss events
+    ///
+    /// The stream survives reconnects. It can only be taken once; subsequent calls
+    /// return an empty stream.
+    fn message_stream(&mut self) -> MessageStream<DeribitPrivateMessage> {
+        connection::message_stream(&mut self.notification_rx)
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
+    use futures::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+    use tokio_tungstenite::tungstenite::Message;
+
     use super::*;
-    use crate::deribit::AccountTier;
+    use crate::deribit::{AccountTier, HeartbeatType};
 
-    struct TestSecret {
+    pub(crate) struct TestSecret {
         value: String,
     }
 
     impl TestSecret {
-        fn new(value: String) -> Self {
+        pub(crate) fn new(value: String) -> Self {
             Self { value }
         }
     }
 
     impl ExposableSecret for TestSecret {
-        fn expose_secret(&self) -> &str {
-            &self.value
+        fn expose_secret(&self) -> String {
+            self.value.clone()
         }
     }
 
-    #[test]
-    fn test_private_websocket_client_creation() {
+    pub(crate) fn test_client(url: Option<String>) -> PrivateWebSocketClient {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
         let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
         let rl = RateLimiter::new(AccountTier::default());
-        
-        let client = PrivateWebSocketClient::new(api_key, api_secret, None, rl);
-        
+        PrivateWebSocketClient::new(api_key, api_secret, url, rl)
+    }
+
+    /// The shared stand-in server works for private requests too
+    pub(crate) use crate::deribit::connection::tests::spawn_server;
+
+    pub(crate) fn auth_reply(request: &serde_json::Value) -> serde_json::Value {
+        auth_reply_expiring(request, 31536000)
+    }
+
+    pub(crate) fn auth_reply_expiring(request: &serde_json::Value, expires_in: i64) -> serde_json::Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "result": {
+                "access_token": "access",
+                "expires_in": expires_in,
+                "refresh_token": "refresh",
+                "scope": "connection mainaccount",
+                "token_type": "bearer"
+            }
+        })
+    }
+
+    #[test]
+    fn test_private_websocket_client_creation() {
+        let client = test_client(None);
+
         assert!(!client.is_connected());
         assert!(!client.is_authenticated());
         assert_eq!(client.url, "wss://www.deribit.com/ws/api/v2");
+        assert_eq!(client.grant_type, GrantType::ClientSignature);
     }
 
     #[test]
     fn test_private_websocket_client_custom_url() {
-        let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
-        let rl = RateLimiter::new(AccountTier::default());
         let url = "wss://test.deribit.com/ws/api/v2".to_string();
-        
-        let client = PrivateWebSocketClient::new(api_key, api_secret, Some(url.clone()), rl);
-        
+
+        let client = test_client(Some(url.clone()));
+
         assert_eq!(client.url, url);
     }
 
     #[test]
     fn test_request_id_generation() {
-        let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
-        let rl = RateLimiter::new(AccountTier::default());
-        
-        let client = PrivateWebSocketClient::new(api_key, api_secret, None, rl);
-        
-        let id1 = client.next_request_id();
-        let id2 = client.next_request_id();
+        let client = test_client(None);
+
+        let id1 = client.state.rpc.next_request_id();
+        let id2 = client.state.rpc.next_request_id();
         assert_eq!(id2, id1 + 1);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_send_request_requires_connection() {
+        let client = test_client(None);
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("public/test", &serde_json::json!({}))
+            .await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn test_connect_authenticates_and_routes_responses() {
+        let (url, _seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            _ => vec![serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": {"version": "1.2.26"}})],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+
+        client.connect().await.unwrap();
+        assert!(client.is_connected());
+        assert!(client.is_authenticated());
+
+        let result: serde_json::Value = client
+            .send_request("public/test", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result["version"], "1.2.26");
+    }
+
+    #[tokio::test]
+    async fn test_json_rpc_error_is_mapped() {
+        let (url, _seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            _ => vec![serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": {"code": 10009, "message": "not_enough_funds"}
+            })],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("private/get_position", &serde_json::json!({}))
+            .await;
+
+        match result {
+            Err(DeribitWebSocketError::JsonRpc { code, message }) => {
+                assert_eq!(code, 10009);
+                assert_eq!(message, "not_enough_funds");
+            }
+            other => panic!("Expected JsonRpc error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_backs_off() {
+        let (url, _seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            _ => vec![serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": {"code": 10028, "message": "too_many_requests"}
+            })],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("private/buy", &serde_json::json!({"instrument_name": "BTC-PERPETUAL"}))
+            .await;
+        assert!(matches!(result, Err(DeribitWebSocketError::JsonRpc { code: 10028, .. })));
+
+        let status = client.state.rpc.rate_limiter.get_status().await;
+        assert_eq!(status.too_many_requests_errors, 1);
+        assert!(status.backoff_remaining.is_some());
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("private/buy", &serde_json::json!({"instrument_name": "BTC-PERPETUAL"}))
+            .await;
+        assert!(matches!(result, Err(DeribitWebSocketError::RateLimit(_))));
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        let (url, _seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            _ => vec![],
+        })
+        .await;
+        let mut client = test_client(Some(url)).with_request_timeout(Duration::from_millis(50));
+        client.connect().await.unwrap();
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("public/test", &serde_json::json!({}))
+            .await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::Timeout { .. })));
+        assert!(client.state.rpc.pending_requests.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_test_request_is_answered() {
+        let (url, mut seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![
+                auth_reply(request),
+                serde_json::json!({"jsonrpc": "2.0", "method": "heartbeat", "params": {"type": "test_request"}}),
+            ],
+            _ => vec![],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        let mut stream = client.message_stream();
+        client.connect().await.unwrap();
+
+        match stream.next().await {
+            Some(Ok(DeribitPrivateMessage::Heartbeat(heartbeat))) => assert_eq!(heartbeat.heartbeat_type, HeartbeatType::TestRequest),
+            other => panic!("Expected heartbeat, got {:?}", other),
+        }
+
+        // First frame is the auth request, second must be the public/test reply
+        let _auth = seen.recv().await.unwrap();
+        match seen.recv().await.unwrap() {
+            Message::Text(text) => assert!(text.as_str().contains("public/test")),
+            other => panic!("Expected text frame, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_closes_socket() {
+        let (url, mut seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            _ => vec![],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        client.disconnect().await.unwrap();
+
+        assert!(!client.is_connected());
+        assert!(!client.is_authenticated());
+        let _auth = seen.recv().await.unwrap();
+        assert!(matches!(seen.recv().await, Some(Message::Close(_))));
+    }
+
+    #[tokio::test]
+    async fn test_server_close_marks_disconnected() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(socket).await.unwrap();
+            if let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                ws.send(Message::Text(auth_reply(&request).to_string().into()))
+                    .await
+                    .unwrap();
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            ws.close(None).await.unwrap();
+        });
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        for _ in 0..50 {
+            if !client.is_connected() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(!client.is_connected());
+        assert!(!client.is_authenticated());
+    }
+
+    #[tokio::test]
+    async fn test_server_close_emits_authentication_lost() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(socket).await.unwrap();
+            if let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                ws.send(Message::Text(auth_reply(&request).to_string().into()))
+                    .await
+                    .unwrap();
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            ws.close(None).await.unwrap();
+        });
+        let mut client = test_client(Some(url));
+        let mut stream = client.message_stream();
+        client.connect().await.unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+
+        match event {
+            Some(Ok(DeribitPrivateMessage::AuthenticationLost(reason))) => assert_eq!(reason, AuthenticationLostReason::ConnectionClosed),
+            other => panic!("Expected AuthenticationLost, got {:?}", other),
+        }
+        assert!(client.auth_session().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_auth_session_is_exposed() {
+        let (url, _seen) = spawn_server(|request| vec![auth_reply(request)]).await;
+        let mut client = test_client(Some(url));
+        assert!(client.auth_session().await.is_none());
+
+        client.connect().await.unwrap();
+
+        let session = client.auth_session().await.unwrap();
+        assert_eq!(session.scope, "connection mainaccount");
+        assert_eq!(session.token_type, "bearer");
+        assert_eq!(session.expires_in, 31536000);
+        assert!(session.expires_at > chrono::Utc::now() + chrono::TimeDelta::days(364));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_reauthenticates() {
+        let (url, mut seen) = spawn_server(|request| vec![auth_reply(request)]).await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        client.reconnect().await.unwrap();
+
+        assert!(client.is_connected());
+        assert!(client.is_authenticated());
+        let mut auth_requests = 0;
+        while let Ok(Some(message)) = tokio::time::timeout(Duration::from_millis(100), seen.recv()).await {
+            if let Message::Text(text) = message {
+                if text.as_str().contains("public/auth") {
+                    auth_requests += 1;
+                }
+            }
+        }
+        assert_eq!(auth_requests, 2);
+    }
+}
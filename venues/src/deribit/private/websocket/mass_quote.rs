@@ -0,0 +1,73 @@
+//! The private/mass_quote WebSocket method
+//!
+//! Quoting over the WebSocket avoids a TLS handshake and HTTP round trip per refresh,
+//! which matters when hundreds of quotes are replaced per second. Request and result
+//! types are shared with the REST endpoint.
+
+use super::client::PrivateWebSocketClient;
+use crate::deribit::DeribitWebSocketError;
+use crate::deribit::private::rest::{MassQuoteRequest, MassQuoteResult};
+
+impl PrivateWebSocketClient {
+    /// Place or replace quotes on several instruments in a single request
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-mass_quote>
+    ///
+    /// # Returns
+    /// The resulting orders and trades, and the quotes that were rejected
+    pub async fn mass_quote(&self, request: MassQuoteRequest) -> Result<MassQuoteResult, DeribitWebSocketError> {
+        self.send_request("private/mass_quote", &request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_tungstenite::tungstenite::Message;
+    use websockets::WebSocketConnection;
+
+    use super::*;
+    use crate::deribit::QuoteSide;
+    use crate::deribit::private::rest::mass_quote::tests::{mass_quote_request, mass_quote_result_json};
+    use crate::deribit::private::websocket::client::tests::{auth_reply, spawn_server, test_client};
+
+    #[tokio::test]
+    async fn test_mass_quote_requires_connection() {
+        let client = test_client(None);
+
+        let result = client.mass_quote(mass_quote_request()).await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn test_mass_quote_message_flow() {
+        let (url, mut seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            Some("private/mass_quote") => vec![serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": mass_quote_result_json()
+            })],
+            _ => vec![],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let result = client.mass_quote(mass_quote_request()).await.unwrap();
+
+        assert_eq!(result.orders[0].order_id, "BTC-7729");
+        assert_eq!(result.errors[0].side, QuoteSide::Ask);
+
+        let sent = loop {
+            if let Some(Message::Text(text)) = seen.recv().await {
+                let request: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                if request["method"] == "private/mass_quote" {
+                    break request;
+                }
+            }
+        };
+        assert_eq!(sent["params"]["mmp_group"], "btc_options");
+        assert_eq!(sent["params"]["quotes"][0]["bid"]["price"], 0.0405);
+    }
+}
@@ -0,0 +1,156 @@
+//! Request and response structures for the private/subscribe WebSocket method
+//!
+//! Subscribes to one or more channels. Private channels (e.g., `user.orders.*`) require
+//! an authenticated connection; notifications are delivered through `message_stream`.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::PrivateWebSocketClient;
+use crate::deribit::DeribitWebSocketError;
+use crate::deribit::channel::Channel;
+
+/// Request parameters for the private/subscribe method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateSubscribeRequest {
+    /// A list of channels to subscribe to (e.g., "user.orders.BTC-PERPETUAL.raw")
+    pub channels: Vec<String>,
+
+    /// Optional label which will be added to notifications of private channels (max 16 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl PrivateSubscribeRequest {
+    /// Build a request from typed channels, without a label
+    pub fn from_channels(channels: impl IntoIterator<Item = Channel>) -> Self {
+        Self {
+            channels: channels.into_iter().map(String::from).collect(),
+            label: None,
+        }
+    }
+}
+
+impl PrivateWebSocketClient {
+    /// Subscribe to one or more channels
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-subscribe>
+    ///
+    /// # Returns
+    /// The list of channels that were subscribed
+    pub async fn subscribe(&self, request: PrivateSubscribeRequest) -> Result<Vec<String>, DeribitWebSocketError> {
+        self.send_request("private/subscribe", &request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use websockets::WebSocketConnection;
+
+    use super::*;
+    use crate::deribit::private::websocket::DeribitPrivateMessage;
+    use crate::deribit::private::websocket::client::tests::{auth_reply, spawn_server, test_client};
+    use crate::deribit::{ChannelNotification, Notification, OrderState};
+
+    #[test]
+    fn test_subscribe_request_serialization() {
+        let request = PrivateSubscribeRequest {
+            channels: vec!["user.orders.BTC-PERPETUAL.raw".to_string()],
+            label: None,
+        };
+
+        let json = serde_json::to_string(&request).unwrap();
+
+        assert_eq!(json, r#"{"channels":["user.orders.BTC-PERPETUAL.raw"]}"#);
+    }
+
+    #[test]
+    fn test_subscribe_request_with_label() {
+        let request = PrivateSubscribeRequest {
+            channels: vec!["user.trades.any.any.raw".to_string()],
+            label: Some("strategy1".to_string()),
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["label"], "strategy1");
+    }
+
+    #[test]
+    fn test_subscribe_request_from_channels() {
+        let request = PrivateSubscribeRequest::from_channels([Channel::user_portfolio(crate::deribit::Currency::ETH)]);
+
+        assert_eq!(request.channels, vec!["user.portfolio.eth"]);
+        assert!(request.label.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_requires_authentication() {
+        let client = test_client(None);
+        let request = PrivateSubscribeRequest {
+            channels: vec!["user.orders.any.any.raw".to_string()],
+            label: None,
+        };
+
+        let result = client.subscribe(request).await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_message_flow() {
+        let (url, _seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            Some("private/subscribe") => vec![
+                serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": request["params"]["channels"]}),
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "subscription",
+                    "params": {
+                        "channel": "user.orders.BTC-PERPETUAL.raw",
+                        "data": {
+                            "order_id": "ETH-123",
+                            "order_state": "open",
+                            "instrument_name": "BTC-PERPETUAL",
+                            "direction": "buy",
+                            "amount": 10,
+                            "filled_amount": 0,
+                            "price": 65000.0,
+                            "creation_timestamp": 1634138080000i64,
+                            "last_update_timestamp": 1634138080000i64
+                        }
+                    }
+                }),
+            ],
+            _ => vec![],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        let mut stream = client.message_stream();
+        client.connect().await.unwrap();
+
+        let subscribed = client
+            .subscribe(PrivateSubscribeRequest {
+                channels: vec!["user.orders.BTC-PERPETUAL.raw".to_string()],
+                label: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            subscribed,
+            vec!["user.orders.BTC-PERPETUAL.raw".to_string()]
+        );
+
+        match stream.next().await {
+            Some(Ok(DeribitPrivateMessage::Subscription(ChannelNotification {
+                channel,
+                data: Notification::Orders(orders),
+            }))) => {
+                assert_eq!(channel, "user.orders.BTC-PERPETUAL.raw");
+                assert_eq!(orders[0].order_id, "ETH-123");
+                assert_eq!(orders[0].order_state, OrderState::Open);
+            }
+            other => panic!("Expected subscription notification, got {:?}", other),
+        }
+    }
+}
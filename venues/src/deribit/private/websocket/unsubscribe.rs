@@ -0,0 +1,77 @@
+//! Request and response structures for the private/unsubscribe WebSocket method
+//!
+//! Unsubscribes from one or more channels previously subscribed with `private/subscribe`.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::PrivateWebSocketClient;
+use crate::deribit::DeribitWebSocketError;
+use crate::deribit::channel::Channel;
+
+/// Request parameters for the private/unsubscribe method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateUnsubscribeRequest {
+    /// A list of channels to unsubscribe from
+    pub channels: Vec<String>,
+}
+
+impl PrivateUnsubscribeRequest {
+    /// Build a request from typed channels
+    pub fn from_channels(channels: impl IntoIterator<Item = Channel>) -> Self {
+        Self {
+            channels: channels.into_iter().map(String::from).collect(),
+        }
+    }
+}
+
+impl PrivateWebSocketClient {
+    /// Unsubscribe from one or more channels
+    ///
+    /// See: <https://docs.deribit.com/v2/#private-unsubscribe>
+    ///
+    /// # Returns
+    /// The list of channels that were unsubscribed
+    pub async fn unsubscribe(&self, request: PrivateUnsubscribeRequest) -> Result<Vec<String>, DeribitWebSocketError> {
+        self.send_request("private/unsubscribe", &request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use websockets::WebSocketConnection;
+
+    use super::*;
+    use crate::deribit::private::websocket::client::tests::{auth_reply, spawn_server, test_client};
+
+    #[test]
+    fn test_unsubscribe_request_serialization() {
+        let request = PrivateUnsubscribeRequest {
+            channels: vec!["user.portfolio.btc".to_string()],
+        };
+
+        let json = serde_json::to_string(&request).unwrap();
+
+        assert_eq!(json, r#"{"channels":["user.portfolio.btc"]}"#);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_message_flow() {
+        let (url, _seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/auth") => vec![auth_reply(request)],
+            Some("private/unsubscribe") => vec![serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": request["params"]["channels"]})],
+            _ => vec![],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let result = client
+            .unsubscribe(PrivateUnsubscribeRequest {
+                channels: vec!["user.portfolio.btc".to_string()],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result, vec!["user.portfolio.btc".to_string()]);
+    }
+}
@@ -3,11 +3,17 @@
 // Provides access to all public REST API endpoints for Deribit.
 // All requests are unauthenticated and do not require API credentials.
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
-use reqwest::Client;
+use async_trait::async_trait;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::de::DeserializeOwned;
 
-use crate::deribit::{EndpointType, Errors, RateLimiter, RestResult};
+use crate::deribit::errors::VENUE;
+use crate::deribit::{EndpointType, ErrorResponse, Errors, RateLimiter, RestResult};
 
 /// Public REST client for Deribit exchange
 ///
@@ -24,7 +30,7 @@ pub struct RestClient {
     /// The underlying HTTP client used for making requests.
     ///
     /// This is reused for connection pooling and performance.
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits.
     ///
@@ -39,10 +45,10 @@ impl RestClient {
     /// * `base_url` - The base URL for the Deribit public REST API (e.g., "https://www.deribit.com")
     /// * `client` - The HTTP client to use for requests
     /// * `rate_limiter` - The rate limiter for managing API limits
-    pub fn new(base_url: impl Into<Cow<'static, str>>, client: Client, rate_limiter: RateLimiter) -> Self {
+    pub fn new(base_url: impl Into<Cow<'static, str>>, client: impl HttpTransport + 'static, rate_limiter: RateLimiter) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
         }
     }
@@ -62,11 +68,18 @@ impl RestClient {
         T: DeserializeOwned,
         P: serde::Serialize + ?Sized,
     {
-        // Check rate limits before making the request
+        // Check rate limits (or wait for capacity) before making the request
+        let params = params
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Errors::Error(format!("Failed to serialize params: {}", e)))?;
+        let instrument_name = params
+            .as_ref()
+            .and_then(|params| params.get("instrument_name"))
+            .and_then(|name| name.as_str());
         self.rate_limiter
-            .check_limits(endpoint_type)
-            .await
-            .map_err(|e| Errors::Error(e.to_string()))?;
+            .admit(endpoint, endpoint_type, instrument_name)
+            .await?;
 
         // Build the URL - Deribit uses JSON-RPC 2.0 over HTTP
         let url = if endpoint.starts_with("http") {
@@ -75,12 +88,11 @@ impl RestClient {
             format!("{}/api/v2/{}", self.base_url, endpoint)
         };
 
-        // Build the request
-        let mut request_builder = self.client.request(method.clone(), &url);
+        // Build the request with the required headers
+        let mut request = HttpRequest::new(method.clone(), url).header("Content-Type", "application/json");
 
         // Add parameters based on method
-        if let Some(params) = params {
-            let params_value = serde_json::to_value(params).map_err(|e| Errors::Error(format!("Failed to serialize params: {}", e)))?;
+        if let Some(params_value) = params {
             if method == reqwest::Method::GET {
                 // For GET requests, add parameters as query string
                 if let Some(params_obj) = params_value.as_object() {
@@ -92,33 +104,39 @@ impl RestClient {
                             serde_json::Value::Null => continue, // Skip null values
                             _ => value.to_string(),
                         };
-                        request_builder = request_builder.query(&[(key, value_str)]);
+                        request = request
+                            .query(&[(key, value_str)])
+                            .map_err(Errors::HttpError)?;
                     }
                 }
             } else {
                 // For POST requests, add parameters as JSON body
-                request_builder = request_builder.json(&params_value);
+                request = request.json(&params_value).map_err(Errors::HttpError)?;
             }
         }
 
-        // Add required headers
-        request_builder = request_builder.header("Content-Type", "application/json");
-
         // Send the request
-        let response = request_builder.send().await.map_err(Errors::HttpError)?;
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(Errors::HttpError)?;
+
+        let status = response.status;
+        let response_text = response.body;
 
-        // Record the request after successful send
-        self.rate_limiter.record_request(endpoint_type).await;
+        // JSON-RPC errors come with an error status, let the rate limiter see them first
+        if let Some(error) = ErrorResponse::from_body(&response_text) {
+            self.rate_limiter.record_error(error.code).await;
+            return Err(Errors::ApiError(error.into()));
+        }
 
         // Check if the response was successful
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.map_err(Errors::HttpError)?;
-            return Err(Errors::Error(format!("HTTP {}: {}", status, error_text)));
+        if !status.is_success() {
+            return Err(Errors::Error(format!("HTTP {}: {}", status, response_text)));
         }
 
         // Parse the response
-        let response_text = response.text().await.map_err(Errors::HttpError)?;
 
         let parsed_response: T = serde_json::from_str(&response_text).map_err(|e| Errors::Error(format!("Failed to parse response: {}", e)))?;
 
@@ -126,6 +144,37 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,230 @@
+//! Request and response structs for public/get_book_summary_by_currency endpoint
+//!
+//! Retrieves the summary information such as open interest, 24h volume, etc. for all
+//! instruments for the currency (optionally filtered by kind).
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, InstrumentKind, RestResult};
+
+/// Request parameters for the public/get_book_summary_by_currency endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetBookSummaryByCurrencyRequest {
+    /// The currency symbol
+    pub currency: Currency,
+
+    /// Instrument kind, if not provided instruments of all kinds are considered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<InstrumentKind>,
+}
+
+/// Book summary of one instrument, returned by public/get_book_summary_by_currency
+/// and public/get_book_summary_by_instrument
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookSummary {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// Base currency
+    pub base_currency: String,
+
+    /// Quote currency
+    pub quote_currency: String,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub creation_timestamp: i64,
+
+    /// The current best bid price, `None` if there aren't any bids
+    #[serde(default)]
+    pub bid_price: Option<f64>,
+
+    /// The current best ask price, `None` if there aren't any asks
+    #[serde(default)]
+    pub ask_price: Option<f64>,
+
+    /// The average of the best bid and ask, `None` if there aren't any asks or bids
+    #[serde(default)]
+    pub mid_price: Option<f64>,
+
+    /// The current instrument market price
+    pub mark_price: f64,
+
+    /// The price of the latest trade, `None` if there weren't any trades
+    #[serde(default)]
+    pub last: Option<f64>,
+
+    /// Price of the 24h highest trade
+    #[serde(default)]
+    pub high: Option<f64>,
+
+    /// Price of the 24h lowest trade
+    #[serde(default)]
+    pub low: Option<f64>,
+
+    /// 24-hour price change expressed as a percentage
+    #[serde(default)]
+    pub price_change: Option<f64>,
+
+    /// The total amount of outstanding contracts in the corresponding amount units
+    pub open_interest: f64,
+
+    /// The total 24h traded volume (in base currency)
+    pub volume: f64,
+
+    /// Volume in USD
+    #[serde(default)]
+    pub volume_usd: Option<f64>,
+
+    /// Volume in quote currency (futures and spot only)
+    #[serde(default)]
+    pub volume_notional: Option<f64>,
+
+    /// Estimated delivery price for the market (futures and options only)
+    #[serde(default)]
+    pub estimated_delivery_price: Option<f64>,
+
+    /// Current funding (perpetual only)
+    #[serde(default)]
+    pub current_funding: Option<f64>,
+
+    /// Funding 8h (perpetual only)
+    #[serde(default)]
+    pub funding_8h: Option<f64>,
+
+    /// Implied volatility for mark price (options only)
+    #[serde(default)]
+    pub mark_iv: Option<f64>,
+
+    /// Interest rate used in implied volatility calculations (options only)
+    #[serde(default)]
+    pub interest_rate: Option<f64>,
+
+    /// Underlying price for implied volatility calculations (options only)
+    #[serde(default)]
+    pub underlying_price: Option<f64>,
+
+    /// Name of the underlying future, or "index_price" (options only)
+    #[serde(default)]
+    pub underlying_index: Option<String>,
+}
+
+/// Response for public/get_book_summary_by_currency endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetBookSummaryByCurrencyResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Book summaries, one per instrument
+    pub result: Vec<BookSummary>,
+}
+
+impl RestClient {
+    /// Calls the public/get_book_summary_by_currency endpoint.
+    ///
+    /// Retrieves the summary information such as open interest, 24h volume, etc. for
+    /// all instruments for the currency (optionally filtered by kind).
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including currency and optional kind
+    ///
+    /// # Returns
+    /// A result containing the response with book summaries or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_book_summary_by_currency)
+    pub async fn get_book_summary_by_currency(&self, params: GetBookSummaryByCurrencyRequest) -> RestResult<GetBookSummaryByCurrencyResponse> {
+        self.send_request(
+            "public/get_book_summary_by_currency",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_book_summary_by_currency_request_serialization() {
+        let request = GetBookSummaryByCurrencyRequest {
+            currency: Currency::BTC,
+            kind: Some(InstrumentKind::Future),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"currency": "BTC", "kind": "future"}));
+    }
+
+    #[test]
+    fn test_get_book_summary_by_currency_response_structure() {
+        let response_json = json!({
+            "id": 3659,
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "volume": 0.55,
+                    "underlying_price": 121.38,
+                    "underlying_index": "index_price",
+                    "quote_currency": "USD",
+                    "price_change": -26.7793594,
+                    "open_interest": 0.55,
+                    "mid_price": 0.2444,
+                    "mark_price": 80.0,
+                    "low": 0.34,
+                    "last": 0.34,
+                    "interest_rate": 0.207,
+                    "instrument_name": "ETH-22FEB19-140-P",
+                    "high": 0.34,
+                    "creation_timestamp": 1550227952163i64,
+                    "bid_price": 0.1488,
+                    "base_currency": "ETH",
+                    "ask_price": 0.34
+                },
+                {
+                    "volume": 10.0,
+                    "quote_currency": "USD",
+                    "open_interest": 1000.0,
+                    "mark_price": 3500.0,
+                    "instrument_name": "ETH-PERPETUAL",
+                    "creation_timestamp": 1550227952163i64,
+                    "base_currency": "ETH",
+                    "funding_8h": 0.0001,
+                    "current_funding": 0.0
+                }
+            ]
+        });
+
+        let response: GetBookSummaryByCurrencyResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 2);
+        assert_eq!(
+            response.result[0].underlying_index.as_deref(),
+            Some("index_price")
+        );
+        assert_eq!(response.result[0].mid_price, Some(0.2444));
+        assert!(response.result[1].bid_price.is_none());
+        assert_eq!(response.result[1].funding_8h, Some(0.0001));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,121 @@
+//! Request and response structs for public/get_book_summary_by_instrument endpoint
+//!
+//! Retrieves the summary information such as open interest, 24h volume, etc. for a
+//! specific instrument.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+pub use super::get_book_summary_by_currency::BookSummary;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for the public/get_book_summary_by_instrument endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetBookSummaryByInstrumentRequest {
+    /// Instrument name
+    pub instrument_name: String,
+}
+
+/// Response for public/get_book_summary_by_instrument endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetBookSummaryByInstrumentResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Book summaries (a single element for the requested instrument)
+    pub result: Vec<BookSummary>,
+}
+
+impl RestClient {
+    /// Calls the public/get_book_summary_by_instrument endpoint.
+    ///
+    /// Retrieves the summary information such as open interest, 24h volume, etc. for a
+    /// specific instrument.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters with the instrument name
+    ///
+    /// # Returns
+    /// A result containing the response with the book summary or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_book_summary_by_instrument)
+    pub async fn get_book_summary_by_instrument(&self, params: GetBookSummaryByInstrumentRequest) -> RestResult<GetBookSummaryByInstrumentResponse> {
+        self.send_request(
+            "public/get_book_summary_by_instrument",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_book_summary_by_instrument_request_serialization() {
+        let request = GetBookSummaryByInstrumentRequest {
+            instrument_name: "ETH-22FEB19-140-P".to_string(),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"instrument_name": "ETH-22FEB19-140-P"}));
+    }
+
+    #[test]
+    fn test_get_book_summary_by_instrument_response_structure() {
+        let response_json = json!({
+            "id": 3659,
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "volume": 0.55,
+                    "underlying_price": 121.38,
+                    "underlying_index": "index_price",
+                    "quote_currency": "USD",
+                    "price_change": -26.7793594,
+                    "open_interest": 0.55,
+                    "mid_price": 0.2444,
+                    "mark_price": 80.0,
+                    "mark_iv": 72.5,
+                    "low": 0.34,
+                    "last": 0.34,
+                    "interest_rate": 0.207,
+                    "instrument_name": "ETH-22FEB19-140-P",
+                    "high": 0.34,
+                    "creation_timestamp": 1550227952163i64,
+                    "bid_price": 0.1488,
+                    "base_currency": "ETH",
+                    "ask_price": 0.34
+                }
+            ]
+        });
+
+        let response: GetBookSummaryByInstrumentResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 1);
+        assert_eq!(response.result[0].instrument_name, "ETH-22FEB19-140-P");
+        assert_eq!(response.result[0].mark_iv, Some(72.5));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
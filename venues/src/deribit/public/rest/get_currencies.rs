@@ -0,0 +1,179 @@
+//! Request and response structs for public/get_currencies endpoint
+//!
+//! Retrieves all cryptocurrencies supported by the API.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for the public/get_currencies endpoint (no parameters).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetCurrenciesRequest {}
+
+/// Withdrawal priority available for a currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyWithdrawalPriority {
+    /// Name of the priority
+    pub name: String,
+
+    /// Priority value
+    pub value: f64,
+}
+
+/// Currency information returned by public/get_currencies
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyInfo {
+    /// The abbreviation of the currency, e.g. "BTC"
+    pub currency: String,
+
+    /// The full name of the currency
+    pub currency_long: String,
+
+    /// Fee precision
+    #[serde(default)]
+    pub fee_precision: Option<i64>,
+
+    /// Minimum number of block chain confirmations before deposit is accepted
+    #[serde(default)]
+    pub min_confirmations: Option<i64>,
+
+    /// The minimum transaction fee paid for withdrawals
+    #[serde(default)]
+    pub min_withdrawal_fee: Option<f64>,
+
+    /// The total transaction fee paid for withdrawals
+    #[serde(default)]
+    pub withdrawal_fee: Option<f64>,
+
+    /// Withdrawal priorities available for the currency
+    #[serde(default)]
+    pub withdrawal_priorities: Vec<CurrencyWithdrawalPriority>,
+
+    /// The type of the currency (e.g. "BTC", "ETH", "TOKEN")
+    #[serde(default)]
+    pub coin_type: Option<String>,
+
+    /// Network fee
+    #[serde(default)]
+    pub network_fee: Option<f64>,
+
+    /// Network currency
+    #[serde(default)]
+    pub network_currency: Option<String>,
+
+    /// Decimal places of the currency
+    #[serde(default)]
+    pub decimals: Option<i64>,
+
+    /// Annual percentage yield for yield-bearing currencies
+    #[serde(default)]
+    pub apr: Option<f64>,
+
+    /// Whether the currency is part of the cross collateral pool
+    #[serde(default)]
+    pub in_cross_collateral_pool: Option<bool>,
+}
+
+/// Response for public/get_currencies endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetCurrenciesResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Array of supported currencies
+    pub result: Vec<CurrencyInfo>,
+}
+
+impl RestClient {
+    /// Calls the public/get_currencies endpoint.
+    ///
+    /// Retrieves all cryptocurrencies supported by the API.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (empty)
+    ///
+    /// # Returns
+    /// A result containing the response with currencies or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_currencies)
+    pub async fn get_currencies(&self, params: GetCurrenciesRequest) -> RestResult<GetCurrenciesResponse> {
+        self.send_request(
+            "public/get_currencies",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_currencies_request_serialization() {
+        let json_value = serde_json::to_value(GetCurrenciesRequest::default()).unwrap();
+        assert_eq!(json_value, json!({}));
+    }
+
+    #[test]
+    fn test_get_currencies_response_structure() {
+        let response_json = json!({
+            "id": 7538,
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "withdrawal_priorities": [
+                        {"value": 0.15, "name": "very_low"},
+                        {"value": 1.5, "name": "very_high"}
+                    ],
+                    "withdrawal_fee": 0.0001,
+                    "min_withdrawal_fee": 0.0001,
+                    "min_confirmations": 1,
+                    "fee_precision": 4,
+                    "currency_long": "Bitcoin",
+                    "currency": "BTC",
+                    "coin_type": "BITCOIN"
+                },
+                {
+                    "currency_long": "USD Coin",
+                    "currency": "USDC",
+                    "decimals": 6
+                }
+            ]
+        });
+
+        let response: GetCurrenciesResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 2);
+        assert_eq!(response.result[0].currency, "BTC");
+        assert_eq!(response.result[0].withdrawal_priorities.len(), 2);
+        assert_eq!(
+            response.result[0].withdrawal_priorities[1].name,
+            "very_high"
+        );
+        assert_eq!(response.result[1].decimals, Some(6));
+        assert!(response.result[1].withdrawal_priorities.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,136 @@
+//! Request and response structs for public/get_delivery_prices endpoint
+//!
+//! Retrieves delivery prices for the given index.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{CurrencyPair, EndpointType, RestResult};
+
+/// Request parameters for the public/get_delivery_prices endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDeliveryPricesRequest {
+    /// Index identifier, matches (base) cryptocurrency with quote currency
+    pub index_name: CurrencyPair,
+
+    /// The offset for pagination, default - 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+
+    /// Number of requested items, default - 10, maximum - 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+/// Delivery price of one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryPrice {
+    /// The event date with year, month and day (e.g. "2020-01-02")
+    pub date: String,
+
+    /// The settlement price for the instrument
+    pub delivery_price: f64,
+}
+
+/// Result data for public/get_delivery_prices
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryPrices {
+    /// Delivery prices, most recent first
+    pub data: Vec<DeliveryPrice>,
+
+    /// Available delivery prices
+    pub records_total: i64,
+}
+
+/// Response for public/get_delivery_prices endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetDeliveryPricesResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The page of delivery prices
+    pub result: DeliveryPrices,
+}
+
+impl RestClient {
+    /// Calls the public/get_delivery_prices endpoint.
+    ///
+    /// Retrieves delivery prices for the given index.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including index name and optional pagination
+    ///
+    /// # Returns
+    /// A result containing the response with delivery prices or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_delivery_prices)
+    pub async fn get_delivery_prices(&self, params: GetDeliveryPricesRequest) -> RestResult<GetDeliveryPricesResponse> {
+        self.send_request(
+            "public/get_delivery_prices",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_delivery_prices_request_serialization() {
+        let request = GetDeliveryPricesRequest {
+            index_name: CurrencyPair::BtcUsd,
+            offset: Some(0),
+            count: Some(5),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"index_name": "btc_usd", "offset": 0, "count": 5})
+        );
+    }
+
+    #[test]
+    fn test_get_delivery_prices_response_structure() {
+        let response_json = json!({
+            "id": 3601,
+            "jsonrpc": "2.0",
+            "result": {
+                "data": [
+                    {"date": "2020-01-02", "delivery_price": 7131.214606410254},
+                    {"date": "2019-12-21", "delivery_price": 7150.943217777777}
+                ],
+                "records_total": 58
+            }
+        });
+
+        let response: GetDeliveryPricesResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.records_total, 58);
+        assert_eq!(response.result.data.len(), 2);
+        assert_eq!(response.result.data[0].date, "2020-01-02");
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
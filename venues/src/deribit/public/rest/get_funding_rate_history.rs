@@ -0,0 +1,136 @@
+//! Request and response structs for public/get_funding_rate_history endpoint
+//!
+//! Retrieves hourly historical interest rates for the requested perpetual instrument.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for the public/get_funding_rate_history endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetFundingRateHistoryRequest {
+    /// Instrument name (a perpetual)
+    pub instrument_name: String,
+
+    /// The earliest timestamp to return result from (milliseconds since the UNIX epoch)
+    pub start_timestamp: i64,
+
+    /// The most recent timestamp to return result from (milliseconds since the UNIX epoch)
+    pub end_timestamp: i64,
+}
+
+/// One hourly funding rate record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundingRate {
+    /// The timestamp of the record (milliseconds since the UNIX epoch)
+    pub timestamp: i64,
+
+    /// Price in base currency at the timestamp
+    pub index_price: f64,
+
+    /// Price in base currency at the previous timestamp
+    pub prev_index_price: f64,
+
+    /// 8 hour interest rate
+    pub interest_8h: f64,
+
+    /// 1 hour interest rate
+    pub interest_1h: f64,
+}
+
+/// Response for public/get_funding_rate_history endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetFundingRateHistoryResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Funding rate records in chronological order
+    pub result: Vec<FundingRate>,
+}
+
+impl RestClient {
+    /// Calls the public/get_funding_rate_history endpoint.
+    ///
+    /// Retrieves hourly historical interest rates for the requested perpetual instrument.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including instrument name and time range
+    ///
+    /// # Returns
+    /// A result containing the response with funding rate records or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_funding_rate_history)
+    pub async fn get_funding_rate_history(&self, params: GetFundingRateHistoryRequest) -> RestResult<GetFundingRateHistoryResponse> {
+        self.send_request(
+            "public/get_funding_rate_history",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_funding_rate_history_request_serialization() {
+        let request = GetFundingRateHistoryRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+            start_timestamp: 1569888000000,
+            end_timestamp: 1569902400000,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"instrument_name": "BTC-PERPETUAL", "start_timestamp": 1569888000000i64, "end_timestamp": 1569902400000i64})
+        );
+    }
+
+    #[test]
+    fn test_get_funding_rate_history_response_structure() {
+        let response_json = json!({
+            "id": 7617,
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "timestamp": 1569891600000i64,
+                    "index_price": 8222.87,
+                    "prev_index_price": 8305.72,
+                    "interest_8h": -0.00009234260068476106,
+                    "interest_1h": -4.739622041017375e-7
+                }
+            ]
+        });
+
+        let response: GetFundingRateHistoryResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 1);
+        assert_eq!(response.result[0].timestamp, 1569891600000);
+        assert_eq!(response.result[0].prev_index_price, 8305.72);
+        assert!(response.result[0].interest_8h < 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
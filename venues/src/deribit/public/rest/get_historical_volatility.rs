@@ -0,0 +1,121 @@
+//! Request and response structs for public/get_historical_volatility endpoint
+//!
+//! Provides information about historical volatility for a given cryptocurrency.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, RestResult};
+
+/// Request parameters for the public/get_historical_volatility endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetHistoricalVolatilityRequest {
+    /// The currency symbol
+    pub currency: Currency,
+}
+
+/// Historical volatility data point, sent as `[timestamp, value]`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(from = "(i64, f64)")]
+pub struct HistoricalVolatility {
+    /// Timestamp of the data point (milliseconds since the UNIX epoch)
+    pub timestamp: i64,
+
+    /// Volatility value
+    pub volatility: f64,
+}
+
+impl From<(i64, f64)> for HistoricalVolatility {
+    fn from((timestamp, volatility): (i64, f64)) -> Self {
+        Self {
+            timestamp,
+            volatility,
+        }
+    }
+}
+
+/// Response for public/get_historical_volatility endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetHistoricalVolatilityResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Hourly volatility data points in chronological order
+    pub result: Vec<HistoricalVolatility>,
+}
+
+impl RestClient {
+    /// Calls the public/get_historical_volatility endpoint.
+    ///
+    /// Provides information about historical volatility for a given cryptocurrency.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters with the currency
+    ///
+    /// # Returns
+    /// A result containing the response with volatility data points or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_historical_volatility)
+    pub async fn get_historical_volatility(&self, params: GetHistoricalVolatilityRequest) -> RestResult<GetHistoricalVolatilityResponse> {
+        self.send_request(
+            "public/get_historical_volatility",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_historical_volatility_request_serialization() {
+        let request = GetHistoricalVolatilityRequest {
+            currency: Currency::ETH,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"currency": "ETH"}));
+    }
+
+    #[test]
+    fn test_get_historical_volatility_response_structure() {
+        let response_json = json!({
+            "id": 8387,
+            "jsonrpc": "2.0",
+            "result": [
+                [1549720800000i64, 14.747743607344217],
+                [1549720800000i64, 14.747743607344217],
+                [1549724400000i64, 14.74257778551467]
+            ]
+        });
+
+        let response: GetHistoricalVolatilityResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 3);
+        assert_eq!(response.result[2].timestamp, 1549724400000);
+        assert_eq!(response.result[2].volatility, 14.74257778551467);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
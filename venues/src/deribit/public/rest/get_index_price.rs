@@ -0,0 +1,109 @@
+//! Request and response structs for public/get_index_price endpoint
+//!
+//! Retrieves the current index price value for a given index name.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{CurrencyPair, EndpointType, RestResult};
+
+/// Request parameters for the public/get_index_price endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetIndexPriceRequest {
+    /// Index identifier, matches (base) cryptocurrency with quote currency
+    pub index_name: CurrencyPair,
+}
+
+/// Index price value returned by public/get_index_price
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexPrice {
+    /// Value of requested index
+    pub index_price: f64,
+
+    /// Estimated delivery price for the market
+    pub estimated_delivery_price: f64,
+}
+
+/// Response for public/get_index_price endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetIndexPriceResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The index price
+    pub result: IndexPrice,
+}
+
+impl RestClient {
+    /// Calls the public/get_index_price endpoint.
+    ///
+    /// Retrieves the current index price value for a given index name.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters with the index name
+    ///
+    /// # Returns
+    /// A result containing the response with the index price or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_index_price)
+    pub async fn get_index_price(&self, params: GetIndexPriceRequest) -> RestResult<GetIndexPriceResponse> {
+        self.send_request(
+            "public/get_index_price",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_index_price_request_serialization() {
+        let request = GetIndexPriceRequest {
+            index_name: CurrencyPair::EthUsd,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"index_name": "eth_usd"}));
+    }
+
+    #[test]
+    fn test_get_index_price_response_structure() {
+        let response_json = json!({
+            "id": 1,
+            "jsonrpc": "2.0",
+            "result": {
+                "estimated_delivery_price": 11628.81,
+                "index_price": 11628.81
+            }
+        });
+
+        let response: GetIndexPriceResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.index_price, 11628.81);
+        assert_eq!(response.result.estimated_delivery_price, 11628.81);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
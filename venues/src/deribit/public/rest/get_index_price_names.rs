@@ -0,0 +1,94 @@
+//! Request and response structs for public/get_index_price_names endpoint
+//!
+//! Retrieves the identifiers of all supported price indexes.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for the public/get_index_price_names endpoint (no parameters).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetIndexPriceNamesRequest {}
+
+/// Response for public/get_index_price_names endpoint following Deribit JSON-RPC 2.0 format.
+///
+/// Index names are returned as plain strings so that newly listed indexes do not
+/// break deserialization; known names parse into [`crate::deribit::CurrencyPair`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetIndexPriceNamesResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Array of index names
+    pub result: Vec<String>,
+}
+
+impl RestClient {
+    /// Calls the public/get_index_price_names endpoint.
+    ///
+    /// Retrieves the identifiers of all supported price indexes.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters (empty)
+    ///
+    /// # Returns
+    /// A result containing the response with index names or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_index_price_names)
+    pub async fn get_index_price_names(&self, params: GetIndexPriceNamesRequest) -> RestResult<GetIndexPriceNamesResponse> {
+        self.send_request(
+            "public/get_index_price_names",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, CurrencyPair, RateLimiter};
+
+    #[test]
+    fn test_get_index_price_names_request_serialization() {
+        let json_value = serde_json::to_value(GetIndexPriceNamesRequest::default()).unwrap();
+        assert_eq!(json_value, json!({}));
+    }
+
+    #[test]
+    fn test_get_index_price_names_response_structure() {
+        let response_json = json!({
+            "id": 25718,
+            "jsonrpc": "2.0",
+            "result": ["btc_eth", "btc_usdc", "eth_usdc"]
+        });
+
+        let response: GetIndexPriceNamesResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 3);
+
+        let pair: CurrencyPair = serde_json::from_value(json!(response.result[1])).unwrap();
+        assert_eq!(pair, CurrencyPair::BtcUsdc);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
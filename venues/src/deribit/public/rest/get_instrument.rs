@@ -0,0 +1,114 @@
+//! Request and response structs for public/get_instrument endpoint
+//!
+//! Retrieves information about a single instrument.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+pub use super::get_instruments::Instrument;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for the public/get_instrument endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetInstrumentRequest {
+    /// Instrument name
+    pub instrument_name: String,
+}
+
+/// Response for public/get_instrument endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetInstrumentResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The instrument definition
+    pub result: Instrument,
+}
+
+impl RestClient {
+    /// Calls the public/get_instrument endpoint.
+    ///
+    /// Retrieves information about a single instrument.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters with the instrument name
+    ///
+    /// # Returns
+    /// A result containing the response with the instrument or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_instrument)
+    pub async fn get_instrument(&self, params: GetInstrumentRequest) -> RestResult<GetInstrumentResponse> {
+        self.send_request(
+            "public/get_instrument",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, InstrumentKind, RateLimiter};
+
+    #[test]
+    fn test_get_instrument_request_serialization() {
+        let request = GetInstrumentRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"instrument_name": "BTC-PERPETUAL"}));
+    }
+
+    #[test]
+    fn test_get_instrument_response_structure() {
+        let response_json = json!({
+            "id": 2,
+            "jsonrpc": "2.0",
+            "result": {
+                "tick_size": 0.0001,
+                "taker_commission": 0.0,
+                "settlement_currency": "USDC",
+                "quote_currency": "USDC",
+                "min_trade_amount": 0.0001,
+                "maker_commission": 0.0,
+                "kind": "spot",
+                "is_active": true,
+                "instrument_name": "ETH_USDC",
+                "instrument_id": 210839,
+                "expiration_timestamp": 32503708800000i64,
+                "creation_timestamp": 1671696000000i64,
+                "contract_size": 0.0001,
+                "base_currency": "ETH"
+            }
+        });
+
+        let response: GetInstrumentResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.id, 2);
+        assert_eq!(response.result.instrument_name, "ETH_USDC");
+        assert_eq!(response.result.kind, InstrumentKind::Spot);
+        assert!(response.result.settlement_period.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,292 @@
+//! Request and response structs for public/get_instruments endpoint
+//!
+//! Retrieves available trading instruments. This method can be used to see which
+//! instruments are available for trading, or which instruments have recently expired.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, InstrumentKind, InstrumentType, OptionType, RestResult, SettlementPeriod};
+
+/// Request parameters for the public/get_instruments endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetInstrumentsRequest {
+    /// The currency symbol or "any" for all
+    pub currency: Currency,
+
+    /// Instrument kind, if not provided instruments of all kinds are considered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<InstrumentKind>,
+
+    /// Set to true to show recently expired instruments instead of active ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired: Option<bool>,
+}
+
+/// A tick size that applies above a given price
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickSizeStep {
+    /// The price from which the increased tick size applies
+    pub above_price: f64,
+
+    /// Tick size to be used above the price
+    pub tick_size: f64,
+}
+
+/// Trading instrument definition returned by public/get_instruments and public/get_instrument
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Instrument {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// Instrument ID
+    pub instrument_id: i64,
+
+    /// Instrument kind
+    pub kind: InstrumentKind,
+
+    /// The underlying currency being traded
+    pub base_currency: String,
+
+    /// The currency in which the instrument prices are quoted
+    pub quote_currency: String,
+
+    /// Counter currency for the instrument
+    #[serde(default)]
+    pub counter_currency: Option<String>,
+
+    /// Settlement currency for the instrument (not present for spot)
+    #[serde(default)]
+    pub settlement_currency: Option<String>,
+
+    /// Contract size for futures and options; base currency amount for spot
+    pub contract_size: f64,
+
+    /// Minimum amount for trading
+    pub min_trade_amount: f64,
+
+    /// Specifies minimal price change and the number of decimal places for instrument prices
+    pub tick_size: f64,
+
+    /// Increased tick sizes that apply above given prices
+    #[serde(default)]
+    pub tick_size_steps: Vec<TickSizeStep>,
+
+    /// Maker commission for instrument
+    pub maker_commission: f64,
+
+    /// Taker commission for instrument
+    pub taker_commission: f64,
+
+    /// Block trade commission for instrument
+    #[serde(default)]
+    pub block_trade_commission: Option<f64>,
+
+    /// Minimum amount for block trading
+    #[serde(default)]
+    pub block_trade_min_trade_amount: Option<f64>,
+
+    /// Specifies minimal price change for block trading
+    #[serde(default)]
+    pub block_trade_tick_size: Option<f64>,
+
+    /// The time when the instrument was first created (milliseconds since the UNIX epoch)
+    pub creation_timestamp: i64,
+
+    /// The time when the instrument will expire (milliseconds since the UNIX epoch)
+    pub expiration_timestamp: i64,
+
+    /// Indicates if the instrument can currently be traded
+    pub is_active: bool,
+
+    /// The option type (only for options)
+    #[serde(default)]
+    pub option_type: Option<OptionType>,
+
+    /// The strike value (only for options)
+    #[serde(default)]
+    pub strike: Option<f64>,
+
+    /// The settlement period (not present for spot)
+    #[serde(default)]
+    pub settlement_period: Option<SettlementPeriod>,
+
+    /// Whether the instrument is linear or reversed (futures and options)
+    #[serde(default)]
+    pub instrument_type: Option<InstrumentType>,
+
+    /// Maximal leverage for instrument (only for futures)
+    #[serde(default)]
+    pub max_leverage: Option<i64>,
+
+    /// Maximal liquidation trade commission for instrument (only for futures)
+    #[serde(default)]
+    pub max_liquidation_commission: Option<f64>,
+
+    /// Whether or not RFQ is active on the instrument
+    #[serde(default)]
+    pub rfq: Option<bool>,
+
+    /// Name of price index that is used for this instrument
+    #[serde(default)]
+    pub price_index: Option<String>,
+}
+
+/// Response for public/get_instruments endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetInstrumentsResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// Array of instruments
+    pub result: Vec<Instrument>,
+}
+
+impl RestClient {
+    /// Calls the public/get_instruments endpoint.
+    ///
+    /// Retrieves available trading instruments. This endpoint has its own rate limit
+    /// (1 request per 10 seconds with a burst of 5).
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including currency, optional kind and expired flag
+    ///
+    /// # Returns
+    /// A result containing the response with instruments or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_instruments)
+    pub async fn get_instruments(&self, params: GetInstrumentsRequest) -> RestResult<GetInstrumentsResponse> {
+        self.send_request(
+            "public/get_instruments",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::PublicGetInstruments,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_instruments_request_serialization() {
+        let request = GetInstrumentsRequest {
+            currency: Currency::BTC,
+            kind: Some(InstrumentKind::Option),
+            expired: Some(false),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value["currency"], "BTC");
+        assert_eq!(json_value["kind"], "option");
+        assert_eq!(json_value["expired"], false);
+    }
+
+    #[test]
+    fn test_get_instruments_request_minimal() {
+        let request = GetInstrumentsRequest {
+            currency: Currency::Any,
+            kind: None,
+            expired: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"currency": "any"}));
+    }
+
+    #[test]
+    fn test_get_instruments_response_structure() {
+        let response_json = json!({
+            "id": 1,
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "tick_size": 0.0005,
+                    "tick_size_steps": [{"above_price": 0.005, "tick_size": 0.0005}],
+                    "taker_commission": 0.0003,
+                    "strike": 60000.0,
+                    "settlement_period": "month",
+                    "settlement_currency": "BTC",
+                    "rfq": false,
+                    "quote_currency": "BTC",
+                    "price_index": "btc_usd",
+                    "option_type": "call",
+                    "min_trade_amount": 0.1,
+                    "maker_commission": 0.0003,
+                    "kind": "option",
+                    "is_active": true,
+                    "instrument_name": "BTC-27JUN25-60000-C",
+                    "instrument_id": 124006,
+                    "expiration_timestamp": 1751011200000i64,
+                    "creation_timestamp": 1719475200000i64,
+                    "counter_currency": "USD",
+                    "contract_size": 1.0,
+                    "block_trade_tick_size": 0.0001,
+                    "block_trade_min_trade_amount": 25,
+                    "block_trade_commission": 0.00015,
+                    "base_currency": "BTC"
+                },
+                {
+                    "tick_size": 0.5,
+                    "taker_commission": 0.0005,
+                    "settlement_period": "perpetual",
+                    "settlement_currency": "BTC",
+                    "quote_currency": "USD",
+                    "min_trade_amount": 10.0,
+                    "max_liquidation_commission": 0.0075,
+                    "max_leverage": 50,
+                    "maker_commission": 0.0,
+                    "kind": "future",
+                    "is_active": true,
+                    "instrument_name": "BTC-PERPETUAL",
+                    "instrument_id": 124972,
+                    "instrument_type": "reversed",
+                    "expiration_timestamp": 32503708800000i64,
+                    "creation_timestamp": 1534242287000i64,
+                    "contract_size": 10.0,
+                    "base_currency": "BTC"
+                }
+            ]
+        });
+
+        let response: GetInstrumentsResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.len(), 2);
+
+        let option = &response.result[0];
+        assert_eq!(option.kind, InstrumentKind::Option);
+        assert_eq!(option.option_type, Some(OptionType::Call));
+        assert_eq!(option.strike, Some(60000.0));
+        assert_eq!(option.settlement_period, Some(SettlementPeriod::Month));
+        assert_eq!(option.tick_size_steps.len(), 1);
+
+        let perpetual = &response.result[1];
+        assert_eq!(perpetual.instrument_name, "BTC-PERPETUAL");
+        assert_eq!(perpetual.instrument_type, Some(InstrumentType::Reversed));
+        assert_eq!(perpetual.max_leverage, Some(50));
+        assert!(perpetual.option_type.is_none());
+        assert!(perpetual.tick_size_steps.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        // get_instruments has its own time-based limit rather than consuming credits
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::PublicGetInstruments)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,155 @@
+//! Request and response structs for public/get_last_trades_by_currency endpoint
+//!
+//! Retrieves the latest trades that have occurred for instruments in a specific currency.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+pub use super::get_last_trades_by_instrument::LastTradesResult;
+use crate::deribit::{Currency, EndpointType, InstrumentKind, RestResult, Sorting};
+
+/// Request parameters for the public/get_last_trades_by_currency endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetLastTradesByCurrencyRequest {
+    /// The currency symbol
+    pub currency: Currency,
+
+    /// Instrument kind, if not provided instruments of all kinds are considered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<InstrumentKind>,
+
+    /// The ID of the first trade to be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_id: Option<String>,
+
+    /// The ID of the last trade to be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_id: Option<String>,
+
+    /// The earliest timestamp to return result from (milliseconds since the UNIX epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timestamp: Option<i64>,
+
+    /// The most recent timestamp to return result from (milliseconds since the UNIX epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_timestamp: Option<i64>,
+
+    /// Number of requested items, default - 10, maximum - 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// Direction of results sorting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sorting: Option<Sorting>,
+}
+
+/// Response for public/get_last_trades_by_currency endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetLastTradesByCurrencyResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The page of trades
+    pub result: LastTradesResult,
+}
+
+impl RestClient {
+    /// Calls the public/get_last_trades_by_currency endpoint.
+    ///
+    /// Retrieves the latest trades that have occurred for instruments in a specific currency.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including currency and optional kind, id and time bounds
+    ///
+    /// # Returns
+    /// A result containing the response with trades or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_last_trades_by_currency)
+    pub async fn get_last_trades_by_currency(&self, params: GetLastTradesByCurrencyRequest) -> RestResult<GetLastTradesByCurrencyResponse> {
+        self.send_request(
+            "public/get_last_trades_by_currency",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_last_trades_by_currency_request_serialization() {
+        let request = GetLastTradesByCurrencyRequest {
+            currency: Currency::BTC,
+            kind: Some(InstrumentKind::Option),
+            start_id: None,
+            end_id: None,
+            start_timestamp: None,
+            end_timestamp: None,
+            count: Some(50),
+            sorting: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"currency": "BTC", "kind": "option", "count": 50})
+        );
+    }
+
+    #[test]
+    fn test_get_last_trades_by_currency_response_structure() {
+        let response_json = json!({
+            "id": 9290,
+            "jsonrpc": "2.0",
+            "result": {
+                "trades": [
+                    {
+                        "trade_seq": 3471,
+                        "trade_id": "48077291",
+                        "timestamp": 1590484512188i64,
+                        "tick_direction": 2,
+                        "price": 0.0075,
+                        "mark_price": 0.01062686,
+                        "iv": 47.58,
+                        "instrument_name": "BTC-27NOV20-17000-C",
+                        "index_price": 8956.17,
+                        "direction": "sell",
+                        "amount": 3.0,
+                        "block_trade_id": "154"
+                    }
+                ],
+                "has_more": true
+            }
+        });
+
+        let response: GetLastTradesByCurrencyResponse = serde_json::from_value(response_json).unwrap();
+        let trade = &response.result.trades[0];
+        assert_eq!(trade.instrument_name, "BTC-27NOV20-17000-C");
+        assert_eq!(trade.iv, Some(47.58));
+        assert_eq!(trade.block_trade_id.as_deref(), Some("154"));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,161 @@
+//! Request and response structs for public/get_last_trades_by_instrument endpoint
+//!
+//! Retrieves the latest trades that have occurred for a specific instrument.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, PublicTrade, RestResult, Sorting};
+
+/// Request parameters for the public/get_last_trades_by_instrument endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetLastTradesByInstrumentRequest {
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// The sequence number of the first trade to be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_seq: Option<i64>,
+
+    /// The sequence number of the last trade to be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_seq: Option<i64>,
+
+    /// The earliest timestamp to return result from (milliseconds since the UNIX epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timestamp: Option<i64>,
+
+    /// The most recent timestamp to return result from (milliseconds since the UNIX epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_timestamp: Option<i64>,
+
+    /// Number of requested items, default - 10, maximum - 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// Direction of results sorting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sorting: Option<Sorting>,
+}
+
+/// A page of public trades returned by the public/get_last_trades_* endpoints
+#[derive(Debug, Clone, Deserialize)]
+pub struct LastTradesResult {
+    /// Array of trades
+    pub trades: Vec<PublicTrade>,
+
+    /// Whether there are more trades available
+    pub has_more: bool,
+}
+
+/// Response for public/get_last_trades_by_instrument endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetLastTradesByInstrumentResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The page of trades
+    pub result: LastTradesResult,
+}
+
+impl RestClient {
+    /// Calls the public/get_last_trades_by_instrument endpoint.
+    ///
+    /// Retrieves the latest trades that have occurred for a specific instrument.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including instrument name and optional sequence/time bounds
+    ///
+    /// # Returns
+    /// A result containing the response with trades or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_last_trades_by_instrument)
+    pub async fn get_last_trades_by_instrument(&self, params: GetLastTradesByInstrumentRequest) -> RestResult<GetLastTradesByInstrumentResponse> {
+        self.send_request(
+            "public/get_last_trades_by_instrument",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, OrderDirection, RateLimiter, TickDirection};
+
+    #[test]
+    fn test_get_last_trades_by_instrument_request_serialization() {
+        let request = GetLastTradesByInstrumentRequest {
+            instrument_name: "ETH-PERPETUAL".to_string(),
+            start_seq: Some(100),
+            end_seq: None,
+            start_timestamp: None,
+            end_timestamp: None,
+            count: Some(1),
+            sorting: Some(Sorting::Desc),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json_value,
+            json!({"instrument_name": "ETH-PERPETUAL", "start_seq": 100, "count": 1, "sorting": "desc"})
+        );
+    }
+
+    #[test]
+    fn test_get_last_trades_by_instrument_response_structure() {
+        let response_json = json!({
+            "id": 9267,
+            "jsonrpc": "2.0",
+            "result": {
+                "trades": [
+                    {
+                        "trade_seq": 36798,
+                        "trade_id": "ETH-2696097",
+                        "timestamp": 1590484156350i64,
+                        "tick_direction": 0,
+                        "price": 202.8,
+                        "mark_price": 202.79,
+                        "instrument_name": "ETH-PERPETUAL",
+                        "index_price": 202.79,
+                        "direction": "sell",
+                        "amount": 1.0,
+                        "contracts": 1.0
+                    }
+                ],
+                "has_more": true
+            }
+        });
+
+        let response: GetLastTradesByInstrumentResponse = serde_json::from_value(response_json).unwrap();
+        assert!(response.result.has_more);
+        assert_eq!(response.result.trades.len(), 1);
+
+        let trade = &response.result.trades[0];
+        assert_eq!(trade.trade_id, "ETH-2696097");
+        assert_eq!(trade.tick_direction, TickDirection::PlusTick);
+        assert_eq!(trade.direction, OrderDirection::Sell);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,143 @@
+//! Request and response structs for public/get_last_trades_by_instrument_and_time endpoint
+//!
+//! Retrieves the latest trades that have occurred for a specific instrument and within
+//! a given time range.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+pub use super::get_last_trades_by_instrument::LastTradesResult;
+use crate::deribit::{EndpointType, RestResult, Sorting};
+
+/// Request parameters for the public/get_last_trades_by_instrument_and_time endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetLastTradesByInstrumentAndTimeRequest {
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// The earliest timestamp to return result from (milliseconds since the UNIX epoch)
+    pub start_timestamp: i64,
+
+    /// The most recent timestamp to return result from (milliseconds since the UNIX epoch)
+    pub end_timestamp: i64,
+
+    /// Number of requested items, default - 10, maximum - 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// Direction of results sorting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sorting: Option<Sorting>,
+}
+
+/// Response for public/get_last_trades_by_instrument_and_time endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetLastTradesByInstrumentAndTimeResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The page of trades
+    pub result: LastTradesResult,
+}
+
+impl RestClient {
+    /// Calls the public/get_last_trades_by_instrument_and_time endpoint.
+    ///
+    /// Retrieves the latest trades that have occurred for a specific instrument and
+    /// within a given time range.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including instrument name and time range
+    ///
+    /// # Returns
+    /// A result containing the response with trades or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_last_trades_by_instrument_and_time)
+    pub async fn get_last_trades_by_instrument_and_time(
+        &self,
+        params: GetLastTradesByInstrumentAndTimeRequest,
+    ) -> RestResult<GetLastTradesByInstrumentAndTimeResponse> {
+        self.send_request(
+            "public/get_last_trades_by_instrument_and_time",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, LiquidationSide, RateLimiter};
+
+    #[test]
+    fn test_get_last_trades_by_instrument_and_time_request_serialization() {
+        let request = GetLastTradesByInstrumentAndTimeRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+            start_timestamp: 1590470022768,
+            end_timestamp: 1590480022768,
+            count: None,
+            sorting: Some(Sorting::Asc),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value["start_timestamp"], 1590470022768i64);
+        assert_eq!(json_value["end_timestamp"], 1590480022768i64);
+        assert_eq!(json_value["sorting"], "asc");
+        assert!(!json_value.as_object().unwrap().contains_key("count"));
+    }
+
+    #[test]
+    fn test_get_last_trades_by_instrument_and_time_response_structure() {
+        let response_json = json!({
+            "id": 3983,
+            "jsonrpc": "2.0",
+            "result": {
+                "trades": [
+                    {
+                        "trade_seq": 1966031,
+                        "trade_id": "ETH-2696060",
+                        "timestamp": 1590480363130i64,
+                        "tick_direction": 2,
+                        "price": 8955.0,
+                        "mark_price": 8952.86,
+                        "liquidation": "M",
+                        "instrument_name": "BTC-PERPETUAL",
+                        "index_price": 8955.73,
+                        "direction": "sell",
+                        "amount": 10.0
+                    }
+                ],
+                "has_more": false
+            }
+        });
+
+        let response: GetLastTradesByInstrumentAndTimeResponse = serde_json::from_value(response_json).unwrap();
+        assert!(!response.result.has_more);
+        assert_eq!(
+            response.result.trades[0].liquidation,
+            Some(LiquidationSide::Maker)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,292 @@
+//! Request and response structs for public/get_order_book endpoint
+//!
+//! Retrieves the order book, along with other market values, for a given instrument.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{BookLevel, EndpointType, Greeks, RestResult, TickerState, TickerStats};
+
+/// Request parameters for the public/get_order_book endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetOrderBookRequest {
+    /// The instrument name for which to retrieve the order book
+    pub instrument_name: String,
+
+    /// The number of entries to return for bids and asks (1, 5, 10, 20, 50, 100, 1000 or 10000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+}
+
+/// Order book snapshot with market values, returned by public/get_order_book
+/// and public/get_order_book_by_instrument_id
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderBook {
+    /// Unique instrument identifier
+    pub instrument_name: String,
+
+    /// The timestamp (milliseconds since the Unix epoch)
+    pub timestamp: i64,
+
+    /// The state of the order book
+    pub state: TickerState,
+
+    /// Id of the order book state
+    pub change_id: i64,
+
+    /// List of bids, best first
+    pub bids: Vec<BookLevel>,
+
+    /// List of asks, best first
+    pub asks: Vec<BookLevel>,
+
+    /// The current best bid price, `None` if there aren't any bids
+    #[serde(default)]
+    pub best_bid_price: Option<f64>,
+
+    /// The order size of all best bids
+    pub best_bid_amount: f64,
+
+    /// The current best ask price, `None` if there aren't any asks
+    #[serde(default)]
+    pub best_ask_price: Option<f64>,
+
+    /// The order size of all best asks
+    pub best_ask_amount: f64,
+
+    /// Current index price
+    pub index_price: f64,
+
+    /// The mark price for the instrument
+    pub mark_price: f64,
+
+    /// The price for the last trade
+    #[serde(default)]
+    pub last_price: Option<f64>,
+
+    /// The minimum price for the future; any sell orders below this price will be rejected
+    pub min_price: f64,
+
+    /// The maximum price for the future; any buy orders above this price will be rejected
+    pub max_price: f64,
+
+    /// The total amount of outstanding contracts in the corresponding amount units
+    pub open_interest: f64,
+
+    /// The settlement price for the instrument, only when state is "open"
+    #[serde(default)]
+    pub settlement_price: Option<f64>,
+
+    /// The settlement price for the instrument, only when state is "closed"
+    #[serde(default)]
+    pub delivery_price: Option<f64>,
+
+    /// Estimated delivery price for the market (only for futures and options)
+    #[serde(default)]
+    pub estimated_delivery_price: Option<f64>,
+
+    /// 24h statistics
+    pub stats: TickerStats,
+
+    /// Funding 8h (only for perpetual)
+    #[serde(default)]
+    pub funding_8h: Option<f64>,
+
+    /// Current funding (only for perpetual)
+    #[serde(default)]
+    pub current_funding: Option<f64>,
+
+    /// Value used to calculate realized_funding in positions (only for perpetual)
+    #[serde(default)]
+    pub interest_value: Option<f64>,
+
+    /// Implied volatility for mark price (only for options)
+    #[serde(default)]
+    pub mark_iv: Option<f64>,
+
+    /// Implied volatility for best bid (only for options)
+    #[serde(default)]
+    pub bid_iv: Option<f64>,
+
+    /// Implied volatility for best ask (only for options)
+    #[serde(default)]
+    pub ask_iv: Option<f64>,
+
+    /// Underlying price for implied volatility calculations (only for options)
+    #[serde(default)]
+    pub underlying_price: Option<f64>,
+
+    /// Name of the underlying future, or "index_price" (only for options)
+    #[serde(default)]
+    pub underlying_index: Option<String>,
+
+    /// Interest rate used in implied volatility calculations (only for options)
+    #[serde(default)]
+    pub interest_rate: Option<f64>,
+
+    /// Option greeks (only for options)
+    #[serde(default)]
+    pub greeks: Option<Greeks>,
+}
+
+/// Response for public/get_order_book endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetOrderBookResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The order book snapshot
+    pub result: OrderBook,
+}
+
+impl RestClient {
+    /// Calls the public/get_order_book endpoint.
+    ///
+    /// Retrieves the order book, along with other market values, for a given instrument.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including instrument name and optional depth
+    ///
+    /// # Returns
+    /// A result containing the response with the order book or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_order_book)
+    pub async fn get_order_book(&self, params: GetOrderBookRequest) -> RestResult<GetOrderBookResponse> {
+        self.send_request(
+            "public/get_order_book",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_order_book_request_serialization() {
+        let request = GetOrderBookRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+            depth: Some(5),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value["instrument_name"], "BTC-PERPETUAL");
+        assert_eq!(json_value["depth"], 5);
+    }
+
+    #[test]
+    fn test_get_order_book_request_without_depth() {
+        let request = GetOrderBookRequest {
+            instrument_name: "ETH-PERPETUAL".to_string(),
+            depth: None,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert!(!json_value.as_object().unwrap().contains_key("depth"));
+    }
+
+    #[test]
+    fn test_get_order_book_response_structure() {
+        let response_json = json!({
+            "id": 8772,
+            "jsonrpc": "2.0",
+            "result": {
+                "timestamp": 1550757626706i64,
+                "stats": {"volume": 93.35589552, "price_change": 0.6913, "low": 3940.75, "high": 3976.25},
+                "state": "open",
+                "settlement_price": 3925.85,
+                "open_interest": 45.27600333464605,
+                "min_price": 3932.22,
+                "max_price": 3971.74,
+                "mark_price": 3931.97,
+                "last_price": 3955.75,
+                "instrument_name": "BTC-PERPETUAL",
+                "index_price": 3910.46,
+                "funding_8h": 0.00455263,
+                "current_funding": 0.00500063,
+                "change_id": 474988,
+                "bids": [[3955.75, 30.0], [3940.75, 102020.0]],
+                "best_bid_price": 3955.75,
+                "best_bid_amount": 30.0,
+                "best_ask_price": 0.0,
+                "best_ask_amount": 0.0,
+                "asks": []
+            }
+        });
+
+        let response: GetOrderBookResponse = serde_json::from_value(response_json).unwrap();
+        let book = response.result;
+        assert_eq!(book.instrument_name, "BTC-PERPETUAL");
+        assert_eq!(book.change_id, 474988);
+        assert_eq!(book.state, TickerState::Open);
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.bids[0].price, 3955.75);
+        assert_eq!(book.bids[1].amount, 102020.0);
+        assert!(book.asks.is_empty());
+        assert_eq!(book.funding_8h, Some(0.00455263));
+        assert!(book.greeks.is_none());
+    }
+
+    #[test]
+    fn test_get_order_book_option_response() {
+        let response_json = json!({
+            "id": 1,
+            "jsonrpc": "2.0",
+            "result": {
+                "timestamp": 1700000000000i64,
+                "stats": {"volume": null, "price_change": null, "low": null, "high": null},
+                "state": "open",
+                "open_interest": 12.5,
+                "min_price": 0.0001,
+                "max_price": 0.05,
+                "mark_price": 0.021,
+                "instrument_name": "BTC-27JUN25-60000-C",
+                "index_price": 58000.0,
+                "change_id": 1,
+                "bids": [[0.02, 5.0]],
+                "asks": [[0.022, 3.0]],
+                "best_bid_price": 0.02,
+                "best_bid_amount": 5.0,
+                "best_ask_price": 0.022,
+                "best_ask_amount": 3.0,
+                "mark_iv": 55.2,
+                "bid_iv": 54.0,
+                "ask_iv": 56.1,
+                "underlying_price": 58100.0,
+                "underlying_index": "BTC-27JUN25",
+                "interest_rate": 0.0,
+                "greeks": {"delta": 0.45, "gamma": 0.0001, "vega": 80.1, "theta": -40.2, "rho": 10.5}
+            }
+        });
+
+        let response: GetOrderBookResponse = serde_json::from_value(response_json).unwrap();
+        let book = response.result;
+        assert!(book.last_price.is_none());
+        assert_eq!(book.underlying_index.as_deref(), Some("BTC-27JUN25"));
+        assert_eq!(book.greeks.unwrap().delta, 0.45);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,121 @@
+//! Request and response structs for public/get_order_book_by_instrument_id endpoint
+//!
+//! Retrieves the order book, along with other market values, for a given instrument ID.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+pub use super::get_order_book::OrderBook;
+use crate::deribit::{EndpointType, RestResult};
+
+/// Request parameters for the public/get_order_book_by_instrument_id endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetOrderBookByInstrumentIdRequest {
+    /// The instrument ID for which to retrieve the order book
+    pub instrument_id: i64,
+
+    /// The number of entries to return for bids and asks (1, 5, 10, 20, 50, 100, 1000 or 10000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+}
+
+/// Response for public/get_order_book_by_instrument_id endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetOrderBookByInstrumentIdResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The order book snapshot
+    pub result: OrderBook,
+}
+
+impl RestClient {
+    /// Calls the public/get_order_book_by_instrument_id endpoint.
+    ///
+    /// Retrieves the order book, along with other market values, for a given instrument ID.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including instrument ID and optional depth
+    ///
+    /// # Returns
+    /// A result containing the response with the order book or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_order_book_by_instrument_id)
+    pub async fn get_order_book_by_instrument_id(&self, params: GetOrderBookByInstrumentIdRequest) -> RestResult<GetOrderBookByInstrumentIdResponse> {
+        self.send_request(
+            "public/get_order_book_by_instrument_id",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_order_book_by_instrument_id_request_serialization() {
+        let request = GetOrderBookByInstrumentIdRequest {
+            instrument_id: 124972,
+            depth: Some(1),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"instrument_id": 124972, "depth": 1}));
+    }
+
+    #[test]
+    fn test_get_order_book_by_instrument_id_response_structure() {
+        let response_json = json!({
+            "id": 3,
+            "jsonrpc": "2.0",
+            "result": {
+                "timestamp": 1700000000000i64,
+                "stats": {"volume": 1000.0, "price_change": -1.2, "low": 35000.0, "high": 36000.0, "volume_usd": 35500000.0},
+                "state": "open",
+                "open_interest": 500000000.0,
+                "min_price": 35000.0,
+                "max_price": 36100.0,
+                "mark_price": 35550.0,
+                "last_price": 35551.0,
+                "instrument_name": "BTC-PERPETUAL",
+                "index_price": 35540.0,
+                "change_id": 99,
+                "bids": [[35550.0, 1000.0]],
+                "asks": [[35551.0, 2000.0]],
+                "best_bid_price": 35550.0,
+                "best_bid_amount": 1000.0,
+                "best_ask_price": 35551.0,
+                "best_ask_amount": 2000.0
+            }
+        });
+
+        let response: GetOrderBookByInstrumentIdResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.change_id, 99);
+        assert_eq!(response.result.asks[0].price, 35551.0);
+        assert_eq!(response.result.stats.volume_usd, Some(35500000.0));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -3,6 +3,9 @@
 //! Retrieves the current time (in milliseconds). This API endpoint can be used to
 //! check the clock skew between your software and Deribit's systems.
 
+use async_trait::async_trait;
+use rest::clock::TimeSource;
+use rest::error::{RestError, VenueError};
 use serde::{Deserialize, Serialize};
 
 use super::client::RestClient;
@@ -51,6 +54,17 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl TimeSource for RestClient {
+    async fn server_time_ms(&self) -> Result<i64, RestError> {
+        let response = self
+            .get_time(GetTimeRequest {})
+            .await
+            .map_err(VenueError::from)?;
+        Ok(response.result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
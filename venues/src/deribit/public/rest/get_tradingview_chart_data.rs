@@ -0,0 +1,162 @@
+//! Request and response structs for public/get_tradingview_chart_data endpoint
+//!
+//! Publicly available market data used to generate a TradingView candle chart.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{ChartDataStatus, EndpointType, Resolution, RestResult};
+
+/// Request parameters for the public/get_tradingview_chart_data endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTradingviewChartDataRequest {
+    /// Instrument name
+    pub instrument_name: String,
+
+    /// The earliest timestamp to return result from (milliseconds since the UNIX epoch)
+    pub start_timestamp: i64,
+
+    /// The most recent timestamp to return result from (milliseconds since the UNIX epoch)
+    pub end_timestamp: i64,
+
+    /// Chart bars resolution
+    pub resolution: Resolution,
+}
+
+/// Candle data in columnar form; the n-th element of each vector belongs to the n-th candle
+#[derive(Debug, Clone, Deserialize)]
+pub struct TradingviewChartData {
+    /// Status of the query
+    pub status: ChartDataStatus,
+
+    /// Start timestamps of the candles (milliseconds since the UNIX epoch)
+    pub ticks: Vec<i64>,
+
+    /// Open prices
+    pub open: Vec<f64>,
+
+    /// Highest prices
+    pub high: Vec<f64>,
+
+    /// Lowest prices
+    pub low: Vec<f64>,
+
+    /// Close prices
+    pub close: Vec<f64>,
+
+    /// Volumes in base currency
+    pub volume: Vec<f64>,
+
+    /// Volumes in quote currency
+    pub cost: Vec<f64>,
+}
+
+/// Response for public/get_tradingview_chart_data endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetTradingviewChartDataResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The chart data
+    pub result: TradingviewChartData,
+}
+
+impl RestClient {
+    /// Calls the public/get_tradingview_chart_data endpoint.
+    ///
+    /// Publicly available market data used to generate a TradingView candle chart.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including instrument name, time range and resolution
+    ///
+    /// # Returns
+    /// A result containing the response with chart data or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_tradingview_chart_data)
+    pub async fn get_tradingview_chart_data(&self, params: GetTradingviewChartDataRequest) -> RestResult<GetTradingviewChartDataResponse> {
+        self.send_request(
+            "public/get_tradingview_chart_data",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_tradingview_chart_data_request_serialization() {
+        let request = GetTradingviewChartDataRequest {
+            instrument_name: "BTC-5APR19".to_string(),
+            start_timestamp: 1554373800000,
+            end_timestamp: 1554376800000,
+            resolution: Resolution::ThirtyMinutes,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value["instrument_name"], "BTC-5APR19");
+        assert_eq!(json_value["resolution"], "30");
+    }
+
+    #[test]
+    fn test_get_tradingview_chart_data_response_structure() {
+        let response_json = json!({
+            "id": 833,
+            "jsonrpc": "2.0",
+            "result": {
+                "volume": [19.007942601, 20.095877981],
+                "ticks": [1554373800000i64, 1554375600000i64],
+                "status": "ok",
+                "open": [4963.42, 4986.29],
+                "low": [4728.94, 4726.6],
+                "high": [5010.46, 5010.46],
+                "cost": [19000.0, 23400.0],
+                "close": [4986.29, 4986.29]
+            }
+        });
+
+        let response: GetTradingviewChartDataResponse = serde_json::from_value(response_json).unwrap();
+        let data = response.result;
+        assert_eq!(data.status, ChartDataStatus::Ok);
+        assert_eq!(data.ticks.len(), 2);
+        assert_eq!(data.open[1], 4986.29);
+        assert_eq!(data.cost[0], 19000.0);
+    }
+
+    #[test]
+    fn test_get_tradingview_chart_data_no_data() {
+        let response_json = json!({
+            "id": 1,
+            "jsonrpc": "2.0",
+            "result": {"volume": [], "ticks": [], "status": "no_data", "open": [], "low": [], "high": [], "cost": [], "close": []}
+        });
+
+        let response: GetTradingviewChartDataResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.status, ChartDataStatus::NoData);
+        assert!(response.result.ticks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
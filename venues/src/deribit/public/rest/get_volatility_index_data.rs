@@ -0,0 +1,161 @@
+//! Request and response structs for public/get_volatility_index_data endpoint
+//!
+//! Retrieves volatility index (DVOL) chart data formatted as OHLC candles.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{Currency, EndpointType, RestResult, VolatilityIndexResolution};
+
+/// Request parameters for the public/get_volatility_index_data endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetVolatilityIndexDataRequest {
+    /// The currency symbol
+    pub currency: Currency,
+
+    /// The earliest timestamp to return result from (milliseconds since the UNIX epoch)
+    pub start_timestamp: i64,
+
+    /// The most recent timestamp to return result from (milliseconds since the UNIX epoch)
+    pub end_timestamp: i64,
+
+    /// Time resolution of the candles
+    pub resolution: VolatilityIndexResolution,
+}
+
+/// Volatility index candle, sent as `[timestamp, open, high, low, close]`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(from = "(i64, f64, f64, f64, f64)")]
+pub struct VolatilityCandle {
+    /// Start of the candle (milliseconds since the UNIX epoch)
+    pub timestamp: i64,
+
+    /// Open value
+    pub open: f64,
+
+    /// High value
+    pub high: f64,
+
+    /// Low value
+    pub low: f64,
+
+    /// Close value
+    pub close: f64,
+}
+
+impl From<(i64, f64, f64, f64, f64)> for VolatilityCandle {
+    fn from((timestamp, open, high, low, close): (i64, f64, f64, f64, f64)) -> Self {
+        Self {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+}
+
+/// Result data for public/get_volatility_index_data
+#[derive(Debug, Clone, Deserialize)]
+pub struct VolatilityIndexData {
+    /// Candles in chronological order
+    pub data: Vec<VolatilityCandle>,
+
+    /// Continuation token; pass it as `end_timestamp` to fetch the previous page,
+    /// `None` when there is no more data
+    #[serde(default)]
+    pub continuation: Option<i64>,
+}
+
+/// Response for public/get_volatility_index_data endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetVolatilityIndexDataResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The volatility index candles
+    pub result: VolatilityIndexData,
+}
+
+impl RestClient {
+    /// Calls the public/get_volatility_index_data endpoint.
+    ///
+    /// Retrieves volatility index (DVOL) chart data formatted as OHLC candles.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters including currency, time range and resolution
+    ///
+    /// # Returns
+    /// A result containing the response with candles or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-get_volatility_index_data)
+    pub async fn get_volatility_index_data(&self, params: GetVolatilityIndexDataRequest) -> RestResult<GetVolatilityIndexDataResponse> {
+        self.send_request(
+            "public/get_volatility_index_data",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_get_volatility_index_data_request_serialization() {
+        let request = GetVolatilityIndexDataRequest {
+            currency: Currency::BTC,
+            start_timestamp: 1599373800000,
+            end_timestamp: 1599376800000,
+            resolution: VolatilityIndexResolution::OneMinute,
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value["currency"], "BTC");
+        assert_eq!(json_value["resolution"], "60");
+    }
+
+    #[test]
+    fn test_get_volatility_index_data_response_structure() {
+        let response_json = json!({
+            "id": 5,
+            "jsonrpc": "2.0",
+            "result": {
+                "data": [
+                    [1598019300000i64, 0.210084879, 0.212860821, 0.210084879, 0.212860821],
+                    [1598019360000i64, 0.212869011, 0.212987527, 0.212869011, 0.212987527]
+                ],
+                "continuation": null
+            }
+        });
+
+        let response: GetVolatilityIndexDataResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.result.data.len(), 2);
+        assert_eq!(response.result.data[0].timestamp, 1598019300000);
+        assert_eq!(response.result.data[1].close, 0.212987527);
+        assert!(response.result.continuation.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -1,15 +1,51 @@
 pub mod client;
+pub mod get_book_summary_by_currency;
+pub mod get_book_summary_by_instrument;
 pub mod get_combo_details;
 pub mod get_combo_ids;
 pub mod get_combos;
+pub mod get_currencies;
+pub mod get_delivery_prices;
+pub mod get_funding_rate_history;
+pub mod get_historical_volatility;
+pub mod get_index_price;
+pub mod get_index_price_names;
+pub mod get_instrument;
+pub mod get_instruments;
+pub mod get_last_trades_by_currency;
+pub mod get_last_trades_by_instrument;
+pub mod get_last_trades_by_instrument_and_time;
+pub mod get_order_book;
+pub mod get_order_book_by_instrument_id;
 pub mod get_status;
 pub mod get_time;
+pub mod get_tradingview_chart_data;
+pub mod get_volatility_index_data;
 pub mod test;
+pub mod ticker;
 
 pub use client::RestClient;
+pub use get_book_summary_by_currency::{BookSummary, GetBookSummaryByCurrencyRequest, GetBookSummaryByCurrencyResponse};
+pub use get_book_summary_by_instrument::{GetBookSummaryByInstrumentRequest, GetBookSummaryByInstrumentResponse};
 pub use get_combo_details::{GetComboDetailsRequest, GetComboDetailsResponse};
 pub use get_combo_ids::{GetComboIdsRequest, GetComboIdsResponse};
 pub use get_combos::{GetCombosRequest, GetCombosResponse, ComboInfo, ComboLeg};
+pub use get_currencies::{CurrencyInfo, CurrencyWithdrawalPriority, GetCurrenciesRequest, GetCurrenciesResponse};
+pub use get_delivery_prices::{DeliveryPrice, DeliveryPrices, GetDeliveryPricesRequest, GetDeliveryPricesResponse};
+pub use get_funding_rate_history::{FundingRate, GetFundingRateHistoryRequest, GetFundingRateHistoryResponse};
+pub use get_historical_volatility::{GetHistoricalVolatilityRequest, GetHistoricalVolatilityResponse, HistoricalVolatility};
+pub use get_index_price::{GetIndexPriceRequest, GetIndexPriceResponse, IndexPrice};
+pub use get_index_price_names::{GetIndexPriceNamesRequest, GetIndexPriceNamesResponse};
+pub use get_instrument::{GetInstrumentRequest, GetInstrumentResponse};
+pub use get_instruments::{GetInstrumentsRequest, GetInstrumentsResponse, Instrument, TickSizeStep};
+pub use get_last_trades_by_currency::{GetLastTradesByCurrencyRequest, GetLastTradesByCurrencyResponse};
+pub use get_last_trades_by_instrument::{GetLastTradesByInstrumentRequest, GetLastTradesByInstrumentResponse, LastTradesResult};
+pub use get_last_trades_by_instrument_and_time::{GetLastTradesByInstrumentAndTimeRequest, GetLastTradesByInstrumentAndTimeResponse};
+pub use get_order_book::{GetOrderBookRequest, GetOrderBookResponse, OrderBook};
+pub use get_order_book_by_instrument_id::{GetOrderBookByInstrumentIdRequest, GetOrderBookByInstrumentIdResponse};
 pub use get_status::{GetStatusRequest, GetStatusResponse, GetStatusResult};
 pub use get_time::{GetTimeRequest, GetTimeResponse};
+pub use get_tradingview_chart_data::{GetTradingviewChartDataRequest, GetTradingviewChartDataResponse, TradingviewChartData};
+pub use get_volatility_index_data::{GetVolatilityIndexDataRequest, GetVolatilityIndexDataResponse, VolatilityCandle, VolatilityIndexData};
 pub use test::{TestRequest, TestResponse, TestResult};
+pub use ticker::{TickerRequest, TickerResponse};
\ No newline at end of file
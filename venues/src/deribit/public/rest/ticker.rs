@@ -0,0 +1,126 @@
+//! Request and response structs for public/ticker endpoint
+//!
+//! Gets the ticker for an instrument. The payload is the same as the one pushed
+//! on the `ticker.{instrument_name}.{interval}` channel.
+
+use serde::{Deserialize, Serialize};
+
+use super::client::RestClient;
+use crate::deribit::{EndpointType, RestResult, Ticker};
+
+/// Request parameters for the public/ticker endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickerRequest {
+    /// Instrument name
+    pub instrument_name: String,
+}
+
+/// Response for public/ticker endpoint following Deribit JSON-RPC 2.0 format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TickerResponse {
+    /// The id that was sent in the request
+    pub id: i64,
+
+    /// The JSON-RPC version (2.0)
+    pub jsonrpc: String,
+
+    /// The ticker of the instrument
+    pub result: Ticker,
+}
+
+impl RestClient {
+    /// Calls the public/ticker endpoint.
+    ///
+    /// Gets the ticker for an instrument.
+    ///
+    /// # Arguments
+    /// * `params` - The request parameters with the instrument name
+    ///
+    /// # Returns
+    /// A result containing the response with the ticker or an error
+    ///
+    /// [Official API docs](https://docs.deribit.com/#public-ticker)
+    pub async fn ticker(&self, params: TickerRequest) -> RestResult<TickerResponse> {
+        self.send_request(
+            "public/ticker",
+            reqwest::Method::GET,
+            Some(&params),
+            EndpointType::NonMatchingEngine,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::deribit::{AccountTier, RateLimiter};
+
+    #[test]
+    fn test_ticker_request_serialization() {
+        let request = TickerRequest {
+            instrument_name: "BTC-PERPETUAL".to_string(),
+        };
+
+        let json_value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json_value, json!({"instrument_name": "BTC-PERPETUAL"}));
+    }
+
+    #[test]
+    fn test_ticker_response_structure() {
+        let response_json = json!({
+            "id": 8106,
+            "jsonrpc": "2.0",
+            "result": {
+                "best_ask_amount": 53040,
+                "best_ask_price": 36290,
+                "best_bid_amount": 4600,
+                "best_bid_price": 36289.5,
+                "current_funding": 0,
+                "estimated_delivery_price": 36297.02,
+                "funding_8h": 0.00002203,
+                "index_price": 36297.02,
+                "instrument_name": "BTC-PERPETUAL",
+                "interest_value": 1.7362511643080387,
+                "last_price": 36289.5,
+                "mark_price": 36288.31,
+                "max_price": 36833.4,
+                "min_price": 35744.73,
+                "open_interest": 502231260,
+                "settlement_price": 36169.49,
+                "state": "open",
+                "stats": {
+                    "high": 36824.5,
+                    "low": 35213.5,
+                    "price_change": 0.2362,
+                    "volume": 7831.26548117,
+                    "volume_usd": 282615600
+                },
+                "timestamp": 1623059681955i64
+            }
+        });
+
+        let response: TickerResponse = serde_json::from_value(response_json).unwrap();
+        assert_eq!(response.id, 8106);
+        assert_eq!(response.result.instrument_name, "BTC-PERPETUAL");
+        assert_eq!(response.result.best_bid_price, Some(36289.5));
+        assert_eq!(response.result.funding_8h, Some(0.00002203));
+        assert_eq!(response.result.stats.volume_usd, Some(282615600.0));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_type_usage() {
+        let client = reqwest::Client::new();
+        let rate_limiter = RateLimiter::new(AccountTier::Tier4);
+
+        let rest_client = RestClient::new("https://test.deribit.com", client, rate_limiter);
+
+        let result = rest_client
+            .rate_limiter
+            .check_limits(EndpointType::NonMatchingEngine)
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -5,23 +5,24 @@
 //! All message construction, serialization, and endpoint logic must be in separate files.
 //! This file should not contain endpoint-specific logic or message types.
 
-use futures::SinkExt;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::pin::Pin;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
 
 use async_trait::async_trait;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use thiserror::Error;
-use tokio::net::TcpStream;
-use tokio::sync::Mutex;
-use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
-use websockets::{BoxResult, WebSocketConnection};
+use tokio::sync::mpsc;
+use websockets::{BoxResult, VenueMessage, WebSocketConnection};
 
-use crate::deribit::message::DeribitMessage;
+use crate::deribit::connection::{self, MessageStream, RpcConnection, RpcMessage, RpcState};
+use crate::deribit::message::HeartbeatNotification;
+use crate::deribit::notification::ChannelNotification;
 use crate::deribit::rate_limit::RateLimiter;
 
+/// Default time to wait for a JSON-RPC response before giving up
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Errors specific to Deribit WebSocket operations
 #[derive(Error, Debug)]
 pub enum DeribitWebSocketError {
@@ -42,126 +43,404 @@ pub enum DeribitWebSocketError {
 
     #[error("Invalid response for request id {id}")]
     InvalidResponse { id: u64 },
+
+    #[error("Not connected")]
+    NotConnected,
+
+    #[error("Not authenticated")]
+    NotAuthenticated,
+
+    #[error("Authentication failed: {0}")]
+    AuthenticationFailed(String),
 }
 
-#[derive(serde::Deserialize)]
-struct JsonRpcEnvelope<R> {
-    id: Option<i32>,
-    jsonrpc: Option<String>,
-    result: R,
+/// Message type for Deribit public WebSocket messages
+///
+/// These are the server initiated messages yielded by `message_stream`. Responses to
+/// requests are returned directly from the endpoint methods.
+#[derive(Debug, Clone)]
+pub enum DeribitPublicMessage {
+    /// Typed data pushed for a subscribed channel
+    Subscription(ChannelNotification),
+
+    /// Heartbeat notification (test requests are answered automatically)
+    Heartbeat(HeartbeatNotification),
+}
+
+impl VenueMessage for DeribitPublicMessage {}
+
+impl RpcMessage for DeribitPublicMessage {
+    fn subscription(notification: ChannelNotification) -> Self {
+        DeribitPublicMessage::Subscription(notification)
+    }
+
+    fn heartbeat(heartbeat: HeartbeatNotification) -> Self {
+        DeribitPublicMessage::Heartbeat(heartbeat)
+    }
 }
 
 /// WebSocket client for Deribit public endpoints
 ///
 /// This struct manages the connection and delegates endpoint logic to endpoint modules.
-pub struct PrivateWebSocketClient {
-    /// WebSocket connection
-    pub(crate) websocket: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>,
-    /// Connection status
-    connected: Arc<AtomicBool>,
-    /// Rate limiter for API calls
-    rate_limiter: Arc<RateLimiter>,
-    /// Request ID counter for JSON-RPC
-    pub(crate) request_id: Arc<AtomicU64>,
-    /// Pending requests awaiting responses
-    pending_requests: Arc<Mutex<HashMap<u64, tokio::sync::oneshot::Sender<serde_json::Value>>>>,
+/// Requests are multiplexed over one socket by the shared JSON-RPC connection, which routes
+/// responses back by id and notifications to `message_stream`.
+pub struct PublicWebSocketClient {
+    /// Current connection, if any
+    connection: Option<RpcConnection<DeribitPublicMessage>>,
+
+    /// State shared with the tasks of the current connection
+    state: Arc<RpcState<DeribitPublicMessage>>,
+
+    /// Receiver side of the notification channel, taken by `message_stream`
+    notification_rx: Option<mpsc::UnboundedReceiver<BoxResult<DeribitPublicMessage>>>,
+
+    /// Default time to wait for the response to a request
+    request_timeout: Duration,
+
     /// WebSocket URL
     url: String,
 }
 
-impl PrivateWebSocketClient {
+impl PublicWebSocketClient {
     /// Create a new Deribit WebSocket client
     pub fn new(url: Option<String>, rate_limiter: RateLimiter) -> Self {
+        let (state, notification_rx) = RpcState::new(rate_limiter);
         Self {
-            websocket: None,
-            connected: Arc::new(AtomicBool::new(false)),
-            rate_limiter: Arc::new(rate_limiter),
-            request_id: Arc::new(AtomicU64::new(1)),
-            pending_requests: Arc::new(Mutex::new(HashMap::new())),
+            connection: None,
+            state: Arc::new(state),
+            notification_rx: Some(notification_rx),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
             url: url.unwrap_or_else(|| "wss://www.deribit.com/ws/api/v2".to_string()),
         }
     }
 
+    /// Set the default time to wait for the response to a request
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
     /// Get the next request ID
     pub fn next_request_id(&self) -> u64 {
-        self.request_id.fetch_add(1, Ordering::SeqCst)
-    }
-
-    /// Receive a response for a sent request (used internally by endpoint modules)
-    pub(crate) async fn receive_response(&mut self) -> Result<String, DeribitWebSocketError> {
-        let mut pending = self.pending_requests.lock().await;
-        let (tx, rx) = tokio::sync::oneshot::channel();
-        let id = self.next_request_id();
-        pending.insert(id, tx);
-        drop(pending);
-        // Wait for the response or timeout
-        let response: serde_json::Value = tokio::select! {
-            res = rx => res.map_err(|_| DeribitWebSocketError::Timeout { id })?,
-            _ = tokio::time::sleep(std::time::Duration::from_secs(10)) => {
-                return Err(DeribitWebSocketError::Timeout { id });
-            }
-        };
-        let response_str = serde_json::to_string(&response).map_err(DeribitWebSocketError::Serialization)?;
-        Ok(response_str)
+        self.state.next_request_id()
     }
 
     /// Check if the client is connected
     pub fn is_connected(&self) -> bool {
-        self.connected.load(Ordering::SeqCst)
+        self.state.is_connected()
     }
 
-    /// Send any serializable request struct over the websocket
-    pub async fn send_serializable<T: serde::Serialize + ?Sized>(&mut self, req: &T) -> Result<(), DeribitWebSocketError> {
-        if !self.is_connected() {
-            return Err(DeribitWebSocketError::Connection(
-                "Not connected".to_string(),
-            ));
-        }
-        let req_json = serde_json::to_string(req)?;
-        self.websocket
-            .as_mut()
-            .ok_or_else(|| DeribitWebSocketError::Connection("WebSocket not connected".to_string()))?
-            .send(tokio_tungstenite::tungstenite::Message::Text(
-                req_json.into(),
-            ))
+    /// Send a JSON-RPC request and wait for the matching response using the default timeout
+    pub(crate) async fn send_request<P, R>(&self, method: &str, params: &P) -> Result<R, DeribitWebSocketError>
+    where
+        P: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        self.send_request_with_timeout(method, params, self.request_timeout)
             .await
-            .map_err(|e| DeribitWebSocketError::Connection(e.to_string()))?;
-        Ok(())
     }
 
-    /// Generic send and receive for request/response types
-    /// Generic send and receive for request/response types, extracting the `result` field
-    pub async fn send_and_receive<T: Serialize, R: for<'de> Deserialize<'de>>(&mut self, request: &T) -> Result<R, DeribitWebSocketError> {
-        self.send_serializable(request).await?;
-        let response_str = self.receive_response().await?;
-        let envelope: JsonRpcEnvelope<R> = serde_json::from_str(&response_str)?;
-        Ok(envelope.result)
+    /// Send a JSON-RPC request and wait up to `timeout` for the matching response
+    ///
+    /// # Returns
+    /// The deserialized `result` field, or `DeribitWebSocketError::JsonRpc` if Deribit
+    /// answered with an `error` object.
+    pub async fn send_request_with_timeout<P, R>(&self, method: &str, params: &P, timeout: Duration) -> Result<R, DeribitWebSocketError>
+    where
+        P: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        self.connection
+            .as_ref()
+            .ok_or(DeribitWebSocketError::NotConnected)?
+            .handle()
+            .send_request(method, params, timeout)
+            .await
     }
 }
 
 #[async_trait]
-impl WebSocketConnection<DeribitMessage> for PrivateWebSocketClient {
+impl WebSocketConnection<DeribitPublicMessage> for PublicWebSocketClient {
     async fn connect(&mut self) -> BoxResult<()> {
-        let (ws_stream, _) = connect_async(&self.url).await?;
-        self.websocket = Some(ws_stream);
-        self.connected.store(true, Ordering::SeqCst);
+        // Stop the tasks of any previous connection before opening the new one
+        if let Some(connection) = self.connection.take() {
+            connection.shutdown().await;
+        }
+        self.connection = Some(RpcConnection::open(&self.url, self.state.clone(), || async {}).await?);
         Ok(())
     }
 
     async fn disconnect(&mut self) -> BoxResult<()> {
-        if let Some(mut ws) = self.websocket.take() {
-            ws.close(None).await?;
+        if let Some(connection) = self.connection.take() {
+            connection.close().await;
         }
-        self.connected.store(false, Ordering::SeqCst);
         Ok(())
     }
 
     fn is_connected(&self) -> bool {
-        self.connected.load(Ordering::SeqCst)
+        self.state.is_connected()
+    }
+
+    /// Get the stream of subscription and heartbeat notifications
+    ///
+    /// The stream survives reconnects. It can only be taken once; subsequent calls
+    /// return an empty stream.
+    fn message_stream(&mut self) -> MessageStream<DeribitPublicMessage> {
+        connection::message_stream(&mut self.notification_rx)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use futures::StreamExt;
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+    use crate::deribit::connection::tests::{echo_reply, spawn_server};
+    use crate::deribit::notification::Notification;
+    use crate::deribit::{AccountTier, HeartbeatType};
+
+    pub(crate) fn test_client(url: Option<String>) -> PublicWebSocketClient {
+        PublicWebSocketClient::new(url, RateLimiter::new(AccountTier::default()))
+    }
+
+    #[test]
+    fn test_public_websocket_client_creation() {
+        let client = test_client(None);
+
+        assert!(!client.is_connected());
+        assert_eq!(client.url, "wss://www.deribit.com/ws/api/v2");
+        assert_eq!(client.request_timeout, DEFAULT_REQUEST_TIMEOUT);
     }
 
-    fn message_stream(&mut self) -> Pin<Box<dyn futures::Stream<Item = websockets::BoxResult<DeribitMessage>> + Send>> {
-        // Not implemented for public client (not used in this context)
-        unimplemented!("message_stream is not implemented for DeribitWebSocketClient public client")
+    #[test]
+    fn test_request_id_generation() {
+        let client = test_client(None);
+
+        let id1 = client.next_request_id();
+        let id2 = client.next_request_id();
+
+        assert_eq!(id2, id1 + 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_request_requires_connection() {
+        let client = test_client(None);
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("public/test", &serde_json::json!({}))
+            .await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn test_request_is_sent_with_method_and_id() {
+        let (url, mut seen) = spawn_server(|request| vec![echo_reply(request)]).await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let result: serde_json::Value = client
+            .send_request("public/hello", &serde_json::json!({"client_name": "ccrxt"}))
+            .await
+            .unwrap();
+
+        assert_eq!(result["client_name"], "ccrxt");
+        match seen.recv().await.unwrap() {
+            Message::Text(text) => {
+                let request: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                assert_eq!(request["jsonrpc"], "2.0");
+                assert_eq!(request["method"], "public/hello");
+                assert!(request["id"].is_u64());
+            }
+            other => panic!("Expected text frame, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_responses_are_routed_by_id() {
+        // The first request is held back and answered after the second, so replies arrive out of order
+        let held = std::sync::Mutex::new(None);
+        let (url, _seen) = spawn_server(move |request| {
+            let mut held = held.lock().unwrap();
+            match held.take() {
+                None => {
+                    *held = Some(request.clone());
+                    vec![]
+                }
+                Some(first) => vec![echo_reply(request), echo_reply(&first)],
+            }
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let (params1, params2) = (serde_json::json!({"n": 1}), serde_json::json!({"n": 2}));
+        let (first, second) = tokio::join!(
+            client.send_request::<_, serde_json::Value>("public/test", &params1),
+            client.send_request::<_, serde_json::Value>("public/test", &params2),
+        );
+
+        assert_eq!(first.unwrap()["n"], 1);
+        assert_eq!(second.unwrap()["n"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_json_rpc_error_is_mapped() {
+        let (url, _seen) = spawn_server(|request| {
+            vec![serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": {"code": 11050, "message": "bad_request"}
+            })]
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("public/subscribe", &serde_json::json!({"channels": []}))
+            .await;
+
+        match result {
+            Err(DeribitWebSocketError::JsonRpc { code, message }) => {
+                assert_eq!(code, 11050);
+                assert_eq!(message, "bad_request");
+            }
+            other => panic!("Expected JsonRpc error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_per_call_timeout() {
+        let (url, _seen) = spawn_server(|_| vec![]).await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let started = tokio::time::Instant::now();
+        let result: Result<serde_json::Value, _> = client
+            .send_request_with_timeout(
+                "public/test",
+                &serde_json::json!({}),
+                Duration::from_millis(50),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::Timeout { .. })));
+        assert!(started.elapsed() < DEFAULT_REQUEST_TIMEOUT);
+        assert!(client.state.pending_requests.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_default_timeout_is_configurable() {
+        let (url, _seen) = spawn_server(|_| vec![]).await;
+        let mut client = test_client(Some(url)).with_request_timeout(Duration::from_millis(50));
+        client.connect().await.unwrap();
+
+        let result: Result<serde_json::Value, _> = client
+            .send_request("public/test", &serde_json::json!({}))
+            .await;
+
+        assert!(matches!(result, Err(DeribitWebSocketError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_notifications_are_streamed() {
+        let (url, mut seen) = spawn_server(|request| {
+            vec![
+                echo_reply(request),
+                serde_json::json!({"jsonrpc": "2.0", "method": "heartbeat", "params": {"type": "test_request"}}),
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "subscription",
+                    "params": {
+                        "channel": "quote.BTC-PERPETUAL",
+                        "data": {"timestamp": 1550658624149i64, "instrument_name": "BTC-PERPETUAL", "best_bid_price": 64999.5, "best_bid_amount": 40, "best_ask_price": 65000.0, "best_ask_amount": 50}
+                    }
+                }),
+            ]
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        let mut stream = client.message_stream();
+        client.connect().await.unwrap();
+
+        let _: serde_json::Value = client
+            .send_request(
+                "public/subscribe",
+                &serde_json::json!({"channels": ["quote.BTC-PERPETUAL"]}),
+            )
+            .await
+            .unwrap();
+
+        match stream.next().await {
+            Some(Ok(DeribitPublicMessage::Heartbeat(heartbeat))) => assert_eq!(heartbeat.heartbeat_type, HeartbeatType::TestRequest),
+            other => panic!("Expected heartbeat, got {:?}", other),
+        }
+        match stream.next().await {
+            Some(Ok(DeribitPublicMessage::Subscription(ChannelNotification {
+                channel,
+                data: Notification::Quote(quote),
+            }))) => {
+                assert_eq!(channel, "quote.BTC-PERPETUAL");
+                assert_eq!(quote.best_ask_price, Some(65000.0));
+            }
+            other => panic!("Expected subscription notification, got {:?}", other),
+        }
+
+        // The test_request heartbeat must be answered with public/test
+        let _subscribe = seen.recv().await.unwrap();
+        match seen.recv().await.unwrap() {
+            Message::Text(text) => assert!(text.as_str().contains("public/test")),
+            other => panic!("Expected text frame, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_fails_requests_of_previous_connection() {
+        let (url, _seen) = spawn_server(|request| match request["method"].as_str() {
+            Some("public/hello") => vec![echo_reply(request)],
+            _ => vec![],
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+        let previous = client.connection.as_ref().unwrap().handle().clone();
+        let pending = tokio::spawn(async move {
+            previous
+                .send_request::<_, serde_json::Value>(
+                    "public/test",
+                    &serde_json::json!({}),
+                    DEFAULT_REQUEST_TIMEOUT,
+                )
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        client.connect().await.unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(2), pending)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(result, Err(DeribitWebSocketError::Connection(_))));
+        assert!(client.is_connected());
+        let reply: serde_json::Value = client
+            .send_request("public/hello", &serde_json::json!({"client_name": "ccrxt"}))
+            .await
+            .unwrap();
+        assert_eq!(reply["client_name"], "ccrxt");
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_closes_socket() {
+        let (url, mut seen) = spawn_server(|_| vec![]).await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        client.disconnect().await.unwrap();
+
+        assert!(!client.is_connected());
+        assert!(matches!(seen.recv().await, Some(Message::Close(_))));
     }
 }
@@ -3,7 +3,7 @@
 //! This method is used to introduce the client software connected to Deribit platform over
 //! websocket. Provided data may have an impact on the maintained connection and
 //! will be collected for internal statistical purposes.
-use crate::deribit::public::websocket::client::{DeribitWebSocketError, PrivateWebSocketClient};
+use crate::deribit::public::websocket::client::{DeribitWebSocketError, PublicWebSocketClient};
 
 use serde::{Deserialize, Serialize};
 
@@ -31,19 +31,23 @@ pub struct HelloResult {
     pub version: String,
 }
 
-impl PrivateWebSocketClient {
+impl PublicWebSocketClient {
     /// Send a hello request and wait for the response
-    pub async fn hello(&mut self, request: HelloRequest) -> Result<HelloResult, DeribitWebSocketError> {
-        self.send_and_receive::<HelloRequest, HelloResult>(&request)
-            .await
+    ///
+    /// See: <https://docs.deribit.com/v2/#public-hello>
+    pub async fn hello(&self, request: HelloRequest) -> Result<HelloResult, DeribitWebSocketError> {
+        self.send_request("public/hello", &request).await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json;
+    use websockets::WebSocketConnection;
 
     use super::*;
+    use crate::deribit::connection::tests::spawn_server;
+    use crate::deribit::public::websocket::client::tests::test_client;
 
     #[test]
     fn test_hello_request_serialization() {
@@ -77,4 +81,26 @@ mod tests {
         assert_eq!(hello_req.client_name, "my_client");
         assert_eq!(hello_req.client_version, "2.1.0");
     }
+
+    #[tokio::test]
+    async fn test_hello_message_flow() {
+        let (url, _seen) = spawn_server(|request| {
+            assert_eq!(request["method"], "public/hello");
+            assert_eq!(request["params"]["client_name"], "test_client");
+            vec![serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": {"version": "1.2.26"}})]
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let result = client
+            .hello(HelloRequest {
+                client_name: "test_client".to_string(),
+                client_version: "1.0.0".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.version, "1.2.26");
+    }
 }
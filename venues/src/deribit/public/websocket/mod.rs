@@ -7,7 +7,7 @@ pub mod unsubscribe;
 pub mod unsubscribe_all;
 
 // Only connection management and client struct from client.rs
-pub use client::{PrivateWebSocketClient, DeribitWebSocketError};
+pub use client::{DeribitPublicMessage, DeribitWebSocketError, PublicWebSocketClient};
 // Endpoint request/response types and methods
 pub use hello::{HelloRequest, HelloResponse, HelloResult};
 pub use subscribe::{SubscribeRequest, SubscribeResponse};
@@ -3,7 +3,8 @@
 //! This method is used to subscribe to one or more public channels.
 //! This is the same method as /private/subscribe, but it can only be used for 'public' channels.
 
-use crate::deribit::public::websocket::client::{DeribitWebSocketError, PrivateWebSocketClient};
+use crate::deribit::channel::Channel;
+use crate::deribit::public::websocket::client::{DeribitWebSocketError, PublicWebSocketClient};
 use serde::{Deserialize, Serialize};
 
 /// Request parameters for the public/subscribe endpoint.
@@ -15,6 +16,15 @@ pub struct SubscribeRequest {
     pub channels: Vec<String>,
 }
 
+impl SubscribeRequest {
+    /// Build a request from typed channels
+    pub fn from_channels(channels: impl IntoIterator<Item = Channel>) -> Self {
+        Self {
+            channels: channels.into_iter().map(String::from).collect(),
+        }
+    }
+}
+
 /// Response for public/subscribe endpoint.
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct SubscribeResponse {
@@ -22,19 +32,24 @@ pub struct SubscribeResponse {
     pub result: Vec<String>,
 }
 
-impl PrivateWebSocketClient {
+impl PublicWebSocketClient {
     /// Send a subscribe request and wait for the response
-    pub async fn subscribe(&mut self, request: SubscribeRequest) -> Result<SubscribeResponse, DeribitWebSocketError> {
-        self.send_and_receive::<SubscribeRequest, SubscribeResponse>(&request)
-            .await
+    ///
+    /// See: <https://docs.deribit.com/v2/#public-subscribe>
+    pub async fn subscribe(&self, request: SubscribeRequest) -> Result<SubscribeResponse, DeribitWebSocketError> {
+        let result = self.send_request("public/subscribe", &request).await?;
+        Ok(SubscribeResponse { result })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json;
+    use websockets::WebSocketConnection;
 
     use super::*;
+    use crate::deribit::connection::tests::{echo_reply, spawn_server};
+    use crate::deribit::public::websocket::client::tests::test_client;
 
     #[test]
     fn test_subscribe_request_serialization() {
@@ -51,6 +66,21 @@ mod tests {
         assert_eq!(parsed["channels"][1], "trades.BTC-PERPETUAL");
     }
 
+    #[test]
+    fn test_subscribe_request_from_channels() {
+        use crate::deribit::SubscriptionInterval;
+
+        let request = SubscribeRequest::from_channels([
+            Channel::book("BTC-PERPETUAL", SubscriptionInterval::Ms100),
+            Channel::quote("ETH-PERPETUAL"),
+        ]);
+
+        assert_eq!(
+            request.channels,
+            vec!["book.BTC-PERPETUAL.100ms", "quote.ETH-PERPETUAL"]
+        );
+    }
+
     #[test]
     fn test_subscribe_request_single_channel() {
         let channels = vec!["ticker.BTC-PERPETUAL".to_string()];
@@ -112,4 +142,29 @@ mod tests {
 
         assert_eq!(response.result.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_subscribe_message_flow() {
+        let (url, _seen) = spawn_server(|request| {
+            assert_eq!(request["method"], "public/subscribe");
+            let mut reply = echo_reply(request);
+            reply["result"] = request["params"]["channels"].clone();
+            vec![reply]
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let response = client
+            .subscribe(SubscribeRequest {
+                channels: vec!["trades.BTC-PERPETUAL.raw".to_string()],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.result,
+            vec!["trades.BTC-PERPETUAL.raw".to_string()]
+        );
+    }
 }
@@ -1,7 +1,8 @@
 //! Unsubscribe from specific channels on Deribit WebSocket API.
 //!
 //! This file defines the request and response payloads for the `public/unsubscribe` RPC call.
-use crate::deribit::public::websocket::client::{DeribitWebSocketError, PrivateWebSocketClient};
+use crate::deribit::channel::Channel;
+use crate::deribit::public::websocket::client::{DeribitWebSocketError, PublicWebSocketClient};
 
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +13,15 @@ pub struct UnsubscribeRequest {
     pub channels: Vec<String>,
 }
 
+impl UnsubscribeRequest {
+    /// Build a request from typed channels
+    pub fn from_channels(channels: impl IntoIterator<Item = Channel>) -> Self {
+        Self {
+            channels: channels.into_iter().map(String::from).collect(),
+        }
+    }
+}
+
 /// Response for the `public/unsubscribe` method.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnsubscribeResponse {
@@ -19,10 +29,55 @@ pub struct UnsubscribeResponse {
     pub result: Vec<String>,
 }
 
-impl PrivateWebSocketClient {
+impl PublicWebSocketClient {
     /// Unsubscribe from specific channels
-    pub async fn unsubscribe(&mut self, request: UnsubscribeRequest) -> Result<UnsubscribeResponse, DeribitWebSocketError> {
-        self.send_and_receive::<UnsubscribeRequest, UnsubscribeResponse>(&request)
+    ///
+    /// See: <https://docs.deribit.com/v2/#public-unsubscribe>
+    pub async fn unsubscribe(&self, request: UnsubscribeRequest) -> Result<UnsubscribeResponse, DeribitWebSocketError> {
+        let result = self.send_request("public/unsubscribe", &request).await?;
+        Ok(UnsubscribeResponse { result })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use websockets::WebSocketConnection;
+
+    use super::*;
+    use crate::deribit::connection::tests::spawn_server;
+    use crate::deribit::public::websocket::client::tests::test_client;
+
+    #[test]
+    fn test_unsubscribe_request_serialization() {
+        let request = UnsubscribeRequest {
+            channels: vec!["book.BTC-PERPETUAL.100ms".to_string()],
+        };
+
+        let json = serde_json::to_string(&request).unwrap();
+
+        assert_eq!(json, r#"{"channels":["book.BTC-PERPETUAL.100ms"]}"#);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_message_flow() {
+        let (url, _seen) = spawn_server(|request| {
+            assert_eq!(request["method"], "public/unsubscribe");
+            vec![serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": request["params"]["channels"]})]
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let response = client
+            .unsubscribe(UnsubscribeRequest {
+                channels: vec!["book.BTC-PERPETUAL.100ms".to_string()],
+            })
             .await
+            .unwrap();
+
+        assert_eq!(
+            response.result,
+            vec!["book.BTC-PERPETUAL.100ms".to_string()]
+        );
     }
 }
@@ -1,13 +1,13 @@
 //! Unsubscribe from all channels on Deribit WebSocket API.
 //!
 //! This file defines the request and response payloads for the `public/unsubscribe_all` RPC call.
-use crate::deribit::public::websocket::client::{DeribitWebSocketError, PrivateWebSocketClient};
+use crate::deribit::public::websocket::client::{DeribitWebSocketError, PublicWebSocketClient};
 
 use serde::{Deserialize, Serialize};
 
 /// Request for the `public/unsubscribe_all` method (no parameters).
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct UnsubscribeAllRequest;
+pub struct UnsubscribeAllRequest {}
 
 /// Response for the `public/unsubscribe_all` method.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,11 +16,45 @@ pub struct UnsubscribeAllResponse {
     pub result: String,
 }
 
-impl PrivateWebSocketClient {
+impl PublicWebSocketClient {
     /// Unsubscribes from all channels for this client instance.
-    pub async fn unsubscribe_all(&mut self) -> Result<UnsubscribeAllResponse, DeribitWebSocketError> {
-        let req = UnsubscribeAllRequest::default();
-        self.send_and_receive::<UnsubscribeAllRequest, UnsubscribeAllResponse>(&req)
-            .await
+    ///
+    /// See: <https://docs.deribit.com/v2/#public-unsubscribe_all>
+    pub async fn unsubscribe_all(&self) -> Result<UnsubscribeAllResponse, DeribitWebSocketError> {
+        let result = self
+            .send_request("public/unsubscribe_all", &UnsubscribeAllRequest {})
+            .await?;
+        Ok(UnsubscribeAllResponse { result })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use websockets::WebSocketConnection;
+
+    use super::*;
+    use crate::deribit::connection::tests::spawn_server;
+    use crate::deribit::public::websocket::client::tests::test_client;
+
+    #[test]
+    fn test_unsubscribe_all_request_serialization() {
+        let json = serde_json::to_string(&UnsubscribeAllRequest {}).unwrap();
+
+        assert_eq!(json, "{}");
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_all_message_flow() {
+        let (url, _seen) = spawn_server(|request| {
+            assert_eq!(request["method"], "public/unsubscribe_all");
+            vec![serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": "ok"})]
+        })
+        .await;
+        let mut client = test_client(Some(url));
+        client.connect().await.unwrap();
+
+        let response = client.unsubscribe_all().await.unwrap();
+
+        assert_eq!(response.result, "ok");
     }
 }
@@ -1,7 +1,27 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rest::error::{RestError, VenueError};
 use thiserror::Error;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
+
+use crate::deribit::{AccountLimits, Errors, MatchingEngineLimits, RateLimitBucket};
+
+/// JSON-RPC error code of the `too_many_requests` error Deribit returns when a limit is exceeded
+pub const TOO_MANY_REQUESTS_CODE: i32 = 10028;
+
+/// Longest a queued request sleeps before re-checking capacity, in case a wakeup was missed
+const MAX_WAIT_POLL: Duration = Duration::from_millis(100);
+
+/// Back-off after a first `too_many_requests` error, doubled for each one that follows closely
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Upper bound for the back-off after repeated `too_many_requests` errors
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
 
 /// Account tiers for Deribit matching engine rate limits based on 7-day trading volume
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -38,6 +58,14 @@ impl AccountTier {
             AccountTier::Tier4 => 20,
         }
     }
+
+    /// The highest tier whose sustained rate does not exceed `rate` requests per second
+    pub fn from_sustained_rate(rate: u32) -> Self {
+        [AccountTier::Tier1, AccountTier::Tier2, AccountTier::Tier3]
+            .into_iter()
+            .find(|tier| rate >= tier.sustained_rate())
+            .unwrap_or(AccountTier::Tier4)
+    }
 }
 
 
@@ -135,6 +163,44 @@ impl EndpointType {
     }
 }
 
+/// What the rate limiter does when a request would exceed the limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitMode {
+    /// Fail immediately with a `RateLimitError`
+    #[default]
+    Reject,
+    /// Wait until enough capacity has refilled (see `RateLimiter::acquire`)
+    Wait,
+}
+
+/// Order in which requests waiting for the same limit are admitted
+///
+/// Waiting requests are admitted highest priority first, then in arrival order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum RequestPriority {
+    /// Queries and other requests that can wait
+    Low,
+    /// Order entry and everything not classified otherwise
+    #[default]
+    Normal,
+    /// Cancellations, admitted ahead of everything else
+    High,
+}
+
+impl RequestPriority {
+    /// Determine the priority of a request from its API path
+    pub fn from_path(path: &str) -> Self {
+        if path.starts_with("private/cancel") {
+            return RequestPriority::High;
+        }
+
+        match EndpointType::from_path(path) {
+            EndpointType::MatchingEngine => RequestPriority::Normal,
+            _ => RequestPriority::Low,
+        }
+    }
+}
+
 /// Rate limiting errors for Deribit
 #[derive(Error, Debug)]
 pub enum RateLimitError {
@@ -152,6 +218,18 @@ pub enum RateLimitError {
         endpoint: String,
         requests_in_window: usize,
     },
+
+    #[error("Waiting for {endpoint_type:?} capacity would exceed the maximum wait of {max_wait:?}")]
+    MaxWaitExceeded {
+        endpoint_type: EndpointType,
+        max_wait: Duration,
+    },
+
+    #[error("Matching engine rate limit exceeded for the {group} group")]
+    MatchingEngineGroupRateExceeded { group: String },
+
+    #[error("Backing off for {remaining:?} after a too_many_requests error")]
+    BackingOff { remaining: Duration },
 }
 
 /// Credit pool state for non-matching engine requests
@@ -204,6 +282,33 @@ impl CreditPool {
         self.available_credits = self.available_credits.saturating_sub(required);
         Ok(())
     }
+
+    /// Change the pool size and refill rate, keeping the credits already available
+    fn reconfigure(&mut self, max_credits: u32, refill_rate: u32) {
+        self.refill();
+        self.max_credits = max_credits;
+        self.refill_rate = refill_rate;
+        self.available_credits = self.available_credits.min(max_credits);
+    }
+
+    /// Drop all available credits, as the server reported the pool is exhausted
+    fn drain(&mut self) {
+        self.refill();
+        self.available_credits = 0;
+    }
+
+    /// Time until `required` credits will be available, assuming nothing else consumes them
+    #[allow(clippy::float_arithmetic)]
+    fn time_until_available(&self, required: u32) -> Duration {
+        let missing = required.saturating_sub(self.available_credits);
+        if missing == 0 {
+            return Duration::ZERO;
+        }
+        if self.refill_rate == 0 || required > self.max_credits {
+            return Duration::MAX;
+        }
+        Duration::try_from_secs_f64(f64::from(missing) / f64::from(self.refill_rate)).unwrap_or(Duration::MAX)
+    }
 }
 
 /// Request history for time-based rate limiting (matching engine and special endpoints)
@@ -248,6 +353,267 @@ impl RequestHistory {
     fn record_request(&mut self) {
         self.timestamps.push(Instant::now());
     }
+
+    /// Requests remaining in the current window, the window size and the time until
+    /// the oldest request in the window expires
+    fn status(&self) -> (u32, u32, Duration) {
+        let now = Instant::now();
+        let in_window: Vec<Instant> = self
+            .timestamps
+            .iter()
+            .copied()
+            .filter(|&timestamp| now.duration_since(timestamp) < self.window)
+            .collect();
+        let reset_in = in_window
+            .iter()
+            .min()
+            .map(|&oldest| self.window.saturating_sub(now.duration_since(oldest)))
+            .unwrap_or_default();
+        let remaining = self
+            .max_requests
+            .saturating_sub(u32::try_from(in_window.len()).unwrap_or(u32::MAX));
+        (remaining, self.max_requests, reset_in)
+    }
+
+    /// Time until the oldest request in a full window expires
+    fn time_until_slot(&self) -> Duration {
+        if self.timestamps.len() < self.max_requests as usize {
+            return Duration::ZERO;
+        }
+        if self.max_requests == 0 {
+            return Duration::MAX;
+        }
+        self.timestamps
+            .iter()
+            .min()
+            .and_then(|oldest| oldest.checked_add(self.window))
+            .map(|expiry| expiry.saturating_duration_since(Instant::now()))
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Kind of instrument as used for the per-kind matching engine trading limits
+///
+/// Derived from the instrument naming scheme, e.g. `BTC-PERPETUAL`, `BTC-27JUN25`,
+/// `BTC-27JUN25-100000-C`, `BTC-FS-27JUN25_PERP` and `BTC_USDC`.
+fn instrument_kind(instrument_name: &str) -> Option<&'static str> {
+    let parts: Vec<&str> = instrument_name.split('-').collect();
+    match parts.as_slice() {
+        [pair] if pair.contains('_') => Some("spot"),
+        [_, "PERPETUAL"] => Some("perpetual"),
+        [_, "FS", ..] => Some("future_combo"),
+        [_, _] => Some("future"),
+        [_, _, _, "C" | "P"] => Some("option"),
+        [_, _, ..] => Some("option_combo"),
+        _ => None,
+    }
+}
+
+/// Matching engine limit group a request is counted against, besides the overall trading limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchingEngineGroup<'a> {
+    /// Mass quotes and quote cancellation
+    Quotes,
+    /// The cancel_all family
+    CancelAll,
+    /// Orders on spot instruments
+    Spot,
+    /// Orders on other instruments, limited per instrument kind or per currency
+    Trading { kind: Option<&'static str>, currency: &'a str },
+}
+
+impl<'a> MatchingEngineGroup<'a> {
+    fn resolve(path: &str, instrument_name: Option<&'a str>) -> Option<Self> {
+        if matches!(path, "private/mass_quote" | "private/cancel_quotes") {
+            return Some(MatchingEngineGroup::Quotes);
+        }
+        if path.starts_with("private/cancel_all") {
+            return Some(MatchingEngineGroup::CancelAll);
+        }
+
+        let instrument_name = instrument_name?;
+        let kind = instrument_kind(instrument_name);
+        if kind == Some("spot") {
+            return Some(MatchingEngineGroup::Spot);
+        }
+        let currency = instrument_name
+            .split(['-', '_'])
+            .next()
+            .unwrap_or(instrument_name);
+        Some(MatchingEngineGroup::Trading { kind, currency })
+    }
+}
+
+/// State of one learned matching engine limit group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchingEngineGroupStatus {
+    /// Requests that can be sent right now
+    pub available: u32,
+    /// Maximum number of requests allowed in a burst
+    pub burst: u32,
+    /// Requests per second
+    pub rate: u32,
+}
+
+/// Matching engine limits learned from the account limits, each a bucket of one credit per request
+#[derive(Debug, Default)]
+struct MatchingEngineGroups {
+    /// Trading limits keyed by instrument kind or currency, excluding the overall "total"
+    trading: HashMap<String, CreditPool>,
+    spot: Option<CreditPool>,
+    quotes: Option<CreditPool>,
+    cancel_all: Option<CreditPool>,
+}
+
+impl MatchingEngineGroups {
+    fn sync_pool(pool: &mut Option<CreditPool>, bucket: Option<RateLimitBucket>) {
+        *pool = match (pool.take(), bucket) {
+            (Some(mut existing), Some(bucket)) => {
+                existing.reconfigure(bucket.burst, bucket.rate);
+                Some(existing)
+            }
+            (None, Some(bucket)) => Some(CreditPool::new(bucket.burst, bucket.rate)),
+            (_, None) => None,
+        };
+    }
+
+    /// Apply the server-reported limits, keeping the state of groups that already exist
+    fn sync(&mut self, limits: &MatchingEngineLimits) {
+        let trading = limits.trading.clone().unwrap_or_default();
+        self.trading
+            .retain(|key, _| trading.contains_key(key));
+        for (key, bucket) in trading {
+            if key == "total" {
+                continue;
+            }
+            let mut pool = self.trading.remove(&key);
+            Self::sync_pool(&mut pool, Some(bucket));
+            if let Some(pool) = pool {
+                self.trading.insert(key, pool);
+            }
+        }
+        Self::sync_pool(&mut self.spot, limits.spot);
+        Self::sync_pool(&mut self.quotes, limits.quotes);
+        Self::sync_pool(&mut self.cancel_all, limits.cancel_all);
+    }
+
+    /// The bucket of a group and its name, if a limit was learned for it
+    fn pool_mut(&mut self, group: MatchingEngineGroup<'_>) -> Option<(String, &mut CreditPool)> {
+        match group {
+            MatchingEngineGroup::Quotes => self.quotes.as_mut().map(|pool| ("quotes".to_string(), pool)),
+            MatchingEngineGroup::CancelAll => self
+                .cancel_all
+                .as_mut()
+                .map(|pool| ("cancel_all".to_string(), pool)),
+            MatchingEngineGroup::Spot => self.spot.as_mut().map(|pool| ("spot".to_string(), pool)),
+            MatchingEngineGroup::Trading { kind, currency } => {
+                let key = kind
+                    .filter(|kind| self.trading.contains_key(*kind))
+                    .unwrap_or(currency);
+                self.trading
+                    .get_mut(key)
+                    .map(|pool| (format!("trading.{key}"), pool))
+            }
+        }
+    }
+
+    fn status(&self) -> HashMap<String, MatchingEngineGroupStatus> {
+        let named = [("spot", &self.spot), ("quotes", &self.quotes), ("cancel_all", &self.cancel_all)];
+        let trading = self
+            .trading
+            .iter()
+            .map(|(key, pool)| (format!("trading.{key}"), pool));
+        named
+            .into_iter()
+            .filter_map(|(name, pool)| pool.as_ref().map(|pool| (name.to_string(), pool)))
+            .chain(trading)
+            .map(|(name, pool)| {
+                let mut pool = pool.clone();
+                pool.refill();
+                let status = MatchingEngineGroupStatus {
+                    available: pool.available_credits,
+                    burst: pool.max_credits,
+                    rate: pool.refill_rate,
+                };
+                (name, status)
+            })
+            .collect()
+    }
+}
+
+/// Back-off state after `too_many_requests` errors
+#[derive(Debug, Default)]
+struct Backoff {
+    /// End of the current back-off
+    until: Option<Instant>,
+    /// Length of the last back-off
+    current: Duration,
+}
+
+impl Backoff {
+    /// Time left in the current back-off, if any
+    fn remaining(&self) -> Option<Duration> {
+        self.until
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Start a new back-off, twice as long as the last one if that ended less than its own length ago
+    fn extend(&mut self) {
+        let now = Instant::now();
+        let repeated = self
+            .until
+            .and_then(|until| until.checked_add(self.current))
+            .is_some_and(|recent| now < recent);
+        self.current = if repeated {
+            self.current.saturating_mul(2).min(MAX_BACKOFF)
+        } else {
+            INITIAL_BACKOFF
+        };
+        self.until = now.checked_add(self.current);
+    }
+}
+
+/// Requests waiting for capacity on one limit, ordered by priority then arrival
+#[derive(Debug, Default)]
+struct WaitQueue {
+    /// Queued tickets; the first entry is the next request to be admitted
+    tickets: Mutex<BTreeSet<(Reverse<RequestPriority>, u64)>>,
+    /// Arrival counter used to keep equal priorities in FIFO order
+    next_sequence: AtomicU64,
+    /// Woken whenever a ticket leaves the queue
+    changed: Notify,
+}
+
+impl WaitQueue {
+    fn enqueue(&self, priority: RequestPriority) -> Ticket<'_> {
+        let key = (Reverse(priority), self.next_sequence.fetch_add(1, Ordering::Relaxed));
+        self.tickets.lock().insert(key);
+        Ticket { queue: self, key }
+    }
+
+    fn len(&self) -> usize {
+        self.tickets.lock().len()
+    }
+}
+
+/// Place in a `WaitQueue`; leaving the queue (including by dropping a cancelled future) wakes the others
+struct Ticket<'a> {
+    queue: &'a WaitQueue,
+    key: (Reverse<RequestPriority>, u64),
+}
+
+impl Ticket<'_> {
+    fn is_next(&self) -> bool {
+        self.queue.tickets.lock().first() == Some(&self.key)
+    }
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        self.queue.tickets.lock().remove(&self.key);
+        self.queue.changed.notify_waiters();
+    }
 }
 
 /// Deribit rate limiter implementing credit-based system
@@ -256,16 +622,36 @@ pub struct RateLimiter {
     /// Credit pool for non-matching engine requests
     credit_pool: RwLock<CreditPool>,
     /// Account tier for matching engine limits
-    account_tier: AccountTier,
+    account_tier: Mutex<AccountTier>,
     /// Request history for matching engine endpoints
     matching_engine_history: RwLock<RequestHistory>,
+    /// Per-group matching engine limits learned from the account limits
+    matching_engine_groups: RwLock<MatchingEngineGroups>,
     /// Request history for public/get_instruments endpoint
     get_instruments_history: RwLock<RequestHistory>,
+    /// Whether requests over the limit are rejected or wait for capacity
+    mode: RateLimitMode,
+    /// Longest a request may wait for capacity, unbounded when `None`
+    max_wait: Option<Duration>,
+    /// Requests waiting for credits
+    credit_queue: WaitQueue,
+    /// Requests waiting for a matching engine slot
+    matching_engine_queue: WaitQueue,
+    /// Requests waiting for a public/get_instruments slot
+    get_instruments_queue: WaitQueue,
+    /// Back-off after `too_many_requests` errors
+    backoff: Mutex<Backoff>,
+    /// Number of `too_many_requests` errors received
+    too_many_requests_errors: AtomicU64,
+    /// Whether the limits were learned from the account limits
+    limits_synced: AtomicBool,
 }
 
 impl Clone for RateLimiter {
     fn clone(&self) -> Self {
-        Self::new(self.account_tier)
+        Self::new(*self.account_tier.lock())
+            .with_mode(self.mode)
+            .with_optional_max_wait(self.max_wait)
     }
 }
 
@@ -287,9 +673,18 @@ impl RateLimiter {
 
         Self {
             credit_pool: RwLock::new(credit_pool),
-            account_tier,
+            account_tier: Mutex::new(account_tier),
             matching_engine_history: RwLock::new(matching_engine_history),
+            matching_engine_groups: RwLock::new(MatchingEngineGroups::default()),
             get_instruments_history: RwLock::new(get_instruments_history),
+            mode: RateLimitMode::default(),
+            max_wait: None,
+            credit_queue: WaitQueue::default(),
+            matching_engine_queue: WaitQueue::default(),
+            get_instruments_queue: WaitQueue::default(),
+            backoff: Mutex::new(Backoff::default()),
+            too_many_requests_errors: AtomicU64::new(0),
+            limits_synced: AtomicBool::new(false),
         }
     }
 
@@ -303,60 +698,227 @@ impl RateLimiter {
 
         Self {
             credit_pool: RwLock::new(credit_pool),
-            account_tier,
+            account_tier: Mutex::new(account_tier),
             matching_engine_history: RwLock::new(matching_engine_history),
+            matching_engine_groups: RwLock::new(MatchingEngineGroups::default()),
             get_instruments_history: RwLock::new(get_instruments_history),
+            mode: RateLimitMode::default(),
+            max_wait: None,
+            credit_queue: WaitQueue::default(),
+            matching_engine_queue: WaitQueue::default(),
+            get_instruments_queue: WaitQueue::default(),
+            backoff: Mutex::new(Backoff::default()),
+            too_many_requests_errors: AtomicU64::new(0),
+            limits_synced: AtomicBool::new(false),
         }
     }
 
-    /// Check if a request can be made for the given endpoint type
-    pub async fn check_limits(&self, endpoint_type: EndpointType) -> Result<(), RateLimitError> {
+    /// Set whether requests over the limit are rejected or wait for capacity
+    pub fn with_mode(mut self, mode: RateLimitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the longest a request may wait for capacity before failing with `MaxWaitExceeded`
+    pub fn with_max_wait(self, max_wait: Duration) -> Self {
+        self.with_optional_max_wait(Some(max_wait))
+    }
+
+    fn with_optional_max_wait(mut self, max_wait: Option<Duration>) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Whether requests over the limit are rejected or wait for capacity
+    pub fn mode(&self) -> RateLimitMode {
+        self.mode
+    }
+
+    /// Admit a request to `path` according to the configured mode
+    ///
+    /// In `RateLimitMode::Reject` this is `check_limits`; in `RateLimitMode::Wait` it is
+    /// `acquire_with_priority` with the priority derived from the path. The REST and
+    /// WebSocket clients call this before sending; on success the request is already counted.
+    ///
+    /// `instrument_name` selects the learned per-kind matching engine limit, if any
+    /// (see `sync_account_limits`).
+    pub async fn admit(&self, path: &str, endpoint_type: EndpointType, instrument_name: Option<&str>) -> Result<(), RateLimitError> {
+        let group = MatchingEngineGroup::resolve(path, instrument_name);
+        match self.mode {
+            RateLimitMode::Reject => self.check(endpoint_type, group).await,
+            RateLimitMode::Wait => {
+                self.acquire_in_group(endpoint_type, RequestPriority::from_path(path), group)
+                    .await
+            }
+        }
+    }
+
+    /// Wait until a request of the given endpoint type can be made, with normal priority
+    pub async fn acquire(&self, endpoint_type: EndpointType) -> Result<(), RateLimitError> {
+        self.acquire_with_priority(endpoint_type, RequestPriority::Normal)
+            .await
+    }
+
+    /// Wait until a request of the given endpoint type can be made
+    ///
+    /// Requests waiting on the same limit are admitted highest priority first, then in
+    /// arrival order. The wait is computed from the credit refill rate or the account
+    /// tier window. On success the credits or the window slot are already taken.
+    ///
+    /// Dropping the returned future before it completes leaves the limiter unchanged
+    /// and lets the next waiter proceed.
+    ///
+    /// # Errors
+    /// `RateLimitError::MaxWaitExceeded` if the configured maximum wait elapses, or would
+    /// elapse, before capacity is available.
+    pub async fn acquire_with_priority(&self, endpoint_type: EndpointType, priority: RequestPriority) -> Result<(), RateLimitError> {
+        self.acquire_in_group(endpoint_type, priority, None)
+            .await
+    }
+
+    async fn acquire_in_group(
+        &self,
+        endpoint_type: EndpointType,
+        priority: RequestPriority,
+        group: Option<MatchingEngineGroup<'_>>,
+    ) -> Result<(), RateLimitError> {
+        let Some(queue) = self.wait_queue(endpoint_type) else {
+            return Ok(());
+        };
+        let deadline = self
+            .max_wait
+            .and_then(|max_wait| Instant::now().checked_add(max_wait));
+        let ticket = queue.enqueue(priority);
+
+        loop {
+            let changed = queue.changed.notified();
+            tokio::pin!(changed);
+            changed.as_mut().enable();
+
+            let mut wait = MAX_WAIT_POLL;
+            if ticket.is_next() {
+                match self.try_reserve(endpoint_type, group).await {
+                    Ok(()) => return Ok(()),
+                    Err(until_available) => wait = until_available,
+                }
+            }
+
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() || (ticket.is_next() && wait > remaining) {
+                    return Err(RateLimitError::MaxWaitExceeded {
+                        endpoint_type,
+                        max_wait: self.max_wait.unwrap_or_default(),
+                    });
+                }
+                wait = wait.min(remaining);
+            }
+
+            tokio::select! {
+                _ = changed => {}
+                _ = tokio::time::sleep(wait.min(MAX_WAIT_POLL)) => {}
+            }
+        }
+    }
+
+    /// Queue of requests waiting on the limit that applies to the endpoint type
+    fn wait_queue(&self, endpoint_type: EndpointType) -> Option<&WaitQueue> {
+        match endpoint_type {
+            EndpointType::NonMatchingEngine | EndpointType::PublicGetComboIds | EndpointType::PublicGetCombos | EndpointType::PublicGetComboDetails | EndpointType::PublicGetStatus => {
+                Some(&self.credit_queue)
+            }
+            EndpointType::MatchingEngine => Some(&self.matching_engine_queue),
+            EndpointType::PublicGetInstruments => Some(&self.get_instruments_queue),
+            EndpointType::PublicHello => None,
+        }
+    }
+
+    /// Take credits or a window slot for the endpoint type, or return how long until one is available
+    async fn try_reserve(&self, endpoint_type: EndpointType, group: Option<MatchingEngineGroup<'_>>) -> Result<(), Duration> {
+        if endpoint_type != EndpointType::PublicHello
+            && let Some(remaining) = self.backoff.lock().remaining()
+        {
+            return Err(remaining);
+        }
+
         match endpoint_type {
             EndpointType::NonMatchingEngine | EndpointType::PublicGetComboIds | EndpointType::PublicGetCombos | EndpointType::PublicGetComboDetails | EndpointType::PublicGetStatus => {
                 let mut pool = self.credit_pool.write().await;
-                pool.consume_credits(endpoint_type.credit_cost())
+                let required = endpoint_type.credit_cost();
+                pool.consume_credits(required)
+                    .map_err(|_| pool.time_until_available(required))
             }
             EndpointType::MatchingEngine => {
                 let mut history = self.matching_engine_history.write().await;
-                history.check_limit().map_err(
-                    |requests_in_window| RateLimitError::MatchingEngineRateExceeded {
-                        tier: self.account_tier,
-                        requests_in_window,
-                    },
-                )
+                history.check_limit().map_err(|_| history.time_until_slot())?;
+                let mut groups = self.matching_engine_groups.write().await;
+                if let Some((_, pool)) = group.and_then(|group| groups.pool_mut(group)) {
+                    pool.consume_credits(1)
+                        .map_err(|_| pool.time_until_available(1))?;
+                }
+                history.record_request();
+                Ok(())
             }
             EndpointType::PublicGetInstruments => {
                 let mut history = self.get_instruments_history.write().await;
-                history.check_limit().map_err(
-                    |requests_in_window| RateLimitError::SpecialEndpointRateExceeded {
-                        endpoint: "public/get_instruments".to_string(),
-                        requests_in_window,
-                    },
-                )
-            }
-            EndpointType::PublicHello => {
-                // public/hello is WebSocket only with no documented rate limits
+                history.check_limit().map_err(|_| history.time_until_slot())?;
+                history.record_request();
                 Ok(())
             }
+            EndpointType::PublicHello => Ok(()),
         }
     }
 
-    /// Record a successful request for the given endpoint type
-    pub async fn record_request(&self, endpoint_type: EndpointType) {
+    /// Check if a request can be made for the given endpoint type and, if so, count it
+    ///
+    /// Credits and window slots are taken here, so a request is counted exactly once
+    /// whether it is admitted by `check_limits`, `acquire` or `admit`.
+    pub async fn check_limits(&self, endpoint_type: EndpointType) -> Result<(), RateLimitError> {
+        self.check(endpoint_type, None).await
+    }
+
+    async fn check(&self, endpoint_type: EndpointType, group: Option<MatchingEngineGroup<'_>>) -> Result<(), RateLimitError> {
+        if endpoint_type != EndpointType::PublicHello
+            && let Some(remaining) = self.backoff.lock().remaining()
+        {
+            return Err(RateLimitError::BackingOff { remaining });
+        }
+
         match endpoint_type {
             EndpointType::NonMatchingEngine | EndpointType::PublicGetComboIds | EndpointType::PublicGetCombos | EndpointType::PublicGetComboDetails | EndpointType::PublicGetStatus => {
-                // Credits are already consumed in check_limits
+                let mut pool = self.credit_pool.write().await;
+                pool.consume_credits(endpoint_type.credit_cost())
             }
             EndpointType::MatchingEngine => {
                 let mut history = self.matching_engine_history.write().await;
+                history.check_limit().map_err(
+                    |requests_in_window| RateLimitError::MatchingEngineRateExceeded {
+                        tier: *self.account_tier.lock(),
+                        requests_in_window,
+                    },
+                )?;
+                let mut groups = self.matching_engine_groups.write().await;
+                if let Some((name, pool)) = group.and_then(|group| groups.pool_mut(group)) {
+                    pool.consume_credits(1)
+                        .map_err(|_| RateLimitError::MatchingEngineGroupRateExceeded { group: name })?;
+                }
                 history.record_request();
+                Ok(())
             }
             EndpointType::PublicGetInstruments => {
                 let mut history = self.get_instruments_history.write().await;
+                history.check_limit().map_err(
+                    |requests_in_window| RateLimitError::SpecialEndpointRateExceeded {
+                        endpoint: "public/get_instruments".to_string(),
+                        requests_in_window,
+                    },
+                )?;
                 history.record_request();
+                Ok(())
             }
             EndpointType::PublicHello => {
-                // No rate limit tracking needed for public/hello
+                // public/hello is WebSocket only with no documented rate limits
+                Ok(())
             }
         }
     }
@@ -369,23 +931,86 @@ impl RateLimiter {
 
         let matching_history = self.matching_engine_history.read().await;
         let instruments_history = self.get_instruments_history.read().await;
+        let matching_engine_groups = self.matching_engine_groups.read().await.status();
 
         RateLimitStatus {
             available_credits: pool_clone.available_credits,
             max_credits: pool_clone.max_credits,
             credit_refill_rate: pool_clone.refill_rate,
-            account_tier: self.account_tier,
+            account_tier: *self.account_tier.lock(),
             matching_engine_requests_in_window: matching_history.timestamps.len() as u32,
             instruments_requests_in_window: instruments_history.timestamps.len() as u32,
+            mode: self.mode,
+            queued_requests: self
+                .credit_queue
+                .len()
+                .saturating_add(self.matching_engine_queue.len())
+                .saturating_add(self.get_instruments_queue.len()),
+            matching_engine_groups,
+            limits_synced: self.limits_synced.load(Ordering::Relaxed),
+            backoff_remaining: self.backoff.lock().remaining(),
+            too_many_requests_errors: self.too_many_requests_errors.load(Ordering::Relaxed),
         }
     }
 
     /// Update account tier (e.g., when trading volume changes)
     pub async fn update_account_tier(&self, new_tier: AccountTier) {
-        if new_tier != self.account_tier {
-            let mut history = self.matching_engine_history.write().await;
-            *history = RequestHistory::new(Duration::from_secs(1), new_tier.sustained_rate());
+        self.set_matching_engine_rate(new_tier, new_tier.sustained_rate())
+            .await;
+    }
+
+    async fn set_matching_engine_rate(&self, tier: AccountTier, rate: u32) {
+        let mut history = self.matching_engine_history.write().await;
+        *self.account_tier.lock() = tier;
+        history.max_requests = rate;
+    }
+
+    /// Apply the limits reported in the `limits` field of `private/get_account_summary`
+    ///
+    /// The non-matching engine bucket sizes the credit pool, the overall "total" trading
+    /// bucket replaces the tier-based matching engine limit, and the spot, quotes,
+    /// cancel_all and per-kind (or per-currency) trading buckets are enforced in addition
+    /// for requests that fall in them. Credits already used are kept.
+    pub async fn sync_account_limits(&self, limits: &AccountLimits) {
+        if let Some(bucket) = limits.non_matching_engine {
+            let cost = EndpointType::NonMatchingEngine.credit_cost();
+            self.credit_pool
+                .write()
+                .await
+                .reconfigure(bucket.burst.saturating_mul(cost), bucket.rate.saturating_mul(cost));
         }
+
+        if let Some(matching_engine) = &limits.matching_engine {
+            let total = matching_engine
+                .trading
+                .as_ref()
+                .and_then(|trading| trading.get("total"));
+            if let Some(total) = total {
+                self.set_matching_engine_rate(AccountTier::from_sustained_rate(total.rate), total.rate)
+                    .await;
+            }
+            self.matching_engine_groups
+                .write()
+                .await
+                .sync(matching_engine);
+        }
+
+        self.limits_synced.store(true, Ordering::Relaxed);
+    }
+
+    /// Record a JSON-RPC error code returned for a request
+    ///
+    /// On `too_many_requests` (10028) the credit pool is emptied and all requests back
+    /// off, starting at 100ms and doubling up to 5s while the errors keep coming.
+    pub async fn record_error(&self, code: i32) {
+        if code != TOO_MANY_REQUESTS_CODE {
+            return;
+        }
+
+        self.too_many_requests_errors
+            .fetch_add(1, Ordering::Relaxed);
+        self.backoff.lock().extend();
+        self.credit_pool.write().await.drain();
     }
 }
 
@@ -404,6 +1029,70 @@ pub struct RateLimitStatus {
     pub matching_engine_requests_in_window: u32,
     /// Number of public/get_instruments requests in current 10-second window
     pub instruments_requests_in_window: u32,
+    /// Whether requests over the limit are rejected or wait for capacity
+    pub mode: RateLimitMode,
+    /// Number of requests currently waiting for capacity
+    pub queued_requests: usize,
+    /// Learned matching engine groups by name ("spot", "quotes", "cancel_all", "trading.<kind or currency>")
+    pub matching_engine_groups: HashMap<String, MatchingEngineGroupStatus>,
+    /// Whether the limits were learned from the account limits
+    pub limits_synced: bool,
+    /// Time left in the back-off after a `too_many_requests` error
+    pub backoff_remaining: Option<Duration>,
+    /// Number of `too_many_requests` errors received
+    pub too_many_requests_errors: u64,
+}
+
+#[async_trait]
+impl rest::rate_limiter::RateLimiter for RateLimiter {
+    type Key = EndpointType;
+
+    /// Admits the request as `admit` does, so in `RateLimitMode::Wait` this waits for capacity
+    async fn check_limit(&self, endpoint: &str, endpoint_type: &EndpointType) -> Result<(), RestError> {
+        self.admit(endpoint, *endpoint_type, None)
+            .await
+            .map_err(|err| VenueError::from(Errors::RateLimitError(err)).into())
+    }
+
+    /// Nothing to do: `check_limit` already counted the request
+    async fn record_request(&self, _endpoint: &str, _endpoint_type: &EndpointType) {}
+
+    /// Credits for credit-based endpoints, requests in the window for the others
+    async fn get_rate_limit_status(&self, endpoint_type: &EndpointType) -> rest::rate_limiter::RateLimitStatus {
+        let (remaining, limit, reset_in) = match endpoint_type {
+            EndpointType::NonMatchingEngine
+            | EndpointType::PublicGetComboIds
+            | EndpointType::PublicGetCombos
+            | EndpointType::PublicGetComboDetails
+            | EndpointType::PublicGetStatus => {
+                let mut pool = self.credit_pool.read().await.clone();
+                pool.refill();
+                (
+                    pool.available_credits,
+                    pool.max_credits,
+                    pool.time_until_available(pool.max_credits),
+                )
+            }
+            EndpointType::MatchingEngine => self.matching_engine_history.read().await.status(),
+            EndpointType::PublicGetInstruments => self.get_instruments_history.read().await.status(),
+            EndpointType::PublicHello => (u32::MAX, u32::MAX, Duration::ZERO),
+        };
+
+        // Nothing is admitted while backing off after a too_many_requests error
+        let backoff = self.backoff.lock().remaining();
+        match backoff {
+            Some(backoff) if *endpoint_type != EndpointType::PublicHello => rest::rate_limiter::RateLimitStatus {
+                remaining: 0,
+                limit,
+                reset_in: backoff.max(reset_in),
+            },
+            _ => rest::rate_limiter::RateLimitStatus {
+                remaining,
+                limit,
+                reset_in,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -527,9 +1216,6 @@ mod tests {
                 .await
                 .is_ok()
         );
-        limiter
-            .record_request(EndpointType::NonMatchingEngine)
-            .await;
 
         let status = limiter.get_status().await;
         assert_eq!(status.available_credits, 50_000 - 500); // 500 credits consumed
@@ -547,7 +1233,6 @@ mod tests {
                     .await
                     .is_ok()
             );
-            limiter.record_request(EndpointType::MatchingEngine).await;
         }
 
         // 6th request should fail
@@ -571,9 +1256,6 @@ mod tests {
                     .await
                     .is_ok()
             );
-            limiter
-                .record_request(EndpointType::PublicGetInstruments)
-                .await;
         }
 
         // 6th request should fail
@@ -597,7 +1279,6 @@ mod tests {
                     .await
                     .is_ok()
             );
-            limiter.record_request(EndpointType::PublicHello).await;
         }
 
         // Should still work after many requests
@@ -651,7 +1332,6 @@ mod tests {
                     .await
                     .is_ok()
             );
-            limiter.record_request(EndpointType::MatchingEngine).await;
         }
 
         // Should be at limit
@@ -699,9 +1379,6 @@ mod tests {
                 "Non-matching engine request {} should succeed",
                 i
             );
-            limiter
-                .record_request(EndpointType::NonMatchingEngine)
-                .await;
         }
 
         // Make some matching engine requests (Tier3 allows 10 per second)
@@ -714,7 +1391,6 @@ mod tests {
                 "Matching engine request {} should succeed",
                 i
             );
-            limiter.record_request(EndpointType::MatchingEngine).await;
         }
 
         // This should fail as we're at the tier limit
@@ -728,7 +1404,9 @@ mod tests {
         // Check status
         let status = limiter.get_status().await;
         assert_eq!(status.account_tier, AccountTier::Tier3);
-        assert_eq!(status.available_credits, 50_000 - (10 * 500)); // 10 non-matching requests used
+        // 10 non-matching requests used; credits keep refilling in the meantime
+        assert!(status.available_credits >= 50_000 - (10 * 500));
+        assert!(status.available_credits < 50_000 - (9 * 500));
         assert_eq!(status.matching_engine_requests_in_window, 10);
     }
 
@@ -745,9 +1423,6 @@ mod tests {
                     .await
                     .is_ok()
             );
-            limiter
-                .record_request(EndpointType::NonMatchingEngine)
-                .await;
         }
 
         let status = limiter.get_status().await;
@@ -763,4 +1438,443 @@ mod tests {
             "Should not have refilled completely yet"
         );
     }
+
+    #[test]
+    fn test_request_priority_from_path() {
+        assert_eq!(RequestPriority::from_path("private/cancel_all"), RequestPriority::High);
+        assert_eq!(RequestPriority::from_path("private/cancel"), RequestPriority::High);
+        assert_eq!(RequestPriority::from_path("private/buy"), RequestPriority::Normal);
+        assert_eq!(RequestPriority::from_path("private/get_positions"), RequestPriority::Low);
+        assert!(RequestPriority::High > RequestPriority::Normal);
+        assert!(RequestPriority::Normal > RequestPriority::Low);
+    }
+
+    #[tokio::test]
+    async fn test_admit_rejects_by_default() {
+        let limiter = RateLimiter::with_custom_credits(AccountTier::Tier4, 500, 1);
+
+        assert!(limiter.admit("public/get_time", EndpointType::NonMatchingEngine, None).await.is_ok());
+        assert!(matches!(
+            limiter.admit("public/get_time", EndpointType::NonMatchingEngine, None).await,
+            Err(RateLimitError::CreditLimitExceeded { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_credit_refill() {
+        let limiter = RateLimiter::with_custom_credits(AccountTier::Tier4, 1000, 2000).with_mode(RateLimitMode::Wait);
+
+        limiter.acquire(EndpointType::NonMatchingEngine).await.unwrap();
+        limiter.acquire(EndpointType::NonMatchingEngine).await.unwrap();
+        let started = Instant::now();
+        limiter.acquire(EndpointType::NonMatchingEngine).await.unwrap();
+
+        // 500 credits at 2000 credits/sec
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_matching_engine_window() {
+        let limiter = RateLimiter::new(AccountTier::Tier4).with_mode(RateLimitMode::Wait);
+
+        for _ in 0..5 {
+            limiter.acquire(EndpointType::MatchingEngine).await.unwrap();
+        }
+        assert_eq!(limiter.get_status().await.matching_engine_requests_in_window, 5);
+
+        let started = Instant::now();
+        limiter.acquire(EndpointType::MatchingEngine).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_counts_request_once_in_reject_mode() {
+        let limiter = RateLimiter::new(AccountTier::Tier4);
+        assert_eq!(limiter.mode(), RateLimitMode::Reject);
+
+        limiter
+            .acquire_with_priority(EndpointType::MatchingEngine, RequestPriority::High)
+            .await
+            .unwrap();
+        assert_eq!(limiter.get_status().await.matching_engine_requests_in_window, 1);
+
+        limiter
+            .admit("private/buy", EndpointType::MatchingEngine, None)
+            .await
+            .unwrap();
+        assert_eq!(limiter.get_status().await.matching_engine_requests_in_window, 2);
+
+        // The remaining three of the five per second are still available
+        for _ in 0..3 {
+            limiter.check_limits(EndpointType::MatchingEngine).await.unwrap();
+        }
+        assert!(limiter.check_limits(EndpointType::MatchingEngine).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_fails_when_max_wait_would_be_exceeded() {
+        let limiter = RateLimiter::with_custom_credits(AccountTier::Tier4, 500, 1)
+            .with_mode(RateLimitMode::Wait)
+            .with_max_wait(Duration::from_millis(50));
+
+        limiter.acquire(EndpointType::NonMatchingEngine).await.unwrap();
+        let started = Instant::now();
+        let result = limiter.acquire(EndpointType::NonMatchingEngine).await;
+
+        assert!(matches!(
+            result,
+            Err(RateLimitError::MaxWaitExceeded {
+                endpoint_type: EndpointType::NonMatchingEngine,
+                ..
+            })
+        ));
+        assert!(started.elapsed() < Duration::from_millis(50));
+        assert_eq!(limiter.get_status().await.queued_requests, 0);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_admits_higher_priority_first() {
+        let limiter = std::sync::Arc::new(RateLimiter::with_custom_credits(AccountTier::Tier4, 500, 5000).with_mode(RateLimitMode::Wait));
+        limiter.acquire(EndpointType::NonMatchingEngine).await.unwrap();
+        let (order_tx, mut order_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        for priority in [RequestPriority::Low, RequestPriority::High] {
+            let limiter = limiter.clone();
+            let order_tx = order_tx.clone();
+            tokio::spawn(async move {
+                limiter
+                    .acquire_with_priority(EndpointType::NonMatchingEngine, priority)
+                    .await
+                    .unwrap();
+                order_tx.send(priority).unwrap();
+            });
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(order_rx.recv().await, Some(RequestPriority::High));
+        assert_eq!(order_rx.recv().await, Some(RequestPriority::Low));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_acquire_leaves_queue() {
+        let limiter = RateLimiter::with_custom_credits(AccountTier::Tier4, 500, 5000).with_mode(RateLimitMode::Wait);
+        limiter.acquire(EndpointType::NonMatchingEngine).await.unwrap();
+
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(10),
+            limiter.acquire(EndpointType::NonMatchingEngine),
+        )
+        .await;
+        assert!(cancelled.is_err());
+        assert_eq!(limiter.get_status().await.queued_requests, 0);
+
+        // The dropped request took no credits, so the next one only waits for the refill
+        limiter.acquire(EndpointType::NonMatchingEngine).await.unwrap();
+        assert!(limiter.get_status().await.available_credits < 500);
+    }
+
+    #[tokio::test]
+    async fn test_clone_keeps_mode() {
+        let limiter = RateLimiter::new(AccountTier::Tier4)
+            .with_mode(RateLimitMode::Wait)
+            .with_max_wait(Duration::from_secs(1));
+
+        let status = limiter.clone().get_status().await;
+        assert_eq!(status.mode, RateLimitMode::Wait);
+        assert_eq!(status.queued_requests, 0);
+    }
+
+    fn account_limits(matching_engine: serde_json::Value) -> AccountLimits {
+        serde_json::from_value(serde_json::json!({
+            "limits_per_currency": false,
+            "non_matching_engine": {"rate": 20, "burst": 100},
+            "matching_engine": matching_engine
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_account_tier_from_sustained_rate() {
+        assert_eq!(AccountTier::from_sustained_rate(50), AccountTier::Tier1);
+        assert_eq!(AccountTier::from_sustained_rate(20), AccountTier::Tier2);
+        assert_eq!(AccountTier::from_sustained_rate(15), AccountTier::Tier3);
+        assert_eq!(AccountTier::from_sustained_rate(5), AccountTier::Tier4);
+        assert_eq!(AccountTier::from_sustained_rate(0), AccountTier::Tier4);
+    }
+
+    #[test]
+    fn test_instrument_kind() {
+        assert_eq!(instrument_kind("BTC-PERPETUAL"), Some("perpetual"));
+        assert_eq!(instrument_kind("BTC_USDC-PERPETUAL"), Some("perpetual"));
+        assert_eq!(instrument_kind("BTC-27JUN25"), Some("future"));
+        assert_eq!(instrument_kind("BTC-27JUN25-100000-C"), Some("option"));
+        assert_eq!(instrument_kind("ETH-27JUN25-3000-P"), Some("option"));
+        assert_eq!(instrument_kind("BTC-FS-27JUN25_PERP"), Some("future_combo"));
+        assert_eq!(instrument_kind("BTC-CS-27JUN25-100000_110000"), Some("option_combo"));
+        assert_eq!(instrument_kind("BTC_USDC"), Some("spot"));
+        assert_eq!(instrument_kind("BTC"), None);
+    }
+
+    #[tokio::test]
+    async fn test_update_account_tier_updates_status() {
+        let limiter = RateLimiter::new(AccountTier::Tier4);
+
+        limiter.update_account_tier(AccountTier::Tier2).await;
+
+        assert_eq!(limiter.get_status().await.account_tier, AccountTier::Tier2);
+    }
+
+    #[tokio::test]
+    async fn test_sync_account_limits() {
+        let limiter = RateLimiter::new(AccountTier::Tier4);
+        let limits = account_limits(serde_json::json!({
+            "trading": {"total": {"rate": 30, "burst": 100}},
+            "spot": {"rate": 5, "burst": 20},
+            "quotes": {"rate": 500, "burst": 500},
+            "max_quotes": {"rate": 10, "burst": 10},
+            "cancel_all": {"rate": 5, "burst": 20}
+        }));
+
+        limiter.sync_account_limits(&limits).await;
+
+        let status = limiter.get_status().await;
+        assert!(status.limits_synced);
+        assert_eq!(status.max_credits, 50_000);
+        assert_eq!(status.credit_refill_rate, 10_000);
+        assert_eq!(status.account_tier, AccountTier::Tier1);
+        assert_eq!(status.matching_engine_groups.len(), 3);
+        assert_eq!(
+            status.matching_engine_groups["quotes"],
+            MatchingEngineGroupStatus {
+                available: 500,
+                burst: 500,
+                rate: 500
+            }
+        );
+
+        // 30 requests per second instead of the 5 of Tier4
+        for _ in 0..30 {
+            limiter.check_limits(EndpointType::MatchingEngine).await.unwrap();
+        }
+        assert!(limiter.check_limits(EndpointType::MatchingEngine).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sync_account_limits_keeps_used_credits() {
+        let limiter = RateLimiter::with_custom_credits(AccountTier::Tier4, 1000, 1);
+        limiter.check_limits(EndpointType::NonMatchingEngine).await.unwrap();
+
+        limiter
+            .sync_account_limits(&account_limits(serde_json::json!({})))
+            .await;
+
+        let status = limiter.get_status().await;
+        assert_eq!(status.max_credits, 50_000);
+        assert!(status.available_credits < 1000);
+    }
+
+    #[tokio::test]
+    async fn test_matching_engine_group_limits() {
+        let limiter = RateLimiter::new(AccountTier::Tier1);
+        let limits = account_limits(serde_json::json!({
+            "quotes": {"rate": 1, "burst": 2}
+        }));
+        limiter.sync_account_limits(&limits).await;
+
+        for _ in 0..2 {
+            limiter
+                .admit("private/mass_quote", EndpointType::MatchingEngine, None)
+                .await
+                .unwrap();
+        }
+        let result = limiter
+            .admit("private/mass_quote", EndpointType::MatchingEngine, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(RateLimitError::MatchingEngineGroupRateExceeded { ref group }) if group == "quotes"
+        ));
+
+        // Orders are not counted against the quotes group
+        assert!(
+            limiter
+                .admit("private/buy", EndpointType::MatchingEngine, Some("BTC-PERPETUAL"))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_matching_engine_trading_limits_per_instrument_kind() {
+        let limiter = RateLimiter::new(AccountTier::Tier1);
+        let limits = account_limits(serde_json::json!({
+            "trading": {
+                "total": {"rate": 30, "burst": 100},
+                "option": {"rate": 1, "burst": 1}
+            }
+        }));
+        limiter.sync_account_limits(&limits).await;
+
+        let option = Some("BTC-27JUN25-100000-C");
+        limiter
+            .admit("private/buy", EndpointType::MatchingEngine, option)
+            .await
+            .unwrap();
+        let result = limiter
+            .admit("private/sell", EndpointType::MatchingEngine, option)
+            .await;
+        assert!(matches!(
+            result,
+            Err(RateLimitError::MatchingEngineGroupRateExceeded { ref group }) if group == "trading.option"
+        ));
+
+        assert!(
+            limiter
+                .admit("private/buy", EndpointType::MatchingEngine, Some("BTC-PERPETUAL"))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_matching_engine_trading_limits_per_currency() {
+        let limiter = RateLimiter::new(AccountTier::Tier1);
+        let limits = account_limits(serde_json::json!({
+            "trading": {"ETH": {"rate": 1, "burst": 1}}
+        }));
+        limiter.sync_account_limits(&limits).await;
+
+        limiter
+            .admit("private/buy", EndpointType::MatchingEngine, Some("ETH-PERPETUAL"))
+            .await
+            .unwrap();
+        assert!(
+            limiter
+                .admit("private/buy", EndpointType::MatchingEngine, Some("ETH-27JUN25"))
+                .await
+                .is_err()
+        );
+        assert!(
+            limiter
+                .admit("private/buy", EndpointType::MatchingEngine, Some("BTC-PERPETUAL"))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_backs_off() {
+        let limiter = RateLimiter::new(AccountTier::Tier4);
+
+        limiter.record_error(10009).await;
+        assert!(limiter.get_status().await.backoff_remaining.is_none());
+
+        limiter.record_error(TOO_MANY_REQUESTS_CODE).await;
+
+        let status = limiter.get_status().await;
+        assert_eq!(status.too_many_requests_errors, 1);
+        assert!(status.backoff_remaining.is_some());
+        assert!(status.available_credits < 500);
+        assert!(matches!(
+            limiter.check_limits(EndpointType::MatchingEngine).await,
+            Err(RateLimitError::BackingOff { .. })
+        ));
+        assert!(limiter.check_limits(EndpointType::PublicHello).await.is_ok());
+
+        sleep(INITIAL_BACKOFF).await;
+        assert!(limiter.check_limits(EndpointType::MatchingEngine).await.is_ok());
+    }
+
+    #[test]
+    fn test_backoff_doubles_on_repeated_errors() {
+        let mut backoff = Backoff::default();
+
+        backoff.extend();
+        assert_eq!(backoff.current, INITIAL_BACKOFF);
+        backoff.extend();
+        assert_eq!(backoff.current, INITIAL_BACKOFF * 2);
+
+        backoff.current = MAX_BACKOFF;
+        backoff.extend();
+        assert_eq!(backoff.current, MAX_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_out_backoff() {
+        let limiter = RateLimiter::new(AccountTier::Tier4).with_mode(RateLimitMode::Wait);
+        limiter.record_error(TOO_MANY_REQUESTS_CODE).await;
+
+        let started = Instant::now();
+        limiter.acquire(EndpointType::MatchingEngine).await.unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_rest_rate_limiter_trait() {
+        use rest::error::ErrorKind;
+
+        let limiter = RateLimiter::with_custom_credits(AccountTier::Tier4, 1000, 0);
+        // Call through the trait, as the generic `rest` clients do
+        let rest_limiter: &dyn rest::rate_limiter::RateLimiter<Key = EndpointType> = &limiter;
+
+        let status = rest_limiter
+            .get_rate_limit_status(&EndpointType::NonMatchingEngine)
+            .await;
+        assert_eq!(status.remaining, 1000);
+        assert_eq!(status.limit, 1000);
+
+        for _ in 0..2 {
+            rest_limiter
+                .check_limit("public/ticker", &EndpointType::NonMatchingEngine)
+                .await
+                .unwrap();
+            rest_limiter
+                .record_request("public/ticker", &EndpointType::NonMatchingEngine)
+                .await;
+        }
+        let status = rest_limiter
+            .get_rate_limit_status(&EndpointType::NonMatchingEngine)
+            .await;
+        assert_eq!(status.remaining, 0);
+
+        let err = rest_limiter
+            .check_limit("public/ticker", &EndpointType::NonMatchingEngine)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited { retry_after: None });
+
+        // Matching engine requests are counted per window
+        rest_limiter
+            .check_limit("private/buy", &EndpointType::MatchingEngine)
+            .await
+            .unwrap();
+        rest_limiter
+            .record_request("private/buy", &EndpointType::MatchingEngine)
+            .await;
+        let status = rest_limiter
+            .get_rate_limit_status(&EndpointType::MatchingEngine)
+            .await;
+        assert_eq!(status.limit, AccountTier::Tier4.sustained_rate());
+        assert_eq!(status.remaining, AccountTier::Tier4.sustained_rate() - 1);
+        assert!(status.reset_in <= Duration::from_secs(1));
+
+        // Back-off blocks everything but public/hello
+        limiter.record_error(TOO_MANY_REQUESTS_CODE).await;
+        let status = rest_limiter
+            .get_rate_limit_status(&EndpointType::MatchingEngine)
+            .await;
+        assert_eq!(status.remaining, 0);
+        assert!(status.reset_in > Duration::ZERO);
+        let err = rest_limiter
+            .check_limit("private/buy", &EndpointType::MatchingEngine)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::RateLimited { retry_after: Some(_) }));
+        rest_limiter
+            .check_limit("public/hello", &EndpointType::PublicHello)
+            .await
+            .unwrap();
+    }
 }
@@ -1,8 +1,13 @@
 use std::fmt;
 
+use rest::error::{ErrorKind, VenueError};
+use rest::transport::TransportError;
 use serde::Deserialize;
 use thiserror::Error;
 
+/// Venue name used when converting into [`VenueError`]
+pub(crate) const VENUE: &str = "okx";
+
 /// Represents all possible errors that can occur when interacting with the OKX API
 #[derive(Debug)]
 pub enum Errors {
@@ -14,7 +19,7 @@ pub enum Errors {
     /// such as network issues or HTTP errors.
     /// It can be used to wrap any error that occurs during the request process.
     /// This variant is not used for errors returned by the OKX API itself.
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
 
     /// An error returned by the OKX API
     ApiError(ApiError),
@@ -36,9 +41,15 @@ impl fmt::Display for Errors {
 
 impl std::error::Error for Errors {}
 
+impl From<TransportError> for Errors {
+    fn from(err: TransportError) -> Self {
+        Errors::HttpError(err)
+    }
+}
+
 impl From<reqwest::Error> for Errors {
     fn from(err: reqwest::Error) -> Self {
-        Errors::HttpError(err)
+        Errors::HttpError(TransportError::from(err))
     }
 }
 
@@ -161,6 +172,88 @@ impl From<ErrorResponse> for ApiError {
     }
 }
 
+impl ApiError {
+    /// The OKX error code this error was created from
+    pub fn code(&self) -> &str {
+        match self {
+            ApiError::Success => "0",
+            ApiError::InvalidParameter => "50000",
+            ApiError::MissingParameter => "50001",
+            ApiError::InvalidInstrumentId => "50002",
+            ApiError::InvalidOrderType => "50004",
+            ApiError::InvalidOrderSide => "50005",
+            ApiError::InvalidOrderSize => "50006",
+            ApiError::InvalidPrice => "50007",
+            ApiError::InsufficientBalance => "50101",
+            ApiError::AccountSuspended => "50102",
+            ApiError::PositionNotFound => "50103",
+            ApiError::OrderNotFound => "50104",
+            ApiError::RateLimitExceeded => "50011",
+            ApiError::InvalidApiKey => "50100",
+            ApiError::InvalidSignature => "50105",
+            ApiError::InvalidTimestamp => "50113",
+            ApiError::SystemMaintenance => "50012",
+            ApiError::InternalServerError => "50013",
+            ApiError::UnmappedApiError { code, .. } => code,
+        }
+    }
+
+    /// The venue-agnostic classification of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::Success => ErrorKind::Other,
+            ApiError::InvalidParameter
+            | ApiError::MissingParameter
+            | ApiError::InvalidInstrumentId
+            | ApiError::InvalidOrderType
+            | ApiError::InvalidOrderSide
+            | ApiError::InvalidOrderSize
+            | ApiError::InvalidPrice
+            | ApiError::PositionNotFound => ErrorKind::InvalidRequest,
+            ApiError::InsufficientBalance => ErrorKind::InsufficientFunds,
+            ApiError::OrderNotFound => ErrorKind::OrderNotFound,
+            ApiError::RateLimitExceeded => ErrorKind::RateLimited { retry_after: None },
+            ApiError::AccountSuspended | ApiError::InvalidApiKey | ApiError::InvalidSignature | ApiError::InvalidTimestamp => ErrorKind::AuthFailed,
+            ApiError::SystemMaintenance => ErrorKind::ExchangeUnavailable,
+            ApiError::InternalServerError => ErrorKind::UnknownExecutionStatus,
+            ApiError::UnmappedApiError { code, .. } => error_kind(code),
+        }
+    }
+}
+
+/// Classify an OKX error code that has no dedicated [`ApiError`] variant
+fn error_kind(code: &str) -> ErrorKind {
+    match code {
+        "50061" => ErrorKind::RateLimited { retry_after: None },
+        "50026" => ErrorKind::ExchangeUnavailable,
+        "51008" => ErrorKind::InsufficientFunds,
+        "51400" | "51603" => ErrorKind::OrderNotFound,
+        _ => match code.parse::<u32>() {
+            Ok(50106..=50119) => ErrorKind::AuthFailed,
+            Ok(51000..=51999) => ErrorKind::InvalidRequest,
+            _ => ErrorKind::Other,
+        },
+    }
+}
+
+impl From<&Errors> for VenueError {
+    fn from(err: &Errors) -> Self {
+        match err {
+            Errors::InvalidApiKey() => VenueError::new(VENUE, ErrorKind::AuthFailed, err.to_string()),
+            Errors::HttpError(err) => VenueError::from_http_error(VENUE, err),
+            Errors::ApiError(ApiError::UnmappedApiError { code, msg }) => VenueError::new(VENUE, error_kind(code), msg.clone()).with_code(code),
+            Errors::ApiError(api_error) => VenueError::new(VENUE, api_error.kind(), api_error.to_string()).with_code(api_error.code()),
+            Errors::Error(msg) => VenueError::new(VENUE, ErrorKind::Other, msg.clone()),
+        }
+    }
+}
+
+impl From<Errors> for VenueError {
+    fn from(err: Errors) -> Self {
+        VenueError::from(&err)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::assertions_on_constants)]
 mod tests {
@@ -216,4 +309,38 @@ mod tests {
         let error_string = format!("{}", invalid_key_error);
         assert_eq!(error_string, "Invalid API key or signature");
     }
+
+    #[test]
+    fn test_venue_error_keeps_code_and_message() {
+        let error: VenueError = Errors::ApiError(ApiError::OrderNotFound).into();
+        assert_eq!(error.kind, ErrorKind::OrderNotFound);
+        assert_eq!(error.code.as_deref(), Some("50104"));
+        assert_eq!(error.venue, VENUE);
+
+        let error: VenueError = Errors::ApiError(ApiError::from(ErrorResponse {
+            code: "51008".to_string(),
+            msg: "Order failed. Insufficient balance".to_string(),
+        }))
+        .into();
+        assert_eq!(error.kind, ErrorKind::InsufficientFunds);
+        assert_eq!(error.code.as_deref(), Some("51008"));
+        assert_eq!(error.message, "Order failed. Insufficient balance");
+    }
+
+    #[test]
+    fn test_venue_error_classification() {
+        assert_eq!(
+            ApiError::RateLimitExceeded.kind(),
+            ErrorKind::RateLimited { retry_after: None }
+        );
+        assert_eq!(ApiError::InvalidSignature.kind(), ErrorKind::AuthFailed);
+        assert_eq!(
+            ApiError::SystemMaintenance.kind(),
+            ErrorKind::ExchangeUnavailable
+        );
+        assert_eq!(error_kind("51603"), ErrorKind::OrderNotFound);
+        assert_eq!(error_kind("50111"), ErrorKind::AuthFailed);
+        assert_eq!(error_kind("51121"), ErrorKind::InvalidRequest);
+        assert_eq!(error_kind("99999"), ErrorKind::Other);
+    }
 }
@@ -3,16 +3,21 @@
 // Provides access to all private REST API endpoints for OKX Exchange.
 // All requests are authenticated and require API credentials.
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
 
+use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose};
-use chrono::Utc;
-use hmac::{Hmac, Mac};
-use reqwest::Client;
-use rest::secrets::ExposableSecret;
+use chrono::{DateTime, Utc};
+use rest::clock::ServerClock;
+use rest::error::{RestError, VenueError};
+use rest::request::{RestRequest, RestResponse};
+use rest::secrets::{ExposableSecret, HmacSigner};
+use rest::transport::{HttpRequest, HttpTransport};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use sha2::Sha256;
 
+use crate::okx::errors::VENUE;
 use crate::okx::{EndpointType, Errors, RateLimiter, RestResult};
 
 /// Private REST client for OKX exchange
@@ -28,7 +33,7 @@ pub struct RestClient {
     /// The underlying HTTP client used for making requests.
     ///
     /// This is reused for connection pooling and performance.
-    pub client: Client,
+    pub client: Arc<dyn HttpTransport>,
 
     /// The rate limiter used to manage request rates and prevent hitting API limits.
     ///
@@ -38,11 +43,14 @@ pub struct RestClient {
     /// The encrypted API key.
     pub(crate) api_key: Box<dyn ExposableSecret>,
 
-    /// The encrypted API secret.
-    pub(crate) api_secret: Box<dyn ExposableSecret>,
+    /// Signs requests with the API secret, which may be held by a remote signer.
+    pub(crate) api_secret: Box<dyn HmacSigner>,
 
     /// The encrypted API passphrase.
     pub(crate) api_passphrase: Box<dyn ExposableSecret>,
+
+    /// The server clock signed requests are timestamped with.
+    pub(crate) clock: ServerClock,
 }
 
 impl RestClient {
@@ -50,29 +58,36 @@ impl RestClient {
     ///
     /// # Arguments
     /// * `api_key` - The encrypted API key
-    /// * `api_secret` - The encrypted API secret  
+    /// * `api_secret` - Signer for the API secret, e.g. a Vault transit key
     /// * `api_passphrase` - The encrypted API passphrase
     /// * `base_url` - The base URL for the OKX private REST API (e.g., "https://www.okx.com")
     /// * `client` - The HTTP client to use for requests
     /// * `rate_limiter` - The rate limiter for managing API limits
     pub fn new(
         api_key: Box<dyn ExposableSecret>,
-        api_secret: Box<dyn ExposableSecret>,
+        api_secret: Box<dyn HmacSigner>,
         api_passphrase: Box<dyn ExposableSecret>,
         base_url: impl Into<Cow<'static, str>>,
-        client: Client,
+        client: impl HttpTransport + 'static,
         rate_limiter: RateLimiter,
     ) -> Self {
         Self {
             base_url: base_url.into(),
-            client,
+            client: Arc::new(client),
             rate_limiter,
             api_key,
             api_secret,
             api_passphrase,
+            clock: ServerClock::default(),
         }
     }
 
+    /// Takes the `OK-ACCESS-TIMESTAMP` header of signed requests from `clock`
+    pub fn with_server_clock(mut self, clock: ServerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Sign a request for OKX private endpoints
     ///
     /// Creates the signature according to OKX's signing algorithm:
@@ -88,17 +103,19 @@ impl RestClient {
     ///
     /// # Returns
     /// A result containing the signature as a base64 string or an error
-    pub fn sign_request(&self, timestamp: &str, method: &str, request_path: &str, body: &str) -> Result<String, Errors> {
+    pub async fn sign_request(&self, timestamp: &str, method: &str, request_path: &str, body: &str) -> Result<String, Errors> {
         // Create the pre-hash string: timestamp + method + requestPath + body
         let pre_hash = format!("{}{}{}{}", timestamp, method, request_path, body);
 
         // Sign with HMAC SHA256
-        let api_secret = self.api_secret.expose_secret();
-        let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).map_err(|_| Errors::InvalidApiKey())?;
-        mac.update(pre_hash.as_bytes());
+        let signature = self
+            .api_secret
+            .hmac_sha256(pre_hash.as_bytes())
+            .await
+            .map_err(|_| Errors::InvalidApiKey())?;
 
         // Encode as Base64
-        Ok(general_purpose::STANDARD.encode(mac.finalize().into_bytes()))
+        Ok(general_purpose::STANDARD.encode(signature))
     }
 
     /// Send a request to a private endpoint
@@ -125,17 +142,22 @@ impl RestClient {
         let url = format!("{}/{}", self.base_url.trim_end_matches('/'), endpoint);
 
         // Create timestamp
-        let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+        let timestamp = i64::try_from(self.clock.now_ms())
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .unwrap_or_else(Utc::now)
+            .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+            .to_string();
 
         // Prepare request
-        let mut request_builder = self.client.request(method.clone(), &url);
+        let mut request = HttpRequest::new(method.clone(), url);
 
         // Handle query parameters for GET requests or body for POST/PUT/DELETE
         let (request_path, body) = if method == reqwest::Method::GET {
             if let Some(params) = params {
                 let query_string = serde_urlencoded::to_string(params).map_err(|e| Errors::Error(format!("Failed to serialize query parameters: {}", e)))?;
                 if !query_string.is_empty() {
-                    request_builder = request_builder.query(&query_string);
+                    request.url = format!("{}?{}", request.url, query_string);
                     (format!("/{}?{}", endpoint, query_string), String::new())
                 } else {
                     (format!("/{}", endpoint), String::new())
@@ -151,52 +173,128 @@ impl RestClient {
             };
 
             if !body.is_empty() {
-                request_builder = request_builder.body(body.clone());
-                request_builder = request_builder.header("Content-Type", "application/json");
+                request = request
+                    .body(body.clone())
+                    .header("Content-Type", "application/json");
             }
 
             (format!("/{}", endpoint), body)
         };
 
         // Create signature
-        let signature = self.sign_request(&timestamp, method.as_str(), &request_path, &body)?;
+        let signature = self
+            .sign_request(&timestamp, method.as_str(), &request_path, &body)
+            .await?;
 
         // Add required headers
         let api_key = self.api_key.expose_secret();
         let api_passphrase = self.api_passphrase.expose_secret();
 
-        request_builder = request_builder
+        let request = request
             .header("OK-ACCESS-KEY", api_key.as_str())
-            .header("OK-ACCESS-SIGN", &signature)
-            .header("OK-ACCESS-TIMESTAMP", &timestamp)
+            .header("OK-ACCESS-SIGN", signature)
+            .header("OK-ACCESS-TIMESTAMP", timestamp)
             .header("OK-ACCESS-PASSPHRASE", api_passphrase.as_str());
 
         // Send request
-        let response = request_builder.send().await?;
+        let response = self.client.execute(request).await?;
 
         // Record request for rate limiting
         self.rate_limiter.increment_request(endpoint_type).await;
 
         // Handle response
-        if response.status().is_success() {
-            let response_text = response.text().await?;
-
+        if response.status.is_success() {
             // Parse the response
-            let parsed: T = serde_json::from_str(&response_text).map_err(|e| Errors::Error(format!("Failed to parse response: {}", e)))?;
+            let parsed: T = serde_json::from_str(&response.body).map_err(|e| Errors::Error(format!("Failed to parse response: {}", e)))?;
 
             Ok(parsed)
         } else {
-            let status = response.status();
-            let error_text = response.text().await?;
-            Err(Errors::Error(format!("HTTP {}: {}", status, error_text)))
+            Err(Errors::Error(format!(
+                "HTTP {}: {}",
+                response.status, response.body
+            )))
         }
     }
 }
 
+#[async_trait]
+impl rest::request::RestClient for RestClient {
+    type RateLimiter = RateLimiter;
+
+    fn venue(&self) -> &'static str {
+        VENUE
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    async fn send(&self, request: RestRequest<EndpointType>) -> Result<RestResponse<serde_json::Value>, RestError> {
+        let start = Instant::now();
+        let data = self
+            .send_request::<serde_json::Value, _>(
+                &request.endpoint,
+                request.method,
+                request.params.as_ref(),
+                request.rate_limit_key,
+            )
+            .await
+            .map_err(VenueError::from)?;
+        Ok(RestResponse::new(data, start.elapsed()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::{Client, StatusCode};
+    use rest::middleware::{HeadersMiddleware, MetricsMiddleware, Pipeline};
+    use rest::transport::MockTransport;
+    use serde_json::json;
+
     use super::*;
 
+    #[tokio::test]
+    async fn test_timestamp_header_uses_server_clock() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({ "code": "0", "msg": "", "data": [] }),
+        );
+        let clock = ServerClock::new();
+        // Sent at 1000 local, the server read 2020-08-10T02:26:23.085Z
+        clock.record(1000, 1_597_026_383_085, std::time::Duration::ZERO);
+        let rest_client = RestClient::new(
+            Box::new(TestSecret::new("test_key".to_string())),
+            Box::new(TestSecret::new("test_secret".to_string())),
+            Box::new(TestSecret::new("test_passphrase".to_string())),
+            "https://www.okx.com",
+            transport.clone(),
+            RateLimiter::new(),
+        )
+        .with_server_clock(clock);
+
+        let _: serde_json::Value = rest_client
+            .send_request(
+                "api/v5/account/balance",
+                reqwest::Method::GET,
+                None::<&()>,
+                EndpointType::PrivateAccount,
+            )
+            .await
+            .unwrap();
+
+        let sent = transport.last_request().unwrap();
+        let timestamp = sent.header_value("OK-ACCESS-TIMESTAMP").unwrap();
+        let timestamp = DateTime::parse_from_rfc3339(timestamp).unwrap();
+        let local = Utc::now().timestamp_millis();
+        let offset = timestamp.timestamp_millis().saturating_sub(local);
+        assert!(offset.saturating_sub(1_597_026_382_085).abs() < 1000);
+    }
+
     #[derive(Clone)]
     struct TestSecret {
         secret: String,
@@ -217,7 +315,7 @@ mod tests {
     #[test]
     fn test_private_client_creation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let api_passphrase = Box::new(TestSecret::new("test_passphrase".to_string())) as Box<dyn ExposableSecret>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
@@ -234,10 +332,10 @@ mod tests {
         assert_eq!(rest_client.base_url, "https://www.okx.com");
     }
 
-    #[test]
-    fn test_signature_generation() {
+    #[tokio::test]
+    async fn test_signature_generation() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let api_passphrase = Box::new(TestSecret::new("test_passphrase".to_string())) as Box<dyn ExposableSecret>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
@@ -258,6 +356,7 @@ mod tests {
 
         let signature = rest_client
             .sign_request(timestamp, method, request_path, body)
+            .await
             .unwrap();
 
         // Verify the signature is a valid base64 string
@@ -267,7 +366,7 @@ mod tests {
     #[tokio::test]
     async fn test_rate_limiting_integration() {
         let api_key = Box::new(TestSecret::new("test_key".to_string())) as Box<dyn ExposableSecret>;
-        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn ExposableSecret>;
+        let api_secret = Box::new(TestSecret::new("test_secret".to_string())) as Box<dyn HmacSigner>;
         let api_passphrase = Box::new(TestSecret::new("test_passphrase".to_string())) as Box<dyn ExposableSecret>;
         let client = Client::new();
         let rate_limiter = RateLimiter::new();
@@ -288,4 +387,54 @@ mod tests {
             .await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_requests_pass_through_middleware() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({ "code": "0", "msg": "", "data": [] }),
+        );
+        let metrics = MetricsMiddleware::new();
+        let pipeline = Pipeline::new(transport.clone())
+            .with(HeadersMiddleware::new().header("User-Agent", "ccrxt"))
+            .with(metrics.clone());
+        let rest_client = RestClient::new(
+            Box::new(TestSecret::new("test_key".to_string())),
+            Box::new(TestSecret::new("test_secret".to_string())),
+            Box::new(TestSecret::new("test_passphrase".to_string())),
+            "https://www.okx.com",
+            pipeline,
+            RateLimiter::new(),
+        );
+
+        let _: serde_json::Value = rest_client
+            .send_request(
+                "api/v5/account/balance",
+                reqwest::Method::GET,
+                Some(&[("ccy", "BTC")]),
+                EndpointType::PrivateAccount,
+            )
+            .await
+            .unwrap();
+
+        let sent = transport.last_request().unwrap();
+        assert_eq!(
+            sent.url,
+            "https://www.okx.com/api/v5/account/balance?ccy=BTC"
+        );
+        assert_eq!(sent.header_value("User-Agent"), Some("ccrxt"));
+        assert_eq!(sent.header_value("OK-ACCESS-KEY"), Some("test_key"));
+        assert_eq!(
+            sent.header_value("OK-ACCESS-PASSPHRASE"),
+            Some("test_passphrase")
+        );
+        let timestamp = sent.header_value("OK-ACCESS-TIMESTAMP").unwrap();
+        let expected = rest_client
+            .sign_request(timestamp, "GET", "/api/v5/account/balance?ccy=BTC", "")
+            .await
+            .unwrap();
+        assert_eq!(sent.header_value("OK-ACCESS-SIGN"), Some(expected.as_str()));
+        assert_eq!(metrics.metrics().success, 1);
+    }
 }
@@ -1,6 +1,10 @@
+use async_trait::async_trait;
+use rest::clock::TimeSource;
+use rest::error::{ErrorKind, RestError, VenueError};
 use serde::{Deserialize, Serialize};
 
 use super::client::RestClient;
+use crate::okx::errors::VENUE;
 use crate::okx::{EndpointType, RestResult};
 
 /// Time data structure
@@ -43,11 +47,34 @@ impl RestClient {
     }
 }
 
+#[async_trait]
+impl TimeSource for RestClient {
+    async fn server_time_ms(&self) -> Result<i64, RestError> {
+        let response = self.get_time().await.map_err(VenueError::from)?;
+        response
+            .data
+            .first()
+            .and_then(|time| time.ts.parse().ok())
+            .ok_or_else(|| {
+                VenueError::new(
+                    VENUE,
+                    ErrorKind::Other,
+                    format!("Invalid server time: {}", response.code),
+                )
+                .into()
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use reqwest::StatusCode;
+    use rest::clock::ServerClock;
+    use rest::transport::MockTransport;
     use serde_json::json;
 
     use super::*;
+    use crate::okx::RateLimiter;
 
     #[test]
     fn test_time_data_structure() {
@@ -133,4 +160,24 @@ mod tests {
         // This proves the method signature is correct without calling it
         println!("get_time method is accessible and properly typed");
     }
+
+    #[tokio::test]
+    async fn test_server_clock_syncs_from_get_time() {
+        let transport = MockTransport::new();
+        transport.push_json(
+            StatusCode::OK,
+            json!({ "code": "0", "msg": "", "data": [{ "ts": "1597026383085" }] }),
+        );
+        let client = RestClient::new("https://www.okx.com", transport.clone(), RateLimiter::new());
+        let clock = ServerClock::new();
+
+        clock.sync(&client).await.unwrap();
+
+        assert_eq!(
+            transport.last_request().unwrap().url,
+            "https://www.okx.com/api/v5/public/time"
+        );
+        assert!(clock.offset_ms() < 0);
+        assert!(clock.now_ms().abs_diff(1_597_026_383_085) < 1000);
+    }
 }
@@ -0,0 +1,485 @@
+//! Fan-out of one message stream to many consumers
+//!
+//! `Broadcaster` copies every message into a bounded queue per subscriber, so one socket
+//! can feed an order book builder, a trade recorder and a risk monitor at once. What
+//! happens when a subscriber's queue is full is set by the `LagPolicy`, and each
+//! subscriber's backlog and losses are reported by `SubscriberMetrics`.
+
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::task::AtomicWaker;
+use futures::{Stream, StreamExt};
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+/// What a `Broadcaster` does when a subscriber's queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LagPolicy {
+    /// Drop the subscriber's oldest queued message to make room
+    #[default]
+    DropOldest,
+    /// Disconnect the subscriber; its stream ends
+    DisconnectSlowConsumer,
+    /// Wait until the subscriber has room, slowing down every other subscriber too
+    Block,
+}
+
+/// Lag counters of one subscriber
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubscriberMetrics {
+    /// Subscriber id, unique within the broadcaster
+    pub id: u64,
+    /// Messages waiting in the queue, i.e. how far the subscriber is behind
+    pub queued: usize,
+    /// Highest number of messages ever waiting in the queue
+    pub max_queued: usize,
+    /// Messages taken from the queue by the subscriber
+    pub delivered: u64,
+    /// Messages dropped because the queue was full
+    pub dropped: u64,
+    /// Whether the subscriber was disconnected for being too slow
+    pub disconnected: bool,
+}
+
+/// Queue and counters of one subscriber
+struct Slot<T> {
+    id: u64,
+    queue: Mutex<VecDeque<T>>,
+    /// Wakes the subscriber when a message arrives or the slot closes
+    waker: AtomicWaker,
+    /// Wakes blocked senders when the subscriber takes a message or goes away
+    space: Notify,
+    /// No more messages will arrive: the subscriber was disconnected, dropped, or the broadcaster closed
+    closed: AtomicBool,
+    disconnected: AtomicBool,
+    max_queued: AtomicUsize,
+    delivered: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl<T> Slot<T> {
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.waker.wake();
+        self.space.notify_waiters();
+    }
+
+    fn metrics(&self) -> SubscriberMetrics {
+        SubscriberMetrics {
+            id: self.id,
+            queued: self.queue.lock().len(),
+            max_queued: self.max_queued.load(Ordering::Relaxed),
+            delivered: self.delivered.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            disconnected: self.disconnected.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Queue a message after making sure there is room
+    fn push(&self, queue: &mut VecDeque<T>, item: T) {
+        queue.push_back(item);
+        self.max_queued.fetch_max(queue.len(), Ordering::Relaxed);
+        self.waker.wake();
+    }
+}
+
+struct Inner<T> {
+    subscribers: Mutex<HashMap<u64, Arc<Slot<T>>>>,
+    capacity: usize,
+    policy: LagPolicy,
+    next_id: AtomicU64,
+    closed: AtomicBool,
+}
+
+/// Sends every message to all subscribers through bounded per-subscriber queues
+pub struct Broadcaster<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Clone for Broadcaster<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> Broadcaster<T> {
+    /// Create a broadcaster whose subscribers buffer up to `capacity` messages (at least 1)
+    pub fn new(capacity: usize, policy: LagPolicy) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                subscribers: Mutex::new(HashMap::new()),
+                capacity: capacity.max(1),
+                policy,
+                next_id: AtomicU64::new(0),
+                closed: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Add a subscriber that receives every message sent from now on
+    pub fn subscribe(&self) -> BroadcastReceiver<T> {
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let slot = Arc::new(Slot {
+            id,
+            queue: Mutex::new(VecDeque::with_capacity(self.inner.capacity)),
+            waker: AtomicWaker::new(),
+            space: Notify::new(),
+            closed: AtomicBool::new(self.inner.closed.load(Ordering::SeqCst)),
+            disconnected: AtomicBool::new(false),
+            max_queued: AtomicUsize::new(0),
+            delivered: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        });
+        self.inner.subscribers.lock().insert(id, slot.clone());
+        BroadcastReceiver {
+            broadcaster: self.inner.clone(),
+            slot,
+        }
+    }
+
+    /// Number of connected subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.inner.subscribers.lock().len()
+    }
+
+    /// Lag counters of every connected subscriber, ordered by id
+    pub fn metrics(&self) -> Vec<SubscriberMetrics> {
+        let mut metrics: Vec<SubscriberMetrics> = self
+            .inner
+            .subscribers
+            .lock()
+            .values()
+            .map(|slot| slot.metrics())
+            .collect();
+        metrics.sort_by_key(|metrics| metrics.id);
+        metrics
+    }
+
+    /// Send a message to every subscriber
+    ///
+    /// With `LagPolicy::Block` this waits until every subscriber has room. Returns the
+    /// number of subscribers the message was queued for.
+    pub async fn send(&self, item: T) -> usize {
+        let slots: Vec<Arc<Slot<T>>> = self.inner.subscribers.lock().values().cloned().collect();
+        let mut queued = 0usize;
+
+        for slot in slots {
+            let delivered = match self.inner.policy {
+                LagPolicy::DropOldest => self.send_drop_oldest(&slot, item.clone()),
+                LagPolicy::DisconnectSlowConsumer => self.send_or_disconnect(&slot, item.clone()),
+                LagPolicy::Block => self.send_blocking(&slot, item.clone()).await,
+            };
+            if delivered {
+                queued = queued.saturating_add(1);
+            }
+        }
+        queued
+    }
+
+    /// Forward every item of `stream` until it ends, then close the broadcaster
+    pub async fn forward<S>(&self, stream: S)
+    where
+        S: Stream<Item = T> + Send,
+    {
+        futures::pin_mut!(stream);
+        while let Some(item) = stream.next().await {
+            self.send(item).await;
+        }
+        self.close();
+    }
+
+    /// End every subscriber's stream once it has drained its queue
+    pub fn close(&self) {
+        self.inner.closed.store(true, Ordering::SeqCst);
+        for slot in self.inner.subscribers.lock().values() {
+            slot.close();
+        }
+    }
+
+    fn send_drop_oldest(&self, slot: &Slot<T>, item: T) -> bool {
+        if slot.closed.load(Ordering::SeqCst) {
+            return false;
+        }
+        let mut queue = slot.queue.lock();
+        if queue.len() >= self.inner.capacity {
+            queue.pop_front();
+            slot.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        slot.push(&mut queue, item);
+        true
+    }
+
+    fn send_or_disconnect(&self, slot: &Slot<T>, item: T) -> bool {
+        if slot.closed.load(Ordering::SeqCst) {
+            return false;
+        }
+        let mut queue = slot.queue.lock();
+        if queue.len() >= self.inner.capacity {
+            let discarded = u64::try_from(queue.len()).unwrap_or(u64::MAX);
+            queue.clear();
+            drop(queue);
+            slot.dropped
+                .fetch_add(discarded.saturating_add(1), Ordering::Relaxed);
+            slot.disconnected.store(true, Ordering::Relaxed);
+            slot.close();
+            self.inner.subscribers.lock().remove(&slot.id);
+            return false;
+        }
+        slot.push(&mut queue, item);
+        true
+    }
+
+    async fn send_blocking(&self, slot: &Slot<T>, item: T) -> bool {
+        loop {
+            let space = slot.space.notified();
+            futures::pin_mut!(space);
+            space.as_mut().enable();
+
+            if slot.closed.load(Ordering::SeqCst) {
+                return false;
+            }
+            {
+                let mut queue = slot.queue.lock();
+                if queue.len() < self.inner.capacity {
+                    slot.push(&mut queue, item);
+                    return true;
+                }
+            }
+            space.await;
+        }
+    }
+}
+
+/// One subscriber of a `Broadcaster`; a `Stream` of the messages sent after it subscribed
+///
+/// The stream ends when the broadcaster is closed and the queue is drained, or right
+/// away when the subscriber is disconnected for being too slow.
+pub struct BroadcastReceiver<T> {
+    broadcaster: Arc<Inner<T>>,
+    slot: Arc<Slot<T>>,
+}
+
+impl<T> BroadcastReceiver<T> {
+    /// Subscriber id, as reported in `SubscriberMetrics`
+    pub fn id(&self) -> u64 {
+        self.slot.id
+    }
+
+    /// This subscriber's lag counters
+    pub fn metrics(&self) -> SubscriberMetrics {
+        self.slot.metrics()
+    }
+
+    /// Whether this subscriber was disconnected for being too slow
+    pub fn is_disconnected(&self) -> bool {
+        self.slot.disconnected.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Stream for BroadcastReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let slot = &self.slot;
+        // Register before checking, so a message queued in between still wakes us
+        slot.waker.register(cx.waker());
+
+        let item = slot.queue.lock().pop_front();
+        match item {
+            Some(item) => {
+                slot.delivered.fetch_add(1, Ordering::Relaxed);
+                slot.space.notify_waiters();
+                Poll::Ready(Some(item))
+            }
+            None if slot.closed.load(Ordering::SeqCst) => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Drop for BroadcastReceiver<T> {
+    fn drop(&mut self) {
+        self.broadcaster.subscribers.lock().remove(&self.slot.id);
+        self.slot.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    async fn next<T>(receiver: &mut BroadcastReceiver<T>) -> Option<T> {
+        tokio::time::timeout(Duration::from_secs(1), receiver.next())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_every_subscriber_receives_every_message() {
+        let broadcaster = Broadcaster::new(8, LagPolicy::DropOldest);
+        let mut first = broadcaster.subscribe();
+        let mut second = broadcaster.subscribe();
+
+        assert_eq!(broadcaster.send(1).await, 2);
+        assert_eq!(broadcaster.send(2).await, 2);
+
+        assert_eq!(next(&mut first).await, Some(1));
+        assert_eq!(next(&mut first).await, Some(2));
+        assert_eq!(next(&mut second).await, Some(1));
+        assert_eq!(next(&mut second).await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_keeps_the_latest_messages() {
+        let broadcaster = Broadcaster::new(2, LagPolicy::DropOldest);
+        let mut slow = broadcaster.subscribe();
+
+        for item in 1..=5 {
+            broadcaster.send(item).await;
+        }
+
+        let metrics = slow.metrics();
+        assert_eq!(metrics.queued, 2);
+        assert_eq!(metrics.max_queued, 2);
+        assert_eq!(metrics.dropped, 3);
+        assert_eq!(next(&mut slow).await, Some(4));
+        assert_eq!(next(&mut slow).await, Some(5));
+        assert_eq!(slow.metrics().delivered, 2);
+    }
+
+    #[tokio::test]
+    async fn test_slow_consumer_is_disconnected() {
+        let broadcaster = Broadcaster::new(2, LagPolicy::DisconnectSlowConsumer);
+        let mut slow = broadcaster.subscribe();
+        let mut fast = broadcaster.subscribe();
+
+        broadcaster.send(1).await;
+        assert_eq!(next(&mut fast).await, Some(1));
+        broadcaster.send(2).await;
+        assert_eq!(next(&mut fast).await, Some(2));
+        assert_eq!(broadcaster.send(3).await, 1);
+
+        assert!(slow.is_disconnected());
+        assert_eq!(slow.metrics().dropped, 3);
+        assert_eq!(next(&mut slow).await, None);
+        assert_eq!(broadcaster.subscriber_count(), 1);
+        assert_eq!(next(&mut fast).await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_block_waits_for_the_slowest_subscriber() {
+        let broadcaster = Broadcaster::new(1, LagPolicy::Block);
+        let mut slow = broadcaster.subscribe();
+        broadcaster.send(1).await;
+
+        let sender = broadcaster.clone();
+        let blocked = tokio::spawn(async move { sender.send(2).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!blocked.is_finished());
+
+        assert_eq!(next(&mut slow).await, Some(1));
+        assert_eq!(blocked.await.unwrap(), 1);
+        assert_eq!(next(&mut slow).await, Some(2));
+        assert_eq!(slow.metrics().dropped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_subscriber_unblocks_sender() {
+        let broadcaster = Broadcaster::new(1, LagPolicy::Block);
+        let slow = broadcaster.subscribe();
+        broadcaster.send(1).await;
+
+        let sender = broadcaster.clone();
+        let blocked = tokio::spawn(async move { sender.send(2).await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(slow);
+
+        assert_eq!(
+            tokio::time::timeout(Duration::from_secs(1), blocked)
+                .await
+                .unwrap()
+                .unwrap(),
+            0
+        );
+        assert_eq!(broadcaster.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_per_subscriber() {
+        let broadcaster = Broadcaster::new(4, LagPolicy::DropOldest);
+        let mut reader = broadcaster.subscribe();
+        let _idle = broadcaster.subscribe();
+
+        for item in 0..3 {
+            broadcaster.send(item).await;
+        }
+        next(&mut reader).await;
+
+        let metrics = broadcaster.metrics();
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(
+            metrics,
+            vec![
+                SubscriberMetrics {
+                    id: 0,
+                    queued: 2,
+                    max_queued: 3,
+                    delivered: 1,
+                    dropped: 0,
+                    disconnected: false,
+                },
+                SubscriberMetrics {
+                    id: 1,
+                    queued: 3,
+                    max_queued: 3,
+                    delivered: 0,
+                    dropped: 0,
+                    disconnected: false,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forward_closes_after_the_stream_ends() {
+        let broadcaster = Broadcaster::new(8, LagPolicy::DropOldest);
+        let mut receiver = broadcaster.subscribe();
+
+        broadcaster
+            .forward(futures::stream::iter(vec!["a", "b"]))
+            .await;
+
+        assert_eq!(next(&mut receiver).await, Some("a"));
+        assert_eq!(next(&mut receiver).await, Some("b"));
+        assert_eq!(next(&mut receiver).await, None);
+        // Late subscribers see a closed stream
+        let mut late = broadcaster.subscribe();
+        assert_eq!(next(&mut late).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_receiver_is_woken_by_send() {
+        let broadcaster = Broadcaster::new(8, LagPolicy::DropOldest);
+        let mut receiver = broadcaster.subscribe();
+
+        let reader = tokio::spawn(async move { receiver.next().await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        broadcaster.send(7).await;
+
+        assert_eq!(
+            tokio::time::timeout(Duration::from_secs(1), reader)
+                .await
+                .unwrap()
+                .unwrap(),
+            Some(7)
+        );
+    }
+}
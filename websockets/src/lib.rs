@@ -32,3 +32,11 @@ where
     /// Returns a Stream that yields venue-specific messages
     fn message_stream(&mut self) -> Pin<Box<dyn Stream<Item = BoxResult<T>> + Send>>;
 }
+
+pub mod broadcast;
+pub mod reconnect;
+pub mod subscription;
+
+pub use broadcast::{BroadcastReceiver, Broadcaster, LagPolicy, SubscriberMetrics};
+pub use reconnect::{ConnectionEvent, DisconnectReason, ReconnectConfig, ReconnectProtocol, ReconnectingConnection};
+pub use subscription::{Subscription, SubscriptionManager, SubscriptionRequest};
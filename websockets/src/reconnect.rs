@@ -0,0 +1,866 @@
+//! Reconnecting WebSocket driver
+//!
+//! `ReconnectingConnection` owns a WebSocket and keeps it alive: it pings the server,
+//! treats a connection that stays silent for longer than the idle timeout as stale,
+//! reconnects with exponential backoff and jitter, and replays the active subscriptions
+//! on every new connection. Venue specifics (subscription frames and message decoding)
+//! are supplied through `ReconnectProtocol`.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::{SinkExt, Stream, StreamExt};
+use parking_lot::Mutex;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+use crate::{BoxResult, VenueMessage, WebSocketConnection};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Venue-specific framing used by `ReconnectingConnection`
+pub trait ReconnectProtocol: Send + Sync + 'static {
+    /// Messages decoded from the venue's text frames
+    type Message: VenueMessage + 'static;
+
+    /// Text frames that subscribe to `channels`
+    fn subscribe_frames(&self, channels: &[String]) -> Vec<String>;
+
+    /// Text frames that unsubscribe from `channels`
+    fn unsubscribe_frames(&self, channels: &[String]) -> Vec<String>;
+
+    /// Decode a text frame; `Ok(None)` skips frames such as acknowledgements
+    fn decode(&self, text: &str) -> BoxResult<Option<Self::Message>>;
+
+    /// Application-level ping sent alongside the WebSocket ping, for venues that expect one
+    fn ping_frame(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Timing of reconnects and liveness checks
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// Delay before the first reconnect attempt
+    pub initial_backoff: Duration,
+
+    /// Upper bound of the delay between reconnect attempts
+    pub max_backoff: Duration,
+
+    /// Fraction of each delay (0.0 to 1.0) that is randomly taken off, so clients do not reconnect in lockstep
+    pub jitter: f64,
+
+    /// Interval between pings
+    pub ping_interval: Duration,
+
+    /// A connection that receives nothing (not even a pong) for this long is stale
+    pub idle_timeout: Duration,
+
+    /// Consecutive failed reconnect attempts before giving up, unlimited when `None`
+    pub max_reconnect_attempts: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: 0.2,
+            ping_interval: Duration::from_secs(15),
+            idle_timeout: Duration::from_secs(30),
+            max_reconnect_attempts: None,
+        }
+    }
+}
+
+impl ReconnectConfig {
+    /// Set the initial and maximum delay between reconnect attempts
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set the fraction of each delay that is randomized
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the ping interval and the idle timeout after which a connection is stale
+    pub fn with_liveness(mut self, ping_interval: Duration, idle_timeout: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Give up after this many consecutive failed reconnect attempts
+    pub fn with_max_reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.max_reconnect_attempts = Some(attempts);
+        self
+    }
+}
+
+/// Exponential backoff with jitter
+#[derive(Debug)]
+struct Backoff {
+    initial: Duration,
+    max: Duration,
+    jitter: f64,
+    attempt: u32,
+}
+
+impl Backoff {
+    fn new(config: &ReconnectConfig) -> Self {
+        Self {
+            initial: config.initial_backoff,
+            max: config.max_backoff,
+            jitter: config.jitter,
+            attempt: 0,
+        }
+    }
+
+    /// Delay before the next attempt: `initial * 2^attempt`, capped at `max`, minus up to `jitter` of it
+    #[allow(clippy::float_arithmetic)]
+    fn next_delay(&mut self) -> Duration {
+        let base = self
+            .initial
+            .checked_mul(2u32.saturating_pow(self.attempt))
+            .map_or(self.max, |delay| delay.min(self.max));
+        self.attempt = self.attempt.saturating_add(1);
+        base.mul_f64(1.0 - self.jitter * rand::random::<f64>())
+    }
+
+    fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+/// Why a connection ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// The server closed the connection, with the close frame's reason if it sent one
+    Closed(Option<String>),
+    /// Reading from or writing to the socket failed
+    Error(String),
+    /// Nothing was received within the idle timeout
+    Stale,
+    /// `disconnect` was called
+    Requested,
+    /// Reconnecting failed `max_reconnect_attempts` times in a row
+    GaveUp,
+}
+
+impl fmt::Display for DisconnectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisconnectReason::Closed(Some(reason)) => write!(f, "closed by server: {}", reason),
+            DisconnectReason::Closed(None) => write!(f, "closed by server"),
+            DisconnectReason::Error(error) => write!(f, "connection error: {}", error),
+            DisconnectReason::Stale => write!(f, "no data within the idle timeout"),
+            DisconnectReason::Requested => write!(f, "disconnect requested"),
+            DisconnectReason::GaveUp => write!(f, "reconnect attempts exhausted"),
+        }
+    }
+}
+
+/// Lifecycle events and messages yielded by `ReconnectingConnection::message_stream`
+#[derive(Debug, PartialEq)]
+pub enum ConnectionEvent<T> {
+    /// A connection was established (the first one or a reconnect)
+    Connected,
+    /// The connection ended; unless the reason is `Requested` or `GaveUp`, a reconnect follows
+    Disconnected { reason: DisconnectReason },
+    /// The active subscriptions were sent again on a new connection
+    Resubscribed { channels: Vec<String> },
+    /// Nothing was received within the idle timeout; the connection is dropped and re-established
+    Stale,
+    /// A message decoded by the protocol
+    Message(T),
+}
+
+impl<T: VenueMessage> VenueMessage for ConnectionEvent<T> {}
+
+/// Requests from the handle to the driver task
+enum Command {
+    Send(Vec<String>),
+    Shutdown,
+}
+
+/// State shared between the handle and the driver task
+struct Shared {
+    connected: AtomicBool,
+    subscriptions: Mutex<BTreeSet<String>>,
+}
+
+/// WebSocket connection that reconnects, keeps itself alive and replays subscriptions
+pub struct ReconnectingConnection<P: ReconnectProtocol> {
+    url: String,
+    protocol: Arc<P>,
+    config: ReconnectConfig,
+    shared: Arc<Shared>,
+    commands: Option<mpsc::UnboundedSender<Command>>,
+    task: Option<JoinHandle<()>>,
+    event_tx: mpsc::UnboundedSender<BoxResult<ConnectionEvent<P::Message>>>,
+    event_rx: Option<mpsc::UnboundedReceiver<BoxResult<ConnectionEvent<P::Message>>>>,
+}
+
+impl<P: ReconnectProtocol> ReconnectingConnection<P> {
+    /// Create a driver for `url` with the default `ReconnectConfig`
+    pub fn new(url: impl Into<String>, protocol: P) -> Self {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        Self {
+            url: url.into(),
+            protocol: Arc::new(protocol),
+            config: ReconnectConfig::default(),
+            shared: Arc::new(Shared {
+                connected: AtomicBool::new(false),
+                subscriptions: Mutex::new(BTreeSet::new()),
+            }),
+            commands: None,
+            task: None,
+            event_tx,
+            event_rx: Some(event_rx),
+        }
+    }
+
+    /// Set the reconnect and liveness timing
+    pub fn with_config(mut self, config: ReconnectConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Channels that are replayed on every new connection
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.shared.subscriptions.lock().iter().cloned().collect()
+    }
+
+    /// Subscribe to `channels` now (if connected) and on every reconnect
+    ///
+    /// Channels that are already active are not subscribed again.
+    pub fn subscribe(&self, channels: &[String]) -> BoxResult<()> {
+        let added: Vec<String> = {
+            let mut subscriptions = self.shared.subscriptions.lock();
+            channels
+                .iter()
+                .filter(|channel| subscriptions.insert((*channel).clone()))
+                .cloned()
+                .collect()
+        };
+        if added.is_empty() {
+            return Ok(());
+        }
+        self.send_frames(self.protocol.subscribe_frames(&added))
+    }
+
+    /// Unsubscribe from `channels` and stop replaying them
+    pub fn unsubscribe(&self, channels: &[String]) -> BoxResult<()> {
+        let removed: Vec<String> = {
+            let mut subscriptions = self.shared.subscriptions.lock();
+            channels
+                .iter()
+                .filter(|channel| subscriptions.remove(*channel))
+                .cloned()
+                .collect()
+        };
+        if removed.is_empty() {
+            return Ok(());
+        }
+        self.send_frames(self.protocol.unsubscribe_frames(&removed))
+    }
+
+    /// Send a text frame on the current connection; it is not replayed after a reconnect
+    pub fn send(&self, frame: String) -> BoxResult<()> {
+        if !self.is_connected() {
+            return Err("not connected".into());
+        }
+        self.send_frames(vec![frame])
+    }
+
+    /// Queue frames for the driver task; while disconnected they are dropped, as the
+    /// subscriptions they carry are replayed on the next connection
+    fn send_frames(&self, frames: Vec<String>) -> BoxResult<()> {
+        match &self.commands {
+            Some(commands) if self.is_connected() => commands
+                .send(Command::Send(frames))
+                .map_err(|_| "connection driver stopped".into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl<P: ReconnectProtocol> WebSocketConnection<ConnectionEvent<P::Message>> for ReconnectingConnection<P> {
+    /// Connect and start the driver task
+    ///
+    /// The first connection attempt is made here and its failure returned; after that
+    /// the driver reconnects on its own until `disconnect` is called.
+    async fn connect(&mut self) -> BoxResult<()> {
+        if self.task.as_ref().is_some_and(|task| !task.is_finished()) {
+            return Ok(());
+        }
+
+        let (socket, _) = connect_async(self.url.as_str()).await?;
+        let (commands, command_rx) = mpsc::unbounded_channel();
+        let driver = Driver {
+            url: self.url.clone(),
+            protocol: self.protocol.clone(),
+            config: self.config.clone(),
+            shared: self.shared.clone(),
+            events: self.event_tx.clone(),
+            commands: command_rx,
+        };
+        self.shared.connected.store(true, Ordering::SeqCst);
+        self.commands = Some(commands);
+        self.task = Some(tokio::spawn(driver.run(socket)));
+        Ok(())
+    }
+
+    /// Close the connection and stop reconnecting
+    async fn disconnect(&mut self) -> BoxResult<()> {
+        if let Some(commands) = self.commands.take() {
+            let _ = commands.send(Command::Shutdown);
+        }
+        if let Some(task) = self.task.take() {
+            task.await?;
+        }
+        self.shared.connected.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.shared.connected.load(Ordering::SeqCst)
+    }
+
+    fn message_stream(&mut self) -> Pin<Box<dyn Stream<Item = BoxResult<ConnectionEvent<P::Message>>> + Send>> {
+        match self.event_rx.take() {
+            Some(rx) => Box::pin(futures::stream::unfold(rx, |mut rx| async move {
+                rx.recv().await.map(|event| (event, rx))
+            })),
+            None => Box::pin(futures::stream::empty()),
+        }
+    }
+}
+
+impl<P: ReconnectProtocol> Drop for ReconnectingConnection<P> {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Background task owning the socket
+struct Driver<P: ReconnectProtocol> {
+    url: String,
+    protocol: Arc<P>,
+    config: ReconnectConfig,
+    shared: Arc<Shared>,
+    events: mpsc::UnboundedSender<BoxResult<ConnectionEvent<P::Message>>>,
+    commands: mpsc::UnboundedReceiver<Command>,
+}
+
+impl<P: ReconnectProtocol> Driver<P> {
+    fn emit(&self, event: ConnectionEvent<P::Message>) {
+        // Nobody reading the stream is not an error
+        let _ = self.events.send(Ok(event));
+    }
+
+    async fn run(mut self, first: Socket) {
+        let mut backoff = Backoff::new(&self.config);
+        let mut socket = Some(first);
+        let mut failed_attempts = 0u32;
+
+        loop {
+            if let Some(connected) = socket.take() {
+                backoff.reset();
+                failed_attempts = 0;
+                self.shared.connected.store(true, Ordering::SeqCst);
+                let reason = self.session(connected).await;
+                self.shared.connected.store(false, Ordering::SeqCst);
+                let stop = reason == DisconnectReason::Requested;
+                self.emit(ConnectionEvent::Disconnected { reason });
+                if stop {
+                    return;
+                }
+            }
+
+            if self
+                .config
+                .max_reconnect_attempts
+                .is_some_and(|max| failed_attempts >= max)
+            {
+                self.emit(ConnectionEvent::Disconnected {
+                    reason: DisconnectReason::GaveUp,
+                });
+                return;
+            }
+
+            // One delay per attempt: commands arriving meanwhile must not restart or grow it
+            let delay = tokio::time::sleep(backoff.next_delay());
+            tokio::pin!(delay);
+            loop {
+                tokio::select! {
+                    command = self.commands.recv() => {
+                        if matches!(command, Some(Command::Shutdown) | None) {
+                            return;
+                        }
+                        // Frames queued while disconnected are covered by the replay
+                    }
+                    _ = &mut delay => break,
+                }
+            }
+
+            match connect_async(self.url.as_str()).await {
+                Ok((connected, _)) => socket = Some(connected),
+                Err(_) => failed_attempts = failed_attempts.saturating_add(1),
+            }
+        }
+    }
+
+    /// Drive one connection until it ends
+    #[allow(clippy::arithmetic_side_effects)]
+    async fn session(&mut self, mut socket: Socket) -> DisconnectReason {
+        self.emit(ConnectionEvent::Connected);
+
+        let channels: Vec<String> = self.shared.subscriptions.lock().iter().cloned().collect();
+        if !channels.is_empty() {
+            for frame in self.protocol.subscribe_frames(&channels) {
+                if let Err(error) = socket.send(Message::Text(frame.into())).await {
+                    return DisconnectReason::Error(error.to_string());
+                }
+            }
+            self.emit(ConnectionEvent::Resubscribed { channels });
+        }
+
+        let mut last_seen = Instant::now();
+        let mut ping = tokio::time::interval_at(
+            Instant::now() + self.config.ping_interval,
+            self.config.ping_interval,
+        );
+
+        loop {
+            let idle_deadline = last_seen + self.config.idle_timeout;
+            tokio::select! {
+                frame = socket.next() => {
+                    last_seen = Instant::now();
+                    match frame {
+                        Some(Ok(Message::Text(text))) => match self.protocol.decode(text.as_str()) {
+                            Ok(Some(message)) => self.emit(ConnectionEvent::Message(message)),
+                            Ok(None) => {}
+                            Err(error) => {
+                                let _ = self.events.send(Err(error));
+                            }
+                        },
+                        Some(Ok(Message::Close(frame))) => {
+                            return DisconnectReason::Closed(frame.map(|frame| frame.reason.to_string()));
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(error)) => return DisconnectReason::Error(error.to_string()),
+                        None => return DisconnectReason::Closed(None),
+                    }
+                }
+                command = self.commands.recv() => match command {
+                    Some(Command::Send(frames)) => {
+                        for frame in frames {
+                            if let Err(error) = socket.send(Message::Text(frame.into())).await {
+                                return DisconnectReason::Error(error.to_string());
+                            }
+                        }
+                    }
+                    Some(Command::Shutdown) | None => {
+                        let _ = socket.close(None).await;
+                        return DisconnectReason::Requested;
+                    }
+                },
+                _ = ping.tick() => {
+                    if let Err(error) = socket.send(Message::Ping(Vec::new().into())).await {
+                        return DisconnectReason::Error(error.to_string());
+                    }
+                    if let Some(frame) = self.protocol.ping_frame() {
+                        if let Err(error) = socket.send(Message::Text(frame.into())).await {
+                            return DisconnectReason::Error(error.to_string());
+                        }
+                    }
+                }
+                _ = tokio::time::sleep_until(idle_deadline) => {
+                    self.emit(ConnectionEvent::Stale);
+                    return DisconnectReason::Stale;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct TestMessage(String);
+
+    impl VenueMessage for TestMessage {}
+
+    struct TestProtocol;
+
+    impl ReconnectProtocol for TestProtocol {
+        type Message = TestMessage;
+
+        fn subscribe_frames(&self, channels: &[String]) -> Vec<String> {
+            vec![format!("subscribe:{}", channels.join(","))]
+        }
+
+        fn unsubscribe_frames(&self, channels: &[String]) -> Vec<String> {
+            vec![format!("unsubscribe:{}", channels.join(","))]
+        }
+
+        fn decode(&self, text: &str) -> BoxResult<Option<TestMessage>> {
+            match text {
+                "ack" => Ok(None),
+                "garbage" => Err("cannot decode".into()),
+                _ => Ok(Some(TestMessage(text.to_string()))),
+            }
+        }
+    }
+
+    /// How the test server treats each accepted connection
+    #[derive(Clone, Copy)]
+    enum ServerBehavior {
+        /// Echo "data:<n>" for every frame received, then drop after the first one
+        EchoThenDrop,
+        /// Accept and never read or write, so pings go unanswered
+        Silent,
+    }
+
+    /// In-process server; returns its URL and the frames it received, tagged with the connection number
+    async fn spawn_server(behavior: ServerBehavior) -> (String, mpsc::UnboundedReceiver<(usize, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (seen_tx, seen_rx) = mpsc::unbounded_channel();
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let number = connections.fetch_add(1, Ordering::SeqCst);
+                let seen_tx = seen_tx.clone();
+                tokio::spawn(async move {
+                    let mut socket = accept_async(stream).await.unwrap();
+                    match behavior {
+                        ServerBehavior::EchoThenDrop => {
+                            socket.send(Message::Text("ack".into())).await.unwrap();
+                            while let Some(Ok(frame)) = socket.next().await {
+                                if let Message::Text(text) = frame {
+                                    let _ = seen_tx.send((number, text.to_string()));
+                                    let _ = socket
+                                        .send(Message::Text(format!("data:{}", number).into()))
+                                        .await;
+                                    // Drop the connection without a close handshake
+                                    return;
+                                }
+                            }
+                        }
+                        ServerBehavior::Silent => {
+                            tokio::time::sleep(Duration::from_secs(5)).await;
+                            drop(socket);
+                        }
+                    }
+                });
+            }
+        });
+
+        (url, seen_rx)
+    }
+
+    fn fast_config() -> ReconnectConfig {
+        ReconnectConfig::default()
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(50))
+            .with_liveness(Duration::from_millis(50), Duration::from_millis(200))
+    }
+
+    async fn next_event<S>(stream: &mut S) -> ConnectionEvent<TestMessage>
+    where
+        S: Stream<Item = BoxResult<ConnectionEvent<TestMessage>>> + Unpin,
+    {
+        tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let config = ReconnectConfig::default()
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(1000))
+            .with_jitter(0.0);
+        let mut backoff = Backoff::new(&config);
+
+        let delays: Vec<u128> = (0..6).map(|_| backoff.next_delay().as_millis()).collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_within_bounds() {
+        let config = ReconnectConfig::default()
+            .with_backoff(Duration::from_millis(1000), Duration::from_millis(1000))
+            .with_jitter(0.5);
+        let mut backoff = Backoff::new(&config);
+
+        for _ in 0..100 {
+            let delay = backoff.next_delay();
+            assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn test_jitter_is_clamped() {
+        assert_eq!(ReconnectConfig::default().with_jitter(3.0).jitter, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_connect_failure_is_returned() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let mut connection = ReconnectingConnection::new(url, TestProtocol);
+        assert!(connection.connect().await.is_err());
+        assert!(!connection.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_and_replays_subscriptions() {
+        let (url, mut seen) = spawn_server(ServerBehavior::EchoThenDrop).await;
+        let mut connection = ReconnectingConnection::new(url, TestProtocol).with_config(fast_config());
+        let mut events = connection.message_stream();
+
+        connection.connect().await.unwrap();
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        connection.subscribe(&["trades".to_string()]).unwrap();
+
+        assert_eq!(seen.recv().await, Some((0, "subscribe:trades".to_string())));
+        assert_eq!(
+            next_event(&mut events).await,
+            ConnectionEvent::Message(TestMessage("data:0".to_string()))
+        );
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected {
+                reason: DisconnectReason::Closed(_) | DisconnectReason::Error(_)
+            }
+        ));
+
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        assert_eq!(
+            next_event(&mut events).await,
+            ConnectionEvent::Resubscribed {
+                channels: vec!["trades".to_string()]
+            }
+        );
+        assert_eq!(seen.recv().await, Some((1, "subscribe:trades".to_string())));
+        assert_eq!(
+            next_event(&mut events).await,
+            ConnectionEvent::Message(TestMessage("data:1".to_string()))
+        );
+
+        connection.disconnect().await.unwrap();
+        assert!(!connection.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribed_channels_are_not_replayed() {
+        let (url, mut seen) = spawn_server(ServerBehavior::EchoThenDrop).await;
+        let mut connection = ReconnectingConnection::new(url, TestProtocol).with_config(fast_config());
+        connection
+            .subscribe(&["trades".to_string(), "book".to_string()])
+            .unwrap();
+        connection.unsubscribe(&["book".to_string()]).unwrap();
+
+        connection.connect().await.unwrap();
+
+        assert_eq!(connection.subscriptions(), vec!["trades".to_string()]);
+        assert_eq!(seen.recv().await, Some((0, "subscribe:trades".to_string())));
+        connection.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_silent_connection_is_stale() {
+        let (url, _seen) = spawn_server(ServerBehavior::Silent).await;
+        let mut connection = ReconnectingConnection::new(url, TestProtocol).with_config(fast_config());
+        let mut events = connection.message_stream();
+
+        connection.connect().await.unwrap();
+
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Stale
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected {
+                reason: DisconnectReason::Stale
+            }
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        connection.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        // Accept one connection, then stop listening
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let socket = accept_async(stream).await.unwrap();
+            drop(socket);
+        });
+        let mut connection = ReconnectingConnection::new(url, TestProtocol).with_config(fast_config().with_max_reconnect_attempts(2));
+        let mut events = connection.message_stream();
+
+        connection.connect().await.unwrap();
+        server.await.unwrap();
+
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected { .. }
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected {
+                reason: DisconnectReason::GaveUp
+            }
+        ));
+        assert!(!connection.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_frames_during_backoff_do_not_delay_reconnect() {
+        let (url, _seen) = spawn_server(ServerBehavior::EchoThenDrop).await;
+        let config = fast_config()
+            .with_backoff(Duration::from_millis(200), Duration::from_millis(200))
+            .with_jitter(0.0);
+        let mut connection = ReconnectingConnection::new(url, TestProtocol).with_config(config);
+        let mut events = connection.message_stream();
+
+        connection.connect().await.unwrap();
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        connection.send("drop me".to_string()).unwrap();
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Message(_)
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected { .. }
+        ));
+
+        // Keep frames coming faster than the backoff; they must not postpone the reconnect
+        let commands = connection.commands.clone().unwrap();
+        let sender = tokio::spawn(async move {
+            while commands
+                .send(Command::Send(vec!["queued".to_string()]))
+                .is_ok()
+            {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        });
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        sender.abort();
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_stops_reconnecting() {
+        let (url, _seen) = spawn_server(ServerBehavior::EchoThenDrop).await;
+        let mut connection = ReconnectingConnection::new(url, TestProtocol).with_config(fast_config());
+        let mut events = connection.message_stream();
+
+        connection.connect().await.unwrap();
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        connection.disconnect().await.unwrap();
+
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected {
+                reason: DisconnectReason::Requested
+            }
+        ));
+        assert!(!connection.is_connected());
+        assert!(connection.send("late".to_string()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_decode_errors_are_yielded() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = accept_async(stream).await.unwrap();
+            socket.send(Message::Text("garbage".into())).await.unwrap();
+            socket.send(Message::Text("hello".into())).await.unwrap();
+            while socket.next().await.is_some() {}
+        });
+        let mut connection = ReconnectingConnection::new(url, TestProtocol).with_config(fast_config());
+        let mut events = connection.message_stream();
+
+        connection.connect().await.unwrap();
+
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected
+        ));
+        let error = tokio::time::timeout(Duration::from_secs(5), events.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(error.is_err());
+        assert_eq!(
+            next_event(&mut events).await,
+            ConnectionEvent::Message(TestMessage("hello".to_string()))
+        );
+        connection.disconnect().await.unwrap();
+    }
+}
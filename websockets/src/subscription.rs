@@ -0,0 +1,534 @@
+//! Subscription management shared by venue WebSocket clients
+//!
+//! `SubscriptionManager` keeps the set of channels consumers want (the desired set) apart
+//! from the set the venue has acknowledged (the confirmed set). The difference is turned
+//! into subscribe and unsubscribe requests of at most the venue's per-message channel
+//! limit, which the caller sends and then confirms. Channels are reference-counted, so a
+//! channel shared by several consumers is only unsubscribed when the last one lets go,
+//! and each consumer gets a `Subscription` stream carrying only its own channels.
+
+use std::collections::{BTreeSet, HashMap};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use parking_lot::Mutex;
+use tokio::sync::{mpsc, Notify};
+
+/// A batch of channels to send in one subscribe or unsubscribe message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionRequest {
+    /// Subscribe to the channels
+    Subscribe(Vec<String>),
+    /// Unsubscribe from the channels
+    Unsubscribe(Vec<String>),
+}
+
+impl SubscriptionRequest {
+    /// Channels carried by the request
+    pub fn channels(&self) -> &[String] {
+        match self {
+            SubscriptionRequest::Subscribe(channels) | SubscriptionRequest::Unsubscribe(channels) => channels,
+        }
+    }
+}
+
+/// One consumer's channels and the sender of its stream
+struct Consumer<M> {
+    channels: BTreeSet<String>,
+    tx: mpsc::UnboundedSender<M>,
+}
+
+struct State<M> {
+    /// Desired channels with the number of consumers holding each
+    refs: HashMap<String, usize>,
+    /// Channels the venue has acknowledged
+    confirmed: BTreeSet<String>,
+    /// Channels in subscribe requests that are not confirmed yet
+    subscribing: BTreeSet<String>,
+    /// Channels in unsubscribe requests that are not confirmed yet
+    unsubscribing: BTreeSet<String>,
+    consumers: HashMap<u64, Consumer<M>>,
+    next_consumer: u64,
+}
+
+impl<M> State<M> {
+    fn release(&mut self, channel: &str) {
+        let remaining = match self.refs.get_mut(channel) {
+            Some(count) => {
+                *count = count.saturating_sub(1);
+                *count
+            }
+            None => return,
+        };
+        if remaining == 0 {
+            self.refs.remove(channel);
+        }
+    }
+}
+
+struct Inner<M> {
+    state: Mutex<State<M>>,
+    /// Woken when the desired set changes or a confirmation arrives
+    changed: Notify,
+    max_channels_per_request: usize,
+}
+
+/// Tracks desired and confirmed channels and routes messages to the consumers of each channel
+pub struct SubscriptionManager<M> {
+    inner: Arc<Inner<M>>,
+}
+
+impl<M> Clone for SubscriptionManager<M> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<M: Clone + Send + 'static> SubscriptionManager<M> {
+    /// Create a manager for a venue that accepts at most `max_channels_per_request`
+    /// channels in one subscribe or unsubscribe message (treated as 1 if 0)
+    pub fn new(max_channels_per_request: usize) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State {
+                    refs: HashMap::new(),
+                    confirmed: BTreeSet::new(),
+                    subscribing: BTreeSet::new(),
+                    unsubscribing: BTreeSet::new(),
+                    consumers: HashMap::new(),
+                    next_consumer: 0,
+                }),
+                changed: Notify::new(),
+                max_channels_per_request: max_channels_per_request.max(1),
+            }),
+        }
+    }
+
+    /// Register a consumer; its channels are released when the returned subscription is dropped
+    pub fn consumer(&self) -> Subscription<M> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut state = self.inner.state.lock();
+        let id = state.next_consumer;
+        state.next_consumer = state.next_consumer.saturating_add(1);
+        state.consumers.insert(
+            id,
+            Consumer {
+                channels: BTreeSet::new(),
+                tx,
+            },
+        );
+        Subscription {
+            manager: self.clone(),
+            id,
+            rx,
+        }
+    }
+
+    /// Channels at least one consumer wants
+    pub fn desired(&self) -> BTreeSet<String> {
+        self.inner.state.lock().refs.keys().cloned().collect()
+    }
+
+    /// Channels the venue has acknowledged
+    pub fn confirmed(&self) -> BTreeSet<String> {
+        self.inner.state.lock().confirmed.clone()
+    }
+
+    /// Number of consumers holding `channel`
+    pub fn ref_count(&self, channel: &str) -> usize {
+        self.inner
+            .state
+            .lock()
+            .refs
+            .get(channel)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Requests that bring the confirmed set to the desired set, unsubscribes first
+    ///
+    /// Channels in the returned requests are considered in flight until confirmed with
+    /// `confirm`, or released with `request_failed`, and are not requested again meanwhile.
+    pub fn pending_requests(&self) -> Vec<SubscriptionRequest> {
+        let mut state = self.inner.state.lock();
+        let state = &mut *state;
+
+        let to_unsubscribe: Vec<String> = state
+            .confirmed
+            .iter()
+            .filter(|channel| !state.refs.contains_key(*channel) && !state.unsubscribing.contains(*channel))
+            .cloned()
+            .collect();
+        let mut to_subscribe: Vec<String> = state
+            .refs
+            .keys()
+            .filter(|channel| !state.confirmed.contains(*channel) && !state.subscribing.contains(*channel))
+            .cloned()
+            .collect();
+        to_subscribe.sort();
+
+        state.unsubscribing.extend(to_unsubscribe.iter().cloned());
+        state.subscribing.extend(to_subscribe.iter().cloned());
+
+        let max = self.inner.max_channels_per_request;
+        let unsubscribes = to_unsubscribe
+            .chunks(max)
+            .map(|chunk| SubscriptionRequest::Unsubscribe(chunk.to_vec()));
+        let subscribes = to_subscribe
+            .chunks(max)
+            .map(|chunk| SubscriptionRequest::Subscribe(chunk.to_vec()));
+        unsubscribes.chain(subscribes).collect()
+    }
+
+    /// Record that the venue acknowledged a request
+    ///
+    /// `channels` are the channels the venue reported, which may be a subset of those requested.
+    pub fn confirm(&self, request: &SubscriptionRequest, channels: &[String]) {
+        let mut state = self.inner.state.lock();
+        match request {
+            SubscriptionRequest::Subscribe(requested) => {
+                for channel in requested {
+                    state.subscribing.remove(channel);
+                }
+                state.confirmed.extend(channels.iter().cloned());
+            }
+            SubscriptionRequest::Unsubscribe(requested) => {
+                for channel in requested {
+                    state.unsubscribing.remove(channel);
+                }
+                for channel in channels {
+                    state.confirmed.remove(channel);
+                }
+            }
+        }
+        drop(state);
+        self.inner.changed.notify_one();
+    }
+
+    /// Release the channels of a request that failed, so the next `pending_requests` retries them
+    pub fn request_failed(&self, request: &SubscriptionRequest) {
+        let mut state = self.inner.state.lock();
+        for channel in request.channels() {
+            state.subscribing.remove(channel);
+            state.unsubscribing.remove(channel);
+        }
+        drop(state);
+        self.inner.changed.notify_one();
+    }
+
+    /// Forget all confirmations, e.g. after the connection was lost
+    ///
+    /// The next `pending_requests` subscribes to every desired channel again.
+    pub fn reset(&self) {
+        let mut state = self.inner.state.lock();
+        state.confirmed.clear();
+        state.subscribing.clear();
+        state.unsubscribing.clear();
+        drop(state);
+        self.inner.changed.notify_one();
+    }
+
+    /// Wait until the desired set changes or a confirmation arrives
+    pub async fn changed(&self) {
+        self.inner.changed.notified().await;
+    }
+
+    /// Deliver a message on `channel` to every consumer holding it
+    ///
+    /// Returns the number of consumers it was delivered to.
+    pub fn dispatch(&self, channel: &str, message: M) -> usize {
+        let state = self.inner.state.lock();
+        state
+            .consumers
+            .values()
+            .filter(|consumer| consumer.channels.contains(channel))
+            .filter(|consumer| consumer.tx.send(message.clone()).is_ok())
+            .count()
+    }
+
+    fn add_channels(&self, id: u64, channels: &[String]) {
+        let mut state = self.inner.state.lock();
+        let state = &mut *state;
+        let Some(consumer) = state.consumers.get_mut(&id) else {
+            return;
+        };
+        for channel in channels {
+            if consumer.channels.insert(channel.clone()) {
+                let count = state.refs.entry(channel.clone()).or_insert(0);
+                *count = count.saturating_add(1);
+            }
+        }
+        self.inner.changed.notify_one();
+    }
+
+    fn remove_channels(&self, id: u64, channels: &[String]) {
+        let mut state = self.inner.state.lock();
+        let removed: Vec<String> = match state.consumers.get_mut(&id) {
+            Some(consumer) => channels
+                .iter()
+                .filter(|channel| consumer.channels.remove(*channel))
+                .cloned()
+                .collect(),
+            None => return,
+        };
+        for channel in &removed {
+            state.release(channel);
+        }
+        self.inner.changed.notify_one();
+    }
+
+    fn remove_consumer(&self, id: u64) {
+        let mut state = self.inner.state.lock();
+        if let Some(consumer) = state.consumers.remove(&id) {
+            for channel in &consumer.channels {
+                state.release(channel);
+            }
+        }
+        self.inner.changed.notify_one();
+    }
+}
+
+/// A consumer's handle: changes its channels and streams the messages published on them
+pub struct Subscription<M: Clone + Send + 'static> {
+    manager: SubscriptionManager<M>,
+    id: u64,
+    rx: mpsc::UnboundedReceiver<M>,
+}
+
+impl<M: Clone + Send + 'static> Subscription<M> {
+    /// Add channels to this consumer
+    pub fn subscribe(&self, channels: &[String]) {
+        self.manager.add_channels(self.id, channels);
+    }
+
+    /// Remove channels from this consumer
+    pub fn unsubscribe(&self, channels: &[String]) {
+        self.manager.remove_channels(self.id, channels);
+    }
+
+    /// Channels this consumer holds
+    pub fn channels(&self) -> BTreeSet<String> {
+        self.manager
+            .inner
+            .state
+            .lock()
+            .consumers
+            .get(&self.id)
+            .map(|consumer| consumer.channels.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl<M: Clone + Send + 'static> Stream for Subscription<M> {
+    type Item = M;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<M>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl<M: Clone + Send + 'static> Drop for Subscription<M> {
+    fn drop(&mut self) {
+        self.manager.remove_consumer(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    use super::*;
+
+    fn channels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    /// Confirm every request in full, as a venue that accepts everything would
+    fn confirm_all(manager: &SubscriptionManager<String>) -> Vec<SubscriptionRequest> {
+        let requests = manager.pending_requests();
+        for request in &requests {
+            manager.confirm(request, request.channels());
+        }
+        requests
+    }
+
+    #[test]
+    fn test_requests_are_batched_under_the_channel_limit() {
+        let manager = SubscriptionManager::<String>::new(2);
+        let consumer = manager.consumer();
+
+        consumer.subscribe(&channels(&["a", "b", "c", "d", "e"]));
+
+        assert_eq!(
+            manager.pending_requests(),
+            vec![
+                SubscriptionRequest::Subscribe(channels(&["a", "b"])),
+                SubscriptionRequest::Subscribe(channels(&["c", "d"])),
+                SubscriptionRequest::Subscribe(channels(&["e"])),
+            ]
+        );
+        // In flight channels are not requested twice
+        assert!(manager.pending_requests().is_empty());
+    }
+
+    #[test]
+    fn test_zero_limit_is_treated_as_one() {
+        let manager = SubscriptionManager::<String>::new(0);
+        let consumer = manager.consumer();
+
+        consumer.subscribe(&channels(&["a", "b"]));
+
+        assert_eq!(manager.pending_requests().len(), 2);
+    }
+
+    #[test]
+    fn test_desired_and_confirmed_sets() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let consumer = manager.consumer();
+        consumer.subscribe(&channels(&["a", "b"]));
+
+        let requests = manager.pending_requests();
+        // The venue only accepted "a"
+        manager.confirm(requests.first().unwrap(), &channels(&["a"]));
+
+        assert_eq!(
+            manager.desired(),
+            channels(&["a", "b"]).into_iter().collect()
+        );
+        assert_eq!(manager.confirmed(), channels(&["a"]).into_iter().collect());
+        assert_eq!(
+            manager.pending_requests(),
+            vec![SubscriptionRequest::Subscribe(channels(&["b"]))]
+        );
+    }
+
+    #[test]
+    fn test_shared_channels_are_reference_counted() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let first = manager.consumer();
+        let second = manager.consumer();
+        first.subscribe(&channels(&["trades"]));
+        second.subscribe(&channels(&["trades", "book"]));
+        // Subscribing twice from the same consumer does not add a reference
+        first.subscribe(&channels(&["trades"]));
+
+        assert_eq!(manager.ref_count("trades"), 2);
+        assert_eq!(
+            confirm_all(&manager),
+            vec![SubscriptionRequest::Subscribe(channels(&[
+                "book", "trades"
+            ]))]
+        );
+
+        first.unsubscribe(&channels(&["trades"]));
+        assert_eq!(manager.ref_count("trades"), 1);
+        assert!(manager.pending_requests().is_empty());
+
+        drop(second);
+        assert_eq!(manager.ref_count("trades"), 0);
+        assert_eq!(
+            manager.pending_requests(),
+            vec![SubscriptionRequest::Unsubscribe(channels(&[
+                "book", "trades"
+            ]))]
+        );
+    }
+
+    #[test]
+    fn test_unsubscribe_confirmation_updates_confirmed_set() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let consumer = manager.consumer();
+        consumer.subscribe(&channels(&["a"]));
+        confirm_all(&manager);
+
+        consumer.unsubscribe(&channels(&["a"]));
+        confirm_all(&manager);
+
+        assert!(manager.confirmed().is_empty());
+        assert!(manager.pending_requests().is_empty());
+    }
+
+    #[test]
+    fn test_channel_dropped_while_subscribing_is_unsubscribed() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let consumer = manager.consumer();
+        consumer.subscribe(&channels(&["a"]));
+        let requests = manager.pending_requests();
+
+        consumer.unsubscribe(&channels(&["a"]));
+        let request = requests.first().unwrap();
+        manager.confirm(request, request.channels());
+
+        assert_eq!(
+            manager.pending_requests(),
+            vec![SubscriptionRequest::Unsubscribe(channels(&["a"]))]
+        );
+    }
+
+    #[test]
+    fn test_failed_request_is_retried() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let consumer = manager.consumer();
+        consumer.subscribe(&channels(&["a"]));
+        let requests = manager.pending_requests();
+
+        manager.request_failed(requests.first().unwrap());
+
+        assert_eq!(manager.pending_requests(), requests);
+    }
+
+    #[test]
+    fn test_reset_resubscribes_desired_channels() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let consumer = manager.consumer();
+        consumer.subscribe(&channels(&["a", "b"]));
+        confirm_all(&manager);
+
+        manager.reset();
+
+        assert!(manager.confirmed().is_empty());
+        assert_eq!(
+            manager.pending_requests(),
+            vec![SubscriptionRequest::Subscribe(channels(&["a", "b"]))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_consumers_only_receive_their_channels() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let mut trades = manager.consumer();
+        let mut book = manager.consumer();
+        trades.subscribe(&channels(&["trades"]));
+        book.subscribe(&channels(&["book", "trades"]));
+
+        assert_eq!(manager.dispatch("book", "b1".to_string()), 1);
+        assert_eq!(manager.dispatch("trades", "t1".to_string()), 2);
+        assert_eq!(manager.dispatch("ticker", "x".to_string()), 0);
+
+        assert_eq!(trades.next().await, Some("t1".to_string()));
+        assert_eq!(book.next().await, Some("b1".to_string()));
+        assert_eq!(book.next().await, Some("t1".to_string()));
+        assert_eq!(
+            trades.channels(),
+            channels(&["trades"]).into_iter().collect()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_changed_is_notified() {
+        let manager = SubscriptionManager::<String>::new(10);
+        let consumer = manager.consumer();
+
+        consumer.subscribe(&channels(&["a"]));
+
+        tokio::time::timeout(Duration::from_secs(1), manager.changed())
+            .await
+            .unwrap();
+    }
+}